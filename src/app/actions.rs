@@ -3,8 +3,11 @@
 //!
 //! `actions_for` is the single source of truth for "what can I do to the
 //! selected item" so the menu, hotkey hints, and handlers can never drift.
+use std::time::Duration;
+
 use crate::app::key_binding::DEFAULT_KEYBINDING;
 use crate::app::ActiveBlock;
+use crate::cmd::IoCmdEvent;
 use crate::event::Key;
 use crate::network::{IoEvent, ResourcePatch};
 
@@ -21,6 +24,9 @@ pub enum ResourceAction {
   PreviousLogs,
   Restart,
   Scale,
+  RolloutUndo,
+  Evict,
+  RestartCrashLooping,
   Cordon,
   Suspend,
   Trigger,
@@ -41,6 +47,9 @@ impl ResourceAction {
       ResourceAction::PreviousLogs => "Previous logs",
       ResourceAction::Restart => "Rollout restart",
       ResourceAction::Scale => "Scale",
+      ResourceAction::RolloutUndo => "Rollout undo",
+      ResourceAction::Evict => "Evict",
+      ResourceAction::RestartCrashLooping => "Restart crash-looping pods",
       ResourceAction::Cordon => "Cordon / Uncordon",
       ResourceAction::Suspend => "Suspend / Resume",
       ResourceAction::Trigger => "Trigger now",
@@ -72,10 +81,13 @@ impl ResourceAction {
       ResourceAction::Restart => Some(DEFAULT_KEYBINDING.restart_resource.key),
       ResourceAction::DecodeSecret => Some(DEFAULT_KEYBINDING.decode_secret.key),
       ResourceAction::Delete => Some(DEFAULT_KEYBINDING.delete_resource.key),
-      // Menu-only actions: they need a value (scale) or a derived direction
-      // (cordon/suspend) so they open an input/confirm overlay from the menu
-      // rather than firing a single hotkey.
+      // Menu-only actions: they need a value (scale, undo revision) or a
+      // derived direction (cordon/suspend) so they open an input/confirm
+      // overlay from the menu rather than firing a single hotkey.
       ResourceAction::Scale
+      | ResourceAction::RolloutUndo
+      | ResourceAction::Evict
+      | ResourceAction::RestartCrashLooping
       | ResourceAction::Cordon
       | ResourceAction::Suspend
       | ResourceAction::Trigger => None,
@@ -90,6 +102,7 @@ pub fn actions_for(block: ActiveBlock) -> Vec<ResourceAction> {
   use ResourceAction::*;
   match block {
     ActiveBlock::Containers => vec![Logs, PreviousLogs, Shell],
+    // Evict goes through the PodDisruptionBudgets a delete ignores.
     ActiveBlock::Pods => vec![
       Describe,
       Yaml,
@@ -97,15 +110,26 @@ pub fn actions_for(block: ActiveBlock) -> Vec<ResourceAction> {
       Logs,
       PreviousLogs,
       PortForward,
+      Evict,
+      RestartCrashLooping,
       Delete,
     ],
     // Services are port-forwardable but not pod-bearing (no logs/shell).
     ActiveBlock::Services => vec![Describe, Yaml, Edit, PortForward, Delete],
     ActiveBlock::Secrets => vec![Describe, Yaml, Edit, DecodeSecret, Delete],
-    // Deployments and statefulsets are both rollout-restartable and scalable.
-    ActiveBlock::Deployments | ActiveBlock::StatefulSets => {
-      vec![Describe, Yaml, Edit, Logs, Restart, Scale, Delete]
-    }
+    // Deployments and statefulsets are both rollout-restartable and scalable;
+    // deployments can also be rolled back.
+    ActiveBlock::Deployments => vec![
+      Describe,
+      Yaml,
+      Edit,
+      Logs,
+      Restart,
+      Scale,
+      RolloutUndo,
+      Delete,
+    ],
+    ActiveBlock::StatefulSets => vec![Describe, Yaml, Edit, Logs, Restart, Scale, Delete],
     // Daemonsets are restartable but not scalable (no replica count).
     ActiveBlock::DaemonSets => vec![Describe, Yaml, Edit, Logs, Restart, Delete],
     // Replicasets and replicationcontrollers are scalable but not restartable.
@@ -118,6 +142,7 @@ pub fn actions_for(block: ActiveBlock) -> Vec<ResourceAction> {
     // Troubleshoot findings support describe/yaml (handled by the troubleshoot
     // route), so the `m` hint shown on that pane is honest.
     ActiveBlock::Troubleshoot => vec![Describe, Yaml],
+    ActiveBlock::Watchlist => vec![Describe],
     ActiveBlock::ConfigMaps
     | ActiveBlock::StorageClasses
     | ActiveBlock::Roles
@@ -128,6 +153,10 @@ pub fn actions_for(block: ActiveBlock) -> Vec<ResourceAction> {
     | ActiveBlock::PersistentVolumeClaims
     | ActiveBlock::PersistentVolumes
     | ActiveBlock::NetworkPolicies
+    | ActiveBlock::ResourceQuotas
+    | ActiveBlock::LimitRanges
+    | ActiveBlock::PodDisruptionBudgets
+    | ActiveBlock::CustomResourceDefinitions
     | ActiveBlock::ServiceAccounts
     | ActiveBlock::Events
     | ActiveBlock::DynamicResource => vec![Describe, Yaml, Edit, Delete],
@@ -143,16 +172,48 @@ pub struct Modal {
   pub title: String,
   pub prompt: String,
   /// Event dispatched when the modal is confirmed.
-  pub on_confirm: IoEvent,
+  pub on_confirm: ConfirmAction,
+}
+
+/// What a confirmed [`Modal`] dispatches: an API call, a kubectl command for
+/// what the API has no single call for (rollout undo), or the calls of a
+/// macro in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfirmAction {
+  Io(IoEvent),
+  Cmd(IoCmdEvent),
+  Macro { name: String, events: Vec<IoEvent> },
+}
+
+impl From<IoEvent> for ConfirmAction {
+  fn from(event: IoEvent) -> Self {
+    ConfirmAction::Io(event)
+  }
+}
+
+impl From<IoCmdEvent> for ConfirmAction {
+  fn from(event: IoCmdEvent) -> Self {
+    ConfirmAction::Cmd(event)
+  }
+}
+
+impl PartialEq<IoEvent> for ConfirmAction {
+  fn eq(&self, other: &IoEvent) -> bool {
+    matches!(self, ConfirmAction::Io(event) if event == other)
+  }
 }
 
 impl Modal {
   /// Build a confirmation modal that dispatches `on_confirm` when accepted.
-  pub fn confirm(title: impl Into<String>, prompt: impl Into<String>, on_confirm: IoEvent) -> Self {
+  pub fn confirm(
+    title: impl Into<String>,
+    prompt: impl Into<String>,
+    on_confirm: impl Into<ConfirmAction>,
+  ) -> Self {
     Modal {
       title: title.into(),
       prompt: prompt.into(),
-      on_confirm,
+      on_confirm: on_confirm.into(),
     }
   }
 }
@@ -191,6 +252,19 @@ pub enum InputAction {
     namespace: String,
     name: String,
   },
+  /// Filter the pod list server-side by a label selector; an empty buffer
+  /// clears the active selector.
+  LabelSelector,
+  /// Limit the log view to a recent window (e.g. `10m`); an empty buffer
+  /// returns to the tail-line count.
+  LogSince,
+  /// Mark the log view, with the buffer as the note.
+  LogMark,
+  /// Create a namespace named after the buffer.
+  CreateNamespace,
+  /// Roll a deployment back to the revision in the buffer, or to the previous
+  /// one when it is empty.
+  RolloutUndo { name: String, namespace: String },
 }
 
 /// What a validated [`InputModal`] feeds into. Impactful actions chain into a
@@ -205,6 +279,10 @@ pub enum InputSubmit {
     local_port: u16,
     remote_port: u16,
   },
+  SetLabelSelector(Option<String>),
+  SetLogSince(Option<Duration>),
+  AddLogMark(String),
+  CreateNamespace(String),
 }
 
 impl InputModal {
@@ -256,6 +334,33 @@ impl InputModal {
           remote_port,
         })
       }
+      InputAction::LabelSelector => Ok(InputSubmit::SetLabelSelector(parse_label_selector(
+        &self.buffer,
+      )?)),
+      InputAction::LogSince => Ok(InputSubmit::SetLogSince(parse_log_since(&self.buffer)?)),
+      InputAction::LogMark => Ok(InputSubmit::AddLogMark(self.buffer.trim().to_owned())),
+      InputAction::CreateNamespace => Ok(InputSubmit::CreateNamespace(parse_namespace_name(
+        &self.buffer,
+      )?)),
+      InputAction::RolloutUndo { name, namespace } => {
+        let revision = parse_revision(&self.buffer)?;
+        let target = match revision {
+          Some(revision) => format!("revision {}", revision),
+          None => "the previous revision".to_owned(),
+        };
+        Ok(InputSubmit::Confirm(Modal::confirm(
+          "Confirm rollout undo",
+          format!(
+            "Roll back deployment '{}' in namespace '{}' to {}?",
+            name, namespace, target
+          ),
+          IoCmdEvent::RolloutUndo {
+            name: name.clone(),
+            namespace: namespace.clone(),
+            revision,
+          },
+        )))
+      }
     }
   }
 }
@@ -279,6 +384,185 @@ fn parse_port_mapping(buffer: &str) -> Result<(u16, u16), String> {
   Ok((local, remote))
 }
 
+/// A rollout revision number, or `None` for the previous revision when the
+/// buffer is empty.
+fn parse_revision(buffer: &str) -> Result<Option<u32>, String> {
+  let buffer = buffer.trim();
+  if buffer.is_empty() {
+    return Ok(None);
+  }
+  match buffer.parse() {
+    Ok(revision) if revision > 0 => Ok(Some(revision)),
+    _ => Err("Enter a revision number, or leave empty for the previous one".to_owned()),
+  }
+}
+
+/// Validate a namespace name against the DNS-1123 label rules the API server
+/// enforces: at most 63 lowercase alphanumerics or `-`, starting and ending
+/// with an alphanumeric.
+pub fn parse_namespace_name(buffer: &str) -> Result<String, String> {
+  let name = buffer.trim();
+  if name.is_empty() {
+    return Err("Enter a namespace name".to_owned());
+  }
+  if name.len() > 63 {
+    return Err("Namespace names are at most 63 characters".to_owned());
+  }
+  if !name
+    .chars()
+    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+  {
+    return Err("Use only lowercase letters, digits and '-'".to_owned());
+  }
+  if name.starts_with('-') || name.ends_with('-') {
+    return Err("Namespace names must start and end with a letter or digit".to_owned());
+  }
+  Ok(name.to_owned())
+}
+
+/// Parse a human duration such as `10m`, `1h 30m` or `45s`, or `None` when the
+/// buffer is empty.
+pub fn parse_log_since(buffer: &str) -> Result<Option<Duration>, String> {
+  let buffer = buffer.trim();
+  if buffer.is_empty() {
+    return Ok(None);
+  }
+  match humantime::parse_duration(buffer) {
+    Ok(since) if since.is_zero() => Err("Duration must be greater than zero".to_owned()),
+    Ok(since) => Ok(Some(since)),
+    Err(_) => Err("Enter a duration like 10m, 1h or 30s".to_owned()),
+  }
+}
+
+/// Validate a Kubernetes label selector (e.g. `app=nginx,tier!=cache`,
+/// `env in (prod,qa)`, `!canary`) and return it trimmed, or `None` when the
+/// buffer is empty. The selector is passed verbatim to the API server, so this
+/// only catches syntax errors early enough to show them inline.
+pub fn parse_label_selector(buffer: &str) -> Result<Option<String>, String> {
+  let selector = buffer.trim();
+  if selector.is_empty() {
+    return Ok(None);
+  }
+  for requirement in split_selector_requirements(selector)? {
+    parse_selector_requirement(requirement.trim())?;
+  }
+  Ok(Some(selector.to_owned()))
+}
+
+/// Split on commas that are not inside an `in (...)` / `notin (...)` value set.
+fn split_selector_requirements(selector: &str) -> Result<Vec<&str>, String> {
+  let mut requirements = vec![];
+  let mut depth = 0u32;
+  let mut start = 0;
+  for (i, c) in selector.char_indices() {
+    match c {
+      '(' => depth += 1,
+      ')' => {
+        depth = depth
+          .checked_sub(1)
+          .ok_or_else(|| "Unbalanced ')' in selector".to_owned())?
+      }
+      ',' if depth == 0 => {
+        requirements.push(&selector[start..i]);
+        start = i + 1;
+      }
+      _ => {}
+    }
+  }
+  if depth > 0 {
+    return Err("Unbalanced '(' in selector".to_owned());
+  }
+  requirements.push(&selector[start..]);
+  Ok(requirements)
+}
+
+fn parse_selector_requirement(requirement: &str) -> Result<(), String> {
+  if requirement.is_empty() {
+    return Err("Empty requirement in selector".to_owned());
+  }
+  if let Some(key) = requirement.strip_prefix('!') {
+    return validate_label_key(key.trim());
+  }
+  if let Some((head, values)) = requirement.split_once('(') {
+    let values = values
+      .strip_suffix(')')
+      .ok_or_else(|| format!("Expected ')' at the end of '{}'", requirement))?;
+    let mut parts = head.split_whitespace();
+    let (Some(key), Some(op), None) = (parts.next(), parts.next(), parts.next()) else {
+      return Err(format!("Expected 'key in (a,b)' but got '{}'", requirement));
+    };
+    if op != "in" && op != "notin" {
+      return Err(format!(
+        "Unknown operator '{}', expected 'in' or 'notin'",
+        op
+      ));
+    }
+    validate_label_key(key)?;
+    for value in values.split(',') {
+      let value = value.trim();
+      if value.is_empty() {
+        return Err(format!("Empty value in '{}'", requirement));
+      }
+      validate_label_value(value)?;
+    }
+    return Ok(());
+  }
+  let split = ["!=", "==", "="]
+    .iter()
+    .find_map(|op| requirement.split_once(op));
+  match split {
+    Some((key, value)) => {
+      validate_label_key(key.trim())?;
+      validate_label_value(value.trim())
+    }
+    None => validate_label_key(requirement),
+  }
+}
+
+/// A label key is an optional DNS-subdomain prefix and `/`, then a name of at
+/// most 63 alphanumerics, `-`, `_` or `.` that starts and ends alphanumeric.
+fn validate_label_key(key: &str) -> Result<(), String> {
+  let invalid = || format!("Invalid label key '{}'", key);
+  let (prefix, name) = match key.split_once('/') {
+    Some((prefix, name)) => (Some(prefix), name),
+    None => (None, key),
+  };
+  if let Some(prefix) = prefix {
+    let valid_prefix = !prefix.is_empty()
+      && prefix.len() <= 253
+      && prefix
+        .split('.')
+        .all(|part| is_label_name(part) && !part.contains('_'));
+    if !valid_prefix {
+      return Err(invalid());
+    }
+  }
+  if is_label_name(name) {
+    Ok(())
+  } else {
+    Err(invalid())
+  }
+}
+
+fn validate_label_value(value: &str) -> Result<(), String> {
+  if value.is_empty() || is_label_name(value) {
+    Ok(())
+  } else {
+    Err(format!("Invalid label value '{}'", value))
+  }
+}
+
+fn is_label_name(name: &str) -> bool {
+  let bytes = name.as_bytes();
+  !bytes.is_empty()
+    && bytes.len() <= 63
+    && bytes[0].is_ascii_alphanumeric()
+    && bytes[bytes.len() - 1].is_ascii_alphanumeric()
+    && bytes
+      .iter()
+      .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -347,6 +631,37 @@ mod tests {
     assert!(!actions_for(ActiveBlock::Pods).contains(&ResourceAction::Scale));
   }
 
+  #[test]
+  fn test_rollout_undo_input_defaults_to_previous_revision() {
+    let input = |buffer: &str| InputModal {
+      title: "Rollout undo".into(),
+      prompt: "Revision:".into(),
+      buffer: buffer.into(),
+      error: None,
+      action: InputAction::RolloutUndo {
+        name: "web".into(),
+        namespace: "default".into(),
+      },
+    };
+    let modal = expect_confirm(input(" ").validate().unwrap());
+    assert_eq!(
+      modal.prompt,
+      "Roll back deployment 'web' in namespace 'default' to the previous revision?"
+    );
+    assert_eq!(
+      modal.on_confirm,
+      ConfirmAction::Cmd(IoCmdEvent::RolloutUndo {
+        name: "web".into(),
+        namespace: "default".into(),
+        revision: None,
+      })
+    );
+    assert!(input("0").validate().is_err());
+    assert!(input("two").validate().is_err());
+    assert!(actions_for(ActiveBlock::Deployments).contains(&ResourceAction::RolloutUndo));
+    assert!(!actions_for(ActiveBlock::StatefulSets).contains(&ResourceAction::RolloutUndo));
+  }
+
   #[test]
   fn test_scale_is_menu_only() {
     assert_eq!(ResourceAction::Scale.hotkey(ActiveBlock::Deployments), None);
@@ -549,4 +864,90 @@ mod tests {
       Some(DEFAULT_KEYBINDING.aggregate_logs.key)
     );
   }
+
+  #[test]
+  fn test_parse_label_selector_accepts_common_forms() {
+    for selector in [
+      "app=nginx",
+      "app=nginx,tier=frontend",
+      "app == nginx, tier != cache",
+      "environment in (production, qa)",
+      "tier notin (frontend,backend),!canary",
+      "app.kubernetes.io/name=web",
+      "release",
+      "app=",
+    ] {
+      assert_eq!(
+        parse_label_selector(selector),
+        Ok(Some(selector.to_owned())),
+        "{selector} should be valid"
+      );
+    }
+  }
+
+  #[test]
+  fn test_parse_label_selector_empty_clears() {
+    assert_eq!(parse_label_selector("   "), Ok(None));
+  }
+
+  #[test]
+  fn test_parse_label_selector_rejects_bad_syntax() {
+    for selector in [
+      "app=nginx,",
+      "-app=nginx",
+      "app=ng inx",
+      "env in (prod",
+      "env within (prod)",
+      "env in (prod,)",
+      "bad_prefix.io/app=web",
+      "app)=web",
+    ] {
+      assert!(
+        parse_label_selector(selector).is_err(),
+        "{selector} should be rejected"
+      );
+    }
+  }
+
+  #[test]
+  fn test_label_selector_input_submits_trimmed_selector() {
+    let input = InputModal {
+      title: "Label selector".into(),
+      prompt: "Selector:".into(),
+      buffer: " app=nginx ".into(),
+      error: None,
+      action: InputAction::LabelSelector,
+    };
+    assert_eq!(
+      input.validate(),
+      Ok(InputSubmit::SetLabelSelector(Some("app=nginx".into())))
+    );
+  }
+
+  #[test]
+  fn test_parse_log_since() {
+    assert_eq!(parse_log_since("10m"), Ok(Some(Duration::from_secs(600))));
+    assert_eq!(
+      parse_log_since(" 1h 30m "),
+      Ok(Some(Duration::from_secs(5400)))
+    );
+    assert_eq!(parse_log_since(""), Ok(None));
+    assert!(parse_log_since("0s").is_err());
+    assert!(parse_log_since("ten minutes").is_err());
+    assert!(parse_log_since("10").is_err());
+  }
+
+  #[test]
+  fn test_parse_namespace_name() {
+    assert_eq!(parse_namespace_name(" test-1 "), Ok("test-1".into()));
+    assert_eq!(parse_namespace_name("a"), Ok("a".into()));
+    assert!(parse_namespace_name("").is_err());
+    assert!(parse_namespace_name("Test").is_err());
+    assert!(parse_namespace_name("team_a").is_err());
+    assert!(parse_namespace_name("team.a").is_err());
+    assert!(parse_namespace_name("-team").is_err());
+    assert!(parse_namespace_name("team-").is_err());
+    assert!(parse_namespace_name(&"a".repeat(63)).is_ok());
+    assert!(parse_namespace_name(&"a".repeat(64)).is_err());
+  }
 }
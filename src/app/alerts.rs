@@ -0,0 +1,159 @@
+//! Opt-in pod alerts (`--alerts`): on every poll, pods that start
+//! crash-looping or fail ring the terminal bell and raise a warning toast.
+use std::collections::HashSet;
+
+use anyhow::anyhow;
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::Pod;
+use kube::{api::ListParams, Api};
+
+use super::{models::KubeResource, pods::KubePod, utils};
+use crate::{network::Network, ui::glyphs::glyphs};
+
+/// `(namespace, name)` of a pod.
+type PodKey = (String, String);
+
+/// Pods seen healthy and pods already alerted on, so each pod alerts once
+/// when it breaks rather than on every poll.
+#[derive(Clone, Debug)]
+pub struct PodAlerts {
+  /// Pods created after this are watched from the start, so one that
+  /// breaks before it was ever seen healthy still alerts.
+  since: DateTime<Utc>,
+  healthy: HashSet<PodKey>,
+  alerted: HashSet<PodKey>,
+  /// Persistent warning naming the broken pods; dismissed with `Esc`.
+  pub toast: String,
+  bell: bool,
+}
+
+impl PodAlerts {
+  pub fn new(since: DateTime<Utc>) -> Self {
+    PodAlerts {
+      since,
+      healthy: HashSet::new(),
+      alerted: HashSet::new(),
+      toast: String::new(),
+      bell: false,
+    }
+  }
+
+  /// Record the latest pod list and return `namespace/name (status)` for
+  /// each pod that just broke. Pods already broken when they were first
+  /// seen are not a transition and stay quiet.
+  pub fn observe(&mut self, pods: &[KubePod]) -> Vec<String> {
+    let mut healthy = HashSet::new();
+    let mut fired = vec![];
+    for pod in pods {
+      let key = (pod.namespace.clone(), pod.name.clone());
+      if !is_broken(&pod.status) {
+        healthy.insert(key);
+        continue;
+      }
+      let created = pod.get_k8s_obj().metadata.creation_timestamp.as_ref();
+      let is_new = created.is_some_and(|time| utils::time_to_chrono(time) >= self.since);
+      if (self.healthy.contains(&key) || is_new) && self.alerted.insert(key) {
+        fired.push(format!("{}/{} ({})", pod.namespace, pod.name, pod.status));
+      }
+    }
+    self.healthy = healthy;
+
+    if !fired.is_empty() {
+      self.toast = format!("{} Pod failing: {}", glyphs().warning, fired.join(", "));
+      self.bell = true;
+    }
+    fired
+  }
+
+  /// Whether the bell is due, clearing it.
+  pub fn take_bell(&mut self) -> bool {
+    std::mem::take(&mut self.bell)
+  }
+}
+
+fn is_broken(status: &str) -> bool {
+  // Init containers report as `Init:CrashLoopBackOff` / `Init:Error`.
+  status.ends_with("CrashLoopBackOff") || status == "Error" || status.ends_with(":Error")
+}
+
+/// List the pods in scope and alert on the ones that just broke. Separate
+/// from the pods view so alerts keep working while another view is open.
+pub async fn check_pod_alerts(nw: &Network<'_>) {
+  let namespace = nw.app.lock().await.data.selected.ns.clone();
+  let api: Api<Pod> = match &namespace {
+    Some(ns) => Api::namespaced(nw.client.clone(), ns),
+    None => Api::all(nw.client.clone()),
+  };
+  let pods: Vec<KubePod> = match api.list(&ListParams::default()).await {
+    Ok(list) => list.into_iter().map(KubePod::from).collect(),
+    Err(e) => {
+      nw.handle_error(anyhow!("Failed to check pods for alerts. {}", e))
+        .await;
+      return;
+    }
+  };
+
+  let mut app = nw.app.lock().await;
+  if let Some(alerts) = app.pod_alerts.as_mut() {
+    alerts.observe(&pods);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::Duration;
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+  use super::*;
+
+  fn pod(name: &str, status: &str, created: DateTime<Utc>) -> KubePod {
+    let mut pod = KubePod::from(Pod {
+      metadata: kube::api::ObjectMeta {
+        name: Some(name.into()),
+        namespace: Some("team-a".into()),
+        creation_timestamp: Some(Time(
+          k8s_openapi::jiff::Timestamp::from_second(created.timestamp()).unwrap(),
+        )),
+        ..Default::default()
+      },
+      ..Default::default()
+    });
+    pod.status = status.into();
+    pod
+  }
+
+  #[test]
+  fn test_pod_alerts_fire_once_per_transition() {
+    let since = Utc::now();
+    let old = since - Duration::hours(1);
+    let mut alerts = PodAlerts::new(since);
+
+    // Already broken when first seen: not a transition.
+    assert!(alerts
+      .observe(&[
+        pod("stale", "CrashLoopBackOff", old),
+        pod("web", "Running", old),
+      ])
+      .is_empty());
+    assert!(!alerts.take_bell());
+
+    let fired = alerts.observe(&[
+      pod("stale", "CrashLoopBackOff", old),
+      pod("web", "CrashLoopBackOff", old),
+    ]);
+    assert_eq!(fired, vec!["team-a/web (CrashLoopBackOff)"]);
+    assert_eq!(alerts.toast, "⚠ Pod failing: team-a/web (CrashLoopBackOff)");
+    assert!(alerts.take_bell());
+    assert!(!alerts.take_bell());
+
+    // Flapping between restarts does not alert again.
+    assert!(alerts.observe(&[pod("web", "Running", old)]).is_empty());
+    assert!(alerts.observe(&[pod("web", "Error", old)]).is_empty());
+
+    // A pod created while watching alerts even if never seen healthy.
+    assert_eq!(
+      alerts.observe(&[pod("job-x", "Init:Error", since + Duration::seconds(5))]),
+      vec!["team-a/job-x (Init:Error)"]
+    );
+  }
+}
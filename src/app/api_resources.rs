@@ -0,0 +1,273 @@
+//! The API resources view, like `kubectl api-resources`: every resource the
+//! cluster serves at its preferred version. Discovery rarely changes within a
+//! session, so the list is fetched once per context and again on `F5`.
+use anyhow::anyhow;
+use async_trait::async_trait;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::APIResource;
+use kube::discovery::{ApiResource, Scope};
+use log::warn;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
+
+use super::{
+  dynamic::KubeDynamicKind,
+  key_binding::DEFAULT_KEYBINDING,
+  models::{AppResource, Named},
+  ActiveBlock, App,
+};
+use crate::{
+  draw_resource_tab,
+  network::{preferred_group_version, Network},
+  ui::utils::{
+    action_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
+    get_cluster_wide_resource_title, get_describe_active, get_resource_title, help_bold_line,
+    style_text, title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+  },
+};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeApiResource {
+  /// The plural name, e.g. `deployments`.
+  pub name: String,
+  pub short_names: Vec<String>,
+  /// `group/version`, or just the version for the core group.
+  pub api_version: String,
+  pub namespaced: bool,
+  pub kind: String,
+  pub verbs: Vec<String>,
+}
+
+impl KubeApiResource {
+  /// One entry per resource in a discovery list. Subresources such as
+  /// `pods/log` are skipped, as `kubectl api-resources` does.
+  pub fn from_list(api_version: &str, resources: Vec<APIResource>) -> Vec<Self> {
+    resources
+      .into_iter()
+      .filter(|res| !res.name.contains('/'))
+      .map(|res| KubeApiResource {
+        name: res.name,
+        short_names: res.short_names.unwrap_or_default(),
+        api_version: api_version.to_owned(),
+        namespaced: res.namespaced,
+        kind: res.kind,
+        verbs: res.verbs,
+      })
+      .collect()
+  }
+
+  pub fn is_listable(&self) -> bool {
+    self.verbs.iter().any(|verb| verb == "list")
+  }
+
+  /// The kind of this resource's objects, for listing them in the dynamic view.
+  pub fn dynamic_kind(&self) -> KubeDynamicKind {
+    let (group, version) = match self.api_version.rsplit_once('/') {
+      Some((group, version)) => (group, version),
+      None => ("", self.api_version.as_str()),
+    };
+    let scope = if self.namespaced {
+      Scope::Namespaced
+    } else {
+      Scope::Cluster
+    };
+    KubeDynamicKind::new(
+      ApiResource {
+        group: group.to_owned(),
+        version: version.to_owned(),
+        api_version: self.api_version.clone(),
+        kind: self.kind.clone(),
+        plural: self.name.clone(),
+      },
+      scope,
+    )
+  }
+}
+
+impl Named for KubeApiResource {
+  fn get_name(&self) -> &String {
+    &self.name
+  }
+}
+
+static API_RESOURCES_TITLE: &str = "API Resources";
+
+pub struct ApiResourcesResource {}
+
+#[async_trait]
+impl AppResource for ApiResourcesResource {
+  fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    draw_resource_tab!(
+      API_RESOURCES_TITLE,
+      block,
+      f,
+      app,
+      area,
+      Self::render,
+      draw_block,
+      app.data.api_resources
+    );
+  }
+
+  async fn get_resource(nw: &Network<'_>) {
+    let mut items = vec![];
+
+    let core_versions = match nw.client.list_core_api_versions().await {
+      Ok(versions) => versions.versions,
+      Err(e) => {
+        nw.handle_error(anyhow!("Failed to get API resources. {}", e))
+          .await;
+        return;
+      }
+    };
+    // The core group serves a single version (v1) in practice.
+    if let Some(version) = core_versions.first() {
+      match nw.client.list_core_api_resources(version).await {
+        Ok(list) => items.extend(KubeApiResource::from_list(version, list.resources)),
+        Err(e) => warn!("Skipping core API resources at '{}': {}", version, e),
+      }
+    }
+
+    let groups = match nw.client.list_api_groups().await {
+      Ok(groups) => groups.groups,
+      Err(e) => {
+        nw.handle_error(anyhow!("Failed to get API groups. {}", e))
+          .await;
+        return;
+      }
+    };
+    for group in groups {
+      let Some(group_version) = preferred_group_version(&group) else {
+        continue;
+      };
+      let api_version = group_version.api_version();
+      match nw.client.list_api_group_resources(&api_version).await {
+        Ok(list) => items.extend(KubeApiResource::from_list(&api_version, list.resources)),
+        Err(e) => warn!("Skipping API resources at '{}': {}", api_version, e),
+      }
+    }
+
+    let mut app = nw.app.lock().await;
+    app.data.api_resources.set_items(items);
+  }
+}
+
+pub(crate) const API_RESOURCE_COLUMNS: [ColumnDef; 6] = [
+  ColumnDef::all("Name", 22, 22, 22),
+  ColumnDef::all("ShortNames", 10, 10, 10),
+  ColumnDef::all("APIVersion", 20, 20, 20),
+  ColumnDef::all("Namespaced", 8, 8, 8),
+  ColumnDef::all("Kind", 18, 18, 18),
+  ColumnDef::all("Verbs", 22, 22, 22),
+];
+
+fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  let load = app.view_load(ActiveBlock::ApiResources);
+  let title =
+    get_cluster_wide_resource_title(API_RESOURCES_TITLE, app.data.api_resources.items.len(), "");
+
+  let columns = app.column_layout(ActiveBlock::ApiResources, ViewTier::Compact);
+
+  draw_resource_block(
+    f,
+    area,
+    ResourceTableProps {
+      title,
+      inline_help: help_bold_line(
+        format!(
+          "{} · {} ",
+          action_hint("instances", DEFAULT_KEYBINDING.submit.key),
+          action_hint("rediscover", DEFAULT_KEYBINDING.poll_now.key)
+        ),
+        app.palette,
+      ),
+      resource: &mut app.data.api_resources,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
+    },
+    |c| {
+      columns
+        .row(vec![
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.short_names.join(",")),
+          Cell::from(c.api_version.to_owned()),
+          Cell::from(c.namespaced.to_string()),
+          Cell::from(c.kind.to_owned()),
+          Cell::from(c.verbs.join(",")),
+        ])
+        .style(style_text(app.palette))
+    },
+    app.palette,
+    load,
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn api_resource(name: &str, kind: &str, namespaced: bool, verbs: &[&str]) -> APIResource {
+    APIResource {
+      name: name.into(),
+      kind: kind.into(),
+      namespaced,
+      verbs: verbs.iter().map(|v| v.to_string()).collect(),
+      ..APIResource::default()
+    }
+  }
+
+  #[test]
+  fn test_api_resources_from_discovery_list() {
+    let mut deployments = api_resource("deployments", "Deployment", true, &["get", "list"]);
+    deployments.short_names = Some(vec!["deploy".into()]);
+    let resources = KubeApiResource::from_list(
+      "apps/v1",
+      vec![
+        deployments,
+        api_resource("deployments/scale", "Scale", true, &["get", "patch"]),
+        api_resource("tokenreviews", "TokenReview", false, &["create"]),
+      ],
+    );
+
+    assert_eq!(
+      resources,
+      vec![
+        KubeApiResource {
+          name: "deployments".into(),
+          short_names: vec!["deploy".into()],
+          api_version: "apps/v1".into(),
+          namespaced: true,
+          kind: "Deployment".into(),
+          verbs: vec!["get".into(), "list".into()],
+        },
+        KubeApiResource {
+          name: "tokenreviews".into(),
+          short_names: vec![],
+          api_version: "apps/v1".into(),
+          namespaced: false,
+          kind: "TokenReview".into(),
+          verbs: vec!["create".into()],
+        },
+      ]
+    );
+    assert!(resources[0].is_listable());
+    assert!(!resources[1].is_listable());
+  }
+
+  #[test]
+  fn test_api_resource_dynamic_kind() {
+    let pods = &KubeApiResource::from_list("v1", vec![api_resource("pods", "Pod", true, &[])])[0];
+    let pod_kind = pods.dynamic_kind();
+    assert_eq!(pod_kind.api_resource.group, "");
+    assert_eq!(pod_kind.api_resource.version, "v1");
+    assert_eq!(pod_kind.scope, Scope::Namespaced);
+
+    let crd = &KubeApiResource::from_list(
+      "cert-manager.io/v1",
+      vec![api_resource("clusterissuers", "ClusterIssuer", false, &[])],
+    )[0];
+    let kind = crd.dynamic_kind();
+    assert_eq!(kind.api_resource.group, "cert-manager.io");
+    assert_eq!(kind.api_resource.api_version, "cert-manager.io/v1");
+    assert_eq!(kind.api_resource.plural, "clusterissuers");
+    assert_eq!(kind.scope, Scope::Cluster);
+  }
+}
@@ -0,0 +1,185 @@
+//! How hard KDash works the API server, counted where the network thread
+//! handles each `IoEvent`: requests per view, how many of them failed and how
+//! long they took, with the rate over the last minute. One event may make more
+//! than one API call, such as a list and its metrics, and the watches of the
+//! stream thread are not counted; the point is to spot the view that polls the
+//! most, not to bill bytes.
+use std::{
+  collections::{BTreeMap, VecDeque},
+  time::{Duration, Instant},
+};
+
+/// The window requests per second are measured over.
+pub const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug, Default)]
+struct ViewStats {
+  requests: u64,
+  errors: u64,
+  total_time: Duration,
+  /// When each request of the last [`RATE_WINDOW`] finished.
+  recent: VecDeque<Instant>,
+}
+
+/// One row of the diagnostics overlay.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApiStatsRow {
+  /// The view the requests fetch for, or the event's name when it is not a
+  /// view's, e.g. `GetPodEvents`.
+  pub label: String,
+  pub requests: u64,
+  pub errors: u64,
+  pub per_second: f64,
+  pub average: Duration,
+}
+
+impl ApiStatsRow {
+  /// The average time taken, e.g. `120 ms` or `1.5 s`.
+  pub fn average_label(&self) -> String {
+    let millis = self.average.as_millis();
+    if millis < 1000 {
+      format!("{} ms", millis)
+    } else {
+      format!("{:.1} s", self.average.as_secs_f64())
+    }
+  }
+}
+
+#[derive(Clone, Debug)]
+pub struct ApiStats {
+  since: Instant,
+  views: BTreeMap<String, ViewStats>,
+}
+
+impl Default for ApiStats {
+  fn default() -> Self {
+    Self {
+      since: Instant::now(),
+      views: BTreeMap::new(),
+    }
+  }
+}
+
+impl ApiStats {
+  /// Count a request for `label` that finished at `now` after `took`.
+  pub fn record(&mut self, label: &str, took: Duration, failed: bool, now: Instant) {
+    let stats = self.views.entry(label.to_owned()).or_default();
+    stats.requests += 1;
+    stats.errors += u64::from(failed);
+    stats.total_time += took;
+    stats.recent.push_back(now);
+    while stats
+      .recent
+      .front()
+      .is_some_and(|&at| now.saturating_duration_since(at) > RATE_WINDOW)
+    {
+      stats.recent.pop_front();
+    }
+  }
+
+  /// How long requests have been counted for.
+  pub fn elapsed(&self, now: Instant) -> Duration {
+    now.saturating_duration_since(self.since)
+  }
+
+  /// One row per label, the busiest over the last minute first, and a
+  /// `Total` row ahead of them.
+  pub fn rows(&self, now: Instant) -> Vec<ApiStatsRow> {
+    // in the first minute there is less than a window to spread requests over
+    let window = self.elapsed(now).clamp(Duration::from_secs(1), RATE_WINDOW);
+    let row = |label: &str, stats: &ViewStats| {
+      let recent = stats
+        .recent
+        .iter()
+        .filter(|&&at| at <= now && now - at <= RATE_WINDOW)
+        .count();
+      ApiStatsRow {
+        label: label.to_owned(),
+        requests: stats.requests,
+        errors: stats.errors,
+        per_second: recent as f64 / window.as_secs_f64(),
+        average: match stats.requests {
+          0 => Duration::ZERO,
+          n => stats.total_time / n as u32,
+        },
+      }
+    };
+    let mut rows: Vec<ApiStatsRow> = self
+      .views
+      .iter()
+      .map(|(label, stats)| row(label, stats))
+      .collect();
+    rows.sort_by(|a, b| {
+      b.per_second
+        .total_cmp(&a.per_second)
+        .then(b.requests.cmp(&a.requests))
+    });
+    let total = self
+      .views
+      .values()
+      .fold(ViewStats::default(), |mut total, stats| {
+        total.requests += stats.requests;
+        total.errors += stats.errors;
+        total.total_time += stats.total_time;
+        total.recent.extend(stats.recent.iter().copied());
+        total
+      });
+    rows.insert(0, row("Total", &total));
+    rows
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_rows_put_the_busiest_view_first_under_the_total() {
+    let mut stats = ApiStats::default();
+    let start = stats.since;
+    let ms = Duration::from_millis;
+    for i in 0..30 {
+      stats.record("pods", ms(100), i == 0, start + Duration::from_secs(i));
+    }
+    for i in 0..3 {
+      stats.record("nodes", ms(400), false, start + Duration::from_secs(i));
+    }
+    // an hour later only what ran in the last minute counts toward the rate
+    let later = start + Duration::from_secs(3600);
+    stats.record("nodes", ms(200), false, later);
+
+    let rows = stats.rows(later);
+    assert_eq!(
+      rows,
+      vec![
+        ApiStatsRow {
+          label: "Total".into(),
+          requests: 34,
+          errors: 1,
+          per_second: 1.0 / 60.0,
+          average: ms(4400) / 34,
+        },
+        ApiStatsRow {
+          label: "nodes".into(),
+          requests: 4,
+          errors: 0,
+          per_second: 1.0 / 60.0,
+          average: ms(350),
+        },
+        ApiStatsRow {
+          label: "pods".into(),
+          requests: 30,
+          errors: 1,
+          per_second: 0.0,
+          average: ms(100),
+        },
+      ]
+    );
+
+    // ten seconds in, the rate is over those ten seconds
+    let early = stats.rows(start + Duration::from_secs(10));
+    assert_eq!(early[1].label, "pods");
+    assert_eq!(early[1].per_second, 1.1);
+    assert_eq!(early[1].average_label(), "100 ms");
+  }
+}
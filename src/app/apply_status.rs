@@ -0,0 +1,306 @@
+//! What a manifest applied from the clipboard turns into. Once
+//! `kubectl apply` succeeds, an overlay lists each applied object as ready or
+//! not, judged as its own view would, and the stream thread watches the
+//! objects until all of them are ready or [`APPLY_WATCH_TIMEOUT`] passes.
+//! Closing the overlay ends the watches. Kinds KDash has no view of, such as
+//! custom resources, are listed as applied without being followed.
+use std::time::Duration;
+
+use k8s_openapi::api::apps::v1::Deployment;
+use serde_json::Value;
+
+use super::{
+  columns,
+  deployments::{KubeDeployment, RolloutState},
+  dynamic::api_resource_for_block,
+  manifest::ManifestObject,
+  watchlist::{live_status, PinStatus},
+  ActiveBlock, App,
+};
+use crate::network::stream::IoStreamEvent;
+
+/// How long applied objects are watched before giving up on them.
+pub const APPLY_WATCH_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// An applied object and what its watch last found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppliedObject {
+  pub object: ManifestObject,
+  /// The view it is read like, or `None` when it is not followed.
+  pub block: Option<ActiveBlock>,
+  pub status: PinStatus,
+}
+
+impl AppliedObject {
+  pub fn is_ready(&self) -> bool {
+    matches!(self.status, PinStatus::Live { healthy: true, .. })
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApplyProgress {
+  Watching,
+  AllReady,
+  TimedOut,
+  /// Every watch ended, some on errors, without all objects being ready.
+  Stopped,
+}
+
+/// The apply status overlay.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApplyStatus {
+  /// Tells the watches of this apply from those of an earlier one.
+  pub id: u64,
+  pub objects: Vec<AppliedObject>,
+  pub progress: ApplyProgress,
+}
+
+impl ApplyStatus {
+  pub fn ready_count(&self) -> usize {
+    self
+      .objects
+      .iter()
+      .filter(|object| object.is_ready())
+      .count()
+  }
+}
+
+/// The view whose kind is `kind`, e.g. Deployments for `Deployment`.
+pub fn block_for_kind(kind: &str) -> Option<ActiveBlock> {
+  columns::view_names()
+    .filter_map(columns::view_block)
+    .find(|&block| {
+      api_resource_for_block(block, None).is_some_and(|(resource, _)| resource.kind == kind)
+    })
+}
+
+/// The status of an applied object. A deployment is ready once its rollout
+/// completes, not merely when no replica is unavailable, which a deployment
+/// the controller has not seen yet also is.
+pub(crate) fn applied_status(block: ActiveBlock, object: Value) -> PinStatus {
+  if block != ActiveBlock::Deployments {
+    return live_status(block, object);
+  }
+  match serde_json::from_value::<Deployment>(object) {
+    Ok(deployment) => {
+      let rollout = KubeDeployment::from(deployment).rollout();
+      PinStatus::Live {
+        status: rollout.message,
+        healthy: rollout.state == RolloutState::Complete,
+      }
+    }
+    Err(e) => PinStatus::Failed(format!("Unreadable object: {}", e)),
+  }
+}
+
+impl App {
+  /// Open the apply status of freshly applied `objects` in place of the
+  /// apply's output, and start watching them.
+  pub async fn watch_applied(&mut self, objects: Vec<ManifestObject>) {
+    self.close_cmd_output();
+    let id = self.stop_apply_watch();
+    self.apply_status = Some(ApplyStatus {
+      id,
+      objects: objects
+        .iter()
+        .map(|object| {
+          let block = block_for_kind(&object.kind);
+          AppliedObject {
+            object: object.clone(),
+            block,
+            status: match block {
+              Some(_) => PinStatus::Loading,
+              None => PinStatus::Live {
+                status: "Applied, not followed".into(),
+                healthy: true,
+              },
+            },
+          }
+        })
+        .collect(),
+      progress: ApplyProgress::Watching,
+    });
+    if objects
+      .iter()
+      .all(|object| block_for_kind(&object.kind).is_none())
+    {
+      self.end_apply_watch(id, false);
+      return;
+    }
+    self
+      .dispatch_stream(IoStreamEvent::WatchApplied { id, objects })
+      .await;
+  }
+
+  /// Read the object at `index` of apply `id` as fetched, and return whether
+  /// it is ready, after which it needs no more watching.
+  pub fn update_applied_object(&mut self, id: u64, index: usize, object: Value) -> bool {
+    let Some(applied) = self.applied_object(id, index) else {
+      return true;
+    };
+    let Some(block) = applied.block else {
+      return true;
+    };
+    applied.status = applied_status(block, object);
+    applied.is_ready()
+  }
+
+  /// Record a status that is not read from the object, such as it being gone.
+  pub fn set_applied_status(&mut self, id: u64, index: usize, status: PinStatus) {
+    if let Some(applied) = self.applied_object(id, index) {
+      applied.status = status;
+    }
+  }
+
+  fn applied_object(&mut self, id: u64, index: usize) -> Option<&mut AppliedObject> {
+    self
+      .apply_status
+      .as_mut()
+      .filter(|status| status.id == id)?
+      .objects
+      .get_mut(index)
+  }
+
+  /// The watches of apply `id` are over, all of them or by the timeout.
+  pub fn end_apply_watch(&mut self, id: u64, timed_out: bool) {
+    let Some(status) = self.apply_status.as_mut().filter(|status| status.id == id) else {
+      return;
+    };
+    let (ready, total) = (status.ready_count(), status.objects.len());
+    status.progress = if ready == total {
+      ApplyProgress::AllReady
+    } else if timed_out {
+      ApplyProgress::TimedOut
+    } else {
+      ApplyProgress::Stopped
+    };
+    self.stop_apply_watch();
+    self.set_status_message(if ready == total {
+      format!("All {} applied objects are ready", total)
+    } else {
+      format!("{} of {} applied objects are ready", ready, total)
+    });
+  }
+
+  pub fn close_apply_status(&mut self) {
+    self.apply_status = None;
+    self.stop_apply_watch();
+  }
+
+  /// Follows which apply is watched; a watch whose id is no longer current
+  /// ends.
+  pub fn apply_watch_rx(&self) -> tokio::sync::watch::Receiver<u64> {
+    self.apply_watch_tx.subscribe()
+  }
+
+  /// End the current watches, and return the id for the next ones.
+  fn stop_apply_watch(&self) -> u64 {
+    let id = *self.apply_watch_tx.borrow() + 1;
+    self.apply_watch_tx.send_replace(id);
+    id
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+  use tokio::sync::mpsc;
+
+  use super::*;
+
+  fn object(kind: &str, name: &str) -> ManifestObject {
+    ManifestObject {
+      kind: kind.into(),
+      name: name.into(),
+      namespace: Some("shop".into()),
+    }
+  }
+
+  #[test]
+  fn test_block_for_kind_finds_the_view_of_a_kind() {
+    assert_eq!(block_for_kind("Deployment"), Some(ActiveBlock::Deployments));
+    assert_eq!(block_for_kind("Node"), Some(ActiveBlock::Nodes));
+    assert_eq!(block_for_kind("Certificate"), None);
+  }
+
+  #[tokio::test]
+  async fn test_watch_applied_checks_off_objects_as_they_become_ready() {
+    let (stream_tx, mut stream_rx) = mpsc::channel::<IoStreamEvent>(10);
+    let mut app = App {
+      io_stream_tx: Some(stream_tx),
+      ..App::default()
+    };
+    app.open_cmd_output("kubectl apply -f -".into());
+    let objects = vec![
+      object("Deployment", "web"),
+      object("ConfigMap", "settings"),
+      object("Certificate", "web-tls"),
+    ];
+    app.watch_applied(objects.clone()).await;
+    let status = app.apply_status.clone().unwrap();
+    assert_eq!(app.cmd_output, None);
+    assert_eq!(
+      stream_rx.recv().await.unwrap(),
+      IoStreamEvent::WatchApplied {
+        id: status.id,
+        objects,
+      }
+    );
+    assert_eq!(status.objects[0].status, PinStatus::Loading);
+    // kinds without a view are not followed
+    assert!(status.objects[2].is_ready());
+    let mut watching = app.apply_watch_rx();
+    assert_eq!(*watching.borrow_and_update(), status.id);
+
+    let deployment = |ready: i64| {
+      json!({
+        "apiVersion": "apps/v1",
+        "kind": "Deployment",
+        "metadata": { "name": "web", "namespace": "shop", "generation": 1 },
+        "spec": { "replicas": 2 },
+        "status": {
+          "observedGeneration": 1,
+          "replicas": 2,
+          "updatedReplicas": 2,
+          "availableReplicas": ready
+        }
+      })
+    };
+    assert!(!app.update_applied_object(status.id, 0, deployment(1)));
+    assert!(app.update_applied_object(status.id, 0, deployment(2)));
+    let config_map =
+      json!({ "apiVersion": "v1", "kind": "ConfigMap", "metadata": { "name": "settings" } });
+    assert!(app.update_applied_object(status.id, 1, config_map));
+    // a watch of an earlier apply changes nothing
+    app.set_applied_status(status.id - 1, 0, PinStatus::Gone);
+    assert_eq!(app.apply_status.as_ref().unwrap().ready_count(), 3);
+
+    app.end_apply_watch(status.id, false);
+    assert_eq!(
+      app.apply_status.as_ref().unwrap().progress,
+      ApplyProgress::AllReady
+    );
+    assert_eq!(app.status_message.text(), "All 3 applied objects are ready");
+    assert!(watching.has_changed().unwrap());
+  }
+
+  #[tokio::test]
+  async fn test_apply_watch_times_out_with_objects_not_ready() {
+    let mut app = App::default();
+    app.watch_applied(vec![object("Deployment", "web")]).await;
+    let id = app.apply_status.as_ref().unwrap().id;
+    app.end_apply_watch(id, true);
+    assert_eq!(
+      app.apply_status.as_ref().unwrap().progress,
+      ApplyProgress::TimedOut
+    );
+    assert_eq!(
+      app.status_message.text(),
+      "0 of 1 applied objects are ready"
+    );
+
+    app.close_apply_status();
+    assert_eq!(app.apply_status, None);
+    assert_ne!(*app.apply_watch_rx().borrow(), id);
+  }
+}
@@ -0,0 +1,321 @@
+//! Audit trail of the mutating actions taken from KDash: deletes, scales,
+//! restarts, applies and the like. Entries are kept in memory for the audit
+//! overlay and written to the debug log under [`AUDIT_LOG_TARGET`].
+use log::info;
+
+use super::{utils, App};
+use crate::{
+  cmd::IoCmdEvent,
+  network::{IoEvent, ResourcePatch},
+};
+
+/// `log` target of audit lines, so they can be picked out of the debug log.
+pub const AUDIT_LOG_TARGET: &str = "kdash::audit";
+pub const MAX_AUDIT_LOG: usize = 200;
+
+/// What a mutating request does and what it does it to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditAction {
+  /// `delete`, `scale to 3`, `rollout restart`, …
+  pub action: String,
+  /// The kind and `namespace/name` of the target, e.g. `deployment shop/web`.
+  pub target: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditEntry {
+  pub timestamp: String,
+  /// The kube context the action ran against.
+  pub context: String,
+  pub action: String,
+  pub target: String,
+  /// Whether the request went through without reporting an error.
+  pub succeeded: bool,
+}
+
+impl AuditEntry {
+  /// `2024-05-01 10:00:00 [prod] delete pod shop/web-1 (ok)`
+  pub fn summary(&self) -> String {
+    format!(
+      "{} [{}] {} {} ({})",
+      self.timestamp,
+      self.context,
+      self.action,
+      self.target,
+      if self.succeeded { "ok" } else { "failed" }
+    )
+  }
+}
+
+fn target(kind: &str, namespace: Option<&str>, name: &str) -> String {
+  match namespace {
+    Some(ns) => format!("{} {}/{}", kind, ns, name),
+    None => format!("{} {}", kind, name),
+  }
+}
+
+fn action(action: impl Into<String>, target: String) -> Option<AuditAction> {
+  Some(AuditAction {
+    action: action.into(),
+    target,
+  })
+}
+
+impl IoEvent {
+  /// The audit record of a request that changes the cluster; `None` for reads.
+  pub fn audit_action(&self, app: &App) -> Option<AuditAction> {
+    match self {
+      IoEvent::DeleteResource {
+        block,
+        name,
+        namespace,
+      } => action(
+        "delete",
+        target(&app.resource_kind_label(*block), namespace.as_deref(), name),
+      ),
+      IoEvent::DeleteResources { block, rows } => {
+        let names: Vec<String> = rows
+          .iter()
+          .map(|row| match &row.namespace {
+            Some(ns) => format!("{}/{}", ns, row.name),
+            None => row.name.clone(),
+          })
+          .collect();
+        action(
+          "delete",
+          format!("{} {}", app.resource_kind_label(*block), names.join(", ")),
+        )
+      }
+      IoEvent::RestartPods { rows } => {
+        let names: Vec<String> = rows
+          .iter()
+          .map(|row| {
+            format!(
+              "{}/{}",
+              row.namespace.as_deref().unwrap_or_default(),
+              row.name
+            )
+          })
+          .collect();
+        action("restart crash-looping", format!("pod {}", names.join(", ")))
+      }
+      IoEvent::CreateNamespace { name } => action("create", target("namespace", None, name)),
+      IoEvent::DeleteNamespace { name } => action("delete", target("namespace", None, name)),
+      IoEvent::PatchResource {
+        block,
+        name,
+        namespace,
+        patch,
+      } => {
+        let verb = match patch {
+          ResourcePatch::RolloutRestart => "rollout restart".to_owned(),
+          ResourcePatch::SetUnschedulable(true) => "cordon".to_owned(),
+          ResourcePatch::SetUnschedulable(false) => "uncordon".to_owned(),
+          ResourcePatch::SetSuspend(true) => "suspend".to_owned(),
+          ResourcePatch::SetSuspend(false) => "resume".to_owned(),
+          ResourcePatch::SetReplicas(replicas) => format!("scale to {}", replicas),
+        };
+        action(
+          verb,
+          target(&app.resource_kind_label(*block), namespace.as_deref(), name),
+        )
+      }
+      IoEvent::TriggerCronJob { name, namespace } => {
+        action("trigger", target("cronjob", Some(namespace), name))
+      }
+      IoEvent::EvictPod { name, namespace } => {
+        action("evict", target("pod", Some(namespace), name))
+      }
+      // the steps of a stopped macro are skipped, so not audited
+      IoEvent::MacroStep { name, event, .. } if app.macro_running(name) => event.audit_action(app),
+      _ => None,
+    }
+  }
+}
+
+impl IoCmdEvent {
+  /// The audit record of a `kubectl` command that changes the cluster.
+  pub fn audit_action(&self) -> Option<AuditAction> {
+    match self {
+      IoCmdEvent::ApplyEdit { kind, name, .. } => action("apply", target(kind, None, name)),
+      IoCmdEvent::ApplyManifest { objects, .. } => action(
+        "apply",
+        objects
+          .iter()
+          .map(ToString::to_string)
+          .collect::<Vec<_>>()
+          .join(", "),
+      ),
+      IoCmdEvent::RolloutUndo {
+        name,
+        namespace,
+        revision,
+      } => action(
+        match revision {
+          Some(revision) => format!("rollout undo to revision {}", revision),
+          None => "rollout undo".to_owned(),
+        },
+        target("deployment", Some(namespace), name),
+      ),
+      _ => None,
+    }
+  }
+}
+
+impl App {
+  /// Record a finished mutating request in the audit overlay and the log.
+  pub fn record_audit(&mut self, audit: AuditAction, succeeded: bool) {
+    let entry = AuditEntry {
+      timestamp: utils::format_now(),
+      context: self
+        .data
+        .active_context
+        .as_ref()
+        .map_or_else(|| "-".to_owned(), |ctx| ctx.name.clone()),
+      action: audit.action,
+      target: audit.target,
+      succeeded,
+    };
+    info!(target: AUDIT_LOG_TARGET, "{}", entry.summary());
+    self.audit_log.push_back(entry);
+    while self.audit_log.len() > MAX_AUDIT_LOG {
+      self.audit_log.pop_front();
+    }
+  }
+
+  /// Open the audit overlay on the latest entry, or report that it is empty.
+  pub fn open_audit_log(&mut self) {
+    if self.audit_log.is_empty() {
+      self.set_status_message("No actions recorded in this session yet");
+      return;
+    }
+    self.show_audit_log = true;
+    self.audit_log_state.select(Some(self.audit_log.len() - 1));
+  }
+
+  pub fn close_audit_log(&mut self) {
+    self.show_audit_log = false;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::app::{contexts::KubeContext, models::MarkedRow, ActiveBlock};
+
+  fn app_in_context(name: &str) -> App {
+    let mut app = App::default();
+    app.data.active_context = Some(KubeContext {
+      name: name.into(),
+      ..KubeContext::default()
+    });
+    app
+  }
+
+  #[test]
+  fn test_audit_action_describes_mutating_events() {
+    let app = App::default();
+    let scale = IoEvent::PatchResource {
+      block: ActiveBlock::Deployments,
+      name: "web".into(),
+      namespace: Some("shop".into()),
+      patch: ResourcePatch::SetReplicas(3),
+    };
+    assert_eq!(
+      scale.audit_action(&app),
+      Some(AuditAction {
+        action: "scale to 3".into(),
+        target: "deployment shop/web".into(),
+      })
+    );
+    let cordon = IoEvent::PatchResource {
+      block: ActiveBlock::Nodes,
+      name: "node-a".into(),
+      namespace: None,
+      patch: ResourcePatch::SetUnschedulable(true),
+    };
+    assert_eq!(cordon.audit_action(&app).unwrap().action, "cordon");
+    assert_eq!(cordon.audit_action(&app).unwrap().target, "node node-a");
+    let batch = IoEvent::DeleteResources {
+      block: ActiveBlock::Pods,
+      rows: vec![
+        MarkedRow {
+          name: "a".into(),
+          namespace: Some("shop".into()),
+        },
+        MarkedRow {
+          name: "b".into(),
+          namespace: Some("shop".into()),
+        },
+      ],
+    };
+    assert_eq!(
+      batch.audit_action(&app).unwrap().target,
+      "pod shop/a, shop/b"
+    );
+    let undo = IoCmdEvent::RolloutUndo {
+      name: "web".into(),
+      namespace: "shop".into(),
+      revision: Some(2),
+    };
+    assert_eq!(
+      undo.audit_action().unwrap().action,
+      "rollout undo to revision 2"
+    );
+    // reads are not audited
+    assert_eq!(IoEvent::GetPods.audit_action(&app), None);
+    assert_eq!(IoCmdEvent::GetCliInfo.audit_action(), None);
+  }
+
+  #[test]
+  fn test_record_audit_keeps_context_outcome_and_bound() {
+    let mut app = app_in_context("prod");
+    app.record_audit(
+      AuditAction {
+        action: "delete".into(),
+        target: "pod shop/web-1".into(),
+      },
+      false,
+    );
+    let entry = app.audit_log.back().unwrap();
+    assert_eq!(entry.context, "prod");
+    assert!(!entry.succeeded);
+    assert!(entry
+      .summary()
+      .ends_with("[prod] delete pod shop/web-1 (failed)"));
+
+    for i in 0..MAX_AUDIT_LOG {
+      app.record_audit(
+        AuditAction {
+          action: "evict".into(),
+          target: format!("pod shop/{}", i),
+        },
+        true,
+      );
+    }
+    assert_eq!(app.audit_log.len(), MAX_AUDIT_LOG);
+    assert_eq!(app.audit_log.front().unwrap().target, "pod shop/0");
+  }
+
+  #[test]
+  fn test_open_audit_log_selects_latest_entry() {
+    let mut app = App::default();
+    app.open_audit_log();
+    assert!(!app.show_audit_log);
+
+    for target in ["pod a", "pod b"] {
+      app.record_audit(
+        AuditAction {
+          action: "delete".into(),
+          target: target.into(),
+        },
+        true,
+      );
+    }
+    app.open_audit_log();
+    assert!(app.show_audit_log);
+    assert_eq!(app.audit_log_state.selected(), Some(1));
+    app.close_audit_log();
+    assert!(!app.show_audit_log);
+  }
+}
@@ -0,0 +1,201 @@
+//! Output of the kubectl commands behind actions such as apply and rollback,
+//! streamed into an overlay line by line as the command prints it and closed
+//! by an exit-status line, so a shell-based action is never a black box.
+use super::App;
+
+/// Lines kept at most; the oldest are dropped past this.
+const MAX_CMD_OUTPUT_LINES: usize = 2_000;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CmdOutputLine {
+  pub text: String,
+  /// Printed on stderr rather than stdout.
+  pub stderr: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CmdOutput {
+  /// The command line, e.g. `kubectl rollout undo deployment/web -n shop`.
+  pub command: String,
+  pub lines: Vec<CmdOutputLine>,
+  /// Whether the command succeeded and its exit code, once it has exited.
+  pub exit: Option<(bool, Option<i32>)>,
+  /// First line shown, or `None` to stay on the newest lines.
+  pub offset: Option<usize>,
+  /// Rows of output the overlay showed last.
+  pub height: usize,
+}
+
+impl CmdOutput {
+  /// `exit 0`, `exit 1 (failed)` or `running…`.
+  pub fn status_line(&self) -> String {
+    match self.exit {
+      None => "running…".into(),
+      Some((true, code)) => format!("exit {}", code.unwrap_or_default()),
+      Some((false, Some(code))) => format!("exit {} (failed)", code),
+      Some((false, None)) => "failed to run".into(),
+    }
+  }
+
+  /// First line to show.
+  pub fn first_visible(&self) -> usize {
+    let last_page = self.lines.len().saturating_sub(self.height);
+    self
+      .offset
+      .map_or(last_page, |offset| offset.min(last_page))
+  }
+
+  /// Scroll by `delta` lines, leaving the bottom unpinned. Scrolling back to
+  /// the end follows new lines again.
+  pub fn scroll(&mut self, delta: isize) {
+    let last_page = self.lines.len().saturating_sub(self.height);
+    let offset = self
+      .first_visible()
+      .saturating_add_signed(delta)
+      .min(last_page);
+    self.offset = (offset < last_page).then_some(offset);
+  }
+}
+
+impl App {
+  /// Open the output overlay for a command about to run.
+  pub fn open_cmd_output(&mut self, command: String) {
+    self.cmd_output = Some(CmdOutput {
+      command,
+      lines: vec![],
+      exit: None,
+      offset: None,
+      height: 0,
+    });
+  }
+
+  /// Run another command in the open overlay below the last one's output, as
+  /// the apply after its dry-run. Both commands and the first exit status
+  /// stay in the scrollback.
+  pub fn continue_cmd_output(&mut self, command: String) {
+    let Some(output) = self.cmd_output.as_mut() else {
+      self.open_cmd_output(command);
+      return;
+    };
+    let header = CmdOutputLine {
+      text: format!("$ {}", output.command),
+      stderr: false,
+    };
+    if !output.lines.contains(&header) {
+      output.lines.insert(0, header);
+    }
+    let previous = output.status_line();
+    output.command = command.clone();
+    output.exit = None;
+    output.offset = None;
+    self.push_cmd_output_line(previous, false);
+    self.push_cmd_output_line(String::new(), false);
+    self.push_cmd_output_line(format!("$ {}", command), false);
+  }
+
+  pub fn push_cmd_output_line(&mut self, text: String, stderr: bool) {
+    if let Some(output) = self.cmd_output.as_mut() {
+      output.lines.push(CmdOutputLine { text, stderr });
+      if output.lines.len() > MAX_CMD_OUTPUT_LINES {
+        output.lines.remove(0);
+      }
+    }
+  }
+
+  pub fn finish_cmd_output(&mut self, success: bool, code: Option<i32>) {
+    if let Some(output) = self.cmd_output.as_mut() {
+      output.exit = Some((success, code));
+    }
+  }
+
+  pub fn close_cmd_output(&mut self) {
+    self.cmd_output = None;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_cmd_output_collects_lines_and_exit_status() {
+    let mut app = App::default();
+    app.push_cmd_output_line("dropped".into(), false);
+    assert_eq!(
+      app.cmd_output, None,
+      "nothing is shown before a command opens it"
+    );
+
+    app.open_cmd_output("kubectl apply -f web.yaml".into());
+    assert_eq!(app.cmd_output.as_ref().unwrap().status_line(), "running…");
+    app.push_cmd_output_line("deployment.apps/web configured".into(), false);
+    app.push_cmd_output_line("Warning: deprecated".into(), true);
+    app.finish_cmd_output(false, Some(1));
+
+    let output = app.cmd_output.clone().unwrap();
+    assert_eq!(output.lines.len(), 2);
+    assert!(output.lines[1].stderr);
+    assert_eq!(output.status_line(), "exit 1 (failed)");
+
+    app.close_cmd_output();
+    assert_eq!(app.cmd_output, None);
+  }
+
+  #[test]
+  fn test_cmd_output_continues_with_a_second_command() {
+    let mut app = App::default();
+    app.open_cmd_output("kubectl apply --dry-run=server -f web.yaml".into());
+    app.push_cmd_output_line(
+      "deployment.apps/web configured (server dry run)".into(),
+      false,
+    );
+    app.finish_cmd_output(true, Some(0));
+    app.continue_cmd_output("kubectl apply -f web.yaml".into());
+    app.push_cmd_output_line("deployment.apps/web configured".into(), false);
+
+    let output = app.cmd_output.clone().unwrap();
+    assert_eq!(output.command, "kubectl apply -f web.yaml");
+    assert_eq!(output.status_line(), "running…");
+    let texts: Vec<_> = output.lines.iter().map(|line| line.text.as_str()).collect();
+    assert_eq!(
+      texts,
+      vec![
+        "$ kubectl apply --dry-run=server -f web.yaml",
+        "deployment.apps/web configured (server dry run)",
+        "exit 0",
+        "",
+        "$ kubectl apply -f web.yaml",
+        "deployment.apps/web configured",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_cmd_output_follows_the_end_until_scrolled_back() {
+    let mut output = CmdOutput {
+      command: "kubectl".into(),
+      lines: (0..10)
+        .map(|i| CmdOutputLine {
+          text: i.to_string(),
+          stderr: false,
+        })
+        .collect(),
+      exit: None,
+      offset: None,
+      height: 4,
+    };
+    assert_eq!(output.first_visible(), 6);
+
+    output.scroll(-2);
+    assert_eq!(output.offset, Some(4));
+    output.lines.push(CmdOutputLine {
+      text: "10".into(),
+      stderr: false,
+    });
+    assert_eq!(output.first_visible(), 4, "scrolled back stays put");
+
+    output.scroll(10);
+    assert_eq!(output.offset, None);
+    assert_eq!(output.first_visible(), 7);
+  }
+}
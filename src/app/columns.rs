@@ -0,0 +1,288 @@
+//! Resource views whose columns can be chosen and ordered with `columns` in
+//! the config file, e.g. `columns: { pods: [Name, Status, Node, Age] }`.
+use log::warn;
+
+use super::{
+  api_resources, configmaps, crds, cronjobs, daemonsets, deployments, events, ingress, jobs,
+  limit_ranges, network_policies, node_pods, nodes, pdbs, pods, pvcs, pvs, replicasets,
+  replication_controllers, resource_quotas, roles, secrets, serviceaccounts, statefulsets,
+  storageclass, svcs, view_slug, ActiveBlock,
+};
+use crate::{config::KdashConfig, ui::utils::ColumnDef};
+
+/// Each view's name in the config file and its column definitions.
+const COLUMN_VIEWS: [(ActiveBlock, &str, &[ColumnDef]); 31] = [
+  (ActiveBlock::Pods, "pods", &pods::POD_COLUMNS),
+  (
+    ActiveBlock::Containers,
+    "containers",
+    &pods::CONTAINER_COLUMNS,
+  ),
+  (
+    ActiveBlock::PodsByNode,
+    "node-pods",
+    &node_pods::NODE_PODS_COLUMNS,
+  ),
+  (ActiveBlock::Nodes, "nodes", &nodes::NODE_COLUMNS),
+  (ActiveBlock::Services, "services", &svcs::SVC_COLUMNS),
+  (
+    ActiveBlock::ConfigMaps,
+    "configmaps",
+    &configmaps::CM_COLUMNS,
+  ),
+  (
+    ActiveBlock::ConfigMapData,
+    "configmap-data",
+    &configmaps::CM_DATA_COLUMNS,
+  ),
+  (
+    ActiveBlock::StatefulSets,
+    "statefulsets",
+    &statefulsets::STFS_COLUMNS,
+  ),
+  (
+    ActiveBlock::ReplicaSets,
+    "replicasets",
+    &replicasets::RS_COLUMNS,
+  ),
+  (
+    ActiveBlock::Deployments,
+    "deployments",
+    &deployments::DEPLOY_COLUMNS,
+  ),
+  (ActiveBlock::Jobs, "jobs", &jobs::JOB_COLUMNS),
+  (
+    ActiveBlock::DaemonSets,
+    "daemonsets",
+    &daemonsets::DS_COLUMNS,
+  ),
+  (ActiveBlock::CronJobs, "cronjobs", &cronjobs::CRON_COLUMNS),
+  (ActiveBlock::Secrets, "secrets", &secrets::SECRET_COLUMNS),
+  (
+    ActiveBlock::ReplicationControllers,
+    "replicationcontrollers",
+    &replication_controllers::RC_COLUMNS,
+  ),
+  (
+    ActiveBlock::StorageClasses,
+    "storageclasses",
+    &storageclass::SC_COLUMNS,
+  ),
+  (ActiveBlock::Roles, "roles", &roles::ROLE_COLUMNS),
+  (
+    ActiveBlock::RoleBindings,
+    "rolebindings",
+    &roles::RB_COLUMNS,
+  ),
+  (
+    ActiveBlock::ClusterRoles,
+    "clusterroles",
+    &roles::CR_COLUMNS,
+  ),
+  (
+    ActiveBlock::ClusterRoleBindings,
+    "clusterrolebindings",
+    &roles::CRB_COLUMNS,
+  ),
+  (
+    ActiveBlock::Ingresses,
+    "ingresses",
+    &ingress::INGRESS_COLUMNS,
+  ),
+  (
+    ActiveBlock::PersistentVolumeClaims,
+    "persistentvolumeclaims",
+    &pvcs::PVC_COLUMNS,
+  ),
+  (
+    ActiveBlock::PersistentVolumes,
+    "persistentvolumes",
+    &pvs::PV_COLUMNS,
+  ),
+  (
+    ActiveBlock::ServiceAccounts,
+    "serviceaccounts",
+    &serviceaccounts::SA_COLUMNS,
+  ),
+  (
+    ActiveBlock::NetworkPolicies,
+    "networkpolicies",
+    &network_policies::NP_COLUMNS,
+  ),
+  (ActiveBlock::Events, "events", &events::EVENT_COLUMNS),
+  (
+    ActiveBlock::ResourceQuotas,
+    "resourcequotas",
+    &resource_quotas::RQ_COLUMNS,
+  ),
+  (
+    ActiveBlock::LimitRanges,
+    "limitranges",
+    &limit_ranges::LR_COLUMNS,
+  ),
+  (
+    ActiveBlock::PodDisruptionBudgets,
+    "poddisruptionbudgets",
+    &pdbs::PDB_COLUMNS,
+  ),
+  (
+    ActiveBlock::CustomResourceDefinitions,
+    "customresourcedefinitions",
+    &crds::CRD_COLUMNS,
+  ),
+  (
+    ActiveBlock::ApiResources,
+    "api-resources",
+    &api_resources::API_RESOURCE_COLUMNS,
+  ),
+];
+
+/// The config name of a view, for views with configurable columns.
+pub fn view_name(block: ActiveBlock) -> Option<&'static str> {
+  COLUMN_VIEWS
+    .iter()
+    .find(|(view, _, _)| *view == block)
+    .map(|(_, name, _)| *name)
+}
+
+/// The config name and column definitions of a view.
+pub fn view_columns(block: ActiveBlock) -> (&'static str, &'static [ColumnDef]) {
+  COLUMN_VIEWS
+    .iter()
+    .find(|(view, _, _)| *view == block)
+    .map(|(_, name, columns)| (*name, *columns))
+    .unwrap_or_else(|| panic!("no column definitions for {:?}", block))
+}
+
+/// The view with this config-file name, e.g. `pods`.
+pub fn view_block(view: &str) -> Option<ActiveBlock> {
+  COLUMN_VIEWS
+    .iter()
+    .find(|(_, name, _)| *name == view)
+    .map(|(block, _, _)| *block)
+}
+
+/// Every view's config-file name, for listing the valid ones in warnings.
+pub fn view_names() -> impl Iterator<Item = &'static str> {
+  COLUMN_VIEWS.iter().map(|(_, name, _)| *name)
+}
+
+/// The `columns` entry for a view, matched like `--view` names.
+pub fn configured_columns<'a>(config: &'a KdashConfig, view: &str) -> Option<&'a [String]> {
+  config
+    .columns
+    .as_ref()?
+    .iter()
+    .find(|(name, _)| view_slug(name) == view)
+    .map(|(_, columns)| columns.as_slice())
+}
+
+/// One warning per unknown view or column name under `columns`. Unknown
+/// names are skipped when drawing, so a typo never breaks a view.
+pub fn column_config_warnings(config: &KdashConfig) -> Vec<String> {
+  let mut warnings = vec![];
+  for (view, names) in config.columns.iter().flatten() {
+    let Some((_, name, columns)) = COLUMN_VIEWS
+      .iter()
+      .find(|(_, name, _)| *name == view_slug(view))
+    else {
+      warnings.push(format!(
+        "Unknown view in columns config: {}. Valid views: {}",
+        view,
+        COLUMN_VIEWS.map(|(_, name, _)| name).join(", ")
+      ));
+      continue;
+    };
+    for column in names {
+      if !columns
+        .iter()
+        .any(|col| col.label.eq_ignore_ascii_case(column.trim()))
+      {
+        warnings.push(format!(
+          "Unknown column for {}: {}. Valid columns: {}",
+          name,
+          column,
+          columns
+            .iter()
+            .map(|col| col.label)
+            .collect::<Vec<_>>()
+            .join(", ")
+        ));
+      }
+    }
+  }
+
+  for warning in &warnings {
+    warn!("{}", warning);
+  }
+
+  warnings
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use super::*;
+
+  fn config_with(columns: &[(&str, &[&str])]) -> KdashConfig {
+    KdashConfig {
+      columns: Some(
+        columns
+          .iter()
+          .map(|(view, names)| {
+            (
+              view.to_string(),
+              names.iter().map(|n| n.to_string()).collect(),
+            )
+          })
+          .collect::<BTreeMap<_, _>>(),
+      ),
+      ..KdashConfig::default()
+    }
+  }
+
+  #[test]
+  fn test_every_view_is_listed_once_under_its_slug() {
+    for (i, (block, name, columns)) in COLUMN_VIEWS.iter().enumerate() {
+      assert_eq!(view_slug(name), *name);
+      assert!(
+        COLUMN_VIEWS[i + 1..]
+          .iter()
+          .all(|(other, other_name, _)| other != block && other_name != name),
+        "{} is listed twice",
+        name
+      );
+      assert!(!columns.is_empty());
+    }
+  }
+
+  #[test]
+  fn test_configured_columns_matches_view_names_loosely() {
+    let config = config_with(&[("Pods", &["Name", "Age"])]);
+
+    assert_eq!(
+      configured_columns(&config, "pods"),
+      Some(&["Name".to_string(), "Age".to_string()][..])
+    );
+    assert_eq!(configured_columns(&config, "nodes"), None);
+    assert_eq!(configured_columns(&KdashConfig::default(), "pods"), None);
+  }
+
+  #[test]
+  fn test_column_config_warnings_name_unknown_views_and_columns() {
+    let config = config_with(&[
+      ("pods", &["name", "Status", "Colour"]),
+      ("widgets", &["Name"]),
+    ]);
+
+    let warnings = column_config_warnings(&config);
+
+    assert_eq!(warnings.len(), 2);
+    assert!(
+      warnings[0].starts_with("Unknown column for pods: Colour. Valid columns: Namespace, Name,")
+    );
+    assert!(warnings[1]
+      .starts_with("Unknown view in columns config: widgets. Valid views: pods, containers,"));
+  }
+}
@@ -3,13 +3,17 @@ use std::collections::BTreeMap;
 use async_trait::async_trait;
 use chrono::Utc;
 use k8s_openapi::api::core::v1::ConfigMap;
+use kube::api::ObjectMeta;
 use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
+  layout::{Constraint, Rect},
+  text::Line,
+  widgets::{Cell, Paragraph},
   Frame,
 };
 
 use super::{
+  consumers::{draw_consumers_describe, ConsumedKind},
+  key_binding::DEFAULT_KEYBINDING,
   models::{AppResource, KubeResource, Named},
   utils, ActiveBlock, App,
 };
@@ -17,9 +21,10 @@ use crate::{
   draw_resource_tab,
   network::Network,
   ui::utils::{
-    describe_and_yaml_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, responsive_columns, style_text,
-    title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+    action_hint, describe_and_yaml_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
+    get_describe_active, get_resource_title, help_bold_line, horizontal_chunks,
+    layout_block_top_border, style_help, style_text, title_style, title_with_dual_style,
+    vertical_chunks, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -48,6 +53,14 @@ impl Named for KubeConfigMap {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<ConfigMap> for KubeConfigMap {
@@ -56,6 +69,67 @@ impl KubeResource<ConfigMap> for KubeConfigMap {
   }
 }
 
+impl KubeConfigMap {
+  /// The `data` keys, then the `binaryData` ones, each by name.
+  pub fn entries(&self) -> Vec<ConfigMapEntry> {
+    let text = self.data.iter().map(|(key, value)| ConfigMapEntry {
+      key: key.clone(),
+      size: value.len(),
+      value: Some(value.clone()),
+    });
+    let binary = self
+      .k8s_obj
+      .binary_data
+      .iter()
+      .flatten()
+      .map(|(key, bytes)| ConfigMapEntry {
+        key: key.clone(),
+        size: bytes.0.len(),
+        value: None,
+      });
+    text.chain(binary).collect()
+  }
+}
+
+/// One key of a ConfigMap in its data view.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigMapEntry {
+  pub key: String,
+  /// The text, or `None` for a `binaryData` key, which is not shown.
+  pub value: Option<String>,
+  /// Size of the value in bytes.
+  pub size: usize,
+}
+
+impl ConfigMapEntry {
+  pub fn line_count(&self) -> usize {
+    self
+      .value
+      .as_deref()
+      .map_or(0, |value| value.lines().count())
+  }
+
+  /// `812 B`, `1.2 KiB`; ConfigMaps are limited to 1 MiB.
+  pub fn size_label(&self) -> String {
+    match self.size {
+      size if size < 1024 => format!("{} B", size),
+      size if size < 1024 * 1024 => format!("{:.1} KiB", size as f64 / 1024.0),
+      size => format!("{:.1} MiB", size as f64 / (1024.0 * 1024.0)),
+    }
+  }
+
+  /// What stands in for a binary value, which is never dumped on screen.
+  pub fn binary_note(&self) -> String {
+    format!("Binary data ({}), not shown", self.size_label())
+  }
+}
+
+impl Named for ConfigMapEntry {
+  fn get_name(&self) -> &String {
+    &self.key
+  }
+}
+
 static CONFIG_MAPS_TITLE: &str = "ConfigMaps";
 
 pub struct ConfigMapResource {}
@@ -63,6 +137,24 @@ pub struct ConfigMapResource {}
 #[async_trait]
 impl AppResource for ConfigMapResource {
   fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    if block == ActiveBlock::Describe {
+      let title = title_with_dual_style(
+        get_resource_title(
+          app,
+          CONFIG_MAPS_TITLE,
+          get_describe_active(block),
+          app.data.config_maps.items.len(),
+        ),
+        crate::ui::utils::copy_and_escape_title_line(CONFIG_MAPS_TITLE, app.palette),
+        app.palette,
+      );
+      draw_consumers_describe(f, app, area, title, ConsumedKind::ConfigMap);
+      return;
+    }
+    if block == ActiveBlock::ConfigMapData {
+      draw_data_block(f, app, area);
+      return;
+    }
     draw_resource_tab!(
       CONFIG_MAPS_TITLE,
       block,
@@ -83,40 +175,137 @@ impl AppResource for ConfigMapResource {
   }
 }
 
-const CM_COLUMNS: [ColumnDef; 4] = [
+pub(crate) const CM_COLUMNS: [ColumnDef; 4] = [
   ColumnDef::all("Namespace", 30, 30, 30),
   ColumnDef::all("Name", 40, 40, 40),
   ColumnDef::all("Data", 15, 15, 15),
   ColumnDef::all("Age", 15, 15, 15),
 ];
 
+pub(crate) const CM_DATA_COLUMNS: [ColumnDef; 3] = [
+  ColumnDef::all("Key", 60, 60, 60),
+  ColumnDef::all("Lines", 20, 20, 20),
+  ColumnDef::all("Size", 20, 20, 20),
+];
+
+/// Most lines of the selected value previewed next to the keys; Enter opens
+/// the whole value.
+const PREVIEW_LINES: usize = 200;
+
+/// The keys of a ConfigMap beside a preview of the selected value, or above
+/// it in the compact layout.
+fn draw_data_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  let split = vec![Constraint::Percentage(40), Constraint::Percentage(60)];
+  let chunks = if app.compact_layout() {
+    vertical_chunks(split, area)
+  } else {
+    horizontal_chunks(split, area)
+  };
+  let palette = app.palette;
+  let preview = match app.data.config_map_data.get_selected_item_copy() {
+    Some(ConfigMapEntry {
+      value: Some(value), ..
+    }) => value
+      .lines()
+      .take(PREVIEW_LINES)
+      .map(|line| Line::styled(line.to_owned(), style_text(palette)))
+      .collect(),
+    Some(entry) => vec![Line::styled(entry.binary_note(), style_help(palette))],
+    None => vec![],
+  };
+  f.render_widget(
+    Paragraph::new(preview).block(layout_block_top_border(
+      Line::from(title_style(" Value ", palette)),
+      palette,
+    )),
+    chunks[1],
+  );
+
+  let load = app.view_load(ActiveBlock::ConfigMaps);
+  let suffix = format!(
+    "-> {} [{}]",
+    app.data.selected.config_map.as_deref().unwrap_or_default(),
+    app.data.config_map_data.items.len()
+  );
+  let title = get_resource_title(
+    app,
+    CONFIG_MAPS_TITLE,
+    &suffix,
+    app.data.config_maps.items.len(),
+  );
+  let columns = app.column_layout(ActiveBlock::ConfigMapData, ViewTier::Compact);
+
+  draw_resource_block(
+    f,
+    chunks[0],
+    ResourceTableProps {
+      title,
+      inline_help: help_bold_line(
+        format!(
+          "{} · {}:back ",
+          action_hint("view value", DEFAULT_KEYBINDING.submit.key),
+          DEFAULT_KEYBINDING.esc.key.symbol()
+        ),
+        palette,
+      ),
+      resource: &mut app.data.config_map_data,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
+    },
+    |c| {
+      let (lines, style) = if c.value.is_some() {
+        (c.line_count().to_string(), style_text(palette))
+      } else {
+        ("binary".to_owned(), style_help(palette))
+      };
+      columns
+        .row(vec![
+          Cell::from(c.key.to_owned()),
+          Cell::from(lines),
+          Cell::from(c.size_label()),
+        ])
+        .style(style)
+    },
+    palette,
+    load,
+  );
+}
+
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::ConfigMaps);
   let title = get_resource_title(app, CONFIG_MAPS_TITLE, "", app.data.config_maps.items.len());
 
-  let (headers, widths) = responsive_columns(&CM_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::ConfigMaps, ViewTier::Compact);
 
   draw_resource_block(
     f,
     area,
     ResourceTableProps {
       title,
-      inline_help: help_bold_line(describe_and_yaml_hint(), app.palette),
+      inline_help: help_bold_line(
+        format!(
+          "{} · {}",
+          action_hint("keys", DEFAULT_KEYBINDING.submit.key),
+          describe_and_yaml_hint()
+        ),
+        app.palette,
+      ),
       resource: &mut app.data.config_maps,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.data.len().to_string()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.data.len().to_string()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -127,6 +316,38 @@ mod tests {
   use super::*;
   use crate::{app::test_utils::*, map_string_object};
 
+  #[test]
+  fn test_config_map_entries() {
+    let (cms, _): (Vec<KubeConfigMap>, Vec<_>) = convert_resource_from_file("cm");
+    let entries = cms[1].entries();
+    assert_eq!(
+      entries,
+      vec![
+        ConfigMapEntry {
+          key: "clusterDNS".into(),
+          value: Some("10.43.0.10".into()),
+          size: 10,
+        },
+        ConfigMapEntry {
+          key: "clusterDomain".into(),
+          value: Some("cluster.local".into()),
+          size: 13,
+        },
+      ]
+    );
+    assert_eq!(cms[2].entries()[2].key, "setup");
+    assert_eq!(cms[2].entries()[2].line_count(), 16);
+
+    let binary = ConfigMapEntry {
+      key: "logo.png".into(),
+      value: None,
+      size: 2560,
+    };
+    assert_eq!(binary.line_count(), 0);
+    assert_eq!(binary.size_label(), "2.5 KiB");
+    assert_eq!(binary.binary_note(), "Binary data (2.5 KiB), not shown");
+  }
+
   #[test]
   fn test_config_map_from_api() {
     let (cms, cm_list): (Vec<KubeConfigMap>, Vec<_>) = convert_resource_from_file("cm");
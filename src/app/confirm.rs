@@ -0,0 +1,178 @@
+//! Which changes ask for confirmation first, from `confirm` in the config file.
+//! Destructive actions are confirmed by default; reversible ones, like
+//! scaling or cordoning, run as soon as they are chosen.
+use log::warn;
+
+use super::{actions::ConfirmAction, App};
+use crate::{
+  cmd::IoCmdEvent,
+  config::KdashConfig,
+  network::{IoEvent, ResourcePatch},
+};
+
+/// Every action `confirm` can name.
+pub const CONFIRM_ACTIONS: &[&str] = &[
+  "delete", "evict", "restart", "undo", "apply", "trigger", "scale", "create", "cordon",
+  "uncordon", "suspend", "resume",
+];
+
+/// Confirmed when `confirm` is not set.
+pub const DEFAULT_CONFIRM_ACTIONS: &[&str] =
+  &["delete", "evict", "restart", "undo", "apply", "trigger"];
+
+impl ConfirmAction {
+  /// The name `confirm` knows this change by; `None` when it does not change
+  /// the cluster.
+  pub fn confirm_name(&self) -> Option<&'static str> {
+    match self {
+      ConfirmAction::Io(event) => match event {
+        IoEvent::DeleteResource { .. }
+        | IoEvent::DeleteResources { .. }
+        | IoEvent::DeleteNamespace { .. } => Some("delete"),
+        IoEvent::CreateNamespace { .. } => Some("create"),
+        IoEvent::EvictPod { .. } => Some("evict"),
+        IoEvent::RestartPods { .. } => Some("restart"),
+        IoEvent::TriggerCronJob { .. } => Some("trigger"),
+        IoEvent::PatchResource { patch, .. } => Some(match patch {
+          ResourcePatch::RolloutRestart => "restart",
+          ResourcePatch::SetUnschedulable(true) => "cordon",
+          ResourcePatch::SetUnschedulable(false) => "uncordon",
+          ResourcePatch::SetSuspend(true) => "suspend",
+          ResourcePatch::SetSuspend(false) => "resume",
+          ResourcePatch::SetReplicas(_) => "scale",
+        }),
+        _ => None,
+      },
+      ConfirmAction::Cmd(event) => match event {
+        // overriding a rejected dry-run is always asked
+        IoCmdEvent::ApplyEdit {
+          skip_dry_run: true, ..
+        } => None,
+        IoCmdEvent::ApplyEdit { .. } => Some("apply"),
+        // the clipboard's content is always shown before it is applied
+        IoCmdEvent::ApplyManifest { .. } => None,
+        IoCmdEvent::RolloutUndo { .. } => Some("undo"),
+        _ => None,
+      },
+      // a macro is always asked for once, in place of its steps
+      ConfirmAction::Macro { .. } => None,
+    }
+  }
+}
+
+impl App {
+  /// Whether `action` waits for the user to confirm it. Anything that is not
+  /// a known change keeps its confirmation.
+  pub fn needs_confirmation(&self, action: &ConfirmAction) -> bool {
+    let Some(name) = action.confirm_name() else {
+      return true;
+    };
+    match &self.config.confirm {
+      Some(names) => names.iter().any(|n| n.trim().eq_ignore_ascii_case(name)),
+      None => DEFAULT_CONFIRM_ACTIONS.contains(&name),
+    }
+  }
+}
+
+/// One warning per unknown action under `confirm`.
+pub fn confirm_warnings(config: &KdashConfig) -> Vec<String> {
+  let warnings: Vec<String> = config
+    .confirm
+    .iter()
+    .flatten()
+    .filter(|name| {
+      !CONFIRM_ACTIONS
+        .iter()
+        .any(|known| known.eq_ignore_ascii_case(name.trim()))
+    })
+    .map(|name| {
+      format!(
+        "Unknown action in confirm config: {}. Valid actions: {}",
+        name,
+        CONFIRM_ACTIONS.join(", ")
+      )
+    })
+    .collect();
+  for warning in &warnings {
+    warn!("{}", warning);
+  }
+  warnings
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::app::ActiveBlock;
+
+  fn patch(patch: ResourcePatch) -> ConfirmAction {
+    IoEvent::PatchResource {
+      block: ActiveBlock::Deployments,
+      name: "web".into(),
+      namespace: Some("shop".into()),
+      patch,
+    }
+    .into()
+  }
+
+  fn delete() -> ConfirmAction {
+    IoEvent::DeleteResource {
+      block: ActiveBlock::Pods,
+      name: "web-1".into(),
+      namespace: Some("shop".into()),
+    }
+    .into()
+  }
+
+  #[test]
+  fn test_destructive_actions_are_confirmed_by_default() {
+    let app = App::default();
+    assert!(app.needs_confirmation(&delete()));
+    assert!(app.needs_confirmation(&patch(ResourcePatch::RolloutRestart)));
+    assert!(!app.needs_confirmation(&patch(ResourcePatch::SetReplicas(3))));
+    assert!(!app.needs_confirmation(&patch(ResourcePatch::SetUnschedulable(true))));
+    // not a change, so never skipped
+    assert!(app.needs_confirmation(&IoEvent::GetPods.into()));
+  }
+
+  #[test]
+  fn test_configured_actions_replace_the_defaults() {
+    let mut app = App::default();
+    app.config.confirm = Some(vec!["Scale".into()]);
+    assert!(app.needs_confirmation(&patch(ResourcePatch::SetReplicas(3))));
+    assert!(!app.needs_confirmation(&delete()));
+
+    app.config.confirm = Some(vec![]);
+    assert!(!app.needs_confirmation(&delete()));
+  }
+
+  #[test]
+  fn test_applying_past_a_rejected_dry_run_is_always_confirmed() {
+    let apply = |skip_dry_run| {
+      ConfirmAction::from(IoCmdEvent::ApplyEdit {
+        kind: "deployment".into(),
+        name: "web".into(),
+        file: "/tmp/web.yaml".into(),
+        skip_dry_run,
+      })
+    };
+    let mut app = App::default();
+    app.config.confirm = Some(vec![]);
+    assert!(!app.needs_confirmation(&apply(false)));
+    assert!(app.needs_confirmation(&apply(true)));
+  }
+
+  #[test]
+  fn test_confirm_warnings_name_unknown_actions() {
+    let config = KdashConfig {
+      confirm: Some(vec!["delete".into(), "drain".into()]),
+      ..KdashConfig::default()
+    };
+    assert_eq!(
+      confirm_warnings(&config),
+      vec![format!(
+        "Unknown action in confirm config: drain. Valid actions: {}",
+        CONFIRM_ACTIONS.join(", ")
+      )]
+    );
+  }
+}
@@ -0,0 +1,67 @@
+//! Deep links from the selected row into a web console, such as Lens or a
+//! vendor's cluster console, built from a URL template in the config file.
+
+/// Shown when the key is pressed without a template for the current context.
+pub const NO_CONSOLE_URL: &str =
+  "No console URL set. Add e.g. `console_url: https://console/{cluster}/{namespace}/{kind}/{name}` to the config file";
+
+/// The values a console URL template can refer to.
+#[derive(Debug, Default)]
+pub struct ConsoleTarget<'a> {
+  pub context: &'a str,
+  pub cluster: &'a str,
+  /// Empty for cluster-scoped resources.
+  pub namespace: &'a str,
+  pub kind: &'a str,
+  pub name: &'a str,
+}
+
+/// Fill the `{context}`, `{cluster}`, `{namespace}`, `{kind}` and `{name}`
+/// placeholders of `template`. Unknown placeholders are left as they are.
+pub fn console_url(template: &str, target: &ConsoleTarget<'_>) -> String {
+  [
+    ("{context}", target.context),
+    ("{cluster}", target.cluster),
+    ("{namespace}", target.namespace),
+    ("{kind}", target.kind),
+    ("{name}", target.name),
+  ]
+  .iter()
+  .fold(template.to_owned(), |url, (placeholder, value)| {
+    url.replace(placeholder, value)
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_console_url_fills_placeholders() {
+    let target = ConsoleTarget {
+      context: "prod",
+      cluster: "eks-prod",
+      namespace: "shop",
+      kind: "deployment",
+      name: "web",
+    };
+    assert_eq!(
+      console_url(
+        "https://console/{cluster}/{namespace}/{kind}/{name}?ctx={context}&x={other}",
+        &target
+      ),
+      "https://console/eks-prod/shop/deployment/web?ctx=prod&x={other}"
+    );
+    assert_eq!(
+      console_url(
+        "https://console/{kind}/{name}",
+        &ConsoleTarget {
+          kind: "node",
+          name: "node-a",
+          ..target
+        }
+      ),
+      "https://console/node/node-a"
+    );
+  }
+}
@@ -0,0 +1,280 @@
+//! Which pods use a ConfigMap or Secret, through volumes, env vars or image
+//! pull secrets. Worked out from the pods already polled, so the describe
+//! view of either kind lists its consumers without extra API calls.
+use k8s_openapi::api::core::v1::{Container, Pod};
+use ratatui::{
+  layout::{Constraint, Rect},
+  text::Line,
+  widgets::Paragraph,
+  Frame,
+};
+
+use super::{models::KubeResource, App};
+use crate::ui::utils::{
+  draw_describe_block, layout_block_default, style_caution, style_text, vertical_chunks,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConsumedKind {
+  ConfigMap,
+  Secret,
+}
+
+impl ConsumedKind {
+  fn label(self) -> &'static str {
+    match self {
+      ConsumedKind::ConfigMap => "configmap",
+      ConsumedKind::Secret => "secret",
+    }
+  }
+}
+
+/// A pod using the object, and how: `volume config`, `app env DB_PASSWORD`,
+/// `app envFrom` or `imagePullSecrets`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Consumer {
+  pub pod: String,
+  pub via: Vec<String>,
+}
+
+/// The pods in `namespace` that use the ConfigMap or Secret `name`.
+pub fn consumers<'a>(
+  kind: ConsumedKind,
+  name: &str,
+  namespace: &str,
+  pods: impl IntoIterator<Item = &'a Pod>,
+) -> Vec<Consumer> {
+  pods
+    .into_iter()
+    .filter(|pod| pod.metadata.namespace.as_deref() == Some(namespace))
+    .filter_map(|pod| {
+      let via = pod_references(kind, name, pod);
+      (!via.is_empty()).then(|| Consumer {
+        pod: pod.metadata.name.clone().unwrap_or_default(),
+        via,
+      })
+    })
+    .collect()
+}
+
+fn pod_references(kind: ConsumedKind, name: &str, pod: &Pod) -> Vec<String> {
+  let Some(spec) = pod.spec.as_ref() else {
+    return vec![];
+  };
+  let mut via = vec![];
+  for volume in spec.volumes.iter().flatten() {
+    let direct = match kind {
+      ConsumedKind::ConfigMap => volume.config_map.as_ref().map(|cm| cm.name.as_str()),
+      ConsumedKind::Secret => volume
+        .secret
+        .as_ref()
+        .and_then(|s| s.secret_name.as_deref()),
+    };
+    let projected = volume
+      .projected
+      .iter()
+      .flat_map(|p| p.sources.iter().flatten())
+      .any(|source| match kind {
+        ConsumedKind::ConfigMap => {
+          source.config_map.as_ref().map(|cm| cm.name.as_str()) == Some(name)
+        }
+        ConsumedKind::Secret => source.secret.as_ref().map(|s| s.name.as_str()) == Some(name),
+      });
+    if direct == Some(name) || projected {
+      via.push(format!("volume {}", volume.name));
+    }
+  }
+  for container in spec
+    .init_containers
+    .iter()
+    .flatten()
+    .chain(spec.containers.iter())
+  {
+    via.extend(container_references(kind, name, container));
+  }
+  if kind == ConsumedKind::Secret
+    && spec
+      .image_pull_secrets
+      .iter()
+      .flatten()
+      .any(|s| s.name == name)
+  {
+    via.push("imagePullSecrets".into());
+  }
+  via
+}
+
+fn container_references(kind: ConsumedKind, name: &str, container: &Container) -> Vec<String> {
+  let mut via: Vec<String> = container
+    .env
+    .iter()
+    .flatten()
+    .filter(|var| {
+      let source = var.value_from.as_ref();
+      match kind {
+        ConsumedKind::ConfigMap => {
+          source
+            .and_then(|s| s.config_map_key_ref.as_ref())
+            .map(|r| r.name.as_str())
+            == Some(name)
+        }
+        ConsumedKind::Secret => {
+          source
+            .and_then(|s| s.secret_key_ref.as_ref())
+            .map(|r| r.name.as_str())
+            == Some(name)
+        }
+      }
+    })
+    .map(|var| format!("{} env {}", container.name, var.name))
+    .collect();
+  let from_all = container
+    .env_from
+    .iter()
+    .flatten()
+    .any(|source| match kind {
+      ConsumedKind::ConfigMap => {
+        source.config_map_ref.as_ref().map(|r| r.name.as_str()) == Some(name)
+      }
+      ConsumedKind::Secret => source.secret_ref.as_ref().map(|r| r.name.as_str()) == Some(name),
+    });
+  if from_all {
+    via.push(format!("{} envFrom", container.name));
+  }
+  via
+}
+
+/// Describe output of a ConfigMap or Secret with the pods using it on top, or
+/// a note that nothing does.
+pub fn draw_consumers_describe(
+  f: &mut Frame<'_>,
+  app: &mut App,
+  area: Rect,
+  title: Line<'_>,
+  kind: ConsumedKind,
+) {
+  let Some((_, name, Some(namespace))) = app
+    .data
+    .last_describe
+    .clone()
+    .and_then(|action| action.described_object())
+  else {
+    draw_describe_block(f, app, area, title);
+    return;
+  };
+  let used_by = consumers(
+    kind,
+    &name,
+    &namespace,
+    app.data.pods.items.iter().map(|pod| pod.get_k8s_obj()),
+  );
+  let (lines, style) = if used_by.is_empty() {
+    (
+      vec![Line::from(format!(
+        "Not used by any loaded pod in {}: this {} may be safe to clean up",
+        namespace,
+        kind.label()
+      ))],
+      style_caution(app.palette),
+    )
+  } else {
+    (
+      used_by
+        .iter()
+        .map(|c| Line::from(format!("{}  ({})", c.pod, c.via.join(", "))))
+        .collect(),
+      style_text(app.palette),
+    )
+  };
+  let height = (lines.len() as u16 + 2).min(area.height / 2).max(3);
+  let chunks = vertical_chunks(vec![Constraint::Length(height), Constraint::Min(0)], area);
+  let pane_title = format!(" Used by ({}) ", used_by.len());
+  f.render_widget(
+    Paragraph::new(lines)
+      .style(style)
+      .block(layout_block_default(&pane_title, app.palette)),
+    chunks[0],
+  );
+  draw_describe_block(f, app, chunks[1], title);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pod(name: &str, spec: serde_json::Value) -> Pod {
+    serde_json::from_value(serde_json::json!({
+      "metadata": { "name": name, "namespace": "shop" },
+      "spec": spec,
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn test_consumers_finds_volume_env_and_pull_secret_references() {
+    let pods = [
+      pod(
+        "web-1",
+        serde_json::json!({
+          "containers": [{
+            "name": "app",
+            "env": [
+              { "name": "DB_PASSWORD", "valueFrom": { "secretKeyRef": { "name": "db", "key": "password" } } },
+              { "name": "MODE", "valueFrom": { "configMapKeyRef": { "name": "settings", "key": "mode" } } }
+            ],
+            "envFrom": [{ "configMapRef": { "name": "settings" } }]
+          }],
+          "imagePullSecrets": [{ "name": "registry" }],
+          "volumes": [
+            { "name": "config", "configMap": { "name": "settings" } },
+            { "name": "all", "projected": { "sources": [{ "secret": { "name": "db" } }] } }
+          ]
+        }),
+      ),
+      pod(
+        "worker-1",
+        serde_json::json!({
+          "initContainers": [{ "name": "migrate", "envFrom": [{ "secretRef": { "name": "db" } }] }],
+          "containers": [{ "name": "worker" }]
+        }),
+      ),
+      pod(
+        "idle-1",
+        serde_json::json!({ "containers": [{ "name": "idle" }] }),
+      ),
+    ];
+
+    assert_eq!(
+      consumers(ConsumedKind::Secret, "db", "shop", &pods),
+      vec![
+        Consumer {
+          pod: "web-1".into(),
+          via: vec!["volume all".into(), "app env DB_PASSWORD".into()],
+        },
+        Consumer {
+          pod: "worker-1".into(),
+          via: vec!["migrate envFrom".into()],
+        },
+      ]
+    );
+    assert_eq!(
+      consumers(ConsumedKind::ConfigMap, "settings", "shop", &pods),
+      vec![Consumer {
+        pod: "web-1".into(),
+        via: vec![
+          "volume config".into(),
+          "app env MODE".into(),
+          "app envFrom".into()
+        ],
+      }]
+    );
+    assert_eq!(
+      consumers(ConsumedKind::Secret, "registry", "shop", &pods)[0].via,
+      vec!["imagePullSecrets".to_string()]
+    );
+    // a same-named object in another namespace is a different object
+    assert!(consumers(ConsumedKind::Secret, "db", "other", &pods).is_empty());
+    // a configmap and a secret of the same name are told apart
+    assert!(consumers(ConsumedKind::ConfigMap, "db", "shop", &pods).is_empty());
+  }
+}
@@ -1,8 +1,14 @@
+use std::{
+  collections::HashMap,
+  time::{Duration, Instant},
+};
+
 use async_trait::async_trait;
 use kube::config::{Context, Kubeconfig, NamedContext};
 use ratatui::{
   layout::{Constraint, Rect},
-  widgets::{Cell, Row, Table},
+  text::{Line, Span},
+  widgets::{Cell, Paragraph, Row, Table},
   Frame,
 };
 
@@ -11,17 +17,74 @@ use super::{
   ActiveBlock, App,
 };
 use crate::{
-  network::Network,
+  network::{connection_info::auth_method, Network},
   ui::{
+    glyphs::glyphs,
+    theme::Palette,
     utils::{
-      default_part, filter_cursor_position, filter_status_parts, layout_block_active_line, loading,
-      mixed_bold_line, style_highlight, style_secondary, style_text, table_header_style,
-      text_matches_filter,
+      default_part, filter_cursor_position, filter_status_parts, layout_block_active_line,
+      layout_block_top_border, loading, mixed_bold_line, style_failure, style_help,
+      style_highlight, style_label, style_secondary, style_success, style_text, table_header_style,
+      text_matches_filter, vertical_chunks,
     },
     HIGHLIGHT,
   },
 };
 
+/// How long one reachability probe may take before the context is marked down.
+pub const CONTEXT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Minimum time between probe rounds while the contexts view is open.
+const CONTEXT_PROBE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Whether a context's API server answered the last probe.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ContextHealth {
+  #[default]
+  Unknown,
+  Reachable,
+  Unreachable,
+}
+
+impl ContextHealth {
+  fn dot(self, palette: Palette) -> Span<'static> {
+    let style = match self {
+      ContextHealth::Unknown => style_help(palette),
+      ContextHealth::Reachable => style_success(palette),
+      ContextHealth::Unreachable => style_failure(palette),
+    };
+    Span::styled(format!("{} ", glyphs().current), style)
+  }
+}
+
+/// Probe results by context name. Kept apart from the context list, which is
+/// rebuilt on every kubeconfig reload.
+#[derive(Debug, Default)]
+pub struct ContextProbes {
+  health: HashMap<String, ContextHealth>,
+  last_round: Option<Instant>,
+}
+
+impl ContextProbes {
+  pub fn health(&self, context: &str) -> ContextHealth {
+    self.health.get(context).copied().unwrap_or_default()
+  }
+
+  pub fn set_health(&mut self, context: String, health: ContextHealth) {
+    self.health.insert(context, health);
+  }
+
+  /// Whether the last probe round is old enough to start another.
+  pub fn is_due(&self, now: Instant) -> bool {
+    self
+      .last_round
+      .is_none_or(|last| now.duration_since(last) >= CONTEXT_PROBE_INTERVAL)
+  }
+
+  pub fn start_round(&mut self, now: Instant) {
+    self.last_round = Some(now);
+  }
+}
+
 #[derive(Clone, Default)]
 pub struct KubeContext {
   pub name: String,
@@ -29,6 +92,10 @@ pub struct KubeContext {
   pub user: Option<String>,
   pub namespace: Option<String>,
   pub is_active: bool,
+  /// The API server of its cluster, from the kubeconfig.
+  pub server: Option<String>,
+  /// How its user authenticates, without any secret, e.g. `bearer token`.
+  pub auth: Option<String>,
 }
 
 impl KubeContext {
@@ -41,6 +108,8 @@ impl KubeContext {
       user: context.user.clone(),
       namespace: context.namespace.clone(),
       is_active,
+      server: None,
+      auth: None,
     }
   }
 }
@@ -50,10 +119,24 @@ pub fn get_contexts(config: &Kubeconfig, selected_ctx: Option<String>) -> Vec<Ku
     .contexts
     .iter()
     .map(|ctx| {
-      KubeContext::from_api(
+      let mut context = KubeContext::from_api(
         ctx,
         is_active_context(&ctx.name, &config.current_context, selected_ctx.to_owned()),
-      )
+      );
+      context.server = config
+        .clusters
+        .iter()
+        .find(|cluster| cluster.name == context.cluster)
+        .and_then(|cluster| cluster.cluster.as_ref()?.server.clone());
+      context.auth = context.user.as_ref().and_then(|user| {
+        config
+          .auth_infos
+          .iter()
+          .find(|auth| &auth.name == user)
+          .and_then(|auth| auth.auth_info.as_ref())
+          .map(auth_method)
+      });
+      context
     })
     .collect::<Vec<KubeContext>>()
 }
@@ -109,11 +192,17 @@ impl AppResource for ContextResource {
             filtered_indices.push(idx);
           }
 
+          let health = app.data.context_probes.health(&c.name);
           Some(
             Row::new(vec![
-              Cell::from(c.name.to_owned()),
+              Cell::from(Line::from(vec![
+                health.dot(app.palette),
+                Span::raw(c.name.to_owned()),
+              ])),
               Cell::from(c.cluster.to_owned()),
+              Cell::from(c.server.clone().unwrap_or_default()),
               Cell::from(c.user.clone().unwrap_or("<none>".to_string())),
+              Cell::from(c.auth.clone().unwrap_or_default()),
             ])
             .style(style),
           )
@@ -133,22 +222,40 @@ impl AppResource for ContextResource {
       let table = Table::new(
         rows,
         [
-          Constraint::Percentage(34),
-          Constraint::Percentage(33),
-          Constraint::Percentage(33),
+          Constraint::Percentage(22),
+          Constraint::Percentage(18),
+          Constraint::Percentage(24),
+          Constraint::Percentage(18),
+          Constraint::Percentage(18),
         ],
       )
       .header(table_header_style(
-        vec!["Context", "Cluster", "User"],
+        vec!["Context", "Cluster", "Server", "User", "Auth"],
         app.palette,
       ))
       .block(block)
       .row_highlight_style(style_highlight())
       .highlight_symbol(HIGHLIGHT);
 
-      f.render_stateful_widget(table, area, &mut app.data.contexts.state);
+      // long servers and auth commands are cut in the table, so the
+      // selected context's are shown in full below it
+      let table_area = match app.data.contexts.get_selected_item() {
+        Some(selected) => {
+          let chunks = vertical_chunks(vec![Constraint::Min(0), Constraint::Length(3)], area);
+          draw_selected_context(f, selected, app.palette, chunks[1]);
+          chunks[0]
+        }
+        None => area,
+      };
+      f.render_stateful_widget(table, table_area, &mut app.data.contexts.state);
     } else {
-      loading(f, block, area, app.is_loading(), app.palette);
+      loading(
+        f,
+        block,
+        area,
+        &app.view_load(ActiveBlock::Contexts),
+        app.palette,
+      );
     }
 
     if app.data.contexts.filter_active {
@@ -166,6 +273,24 @@ impl AppResource for ContextResource {
   }
 }
 
+/// The full server and auth of the selected context.
+fn draw_selected_context(f: &mut Frame<'_>, ctx: &KubeContext, palette: Palette, area: Rect) {
+  let line = |label: &'static str, value: &Option<String>| {
+    Line::from(vec![
+      Span::styled(format!("{:<8}", label), style_label(palette)),
+      Span::styled(
+        value.clone().unwrap_or_else(|| "-".into()),
+        style_text(palette),
+      ),
+    ])
+  };
+  let block = layout_block_top_border(Line::from(format!(" {} ", ctx.name)), palette);
+  f.render_widget(
+    Paragraph::new(vec![line("Server", &ctx.server), line("Auth", &ctx.auth)]).block(block),
+    area,
+  );
+}
+
 fn context_matches_filter(filter: &str, ctx: &KubeContext) -> bool {
   text_matches_filter(filter, &ctx.name)
     || text_matches_filter(filter, &ctx.cluster)
@@ -177,6 +302,10 @@ fn context_matches_filter(filter: &str, ctx: &KubeContext) -> bool {
       .namespace
       .as_deref()
       .is_some_and(|namespace| text_matches_filter(filter, namespace))
+    || ctx
+      .server
+      .as_deref()
+      .is_some_and(|server| text_matches_filter(filter, server))
 }
 
 #[cfg(test)]
@@ -235,6 +364,50 @@ mod tests {
     assert_eq!(contexts[1].namespace, Some("ns-b".to_string()));
   }
 
+  #[test]
+  fn test_get_contexts_tells_apart_contexts_by_server_and_auth() {
+    let config = Kubeconfig::from_yaml(
+      r#"
+apiVersion: v1
+kind: Config
+contexts:
+  - name: default
+    context: { cluster: eu, user: eu-admin }
+  - name: default-us
+    context: { cluster: us, user: us-sso }
+  - name: orphan
+    context: { cluster: gone }
+clusters:
+  - name: eu
+    cluster: { server: "https://eu.example.com:6443" }
+  - name: us
+    cluster: { server: "https://us.example.com" }
+users:
+  - name: eu-admin
+    user: { token: s3cr3t }
+  - name: us-sso
+    user:
+      exec: { command: kubelogin, args: [get-token], apiVersion: client.authentication.k8s.io/v1 }
+"#,
+    )
+    .unwrap();
+
+    let contexts = get_contexts(&config, None);
+    assert_eq!(
+      contexts[0].server.as_deref(),
+      Some("https://eu.example.com:6443")
+    );
+    assert_eq!(contexts[0].auth.as_deref(), Some("bearer token"));
+    assert_eq!(
+      contexts[1].auth.as_deref(),
+      Some("exec plugin: kubelogin get-token")
+    );
+    assert_eq!(contexts[2].server, None);
+    assert_eq!(contexts[2].auth, None);
+    assert!(context_matches_filter("us.example", &contexts[1]));
+    assert!(!format!("{:?}", contexts[0].auth).contains("s3cr3t"));
+  }
+
   #[test]
   fn test_get_contexts_selected_overrides_current_context() {
     let config = Kubeconfig {
@@ -251,6 +424,23 @@ mod tests {
     assert!(!contexts[1].is_active);
   }
 
+  #[test]
+  fn test_context_probes_rounds_and_health() {
+    let now = Instant::now();
+    let mut probes = ContextProbes::default();
+    assert_eq!(probes.health("prod"), ContextHealth::Unknown);
+
+    assert!(probes.is_due(now));
+    probes.start_round(now);
+    assert!(!probes.is_due(now + Duration::from_secs(1)));
+    assert!(probes.is_due(now + CONTEXT_PROBE_INTERVAL));
+
+    probes.set_health("prod".into(), ContextHealth::Reachable);
+    probes.set_health("old".into(), ContextHealth::Unreachable);
+    assert_eq!(probes.health("prod"), ContextHealth::Reachable);
+    assert_eq!(probes.health("old"), ContextHealth::Unreachable);
+  }
+
   #[test]
   fn test_context_matches_filter() {
     let ctx = KubeContext {
@@ -259,6 +449,7 @@ mod tests {
       user: Some("operator".into()),
       namespace: Some("kube-system".into()),
       is_active: false,
+      ..KubeContext::default()
     };
 
     assert!(context_matches_filter("", &ctx));
@@ -0,0 +1,215 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use kube::{
+  api::ObjectMeta,
+  discovery::{ApiResource, Scope},
+};
+use ratatui::{layout::Rect, widgets::Cell, Frame};
+
+use super::{
+  dynamic::KubeDynamicKind,
+  key_binding::DEFAULT_KEYBINDING,
+  models::{AppResource, KubeResource, Named},
+  utils, ActiveBlock, App,
+};
+use crate::{
+  draw_resource_tab,
+  network::Network,
+  ui::utils::{
+    action_hint, describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block,
+    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, style_text,
+    title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+  },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct KubeCrd {
+  pub name: String,
+  pub group: String,
+  pub kind: String,
+  pub plural: String,
+  /// The storage version, or the first served one.
+  pub version: String,
+  pub scope: String,
+  pub age: String,
+  k8s_obj: CustomResourceDefinition,
+}
+
+impl From<CustomResourceDefinition> for KubeCrd {
+  fn from(crd: CustomResourceDefinition) -> Self {
+    let spec = &crd.spec;
+    let version = spec
+      .versions
+      .iter()
+      .find(|v| v.storage)
+      .or_else(|| spec.versions.iter().find(|v| v.served))
+      .map(|v| v.name.clone())
+      .unwrap_or_default();
+
+    KubeCrd {
+      name: crd.metadata.name.clone().unwrap_or_default(),
+      group: spec.group.clone(),
+      kind: spec.names.kind.clone(),
+      plural: spec.names.plural.clone(),
+      version,
+      scope: spec.scope.clone(),
+      age: utils::to_age(crd.metadata.creation_timestamp.as_ref(), Utc::now()),
+      k8s_obj: utils::sanitize_obj(crd),
+    }
+  }
+}
+
+impl KubeCrd {
+  /// The kind of this CRD's instances, for listing them in the dynamic view.
+  pub fn dynamic_kind(&self) -> KubeDynamicKind {
+    let scope = if self.scope == "Cluster" {
+      Scope::Cluster
+    } else {
+      Scope::Namespaced
+    };
+    KubeDynamicKind::new(
+      ApiResource {
+        group: self.group.clone(),
+        version: self.version.clone(),
+        api_version: format!("{}/{}", self.group, self.version),
+        kind: self.kind.clone(),
+        plural: self.plural.clone(),
+      },
+      scope,
+    )
+  }
+}
+
+impl Named for KubeCrd {
+  fn get_name(&self) -> &String {
+    &self.name
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
+}
+
+impl KubeResource<CustomResourceDefinition> for KubeCrd {
+  fn get_k8s_obj(&self) -> &CustomResourceDefinition {
+    &self.k8s_obj
+  }
+}
+
+static CRDS_TITLE: &str = "CustomResourceDefinitions";
+
+pub struct CrdResource {}
+
+#[async_trait]
+impl AppResource for CrdResource {
+  fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    draw_resource_tab!(
+      CRDS_TITLE,
+      block,
+      f,
+      app,
+      area,
+      Self::render,
+      draw_block,
+      app.data.crds
+    );
+  }
+
+  async fn get_resource(nw: &Network<'_>) {
+    let items: Vec<KubeCrd> = nw.get_resources(CustomResourceDefinition::into).await;
+
+    let mut app = nw.app.lock().await;
+    app.data.crds.set_items(items);
+  }
+}
+
+pub(crate) const CRD_COLUMNS: [ColumnDef; 6] = [
+  ColumnDef::all("Name", 35, 35, 35),
+  ColumnDef::all("Group", 20, 20, 20),
+  ColumnDef::all("Kind", 15, 15, 15),
+  ColumnDef::all("Version", 10, 10, 10),
+  ColumnDef::all("Scope", 10, 10, 10),
+  ColumnDef::all("Age", 10, 10, 10),
+];
+
+fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  let load = app.view_load(ActiveBlock::CustomResourceDefinitions);
+  let title = get_resource_title(app, CRDS_TITLE, "", app.data.crds.items.len());
+
+  let columns = app.column_layout(ActiveBlock::CustomResourceDefinitions, ViewTier::Compact);
+
+  draw_resource_block(
+    f,
+    area,
+    ResourceTableProps {
+      title,
+      inline_help: help_bold_line(
+        format!(
+          "{} · {}",
+          action_hint("instances", DEFAULT_KEYBINDING.submit.key),
+          describe_yaml_and_esc_hint()
+        ),
+        app.palette,
+      ),
+      resource: &mut app.data.crds,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
+    },
+    |c| {
+      columns
+        .row(vec![
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.group.to_owned()),
+          Cell::from(c.kind.to_owned()),
+          Cell::from(c.version.to_owned()),
+          Cell::from(c.scope.to_owned()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
+    },
+    app.palette,
+    load,
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::app::test_utils::*;
+
+  #[test]
+  fn test_crds_from_api() {
+    let (crds, crd_list): (Vec<KubeCrd>, Vec<_>) = convert_resource_from_file("crds");
+
+    assert_eq!(crds.len(), 2);
+    assert_eq!(
+      crds[0],
+      KubeCrd {
+        name: "certificates.cert-manager.io".into(),
+        group: "cert-manager.io".into(),
+        kind: "Certificate".into(),
+        plural: "certificates".into(),
+        // v1 is the storage version even though v1alpha2 is listed first.
+        version: "v1".into(),
+        scope: "Namespaced".into(),
+        age: utils::to_age(Some(&get_time("2024-02-01T09:00:00Z")), Utc::now()),
+        k8s_obj: crd_list[0].clone(),
+      }
+    );
+    assert_eq!(crds[1].scope, "Cluster");
+  }
+
+  #[test]
+  fn test_crd_dynamic_kind() {
+    let (crds, _): (Vec<KubeCrd>, Vec<_>) = convert_resource_from_file("crds");
+
+    let kind = crds[0].dynamic_kind();
+    assert_eq!(kind.kind, "Certificate");
+    assert_eq!(kind.scope, Scope::Namespaced);
+    assert_eq!(kind.api_resource.api_version, "cert-manager.io/v1");
+    assert_eq!(kind.api_resource.plural, "certificates");
+
+    assert_eq!(crds[1].dynamic_kind().scope, Scope::Cluster);
+  }
+}
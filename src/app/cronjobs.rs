@@ -1,11 +1,8 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use k8s_openapi::api::batch::v1::CronJob;
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
-};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
 
 use super::{
   models::{self, AppResource, KubeResource, Named},
@@ -17,8 +14,8 @@ use crate::{
   network::Network,
   ui::utils::{
     action_hint, describe_yaml_logs_and_esc_hint, draw_describe_block, draw_resource_block,
-    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, responsive_columns,
-    style_text, title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
+    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, style_text,
+    title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -75,6 +72,31 @@ impl Named for KubeCronJob {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
+
+  /// Creation or the last scheduled run.
+  fn get_changed(&self) -> Option<DateTime<Utc>> {
+    let scheduled = self
+      .k8s_obj
+      .status
+      .as_ref()
+      .and_then(|s| s.last_schedule_time.as_ref());
+    utils::latest_time(
+      self
+        .k8s_obj
+        .metadata
+        .creation_timestamp
+        .iter()
+        .chain(scheduled),
+    )
+  }
 }
 
 impl KubeResource<CronJob> for KubeCronJob {
@@ -119,7 +141,7 @@ impl AppResource for CronJobResource {
   }
 }
 
-const CRON_COLUMNS: [ColumnDef; 8] = [
+pub(crate) const CRON_COLUMNS: [ColumnDef; 8] = [
   ColumnDef::all("Namespace", 20, 15, 15),
   ColumnDef::all("Name", 25, 20, 20),
   ColumnDef::all("Schedule", 15, 12, 12),
@@ -131,11 +153,11 @@ const CRON_COLUMNS: [ColumnDef; 8] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::CronJobs);
   let title = get_resource_title(app, CRON_JOBS_TITLE, "", app.data.cronjobs.items.len());
 
   let tier = ViewTier::from_width(area.width, app.wide_columns);
-  let (headers, widths) = responsive_columns(&CRON_COLUMNS, tier);
+  let columns = app.column_layout(ActiveBlock::CronJobs, tier);
 
   draw_resource_block(
     f,
@@ -152,26 +174,24 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
         app.palette,
       ),
       resource: &mut app.data.cronjobs,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      let mut cells = vec![
+      let cells = vec![
         Cell::from(c.namespace.to_owned()),
         Cell::from(c.name.to_owned()),
         Cell::from(c.schedule.to_owned()),
         Cell::from(c.last_schedule.to_string()),
         Cell::from(c.suspend.to_string()),
         Cell::from(c.active.to_string()),
+        Cell::from(c.concurrency_policy.to_owned()),
+        Cell::from(c.age.to_owned()),
       ];
-      if tier >= ViewTier::Standard {
-        cells.push(Cell::from(c.concurrency_policy.to_owned()));
-      }
-      cells.push(Cell::from(c.age.to_owned()));
-      Row::new(cells).style(style_text(app.palette))
+      columns.row(cells).style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -1,9 +1,18 @@
+use anyhow::anyhow;
 use async_trait::async_trait;
 use chrono::Utc;
-use k8s_openapi::api::apps::v1::DaemonSet;
+use k8s_openapi::api::{
+  apps::v1::DaemonSet,
+  core::v1::{Node, Pod, Taint, Toleration},
+};
+use kube::{
+  api::{ListParams, ObjectMeta},
+  Api,
+};
 use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
+  layout::{Constraint, Rect},
+  text::Line,
+  widgets::{Cell, Paragraph},
   Frame,
 };
 
@@ -17,8 +26,9 @@ use crate::{
   network::Network,
   ui::utils::{
     action_hint, describe_yaml_and_logs_hint, draw_describe_block, draw_resource_block,
-    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, responsive_columns,
-    style_text, title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
+    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, layout_block_default,
+    style_caution, style_failure, style_success, style_text, title_with_dual_style,
+    vertical_chunks, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -92,6 +102,14 @@ impl Named for KubeDaemonSet {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<DaemonSet> for KubeDaemonSet {
@@ -112,6 +130,128 @@ impl models::HasPodSelector for KubeDaemonSet {
   }
 }
 
+/// Which eligible nodes a DaemonSet has no pod on.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DaemonSetCoverage {
+  pub eligible: usize,
+  pub missing: Vec<String>,
+}
+
+/// Work out which nodes should run one of the DaemonSet's pods but don't.
+/// A node is eligible when it matches the pod template's `nodeSelector` and
+/// the template tolerates its `NoSchedule`/`NoExecute` taints; node affinity is
+/// not evaluated. `pods` are matched to the DaemonSet by owner reference.
+pub fn daemon_set_coverage(ds: &DaemonSet, nodes: &[Node], pods: &[Pod]) -> DaemonSetCoverage {
+  let pod_spec = ds.spec.as_ref().and_then(|s| s.template.spec.as_ref());
+  let node_selector = pod_spec.and_then(|ps| ps.node_selector.as_ref());
+  let tolerations = pod_spec
+    .and_then(|ps| ps.tolerations.as_deref())
+    .unwrap_or_default();
+  let ds_uid = ds.metadata.uid.as_deref();
+
+  let eligible: Vec<&Node> = nodes
+    .iter()
+    .filter(|node| {
+      node_selector.is_none_or(|selector| {
+        selector.iter().all(|(k, v)| {
+          node
+            .metadata
+            .labels
+            .as_ref()
+            .and_then(|labels| labels.get(k))
+            == Some(v)
+        })
+      })
+    })
+    .filter(|node| {
+      node
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.taints.as_deref())
+        .unwrap_or_default()
+        .iter()
+        .filter(|taint| taint.effect != "PreferNoSchedule")
+        .all(|taint| tolerations.iter().any(|t| tolerates(t, taint)))
+    })
+    .collect();
+
+  let missing = eligible
+    .iter()
+    .filter_map(|node| node.metadata.name.clone())
+    .filter(|name| {
+      !pods.iter().any(|pod| {
+        pod.spec.as_ref().and_then(|s| s.node_name.as_ref()) == Some(name)
+          && pod
+            .metadata
+            .owner_references
+            .iter()
+            .flatten()
+            .any(|owner| Some(owner.uid.as_str()) == ds_uid)
+      })
+    })
+    .collect();
+
+  DaemonSetCoverage {
+    eligible: eligible.len(),
+    missing,
+  }
+}
+
+fn tolerates(toleration: &Toleration, taint: &Taint) -> bool {
+  let effect_matches = toleration
+    .effect
+    .as_deref()
+    .is_none_or(|effect| effect.is_empty() || effect == taint.effect);
+  let key_matches = match toleration.key.as_deref() {
+    None | Some("") => toleration.operator.as_deref() == Some("Exists"),
+    Some(key) => key == taint.key,
+  };
+  let value_matches = match toleration.operator.as_deref() {
+    Some("Exists") => true,
+    _ => {
+      toleration.value.as_deref().unwrap_or_default() == taint.value.as_deref().unwrap_or_default()
+    }
+  };
+  effect_matches && key_matches && value_matches
+}
+
+pub async fn get_daemon_set_coverage(nw: &Network<'_>, name: &str, namespace: &str) {
+  let ds_api: Api<DaemonSet> = Api::namespaced(nw.client.clone(), namespace);
+  let ds = match ds_api.get(name).await {
+    Ok(ds) => ds,
+    Err(e) => {
+      nw.handle_error(anyhow!("Failed to get daemonset {}. {}", name, e))
+        .await;
+      return;
+    }
+  };
+  let nodes_api: Api<Node> = Api::all(nw.client.clone());
+  let nodes = match nodes_api.list(&ListParams::default()).await {
+    Ok(list) => list.items,
+    Err(e) => {
+      nw.handle_error(anyhow!("Failed to get nodes. {}", e)).await;
+      return;
+    }
+  };
+  let selector = models::HasPodSelector::pod_label_selector(&KubeDaemonSet::from(ds.clone()));
+  let pods_api: Api<Pod> = Api::namespaced(nw.client.clone(), namespace);
+  let lp = match &selector {
+    Some(selector) => ListParams::default().labels(selector),
+    None => ListParams::default(),
+  };
+  let pods = match pods_api.list(&lp).await {
+    Ok(list) => list.items,
+    Err(e) => {
+      nw.handle_error(anyhow!("Failed to get pods for daemonset {}. {}", name, e))
+        .await;
+      return;
+    }
+  };
+
+  let mut app = nw.app.lock().await;
+  app.data.daemon_set_coverage = Some(daemon_set_coverage(&ds, &nodes, &pods));
+}
+
 static DAEMON_SETS_TITLE: &str = "DaemonSets";
 
 pub struct DaemonSetResource {}
@@ -119,6 +259,20 @@ pub struct DaemonSetResource {}
 #[async_trait]
 impl AppResource for DaemonSetResource {
   fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    if block == ActiveBlock::Describe {
+      let title = title_with_dual_style(
+        get_resource_title(
+          app,
+          DAEMON_SETS_TITLE,
+          get_describe_active(block),
+          app.data.daemon_sets.items.len(),
+        ),
+        crate::ui::utils::copy_and_escape_title_line(DAEMON_SETS_TITLE, app.palette),
+        app.palette,
+      );
+      draw_daemon_set_describe(f, app, area, title);
+      return;
+    }
     draw_resource_tab!(
       DAEMON_SETS_TITLE,
       block,
@@ -139,7 +293,7 @@ impl AppResource for DaemonSetResource {
   }
 }
 
-const DS_COLUMNS: [ColumnDef; 10] = [
+pub(crate) const DS_COLUMNS: [ColumnDef; 10] = [
   ColumnDef::all("Namespace", 20, 15, 12),
   ColumnDef::all("Name", 20, 18, 15),
   ColumnDef::all("Desired", 10, 8, 7),
@@ -153,11 +307,11 @@ const DS_COLUMNS: [ColumnDef; 10] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::DaemonSets);
   let title = get_resource_title(app, DAEMON_SETS_TITLE, "", app.data.daemon_sets.items.len());
 
   let tier = ViewTier::from_width(area.width, app.wide_columns);
-  let (headers, widths) = responsive_columns(&DS_COLUMNS, tier);
+  let columns = app.column_layout(ActiveBlock::DaemonSets, tier);
 
   draw_resource_block(
     f,
@@ -174,11 +328,11 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
         app.palette,
       ),
       resource: &mut app.data.daemon_sets,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      let mut cells = vec![
+      let cells = vec![
         Cell::from(c.namespace.to_owned()),
         Cell::from(c.name.to_owned()),
         Cell::from(c.desired.to_string()),
@@ -186,21 +340,56 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
         Cell::from(c.ready.to_string()),
         Cell::from(c.up_to_date.to_string()),
         Cell::from(c.available.to_string()),
+        Cell::from(c.node_selector.to_owned()),
+        Cell::from(c.containers.to_owned()),
+        Cell::from(c.age.to_owned()),
       ];
-      if tier >= ViewTier::Standard {
-        cells.push(Cell::from(c.node_selector.to_owned()));
-      }
-      if tier >= ViewTier::Wide {
-        cells.push(Cell::from(c.containers.to_owned()));
-      }
-      cells.push(Cell::from(c.age.to_owned()));
-      Row::new(cells).style(style_text(app.palette))
+      // fewer ready pods than scheduled nodes means some nodes are uncovered
+      let style = if c.ready < c.desired {
+        style_caution(app.palette)
+      } else {
+        style_text(app.palette)
+      };
+      columns.row(cells).style(style)
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
+/// Describe output with a one-line node coverage pane above it once the
+/// coverage has been fetched.
+fn draw_daemon_set_describe(f: &mut Frame<'_>, app: &mut App, area: Rect, title: Line<'_>) {
+  let Some(coverage) = app.data.daemon_set_coverage.clone() else {
+    draw_describe_block(f, app, area, title);
+    return;
+  };
+  let chunks = vertical_chunks(vec![Constraint::Length(3), Constraint::Min(0)], area);
+  let (text, style) = if coverage.missing.is_empty() {
+    (
+      format!("Pods running on all {} eligible nodes", coverage.eligible),
+      style_success(app.palette),
+    )
+  } else {
+    (
+      format!(
+        "No pod on {} of {} eligible nodes: {}",
+        coverage.missing.len(),
+        coverage.eligible,
+        coverage.missing.join(", ")
+      ),
+      style_failure(app.palette),
+    )
+  };
+  f.render_widget(
+    Paragraph::new(text)
+      .style(style)
+      .block(layout_block_default(" Node coverage ", app.palette)),
+    chunks[0],
+  );
+  draw_describe_block(f, app, chunks[1], title);
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -228,4 +417,147 @@ mod tests {
       }
     );
   }
+
+  #[test]
+  fn test_daemon_set_coverage_lists_uncovered_eligible_nodes() {
+    use std::collections::BTreeMap;
+
+    use k8s_openapi::{
+      api::{
+        apps::v1::DaemonSetSpec,
+        core::v1::{NodeSpec, PodSpec, PodTemplateSpec},
+      },
+      apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference},
+    };
+
+    let node = |name: &str, labels: &[(&str, &str)], taints: Vec<Taint>| Node {
+      metadata: ObjectMeta {
+        name: Some(name.into()),
+        labels: Some(
+          labels
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<BTreeMap<_, _>>(),
+        ),
+        ..Default::default()
+      },
+      spec: Some(NodeSpec {
+        taints: Some(taints),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+    let pod_on = |node_name: &str, owner_uid: &str| Pod {
+      metadata: ObjectMeta {
+        owner_references: Some(vec![OwnerReference {
+          uid: owner_uid.into(),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      },
+      spec: Some(PodSpec {
+        node_name: Some(node_name.into()),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+    let ds = DaemonSet {
+      metadata: ObjectMeta {
+        uid: Some("ds-uid".into()),
+        ..Default::default()
+      },
+      spec: Some(DaemonSetSpec {
+        template: PodTemplateSpec {
+          spec: Some(PodSpec {
+            node_selector: Some(BTreeMap::from([("os".to_string(), "linux".to_string())])),
+            tolerations: Some(vec![Toleration {
+              key: Some("dedicated".into()),
+              operator: Some("Exists".into()),
+              ..Default::default()
+            }]),
+            ..Default::default()
+          }),
+          ..Default::default()
+        },
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+    let taint = |key: &str, effect: &str| Taint {
+      key: key.into(),
+      effect: effect.into(),
+      ..Default::default()
+    };
+    let nodes = vec![
+      node("covered", &[("os", "linux")], vec![]),
+      node(
+        "uncovered",
+        &[("os", "linux")],
+        vec![taint("dedicated", "NoSchedule")],
+      ),
+      node(
+        "other-owner",
+        &[("os", "linux")],
+        vec![taint("soft", "PreferNoSchedule")],
+      ),
+      node("windows", &[("os", "windows")], vec![]),
+      node(
+        "tainted",
+        &[("os", "linux")],
+        vec![taint("gpu", "NoSchedule")],
+      ),
+    ];
+    let pods = vec![
+      pod_on("covered", "ds-uid"),
+      pod_on("other-owner", "other-uid"),
+    ];
+
+    assert_eq!(
+      daemon_set_coverage(&ds, &nodes, &pods),
+      DaemonSetCoverage {
+        eligible: 3,
+        missing: vec!["uncovered".into(), "other-owner".into()],
+      }
+    );
+  }
+
+  #[test]
+  fn test_tolerates() {
+    let taint = Taint {
+      key: "dedicated".into(),
+      value: Some("infra".into()),
+      effect: "NoSchedule".into(),
+      ..Default::default()
+    };
+    let toleration =
+      |key: Option<&str>, operator: &str, value: Option<&str>, effect: Option<&str>| Toleration {
+        key: key.map(Into::into),
+        operator: Some(operator.into()),
+        value: value.map(Into::into),
+        effect: effect.map(Into::into),
+        ..Default::default()
+      };
+
+    assert!(tolerates(
+      &toleration(Some("dedicated"), "Equal", Some("infra"), None),
+      &taint
+    ));
+    assert!(tolerates(
+      &toleration(Some("dedicated"), "Exists", None, Some("NoSchedule")),
+      &taint
+    ));
+    assert!(tolerates(&toleration(None, "Exists", None, None), &taint));
+    assert!(!tolerates(
+      &toleration(Some("dedicated"), "Equal", Some("apps"), None),
+      &taint
+    ));
+    assert!(!tolerates(
+      &toleration(Some("dedicated"), "Exists", None, Some("NoExecute")),
+      &taint
+    ));
+    assert!(!tolerates(
+      &toleration(Some("other"), "Exists", None, None),
+      &taint
+    ));
+  }
 }
@@ -1,9 +1,11 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use k8s_openapi::{api::apps::v1::Deployment, apimachinery::pkg::util::intstr::IntOrString};
+use kube::api::ObjectMeta;
 use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
+  layout::{Constraint, Rect},
+  text::{Line, Span},
+  widgets::{Cell, Paragraph},
   Frame,
 };
 
@@ -17,8 +19,10 @@ use crate::{
   network::Network,
   ui::utils::{
     action_hint, describe_yaml_and_logs_hint, draw_describe_block, draw_resource_block,
-    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, responsive_columns,
-    style_text, title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
+    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line,
+    layout_block_top_border, style_caution, style_failure, style_help, style_label, style_success,
+    style_text, title_style, title_with_dual_style, vertical_chunks, wide_hint, ColumnDef,
+    ResourceTableProps, ViewTier,
   },
 };
 
@@ -96,10 +100,144 @@ impl From<Deployment> for KubeDeployment {
   }
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RolloutState {
+  Progressing,
+  Complete,
+  /// The `Progressing` condition reports `ProgressDeadlineExceeded`.
+  Failed,
+}
+
+impl RolloutState {
+  pub fn label(self) -> &'static str {
+    match self {
+      RolloutState::Progressing => "Progressing",
+      RolloutState::Complete => "Complete",
+      RolloutState::Failed => "Failed",
+    }
+  }
+}
+
+/// Where a deployment's rollout is at, read from its status the way
+/// `kubectl rollout status` does.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeploymentRollout {
+  pub desired: i32,
+  pub updated: i32,
+  pub ready: i32,
+  pub available: i32,
+  pub unavailable: i32,
+  pub state: RolloutState,
+  /// Reason of the `Progressing` condition, e.g. `ReplicaSetUpdated`.
+  pub reason: String,
+  pub message: String,
+}
+
+impl KubeDeployment {
+  pub fn rollout(&self) -> DeploymentRollout {
+    let spec = self.k8s_obj.spec.as_ref();
+    let status = self.k8s_obj.status.clone().unwrap_or_default();
+    let desired = spec.and_then(|s| s.replicas).unwrap_or(1);
+    let updated = status.updated_replicas.unwrap_or_default();
+    let replicas = status.replicas.unwrap_or_default();
+    let available = status.available_replicas.unwrap_or_default();
+    let progressing = status
+      .conditions
+      .as_ref()
+      .and_then(|conditions| conditions.iter().find(|c| c.type_ == "Progressing"));
+    let reason = progressing
+      .and_then(|c| c.reason.clone())
+      .unwrap_or_default();
+
+    let observed = status.observed_generation.unwrap_or_default()
+      >= self.k8s_obj.metadata.generation.unwrap_or_default();
+    let (state, message) = if reason == "ProgressDeadlineExceeded" {
+      (
+        RolloutState::Failed,
+        format!("deployment {} exceeded its progress deadline", self.name),
+      )
+    } else if !observed {
+      (
+        RolloutState::Progressing,
+        "Waiting for the deployment spec update to be observed".to_owned(),
+      )
+    } else if updated < desired {
+      (
+        RolloutState::Progressing,
+        format!(
+          "Waiting for rollout to finish: {} out of {} new replicas have been updated",
+          updated, desired
+        ),
+      )
+    } else if replicas > updated {
+      (
+        RolloutState::Progressing,
+        format!(
+          "Waiting for rollout to finish: {} old replicas are pending termination",
+          replicas - updated
+        ),
+      )
+    } else if available < updated {
+      (
+        RolloutState::Progressing,
+        format!(
+          "Waiting for rollout to finish: {} of {} updated replicas are available",
+          available, updated
+        ),
+      )
+    } else {
+      (
+        RolloutState::Complete,
+        format!("deployment {} successfully rolled out", self.name),
+      )
+    };
+
+    DeploymentRollout {
+      desired,
+      updated,
+      ready: status.ready_replicas.unwrap_or_default(),
+      available,
+      unavailable: status.unavailable_replicas.unwrap_or_default(),
+      state,
+      reason,
+      message,
+    }
+  }
+}
+
 impl Named for KubeDeployment {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
+
+  /// Creation or the last condition update, which a rollout bumps.
+  fn get_changed(&self) -> Option<DateTime<Utc>> {
+    let conditions = self
+      .k8s_obj
+      .status
+      .as_ref()
+      .and_then(|s| s.conditions.as_ref())
+      .into_iter()
+      .flatten()
+      .flat_map(|c| [c.last_update_time.as_ref(), c.last_transition_time.as_ref()])
+      .flatten();
+    utils::latest_time(
+      self
+        .k8s_obj
+        .metadata
+        .creation_timestamp
+        .iter()
+        .chain(conditions),
+    )
+  }
 }
 
 impl KubeResource<Deployment> for KubeDeployment {
@@ -127,6 +265,18 @@ pub struct DeploymentResource {}
 #[async_trait]
 impl AppResource for DeploymentResource {
   fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    // The ReplicaSet drill-down, and describe/yaml opened from it, live in this tab.
+    match block {
+      ActiveBlock::ReplicaSets => {
+        return super::replicasets::draw_block_as_sub(f, app, area);
+      }
+      ActiveBlock::Describe | ActiveBlock::Yaml | ActiveBlock::Diff
+        if app.get_prev_route().active_block == ActiveBlock::ReplicaSets =>
+      {
+        return super::replicasets::ReplicaSetResource::render(block, f, app, area);
+      }
+      _ => {}
+    }
     draw_resource_tab!(
       DEPLOYMENTS_TITLE,
       block,
@@ -147,7 +297,7 @@ impl AppResource for DeploymentResource {
   }
 }
 
-const DEPLOY_COLUMNS: [ColumnDef; 9] = [
+pub(crate) const DEPLOY_COLUMNS: [ColumnDef; 9] = [
   ColumnDef::all("Namespace", 25, 20, 15),
   ColumnDef::all("Name", 35, 30, 20),
   ColumnDef::all("Ready", 10, 10, 8),
@@ -160,11 +310,19 @@ const DEPLOY_COLUMNS: [ColumnDef; 9] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let area = match app.data.deployments.get_selected_item() {
+    Some(deployment) => {
+      let chunks = vertical_chunks(vec![Constraint::Min(0), Constraint::Length(4)], area);
+      draw_rollout_pane(f, app, &deployment.rollout(), chunks[1]);
+      chunks[0]
+    }
+    None => area,
+  };
+  let load = app.view_load(ActiveBlock::Deployments);
   let title = get_resource_title(app, DEPLOYMENTS_TITLE, "", app.data.deployments.items.len());
 
   let tier = ViewTier::from_width(area.width, app.wide_columns);
-  let (headers, widths) = responsive_columns(&DEPLOY_COLUMNS, tier);
+  let columns = app.column_layout(ActiveBlock::Deployments, tier);
 
   draw_resource_block(
     f,
@@ -173,37 +331,80 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       title,
       inline_help: help_bold_line(
         format!(
-          "{} · {} · {}",
+          "{} · {} · {} · {}",
           action_hint("pods", DEFAULT_KEYBINDING.submit.key),
+          action_hint("replicasets", DEFAULT_KEYBINDING.show_replica_sets.key),
           describe_yaml_and_logs_hint(),
           wide_hint()
         ),
         app.palette,
       ),
       resource: &mut app.data.deployments,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      let mut cells = vec![
+      let style = if c.rollout().state == RolloutState::Failed {
+        style_failure(app.palette)
+      } else {
+        style_text(app.palette)
+      };
+      let cells = vec![
         Cell::from(c.namespace.to_owned()),
         Cell::from(c.name.to_owned()),
         Cell::from(c.ready.to_owned()),
         Cell::from(c.updated.to_string()),
         Cell::from(c.available.to_string()),
+        Cell::from(c.strategy.to_owned()),
+        Cell::from(c.max_surge.to_owned()),
+        Cell::from(c.max_unavailable.to_owned()),
+        Cell::from(c.age.to_owned()),
       ];
-      if tier >= ViewTier::Standard {
-        cells.push(Cell::from(c.strategy.to_owned()));
-      }
-      if tier >= ViewTier::Wide {
-        cells.push(Cell::from(c.max_surge.to_owned()));
-        cells.push(Cell::from(c.max_unavailable.to_owned()));
-      }
-      cells.push(Cell::from(c.age.to_owned()));
-      Row::new(cells).style(style_text(app.palette))
+      columns.row(cells).style(style)
     },
     app.palette,
-    is_loading,
+    load,
+  );
+}
+
+/// The selected deployment's rollout: its state and `kubectl rollout status`
+/// message, then the replica counts.
+fn draw_rollout_pane(f: &mut Frame<'_>, app: &App, rollout: &DeploymentRollout, area: Rect) {
+  let palette = app.palette;
+  let style = match rollout.state {
+    RolloutState::Progressing => style_caution(palette),
+    RolloutState::Complete => style_success(palette),
+    RolloutState::Failed => style_failure(palette),
+  };
+  let state = rollout.state.label();
+  let state = if rollout.reason.is_empty() {
+    state.to_owned()
+  } else {
+    format!("{} ({})", state, rollout.reason)
+  };
+  let lines = vec![
+    Line::from(vec![
+      Span::styled(format!("{:<10} ", "Status:"), style_label(palette)),
+      Span::styled(state, style),
+      Span::styled(format!("  {}", rollout.message), style_help(palette)),
+    ]),
+    Line::from(vec![
+      Span::styled(format!("{:<10} ", "Replicas:"), style_label(palette)),
+      Span::styled(
+        format!(
+          "{} desired · {} updated · {} ready · {} available · {} unavailable",
+          rollout.desired, rollout.updated, rollout.ready, rollout.available, rollout.unavailable
+        ),
+        style_text(palette),
+      ),
+    ]),
+  ];
+  f.render_widget(
+    Paragraph::new(lines).block(layout_block_top_border(
+      Line::from(title_style(" Rollout ", palette)),
+      palette,
+    )),
+    area,
   );
 }
 
@@ -236,6 +437,64 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_deployment_rollout_states() {
+    let (_, deployment_list): (Vec<KubeDeployment>, Vec<Deployment>) =
+      convert_resource_from_file("deployments");
+    let with_status = |replicas: i32, updated: i32, available: i32, reason: &str| {
+      let mut deployment = deployment_list[0].clone();
+      deployment.spec.as_mut().unwrap().replicas = Some(3);
+      let status = deployment.status.as_mut().unwrap();
+      status.replicas = Some(replicas);
+      status.updated_replicas = Some(updated);
+      status.available_replicas = Some(available);
+      status.unavailable_replicas = Some(replicas - available);
+      let progressing = status
+        .conditions
+        .as_mut()
+        .unwrap()
+        .iter_mut()
+        .find(|c| c.type_ == "Progressing")
+        .unwrap();
+      progressing.reason = Some(reason.into());
+      KubeDeployment::from(deployment).rollout()
+    };
+
+    let complete = with_status(3, 3, 3, "NewReplicaSetAvailable");
+    assert_eq!(complete.state, RolloutState::Complete);
+    assert_eq!(
+      complete.message,
+      "deployment metrics-server successfully rolled out"
+    );
+
+    let updating = with_status(4, 2, 2, "ReplicaSetUpdated");
+    assert_eq!(updating.state, RolloutState::Progressing);
+    assert_eq!(
+      updating.message,
+      "Waiting for rollout to finish: 2 out of 3 new replicas have been updated"
+    );
+    assert_eq!(updating.unavailable, 2);
+
+    let terminating = with_status(4, 3, 3, "ReplicaSetUpdated");
+    assert_eq!(
+      terminating.message,
+      "Waiting for rollout to finish: 1 old replicas are pending termination"
+    );
+
+    let stalled = with_status(4, 2, 2, "ProgressDeadlineExceeded");
+    assert_eq!(stalled.state, RolloutState::Failed);
+    assert_eq!(stalled.reason, "ProgressDeadlineExceeded");
+
+    let mut deployment = deployment_list[0].clone();
+    deployment.metadata.generation = Some(2);
+    let unobserved = KubeDeployment::from(deployment).rollout();
+    assert_eq!(unobserved.state, RolloutState::Progressing);
+    assert_eq!(
+      unobserved.message,
+      "Waiting for the deployment spec update to be observed"
+    );
+  }
+
   #[test]
   fn test_deployment_pod_label_selector() {
     let (deployments, _): (Vec<KubeDeployment>, Vec<_>) = convert_resource_from_file("deployments");
@@ -0,0 +1,116 @@
+//! Recent `kubectl describe` output, so reopening an unchanged object is
+//! instant. Entries are keyed by resourceVersion: once a poll brings a newer
+//! version of the object, the old output is no longer looked up.
+use std::time::{Duration, Instant};
+
+/// How long an entry is served. Describe output also lists events, which
+/// change without touching the object's resourceVersion.
+const DESCRIBE_CACHE_TTL: Duration = Duration::from_secs(60);
+const DESCRIBE_CACHE_CAPACITY: usize = 32;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DescribeKey {
+  pub kind: String,
+  pub namespace: Option<String>,
+  pub name: String,
+  pub resource_version: String,
+}
+
+impl DescribeKey {
+  fn same_object(&self, other: &DescribeKey) -> bool {
+    self.kind == other.kind && self.namespace == other.namespace && self.name == other.name
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct DescribeCache {
+  entries: Vec<(DescribeKey, String, Instant)>,
+}
+
+impl DescribeCache {
+  pub fn get(&self, key: &DescribeKey, now: Instant) -> Option<&str> {
+    self
+      .entries
+      .iter()
+      .find(|(k, _, stored)| k == key && now.duration_since(*stored) < DESCRIBE_CACHE_TTL)
+      .map(|(_, out, _)| out.as_str())
+  }
+
+  /// Store the output, replacing older versions of the same object. The
+  /// oldest entries are dropped beyond the capacity.
+  pub fn insert(&mut self, key: DescribeKey, out: String, now: Instant) {
+    self.invalidate(&key);
+    self
+      .entries
+      .retain(|(_, _, stored)| now.duration_since(*stored) < DESCRIBE_CACHE_TTL);
+    if self.entries.len() >= DESCRIBE_CACHE_CAPACITY {
+      self.entries.remove(0);
+    }
+    self.entries.push((key, out, now));
+  }
+
+  /// Forget every version of the object, e.g. to force a refetch.
+  pub fn invalidate(&mut self, key: &DescribeKey) {
+    self.entries.retain(|(k, _, _)| !k.same_object(key));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn key(name: &str, resource_version: &str) -> DescribeKey {
+    DescribeKey {
+      kind: "deployment".into(),
+      namespace: Some("default".into()),
+      name: name.into(),
+      resource_version: resource_version.into(),
+    }
+  }
+
+  #[test]
+  fn test_describe_cache_hits_same_version_only() {
+    let now = Instant::now();
+    let mut cache = DescribeCache::default();
+    cache.insert(key("web", "1"), "Name: web".into(), now);
+
+    assert_eq!(cache.get(&key("web", "1"), now), Some("Name: web"));
+    assert_eq!(cache.get(&key("web", "2"), now), None);
+    assert_eq!(cache.get(&key("api", "1"), now), None);
+  }
+
+  #[test]
+  fn test_describe_cache_expires_entries() {
+    let now = Instant::now();
+    let mut cache = DescribeCache::default();
+    cache.insert(key("web", "1"), "Name: web".into(), now);
+
+    assert_eq!(cache.get(&key("web", "1"), now + DESCRIBE_CACHE_TTL), None);
+  }
+
+  #[test]
+  fn test_describe_cache_replaces_old_versions_and_invalidates() {
+    let now = Instant::now();
+    let mut cache = DescribeCache::default();
+    cache.insert(key("web", "1"), "v1".into(), now);
+    cache.insert(key("web", "2"), "v2".into(), now);
+    assert_eq!(cache.entries.len(), 1);
+    assert_eq!(cache.get(&key("web", "2"), now), Some("v2"));
+
+    cache.invalidate(&key("web", "2"));
+    assert_eq!(cache.get(&key("web", "2"), now), None);
+  }
+
+  #[test]
+  fn test_describe_cache_drops_oldest_beyond_capacity() {
+    let now = Instant::now();
+    let mut cache = DescribeCache::default();
+    for i in 0..=DESCRIBE_CACHE_CAPACITY {
+      cache.insert(key(&format!("web-{i}"), "1"), i.to_string(), now);
+    }
+
+    assert_eq!(cache.entries.len(), DESCRIBE_CACHE_CAPACITY);
+    assert_eq!(cache.get(&key("web-0", "1"), now), None);
+    assert_eq!(cache.get(&key("web-1", "1"), now), Some("1"));
+  }
+}
@@ -0,0 +1,164 @@
+//! Drift view: a line diff between a resource's last-applied configuration
+//! (the annotation `kubectl apply` writes) and the live object.
+use serde_json::Value;
+use similar::TextDiff;
+
+pub const LAST_APPLIED_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+/// Metadata the API server owns. None of it is ever in an applied manifest, so
+/// it is dropped from the live side to keep the diff down to real drift.
+const SERVER_METADATA_FIELDS: [&str; 6] = [
+  "managedFields",
+  "resourceVersion",
+  "uid",
+  "creationTimestamp",
+  "generation",
+  "selfLink",
+];
+
+/// Render a unified diff of the last-applied configuration (`-`) against the
+/// live object (`+`). Returns an explanatory message instead when the
+/// annotation is missing or unreadable, or when nothing has drifted.
+pub fn last_applied_diff(live: &Value) -> String {
+  let Some(raw) = live
+    .pointer("/metadata/annotations")
+    .and_then(|annotations| annotations.get(LAST_APPLIED_ANNOTATION))
+    .and_then(Value::as_str)
+  else {
+    return format!(
+      "No {} annotation on this resource.\n\nIt was never applied with `kubectl apply`, or it is managed with server-side apply, so there is no last-applied state to compare against.",
+      LAST_APPLIED_ANNOTATION
+    );
+  };
+  let applied: Value = match serde_json::from_str(raw) {
+    Ok(applied) => applied,
+    Err(e) => {
+      return format!(
+        "Could not parse the {} annotation: {}",
+        LAST_APPLIED_ANNOTATION, e
+      )
+    }
+  };
+
+  let applied_yaml = to_yaml(&applied);
+  let live_yaml = to_yaml(&without_server_fields(live));
+  if applied_yaml == live_yaml {
+    return "No differences between the last-applied configuration and the live object.".into();
+  }
+  TextDiff::from_lines(&applied_yaml, &live_yaml)
+    .unified_diff()
+    .context_radius(3)
+    .header("last-applied", "live")
+    .to_string()
+}
+
+fn without_server_fields(live: &Value) -> Value {
+  let mut live = live.clone();
+  if let Some(obj) = live.as_object_mut() {
+    obj.remove("status");
+  }
+  if let Some(metadata) = live.get_mut("metadata").and_then(Value::as_object_mut) {
+    for field in SERVER_METADATA_FIELDS {
+      metadata.remove(field);
+    }
+    let annotations_empty = metadata
+      .get_mut("annotations")
+      .and_then(Value::as_object_mut)
+      .map(|annotations| {
+        annotations.remove(LAST_APPLIED_ANNOTATION);
+        annotations.is_empty()
+      })
+      .unwrap_or(false);
+    if annotations_empty {
+      metadata.remove("annotations");
+    }
+  }
+  live
+}
+
+fn to_yaml(value: &Value) -> String {
+  serde_saphyr::to_string(value).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  fn with_last_applied(applied: &Value, mut live: Value) -> Value {
+    live["metadata"]["annotations"][LAST_APPLIED_ANNOTATION] = Value::String(applied.to_string());
+    live
+  }
+
+  #[test]
+  fn test_last_applied_diff_without_annotation_explains() {
+    let live = json!({ "metadata": { "name": "web" } });
+    let out = last_applied_diff(&live);
+    assert!(out.starts_with("No kubectl.kubernetes.io/last-applied-configuration annotation"));
+  }
+
+  #[test]
+  fn test_last_applied_diff_ignores_server_fields() {
+    let applied = json!({
+      "apiVersion": "v1",
+      "kind": "ConfigMap",
+      "metadata": { "name": "web" },
+      "data": { "key": "value" }
+    });
+    let live = with_last_applied(
+      &applied,
+      json!({
+        "apiVersion": "v1",
+        "kind": "ConfigMap",
+        "metadata": {
+          "name": "web",
+          "uid": "1234",
+          "resourceVersion": "42",
+          "creationTimestamp": "2024-01-01T00:00:00Z",
+          "managedFields": [{ "manager": "kubectl" }]
+        },
+        "data": { "key": "value" }
+      }),
+    );
+    assert_eq!(
+      last_applied_diff(&live),
+      "No differences between the last-applied configuration and the live object."
+    );
+  }
+
+  #[test]
+  fn test_last_applied_diff_shows_drift() {
+    let applied = json!({
+      "apiVersion": "apps/v1",
+      "kind": "Deployment",
+      "metadata": { "name": "web" },
+      "spec": { "replicas": 2 }
+    });
+    let live = with_last_applied(
+      &applied,
+      json!({
+        "apiVersion": "apps/v1",
+        "kind": "Deployment",
+        "metadata": { "name": "web", "annotations": { "team": "a" } },
+        "spec": { "replicas": 5 },
+        "status": { "readyReplicas": 5 }
+      }),
+    );
+    let out = last_applied_diff(&live);
+    assert!(out.starts_with("--- last-applied\n+++ live\n"), "{out}");
+    assert!(out.contains("\n-  replicas: 2\n"), "{out}");
+    assert!(out.contains("\n+  replicas: 5\n"), "{out}");
+    assert!(out.contains("\n+    team: a\n"), "{out}");
+    // The annotation itself and status are not part of the comparison.
+    assert!(!out.contains(LAST_APPLIED_ANNOTATION), "{out}");
+    assert!(!out.contains("readyReplicas"), "{out}");
+  }
+
+  #[test]
+  fn test_last_applied_diff_reports_unparseable_annotation() {
+    let mut live = json!({ "metadata": { "name": "web" } });
+    live["metadata"]["annotations"][LAST_APPLIED_ANNOTATION] = Value::String("{not json".into());
+    assert!(last_applied_diff(&live).starts_with("Could not parse"));
+  }
+}
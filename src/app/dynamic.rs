@@ -9,6 +9,7 @@ use anyhow::anyhow;
 use async_trait::async_trait;
 use chrono::Utc;
 use kube::{
+  api::ObjectMeta,
   core::DynamicObject,
   discovery::{ApiResource, Scope},
   ResourceExt,
@@ -82,6 +83,14 @@ impl Named for KubeDynamicResource {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    self.namespace.as_deref()
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<DynamicObject> for KubeDynamicResource {
@@ -175,13 +184,15 @@ pub fn api_resource_for_block(
     apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet},
     batch::v1::{CronJob, Job},
     core::v1::{
-      ConfigMap, Event, Node, PersistentVolume, PersistentVolumeClaim, Pod, ReplicationController,
-      Secret, Service, ServiceAccount,
+      ConfigMap, Event, LimitRange, Node, PersistentVolume, PersistentVolumeClaim, Pod,
+      ReplicationController, ResourceQuota, Secret, Service, ServiceAccount,
     },
     networking::v1::{Ingress, NetworkPolicy},
+    policy::v1::PodDisruptionBudget,
     rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding},
     storage::v1::StorageClass,
   };
+  use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
 
   let result = match block {
     ActiveBlock::Pods => (ApiResource::erase::<Pod>(&()), Scope::Namespaced),
@@ -208,6 +219,12 @@ pub fn api_resource_for_block(
     ActiveBlock::NetworkPolicies => (ApiResource::erase::<NetworkPolicy>(&()), Scope::Namespaced),
     ActiveBlock::ServiceAccounts => (ApiResource::erase::<ServiceAccount>(&()), Scope::Namespaced),
     ActiveBlock::Events => (ApiResource::erase::<Event>(&()), Scope::Namespaced),
+    ActiveBlock::ResourceQuotas => (ApiResource::erase::<ResourceQuota>(&()), Scope::Namespaced),
+    ActiveBlock::LimitRanges => (ApiResource::erase::<LimitRange>(&()), Scope::Namespaced),
+    ActiveBlock::PodDisruptionBudgets => (
+      ApiResource::erase::<PodDisruptionBudget>(&()),
+      Scope::Namespaced,
+    ),
     ActiveBlock::Nodes => (ApiResource::erase::<Node>(&()), Scope::Cluster),
     ActiveBlock::PersistentVolumes => (ApiResource::erase::<PersistentVolume>(&()), Scope::Cluster),
     ActiveBlock::StorageClasses => (ApiResource::erase::<StorageClass>(&()), Scope::Cluster),
@@ -216,6 +233,10 @@ pub fn api_resource_for_block(
       ApiResource::erase::<ClusterRoleBinding>(&()),
       Scope::Cluster,
     ),
+    ActiveBlock::CustomResourceDefinitions => (
+      ApiResource::erase::<CustomResourceDefinition>(&()),
+      Scope::Cluster,
+    ),
     ActiveBlock::DynamicResource => {
       let kind = dynamic_kind?;
       (kind.api_resource.clone(), kind.scope.clone())
@@ -294,7 +315,7 @@ const DYN_NAMESPACED_COLUMNS: [ColumnDef; 3] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::DynamicResource);
   let (title, scope) = if let Some(res) = &app.data.selected.dynamic_kind {
     (res.kind.as_str(), res.scope.clone())
   } else {
@@ -335,7 +356,7 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       rows.style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -1,26 +1,30 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::anyhow;
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use k8s_openapi::{
   api::core::v1::Event,
   apimachinery::pkg::apis::meta::v1::{MicroTime, Time},
 };
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
-};
+use kube::api::{Api, ListParams, ObjectMeta};
+use ratatui::{layout::Rect, widgets::Cell, Frame};
+
+use tokio::sync::watch;
 
 use super::{
+  key_binding::DEFAULT_KEYBINDING,
+  load_state::LoadState,
   models::{AppResource, KubeResource, Named},
-  utils, ActiveBlock, App,
+  utils, ActiveBlock, App, RouteId,
 };
 use crate::{
   draw_resource_tab,
-  network::Network,
+  network::{stream::IoStreamEvent, IoEvent, Network},
   ui::utils::{
-    describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, responsive_columns, style_text,
-    title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+    action_hint, describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block,
+    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, style_text,
+    style_warning, title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -28,11 +32,16 @@ use crate::{
 pub struct KubeEvent {
   pub name: String,
   pub namespace: String,
+  /// `Normal` or `Warning`.
+  pub type_: String,
   pub involved_kind: String,
+  pub involved_name: String,
   pub reason: String,
   pub message: String,
   pub count: i32,
   pub age: String,
+  first_seen: Option<Time>,
+  last_seen: Option<Time>,
   k8s_obj: Event,
 }
 
@@ -42,21 +51,97 @@ impl From<Event> for KubeEvent {
       .count
       .or_else(|| event.series.as_ref().and_then(|series| series.count))
       .unwrap_or_default();
-    let age = utils::to_age(event_timestamp(&event).as_ref(), Utc::now());
+    let last_seen = event_timestamp(&event);
+    let first_seen = event
+      .first_timestamp
+      .clone()
+      .or_else(|| event.event_time.as_ref().map(micro_time_to_time))
+      .or_else(|| event.metadata.creation_timestamp.clone());
 
     KubeEvent {
       name: event.metadata.name.clone().unwrap_or_default(),
       namespace: event.metadata.namespace.clone().unwrap_or_default(),
+      type_: event.type_.clone().unwrap_or_default(),
       involved_kind: event.involved_object.kind.clone().unwrap_or_default(),
+      involved_name: event.involved_object.name.clone().unwrap_or_default(),
       reason: event.reason.clone().unwrap_or_default(),
       message: event.message.clone().unwrap_or_default(),
       count,
-      age,
+      age: utils::to_age(last_seen.as_ref(), Utc::now()),
+      first_seen,
+      last_seen,
       k8s_obj: utils::sanitize_obj(event),
     }
   }
 }
 
+impl KubeEvent {
+  pub fn is_warning(&self) -> bool {
+    self.type_ == "Warning"
+  }
+
+  /// When the event was last seen, if known.
+  pub fn last_seen(&self) -> Option<&Time> {
+    self.last_seen.as_ref()
+  }
+
+  /// The count with the span it was seen over, like `12 over 5m` in the
+  /// `(x12 over 5m)` of `kubectl describe`. Just the count when the event
+  /// was seen once or its span is unknown.
+  pub fn occurrences(&self) -> String {
+    match (&self.first_seen, &self.last_seen) {
+      (Some(first), Some(last)) if self.count > 1 && first.0 < last.0 => {
+        let span = utils::time_to_chrono(last).signed_duration_since(utils::time_to_chrono(first));
+        format!(
+          "{} over {}",
+          self.count,
+          utils::duration_to_age(span, false)
+        )
+      }
+      _ => self.count.to_string(),
+    }
+  }
+}
+
+/// Fold events about the same object with the same reason into one row, like
+/// `kubectl` does. The row counts every occurrence, spans from the earliest to
+/// the latest one and shows the latest message. Rows keep the order in which
+/// their first event was listed.
+pub fn group_events(events: Vec<KubeEvent>) -> Vec<KubeEvent> {
+  let mut groups: Vec<KubeEvent> = vec![];
+  let mut index: HashMap<(String, String, String, String), usize> = HashMap::new();
+  for event in events {
+    let key = (
+      event.namespace.clone(),
+      event.involved_kind.clone(),
+      event.involved_name.clone(),
+      event.reason.clone(),
+    );
+    let Some(&i) = index.get(&key) else {
+      index.insert(key, groups.len());
+      groups.push(event);
+      continue;
+    };
+    let group = &mut groups[i];
+    // an event without a count still happened once
+    let count = group.count.max(1) + event.count.max(1);
+    let first_seen = earliest(group.first_seen.take(), event.first_seen.clone());
+    if event.last_seen.as_ref().map(|t| t.0) > group.last_seen.as_ref().map(|t| t.0) {
+      *group = event;
+    }
+    group.count = count;
+    group.first_seen = first_seen;
+  }
+  groups
+}
+
+fn earliest(a: Option<Time>, b: Option<Time>) -> Option<Time> {
+  match (a, b) {
+    (Some(a), Some(b)) => Some(if b.0 < a.0 { b } else { a }),
+    (a, b) => a.or(b),
+  }
+}
+
 fn event_timestamp(event: &Event) -> Option<Time> {
   event
     .series
@@ -75,6 +160,26 @@ impl Named for KubeEvent {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
+
+  /// Creation or the last time the event was seen again.
+  fn get_changed(&self) -> Option<DateTime<Utc>> {
+    utils::latest_time(
+      self
+        .k8s_obj
+        .metadata
+        .creation_timestamp
+        .iter()
+        .chain(&self.k8s_obj.last_timestamp),
+    )
+  }
 }
 
 impl KubeResource<Event> for KubeEvent {
@@ -83,6 +188,225 @@ impl KubeResource<Event> for KubeEvent {
   }
 }
 
+/// Events about the pod whose containers are shown, listed in its detail.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodEvents {
+  pub namespace: String,
+  pub pod: String,
+  /// Grouped like the events view, newest first.
+  pub events: Vec<KubeEvent>,
+}
+
+impl PodEvents {
+  pub fn new(namespace: &str, pod: &str, events: Vec<KubeEvent>) -> Self {
+    let mut events = group_events(events);
+    events.sort_by(|a, b| {
+      b.last_seen
+        .as_ref()
+        .map(|t| t.0)
+        .cmp(&a.last_seen.as_ref().map(|t| t.0))
+    });
+    PodEvents {
+      namespace: namespace.into(),
+      pod: pod.into(),
+      events,
+    }
+  }
+
+  pub fn is_for(&self, namespace: &str, pod: &str) -> bool {
+    self.namespace == namespace && self.pod == pod
+  }
+}
+
+/// Fetch the events about one pod for its containers view, narrowed
+/// server-side to the pod so a busy namespace is not listed.
+pub async fn get_pod_events(nw: &Network<'_>, name: &str, namespace: &str) {
+  let api: Api<Event> = Api::namespaced(nw.client.clone(), namespace);
+  let lp = ListParams::default().fields(&format!(
+    "involvedObject.kind=Pod,involvedObject.name={}",
+    name
+  ));
+  let events = match api.list(&lp).await {
+    Ok(list) => list.items.into_iter().map(KubeEvent::from).collect(),
+    Err(e) => {
+      nw.handle_error(anyhow!("Failed to get events for pod {}. {}", name, e))
+        .await;
+      return;
+    }
+  };
+
+  let mut app = nw.app.lock().await;
+  app.data.pod_events = Some(PodEvents::new(namespace, name, events));
+}
+
+/// How long the API server keeps events by default (its `--event-ttl`).
+/// Watched events last seen longer ago than this are dropped.
+const EVENT_TTL: chrono::Duration = chrono::Duration::hours(1);
+
+/// Most events kept while the events view is watched; the ones seen least
+/// recently go first.
+const MAX_WATCHED_EVENTS: usize = 2000;
+
+/// The events namespace or, with `None`, the whole cluster, watched while
+/// the events view is shown.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WatchedEvents {
+  pub namespace: Option<String>,
+}
+
+/// Every event the view knows of, one row each, as listed and then as the
+/// watch reports them. The view shows them grouped or not.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EventBuffer {
+  events: Vec<KubeEvent>,
+}
+
+impl EventBuffer {
+  /// Start over from a fresh list.
+  pub fn replace(&mut self, events: Vec<KubeEvent>) {
+    self.events = events;
+  }
+
+  /// Add an event the watch reported, or update one seen before, then drop
+  /// what has aged out.
+  pub fn upsert(&mut self, event: KubeEvent, now: DateTime<Utc>) {
+    match self
+      .events
+      .iter_mut()
+      .find(|known| known.name == event.name && known.namespace == event.namespace)
+    {
+      Some(known) => *known = event,
+      None => self.events.push(event),
+    }
+    self.prune(now);
+  }
+
+  /// Drop events past the event TTL, then the least recently seen ones past
+  /// [`MAX_WATCHED_EVENTS`].
+  pub fn prune(&mut self, now: DateTime<Utc>) {
+    let cutoff = now - EVENT_TTL;
+    self.events.retain(|event| {
+      event
+        .last_seen
+        .as_ref()
+        .is_none_or(|seen| utils::time_to_chrono(seen) >= cutoff)
+    });
+    if self.events.len() > MAX_WATCHED_EVENTS {
+      let mut by_age: Vec<usize> = (0..self.events.len()).collect();
+      by_age.sort_by_key(|&i| self.events[i].last_seen.as_ref().map(|t| t.0));
+      let dropped: HashSet<usize> = by_age[..self.events.len() - MAX_WATCHED_EVENTS]
+        .iter()
+        .copied()
+        .collect();
+      let mut index = 0;
+      self.events.retain(|_| {
+        index += 1;
+        !dropped.contains(&(index - 1))
+      });
+    }
+  }
+
+  /// The rows of the view, with ages as of `now`.
+  pub fn rows(&self, grouped: bool, now: DateTime<Utc>) -> Vec<KubeEvent> {
+    let rows: Vec<KubeEvent> = self
+      .events
+      .iter()
+      .cloned()
+      .map(|mut event| {
+        event.age = utils::to_age(event.last_seen.as_ref(), now);
+        event
+      })
+      .collect();
+    if grouped {
+      group_events(rows)
+    } else {
+      rows
+    }
+  }
+}
+
+impl App {
+  /// The events being watched, if a watch is on.
+  pub fn watched_events(&self) -> Option<WatchedEvents> {
+    self.event_watch_tx.borrow().clone()
+  }
+
+  /// A receiver that sees the watched events change, so a watch can tell
+  /// when it is no longer wanted.
+  pub fn event_watch_rx(&self) -> watch::Receiver<Option<WatchedEvents>> {
+    self.event_watch_tx.subscribe()
+  }
+
+  /// End the events watch, if any; polling lists events again.
+  pub fn stop_event_watch(&self) {
+    self.event_watch_tx.send_replace(None);
+  }
+
+  /// Watch the events of the selected namespace while the events view is
+  /// shown, and stop once it is left. A watch that gave up is not started
+  /// again until the view or namespace changes.
+  pub async fn sync_event_watch(&mut self) {
+    let route = self.get_current_route();
+    // without a stream thread there is nothing to watch with, so keep polling
+    let wanted = (self.io_stream_tx.is_some()
+      && route.id == RouteId::Home
+      && route.active_block == ActiveBlock::Events)
+      .then(|| WatchedEvents {
+        namespace: self.data.selected.ns.clone(),
+      });
+    if wanted.is_some() && wanted == self.data.event_watch_given_up {
+      return;
+    }
+    self.data.event_watch_given_up = None;
+    if wanted == self.watched_events() {
+      return;
+    }
+    self.event_watch_tx.send_replace(wanted.clone());
+    if let Some(target) = wanted {
+      self
+        .data
+        .load_states
+        .entry(ActiveBlock::Events)
+        .or_insert(LoadState::Loading);
+      self
+        .dispatch_stream(IoStreamEvent::WatchEvents {
+          namespace: target.namespace,
+        })
+        .await;
+    }
+  }
+
+  /// Show the buffered events, grouped or not, with fresh ages.
+  pub fn refresh_event_rows(&mut self) {
+    let now = Utc::now();
+    if self.watched_events().is_some() {
+      self.data.event_buffer.prune(now);
+    }
+    let rows = self.data.event_buffer.rows(self.data.group_events, now);
+    self.data.events.set_items(rows);
+  }
+
+  /// The events a watch has just listed.
+  pub fn set_watched_events(&mut self, events: Vec<KubeEvent>) {
+    self.data.event_buffer.replace(events);
+    self.refresh_event_rows();
+    let errors = self.errors_recorded();
+    self.finish_load(IoEvent::GetEvents, errors);
+  }
+
+  /// An event the watch saw added or changed.
+  pub fn add_watched_event(&mut self, event: KubeEvent) {
+    self.data.event_buffer.upsert(event, Utc::now());
+    self.refresh_event_rows();
+  }
+
+  /// The watch gave up on `target`; polling takes over.
+  pub fn give_up_event_watch(&mut self, target: WatchedEvents) {
+    self.stop_event_watch();
+    self.data.event_watch_given_up = Some(target);
+  }
+}
+
 static EVENTS_TITLE: &str = "Events";
 
 pub struct EventResource {}
@@ -106,50 +430,72 @@ impl AppResource for EventResource {
     let items: Vec<KubeEvent> = nw.get_namespaced_resources(Event::into).await;
 
     let mut app = nw.app.lock().await;
-    app.data.events.set_items(items);
+    app.data.event_buffer.replace(items);
+    app.refresh_event_rows();
   }
 }
 
-const EVENT_COLUMNS: [ColumnDef; 7] = [
+pub(crate) const EVENT_COLUMNS: [ColumnDef; 7] = [
   ColumnDef::all("Namespace", 12, 12, 12),
   ColumnDef::all("Name", 18, 18, 18),
   ColumnDef::all("Involved Kind", 12, 12, 12),
   ColumnDef::all("Reason", 13, 13, 13),
   ColumnDef::all("Message", 30, 30, 30),
-  ColumnDef::all("Count", 5, 5, 5),
+  ColumnDef::all("Count", 14, 14, 14),
   ColumnDef::all("Age", 10, 10, 10),
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
-  let title = get_resource_title(app, EVENTS_TITLE, "", app.data.events.items.len());
+  let load = app.view_load(ActiveBlock::Events);
+  let suffix = if app.data.group_events {
+    "(grouped)"
+  } else {
+    ""
+  };
+  let title = get_resource_title(app, EVENTS_TITLE, suffix, app.data.events.items.len());
+  let group_hint = action_hint(
+    if app.data.group_events {
+      "ungroup"
+    } else {
+      "group"
+    },
+    DEFAULT_KEYBINDING.group_events.key,
+  );
 
-  let (headers, widths) = responsive_columns(&EVENT_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::Events, ViewTier::Compact);
 
   draw_resource_block(
     f,
     area,
     ResourceTableProps {
       title,
-      inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
+      inline_help: help_bold_line(
+        format!("{} · {}", group_hint, describe_yaml_and_esc_hint()),
+        app.palette,
+      ),
       resource: &mut app.data.events,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.involved_kind.to_owned()),
-        Cell::from(c.reason.to_owned()),
-        Cell::from(c.message.to_owned()),
-        Cell::from(c.count.to_string()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.involved_kind.to_owned()),
+          Cell::from(c.reason.to_owned()),
+          Cell::from(c.message.to_owned()),
+          Cell::from(c.occurrences()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(if c.is_warning() {
+          style_warning(app.palette)
+        } else {
+          style_text(app.palette)
+        })
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -174,12 +520,16 @@ mod tests {
       KubeEvent {
         name: "ga-edge-0.18931e4c1f3244cf".into(),
         namespace: "gagent".into(),
+        type_: "Warning".into(),
         involved_kind: "Pod".into(),
+        involved_name: "ga-edge-0".into(),
         reason: "FailedScheduling".into(),
         message: "0/1 nodes are available: 1 node(s) didn't match Pod's node affinity/selector. preemption: 0/1 nodes are available: 1 Preemption is not helpful for scheduling."
           .into(),
         count: 3432,
         age: utils::to_age(Some(&get_micro_time("2026-02-23T04:41:50.537584Z")), Utc::now()),
+        first_seen: Some(get_micro_time("2026-02-11T06:46:30.776466Z")),
+        last_seen: Some(get_micro_time("2026-02-23T04:41:50.537584Z")),
         k8s_obj: utils::sanitize_obj(events_list[0].clone()),
       }
     );
@@ -188,14 +538,175 @@ mod tests {
       KubeEvent {
         name: "ga-edge-data-8a821b67-ga-edge-0.18931e4cb66ce46b".into(),
         namespace: "gagent".into(),
+        type_: "Normal".into(),
         involved_kind: "PersistentVolumeClaim".into(),
+        involved_name: "ga-edge-data-8a821b67-ga-edge-0".into(),
         reason: "WaitForPodScheduled".into(),
         message: "waiting for pod ga-edge-0 to be scheduled".into(),
         count: 68646,
         age: utils::to_age(Some(&get_time("2026-02-23T04:46:45Z")), Utc::now()),
+        first_seen: Some(get_time("2026-02-11T06:46:33Z")),
+        last_seen: Some(get_time("2026-02-23T04:46:45Z")),
         k8s_obj: utils::sanitize_obj(events_list[1].clone()),
       }
     );
+    assert!(events[0].is_warning());
+    assert!(!events[1].is_warning());
+    assert_eq!(events[1].occurrences(), "68646 over 1w4d22h");
+  }
+
+  fn event(
+    name: &str,
+    object: &str,
+    reason: &str,
+    count: i32,
+    first: &str,
+    last: &str,
+  ) -> KubeEvent {
+    KubeEvent::from(Event {
+      metadata: ObjectMeta {
+        name: Some(name.into()),
+        namespace: Some("default".into()),
+        ..Default::default()
+      },
+      involved_object: ObjectReference {
+        kind: Some("Pod".into()),
+        name: Some(object.into()),
+        ..Default::default()
+      },
+      reason: Some(reason.into()),
+      message: Some(format!("{} message", name)),
+      count: Some(count),
+      first_timestamp: Some(get_time(first)),
+      last_timestamp: Some(get_time(last)),
+      ..Default::default()
+    })
+  }
+
+  #[test]
+  fn test_occurrences_show_the_span_of_repeated_events() {
+    let repeated = event(
+      "a",
+      "web",
+      "BackOff",
+      12,
+      "2024-01-01T10:00:00Z",
+      "2024-01-01T10:05:00Z",
+    );
+    assert_eq!(repeated.occurrences(), "12 over 5m");
+    let once = event(
+      "b",
+      "web",
+      "Pulled",
+      1,
+      "2024-01-01T10:00:00Z",
+      "2024-01-01T10:00:00Z",
+    );
+    assert_eq!(once.occurrences(), "1");
+  }
+
+  #[test]
+  fn test_group_events_folds_same_object_and_reason() {
+    let grouped = group_events(vec![
+      event(
+        "a",
+        "web",
+        "BackOff",
+        3,
+        "2024-01-01T10:02:00Z",
+        "2024-01-01T10:04:00Z",
+      ),
+      event(
+        "b",
+        "web",
+        "Pulled",
+        1,
+        "2024-01-01T10:00:00Z",
+        "2024-01-01T10:00:00Z",
+      ),
+      event(
+        "c",
+        "web",
+        "BackOff",
+        2,
+        "2024-01-01T10:00:00Z",
+        "2024-01-01T10:09:00Z",
+      ),
+      event(
+        "d",
+        "db",
+        "BackOff",
+        1,
+        "2024-01-01T10:00:00Z",
+        "2024-01-01T10:00:00Z",
+      ),
+    ]);
+
+    let rows: Vec<_> = grouped
+      .iter()
+      .map(|e| (e.name.as_str(), e.reason.as_str(), e.occurrences()))
+      .collect();
+    // the BackOff row is the latest event, counted and spanned over both
+    assert_eq!(
+      rows,
+      vec![
+        ("c", "BackOff", "5 over 9m".to_owned()),
+        ("b", "Pulled", "1".to_owned()),
+        ("d", "BackOff", "1".to_owned()),
+      ]
+    );
+    assert_eq!(grouped[0].message, "c message");
+  }
+
+  #[test]
+  fn test_pod_events_are_grouped_newest_first() {
+    let events = PodEvents::new(
+      "default",
+      "web",
+      vec![
+        event(
+          "a",
+          "web",
+          "Scheduled",
+          1,
+          "2023-01-01T10:00:00Z",
+          "2023-01-01T10:00:00Z",
+        ),
+        event(
+          "b",
+          "web",
+          "BackOff",
+          1,
+          "2023-01-01T10:01:00Z",
+          "2023-01-01T10:02:00Z",
+        ),
+        event(
+          "c",
+          "web",
+          "Pulled",
+          1,
+          "2023-01-01T10:01:00Z",
+          "2023-01-01T10:01:00Z",
+        ),
+        event(
+          "d",
+          "web",
+          "BackOff",
+          1,
+          "2023-01-01T10:03:00Z",
+          "2023-01-01T10:05:00Z",
+        ),
+      ],
+    );
+    let rows: Vec<_> = events
+      .events
+      .iter()
+      .map(|e| (e.reason.as_str(), e.count))
+      .collect();
+    assert_eq!(rows, vec![("BackOff", 2), ("Pulled", 1), ("Scheduled", 1)]);
+    assert!(events.is_for("default", "web"));
+    assert!(!events.is_for("default", "db"));
+    assert!(!events.is_for("shop", "web"));
   }
 
   #[test]
@@ -239,6 +750,134 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_event_buffer_updates_and_ages_out_watched_events() {
+    let now: DateTime<Utc> = "2024-01-01T12:00:00Z".parse().unwrap();
+    let mut buffer = EventBuffer::default();
+    buffer.replace(vec![
+      event(
+        "a",
+        "web",
+        "BackOff",
+        1,
+        "2024-01-01T11:50:00Z",
+        "2024-01-01T11:50:00Z",
+      ),
+      event(
+        "old",
+        "web",
+        "Pulled",
+        1,
+        "2024-01-01T10:00:00Z",
+        "2024-01-01T10:30:00Z",
+      ),
+    ]);
+
+    buffer.upsert(
+      event(
+        "a",
+        "web",
+        "BackOff",
+        4,
+        "2024-01-01T11:50:00Z",
+        "2024-01-01T11:59:00Z",
+      ),
+      now,
+    );
+    buffer.upsert(
+      event(
+        "b",
+        "db",
+        "Killing",
+        1,
+        "2024-01-01T11:58:00Z",
+        "2024-01-01T11:58:00Z",
+      ),
+      now,
+    );
+    let rows = buffer.rows(false, now);
+    // the update replaces its row and the event past the TTL is gone
+    assert_eq!(
+      rows
+        .iter()
+        .map(|e| (e.name.as_str(), e.count))
+        .collect::<Vec<_>>(),
+      vec![("a", 4), ("b", 1)]
+    );
+    assert_eq!(rows[0].age, "1m");
+  }
+
+  #[test]
+  fn test_event_buffer_drops_the_least_recently_seen_past_the_cap() {
+    let now: DateTime<Utc> = "2024-01-01T12:00:00Z".parse().unwrap();
+    let mut buffer = EventBuffer::default();
+    buffer.replace(
+      (0..MAX_WATCHED_EVENTS)
+        .map(|i| {
+          let seen = if i == 7 {
+            "2024-01-01T11:00:30Z"
+          } else {
+            "2024-01-01T11:30:00Z"
+          };
+          event(&format!("e{}", i), "web", "BackOff", 1, seen, seen)
+        })
+        .collect(),
+    );
+    buffer.upsert(
+      event(
+        "new",
+        "web",
+        "BackOff",
+        1,
+        "2024-01-01T11:59:00Z",
+        "2024-01-01T11:59:00Z",
+      ),
+      now,
+    );
+    let rows = buffer.rows(false, now);
+    assert_eq!(rows.len(), MAX_WATCHED_EVENTS);
+    assert!(!rows.iter().any(|e| e.name == "e7"));
+    assert_eq!(rows.last().unwrap().name, "new");
+  }
+
+  #[tokio::test]
+  async fn test_events_are_watched_only_while_the_view_is_shown() {
+    let (io_stream_tx, mut io_stream_rx) = tokio::sync::mpsc::channel(10);
+    let mut app = App {
+      io_stream_tx: Some(io_stream_tx),
+      ..App::default()
+    };
+    app.data.selected.ns = Some("shop".into());
+    app.route_to_resource_view(ActiveBlock::Events);
+    app.sync_event_watch().await;
+    let target = WatchedEvents {
+      namespace: Some("shop".into()),
+    };
+    assert_eq!(app.watched_events(), Some(target.clone()));
+    assert_eq!(
+      io_stream_rx.try_recv().unwrap(),
+      IoStreamEvent::WatchEvents {
+        namespace: Some("shop".into())
+      }
+    );
+    // already watching: nothing more is sent
+    app.sync_event_watch().await;
+    assert!(io_stream_rx.try_recv().is_err());
+
+    // a watch that gave up is not restarted for the same namespace
+    app.give_up_event_watch(target);
+    app.sync_event_watch().await;
+    assert_eq!(app.watched_events(), None);
+    assert!(io_stream_rx.try_recv().is_err());
+
+    app.route_to_resource_view(ActiveBlock::Pods);
+    app.sync_event_watch().await;
+    assert_eq!(app.watched_events(), None);
+    app.route_to_resource_view(ActiveBlock::Events);
+    app.sync_event_watch().await;
+    assert!(app.watched_events().is_some());
+  }
+
   fn get_micro_time(s: &str) -> Time {
     Time(s.parse().unwrap())
   }
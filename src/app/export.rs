@@ -0,0 +1,264 @@
+//! Export the current table to a CSV or JSON file in the working directory,
+//! or copy its selected row to the clipboard. The log view is saved as a
+//! plain `.log` file instead.
+//!
+//! Rows are only known as cells at draw time, so an export is requested on the
+//! table, filled in by the next draw with the rows shown (filter, order and
+//! column set included), then written once the frame is done.
+use std::{
+  fs,
+  io::{self, Write},
+  path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, FixedOffset};
+
+use super::utils::create_private_file;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExportFormat {
+  Csv,
+  Json,
+}
+
+impl ExportFormat {
+  fn extension(self) -> &'static str {
+    match self {
+      ExportFormat::Csv => "csv",
+      ExportFormat::Json => "json",
+    }
+  }
+}
+
+/// A table as plain text: the headers shown and one cell per header in each row.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TableText {
+  pub headers: Vec<String>,
+  pub rows: Vec<Vec<String>>,
+}
+
+/// Where a table's export is at.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum TableExport {
+  #[default]
+  Idle,
+  /// Waiting for the next draw to collect the rows.
+  Requested(ExportFormat),
+  /// Collected and ready to be written.
+  Ready(ExportFormat, TableText),
+  /// Waiting for the next draw to collect the selected row.
+  RowRequested,
+  /// The selected row, if any, ready to be copied.
+  RowReady(TableText),
+}
+
+impl TableText {
+  pub fn to_csv(&self) -> String {
+    let mut out = String::new();
+    for record in std::iter::once(&self.headers).chain(&self.rows) {
+      let fields: Vec<String> = record.iter().map(|field| csv_field(field)).collect();
+      out.push_str(&fields.join(","));
+      out.push('\n');
+    }
+    out
+  }
+
+  /// The cells of each row joined by tabs, one row per line, for pasting
+  /// into a spreadsheet. Tabs and line breaks inside a cell become spaces.
+  pub fn to_tsv(&self) -> String {
+    let lines: Vec<String> = self
+      .rows
+      .iter()
+      .map(|row| {
+        let fields: Vec<String> = row
+          .iter()
+          .map(|field| field.replace(['\t', '\r', '\n'], " "))
+          .collect();
+        fields.join("\t")
+      })
+      .collect();
+    lines.join("\n")
+  }
+
+  /// An array with one object per row, keyed by column name. Written by hand
+  /// so the keys keep the column order rather than sorting.
+  pub fn to_json(&self) -> String {
+    let objects: Vec<String> = self
+      .rows
+      .iter()
+      .map(|row| {
+        let fields: Vec<String> = self
+          .headers
+          .iter()
+          .zip(row)
+          .map(|(header, cell)| format!("    {}: {}", json_string(header), json_string(cell)))
+          .collect();
+        format!("  {{\n{}\n  }}", fields.join(",\n"))
+      })
+      .collect();
+    if objects.is_empty() {
+      "[]\n".to_owned()
+    } else {
+      format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+  }
+}
+
+fn json_string(text: &str) -> String {
+  serde_json::to_string(text).unwrap_or_default()
+}
+
+/// Quote a field when it holds a comma, quote or line break (RFC 4180).
+fn csv_field(field: &str) -> String {
+  if field.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", field.replace('"', "\"\""))
+  } else {
+    field.to_owned()
+  }
+}
+
+/// Write `table` to `kdash-<view>-<timestamp>.<ext>` in `dir` and return the path.
+pub fn write_export(
+  dir: &Path,
+  view: &str,
+  now: DateTime<FixedOffset>,
+  format: ExportFormat,
+  table: &TableText,
+) -> io::Result<PathBuf> {
+  let path = dir.join(format!(
+    "kdash-{}-{}.{}",
+    view,
+    now.format("%Y%m%d-%H%M%S"),
+    format.extension()
+  ));
+  let contents = match format {
+    ExportFormat::Csv => table.to_csv(),
+    ExportFormat::Json => table.to_json(),
+  };
+  fs::write(&path, contents)?;
+  Ok(path)
+}
+
+/// Write log lines to a timestamped `.log` file in `dir`, named after the
+/// log view's `id` with anything but letters, digits, `-` and `_` dashed.
+pub fn write_logs(
+  dir: &Path,
+  id: &str,
+  now: DateTime<FixedOffset>,
+  text: &str,
+) -> io::Result<PathBuf> {
+  let name: String = id
+    .chars()
+    .map(|c| {
+      if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+        c
+      } else {
+        '-'
+      }
+    })
+    .collect();
+  let path = dir.join(format!(
+    "kdash-logs-{}-{}.log",
+    name,
+    now.format("%Y%m%d-%H%M%S")
+  ));
+  // the logs may carry tokens or customer data, so only the user can read them
+  create_private_file(&path)?.write_all(text.as_bytes())?;
+  Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::TimeZone;
+  use serde_json::Value;
+
+  use super::*;
+
+  fn table() -> TableText {
+    TableText {
+      headers: vec!["Name".into(), "Status".into(), "Node".into()],
+      rows: vec![
+        vec!["web-1".into(), "Running".into(), "node-a".into()],
+        vec!["say \"hi\", world".into(), "Error".into(), String::new()],
+      ],
+    }
+  }
+
+  #[test]
+  fn test_table_text_to_csv_quotes_special_fields() {
+    assert_eq!(
+      table().to_csv(),
+      "Name,Status,Node\nweb-1,Running,node-a\n\"say \"\"hi\"\", world\",Error,\n"
+    );
+  }
+
+  #[test]
+  fn test_table_text_to_tsv_joins_cells_with_tabs() {
+    let row = TableText {
+      rows: vec![vec![
+        "web-1".into(),
+        "Running\tnow".into(),
+        "multi\nline".into(),
+      ]],
+      ..table()
+    };
+    assert_eq!(row.to_tsv(), "web-1\tRunning now\tmulti line");
+    assert_eq!(
+      table().to_tsv(),
+      "web-1\tRunning\tnode-a\nsay \"hi\", world\tError\t"
+    );
+  }
+
+  #[test]
+  fn test_table_text_to_json_keys_rows_by_column() {
+    let text = table().to_json();
+    assert!(text.find("\"Status\"").unwrap() < text.find("\"Node\"").unwrap());
+    let json: Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(
+      json,
+      serde_json::json!([
+        { "Name": "web-1", "Status": "Running", "Node": "node-a" },
+        { "Name": "say \"hi\", world", "Status": "Error", "Node": "" },
+      ])
+    );
+  }
+
+  #[test]
+  fn test_write_export_names_file_after_view_and_time() {
+    let dir = std::env::temp_dir().join(format!("kdash-export-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let now = chrono::Local
+      .with_ymd_and_hms(2026, 3, 4, 5, 6, 7)
+      .unwrap()
+      .fixed_offset();
+
+    let path = write_export(&dir, "pods", now, ExportFormat::Csv, &table()).unwrap();
+    assert_eq!(path, dir.join("kdash-pods-20260304-050607.csv"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), table().to_csv());
+
+    // A missing directory is reported rather than panicking.
+    let missing = dir.join("missing");
+    assert!(write_export(&missing, "pods", now, ExportFormat::Json, &table()).is_err());
+    fs::remove_dir_all(&dir).unwrap();
+  }
+  #[test]
+  fn test_write_logs_is_private() {
+    let dir = std::env::temp_dir().join(format!("kdash-logs-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let now = chrono::Local
+      .with_ymd_and_hms(2026, 3, 4, 5, 6, 7)
+      .unwrap()
+      .fixed_offset();
+
+    let path = write_logs(&dir, "shop:web-1", now, "started\n").unwrap();
+    assert_eq!(path, dir.join("kdash-logs-shop-web-1-20260304-050607.log"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "started\n");
+    #[cfg(unix)]
+    {
+      use std::os::unix::fs::PermissionsExt;
+      let mode = fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+      assert_eq!(mode, 0o600);
+    }
+    fs::remove_dir_all(&dir).unwrap();
+  }
+}
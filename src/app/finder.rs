@@ -0,0 +1,405 @@
+//! The `Ctrl-p` finder: fuzzy-match a name against every resource list
+//! already loaded into [`App`] and jump to the match in its view. Nothing is
+//! fetched; lists that were never loaded simply have no entries.
+use ratatui::widgets::ListState;
+
+use super::{
+  models::{Named, Scrollable, StatefulTable},
+  ActiveBlock, App,
+};
+
+/// Matches kept for the overlay; a more specific query finds the rest.
+const MAX_MATCHES: usize = 200;
+
+const MATCH_SCORE: i64 = 16;
+/// A match at the start of the name or after `-`, `.`, `/` or `_`.
+const BOUNDARY_BONUS: i64 = 8;
+/// A match right after the previous one.
+const CONSECUTIVE_BONUS: i64 = 8;
+/// Per character skipped between two matches.
+const GAP_PENALTY: i64 = 1;
+
+/// One resource the finder can jump to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinderEntry {
+  pub block: ActiveBlock,
+  pub kind: &'static str,
+  pub namespace: Option<String>,
+  pub name: String,
+  /// Position in the view's item list when the finder was opened.
+  pub index: usize,
+}
+
+impl FinderEntry {
+  /// `namespace/name`, or just the name for cluster-scoped resources.
+  pub fn path(&self) -> String {
+    match &self.namespace {
+      Some(ns) => format!("{}/{}", ns, self.name),
+      None => self.name.clone(),
+    }
+  }
+
+  fn score(&self, query: &str) -> Option<i64> {
+    let by_name = fuzzy_score(query, &self.name);
+    let by_path = self
+      .namespace
+      .as_ref()
+      .and_then(|_| fuzzy_score(query, &self.path()));
+    by_name.max(by_path)
+  }
+}
+
+/// State of the finder overlay: the query and the entries matching it.
+#[derive(Debug)]
+pub struct Finder {
+  pub query: String,
+  entries: Vec<FinderEntry>,
+  /// Indices into `entries`, best match first.
+  matches: Vec<usize>,
+  /// Matches before truncating to [`MAX_MATCHES`].
+  pub matched: usize,
+  pub state: ListState,
+}
+
+impl Finder {
+  pub fn new(entries: Vec<FinderEntry>) -> Self {
+    let mut finder = Finder {
+      query: String::new(),
+      entries,
+      matches: vec![],
+      matched: 0,
+      state: ListState::default(),
+    };
+    finder.update_matches();
+    finder
+  }
+
+  pub fn push(&mut self, c: char) {
+    self.query.push(c);
+    self.update_matches();
+  }
+
+  pub fn pop(&mut self) {
+    self.query.pop();
+    self.update_matches();
+  }
+
+  pub fn entry_count(&self) -> usize {
+    self.entries.len()
+  }
+
+  pub fn matches(&self) -> impl Iterator<Item = &FinderEntry> {
+    self.matches.iter().map(|&i| &self.entries[i])
+  }
+
+  pub fn selected(&self) -> Option<&FinderEntry> {
+    let i = *self.matches.get(self.state.selected()?)?;
+    self.entries.get(i)
+  }
+
+  /// Rank by score, then shorter names, then view order; an empty query
+  /// lists everything in view order.
+  fn update_matches(&mut self) {
+    let mut scored: Vec<(i64, usize)> = self
+      .entries
+      .iter()
+      .enumerate()
+      .filter_map(|(i, entry)| entry.score(&self.query).map(|score| (score, i)))
+      .collect();
+    let by_length = !self.query.trim().is_empty();
+    scored.sort_by_key(|&(score, i)| {
+      let length = if by_length {
+        self.entries[i].name.len()
+      } else {
+        0
+      };
+      (-score, length, i)
+    });
+    self.matched = scored.len();
+    self.matches = scored
+      .into_iter()
+      .take(MAX_MATCHES)
+      .map(|(_, i)| i)
+      .collect();
+    self.state.select((!self.matches.is_empty()).then_some(0));
+  }
+}
+
+impl Scrollable for Finder {
+  fn scroll_to(&mut self, index: usize) {
+    if !self.matches.is_empty() {
+      self.state.select(Some(index));
+    }
+  }
+  fn current_pos(&self) -> Option<usize> {
+    self.state.selected()
+  }
+  fn length(&self) -> usize {
+    self.matches.len()
+  }
+  fn wraps(&self) -> bool {
+    false
+  }
+}
+
+/// Score `candidate` as a case-insensitive subsequence of `query`, higher
+/// for matches that are contiguous or start a word. `None` when some query
+/// character is missing. Spaces in the query are ignored.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+  let query: Vec<char> = query
+    .chars()
+    .filter(|c| !c.is_whitespace())
+    .map(|c| c.to_ascii_lowercase())
+    .collect();
+  let Some(&first) = query.first() else {
+    return Some(0);
+  };
+  let text: Vec<char> = candidate.chars().map(|c| c.to_ascii_lowercase()).collect();
+  // Greedy from each occurrence of the first character, so `dep` scores
+  // `api-deployment` by its `-dep` rather than an earlier stray `d`.
+  (0..text.len())
+    .filter(|&start| text[start] == first)
+    .filter_map(|start| score_from(&query, &text, start))
+    .max()
+}
+
+fn score_from(query: &[char], text: &[char], start: usize) -> Option<i64> {
+  let mut score = -(GAP_PENALTY * start as i64);
+  let mut prev: Option<usize> = None;
+  let mut pos = start;
+  for &qc in query {
+    let i = (pos..text.len()).find(|&i| text[i] == qc)?;
+    score += MATCH_SCORE;
+    if i == 0 || matches!(text[i - 1], '-' | '.' | '/' | '_' | ' ') {
+      score += BOUNDARY_BONUS;
+    }
+    match prev {
+      Some(p) if p + 1 == i => score += CONSECUTIVE_BONUS,
+      Some(p) => score -= GAP_PENALTY * (i - p - 1) as i64,
+      None => {}
+    }
+    prev = Some(i);
+    pos = i + 1;
+  }
+  Some(score)
+}
+
+fn push_entries<T: Named>(
+  entries: &mut Vec<FinderEntry>,
+  block: ActiveBlock,
+  kind: &'static str,
+  table: &StatefulTable<T>,
+) {
+  entries.extend(
+    table
+      .items
+      .iter()
+      .enumerate()
+      .map(|(index, item)| FinderEntry {
+        block,
+        kind,
+        namespace: item.get_namespace().map(str::to_owned),
+        name: item.get_name().clone(),
+        index,
+      }),
+  );
+}
+
+/// Every loaded resource, in tab order and then More-menu order.
+pub fn finder_entries(app: &App) -> Vec<FinderEntry> {
+  let data = &app.data;
+  let mut entries = vec![];
+  push_entries(&mut entries, ActiveBlock::Pods, "Pod", &data.pods);
+  push_entries(
+    &mut entries,
+    ActiveBlock::Services,
+    "Service",
+    &data.services,
+  );
+  push_entries(&mut entries, ActiveBlock::Nodes, "Node", &data.nodes);
+  push_entries(
+    &mut entries,
+    ActiveBlock::ConfigMaps,
+    "ConfigMap",
+    &data.config_maps,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::StatefulSets,
+    "StatefulSet",
+    &data.stateful_sets,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::ReplicaSets,
+    "ReplicaSet",
+    &data.replica_sets,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::Deployments,
+    "Deployment",
+    &data.deployments,
+  );
+  push_entries(&mut entries, ActiveBlock::Jobs, "Job", &data.jobs);
+  push_entries(
+    &mut entries,
+    ActiveBlock::DaemonSets,
+    "DaemonSet",
+    &data.daemon_sets,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::PersistentVolumeClaims,
+    "PersistentVolumeClaim",
+    &data.persistent_volume_claims,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::Ingresses,
+    "Ingress",
+    &data.ingress,
+  );
+  push_entries(&mut entries, ActiveBlock::Secrets, "Secret", &data.secrets);
+  push_entries(
+    &mut entries,
+    ActiveBlock::ServiceAccounts,
+    "ServiceAccount",
+    &data.service_accounts,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::NetworkPolicies,
+    "NetworkPolicy",
+    &data.network_policies,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::ResourceQuotas,
+    "ResourceQuota",
+    &data.resource_quotas,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::LimitRanges,
+    "LimitRange",
+    &data.limit_ranges,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::PodDisruptionBudgets,
+    "PodDisruptionBudget",
+    &data.pdbs,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::CronJobs,
+    "CronJob",
+    &data.cronjobs,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::PersistentVolumes,
+    "PersistentVolume",
+    &data.persistent_volumes,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::StorageClasses,
+    "StorageClass",
+    &data.storage_classes,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::ReplicationControllers,
+    "ReplicationController",
+    &data.replication_controllers,
+  );
+  push_entries(&mut entries, ActiveBlock::Roles, "Role", &data.roles);
+  push_entries(
+    &mut entries,
+    ActiveBlock::RoleBindings,
+    "RoleBinding",
+    &data.role_bindings,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::ClusterRoles,
+    "ClusterRole",
+    &data.cluster_roles,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::ClusterRoleBindings,
+    "ClusterRoleBinding",
+    &data.cluster_role_bindings,
+  );
+  push_entries(
+    &mut entries,
+    ActiveBlock::CustomResourceDefinitions,
+    "CustomResourceDefinition",
+    &data.crds,
+  );
+  entries
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::handlers::ScrollEvent;
+
+  fn entry(kind: &'static str, namespace: Option<&str>, name: &str) -> FinderEntry {
+    FinderEntry {
+      block: ActiveBlock::Pods,
+      kind,
+      namespace: namespace.map(str::to_owned),
+      name: name.into(),
+      index: 0,
+    }
+  }
+
+  #[test]
+  fn test_fuzzy_score_prefers_contiguous_word_starts() {
+    assert_eq!(fuzzy_score("", "anything"), Some(0));
+    assert_eq!(fuzzy_score("xyz", "api-deployment"), None);
+    assert!(fuzzy_score("DEP", "api-deployment").is_some());
+
+    let word_start = fuzzy_score("dep", "api-deployment").unwrap();
+    let scattered = fuzzy_score("dep", "dns-resolver-pod").unwrap();
+    assert!(word_start > scattered);
+    // Spaces separate words in the query but are not matched.
+    assert_eq!(
+      fuzzy_score("api dep", "api-deployment"),
+      fuzzy_score("apidep", "api-deployment")
+    );
+  }
+
+  #[test]
+  fn test_finder_ranks_matches_and_tracks_the_selection() {
+    let mut finder = Finder::new(vec![
+      entry("Pod", Some("team-a"), "redis-0"),
+      entry("Deployment", Some("team-a"), "web"),
+      entry("Service", Some("web"), "frontend"),
+      entry("Node", None, "worker-1"),
+    ]);
+    assert_eq!(finder.matched, 4);
+    assert_eq!(finder.selected().map(|e| e.kind), Some("Pod"));
+
+    finder.push('w');
+    finder.push('e');
+    finder.push('b');
+    let names: Vec<_> = finder.matches().map(FinderEntry::path).collect();
+    // Equal scores: the shorter name first.
+    assert_eq!(names, vec!["team-a/web", "web/frontend"]);
+
+    finder.handle_scroll(ScrollEvent::down());
+    assert_eq!(finder.selected().map(|e| e.kind), Some("Service"));
+
+    finder.push('z');
+    assert_eq!(finder.matched, 0);
+    assert_eq!(finder.selected(), None);
+    finder.pop();
+    assert_eq!(finder.matched, 2);
+    assert_eq!(finder.state.selected(), Some(0));
+  }
+}
@@ -0,0 +1,98 @@
+//! How much the footer and the toasts above it say. Quiet keeps the context,
+//! the namespace and errors and drops informational toasts; verbose adds the
+//! last network event and how long it took. Normal is the middle ground in
+//! between and the default.
+use std::time::Duration;
+
+use super::App;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FooterVerbosity {
+  Quiet,
+  #[default]
+  Normal,
+  Verbose,
+}
+
+impl FooterVerbosity {
+  fn next(self) -> FooterVerbosity {
+    match self {
+      FooterVerbosity::Normal => FooterVerbosity::Quiet,
+      FooterVerbosity::Quiet => FooterVerbosity::Verbose,
+      FooterVerbosity::Verbose => FooterVerbosity::Normal,
+    }
+  }
+}
+
+/// The last network event handled, for the verbose footer.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LastNetworkEvent {
+  pub name: String,
+  pub took: Duration,
+}
+
+impl LastNetworkEvent {
+  /// Its name and time, e.g. `GetPods 120 ms`.
+  pub fn label(&self) -> String {
+    let millis = self.took.as_millis();
+    if millis < 1000 {
+      format!("{} {} ms", self.name, millis)
+    } else {
+      format!("{} {:.1} s", self.name, self.took.as_secs_f64())
+    }
+  }
+}
+
+impl App {
+  /// Cycle normal, quiet and verbose footers.
+  pub fn cycle_footer_verbosity(&mut self) {
+    self.footer_verbosity = self.footer_verbosity.next();
+    match self.footer_verbosity {
+      // quiet drops the toast this would show
+      FooterVerbosity::Quiet => self.status_message.clear(),
+      FooterVerbosity::Normal => self.set_status_message("Footer: normal"),
+      FooterVerbosity::Verbose => self.set_status_message("Footer: verbose"),
+    }
+  }
+
+  /// Informational toasts are only drawn outside quiet mode; errors always
+  /// are.
+  pub fn shows_status_messages(&self) -> bool {
+    self.footer_verbosity != FooterVerbosity::Quiet
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_footer_verbosity_cycles_through_quiet_and_verbose() {
+    let mut app = App::default();
+    assert_eq!(app.footer_verbosity, FooterVerbosity::Normal);
+    app.set_status_message("Exported 3 rows");
+
+    app.cycle_footer_verbosity();
+    assert_eq!(app.footer_verbosity, FooterVerbosity::Quiet);
+    assert!(!app.shows_status_messages());
+    assert!(app.status_message.is_empty());
+
+    app.cycle_footer_verbosity();
+    assert_eq!(app.footer_verbosity, FooterVerbosity::Verbose);
+    assert!(app.shows_status_messages());
+    assert_eq!(app.status_message.text(), "Footer: verbose");
+
+    app.cycle_footer_verbosity();
+    assert_eq!(app.footer_verbosity, FooterVerbosity::Normal);
+  }
+
+  #[test]
+  fn test_last_network_event_label() {
+    let event = |millis| LastNetworkEvent {
+      name: "GetPods".into(),
+      took: Duration::from_millis(millis),
+    };
+    assert_eq!(event(120).label(), "GetPods 120 ms");
+    assert_eq!(event(2345).label(), "GetPods 2.3 s");
+  }
+}
@@ -0,0 +1,243 @@
+//! What "healthy" means for each kind, for the problems-only toggle that
+//! hides healthy rows. Kinds without a notion of health keep every row, so
+//! the toggle only narrows views where a row can be in trouble.
+use k8s_openapi::api::{apps::v1::Deployment, batch::v1::Job};
+
+use super::{
+  api_resources::KubeApiResource,
+  configmaps::{ConfigMapEntry, KubeConfigMap},
+  crds::KubeCrd,
+  cronjobs::KubeCronJob,
+  daemonsets::KubeDaemonSet,
+  deployments::KubeDeployment,
+  dynamic::KubeDynamicResource,
+  events::KubeEvent,
+  ingress::KubeIngress,
+  jobs::KubeJob,
+  limit_ranges::KubeLimitRange,
+  models::KubeResource,
+  network_policies::KubeNetworkPolicy,
+  node_pods::KubeNodePods,
+  nodes::KubeNode,
+  pdbs::KubePdb,
+  pods::{KubeContainer, KubePod},
+  pvcs::KubePVC,
+  pvs::KubePV,
+  replicasets::KubeReplicaSet,
+  replication_controllers::KubeReplicationController,
+  resource_quotas::KubeResourceQuota,
+  roles::{KubeClusterRole, KubeClusterRoleBinding, KubeRole, KubeRoleBinding},
+  secrets::KubeSecret,
+  serviceaccounts::KubeSvcAcct,
+  statefulsets::KubeStatefulSet,
+  storageclass::KubeStorageClass,
+  svcs::KubeSvc,
+  troubleshoot::DisplayFinding,
+};
+
+pub trait Health {
+  /// Whether rows of this kind can be unhealthy at all; the problems-only
+  /// toggle leaves views of other kinds alone.
+  const CHECKED: bool = false;
+
+  fn is_healthy(&self) -> bool {
+    true
+  }
+}
+
+/// Running with every container ready, or finished.
+impl Health for KubePod {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    (self.status == "Running" && self.ready.0 == self.ready.1)
+      || self.status == "Completed"
+      || self.status == "Succeeded"
+  }
+}
+
+/// No unavailable replicas.
+impl Health for KubeDeployment {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    let deployment: &Deployment = self.get_k8s_obj();
+    deployment
+      .status
+      .as_ref()
+      .and_then(|s| s.unavailable_replicas)
+      .unwrap_or_default()
+      == 0
+  }
+}
+
+/// Every desired replica ready.
+impl Health for KubeStatefulSet {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    let sts = self.get_k8s_obj();
+    let desired = sts.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+    let ready = sts
+      .status
+      .as_ref()
+      .and_then(|s| s.ready_replicas)
+      .unwrap_or_default();
+    ready >= desired
+  }
+}
+
+/// Ready and available on every node it should run on.
+impl Health for KubeDaemonSet {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    self.ready >= self.desired && self.available >= self.desired
+  }
+}
+
+impl Health for KubeReplicaSet {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    self.ready >= self.desired
+  }
+}
+
+impl Health for KubeReplicationController {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    self.ready >= self.desired
+  }
+}
+
+/// Not marked `Failed`.
+impl Health for KubeJob {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    let job: &Job = self.get_k8s_obj();
+    !job
+      .status
+      .as_ref()
+      .and_then(|s| s.conditions.as_ref())
+      .is_some_and(|conditions| {
+        conditions
+          .iter()
+          .any(|c| c.type_ == "Failed" && c.status == "True")
+      })
+  }
+}
+
+/// Ready and under no resource pressure.
+impl Health for KubeNode {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    self.status == "Ready" && self.pressures.is_empty()
+  }
+}
+
+impl Health for KubePVC {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    self.status == "Bound"
+  }
+}
+
+/// Bound or waiting to be claimed; `Pending`, `Released` and `Failed` need
+/// a look.
+impl Health for KubePV {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    self.status == "Bound" || self.status == "Available"
+  }
+}
+
+/// `Warning` events are the problems; `Normal` ones are routine.
+impl Health for KubeEvent {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    self.type_ != "Warning"
+  }
+}
+
+impl Health for KubeApiResource {}
+impl Health for KubeClusterRole {}
+impl Health for KubeClusterRoleBinding {}
+impl Health for ConfigMapEntry {}
+impl Health for KubeConfigMap {}
+impl Health for KubeContainer {}
+impl Health for KubeCrd {}
+impl Health for KubeCronJob {}
+impl Health for KubeDynamicResource {}
+impl Health for DisplayFinding {}
+impl Health for KubeIngress {}
+impl Health for KubeLimitRange {}
+impl Health for KubeNetworkPolicy {}
+impl Health for KubeNodePods {}
+impl Health for KubePdb {}
+impl Health for KubeResourceQuota {}
+impl Health for KubeRole {}
+impl Health for KubeRoleBinding {}
+impl Health for KubeSecret {}
+impl Health for KubeStorageClass {}
+impl Health for KubeSvc {}
+impl Health for KubeSvcAcct {}
+
+impl super::App {
+  /// Toggle hiding healthy rows in every view that can tell them apart.
+  pub fn toggle_problems_only(&mut self) {
+    self.problems_only = !self.problems_only;
+    self.set_status_message(if self.problems_only {
+      "Showing problems only"
+    } else {
+      "Showing all rows"
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::app::test_utils::convert_resource_from_file;
+
+  #[test]
+  fn test_health_flags_unhealthy_rows() {
+    let pod = |status: &str, ready: (i32, i32)| {
+      let mut pod = KubePod::default();
+      pod.status = status.into();
+      pod.ready = ready;
+      pod
+    };
+    assert!(pod("Running", (2, 2)).is_healthy());
+    assert!(pod("Completed", (0, 1)).is_healthy());
+    assert!(
+      !pod("Running", (1, 2)).is_healthy(),
+      "not every container ready"
+    );
+    assert!(!pod("CrashLoopBackOff", (0, 1)).is_healthy());
+    assert!(!pod("Pending", (0, 1)).is_healthy());
+
+    let node = |status: &str, pressures: Vec<String>| {
+      let mut node = KubeNode::default();
+      node.status = status.into();
+      node.pressures = pressures;
+      node
+    };
+    assert!(node("Ready", vec![]).is_healthy());
+    assert!(!node("NotReady", vec![]).is_healthy());
+    assert!(!node("Ready", vec!["Memory".into()]).is_healthy());
+
+    let (events, _): (Vec<KubeEvent>, Vec<_>) = convert_resource_from_file("events");
+    let types: Vec<_> = events
+      .iter()
+      .map(|e| (e.type_.as_str(), e.is_healthy()))
+      .collect();
+    assert_eq!(types, vec![("Warning", false), ("Normal", true)]);
+  }
+}
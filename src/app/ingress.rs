@@ -1,11 +1,8 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use k8s_openapi::api::networking::v1::{Ingress, IngressBackend, IngressRule, IngressStatus};
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
-};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
 
 use super::{
   models::{AppResource, KubeResource, Named},
@@ -15,10 +12,11 @@ use super::{
 use crate::{
   draw_resource_tab,
   network::Network,
+  ui::glyphs::glyphs,
   ui::utils::{
     describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, responsive_columns, style_caution,
-    style_success, title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
+    get_describe_active, get_resource_title, help_bold_line, style_caution, style_success,
+    title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -95,6 +93,14 @@ impl Named for KubeIngress {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<Ingress> for KubeIngress {
@@ -139,9 +145,10 @@ fn get_rules(i_rules: &Option<Vec<IngressRule>>) -> Option<String> {
         if let Some(http) = &i_rule.http {
           http.paths.iter().for_each(|path| {
             rule = format!(
-              "{}{}►{}",
+              "{}{}{}{}",
               rule,
               path.path.as_deref().unwrap_or("/*"),
+              glyphs().port_arrow,
               format_backend(&Some(path.backend.clone()))
             );
           });
@@ -205,7 +212,7 @@ impl AppResource for IngressResource {
   }
 }
 
-const INGRESS_COLUMNS: [ColumnDef; 8] = [
+pub(crate) const INGRESS_COLUMNS: [ColumnDef; 8] = [
   ColumnDef::all("Namespace", 10, 8, 8),
   ColumnDef::all("Name", 20, 18, 18),
   ColumnDef::all("Ingress class", 10, 10, 10),
@@ -217,11 +224,11 @@ const INGRESS_COLUMNS: [ColumnDef; 8] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::Ingresses);
   let title = get_resource_title(app, INGRESS_TITLE, "", app.data.ingress.items.len());
 
   let tier = ViewTier::from_width(area.width, app.wide_columns);
-  let (headers, widths) = responsive_columns(&INGRESS_COLUMNS, tier);
+  let columns = app.column_layout(ActiveBlock::Ingresses, tier);
 
   draw_resource_block(
     f,
@@ -233,8 +240,8 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
         app.palette,
       ),
       resource: &mut app.data.ingress,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
       let style = if c.address == "<pending>" {
@@ -242,22 +249,20 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       } else {
         style_success(app.palette)
       };
-      let mut cells = vec![
+      let cells = vec![
         Cell::from(c.namespace.to_owned()),
         Cell::from(c.name.to_owned()),
         Cell::from(c.ingress_class.to_owned()),
         Cell::from(c.paths.to_owned()),
         Cell::from(c.default_backend.to_owned()),
         Cell::from(c.address.to_owned()),
+        Cell::from(c.tls.to_owned()),
+        Cell::from(c.age.to_owned()),
       ];
-      if tier >= ViewTier::Standard {
-        cells.push(Cell::from(c.tls.to_owned()));
-      }
-      cells.push(Cell::from(c.age.to_owned()));
-      Row::new(cells).style(style)
+      columns.row(cells).style(style)
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -1,11 +1,8 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use k8s_openapi::api::batch::v1::Job;
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
-};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
 
 use super::{
   models::{self, AppResource, KubeResource, Named},
@@ -17,8 +14,8 @@ use crate::{
   network::Network,
   ui::utils::{
     action_hint, describe_yaml_and_logs_hint, draw_describe_block, draw_resource_block,
-    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, responsive_columns,
-    style_text, title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
+    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, style_text,
+    title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -96,6 +93,36 @@ impl Named for KubeJob {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
+
+  fn get_changed(&self) -> Option<DateTime<Utc>> {
+    let status = self.k8s_obj.status.as_ref();
+    let conditions = status
+      .and_then(|s| s.conditions.as_ref())
+      .into_iter()
+      .flatten()
+      .filter_map(|c| c.last_transition_time.as_ref());
+    let runs = status
+      .into_iter()
+      .flat_map(|s| [s.start_time.as_ref(), s.completion_time.as_ref()])
+      .flatten();
+    utils::latest_time(
+      self
+        .k8s_obj
+        .metadata
+        .creation_timestamp
+        .iter()
+        .chain(conditions)
+        .chain(runs),
+    )
+  }
 }
 
 impl KubeResource<Job> for KubeJob {
@@ -144,7 +171,7 @@ impl AppResource for JobResource {
   }
 }
 
-const JOB_COLUMNS: [ColumnDef; 7] = [
+pub(crate) const JOB_COLUMNS: [ColumnDef; 7] = [
   ColumnDef::all("Namespace", 25, 20, 15),
   ColumnDef::all("Name", 40, 30, 25),
   ColumnDef::all("Completions", 15, 15, 12),
@@ -155,11 +182,12 @@ const JOB_COLUMNS: [ColumnDef; 7] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::Jobs);
   let title = get_resource_title(app, JOBS_TITLE, "", app.data.jobs.items.len());
 
   let tier = ViewTier::from_width(area.width, app.wide_columns);
-  let (headers, widths) = responsive_columns(&JOB_COLUMNS, tier);
+  let columns = app.column_layout(ActiveBlock::Jobs, tier);
+  let names = app.short_names(app.data.jobs.items.iter().map(|job| job.name.as_str()));
 
   draw_resource_block(
     f,
@@ -176,27 +204,23 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
         app.palette,
       ),
       resource: &mut app.data.jobs,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      let mut cells = vec![
+      let cells = vec![
         Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
+        Cell::from(names.get(&c.name).to_owned()),
         Cell::from(c.completions.to_owned()),
         Cell::from(c.duration.to_string()),
+        Cell::from(c.status_summary.to_owned()),
+        Cell::from(c.backoff_limit.to_owned()),
+        Cell::from(c.age.to_owned()),
       ];
-      if tier >= ViewTier::Standard {
-        cells.push(Cell::from(c.status_summary.to_owned()));
-      }
-      if tier >= ViewTier::Wide {
-        cells.push(Cell::from(c.backoff_limit.to_owned()));
-      }
-      cells.push(Cell::from(c.age.to_owned()));
-      Row::new(cells).style(style_text(app.palette))
+      columns.row(cells).style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -1,4 +1,8 @@
-use std::{fmt, ops::Deref, sync::OnceLock};
+use std::{
+  fmt,
+  ops::Deref,
+  sync::{PoisonError, RwLock},
+};
 
 use log::warn;
 
@@ -57,17 +61,36 @@ generate_keybindings! {
   submit,
   filter,
   refresh,
+  poll_now,
+  toggle_polling,
+  retry_failed,
   toggle_theme,
   cycle_theme_prev,
   cycle_main_views,
   cycle_main_views_prev,
   reset_navigation,
+  find_resource,
+  pick_namespace,
+  export_csv,
+  copy_row,
+  copy_kubectl_command,
+  export_json,
   jump_to_current_context,
   jump_to_all_context,
+  goto_view,
   jump_to_utilization,
   jump_to_troubleshoot,
+  jump_to_dashboard,
+  jump_to_watchlist,
   copy_to_clipboard,
+  paste_from_clipboard,
+  apply_from_clipboard,
   dump_error_log,
+  audit_log,
+  connection_info,
+  api_stats,
+  toggle_redact,
+  snapshot_namespace,
   pg_up,
   pg_down,
   home,
@@ -77,16 +100,33 @@ generate_keybindings! {
   left,
   right,
   toggle_info,
+  cycle_layout,
+  cycle_footer,
+  toggle_problems_only,
+  toggle_short_names,
+  toggle_follow_selection,
   shell_exec,
   log_auto_scroll,
   select_all_namespace,
+  create_namespace,
+  label_selector,
   open_action_menu,
   delete_resource,
+  toggle_select,
+  scroll_columns_left,
+  scroll_columns_right,
   restart_resource,
   previous_logs,
   jump_to_namespace,
   describe_resource,
   resource_yaml,
+  copy_yaml,
+  toggle_yaml_summary,
+  diff_last_applied,
+  refresh_describe,
+  toggle_pod_constraints,
+  toggle_container_mounts,
+  toggle_container_env,
   edit_resource,
   decode_secret,
   jump_to_pods,
@@ -101,12 +141,29 @@ generate_keybindings! {
   jump_to_more_resources,
   jump_to_dynamic_resources,
   aggregate_logs,
+  jump_to_logs,
+  show_replica_sets,
+  follow_rollout,
+  cycle_node_pods_sort,
   port_forward,
+  pin,
+  open_in_console,
   port_forwards_list,
   cycle_group_by,
+  group_events,
+  group_pods,
+  toggle_group,
+  collapse_all_groups,
+  expand_all_groups,
   toggle_wide_columns,
   toggle_log_timestamps,
-  toggle_log_wrap
+  toggle_log_wrap,
+  toggle_log_json,
+  log_since,
+  log_search,
+  log_search_next,
+  log_search_prev,
+  log_mark
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
@@ -177,7 +234,25 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
   refresh: KeyBinding {
     key: Key::Ctrl('r'),
     alt: None,
-    desc: "Refresh data",
+    desc: "Reconnect to the selected context and reload",
+    context: HContext::General,
+  },
+  poll_now: KeyBinding {
+    key: Key::F(5),
+    alt: None,
+    desc: "Re-fetch the current view now",
+    context: HContext::General,
+  },
+  toggle_polling: KeyBinding {
+    key: Key::Shift('p'),
+    alt: None,
+    desc: "Pause/resume auto-refresh",
+    context: HContext::General,
+  },
+  retry_failed: KeyBinding {
+    key: Key::Shift('r'),
+    alt: None,
+    desc: "Retry the failed request of the current view",
     context: HContext::General,
   },
   toggle_theme: KeyBinding {
@@ -192,6 +267,42 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Cycle theme (previous)",
     context: HContext::General,
   },
+  find_resource: KeyBinding {
+    key: Key::Ctrl('p'),
+    alt: None,
+    desc: "Find a loaded resource by name and jump to it",
+    context: HContext::General,
+  },
+  pick_namespace: KeyBinding {
+    key: Key::Ctrl('n'),
+    alt: None,
+    desc: "Pick the namespace to show from a searchable list",
+    context: HContext::General,
+  },
+  export_csv: KeyBinding {
+    key: Key::Shift('e'),
+    alt: None,
+    desc: "Export the current table to CSV",
+    context: HContext::General,
+  },
+  export_json: KeyBinding {
+    key: Key::Ctrl('e'),
+    alt: None,
+    desc: "Export the current table to JSON",
+    context: HContext::General,
+  },
+  copy_row: KeyBinding {
+    key: Key::Shift('y'),
+    alt: None,
+    desc: "Copy the selected row to the clipboard, tab-separated",
+    context: HContext::General,
+  },
+  copy_kubectl_command: KeyBinding {
+    key: Key::Shift('k'),
+    alt: None,
+    desc: "Copy the kubectl command for the current view to the clipboard",
+    context: HContext::General,
+  },
   jump_to_current_context: KeyBinding {
     key: Key::Shift('a'),
     alt: None,
@@ -204,6 +315,12 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Switch to all contexts view",
     context: HContext::General,
   },
+  goto_view: KeyBinding {
+    key: Key::Char('g'),
+    alt: None,
+    desc: "Go to a view with the next key (see below); groups in events and utilization",
+    context: HContext::General,
+  },
   jump_to_utilization: KeyBinding {
     key: Key::Shift('u'),
     alt: None,
@@ -216,6 +333,18 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Switch to troubleshoot view",
     context: HContext::General,
   },
+  jump_to_dashboard: KeyBinding {
+    key: Key::Shift('h'),
+    alt: None,
+    desc: "Switch to cluster dashboard",
+    context: HContext::General,
+  },
+  jump_to_watchlist: KeyBinding {
+    key: Key::Shift('b'),
+    alt: None,
+    desc: "Switch to the watchlist of pinned resources",
+    context: HContext::General,
+  },
   cycle_main_views: KeyBinding {
     key: Key::Tab,
     alt: None,
@@ -240,12 +369,54 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Copy log/output to clipboard",
     context: HContext::General,
   },
+  paste_from_clipboard: KeyBinding {
+    key: Key::Ctrl('y'),
+    alt: None,
+    desc: "Paste the clipboard into the input being typed",
+    context: HContext::General,
+  },
+  apply_from_clipboard: KeyBinding {
+    key: Key::Shift('i'),
+    alt: None,
+    desc: "Apply the manifest in the clipboard, after confirming",
+    context: HContext::General,
+  },
   dump_error_log: KeyBinding {
     key: Key::Shift('d'),
     alt: None,
     desc: "Dump recent errors to file",
     context: HContext::General,
   },
+  audit_log: KeyBinding {
+    key: Key::Ctrl('a'),
+    alt: None,
+    desc: "Show the actions taken this session",
+    context: HContext::General,
+  },
+  connection_info: KeyBinding {
+    key: Key::Alt('c'),
+    alt: None,
+    desc: "Show the server, user, auth and TLS the kube client uses",
+    context: HContext::General,
+  },
+  api_stats: KeyBinding {
+    key: Key::Alt('d'),
+    alt: None,
+    desc: "Show API requests per view, their rate, errors and latency",
+    context: HContext::General,
+  },
+  toggle_redact: KeyBinding {
+    key: Key::Alt('r'),
+    alt: None,
+    desc: "Toggle redact mode, masking passwords, tokens and other sensitive values",
+    context: HContext::General,
+  },
+  snapshot_namespace: KeyBinding {
+    key: Key::Ctrl('s'),
+    alt: None,
+    desc: "Save every resource of the namespace to YAML files, secrets redacted",
+    context: HContext::General,
+  },
   down: KeyBinding {
     key: Key::Down,
     alt: Some(Key::Char('j')),
@@ -278,8 +449,8 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
   },
   end: KeyBinding {
     key: Key::End,
-    alt: None,
-    desc: "Scroll to bottom",
+    alt: Some(Key::Shift('g')),
+    desc: "Scroll to bottom (resumes following in logs)",
     context: HContext::General,
   },
   left: KeyBinding {
@@ -300,6 +471,36 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Show/Hide info bar",
     context: HContext::Overview,
   },
+  cycle_layout: KeyBinding {
+    key: Key::Ctrl('l'),
+    alt: None,
+    desc: "Cycle layout: auto (by width), wide, compact",
+    context: HContext::General,
+  },
+  cycle_footer: KeyBinding {
+    key: Key::Alt('v'),
+    alt: None,
+    desc: "Cycle footer: normal, quiet (no info toasts), verbose (last request)",
+    context: HContext::General,
+  },
+  toggle_problems_only: KeyBinding {
+    key: Key::Char('!'),
+    alt: None,
+    desc: "Show only unhealthy rows / all rows",
+    context: HContext::General,
+  },
+  toggle_short_names: KeyBinding {
+    key: Key::Alt('n'),
+    alt: None,
+    desc: "Show pod, ReplicaSet and Job names without / with their generated hash",
+    context: HContext::General,
+  },
+  toggle_follow_selection: KeyBinding {
+    key: Key::Alt('f'),
+    alt: None,
+    desc: "Keep the selection on the same resource / row index across refreshes",
+    context: HContext::General,
+  },
   shell_exec: KeyBinding {
     key: Key::Char('s'),
     alt: None,
@@ -321,7 +522,19 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
   select_all_namespace: KeyBinding {
     key: Key::Char('a'),
     alt: None,
-    desc: "Select all namespaces",
+    desc: "Toggle all namespaces",
+    context: HContext::Overview,
+  },
+  create_namespace: KeyBinding {
+    key: Key::Shift('n'),
+    alt: None,
+    desc: "Create a namespace (in the namespace block)",
+    context: HContext::Overview,
+  },
+  label_selector: KeyBinding {
+    key: Key::Shift('s'),
+    alt: None,
+    desc: "Filter pods by label selector",
     context: HContext::Overview,
   },
   open_action_menu: KeyBinding {
@@ -336,6 +549,24 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Delete selected resource",
     context: HContext::Overview,
   },
+  toggle_select: KeyBinding {
+    key: Key::Char(' '),
+    alt: None,
+    desc: "Select row for a batch delete",
+    context: HContext::Overview,
+  },
+  scroll_columns_left: KeyBinding {
+    key: Key::Char('<'),
+    alt: None,
+    desc: "Scroll table columns left",
+    context: HContext::Overview,
+  },
+  scroll_columns_right: KeyBinding {
+    key: Key::Char('>'),
+    alt: None,
+    desc: "Scroll table columns right",
+    context: HContext::Overview,
+  },
   restart_resource: KeyBinding {
     key: Key::Char('r'),
     alt: None,
@@ -351,7 +582,7 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
   describe_resource: KeyBinding {
     key: Key::Char('d'),
     alt: None,
-    desc: "Describe resource",
+    desc: "Describe the selected resource, in any view",
     context: HContext::Overview,
   },
   resource_yaml: KeyBinding {
@@ -360,6 +591,48 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Get resource YAML",
     context: HContext::Overview,
   },
+  copy_yaml: KeyBinding {
+    key: Key::Alt('y'),
+    alt: None,
+    desc: "Copy the selected resource's YAML to the clipboard",
+    context: HContext::Overview,
+  },
+  toggle_yaml_summary: KeyBinding {
+    key: Key::Char('y'),
+    alt: None,
+    desc: "Switch the YAML view between the summary and the full YAML",
+    context: HContext::Overview,
+  },
+  diff_last_applied: KeyBinding {
+    key: Key::Char('v'),
+    alt: None,
+    desc: "Diff last-applied configuration vs live (from describe/YAML)",
+    context: HContext::Overview,
+  },
+  refresh_describe: KeyBinding {
+    key: Key::Char('u'),
+    alt: None,
+    desc: "Refetch describe output instead of showing the cached one",
+    context: HContext::Overview,
+  },
+  toggle_pod_constraints: KeyBinding {
+    key: Key::Char('z'),
+    alt: None,
+    desc: "Expand/fold a described pod's tolerations, node selector and affinity",
+    context: HContext::Overview,
+  },
+  toggle_container_mounts: KeyBinding {
+    key: Key::Char('z'),
+    alt: None,
+    desc: "Expand/fold the selected container's volume mounts",
+    context: HContext::Overview,
+  },
+  toggle_container_env: KeyBinding {
+    key: Key::Shift('z'),
+    alt: None,
+    desc: "Expand/fold the selected container's environment variables",
+    context: HContext::Overview,
+  },
   edit_resource: KeyBinding {
     key: Key::Char('e'),
     alt: None,
@@ -444,12 +717,48 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Aggregate logs for resource",
     context: HContext::Overview,
   },
+  jump_to_logs: KeyBinding {
+    key: Key::Shift('v'),
+    alt: None,
+    desc: "View logs of the newest ready pod of the workload or service",
+    context: HContext::Overview,
+  },
+  show_replica_sets: KeyBinding {
+    key: Key::Shift('r'),
+    alt: None,
+    desc: "Show ReplicaSets of selected deployment",
+    context: HContext::Overview,
+  },
+  follow_rollout: KeyBinding {
+    key: Key::Shift('w'),
+    alt: None,
+    desc: "Follow the pods of selected deployment through its rollout",
+    context: HContext::Overview,
+  },
+  cycle_node_pods_sort: KeyBinding {
+    key: Key::Char('o'),
+    alt: None,
+    desc: "Cycle sort in pods by node view",
+    context: HContext::Overview,
+  },
   port_forward: KeyBinding {
     key: Key::Char('f'),
     alt: None,
     desc: "Port-forward selected pod/service",
     context: HContext::Overview,
   },
+  pin: KeyBinding {
+    key: Key::Char('b'),
+    alt: None,
+    desc: "Pin selected resource to the watchlist, or unpin it",
+    context: HContext::Overview,
+  },
+  open_in_console: KeyBinding {
+    key: Key::Shift('o'),
+    alt: None,
+    desc: "Open selected resource in the configured web console",
+    context: HContext::Overview,
+  },
   port_forwards_list: KeyBinding {
     key: Key::Shift('f'),
     alt: None,
@@ -462,6 +771,36 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Cycle through grouping",
     context: HContext::Utilization,
   },
+  group_events: KeyBinding {
+    key: Key::Char('g'),
+    alt: None,
+    desc: "Group repeated events, or list each one",
+    context: HContext::Overview,
+  },
+  group_pods: KeyBinding {
+    key: Key::Alt('g'),
+    alt: None,
+    desc: "Group pods by workload, or list each one",
+    context: HContext::Overview,
+  },
+  toggle_group: KeyBinding {
+    key: Key::Char('z'),
+    alt: None,
+    desc: "Expand/fold the group of the selected row in grouped pods",
+    context: HContext::Overview,
+  },
+  collapse_all_groups: KeyBinding {
+    key: Key::Char('['),
+    alt: None,
+    desc: "Fold every group in grouped pods",
+    context: HContext::Overview,
+  },
+  expand_all_groups: KeyBinding {
+    key: Key::Char(']'),
+    alt: None,
+    desc: "Expand every group in grouped pods",
+    context: HContext::Overview,
+  },
   toggle_wide_columns: KeyBinding {
     key: Key::Char('w'),
     alt: None,
@@ -480,9 +819,49 @@ const DEFAULT_KEYBINDINGS: KeyBindings = KeyBindings {
     desc: "Toggle log line wrap",
     context: HContext::Logs,
   },
+  toggle_log_json: KeyBinding {
+    key: Key::Shift('j'),
+    alt: None,
+    desc: "Toggle pretty-printed JSON log lines",
+    context: HContext::Logs,
+  },
+  log_since: KeyBinding {
+    key: Key::Shift('s'),
+    alt: None,
+    desc: "Show logs since a duration (e.g. 10m, 1h)",
+    context: HContext::Logs,
+  },
+  log_search: KeyBinding {
+    key: Key::Char('/'),
+    alt: None,
+    desc: "Search the logs",
+    context: HContext::Logs,
+  },
+  log_search_next: KeyBinding {
+    key: Key::Char('n'),
+    alt: None,
+    desc: "Jump to the next search match",
+    context: HContext::Logs,
+  },
+  log_search_prev: KeyBinding {
+    key: Key::Shift('n'),
+    alt: None,
+    desc: "Jump to the previous search match",
+    context: HContext::Logs,
+  },
+  log_mark: KeyBinding {
+    key: Key::Shift('m'),
+    alt: None,
+    desc: "Mark the logs here, with an optional note",
+    context: HContext::Logs,
+  },
 };
 
-static ACTIVE_KEYBINDINGS: OnceLock<KeyBindings> = OnceLock::new();
+static BUILT_IN_KEYBINDINGS: KeyBindings = DEFAULT_KEYBINDINGS;
+
+/// The bindings in use. A config reload swaps in a new set; the old one is
+/// leaked, since borrows of it may still be alive, but reloads are rare.
+static ACTIVE_KEYBINDINGS: RwLock<&'static KeyBindings> = RwLock::new(&BUILT_IN_KEYBINDINGS);
 
 pub struct ActiveKeyBindings;
 
@@ -490,15 +869,21 @@ impl Deref for ActiveKeyBindings {
   type Target = KeyBindings;
 
   fn deref(&self) -> &Self::Target {
-    ACTIVE_KEYBINDINGS.get_or_init(|| DEFAULT_KEYBINDINGS.clone())
+    *ACTIVE_KEYBINDINGS
+      .read()
+      .unwrap_or_else(PoisonError::into_inner)
   }
 }
 
 pub static DEFAULT_KEYBINDING: ActiveKeyBindings = ActiveKeyBindings;
 
+/// Build the bindings from the config and make them the active set. Called
+/// at start-up and again when the config file is reloaded.
 pub fn initialize_keybindings(config: &KdashConfig) -> Vec<String> {
   let (keybindings, warnings) = DEFAULT_KEYBINDINGS.with_overrides(config);
-  let _ = ACTIVE_KEYBINDINGS.set(keybindings);
+  *ACTIVE_KEYBINDINGS
+    .write()
+    .unwrap_or_else(PoisonError::into_inner) = Box::leak(Box::new(keybindings));
 
   for warning in &warnings {
     warn!("{}", warning);
@@ -507,6 +892,15 @@ pub fn initialize_keybindings(config: &KdashConfig) -> Vec<String> {
   warnings
 }
 
+/// Second keys after `goto_view`, by the resource tab each opens and its name,
+/// e.g. `g` `p` for pods.
+pub const GOTO_VIEWS: [(char, usize, &str); 4] = [
+  ('p', 0, "pods"),
+  ('s', 1, "services"),
+  ('n', 2, "nodes"),
+  ('d', 6, "deployments"),
+];
+
 /// A help-page section: a context-group title plus its `(keys, description)`
 /// rows. Drives the grouped, multi-column help page.
 pub struct HelpSection {
@@ -527,11 +921,19 @@ pub fn get_help_sections() -> Vec<HelpSection> {
   ]
   .into_iter()
   .filter_map(|ctx| {
-    let rows: Vec<(String, String)> = items
+    let mut rows: Vec<(String, String)> = items
       .iter()
       .filter(|b| b.context == ctx)
       .map(|b| (key_label(b), b.desc.to_string()))
       .collect();
+    if ctx == HContext::General {
+      rows.extend(GOTO_VIEWS.iter().map(|(code, _, view)| {
+        (
+          format!("{} {}", DEFAULT_KEYBINDING.goto_view.key.symbol(), code),
+          format!("Go to {}", view),
+        )
+      }));
+    }
     (!rows.is_empty()).then_some(HelpSection {
       title: ctx.label(),
       context: ctx,
@@ -561,6 +963,20 @@ mod tests {
     assert!(DEFAULT_KEYBINDING.as_iter().len() >= 28);
   }
 
+  #[test]
+  fn test_help_lists_the_goto_views() {
+    let general = get_help_sections()
+      .into_iter()
+      .find(|section| section.context == HContext::General)
+      .unwrap();
+    assert!(general
+      .rows
+      .contains(&("g p".to_string(), "Go to pods".to_string())));
+    assert!(general
+      .rows
+      .contains(&("g d".to_string(), "Go to deployments".to_string())));
+  }
+
   #[test]
   fn test_with_overrides_updates_known_binding() {
     let config = KdashConfig {
@@ -0,0 +1,86 @@
+//! Wide and compact layouts. The wide layout puts panes side by side, which
+//! needs about 100 columns; the compact one stacks them in a single column so
+//! KDash stays usable in a small terminal or tmux pane. By default the layout
+//! follows the terminal width.
+use super::App;
+
+/// Narrower terminals get the compact layout in auto mode. The info bar's
+/// side-by-side panes need this many columns.
+pub const COMPACT_LAYOUT_WIDTH: u16 = 100;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LayoutMode {
+  /// Compact below `COMPACT_LAYOUT_WIDTH` columns, wide otherwise.
+  #[default]
+  Auto,
+  Wide,
+  Compact,
+}
+
+impl LayoutMode {
+  fn next(self) -> LayoutMode {
+    match self {
+      LayoutMode::Auto => LayoutMode::Wide,
+      LayoutMode::Wide => LayoutMode::Compact,
+      LayoutMode::Compact => LayoutMode::Auto,
+    }
+  }
+}
+
+impl App {
+  /// Whether views draw their panes stacked in one column.
+  pub fn compact_layout(&self) -> bool {
+    match self.layout_mode {
+      LayoutMode::Auto => self.size.width > 0 && self.size.width < COMPACT_LAYOUT_WIDTH,
+      LayoutMode::Wide => false,
+      LayoutMode::Compact => true,
+    }
+  }
+
+  /// Cycle auto, wide and compact, saying which layout is now used.
+  pub fn cycle_layout(&mut self) {
+    self.layout_mode = self.layout_mode.next();
+    let layout = if self.compact_layout() {
+      "compact"
+    } else {
+      "wide"
+    };
+    let message = match self.layout_mode {
+      LayoutMode::Auto => format!("Layout: auto ({} at {} columns)", layout, self.size.width),
+      _ => format!("Layout: {}", layout),
+    };
+    self.set_status_message(message);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ratatui::layout::Rect;
+
+  use super::*;
+
+  #[test]
+  fn test_layout_follows_the_width_until_chosen() {
+    let mut app = App {
+      size: Rect::new(0, 0, 80, 24),
+      ..App::default()
+    };
+    assert!(app.compact_layout(), "a small terminal is compact in auto");
+    app.size.width = 160;
+    assert!(!app.compact_layout());
+
+    app.cycle_layout();
+    assert_eq!(app.layout_mode, LayoutMode::Wide);
+    app.size.width = 80;
+    assert!(!app.compact_layout(), "a chosen layout ignores the width");
+
+    app.cycle_layout();
+    assert_eq!(app.layout_mode, LayoutMode::Compact);
+    app.size.width = 160;
+    assert!(app.compact_layout());
+
+    app.cycle_layout();
+    assert_eq!(app.layout_mode, LayoutMode::Auto);
+    assert!(!app.compact_layout());
+  }
+}
@@ -0,0 +1,236 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use k8s_openapi::{
+  api::core::v1::{LimitRange, LimitRangeItem},
+  apimachinery::pkg::api::resource::Quantity,
+};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
+
+use super::{
+  models::{AppResource, KubeResource, Named},
+  utils, ActiveBlock, App,
+};
+use crate::{
+  draw_resource_tab,
+  network::Network,
+  ui::utils::{
+    describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
+    get_describe_active, get_resource_title, help_bold_line, style_text, title_with_dual_style,
+    ColumnDef, ResourceTableProps, ViewTier,
+  },
+};
+
+/// One limit of a LimitRange. A LimitRange constrains several types
+/// (Container, Pod, PersistentVolumeClaim), so it is listed as one row per type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KubeLimitRange {
+  pub namespace: String,
+  pub name: String,
+  pub limit_type: String,
+  pub min: String,
+  pub max: String,
+  pub default_request: String,
+  pub default_limit: String,
+  pub age: String,
+  k8s_obj: LimitRange,
+}
+
+/// `cpu=100m, memory=128Mi`, or `-` when nothing is set.
+fn format_quantities(quantities: Option<&BTreeMap<String, Quantity>>) -> String {
+  match quantities.filter(|q| !q.is_empty()) {
+    Some(q) => q
+      .iter()
+      .map(|(resource, value)| format!("{}={}", resource, value.0))
+      .collect::<Vec<_>>()
+      .join(", "),
+    None => "-".into(),
+  }
+}
+
+impl KubeLimitRange {
+  pub fn from_api(limit_range: LimitRange) -> Vec<Self> {
+    let limit_range = utils::sanitize_obj(limit_range);
+    let namespace = limit_range.metadata.namespace.clone().unwrap_or_default();
+    let name = limit_range.metadata.name.clone().unwrap_or_default();
+    let age = utils::to_age(limit_range.metadata.creation_timestamp.as_ref(), Utc::now());
+
+    let mut items: Vec<LimitRangeItem> = limit_range
+      .spec
+      .as_ref()
+      .map(|s| s.limits.clone())
+      .unwrap_or_default();
+    // Keep a LimitRange without limits visible rather than dropping it.
+    if items.is_empty() {
+      items.push(LimitRangeItem {
+        type_: "-".into(),
+        ..LimitRangeItem::default()
+      });
+    }
+
+    items
+      .into_iter()
+      .map(|item| KubeLimitRange {
+        namespace: namespace.clone(),
+        name: name.clone(),
+        limit_type: item.type_.clone(),
+        min: format_quantities(item.min.as_ref()),
+        max: format_quantities(item.max.as_ref()),
+        default_request: format_quantities(item.default_request.as_ref()),
+        default_limit: format_quantities(item.default.as_ref()),
+        age: age.clone(),
+        k8s_obj: limit_range.clone(),
+      })
+      .collect()
+  }
+}
+
+impl Named for KubeLimitRange {
+  fn get_name(&self) -> &String {
+    &self.name
+  }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
+}
+
+impl KubeResource<LimitRange> for KubeLimitRange {
+  fn get_k8s_obj(&self) -> &LimitRange {
+    &self.k8s_obj
+  }
+}
+
+static LIMIT_RANGES_TITLE: &str = "LimitRanges";
+
+pub struct LimitRangeResource {}
+
+#[async_trait]
+impl AppResource for LimitRangeResource {
+  fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    draw_resource_tab!(
+      LIMIT_RANGES_TITLE,
+      block,
+      f,
+      app,
+      area,
+      Self::render,
+      draw_block,
+      app.data.limit_ranges
+    );
+  }
+
+  async fn get_resource(nw: &Network<'_>) {
+    let items: Vec<KubeLimitRange> = nw
+      .get_namespaced_resources(KubeLimitRange::from_api)
+      .await
+      .into_iter()
+      .flatten()
+      .collect();
+
+    let mut app = nw.app.lock().await;
+    app.data.limit_ranges.set_items(items);
+  }
+}
+
+pub(crate) const LR_COLUMNS: [ColumnDef; 8] = [
+  ColumnDef::all("Namespace", 12, 12, 12),
+  ColumnDef::all("Name", 14, 14, 14),
+  ColumnDef::all("Type", 12, 12, 12),
+  ColumnDef::all("Min", 12, 12, 12),
+  ColumnDef::all("Max", 12, 12, 12),
+  ColumnDef::all("Default Request", 15, 15, 15),
+  ColumnDef::all("Default Limit", 15, 15, 15),
+  ColumnDef::all("Age", 8, 8, 8),
+];
+
+fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  let load = app.view_load(ActiveBlock::LimitRanges);
+  let title = get_resource_title(
+    app,
+    LIMIT_RANGES_TITLE,
+    "",
+    app.data.limit_ranges.items.len(),
+  );
+
+  let columns = app.column_layout(ActiveBlock::LimitRanges, ViewTier::Compact);
+
+  draw_resource_block(
+    f,
+    area,
+    ResourceTableProps {
+      title,
+      inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
+      resource: &mut app.data.limit_ranges,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
+    },
+    |c| {
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.limit_type.to_owned()),
+          Cell::from(c.min.to_owned()),
+          Cell::from(c.max.to_owned()),
+          Cell::from(c.default_request.to_owned()),
+          Cell::from(c.default_limit.to_owned()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
+    },
+    app.palette,
+    load,
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::app::test_utils::*;
+
+  #[test]
+  fn test_limit_ranges_from_api() {
+    let list = load_resource_from_file::<LimitRange>("limit_ranges");
+    let ranges: Vec<KubeLimitRange> = list
+      .items
+      .iter()
+      .cloned()
+      .flat_map(KubeLimitRange::from_api)
+      .collect();
+
+    assert_eq!(ranges.len(), 2);
+    assert_eq!(
+      ranges[0],
+      KubeLimitRange {
+        namespace: "team-a".into(),
+        name: "defaults".into(),
+        limit_type: "Container".into(),
+        min: "cpu=50m".into(),
+        max: "cpu=2, memory=2Gi".into(),
+        default_request: "cpu=100m, memory=128Mi".into(),
+        default_limit: "cpu=500m, memory=512Mi".into(),
+        age: utils::to_age(Some(&get_time("2024-03-01T10:00:00Z")), Utc::now()),
+        k8s_obj: list.items[0].clone(),
+      }
+    );
+    assert_eq!(ranges[1].limit_type, "PersistentVolumeClaim");
+    assert_eq!(ranges[1].min, "storage=1Gi");
+    assert_eq!(ranges[1].default_request, "-");
+    assert_eq!(ranges[1].default_limit, "-");
+  }
+
+  #[test]
+  fn test_limit_range_without_limits_keeps_a_row() {
+    let ranges = KubeLimitRange::from_api(LimitRange::default());
+    assert_eq!(ranges.len(), 1);
+    assert_eq!(ranges[0].limit_type, "-");
+    assert_eq!(ranges[0].max, "-");
+  }
+}
@@ -0,0 +1,92 @@
+//! Per-view fetch state, so an empty table can tell "still loading" apart
+//! from "nothing there" and "the request failed".
+
+/// Outcome of the last fetch for a view.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum LoadState {
+  #[default]
+  Loading,
+  Loaded,
+  /// Loaded, but the API returned no items.
+  Empty,
+  /// The fetch failed; holds the message shown in the view.
+  Error(String),
+}
+
+/// What an empty view renders: its load state, plus the tick it is drawn on
+/// so the loading spinner advances with the event loop.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ViewLoad {
+  pub state: LoadState,
+  pub tick: u64,
+  /// Only part of the list is loaded; scrolling to the end fetches more.
+  pub has_more: bool,
+  /// How long rows stay highlighted after their resource was created or
+  /// changed state; `None` when change highlighting is off.
+  pub highlight_changes: Option<chrono::Duration>,
+  /// Only rows the kind's health check flags are shown.
+  pub problems_only: bool,
+  /// The selection stays on the same resource when the rows change, rather
+  /// than on the same row index.
+  pub follow_selection: bool,
+}
+
+/// Items requested per list call; further pages load as the table is scrolled.
+pub const LIST_PAGE_SIZE: u32 = 500;
+/// Rows before the end of a table at which its next page is requested.
+pub const PAGE_PREFETCH_ROWS: usize = 20;
+
+/// How much of a paginated list a view has loaded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ListPages {
+  /// Pages fetched on each poll, from the first one.
+  pub requested: usize,
+  /// Pages the last fetch returned.
+  pub loaded: usize,
+  /// The API server had items past the last loaded page.
+  pub has_more: bool,
+}
+
+impl Default for ListPages {
+  fn default() -> Self {
+    ListPages {
+      requested: 1,
+      loaded: 0,
+      has_more: false,
+    }
+  }
+}
+
+impl ListPages {
+  /// Ask for one more page, unless the list is complete or the last request
+  /// has not come back yet. Returns whether a page was requested.
+  pub fn request_next(&mut self) -> bool {
+    if self.has_more && self.loaded >= self.requested {
+      self.requested += 1;
+      true
+    } else {
+      false
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_request_next_waits_for_the_pending_page() {
+    let mut pages = ListPages::default();
+    assert!(!pages.request_next(), "nothing loaded yet");
+
+    pages.loaded = 1;
+    pages.has_more = true;
+    assert!(pages.request_next());
+    assert_eq!(pages.requested, 2);
+    assert!(!pages.request_next(), "page 2 is still on its way");
+
+    pages.loaded = 2;
+    pages.has_more = false;
+    assert!(!pages.request_next(), "the list is complete");
+  }
+}
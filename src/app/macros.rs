@@ -0,0 +1,345 @@
+//! Quick-action macros from `macros` in the config file: named runbooks of
+//! restarts, scales and deletes of resources named in one view, each bound to
+//! a key. The key asks once for the whole macro; the steps then go to the
+//! network thread in order, one request per resource, and run one after the
+//! other as it handles one event at a time. The toast counts the steps as they
+//! run, and a failed step stops the ones after it.
+use anyhow::anyhow;
+use kube::discovery::Scope;
+use log::warn;
+
+use super::{
+  actions::{ConfirmAction, Modal},
+  columns,
+  dynamic::api_resource_for_block,
+  ActiveBlock, App,
+};
+use crate::{
+  config::{KdashConfig, MacroConfig, MacroStepConfig},
+  event::Key,
+  network::{IoEvent, ResourcePatch},
+};
+
+/// Views whose workloads a `restart` step rolls out again.
+const RESTART_VIEWS: [ActiveBlock; 3] = [
+  ActiveBlock::Deployments,
+  ActiveBlock::StatefulSets,
+  ActiveBlock::DaemonSets,
+];
+
+/// Views whose workloads a `scale` step sets the replicas of.
+const SCALE_VIEWS: [ActiveBlock; 4] = [
+  ActiveBlock::Deployments,
+  ActiveBlock::StatefulSets,
+  ActiveBlock::ReplicaSets,
+  ActiveBlock::ReplicationControllers,
+];
+
+/// The requests of one step, one per named resource. Resources of namespaced
+/// kinds without a namespace of their own are in `selected_ns`.
+fn step_events(step: &MacroStepConfig, selected_ns: Option<&str>) -> Result<Vec<IoEvent>, String> {
+  let block = columns::view_block(&step.view).ok_or_else(|| {
+    format!(
+      "unknown view {}. Valid views: {}",
+      step.view,
+      columns::view_names().collect::<Vec<_>>().join(", ")
+    )
+  })?;
+  let (_, scope) = api_resource_for_block(block, None)
+    .ok_or_else(|| format!("{} cannot be changed by a macro", step.view))?;
+  let namespace = match scope {
+    Scope::Cluster => None,
+    Scope::Namespaced => Some(
+      step
+        .namespace
+        .as_deref()
+        .or(selected_ns)
+        .ok_or_else(|| format!("{} needs a namespace; select one or set it", step.view))?
+        .to_owned(),
+    ),
+  };
+  let patch = match step.action.trim().to_lowercase().as_str() {
+    "restart" if RESTART_VIEWS.contains(&block) => Some(ResourcePatch::RolloutRestart),
+    "scale" if SCALE_VIEWS.contains(&block) => {
+      Some(ResourcePatch::SetReplicas(step.replicas.ok_or_else(
+        || format!("scale of {} needs replicas", step.view),
+      )?))
+    }
+    "delete" => None,
+    "restart" | "scale" => return Err(format!("{} does not apply to {}", step.action, step.view)),
+    _ => {
+      return Err(format!(
+        "unknown action {}. Valid actions: restart, scale, delete",
+        step.action
+      ))
+    }
+  };
+  if step.names.is_empty() {
+    return Err(format!(
+      "{} of {} names no resources",
+      step.action, step.view
+    ));
+  }
+  Ok(
+    step
+      .names
+      .iter()
+      .map(|name| match &patch {
+        Some(patch) => IoEvent::PatchResource {
+          block,
+          name: name.clone(),
+          namespace: namespace.clone(),
+          patch: patch.clone(),
+        },
+        None => IoEvent::DeleteResource {
+          block,
+          name: name.clone(),
+          namespace: namespace.clone(),
+        },
+      })
+      .collect(),
+  )
+}
+
+/// Every request of `config`'s steps, in order.
+fn macro_events(config: &MacroConfig, selected_ns: Option<&str>) -> Result<Vec<IoEvent>, String> {
+  let mut events = vec![];
+  for (i, step) in config.steps.iter().enumerate() {
+    events.extend(step_events(step, selected_ns).map_err(|e| format!("step {}: {}", i + 1, e))?);
+  }
+  if events.is_empty() {
+    return Err("it has no steps".into());
+  }
+  Ok(events)
+}
+
+/// One warning per macro that cannot run: a bad key, or a step that names an
+/// unknown action or view. A step without a namespace is only checked when it
+/// runs.
+pub fn macro_warnings(config: &KdashConfig) -> Vec<String> {
+  let mut warnings = vec![];
+  for quick_action in &config.macros {
+    if let Err(e) = quick_action.key.parse::<Key>() {
+      warnings.push(format!(
+        "Invalid key for macro {}: {} ({})",
+        quick_action.name, quick_action.key, e
+      ));
+    }
+    // any namespace will do to check the rest of the steps
+    if let Err(e) = macro_events(quick_action, Some("default")) {
+      warnings.push(format!("Macro {} cannot run: {}", quick_action.name, e));
+    }
+  }
+  for warning in &warnings {
+    warn!("{}", warning);
+  }
+  warnings
+}
+
+impl App {
+  /// The macro bound to `key`, if any.
+  pub fn macro_for_key(&self, key: Key) -> Option<MacroConfig> {
+    self
+      .config
+      .macros
+      .iter()
+      .find(|config| config.key.parse::<Key>() == Ok(key))
+      .cloned()
+  }
+
+  /// Ask to run `config`, listing every request it will make.
+  pub fn open_macro(&mut self, config: &MacroConfig) {
+    let events = match macro_events(config, self.data.selected.ns.as_deref()) {
+      Ok(events) => events,
+      Err(e) => {
+        self.handle_error(anyhow!("Unable to run macro {}: {}", config.name, e));
+        return;
+      }
+    };
+    let mut lines = vec![format!(
+      "Run macro {}: {} steps, in order?",
+      config.name,
+      events.len()
+    )];
+    lines.extend(events.iter().enumerate().filter_map(|(i, event)| {
+      let audit = event.audit_action(self)?;
+      Some(format!("  {}. {} {}", i + 1, audit.action, audit.target))
+    }));
+    self.open_modal(Modal::confirm(
+      "Run macro",
+      lines.join("\n"),
+      ConfirmAction::Macro {
+        name: config.name.clone(),
+        events,
+      },
+    ));
+  }
+
+  /// Dispatch the steps of a confirmed macro in order.
+  pub async fn run_macro(&mut self, name: String, events: Vec<IoEvent>) {
+    let steps = events.len();
+    self.running_macro = Some(name.clone());
+    self.set_status_message(format!("Running macro {}", name));
+    for (i, event) in events.into_iter().enumerate() {
+      self
+        .dispatch(IoEvent::MacroStep {
+          name: name.clone(),
+          step: i + 1,
+          steps,
+          event: Box::new(event),
+        })
+        .await;
+    }
+  }
+
+  /// Whether the steps of macro `name` still run; a failed step or another
+  /// macro ends them.
+  pub fn macro_running(&self, name: &str) -> bool {
+    self.running_macro.as_deref() == Some(name)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use tokio::sync::mpsc;
+
+  use super::*;
+
+  fn restart_web() -> MacroConfig {
+    MacroConfig {
+      name: "restart-web".into(),
+      key: "alt+1".into(),
+      steps: vec![
+        MacroStepConfig {
+          action: "restart".into(),
+          view: "deployments".into(),
+          names: vec!["web".into(), "api".into()],
+          ..MacroStepConfig::default()
+        },
+        MacroStepConfig {
+          action: "scale".into(),
+          view: "statefulsets".into(),
+          namespace: Some("data".into()),
+          names: vec!["db".into()],
+          replicas: Some(3),
+        },
+        MacroStepConfig {
+          action: "delete".into(),
+          view: "nodes".into(),
+          names: vec!["node-1".into()],
+          ..MacroStepConfig::default()
+        },
+      ],
+    }
+  }
+
+  #[test]
+  fn test_macro_events_expand_each_step_per_resource() {
+    let events = macro_events(&restart_web(), Some("shop")).unwrap();
+    assert_eq!(
+      events,
+      vec![
+        IoEvent::PatchResource {
+          block: ActiveBlock::Deployments,
+          name: "web".into(),
+          namespace: Some("shop".into()),
+          patch: ResourcePatch::RolloutRestart,
+        },
+        IoEvent::PatchResource {
+          block: ActiveBlock::Deployments,
+          name: "api".into(),
+          namespace: Some("shop".into()),
+          patch: ResourcePatch::RolloutRestart,
+        },
+        IoEvent::PatchResource {
+          block: ActiveBlock::StatefulSets,
+          name: "db".into(),
+          namespace: Some("data".into()),
+          patch: ResourcePatch::SetReplicas(3),
+        },
+        IoEvent::DeleteResource {
+          block: ActiveBlock::Nodes,
+          name: "node-1".into(),
+          namespace: None,
+        },
+      ]
+    );
+    assert_eq!(
+      macro_events(&restart_web(), None),
+      Err("step 1: deployments needs a namespace; select one or set it".into())
+    );
+  }
+
+  #[test]
+  fn test_macro_warnings_name_what_cannot_run() {
+    let mut bad = restart_web();
+    bad.key = "hyper+x".into();
+    bad.steps[0].action = "restart".into();
+    bad.steps[0].view = "configmaps".into();
+    let config = KdashConfig {
+      macros: vec![restart_web(), bad],
+      ..KdashConfig::default()
+    };
+    let warnings = macro_warnings(&config);
+    assert_eq!(warnings.len(), 2, "{:?}", warnings);
+    assert!(warnings[0].starts_with("Invalid key for macro restart-web: hyper+x"));
+    assert_eq!(
+      warnings[1],
+      "Macro restart-web cannot run: step 1: restart does not apply to configmaps"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_open_macro_confirms_once_then_dispatches_the_steps_in_order() {
+    let (io_tx, mut io_rx) = mpsc::channel::<IoEvent>(10);
+    let mut app = App {
+      io_tx: Some(io_tx),
+      ..App::default()
+    };
+    app.config.macros = vec![restart_web()];
+    app.data.selected.ns = Some("shop".into());
+
+    let config = app.macro_for_key(Key::Alt('1')).unwrap();
+    app.open_macro(&config);
+    let modal = app.modal.take().unwrap();
+    assert_eq!(
+      modal.prompt.lines().collect::<Vec<_>>(),
+      vec![
+        "Run macro restart-web: 4 steps, in order?",
+        "  1. rollout restart deployment shop/web",
+        "  2. rollout restart deployment shop/api",
+        "  3. scale to 3 statefulset data/db",
+        "  4. delete node node-1",
+      ]
+    );
+    let ConfirmAction::Macro { name, events } = modal.on_confirm else {
+      panic!("expected a macro");
+    };
+    app.run_macro(name, events).await;
+    assert!(app.macro_running("restart-web"));
+    for step in 1..=4 {
+      match io_rx.recv().await.unwrap() {
+        IoEvent::MacroStep {
+          step: sent, steps, ..
+        } => assert_eq!((sent, steps), (step, 4)),
+        other => panic!("expected a macro step, got {:?}", other),
+      }
+    }
+    assert_eq!(app.macro_for_key(Key::Alt('2')), None);
+
+    // once a step fails the rest are skipped, and not audited
+    let step = IoEvent::MacroStep {
+      name: "restart-web".into(),
+      step: 2,
+      steps: 4,
+      event: Box::new(IoEvent::DeleteResource {
+        block: ActiveBlock::Nodes,
+        name: "node-1".into(),
+        namespace: None,
+      }),
+    };
+    assert!(step.audit_action(&app).is_some());
+    app.running_macro = None;
+    assert!(step.audit_action(&app).is_none());
+  }
+}
@@ -0,0 +1,233 @@
+//! Manifests applied straight from the clipboard with `kubectl apply -f -`,
+//! such as one copied from a wiki or chat. The text has to parse as YAML or
+//! JSON Kubernetes objects before anything is sent, and the confirmation
+//! lists the objects and shows the start of the manifest.
+use std::fmt;
+
+use anyhow::anyhow;
+use serde_json::Value;
+
+use super::{actions::Modal, App};
+use crate::cmd::IoCmdEvent;
+
+/// Objects listed in the confirmation; the rest are counted.
+const MAX_LISTED_OBJECTS: usize = 8;
+/// Manifest lines previewed in the confirmation.
+const MAX_PREVIEW_LINES: usize = 10;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ManifestObject {
+  pub kind: String,
+  pub name: String,
+  pub namespace: Option<String>,
+}
+
+impl fmt::Display for ManifestObject {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.namespace {
+      Some(ns) => write!(f, "{} {}/{}", self.kind, ns, self.name),
+      None => write!(f, "{} {}", self.kind, self.name),
+    }
+  }
+}
+
+/// The objects in a manifest of one or more YAML documents or a JSON object.
+/// `List` objects contribute their items, as with `kubectl apply`.
+pub fn parse_manifest(text: &str) -> Result<Vec<ManifestObject>, String> {
+  if text.trim().is_empty() {
+    return Err("the clipboard is empty".into());
+  }
+  let documents: Vec<Value> = serde_saphyr::from_multiple(text)
+    .map_err(|e| format!("the clipboard is not YAML or JSON: {}", e))?;
+  let mut objects = vec![];
+  for (i, document) in documents.into_iter().enumerate() {
+    if document.is_null() {
+      continue;
+    }
+    let items = match document.get("items").and_then(Value::as_array) {
+      Some(items) if is_list(&document) => items.clone(),
+      _ => vec![document],
+    };
+    for item in items {
+      objects.push(manifest_object(&item).ok_or_else(|| {
+        format!(
+          "document {} is not a Kubernetes object with apiVersion, kind and metadata.name",
+          i + 1
+        )
+      })?);
+    }
+  }
+  if objects.is_empty() {
+    return Err("the clipboard has no Kubernetes objects".into());
+  }
+  Ok(objects)
+}
+
+fn is_list(value: &Value) -> bool {
+  value
+    .get("kind")
+    .and_then(Value::as_str)
+    .is_some_and(|kind| kind.ends_with("List"))
+}
+
+fn non_empty_str(value: Option<&Value>) -> Option<&str> {
+  value.and_then(Value::as_str).filter(|s| !s.is_empty())
+}
+
+fn manifest_object(value: &Value) -> Option<ManifestObject> {
+  non_empty_str(value.get("apiVersion"))?;
+  let metadata = value.get("metadata")?;
+  Some(ManifestObject {
+    kind: non_empty_str(value.get("kind"))?.to_owned(),
+    name: non_empty_str(metadata.get("name"))
+      .or(non_empty_str(metadata.get("generateName")))?
+      .to_owned(),
+    namespace: non_empty_str(metadata.get("namespace")).map(str::to_owned),
+  })
+}
+
+/// The confirmation prompt: what would be applied, then the manifest's
+/// first lines.
+fn apply_prompt(objects: &[ManifestObject], manifest: &str) -> String {
+  let mut lines = vec![format!(
+    "Apply {} from the clipboard with kubectl apply?",
+    match objects.len() {
+      1 => "1 object".to_owned(),
+      n => format!("{} objects", n),
+    }
+  )];
+  lines.extend(
+    objects
+      .iter()
+      .take(MAX_LISTED_OBJECTS)
+      .map(|object| format!("  {}", object)),
+  );
+  if objects.len() > MAX_LISTED_OBJECTS {
+    lines.push(format!(
+      "  …and {} more",
+      objects.len() - MAX_LISTED_OBJECTS
+    ));
+  }
+  lines.push(String::new());
+  let manifest_lines: Vec<&str> = manifest.trim().lines().collect();
+  lines.extend(
+    manifest_lines
+      .iter()
+      .take(MAX_PREVIEW_LINES)
+      .map(|line| line.to_string()),
+  );
+  if manifest_lines.len() > MAX_PREVIEW_LINES {
+    lines.push("…".into());
+  }
+  lines.join("\n")
+}
+
+impl App {
+  /// Check a manifest from the clipboard and ask to apply it, or explain why
+  /// it cannot be.
+  pub fn open_apply_manifest(&mut self, manifest: String) {
+    match parse_manifest(&manifest) {
+      Ok(objects) => {
+        let prompt = apply_prompt(&objects, &manifest);
+        self.open_modal(Modal::confirm(
+          "Apply from clipboard",
+          prompt,
+          IoCmdEvent::ApplyManifest { manifest, objects },
+        ));
+      }
+      Err(e) => self.handle_error(anyhow!("Unable to apply {}", e)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::app::actions::ConfirmAction;
+
+  #[test]
+  fn test_parse_manifest_lists_yaml_and_json_objects() {
+    let yaml = r#"
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+  namespace: shop
+---
+apiVersion: v1
+kind: List
+items:
+  - apiVersion: v1
+    kind: Service
+    metadata:
+      name: web
+  - apiVersion: batch/v1
+    kind: Job
+    metadata:
+      generateName: migrate-
+---
+"#;
+    let objects: Vec<String> = parse_manifest(yaml)
+      .unwrap()
+      .iter()
+      .map(ToString::to_string)
+      .collect();
+    assert_eq!(
+      objects,
+      vec!["Deployment shop/web", "Service web", "Job migrate-"]
+    );
+
+    let json = r#"{"apiVersion": "v1", "kind": "ConfigMap", "metadata": {"name": "settings"}}"#;
+    assert_eq!(
+      parse_manifest(json).unwrap(),
+      vec![ManifestObject {
+        kind: "ConfigMap".into(),
+        name: "settings".into(),
+        namespace: None,
+      }]
+    );
+  }
+
+  #[test]
+  fn test_parse_manifest_rejects_what_is_not_a_manifest() {
+    assert_eq!(parse_manifest("  \n"), Err("the clipboard is empty".into()));
+    assert!(parse_manifest("key: [unclosed")
+      .unwrap_err()
+      .starts_with("the clipboard is not YAML or JSON"));
+    assert_eq!(
+      parse_manifest("kind: Pod\nmetadata:\n  name: web\n"),
+      Err("document 1 is not a Kubernetes object with apiVersion, kind and metadata.name".into())
+    );
+    assert!(parse_manifest("just some text").is_err());
+  }
+
+  #[test]
+  fn test_open_apply_manifest_asks_with_the_content() {
+    let mut app = App::default();
+    let manifest = "apiVersion: v1\nkind: Namespace\nmetadata:\n  name: team-a\n".to_owned();
+    app.open_apply_manifest(manifest.clone());
+
+    let modal = app.modal.clone().unwrap();
+    assert_eq!(
+      modal.prompt,
+      "Apply 1 object from the clipboard with kubectl apply?\n  Namespace team-a\n\n\
+       apiVersion: v1\nkind: Namespace\nmetadata:\n  name: team-a"
+    );
+    assert_eq!(
+      modal.on_confirm,
+      ConfirmAction::Cmd(IoCmdEvent::ApplyManifest {
+        manifest,
+        objects: vec![ManifestObject {
+          kind: "Namespace".into(),
+          name: "team-a".into(),
+          namespace: None,
+        }],
+      })
+    );
+
+    app.modal = None;
+    app.open_apply_manifest("not: a manifest".into());
+    assert_eq!(app.modal, None);
+    assert!(app.api_error.starts_with("Unable to apply document 1"));
+  }
+}
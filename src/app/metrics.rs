@@ -1,10 +1,17 @@
+use std::{
+  collections::HashMap,
+  time::{Duration, Instant},
+};
+
 use anyhow::anyhow;
 use async_trait::async_trait;
-use kube::api::ObjectMeta;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::APIGroupList;
+use kube::api::{Api, ObjectMeta};
 use kubectl_view_allocations::{
   collect_from_metrics, collect_from_nodes, collect_from_pods, make_qualifiers, qty::Qty,
-  QtyByQualifier, Resource, UsedMode,
+  QtyByQualifier, Resource, ResourceQualifier, UsedMode,
 };
+use log::debug;
 use ratatui::{
   layout::{Constraint, Rect},
   text::Span,
@@ -14,9 +21,12 @@ use ratatui::{
 use serde::{Deserialize, Serialize};
 use tokio::sync::MutexGuard;
 
-use super::{models::AppResource, tree::provide_prefix, utils, ActiveBlock, App};
+use super::{
+  models::AppResource, node_pods::PodUsage, tree::provide_prefix, utils, ActiveBlock, App,
+};
 use crate::app::{key_binding::DEFAULT_KEYBINDING, models::FilterableTable};
 use crate::{
+  cmd::IoCmdEvent,
   network::Network,
   ui::utils::{
     action_hint, default_part, filter_cursor_position, filter_status_parts, gauge_line, help_part,
@@ -26,6 +36,11 @@ use crate::{
   },
 };
 
+/// How long a missing metrics API is left alone before it is looked up again.
+pub const METRICS_REPROBE_INTERVAL: Duration = Duration::from_secs(300);
+/// Shown by the usage views instead of empty numbers when there is no metrics API.
+pub const METRICS_UNAVAILABLE_NOTE: &str = "metrics-server not installed";
+
 /// One row of `make_qualifiers` output: qualifier path, summed quantities and
 /// the precomputed free quantity.
 pub type UtilizationQualifier = (Vec<String>, Option<QtyByQualifier>, Option<Qty>);
@@ -67,6 +82,99 @@ impl k8s_openapi::Metadata for NodeMetrics {
   }
 }
 
+/// Usage of one container, as listed in its pod's metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContainerMetrics {
+  name: String,
+  usage: Usage,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodMetrics {
+  metadata: kube::api::ObjectMeta,
+  containers: Vec<ContainerMetrics>,
+  timestamp: String,
+  window: String,
+}
+
+impl k8s_openapi::Resource for PodMetrics {
+  const GROUP: &'static str = "metrics.k8s.io";
+  const KIND: &'static str = "pod";
+  const VERSION: &'static str = "v1beta1";
+  const API_VERSION: &'static str = "metrics.k8s.io/v1beta1";
+  const URL_PATH_SEGMENT: &'static str = "pods";
+  type Scope = k8s_openapi::NamespaceResourceScope;
+}
+
+impl k8s_openapi::Metadata for PodMetrics {
+  type Ty = ObjectMeta;
+
+  fn metadata(&self) -> &Self::Ty {
+    &self.metadata
+  }
+
+  fn metadata_mut(&mut self) -> &mut Self::Ty {
+    &mut self.metadata
+  }
+}
+
+/// Usage of each container of the pod whose containers are shown, so a
+/// sidecar eating memory reads apart from the app next to it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContainerUsage {
+  namespace: String,
+  pod: String,
+  containers: HashMap<String, PodUsage>,
+}
+
+impl ContainerUsage {
+  pub fn new(namespace: &str, pod: &str, metrics: &PodMetrics) -> Self {
+    let containers = metrics
+      .containers
+      .iter()
+      .map(|container| {
+        let usage = PodUsage {
+          cpu: container.usage.cpu.parse().ok(),
+          mem: container.usage.memory.parse().ok(),
+        };
+        (container.name.clone(), usage)
+      })
+      .collect();
+    Self {
+      namespace: namespace.to_owned(),
+      pod: pod.to_owned(),
+      containers,
+    }
+  }
+
+  pub fn is_for(&self, namespace: &str, pod: &str) -> bool {
+    self.namespace == namespace && self.pod == pod
+  }
+
+  pub fn get(&self, container: &str) -> Option<&PodUsage> {
+    self.containers.get(container)
+  }
+}
+
+/// Fetch the per-container usage of one pod for its containers view.
+/// Without the metrics API there is none, and a pod metrics-server has not
+/// scraped yet only gets a debug line; the columns then read `-`.
+pub async fn get_container_usage(nw: &Network<'_>, name: &str, namespace: &str) {
+  if !nw.metrics_source().await.uses_api() {
+    nw.app.lock().await.data.container_usage = None;
+    return;
+  }
+  let api: Api<PodMetrics> = Api::namespaced(nw.client.clone(), namespace);
+  let usage = match api.get(name).await {
+    Ok(metrics) => Some(ContainerUsage::new(namespace, name, &metrics)),
+    Err(e) => {
+      debug!("No metrics for pod {}/{}: {}", namespace, name, e);
+      None
+    }
+  };
+  nw.app.lock().await.data.container_usage = usage;
+}
+
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct KubeNodeMetrics {
   pub name: String,
@@ -95,13 +203,90 @@ impl KubeNodeMetrics {
   }
 }
 
+/// Where node and pod usage is read from. The metrics API is tried first; when
+/// it errors, `kubectl top` is used, which can still reach metrics-server
+/// through the aggregation layer. Kept until the client is refreshed so a
+/// failing path is not retried on every poll.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MetricsSource {
+  #[default]
+  Unknown,
+  Api,
+  KubectlTop,
+  /// Neither path works; usage is not polled.
+  Unavailable,
+}
+
+impl MetricsSource {
+  pub fn uses_api(self) -> bool {
+    matches!(self, Self::Unknown | Self::Api)
+  }
+
+  /// Whether the metrics API should be looked up before polling: on first
+  /// use, and every `METRICS_REPROBE_INTERVAL` while it is missing in case
+  /// metrics-server gets installed.
+  pub fn probe_due(self, probed_at: Option<Instant>, now: Instant) -> bool {
+    match self {
+      Self::Unknown => true,
+      Self::Unavailable => {
+        probed_at.is_none_or(|at| now.duration_since(at) >= METRICS_REPROBE_INTERVAL)
+      }
+      Self::Api | Self::KubectlTop => false,
+    }
+  }
+}
+
+/// The group metrics-server serves node and pod usage under.
+const METRICS_API_GROUP: &str = "metrics.k8s.io";
+
+pub fn serves_metrics_api(groups: &APIGroupList) -> bool {
+  groups.groups.iter().any(|g| g.name == METRICS_API_GROUP)
+}
+
+/// Usage of one pod as reported by `kubectl top pods`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TopPodUsage {
+  pub namespace: String,
+  pub name: String,
+  pub cpu: String,
+  pub memory: String,
+}
+
+/// Add `kubectl top` usage to `resources` as utilization, like
+/// `collect_from_metrics` does for the metrics API. The entries are copied from
+/// the pod's placeholder resources, so pods `collect_from_pods` did not see are
+/// skipped.
+fn push_top_pod_usage(resources: &mut Vec<Resource>, usage: &[TopPodUsage]) {
+  let mut utilizations = vec![];
+  for pod in usage {
+    for (kind, value) in [("cpu", &pod.cpu), ("memory", &pod.memory)] {
+      let Ok(quantity) = value.parse::<Qty>() else {
+        continue;
+      };
+      let placeholder = resources.iter().find(|r| {
+        matches!(r.qualifier, ResourceQualifier::Present)
+          && r.kind == kind
+          && r.location.namespace.as_deref() == Some(pod.namespace.as_str())
+          && r.location.pod_name.as_deref() == Some(pod.name.as_str())
+      });
+      if let Some(placeholder) = placeholder {
+        let mut utilization = placeholder.clone();
+        utilization.qualifier = ResourceQualifier::Utilization;
+        utilization.quantity = quantity;
+        utilizations.push(utilization);
+      }
+    }
+  }
+  resources.extend(utilizations);
+}
+
 pub struct UtilizationResource {}
 
 #[async_trait]
 impl AppResource for UtilizationResource {
   fn render(_block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
     let left_title = format!(
-      " Resource Utilization (ns: [{}]) [{}] ",
+      " Resource Utilization (ns: [{}]) [{}] {}",
       app
         .data
         .selected
@@ -109,6 +294,10 @@ impl AppResource for UtilizationResource {
         .as_ref()
         .unwrap_or(&String::from("all")),
       app.data.metrics.count_label(),
+      app
+        .metrics_note()
+        .map(|note| format!("({}, allocations only) ", note))
+        .unwrap_or_default(),
     );
     // lowercase to keep the old `[resource, node, ...]` look now that the
     // GroupBy variants are PascalCase
@@ -230,7 +419,13 @@ impl AppResource for UtilizationResource {
 
       f.render_stateful_widget(table, table_area, &mut app.data.metrics.state);
     } else {
-      loading(f, block, table_area, app.is_loading(), app.palette);
+      loading(
+        f,
+        block,
+        table_area,
+        &app.view_load(ActiveBlock::Utilization),
+        app.palette,
+      );
     }
 
     if app.data.metrics.filter_active {
@@ -275,14 +470,29 @@ impl AppResource for UtilizationResource {
         .await;
     }
 
-    if collect_from_metrics(nw.client.clone(), &mut resources)
-      .await
-      .is_err()
-    {
-      nw.handle_error(anyhow!("Failed to extract pod utilization metrics. Make sure you have a metrics-server deployed on your cluster.")).await;
+    let source = nw.metrics_source().await;
+    let mut use_kubectl_top = source == MetricsSource::KubectlTop;
+    if source.uses_api() {
+      let collected = collect_from_metrics(nw.client.clone(), &mut resources).await;
+      let mut app = nw.app.lock().await;
+      if collected.is_ok() {
+        app.data.metrics_source = MetricsSource::Api;
+      } else {
+        app.data.metrics_source = MetricsSource::KubectlTop;
+        use_kubectl_top = true;
+      }
     }
 
     let mut app = nw.app.lock().await;
+    if use_kubectl_top {
+      // Usage from the previous `kubectl top` run; the next one is queued here.
+      push_top_pod_usage(&mut resources, &app.data.pod_top_usage);
+      app
+        .dispatch_cmd(IoCmdEvent::TopPods {
+          ns: namespaces.first().cloned(),
+        })
+        .await;
+    }
 
     let data = make_qualifiers(
       &resources,
@@ -437,6 +647,46 @@ mod tests {
   use super::*;
   use crate::app::test_utils::load_resource_from_file;
 
+  #[test]
+  fn test_metrics_probe_due() {
+    let now = Instant::now();
+    let recently = now - Duration::from_secs(10);
+    let long_ago = now - METRICS_REPROBE_INTERVAL;
+
+    assert!(MetricsSource::Unknown.probe_due(Some(recently), now));
+    assert!(!MetricsSource::Api.probe_due(None, now));
+    assert!(!MetricsSource::KubectlTop.probe_due(None, now));
+    assert!(MetricsSource::Unavailable.probe_due(None, now));
+    assert!(!MetricsSource::Unavailable.probe_due(Some(recently), now));
+    assert!(MetricsSource::Unavailable.probe_due(Some(long_ago), now));
+  }
+
+  #[test]
+  fn test_serves_metrics_api() {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::APIGroup;
+    let group = |name: &str| APIGroup {
+      name: name.into(),
+      ..APIGroup::default()
+    };
+    let mut groups = APIGroupList {
+      groups: vec![group("apps"), group("batch")],
+    };
+    assert!(!serves_metrics_api(&groups));
+
+    groups.groups.push(group("metrics.k8s.io"));
+    assert!(serves_metrics_api(&groups));
+  }
+
+  #[test]
+  fn test_metrics_note_only_when_unavailable() {
+    let mut app = App::default();
+    assert_eq!(app.metrics_note(), None);
+
+    app.set_metrics_unavailable();
+    assert_eq!(app.metrics_note(), Some(METRICS_UNAVAILABLE_NOTE));
+    assert!(app.data.metrics_probed_at.is_some());
+  }
+
   #[tokio::test]
   async fn test_kube_node_metrics_from_api() {
     let node_metrics = load_resource_from_file("node_metrics");
@@ -480,6 +730,33 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_container_usage_is_kept_per_container() {
+    let metrics: PodMetrics = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web-1", "namespace": "shop" },
+      "timestamp": "2026-10-14T10:00:00Z",
+      "window": "15s",
+      "containers": [
+        { "name": "app", "usage": { "cpu": "12000000n", "memory": "64Mi" } },
+        { "name": "sidecar", "usage": { "cpu": "250m", "memory": "900Mi" } },
+      ],
+    }))
+    .unwrap();
+
+    let usage = ContainerUsage::new("shop", "web-1", &metrics);
+    assert!(usage.is_for("shop", "web-1"));
+    assert!(!usage.is_for("shop", "web-2"));
+    let sidecar = usage.get("sidecar").unwrap();
+    assert_eq!(sidecar.cpu, Some("250m".parse().unwrap()));
+    assert_eq!(sidecar.mem, Some("900Mi".parse().unwrap()));
+    // metrics-server reports CPU in nanocores
+    assert_eq!(
+      crate::app::pods::format_cpu(usage.get("app").unwrap().cpu.clone()),
+      "12m"
+    );
+    assert_eq!(usage.get("init"), None);
+  }
+
   #[test]
   fn test_summary_rows() {
     use std::str::FromStr;
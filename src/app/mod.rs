@@ -1,73 +1,131 @@
 pub(crate) mod actions;
+pub(crate) mod alerts;
+pub(crate) mod api_resources;
+pub(crate) mod api_stats;
+pub(crate) mod apply_status;
+pub(crate) mod audit;
+pub(crate) mod cmd_output;
+pub(crate) mod columns;
 pub(crate) mod configmaps;
+pub(crate) mod confirm;
+pub(crate) mod console;
+pub(crate) mod consumers;
 pub(crate) mod contexts;
+pub(crate) mod crds;
 pub(crate) mod cronjobs;
 pub(crate) mod daemonsets;
 pub(crate) mod deployments;
+pub(crate) mod describe_cache;
+pub(crate) mod diff;
 pub(crate) mod dynamic;
 pub(crate) mod events;
+pub(crate) mod export;
+pub(crate) mod finder;
+pub(crate) mod footer;
+pub(crate) mod health;
 pub(crate) mod ingress;
 pub(crate) mod jobs;
 pub(crate) mod key_binding;
+pub(crate) mod layout;
+pub(crate) mod limit_ranges;
+pub(crate) mod load_state;
+pub(crate) mod macros;
+pub(crate) mod manifest;
 pub(crate) mod metrics;
 pub(crate) mod models;
 pub(crate) mod network_policies;
+pub(crate) mod node_allocation;
+pub(crate) mod node_pods;
 pub(crate) mod nodes;
 pub(crate) mod ns;
+pub(crate) mod ns_picker;
+pub(crate) mod pdbs;
+pub(crate) mod pod_groups;
+pub(crate) mod pod_owners;
 pub(crate) mod pods;
+pub(crate) mod poll_rates;
 pub(crate) mod port_forward;
+pub(crate) mod prewarm;
 pub(crate) mod pvcs;
 pub(crate) mod pvs;
+pub(crate) mod read_only;
+pub(crate) mod redact;
 pub(crate) mod replicasets;
 pub(crate) mod replication_controllers;
+pub(crate) mod resource_quotas;
 pub(crate) mod roles;
+pub(crate) mod rollout_follow;
 pub(crate) mod secrets;
 pub(crate) mod serviceaccounts;
+pub(crate) mod short_names;
+pub(crate) mod snapshot;
 pub(crate) mod statefulsets;
 pub(crate) mod storageclass;
 pub(crate) mod svcs;
 pub(crate) mod tree;
 pub(crate) mod troubleshoot;
 pub(crate) mod utils;
+pub(crate) mod watchlist;
 
 use anyhow::anyhow;
-use chrono::Local;
+use chrono::{DateTime, FixedOffset, Utc};
 use kube::config::Kubeconfig;
 use kubectl_view_allocations::GroupBy;
 use log::{error, info};
 use ratatui::layout::Rect;
 use ratatui::widgets::ListState;
-use std::collections::VecDeque;
-use std::time::{Duration, Instant};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::process::Child;
 use tokio::sync::{mpsc::Sender, watch};
 
 use self::{
-  actions::{InputModal, Modal, ResourceAction},
-  configmaps::KubeConfigMap,
+  actions::{ConfirmAction, InputModal, Modal, ResourceAction},
+  alerts::PodAlerts,
+  api_resources::KubeApiResource,
+  apply_status::ApplyStatus,
+  audit::AuditEntry,
+  configmaps::{ConfigMapEntry, KubeConfigMap},
   contexts::KubeContext,
+  crds::KubeCrd,
   cronjobs::KubeCronJob,
   daemonsets::KubeDaemonSet,
   deployments::KubeDeployment,
   dynamic::{DynamicResourceCache, KubeDynamicKind, KubeDynamicResource},
   events::KubeEvent,
+  export::{write_export, write_logs, ExportFormat, TableExport, TableText},
+  finder::{Finder, FinderEntry},
+  footer::{FooterVerbosity, LastNetworkEvent},
   ingress::KubeIngress,
   jobs::KubeJob,
   key_binding::DEFAULT_KEYBINDING,
+  layout::LayoutMode,
+  limit_ranges::KubeLimitRange,
+  load_state::{ListPages, LoadState, ViewLoad},
   metrics::KubeNodeMetrics,
   models::{
     FilterableTable, LogsState, ScrollableTxt, StatefulList, StatefulTable, TabRoute, TabsState,
+    YamlDetail,
   },
   network_policies::KubeNetworkPolicy,
+  node_pods::{KubeNodePods, NodePodsSort},
   nodes::KubeNode,
   ns::KubeNs,
-  pods::{KubeContainer, KubePod},
+  ns_picker::NsPicker,
+  pdbs::KubePdb,
+  pods::{ContainerEnvView, KubeContainer, KubePod, WatchedPod},
+  poll_rates::PollSchedule,
   port_forward::{PortForward, PortForwardStatus},
+  prewarm::prewarm_views,
   pvcs::KubePVC,
   pvs::KubePV,
+  redact::{Redactor, DEFAULT_REDACT_PATTERNS},
   replicasets::KubeReplicaSet,
   replication_controllers::KubeReplicationController,
+  resource_quotas::KubeResourceQuota,
   roles::{KubeClusterRole, KubeClusterRoleBinding, KubeRole, KubeRoleBinding},
+  rollout_follow::FollowedRollout,
   secrets::KubeSecret,
   serviceaccounts::KubeSvcAcct,
   statefulsets::KubeStatefulSet,
@@ -77,14 +135,27 @@ use self::{
 use super::{
   cmd::IoCmdEvent,
   config::KdashConfig,
-  network::{stream::IoStreamEvent, IoEvent},
+  handlers::copy_to_clipboard,
+  network::{
+    connection_info::{self, ConnectionInfo},
+    stream::IoStreamEvent,
+    IoEvent,
+  },
 };
 use crate::ui::theme::{apply_legacy_overrides, palette_for, Palette, ThemeName};
+use crate::ui::utils::{ColumnLayout, ViewTier};
 
 const MAX_NAV_STACK: usize = 128;
 const STATUS_MESSAGE_DURATION: Duration = Duration::from_secs(5);
 pub const DEFAULT_LOG_TAIL_LINES: u32 = 100;
+pub const DEFAULT_RESTART_THRESHOLD: u32 = 5;
+/// Seconds a change stays highlighted when `highlight_changes` is on.
+pub const DEFAULT_HIGHLIGHT_CHANGES_SECS: u64 = 30;
 pub const MAX_ERROR_HISTORY: usize = 100;
+/// Ticks between deployment refreshes while the selected rollout is in progress.
+const ROLLOUT_POLL_TICKS: u64 = 4;
+/// Wall-clock gap between two ticks taken for a resume from sleep.
+const SLEEP_GAP: Duration = Duration::from_secs(30);
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ErrorRecord {
@@ -99,8 +170,8 @@ pub struct PendingShellExec {
   pub container: String,
 }
 
-/// A resource to open in `$EDITOR` via `kubectl edit`. `namespace` is `None` for
-/// cluster-scoped kinds.
+/// A resource to open in `$EDITOR` and re-apply with `kubectl apply`.
+/// `namespace` is `None` for cluster-scoped kinds.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PendingEdit {
   pub namespace: Option<String>,
@@ -109,7 +180,7 @@ pub struct PendingEdit {
 }
 
 /// An action that suspends the TUI, runs an interactive child process inheriting
-/// the terminal, then restores the TUI. Shell-exec and `kubectl edit` share this
+/// the terminal, then restores the TUI. Shell-exec and `$EDITOR` share this
 /// suspend/restore machinery.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PendingTerminalAction {
@@ -164,7 +235,7 @@ impl StatusMessage {
   }
 }
 
-#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
 pub enum ActiveBlock {
   Help,
   Pods,
@@ -174,11 +245,14 @@ pub enum ActiveBlock {
   Nodes,
   Deployments,
   ConfigMaps,
+  /// The keys of a ConfigMap, drilled into from the ConfigMaps view.
+  ConfigMapData,
   StatefulSets,
   ReplicaSets,
   Namespaces,
   Describe,
   Yaml,
+  Diff,
   Contexts,
   Utilization,
   Troubleshoot,
@@ -199,6 +273,14 @@ pub enum ActiveBlock {
   NetworkPolicies,
   ServiceAccounts,
   Events,
+  ResourceQuotas,
+  LimitRanges,
+  PodDisruptionBudgets,
+  CustomResourceDefinitions,
+  ApiResources,
+  PodsByNode,
+  Dashboard,
+  Watchlist,
   More,
   DynamicView,
 }
@@ -209,6 +291,8 @@ pub enum RouteId {
   Contexts,
   Utilization,
   Troubleshoot,
+  Dashboard,
+  Watchlist,
   HelpMenu,
 }
 
@@ -235,20 +319,85 @@ pub struct Cli {
 pub struct Data {
   pub selected: Selected,
   pub clis: Vec<Cli>,
+  /// The API server's `gitVersion`, e.g. `v1.30.2`, fetched once per context.
+  pub server_version: Option<String>,
+  /// Who `--as` / `--as-group` act as, for the footer.
+  pub impersonating: Option<String>,
+  /// `--insecure-skip-tls-verify`: server certificates are not checked.
+  pub insecure_tls: bool,
   pub kubeconfig: Option<Kubeconfig>,
   pub contexts: StatefulTable<KubeContext>,
   pub active_context: Option<KubeContext>,
   pub node_metrics: Vec<KubeNodeMetrics>,
+  /// Whether usage comes from the metrics API or `kubectl top`.
+  pub metrics_source: metrics::MetricsSource,
+  /// When the metrics API was last looked up, to re-probe a missing one.
+  pub metrics_probed_at: Option<Instant>,
+  /// Pod usage from the last `kubectl top pods`, when that is the metrics source.
+  pub pod_top_usage: Vec<metrics::TopPodUsage>,
   pub logs: LogsState,
   pub describe_out: ScrollableTxt,
+  /// The summary and full YAML behind `describe_out` in the YAML view, when
+  /// the kind has a summary.
+  pub yaml_detail: Option<YamlDetail>,
+  /// A described pod's scheduling constraints while expanded; scrolling then
+  /// moves these instead of `describe_out`.
+  pub pod_constraints: Option<ScrollableTxt>,
+  /// The selected container's volume mounts while expanded; scrolling in the
+  /// containers view then moves these instead of the selection.
+  pub container_mounts: Option<ScrollableTxt>,
+  /// The selected container's environment while expanded, scrolled like
+  /// `container_mounts`.
+  pub container_env: Option<ContainerEnvView>,
+  /// Fold repeated events about the same object and reason into one row.
+  pub group_events: bool,
+  /// Group the pods tab under the workloads that own them.
+  pub group_pods: bool,
+  /// Groups of the pods tab folded into their header row.
+  pub collapsed_pod_groups: HashSet<pod_groups::PodOwner>,
+  /// The rows of the grouped pods tab, rebuilt from `pods` on each draw.
+  pub pod_groups: StatefulTable<pod_groups::PodGroupRow>,
+  pub describe_cache: describe_cache::DescribeCache,
+  /// The describe request behind `describe_out`, to refetch it on demand.
+  pub last_describe: Option<IoCmdEvent>,
+  /// Outcome of the last fetch per view; views without an entry fall back to
+  /// the global loading indicator.
+  pub load_states: HashMap<ActiveBlock, load_state::LoadState>,
+  /// Pages loaded per paginated list view.
+  pub list_pages: HashMap<ActiveBlock, ListPages>,
+  /// The last fetch that failed per view, re-dispatched by the retry key.
+  pub failed_events: HashMap<ActiveBlock, IoEvent>,
+  /// Reachability of each kubeconfig context, probed from the contexts view.
+  pub context_probes: contexts::ContextProbes,
+  /// Node coverage of the DaemonSet being described; `None` until fetched.
+  pub daemon_set_coverage: Option<daemonsets::DaemonSetCoverage>,
+  /// Endpoints of the Service being described; `None` until fetched.
+  pub service_endpoints: Option<svcs::ServiceEndpoints>,
+  /// Controllers owning the pod being described; `None` until fetched.
+  pub pod_controllers: Option<pod_owners::PodControllers>,
+  /// Events about the pod whose containers are shown; `None` until fetched.
+  pub pod_events: Option<events::PodEvents>,
+  /// Usage of each container of that pod; `None` until fetched or without
+  /// metrics.
+  pub container_usage: Option<metrics::ContainerUsage>,
+  /// Every event fetched or watched, behind the events view's rows.
+  pub event_buffer: events::EventBuffer,
+  /// An events watch that gave up, so polling is used until the view or
+  /// namespace changes.
+  pub event_watch_given_up: Option<events::WatchedEvents>,
+  /// Rules granted by the RoleBinding or ClusterRoleBinding being described.
+  pub binding_rules: Option<roles::BindingRules>,
   pub metrics: StatefulTable<metrics::UtilizationQualifier>,
   pub troubleshoot_findings: StatefulTable<troubleshoot::DisplayFinding>,
+  pub watchlist: StatefulTable<watchlist::WatchRow>,
   pub namespaces: StatefulTable<KubeNs>,
   pub nodes: StatefulTable<KubeNode>,
   pub pods: StatefulTable<KubePod>,
   pub containers: StatefulTable<KubeContainer>,
   pub services: StatefulTable<KubeSvc>,
   pub config_maps: StatefulTable<KubeConfigMap>,
+  /// Keys of the ConfigMap opened in the data view.
+  pub config_map_data: StatefulTable<ConfigMapEntry>,
   pub stateful_sets: StatefulTable<KubeStatefulSet>,
   pub replica_sets: StatefulTable<KubeReplicaSet>,
   pub deployments: StatefulTable<KubeDeployment>,
@@ -268,6 +417,13 @@ pub struct Data {
   pub network_policies: StatefulTable<KubeNetworkPolicy>,
   pub service_accounts: StatefulTable<KubeSvcAcct>,
   pub events: StatefulTable<KubeEvent>,
+  pub resource_quotas: StatefulTable<KubeResourceQuota>,
+  pub limit_ranges: StatefulTable<KubeLimitRange>,
+  pub pdbs: StatefulTable<KubePdb>,
+  pub crds: StatefulTable<KubeCrd>,
+  pub api_resources: StatefulTable<KubeApiResource>,
+  pub node_pods: StatefulTable<KubeNodePods>,
+  pub node_pods_sort: NodePodsSort,
   pub dynamic_kinds: Vec<KubeDynamicKind>,
   pub dynamic_resources: StatefulTable<KubeDynamicResource>,
   pub dynamic_resource_cache: DynamicResourceCache,
@@ -276,6 +432,8 @@ pub struct Data {
 /// selected data items
 pub struct Selected {
   pub ns: Option<String>,
+  /// Namespace restored when toggling back from all namespaces
+  pub previous_ns: Option<String>,
   pub pod: Option<String>,
   pub container: Option<String>,
   pub context: Option<String>,
@@ -286,6 +444,16 @@ pub struct Selected {
   pub pod_selector_ns: Option<String>,
   /// Parent resource name for display in drill-down title breadcrumbs
   pub pod_selector_resource: Option<String>,
+  /// User-entered label selector applied server-side to the pod list
+  pub label_selector: Option<String>,
+  /// ConfigMap whose keys are shown in the ConfigMap data view
+  pub config_map: Option<String>,
+  /// Deployment whose ReplicaSets are shown in a deployment drill-down
+  pub replica_set_owner: Option<String>,
+  /// Namespace of the drilled-down deployment
+  pub replica_set_owner_ns: Option<String>,
+  /// Deployment whose pods are followed through a rollout
+  pub followed_rollout: Option<FollowedRollout>,
 }
 
 /// Holds main application state
@@ -295,6 +463,11 @@ pub struct App {
   io_stream_tx: Option<Sender<IoStreamEvent>>,
   io_cmd_tx: Option<Sender<IoCmdEvent>>,
   log_cancel_tx: watch::Sender<bool>,
+  /// The pod whose containers view is kept live by a watch, if any.
+  pod_watch_tx: watch::Sender<Option<WatchedPod>>,
+  event_watch_tx: watch::Sender<Option<events::WatchedEvents>>,
+  /// The apply whose objects are watched; bumped to end the watches.
+  apply_watch_tx: watch::Sender<u64>,
   loading_counter: u32,
   background_cache_pending: bool,
   pub should_quit: bool,
@@ -306,10 +479,54 @@ pub struct App {
   pub menu_filter_active: bool,
   pub ns_filter: String,
   pub ns_filter_active: bool,
+  /// Narrows the help page to matching keys and descriptions.
+  pub help_filter: String,
+  pub help_filter_active: bool,
   pub show_info_bar: bool,
+  /// When `goto_view` was pressed, while its second key is awaited.
+  pub goto_pending: Option<Instant>,
+  /// Wide, compact or by terminal width; see [`layout`].
+  pub layout_mode: LayoutMode,
+  /// How much the footer and toasts say; see [`footer`].
+  pub footer_verbosity: FooterVerbosity,
+  /// Shown in the verbose footer.
+  pub last_network_event: Option<LastNetworkEvent>,
+  /// Hide healthy rows; see [`health`]. Kept across tab switches.
+  pub problems_only: bool,
+  /// Show names without their controller hashes; see [`short_names`].
+  pub short_names: bool,
+  /// Keep the selection on the same resource across refreshes, by name and
+  /// namespace, rather than on the same row index.
+  pub follow_selection: bool,
   pub is_streaming: bool,
+  /// Log streams waiting to reconnect after their connection dropped,
+  /// flagged in the log view title.
+  pub log_reconnecting: usize,
   pub is_routing: bool,
+  /// Periodic polling is paused; navigating and the refresh keys still fetch.
+  pub polling_paused: bool,
+  /// `--read-only`: every change to the cluster is refused.
+  pub read_only: bool,
+  /// Sensitive values are masked on screen, toggled with `Alt-r`.
+  pub redact: bool,
+  /// What redact mode masks, built from the config when it loads.
+  redactor: Redactor,
+  /// `--profile`: the config file profile in use, laid over the file again
+  /// when it is reloaded.
+  pub profile: Option<String>,
+  /// Resources pinned with `b`, in every context.
+  pub watchlist: Vec<watchlist::Pin>,
+  /// Where pins are saved; `None` keeps them in memory only.
+  pub watchlist_path: Option<PathBuf>,
   pub tick_until_poll: u64,
+  /// Views polled at their own rate instead of every `tick_until_poll` ticks.
+  pub poll_schedule: PollSchedule,
+  /// `--eager-load`: the background cache fetches every view, opened or not.
+  pub eager_load: bool,
+  /// Views opened this session, which the background cache keeps fresh.
+  opened_views: HashSet<ActiveBlock>,
+  /// Views under `prewarm`, fetched in the background before they are opened.
+  prewarm: HashSet<ActiveBlock>,
   pub tick_count: u64,
   pub enhanced_graphics: bool,
   pub size: Rect,
@@ -325,6 +542,9 @@ pub struct App {
   pub palette: Palette,
   pub wide_columns: bool,
   pub refresh: bool,
+  /// Bumped whenever something on screen changes, so the UI loop can skip
+  /// drawing frames identical to the last one.
+  revision: u64,
   pub log_auto_scroll: bool,
   /// True while the log view shows previous (terminated) container logs, so the
   /// periodic poll does not overwrite it with a live stream.
@@ -333,14 +553,37 @@ pub struct App {
   pub log_timestamps: bool,
   /// Wrap long log lines to the viewport width (vs. truncate). Render-side only.
   pub log_wrap: bool,
+  /// Pretty-print JSON log lines. Render-side only, like `log_wrap`.
+  pub log_json: bool,
+  /// Make URLs on screen clickable with OSC 8 hyperlinks.
+  pub hyperlinks: bool,
   /// The log stream that produced the current log view, kept so a timestamp
   /// toggle can re-issue it with the new option.
   active_log_stream: Option<IoStreamEvent>,
   pub log_tail_lines: u32,
+  /// Only stream logs newer than this window. Replaces the initial tail-line
+  /// count while set; changing it re-streams the logs.
+  pub log_since: Option<Duration>,
   pub utilization_group_by: Vec<GroupBy>,
   /// Vertical scroll offset for the grouped help page (clamped at render time).
   pub help_scroll: u16,
   pub error_history: VecDeque<ErrorRecord>,
+  /// Errors recorded so far, to tell whether a fetch reported one.
+  errors_recorded: u64,
+  /// An error since the last check was the API server rejecting the
+  /// credentials.
+  auth_failed: bool,
+  /// The credentials were rejected even after rebuilding the client, so
+  /// they likely need refreshing outside KDash.
+  pub auth_expired: bool,
+  /// The context the clients are being rebuilt for, shown as `Connecting to
+  /// …` until the network thread has switched. Requests queued before the
+  /// switch are dropped meanwhile.
+  pub connecting_to: Option<String>,
+  /// The clients are being rebuilt because the machine seems to have slept.
+  pub reconnecting_after_sleep: bool,
+  /// Wall-clock time of the last tick, to spot a resume from sleep.
+  last_tick_at: Option<SystemTime>,
   /// Active `kubectl port-forward` children, listed and stopped via the
   /// forwards overlay.
   pub port_forwards: Vec<PortForward>,
@@ -349,6 +592,22 @@ pub struct App {
   pub show_port_forwards: bool,
   /// Selection state for the forwards overlay list.
   pub port_forwards_state: ListState,
+  /// Mutating actions taken this session, oldest first.
+  pub audit_log: VecDeque<AuditEntry>,
+  /// Whether the audit overlay is open.
+  pub show_audit_log: bool,
+  /// Requests the network thread handled, per view.
+  pub api_stats: api_stats::ApiStats,
+  /// Whether the API requests overlay is open.
+  pub show_api_stats: bool,
+  /// Output of the running or last kubectl action, shown until closed.
+  pub cmd_output: Option<cmd_output::CmdOutput>,
+  /// What the kube client was built with, while its overlay is open.
+  pub connection_info: Option<ConnectionInfo>,
+  /// Objects applied from the clipboard and how ready they are, while their
+  /// overlay is open.
+  pub apply_status: Option<ApplyStatus>,
+  pub audit_log_state: ListState,
   pending_terminal_action: Option<PendingTerminalAction>,
   /// Transient confirmation overlay guarding an impactful action.
   pub modal: Option<Modal>,
@@ -356,6 +615,14 @@ pub struct App {
   pub input_modal: Option<InputModal>,
   /// Transient `m` action-menu overlay for the selected resource.
   pub action_menu: Option<StatefulList<ResourceAction>>,
+  /// Transient `Ctrl-p` resource finder overlay.
+  pub finder: Option<Finder>,
+  /// Transient `Ctrl-n` namespace picker overlay.
+  pub ns_picker: Option<NsPicker>,
+  /// The macro whose steps are running, until one fails or the last is done.
+  pub running_macro: Option<String>,
+  /// Crash alerts, when enabled with `--alerts`.
+  pub pod_alerts: Option<PodAlerts>,
   pub config: KdashConfig,
   pub data: Data,
 }
@@ -364,13 +631,20 @@ impl Default for Data {
   fn default() -> Self {
     Data {
       clis: vec![],
+      server_version: None,
+      impersonating: None,
+      insecure_tls: false,
       kubeconfig: None,
       contexts: StatefulTable::new(),
       active_context: None,
       node_metrics: vec![],
+      metrics_source: metrics::MetricsSource::default(),
+      metrics_probed_at: None,
+      pod_top_usage: vec![],
       namespaces: StatefulTable::new(),
       selected: Selected {
         ns: None,
+        previous_ns: None,
         pod: None,
         container: None,
         context: None,
@@ -378,16 +652,45 @@ impl Default for Data {
         pod_selector: None,
         pod_selector_ns: None,
         pod_selector_resource: None,
+        label_selector: None,
+        config_map: None,
+        replica_set_owner: None,
+        replica_set_owner_ns: None,
+        followed_rollout: None,
       },
       logs: LogsState::new(String::default()),
       describe_out: ScrollableTxt::new(),
+      yaml_detail: None,
+      pod_constraints: None,
+      container_mounts: None,
+      container_env: None,
+      group_events: false,
+      group_pods: false,
+      collapsed_pod_groups: HashSet::new(),
+      pod_groups: StatefulTable::new(),
+      describe_cache: describe_cache::DescribeCache::default(),
+      last_describe: None,
+      load_states: HashMap::new(),
+      list_pages: HashMap::new(),
+      failed_events: HashMap::new(),
+      context_probes: contexts::ContextProbes::default(),
+      daemon_set_coverage: None,
+      service_endpoints: None,
+      pod_controllers: None,
+      pod_events: None,
+      container_usage: None,
+      event_buffer: events::EventBuffer::default(),
+      event_watch_given_up: None,
+      binding_rules: None,
       metrics: StatefulTable::new(),
       troubleshoot_findings: StatefulTable::new(),
+      watchlist: StatefulTable::new(),
       nodes: StatefulTable::new(),
       pods: StatefulTable::new(),
       containers: StatefulTable::new(),
       services: StatefulTable::new(),
       config_maps: StatefulTable::new(),
+      config_map_data: StatefulTable::new(),
       stateful_sets: StatefulTable::new(),
       replica_sets: StatefulTable::new(),
       deployments: StatefulTable::new(),
@@ -407,6 +710,13 @@ impl Default for Data {
       network_policies: StatefulTable::new(),
       service_accounts: StatefulTable::new(),
       events: StatefulTable::new(),
+      resource_quotas: StatefulTable::new(),
+      limit_ranges: StatefulTable::new(),
+      pdbs: StatefulTable::new(),
+      crds: StatefulTable::new(),
+      api_resources: StatefulTable::new(),
+      node_pods: StatefulTable::new(),
+      node_pods_sort: NodePodsSort::default(),
       dynamic_kinds: vec![],
       dynamic_resources: StatefulTable::new(),
       dynamic_resource_cache: DynamicResourceCache::default(),
@@ -414,15 +724,54 @@ impl Default for Data {
   }
 }
 
+/// Tab title without its key hint: `Pods <1>` is `Pods`.
+/// The theme named by `default_theme`, Macchiato when unset or unknown.
+fn default_theme_from(config: &KdashConfig) -> ThemeName {
+  config
+    .default_theme
+    .as_deref()
+    .and_then(|name| name.parse::<ThemeName>().ok())
+    .unwrap_or(ThemeName::Macchiato)
+}
+
+fn custom_palette_from(config: &KdashConfig) -> Option<Palette> {
+  config
+    .custom_theme
+    .as_ref()
+    .map(|custom| custom.resolve().0)
+}
+
+fn tab_name(title: &str) -> &str {
+  title.rsplit_once(" <").map_or(title, |(name, _)| name)
+}
+
+/// A context for messages, where `None` is the kubeconfig's current one.
+pub fn context_label(context: &Option<String>) -> String {
+  context
+    .clone()
+    .unwrap_or_else(|| "the current context".into())
+}
+
+/// `All Contexts` is `all-contexts`.
+pub fn view_slug(name: &str) -> String {
+  name.trim().to_lowercase().replace([' ', '_'], "-")
+}
+
 impl Default for App {
   fn default() -> Self {
     let (log_cancel_tx, _) = watch::channel(false);
+    let (pod_watch_tx, _) = watch::channel(None);
+    let (event_watch_tx, _) = watch::channel(None);
+    let (apply_watch_tx, _) = watch::channel(0);
     App {
       navigation_stack: vec![DEFAULT_ROUTE],
       io_tx: None,
       io_stream_tx: None,
       io_cmd_tx: None,
       log_cancel_tx,
+      pod_watch_tx,
+      event_watch_tx,
+      apply_watch_tx,
       should_quit: false,
       main_tabs: TabsState::new(vec![
         TabRoute {
@@ -462,6 +811,20 @@ impl Default for App {
             id: RouteId::Troubleshoot,
           },
         },
+        TabRoute {
+          title: format!("Dashboard {}", DEFAULT_KEYBINDING.jump_to_dashboard.key),
+          route: Route {
+            active_block: ActiveBlock::Dashboard,
+            id: RouteId::Dashboard,
+          },
+        },
+        TabRoute {
+          title: format!("Watchlist {}", DEFAULT_KEYBINDING.jump_to_watchlist.key),
+          route: Route {
+            active_block: ActiveBlock::Watchlist,
+            id: RouteId::Watchlist,
+          },
+        },
       ]),
       context_tabs: TabsState::new(vec![
         TabRoute {
@@ -558,6 +921,13 @@ impl Default for App {
         ("Secrets".into(), ActiveBlock::Secrets),
         ("ServiceAccounts".into(), ActiveBlock::ServiceAccounts),
         ("NetworkPolicies".into(), ActiveBlock::NetworkPolicies),
+        ("ResourceQuotas".into(), ActiveBlock::ResourceQuotas),
+        ("LimitRanges".into(), ActiveBlock::LimitRanges),
+        (
+          "PodDisruptionBudgets".into(),
+          ActiveBlock::PodDisruptionBudgets,
+        ),
+        ("Pods by Node".into(), ActiveBlock::PodsByNode),
         ("CronJobs".into(), ActiveBlock::CronJobs),
         ("PersistentVolumes".into(), ActiveBlock::PersistentVolumes),
         ("StorageClasses".into(), ActiveBlock::StorageClasses),
@@ -572,17 +942,43 @@ impl Default for App {
           "ClusterRoleBinding".into(),
           ActiveBlock::ClusterRoleBindings,
         ),
+        (
+          "CustomResourceDefinitions".into(),
+          ActiveBlock::CustomResourceDefinitions,
+        ),
+        ("API Resources".into(), ActiveBlock::ApiResources),
       ]),
       dynamic_resources_menu: StatefulList::new(),
       menu_filter: String::new(),
       menu_filter_active: false,
       ns_filter: String::new(),
       ns_filter_active: false,
+      help_filter: String::new(),
+      help_filter_active: false,
       show_info_bar: true,
+      goto_pending: None,
+      layout_mode: LayoutMode::default(),
+      footer_verbosity: FooterVerbosity::default(),
+      last_network_event: None,
+      problems_only: false,
+      follow_selection: true,
+      short_names: false,
       loading_counter: 0,
       is_streaming: false,
+      log_reconnecting: 0,
       is_routing: false,
+      polling_paused: false,
+      read_only: false,
+      redact: false,
+      redactor: Redactor::new(DEFAULT_REDACT_PATTERNS),
+      profile: None,
+      watchlist: vec![],
+      watchlist_path: None,
       tick_until_poll: 0,
+      poll_schedule: PollSchedule::default(),
+      eager_load: false,
+      opened_views: HashSet::new(),
+      prewarm: prewarm_views(&KdashConfig::default()),
       tick_count: 0,
       enhanced_graphics: false,
       //   table_cols: 0,
@@ -596,24 +992,46 @@ impl Default for App {
       palette: palette_for(ThemeName::Macchiato),
       wide_columns: false,
       refresh: true,
+      revision: 0,
       log_auto_scroll: true,
       log_previous: false,
       log_timestamps: false,
       log_wrap: true,
+      log_json: false,
+      hyperlinks: false,
       active_log_stream: None,
       log_tail_lines: DEFAULT_LOG_TAIL_LINES,
+      log_since: None,
       utilization_group_by: Self::default_utilization_group_by(),
       help_scroll: 0,
       background_cache_pending: false,
       error_history: VecDeque::with_capacity(MAX_ERROR_HISTORY),
+      errors_recorded: 0,
+      auth_failed: false,
+      auth_expired: false,
+      connecting_to: None,
+      reconnecting_after_sleep: false,
+      last_tick_at: None,
       port_forwards: Vec::new(),
       next_port_forward_id: 0,
       show_port_forwards: false,
       port_forwards_state: ListState::default(),
+      audit_log: VecDeque::new(),
+      show_audit_log: false,
+      api_stats: api_stats::ApiStats::default(),
+      show_api_stats: false,
+      cmd_output: None,
+      connection_info: None,
+      apply_status: None,
+      audit_log_state: ListState::default(),
       pending_terminal_action: None,
       modal: None,
       input_modal: None,
       action_menu: None,
+      finder: None,
+      ns_picker: None,
+      running_macro: None,
+      pod_alerts: None,
       config: KdashConfig::default(),
       data: Data::default(),
     }
@@ -650,11 +1068,13 @@ impl App {
       ActiveBlock::Contexts => Some(&self.data.contexts),
       ActiveBlock::Utilization => Some(&self.data.metrics),
       ActiveBlock::Troubleshoot => Some(&self.data.troubleshoot_findings),
+      ActiveBlock::Watchlist => Some(&self.data.watchlist),
       ActiveBlock::Pods => Some(&self.data.pods),
       ActiveBlock::Containers => Some(&self.data.containers),
       ActiveBlock::Services => Some(&self.data.services),
       ActiveBlock::Nodes => Some(&self.data.nodes),
       ActiveBlock::ConfigMaps => Some(&self.data.config_maps),
+      ActiveBlock::ConfigMapData => Some(&self.data.config_map_data),
       ActiveBlock::StatefulSets => Some(&self.data.stateful_sets),
       ActiveBlock::ReplicaSets => Some(&self.data.replica_sets),
       ActiveBlock::Deployments => Some(&self.data.deployments),
@@ -672,6 +1092,12 @@ impl App {
       ActiveBlock::PersistentVolumeClaims => Some(&self.data.persistent_volume_claims),
       ActiveBlock::PersistentVolumes => Some(&self.data.persistent_volumes),
       ActiveBlock::NetworkPolicies => Some(&self.data.network_policies),
+      ActiveBlock::ResourceQuotas => Some(&self.data.resource_quotas),
+      ActiveBlock::LimitRanges => Some(&self.data.limit_ranges),
+      ActiveBlock::PodDisruptionBudgets => Some(&self.data.pdbs),
+      ActiveBlock::CustomResourceDefinitions => Some(&self.data.crds),
+      ActiveBlock::ApiResources => Some(&self.data.api_resources),
+      ActiveBlock::PodsByNode => Some(&self.data.node_pods),
       ActiveBlock::ServiceAccounts => Some(&self.data.service_accounts),
       ActiveBlock::DynamicResource => Some(&self.data.dynamic_resources),
       _ => None,
@@ -683,11 +1109,13 @@ impl App {
       ActiveBlock::Contexts => Some(&mut self.data.contexts),
       ActiveBlock::Utilization => Some(&mut self.data.metrics),
       ActiveBlock::Troubleshoot => Some(&mut self.data.troubleshoot_findings),
+      ActiveBlock::Watchlist => Some(&mut self.data.watchlist),
       ActiveBlock::Pods => Some(&mut self.data.pods),
       ActiveBlock::Containers => Some(&mut self.data.containers),
       ActiveBlock::Services => Some(&mut self.data.services),
       ActiveBlock::Nodes => Some(&mut self.data.nodes),
       ActiveBlock::ConfigMaps => Some(&mut self.data.config_maps),
+      ActiveBlock::ConfigMapData => Some(&mut self.data.config_map_data),
       ActiveBlock::StatefulSets => Some(&mut self.data.stateful_sets),
       ActiveBlock::ReplicaSets => Some(&mut self.data.replica_sets),
       ActiveBlock::Deployments => Some(&mut self.data.deployments),
@@ -705,6 +1133,12 @@ impl App {
       ActiveBlock::PersistentVolumeClaims => Some(&mut self.data.persistent_volume_claims),
       ActiveBlock::PersistentVolumes => Some(&mut self.data.persistent_volumes),
       ActiveBlock::NetworkPolicies => Some(&mut self.data.network_policies),
+      ActiveBlock::ResourceQuotas => Some(&mut self.data.resource_quotas),
+      ActiveBlock::LimitRanges => Some(&mut self.data.limit_ranges),
+      ActiveBlock::PodDisruptionBudgets => Some(&mut self.data.pdbs),
+      ActiveBlock::CustomResourceDefinitions => Some(&mut self.data.crds),
+      ActiveBlock::ApiResources => Some(&mut self.data.api_resources),
+      ActiveBlock::PodsByNode => Some(&mut self.data.node_pods),
       ActiveBlock::ServiceAccounts => Some(&mut self.data.service_accounts),
       ActiveBlock::DynamicResource => Some(&mut self.data.dynamic_resources),
       _ => None,
@@ -729,36 +1163,54 @@ impl App {
     config: KdashConfig,
   ) -> Self {
     let show_info_bar = !config.hide_info_on_start;
-    let custom_palette = config
-      .custom_theme
-      .as_ref()
-      .map(|custom| custom.resolve().0);
-    let theme = config
-      .default_theme
-      .as_deref()
-      .and_then(|name| name.parse::<ThemeName>().ok())
-      .unwrap_or(ThemeName::Macchiato);
+    let hyperlinks = config.hyperlinks;
+    let poll_schedule = PollSchedule::from_config(&config);
+    let prewarm = prewarm_views(&config);
+    let custom_palette = custom_palette_from(&config);
+    let theme = default_theme_from(&config);
+    let redactor = Redactor::from_config(&config);
     let mut app = App {
       io_tx: Some(io_tx),
       io_stream_tx: Some(io_stream_tx),
       io_cmd_tx: Some(io_cmd_tx),
       enhanced_graphics,
       tick_until_poll,
+      poll_schedule,
+      prewarm,
       log_tail_lines,
       show_info_bar,
+      hyperlinks,
       theme,
       custom_palette,
+      redactor,
       config,
       ..App::default()
     };
     app.resolve_palette();
+    if app.config.start_on_dashboard {
+      app.route_dashboard();
+    }
     app
   }
 
-  /// Whether the active theme is a dark theme (drives syntax-highlight theme
-  /// selection and any light/dark-conditional rendering).
-  pub fn is_dark(&self) -> bool {
-    self.palette.is_dark
+  /// Take a config file reloaded while running. Its keybindings and theme
+  /// overrides are already active; this picks up what the app keeps from it.
+  /// The theme only follows `default_theme` when that changed, so a theme
+  /// picked with `t` survives unrelated edits.
+  pub fn apply_config(&mut self, config: KdashConfig) {
+    if config.default_theme != self.config.default_theme {
+      self.theme = default_theme_from(&config);
+    }
+    self.custom_palette = custom_palette_from(&config);
+    if self.theme == ThemeName::Custom && self.custom_palette.is_none() {
+      self.theme = ThemeName::Macchiato;
+    }
+    self.poll_schedule = PollSchedule::from_config(&config);
+    self.prewarm = prewarm_views(&config);
+    self.redactor = Redactor::from_config(&config);
+    self.config = config;
+    self.resolve_palette();
+    self.mark_dirty();
   }
 
   /// Rebuild [`Self::palette`] from `theme`, layering the legacy
@@ -838,8 +1290,134 @@ impl App {
     self.loading_counter > 0
   }
 
+  /// Called once a network, stream or cmd event is handled, which is also
+  /// when the data it fetched is in place.
   pub fn loading_complete(&mut self) {
     self.loading_counter = self.loading_counter.saturating_sub(1);
+    self.mark_dirty();
+  }
+
+  /// Note a change the next frame has to show.
+  pub fn mark_dirty(&mut self) {
+    self.revision = self.revision.wrapping_add(1);
+  }
+
+  pub fn revision(&self) -> u64 {
+    self.revision
+  }
+
+  /// Whether the frame drawn at revision `drawn` is out of date: state changed
+  /// since, or a loading spinner is turning.
+  pub fn needs_redraw(&self, drawn: u64) -> bool {
+    drawn != self.revision
+      || self.is_loading()
+      || self.view_load(self.get_current_route().active_block).state == LoadState::Loading
+  }
+
+  /// Append streamed log lines and redraw for them.
+  pub fn add_log_records(&mut self, records: Vec<String>) {
+    self.data.logs.add_records(records);
+    self.mark_dirty();
+  }
+
+  pub fn errors_recorded(&self) -> u64 {
+    self.errors_recorded
+  }
+
+  /// Whether an authentication failure was reported since the last call.
+  pub fn take_auth_failure(&mut self) -> bool {
+    std::mem::take(&mut self.auth_failed)
+  }
+
+  /// Record how a fetch ended for the view it fills: an error reported since
+  /// `errors_before` fails the view and keeps the event for a retry,
+  /// otherwise the view is loaded or empty.
+  pub fn finish_load(&mut self, event: IoEvent, errors_before: u64) {
+    let Some(block) = event.view_block() else {
+      return;
+    };
+    let state = if self.errors_recorded > errors_before {
+      self.data.failed_events.insert(block, event);
+      LoadState::Error(self.api_error.clone())
+    } else {
+      self.data.failed_events.remove(&block);
+      if self
+        .resource_table(block)
+        .is_some_and(FilterableTable::is_empty)
+      {
+        LoadState::Empty
+      } else {
+        LoadState::Loaded
+      }
+    };
+    self.data.load_states.insert(block, state);
+  }
+
+  /// The failed fetch of the current view, if its last one failed.
+  pub fn current_failed_event(&self) -> Option<&IoEvent> {
+    self
+      .data
+      .failed_events
+      .get(&self.get_current_route().active_block)
+  }
+
+  /// Re-dispatch the failed fetch of the current view, if any.
+  pub async fn retry_failed_fetch(&mut self) {
+    let Some(event) = self.current_failed_event().cloned() else {
+      return;
+    };
+    let block = self.get_current_route().active_block;
+    self.data.load_states.insert(block, LoadState::Loading);
+    self.set_status_message("Retrying...");
+    self.dispatch(event).await;
+  }
+
+  /// Whether the deployments view shows a rollout that is still progressing,
+  /// which is followed more closely than the regular poll.
+  fn rollout_in_progress(&self) -> bool {
+    self.get_current_route().active_block == ActiveBlock::Deployments
+      && self
+        .data
+        .deployments
+        .get_selected_item()
+        .is_some_and(|d| d.rollout().state == deployments::RolloutState::Progressing)
+  }
+
+  /// Load state of a view for rendering. A view that has not finished a
+  /// fetch yet follows the global loading indicator.
+  pub fn view_load(&self, block: ActiveBlock) -> ViewLoad {
+    let has_more = self.data.list_pages.get(&block).is_some_and(|p| p.has_more);
+    match self.data.load_states.get(&block) {
+      Some(state) => ViewLoad {
+        state: state.clone(),
+        tick: self.tick_count,
+        has_more,
+        highlight_changes: self.highlight_changes(),
+        problems_only: self.problems_only,
+        follow_selection: self.follow_selection,
+      },
+      None => ViewLoad {
+        has_more,
+        ..self.global_load()
+      },
+    }
+  }
+
+  /// Load state for output not tied to one view (describe, logs, CLI info).
+  pub fn global_load(&self) -> ViewLoad {
+    let state = if self.is_loading() {
+      LoadState::Loading
+    } else {
+      LoadState::Loaded
+    };
+    ViewLoad {
+      state,
+      tick: self.tick_count,
+      has_more: false,
+      highlight_changes: self.highlight_changes(),
+      problems_only: self.problems_only,
+      follow_selection: self.follow_selection,
+    }
   }
 
   /// Signal any active log stream to stop
@@ -847,6 +1425,127 @@ impl App {
     let _ = self.log_cancel_tx.send(true);
   }
 
+  /// The pod being watched, if a watch is on.
+  pub fn watched_pod(&self) -> Option<WatchedPod> {
+    self.pod_watch_tx.borrow().clone()
+  }
+
+  /// A receiver that sees the watched pod change, so a watch can tell when
+  /// it is no longer wanted.
+  pub fn pod_watch_rx(&self) -> watch::Receiver<Option<WatchedPod>> {
+    self.pod_watch_tx.subscribe()
+  }
+
+  /// End the pod watch, if any; the next poll re-lists pods as usual.
+  pub fn stop_pod_watch(&self) {
+    self.pod_watch_tx.send_replace(None);
+  }
+
+  /// Watch the selected pod while its containers are shown, and stop once the
+  /// view is left. Called whenever the route changes.
+  pub async fn sync_pod_watch(&mut self) {
+    let wanted = if self.get_current_route().active_block == ActiveBlock::Containers {
+      self.data.selected.pod.as_ref().and_then(|name| {
+        self
+          .data
+          .pods
+          .items
+          .iter()
+          .find(|pod| &pod.name == name)
+          .map(|pod| WatchedPod {
+            namespace: pod.namespace.clone(),
+            name: pod.name.clone(),
+          })
+      })
+    } else {
+      None
+    };
+    if wanted == self.watched_pod() {
+      return;
+    }
+    self.pod_watch_tx.send_replace(wanted.clone());
+    if let Some(pod) = wanted {
+      self.dispatch_pod_events().await;
+      self.dispatch_container_usage().await;
+      self
+        .dispatch_stream(IoStreamEvent::WatchPod {
+          namespace: pod.namespace,
+          name: pod.name,
+        })
+        .await;
+    }
+  }
+
+  /// Fetch the events of the pod whose containers are shown. Events of a
+  /// previously shown pod are dropped first.
+  pub async fn dispatch_pod_events(&mut self) {
+    let Some(pod) = self
+      .data
+      .selected
+      .pod
+      .as_ref()
+      .and_then(|name| self.data.pods.items.iter().find(|pod| &pod.name == name))
+    else {
+      return;
+    };
+    let (name, namespace) = (pod.name.clone(), pod.namespace.clone());
+    if !self
+      .data
+      .pod_events
+      .as_ref()
+      .is_some_and(|events| events.is_for(&namespace, &name))
+    {
+      self.data.pod_events = None;
+    }
+    self
+      .dispatch(IoEvent::GetPodEvents { name, namespace })
+      .await;
+  }
+
+  /// Fetch the per-container usage of the pod whose containers are shown,
+  /// dropping what was kept for another pod.
+  pub async fn dispatch_container_usage(&mut self) {
+    let Some(pod) = self
+      .data
+      .selected
+      .pod
+      .as_ref()
+      .and_then(|name| self.data.pods.items.iter().find(|pod| &pod.name == name))
+    else {
+      return;
+    };
+    let (name, namespace) = (pod.name.clone(), pod.namespace.clone());
+    if !self
+      .data
+      .container_usage
+      .as_ref()
+      .is_some_and(|usage| usage.is_for(&namespace, &name))
+    {
+      self.data.container_usage = None;
+    }
+    self
+      .dispatch(IoEvent::GetContainerUsage { name, namespace })
+      .await;
+  }
+
+  /// Apply a watched pod's latest state to the pods list and, while it is the
+  /// selected pod, to the containers view.
+  pub fn update_watched_pod(&mut self, pod: KubePod) {
+    self.mark_dirty();
+    if self.data.selected.pod.as_deref() == Some(pod.name.as_str()) {
+      self.data.containers.set_items(pod.containers.clone());
+    }
+    if let Some(item) = self
+      .data
+      .pods
+      .items
+      .iter_mut()
+      .find(|item| item.name == pod.name && item.namespace == pod.namespace)
+    {
+      *item = pod;
+    }
+  }
+
   /// Get a new receiver for log cancellation.
   /// Resets the channel so the next stream starts clean.
   pub fn new_log_cancel_rx(&self) -> watch::Receiver<bool> {
@@ -854,38 +1553,133 @@ impl App {
     self.log_cancel_tx.subscribe()
   }
 
+  pub fn restart_threshold(&self) -> i32 {
+    let threshold = self
+      .config
+      .restart_threshold
+      .unwrap_or(DEFAULT_RESTART_THRESHOLD);
+    i32::try_from(threshold).unwrap_or(i32::MAX)
+  }
+
+  /// How long changed rows stay highlighted, when highlighting is on.
+  pub fn highlight_changes(&self) -> Option<chrono::Duration> {
+    if !self.config.highlight_changes {
+      return None;
+    }
+    let secs = self
+      .config
+      .highlight_changes_secs
+      .unwrap_or(DEFAULT_HIGHLIGHT_CHANGES_SECS);
+    i64::try_from(secs)
+      .ok()
+      .and_then(chrono::Duration::try_seconds)
+      .filter(|window| *window > chrono::Duration::zero())
+  }
+
+  /// Stop polling usage until the metrics API is probed again.
+  pub fn set_metrics_unavailable(&mut self) {
+    self.data.metrics_source = metrics::MetricsSource::Unavailable;
+    self.data.metrics_probed_at = Some(Instant::now());
+  }
+
+  /// Note for the usage views while the cluster has no metrics API.
+  pub fn metrics_note(&self) -> Option<&'static str> {
+    (self.data.metrics_source == metrics::MetricsSource::Unavailable)
+      .then_some(metrics::METRICS_UNAVAILABLE_NOTE)
+  }
+
+  /// Columns of a resource view at `tier`, honouring its `columns` entry in
+  /// the config file.
+  pub fn column_layout(&self, block: ActiveBlock, tier: ViewTier) -> ColumnLayout {
+    let (view, defs) = columns::view_columns(block);
+    ColumnLayout::new(defs, tier, columns::configured_columns(&self.config, view))
+  }
+
+  pub fn pin_name_column(&self) -> bool {
+    self.config.pin_name_column.unwrap_or(true)
+  }
+
   pub fn initial_log_tail_lines(&self) -> i64 {
     i64::from(self.log_tail_lines)
   }
 
+  /// An empty log buffer for `id`, capped at `log_max_lines` from the config.
+  fn new_logs_state(&self, id: String) -> LogsState {
+    let max = self
+      .config
+      .log_max_lines
+      .filter(|max| *max > 0)
+      .unwrap_or(models::MAX_LOG_RECORDS);
+    LogsState::new(id).with_max_records(max)
+  }
+
+  /// `LogParams.since_seconds` for the active since-window. Rounded up so a
+  /// sub-second window still asks for the last second rather than everything.
+  pub fn log_since_seconds(&self) -> Option<i64> {
+    self
+      .log_since
+      .map(|since| i64::try_from(since.as_secs().max(1)).unwrap_or(i64::MAX))
+  }
+
   pub fn reset(&mut self) {
     self.cancel_log_stream();
+    self.stop_pod_watch();
+    self.stop_event_watch();
     self.kill_all_port_forwards();
     self.loading_counter = 0;
     self.tick_count = 0;
     self.api_error = String::new();
+    self.auth_expired = false;
+    self.connecting_to = None;
+    self.reconnecting_after_sleep = false;
     self.status_message.clear();
     self.modal = None;
     self.input_modal = None;
     self.action_menu = None;
+    self.finder = None;
+    self.ns_picker = None;
+    if self.pod_alerts.is_some() {
+      self.enable_pod_alerts();
+    }
     self.log_previous = false;
     self.utilization_group_by = Self::default_utilization_group_by();
     self.data = Data::default();
     self.route_home();
   }
 
+  /// Watch for pods that start crash-looping or fail from now on.
+  pub fn enable_pod_alerts(&mut self) {
+    self.pod_alerts = Some(PodAlerts::new(Utc::now()));
+  }
+
   /// Open a transient confirmation overlay.
   pub fn open_modal(&mut self, modal: Modal) {
+    if modal.on_confirm.is_mutating(self) && self.refuse_in_read_only() {
+      return;
+    }
     self.modal = Some(modal);
   }
 
-  /// Dismiss the active confirmation overlay, if any.
+  /// Dismiss the active confirmation overlay, if any. Declining to apply an
+  /// edit the dry-run rejected abandons it, so its temp file goes too.
   pub fn close_modal(&mut self) {
-    self.modal = None;
+    if let Some(Modal {
+      on_confirm: ConfirmAction::Cmd(IoCmdEvent::ApplyEdit {
+        kind, name, file, ..
+      }),
+      ..
+    }) = self.modal.take()
+    {
+      crate::cmd::edit::remove_edit_file(&file);
+      self.set_status_message(format!("Discarded the changes to {} '{}'", kind, name));
+    }
   }
 
   /// Open a transient single-line input overlay.
   pub fn open_input_modal(&mut self, modal: InputModal) {
+    if modal.action.is_mutating() && self.refuse_in_read_only() {
+      return;
+    }
     self.input_modal = Some(modal);
   }
 
@@ -908,6 +1702,150 @@ impl App {
     self.action_menu = None;
   }
 
+  /// Export the current table on the next draw; see [`App::write_pending_export`].
+  pub fn request_export(&mut self, format: ExportFormat) {
+    let block = self.get_current_route().active_block;
+    if block == ActiveBlock::Logs {
+      self.save_logs(&std::env::current_dir().unwrap_or_default(), utils::now());
+      return;
+    }
+    match self.resource_table_mut(block) {
+      Some(table) if !table.is_empty() => table.request_export(format),
+      _ => self.set_status_message("Nothing to export in this view"),
+    }
+  }
+
+  /// Drop a marker into the log view, e.g. to note when an incident began.
+  pub fn mark_logs(&mut self, note: &str, now: DateTime<FixedOffset>) {
+    self.data.logs.add_marker(note, now);
+    self.set_status_message(format!("Marked the logs at {}", now.format("%H:%M:%S")));
+  }
+
+  /// Save the log view, markers included, to a timestamped file in `dir`.
+  pub fn save_logs(&mut self, dir: &Path, now: DateTime<FixedOffset>) {
+    let logs = &self.data.logs;
+    let lines = logs.line_count();
+    if lines == 0 {
+      self.set_status_message("No log lines to save");
+      return;
+    }
+    let marks = match logs.marker_count() {
+      0 => String::new(),
+      1 => ", 1 marker".to_owned(),
+      n => format!(", {} markers", n),
+    };
+    match write_logs(dir, &logs.id, now, &logs.saved_text()) {
+      Ok(path) => self.set_status_message(format!(
+        "Saved {} {}{} to {}",
+        lines,
+        if lines == 1 { "line" } else { "lines" },
+        marks,
+        path.display()
+      )),
+      Err(e) => self.handle_error(anyhow!(
+        "Failed to save the logs to {}. {}",
+        dir.display(),
+        e
+      )),
+    }
+  }
+
+  /// Copy the selected row on the next draw; see [`App::write_pending_export`].
+  pub fn request_row_copy(&mut self) {
+    let block = self.get_current_route().active_block;
+    match self.resource_table_mut(block) {
+      Some(table) if !table.is_empty() => table.request_row_copy(),
+      _ => self.set_status_message("Nothing to copy in this view"),
+    }
+  }
+
+  /// Write the rows collected by the last draw to a timestamped file in `dir`,
+  /// reporting the path or the failure. A collected row goes to the clipboard
+  /// instead, as tab-separated text.
+  pub fn write_pending_export(&mut self, dir: &Path) {
+    let block = self.get_current_route().active_block;
+    let Some(export) = self
+      .resource_table_mut(block)
+      .map(|table| table.take_export())
+    else {
+      return;
+    };
+    let (format, text) = match export {
+      TableExport::Idle => return,
+      TableExport::Requested(_) => {
+        self.set_status_message("This view cannot be exported");
+        return;
+      }
+      TableExport::RowRequested => {
+        self.set_status_message("Rows of this view cannot be copied");
+        return;
+      }
+      TableExport::RowReady(text) => {
+        self.copy_row(&text);
+        return;
+      }
+      TableExport::Ready(format, text) => (format, text),
+    };
+    let view = columns::view_name(block)
+      .map(str::to_owned)
+      .unwrap_or_else(|| view_slug(&self.active_tab_name()));
+    match write_export(dir, &view, utils::now(), format, &text) {
+      Ok(path) => self.set_status_message(format!(
+        "Exported {} {} to {}",
+        text.rows.len(),
+        if text.rows.len() == 1 { "row" } else { "rows" },
+        path.display()
+      )),
+      Err(e) => self.handle_error(anyhow!(
+        "Failed to write export to {}. {}",
+        dir.display(),
+        e
+      )),
+    }
+  }
+
+  fn copy_row(&mut self, row: &TableText) {
+    if row.rows.is_empty() {
+      self.set_status_message("No row selected to copy");
+      return;
+    }
+    if copy_to_clipboard(row.to_tsv(), self) {
+      let shown: Vec<&str> = row.rows[0]
+        .iter()
+        .map(|cell| cell.trim())
+        .filter(|cell| !cell.is_empty())
+        .collect();
+      self.set_status_message(format!("Copied row: {}", shown.join(" · ")));
+    }
+  }
+
+  /// Open the resource finder over everything loaded so far.
+  pub fn open_finder(&mut self) {
+    self.finder = Some(Finder::new(finder::finder_entries(self)));
+  }
+
+  pub fn close_finder(&mut self) {
+    self.finder = None;
+  }
+
+  /// Open the entry's view with its row selected. The row is looked up again
+  /// by name, as the list may have been re-polled since the finder opened.
+  pub fn jump_to_finder_entry(&mut self, entry: &FinderEntry) {
+    let index = finder::finder_entries(self)
+      .into_iter()
+      .find(|e| e.block == entry.block && e.name == entry.name && e.namespace == entry.namespace)
+      .map_or(entry.index, |e| e.index);
+    if !self.route_to_resource_view(entry.block) {
+      return;
+    }
+    if let Some(table) = self.resource_table_mut(entry.block) {
+      let (filter, filter_active, state) = table.filter_parts_mut();
+      filter.clear();
+      *filter_active = false;
+      state.select(Some(index));
+    }
+  }
+
   pub fn selected_dynamic_cache_key(&self) -> Option<String> {
     self
       .data
@@ -932,6 +1870,9 @@ impl App {
 
   // Send a network event to the network thread
   pub async fn dispatch(&mut self, action: IoEvent) {
+    if action.audit_action(self).is_some() && self.refuse_in_read_only() {
+      return;
+    }
     // `loading_counter` will be decremented after the async action has finished in network/mod.rs
     if let Some(io_tx) = &self.io_tx {
       self.loading_counter += 1;
@@ -956,6 +1897,9 @@ impl App {
 
   // Send a cmd event to the cmd runner thread
   pub async fn dispatch_cmd(&mut self, action: IoCmdEvent) {
+    if action.audit_action().is_some() && self.refuse_in_read_only() {
+      return;
+    }
     // `loading_counter` will be decremented after the async action has finished in cmd/mod.rs
     if let Some(io_cmd_tx) = &self.io_cmd_tx {
       self.loading_counter += 1;
@@ -977,9 +1921,19 @@ impl App {
     self.data.contexts.set_items(contexts);
   }
 
+  /// Switch to `context`, landing on the namespace it sets in the kubeconfig,
+  /// or on all namespaces when it sets none.
+  pub fn switch_context(&mut self, context: KubeContext) {
+    self.data.selected.context = Some(context.name);
+    self.data.selected.ns = context.namespace;
+    self.data.selected.previous_ns = None;
+    self.refresh();
+  }
+
   pub fn handle_error(&mut self, e: anyhow::Error) {
     // Log the full debug output for diagnostics
     error!("{:?}", e);
+    self.auth_failed |= crate::app::utils::is_auth_failure(&e);
     // Show a cleaned-up message in the UI
     let message = crate::app::utils::sanitize_error_message(&e);
     self.record_error(message.clone());
@@ -988,8 +1942,10 @@ impl App {
   }
 
   pub fn record_error(&mut self, message: String) {
+    crate::network::note_event_error();
+    self.errors_recorded += 1;
     self.error_history.push_back(ErrorRecord {
-      timestamp: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+      timestamp: utils::format_now(),
       message,
     });
 
@@ -1008,6 +1964,9 @@ impl App {
   }
 
   pub fn queue_edit(&mut self, request: PendingEdit) {
+    if self.refuse_in_read_only() {
+      return;
+    }
     self.pending_terminal_action = Some(PendingTerminalAction::Edit(request));
   }
 
@@ -1099,6 +2058,7 @@ impl App {
   pub fn set_port_forward_status(&mut self, id: u64, status: PortForwardStatus) {
     if let Some(pf) = self.port_forwards.iter_mut().find(|pf| pf.id == id) {
       pf.status = status;
+      self.mark_dirty();
     }
   }
 
@@ -1110,6 +2070,43 @@ impl App {
     pf.child.take()
   }
 
+  /// Start a failed forward again with the same target and ports. The dead
+  /// entry is dropped first so its local port is free to rebind.
+  pub async fn restart_port_forward(&mut self, id: u64) {
+    let Some(index) = self
+      .port_forwards
+      .iter()
+      .position(|pf| pf.id == id && matches!(pf.status, PortForwardStatus::Failed(_)))
+    else {
+      self.set_status_message("Only a failed port-forward can be restarted");
+      return;
+    };
+    let pf = self.port_forwards.remove(index);
+    self.clamp_port_forwards_selection();
+    self
+      .start_port_forward(
+        pf.kind,
+        pf.namespace,
+        pf.name,
+        pf.local_port,
+        pf.remote_port,
+      )
+      .await;
+  }
+
+  /// Stop background work before exit: end the log stream and pod watch, kill
+  /// port-forwards and drop the channel senders so the network, stream and cmd
+  /// tasks drain their queues and finish.
+  pub fn begin_shutdown(&mut self) {
+    self.cancel_log_stream();
+    self.stop_pod_watch();
+    self.stop_event_watch();
+    self.kill_all_port_forwards();
+    self.io_tx = None;
+    self.io_stream_tx = None;
+    self.io_cmd_tx = None;
+  }
+
   /// SIGKILL every tracked forward and clear the list. Used on quit; reaping is
   /// left to the OS since the process is exiting.
   pub fn kill_all_port_forwards(&mut self) {
@@ -1142,6 +2139,19 @@ impl App {
     self.show_port_forwards = false;
   }
 
+  /// Open the connection info overlay on what the latest kube client was
+  /// built with.
+  pub fn open_connection_info(&mut self) {
+    match connection_info::latest() {
+      Some(info) => self.connection_info = Some(info),
+      None => self.set_status_message("No kube client has been built yet"),
+    }
+  }
+
+  pub fn close_connection_info(&mut self) {
+    self.connection_info = None;
+  }
+
   /// The id of the forward currently highlighted in the overlay, if any.
   pub fn selected_port_forward_id(&self) -> Option<u64> {
     self
@@ -1167,7 +2177,11 @@ impl App {
   }
 
   fn clear_expired_status_message(&mut self, now: Instant) {
+    let shown = !self.status_message.text().is_empty();
     self.status_message.clear_if_expired(now);
+    if shown && self.status_message.text().is_empty() {
+      self.mark_dirty();
+    }
   }
 
   pub fn push_navigation_stack(&mut self, id: RouteId, active_block: ActiveBlock) {
@@ -1209,6 +2223,52 @@ impl App {
     }
   }
 
+  /// Human-readable kind label for confirmation prompts, also the kind passed
+  /// to describe.
+  pub fn resource_kind_label(&self, block: ActiveBlock) -> String {
+    let label = match block {
+      ActiveBlock::Pods | ActiveBlock::Containers => "pod",
+      ActiveBlock::Services => "service",
+      ActiveBlock::ConfigMaps => "configmap",
+      ActiveBlock::Secrets => "secret",
+      ActiveBlock::StatefulSets => "statefulset",
+      ActiveBlock::ReplicaSets => "replicaset",
+      ActiveBlock::Deployments => "deployment",
+      ActiveBlock::Jobs => "job",
+      ActiveBlock::DaemonSets => "daemonset",
+      ActiveBlock::CronJobs => "cronjob",
+      ActiveBlock::ReplicationControllers => "replicationcontroller",
+      ActiveBlock::Roles => "role",
+      ActiveBlock::RoleBindings => "rolebinding",
+      ActiveBlock::Ingresses => "ingress",
+      ActiveBlock::PersistentVolumeClaims => "persistentvolumeclaim",
+      ActiveBlock::NetworkPolicies => "networkpolicy",
+      ActiveBlock::ResourceQuotas => "resourcequota",
+      ActiveBlock::LimitRanges => "limitrange",
+      ActiveBlock::PodDisruptionBudgets => "poddisruptionbudget",
+      ActiveBlock::ServiceAccounts => "serviceaccount",
+      ActiveBlock::Events => "event",
+      ActiveBlock::Namespaces => "namespace",
+      ActiveBlock::Nodes | ActiveBlock::PodsByNode => "node",
+      ActiveBlock::PersistentVolumes => "persistentvolume",
+      ActiveBlock::StorageClasses => "storageclass",
+      ActiveBlock::ClusterRoles => "clusterrole",
+      ActiveBlock::ClusterRoleBindings => "clusterrolebinding",
+      ActiveBlock::CustomResourceDefinitions => "customresourcedefinition",
+      ActiveBlock::DynamicResource => {
+        return self
+          .data
+          .selected
+          .dynamic_kind
+          .as_ref()
+          .map(|kind| kind.kind.to_lowercase())
+          .unwrap_or_else(|| "resource".to_owned());
+      }
+      _ => "resource",
+    };
+    label.to_owned()
+  }
+
   pub fn get_current_route(&self) -> &Route {
     // if for some reason there is no route return the default
     self.navigation_stack.last().unwrap_or(&DEFAULT_ROUTE)
@@ -1261,6 +2321,16 @@ impl App {
     self.set_route(route);
   }
 
+  pub fn route_dashboard(&mut self) {
+    let route = self.main_tabs.set_index(4).route.clone();
+    self.set_route(route);
+  }
+
+  pub fn route_watchlist(&mut self) {
+    let route = self.main_tabs.set_index(5).route.clone();
+    self.set_route(route);
+  }
+
   /// Navigate from a node to its pods via field selector.
   pub async fn dispatch_node_pods(&mut self, node_name: String, route_id: RouteId) {
     self.data.selected.pod_selector = Some(node_name.clone());
@@ -1290,43 +2360,136 @@ impl App {
     self.push_navigation_stack(route_id, ActiveBlock::Pods);
   }
 
+  pub async fn dispatch_deployment_replica_sets(
+    &mut self,
+    namespace: String,
+    owner: String,
+    route_id: RouteId,
+  ) {
+    self.data.selected.replica_set_owner = Some(owner.clone());
+    self.data.selected.replica_set_owner_ns = Some(namespace.clone());
+    self
+      .dispatch(IoEvent::GetReplicaSetsByOwner { namespace, owner })
+      .await;
+    self.push_navigation_stack(route_id, ActiveBlock::ReplicaSets);
+  }
+
   pub async fn dispatch_pod_logs(&mut self, pod_name: String, route_id: RouteId) {
-    self.cancel_log_stream();
-    self.log_previous = false;
-    self.data.logs = LogsState::new(format!("agg:{}", pod_name));
-    self.push_navigation_stack(route_id, ActiveBlock::Logs);
-    self.active_log_stream = Some(IoStreamEvent::GetPodAllContainerLogs);
+    self.open_pod_logs(pod_name, route_id);
     self
       .dispatch_stream(IoStreamEvent::GetPodAllContainerLogs)
       .await;
   }
 
-  pub async fn dispatch_container_logs(&mut self, id: String, route_id: RouteId) {
-    self.cancel_log_stream();
-    self.log_previous = false;
-    self.data.logs = LogsState::new(id);
-    self.push_navigation_stack(route_id, ActiveBlock::Logs);
-    self.active_log_stream = Some(IoStreamEvent::GetPodLogs(true));
-    self.dispatch_stream(IoStreamEvent::GetPodLogs(true)).await;
+  /// Show a resource in the YAML view, opening on its summary when there is
+  /// one.
+  pub fn open_yaml_view(&mut self, yaml: String, summary: Option<String>, route_id: RouteId) {
+    self.data.describe_out =
+      ScrollableTxt::with_string(summary.clone().unwrap_or_else(|| yaml.clone()));
+    self.data.yaml_detail = summary.map(|summary| YamlDetail {
+      summary,
+      yaml,
+      full: false,
+    });
+    self.push_navigation_stack(route_id, ActiveBlock::Yaml);
   }
 
-  /// View the previous (terminated) instance's logs for the selected container.
-  /// One-shot fetch — sets `log_previous` so the periodic poll does not replace
-  /// it with a live stream.
-  pub async fn dispatch_previous_logs(&mut self, id: String, route_id: RouteId) {
+  /// Switch the YAML view between the summary and the full YAML.
+  pub fn toggle_yaml_summary(&mut self) {
+    let Some(detail) = self.data.yaml_detail.as_mut() else {
+      self.set_status_message("No summary for this kind, the full YAML is shown");
+      return;
+    };
+    detail.full = !detail.full;
+    let text = if detail.full {
+      &detail.yaml
+    } else {
+      &detail.summary
+    };
+    self.data.describe_out = ScrollableTxt::with_string(text.clone());
+  }
+
+  /// Switch to the logs view of all containers of the selected pod; the
+  /// caller starts the stream.
+  pub fn open_pod_logs(&mut self, pod_name: String, route_id: RouteId) {
     self.cancel_log_stream();
-    self.log_previous = true;
-    // Use the container name as the id (matching the live-logs view) so the log
-    // view's `container == logs.id` render guard passes; `log_previous` keeps
-    // the periodic poll from replacing it with a live stream.
-    self.data.logs = LogsState::new(id);
+    self.log_previous = false;
+    self.data.logs = self.new_logs_state(format!("agg:{}", pod_name));
     self.push_navigation_stack(route_id, ActiveBlock::Logs);
-    self.active_log_stream = Some(IoStreamEvent::GetPreviousLogs);
-    self.dispatch_stream(IoStreamEvent::GetPreviousLogs).await;
+    self.active_log_stream = Some(IoStreamEvent::GetPodAllContainerLogs);
   }
 
-  /// Start aggregate log streaming from all pods matching a label selector.
-  pub async fn dispatch_aggregate_logs(
+  /// Resolve the pods of a workload or service and open the logs of the most
+  /// recently started ready one.
+  pub async fn dispatch_jump_to_logs(
+    &mut self,
+    name: String,
+    namespace: String,
+    selector: String,
+    resource_name: String,
+  ) {
+    self
+      .dispatch_stream(IoStreamEvent::JumpToLogs {
+        name,
+        namespace,
+        selector,
+        resource_name,
+      })
+      .await;
+  }
+
+  /// Drill down into `pods` of a workload, as Enter does, select the one at
+  /// `index` and open its logs on top, so going back lands on the pods.
+  pub fn open_representative_pod_logs(
+    &mut self,
+    namespace: String,
+    selector: String,
+    resource_name: String,
+    pods: Vec<KubePod>,
+    index: usize,
+  ) {
+    self.data.selected.pod_selector = Some(selector);
+    self.data.selected.pod_selector_ns = Some(namespace);
+    self.data.selected.pod_selector_resource = Some(resource_name);
+    self.data.pods.filter.clear();
+    self.data.pods.filtered_indices.clear();
+    self.data.pods.set_items(pods);
+    self.data.pods.state.select(Some(index));
+    self.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
+    let Some(pod) = self.data.pods.get_selected_item_copy() else {
+      return;
+    };
+    self.data.selected.pod = Some(pod.name.clone());
+    self.data.containers.set_items(pod.containers);
+    self.open_pod_logs(pod.name, RouteId::Home);
+  }
+
+  pub async fn dispatch_container_logs(&mut self, id: String, route_id: RouteId) {
+    self.cancel_log_stream();
+    self.log_previous = false;
+    self.data.logs = self.new_logs_state(id);
+    self.push_navigation_stack(route_id, ActiveBlock::Logs);
+    self.active_log_stream = Some(IoStreamEvent::GetPodLogs(true));
+    self.dispatch_stream(IoStreamEvent::GetPodLogs(true)).await;
+  }
+
+  /// View the previous (terminated) instance's logs for the selected container.
+  /// One-shot fetch — sets `log_previous` so the periodic poll does not replace
+  /// it with a live stream.
+  pub async fn dispatch_previous_logs(&mut self, id: String, route_id: RouteId) {
+    self.cancel_log_stream();
+    self.log_previous = true;
+    // Use the container name as the id (matching the live-logs view) so the log
+    // view's `container == logs.id` render guard passes; `log_previous` keeps
+    // the periodic poll from replacing it with a live stream.
+    self.data.logs = self.new_logs_state(id);
+    self.push_navigation_stack(route_id, ActiveBlock::Logs);
+    self.active_log_stream = Some(IoStreamEvent::GetPreviousLogs);
+    self.dispatch_stream(IoStreamEvent::GetPreviousLogs).await;
+  }
+
+  /// Start aggregate log streaming from all pods matching a label selector.
+  pub async fn dispatch_aggregate_logs(
     &mut self,
     name: String,
     namespace: String,
@@ -1337,7 +2500,7 @@ impl App {
     self.cancel_log_stream();
     self.log_previous = false;
     self.data.selected.pod_selector_resource = Some(resource_name);
-    self.data.logs = LogsState::new(format!("agg:{}", name));
+    self.data.logs = self.new_logs_state(format!("agg:{}", name));
     self.push_navigation_stack(route_id, ActiveBlock::Logs);
     let event = IoStreamEvent::GetAggregateLogs {
       namespace,
@@ -1356,7 +2519,9 @@ impl App {
     };
     self.cancel_log_stream();
     let id = self.data.logs.id.clone();
-    self.data.logs = LogsState::new(id);
+    let search = std::mem::take(&mut self.data.logs.search);
+    self.data.logs = self.new_logs_state(id);
+    self.data.logs.search = search;
     self.dispatch_stream(event).await;
   }
 
@@ -1364,6 +2529,63 @@ impl App {
     self.refresh = true;
   }
 
+  /// Switch between a selection that follows its resource when rows are
+  /// inserted or reordered and one that stays on its row index.
+  pub fn toggle_follow_selection(&mut self) {
+    self.follow_selection = !self.follow_selection;
+    self.set_status_message(if self.follow_selection {
+      "Selection follows the resource"
+    } else {
+      "Selection stays on its row"
+    });
+  }
+
+  /// Note a tick at `now` on the wall clock, which unlike `Instant` keeps
+  /// counting while the machine sleeps. Ticks come at most a second apart,
+  /// so a gap over [`SLEEP_GAP`] means a resume from sleep with stale
+  /// streams and client, and they are rebuilt as the refresh key does.
+  pub fn note_tick_time(&mut self, now: SystemTime) {
+    let slept = self
+      .last_tick_at
+      .and_then(|at| now.duration_since(at).ok())
+      .is_some_and(|gap| gap > SLEEP_GAP);
+    self.last_tick_at = Some(now);
+    if slept && !self.refresh {
+      info!("Resumed from sleep, reconnecting");
+      self.reconnecting_after_sleep = true;
+      self.refresh();
+    }
+  }
+
+  /// Forget the last tick, for when ticks stop on purpose, such as while a
+  /// shell or editor has the terminal.
+  pub fn reset_tick_time(&mut self) {
+    self.last_tick_at = None;
+  }
+
+  /// Tear down what still talks to the previous client before the network
+  /// and stream threads rebuild theirs for the selected context.
+  fn begin_reconnect(&mut self) {
+    self.cancel_log_stream();
+    self.stop_pod_watch();
+    self.stop_event_watch();
+    self.connecting_to = Some(context_label(&self.data.selected.context));
+  }
+
+  /// Toggle between the selected namespace and all namespaces, like `kubectl -A`.
+  /// Returns false when there is no namespace to toggle back to.
+  pub fn toggle_all_namespaces(&mut self) -> bool {
+    if let Some(ns) = self.data.selected.ns.take() {
+      self.data.selected.previous_ns = Some(ns);
+      true
+    } else if let Some(ns) = self.data.selected.previous_ns.take() {
+      self.data.selected.ns = Some(ns);
+      true
+    } else {
+      false
+    }
+  }
+
   pub fn restore_route_state(
     &mut self,
     main_tab_index: usize,
@@ -1376,6 +2598,79 @@ impl App {
     self.is_routing = true;
   }
 
+  /// Name of the open tab, e.g. `Pods` or `Utilization`. The Active Context
+  /// tab is named after the resource tab open inside it.
+  pub fn active_tab_name(&self) -> String {
+    let tab = match self.main_tabs.index {
+      0 => &self.context_tabs.items[self.context_tabs.index],
+      index => &self.main_tabs.items[index],
+    };
+    tab_name(&tab.title).to_owned()
+  }
+
+  /// Names accepted by `--view`, e.g. `pods` or `all-contexts`: the resource
+  /// tabs, then the main tabs after Active Context.
+  pub fn view_names(&self) -> Vec<String> {
+    self
+      .context_tabs
+      .items
+      .iter()
+      .chain(self.main_tabs.items.iter().skip(1))
+      .map(|tab| view_slug(tab_name(&tab.title)))
+      .collect()
+  }
+
+  /// Open the resource or main tab with this name, ignoring case and
+  /// accepting `-` for spaces. Returns false for unknown names.
+  pub fn select_tab_by_name(&mut self, name: &str) -> bool {
+    let name = view_slug(name);
+    let matches = |tab: &TabRoute| view_slug(tab_name(&tab.title)) == name;
+    if let Some(index) = self.context_tabs.items.iter().position(matches) {
+      self.main_tabs.set_index(0);
+      let route = self.context_tabs.set_index(index).route.clone();
+      self.set_route(route);
+    } else if let Some(index) = self.main_tabs.items.iter().skip(1).position(matches) {
+      let route = self.main_tabs.set_index(index + 1).route.clone();
+      self.set_route(route);
+    } else {
+      return false;
+    }
+    true
+  }
+
+  /// Open a resource view the way its tab, or its entry in the More menu,
+  /// would. Returns false for blocks that are neither.
+  pub fn route_to_resource_view(&mut self, block: ActiveBlock) -> bool {
+    let tab_index = (0..)
+      .map_while(Self::resource_block_for_context_tab)
+      .position(|tab_block| tab_block == block);
+    if let Some(index) = tab_index {
+      self.main_tabs.set_index(0);
+      let route = self.context_tabs.set_index(index).route.clone();
+      self.set_route(route);
+      return true;
+    }
+    let more_tab = self
+      .context_tabs
+      .items
+      .iter()
+      .position(|tab| tab.route.active_block == ActiveBlock::More);
+    let menu_index = self
+      .more_resources_menu
+      .items
+      .iter()
+      .position(|(_, menu_block)| *menu_block == block);
+    let (Some(more_tab), Some(menu_index)) = (more_tab, menu_index) else {
+      return false;
+    };
+    self.main_tabs.set_index(0);
+    let route = self.context_tabs.set_index(more_tab).route.clone();
+    self.set_route(route);
+    self.more_resources_menu.state.select(Some(menu_index));
+    self.push_navigation_stack(RouteId::Home, block);
+    true
+  }
+
   pub fn refresh_restore_route(&self) -> Route {
     match self.main_tabs.index {
       0 => self.context_tabs.get_active_route().clone(),
@@ -1411,14 +2706,19 @@ impl App {
       IoEvent::GetServiceAccounts,
       IoEvent::GetEvents,
       IoEvent::GetNetworkPolicies,
+      IoEvent::GetResourceQuotas,
+      IoEvent::GetLimitRanges,
+      IoEvent::GetPdbs,
     ]
   }
 
   fn active_home_cache_block(&self) -> ActiveBlock {
     match self.get_current_route().active_block {
-      ActiveBlock::Namespaces | ActiveBlock::Describe | ActiveBlock::Yaml | ActiveBlock::Logs => {
-        self.get_prev_route().active_block
-      }
+      ActiveBlock::Namespaces
+      | ActiveBlock::Describe
+      | ActiveBlock::Yaml
+      | ActiveBlock::Diff
+      | ActiveBlock::Logs => self.get_prev_route().active_block,
       active_block => active_block,
     }
   }
@@ -1447,6 +2747,10 @@ impl App {
       ActiveBlock::ServiceAccounts => Some(IoEvent::GetServiceAccounts),
       ActiveBlock::Events => Some(IoEvent::GetEvents),
       ActiveBlock::NetworkPolicies => Some(IoEvent::GetNetworkPolicies),
+      ActiveBlock::ResourceQuotas => Some(IoEvent::GetResourceQuotas),
+      ActiveBlock::LimitRanges => Some(IoEvent::GetLimitRanges),
+      ActiveBlock::PodDisruptionBudgets => Some(IoEvent::GetPdbs),
+      ActiveBlock::CustomResourceDefinitions => Some(IoEvent::GetCrds),
       _ => None,
     }
   }
@@ -1470,6 +2774,12 @@ impl App {
       {
         self.dispatch(IoEvent::GetTroubleshootFindings).await;
       }
+      RouteId::Dashboard => {
+        self.dispatch(IoEvent::GetPods).await;
+      }
+      RouteId::Watchlist if self.get_current_route().active_block == ActiveBlock::Watchlist => {
+        self.dispatch(IoEvent::GetWatchlist).await;
+      }
       _ => {}
     }
   }
@@ -1485,100 +2795,157 @@ impl App {
     };
 
     for event in Self::background_home_resource_events() {
-      if skip_home_event.as_ref() == Some(event) {
+      if skip_home_event.as_ref() == Some(event) || !self.prefetches(event) {
+        continue;
+      }
+      if *event == IoEvent::GetEvents && self.watched_events().is_some() {
         continue;
       }
       self.dispatch(event.clone()).await;
     }
 
-    if self.get_current_route().id != RouteId::Utilization {
+    if self.get_current_route().id != RouteId::Utilization && self.prefetches(&IoEvent::GetMetrics)
+    {
       self.dispatch(IoEvent::GetMetrics).await;
     }
   }
 
-  pub async fn dispatch_by_active_block(&mut self, active_block: ActiveBlock) {
-    match active_block {
+  /// The fetch behind a resource view, narrowed to the drilled-down workload
+  /// when there is one. `None` for views that are not a fetched list.
+  pub fn fetch_event(&self, active_block: ActiveBlock) -> Option<IoEvent> {
+    let event = match active_block {
       ActiveBlock::Pods | ActiveBlock::Containers => {
-        // If we're in a workload drill-down, refresh using the label selector
-        if let (Some(selector), Some(namespace)) = (
+        // If we're in a workload drill-down, refresh using the label selector;
+        // a node drill-down keeps its field selector
+        match (
           self.data.selected.pod_selector.clone(),
           self.data.selected.pod_selector_ns.clone(),
         ) {
-          self
-            .dispatch(IoEvent::GetPodsBySelector {
-              namespace,
-              selector,
-            })
-            .await;
-        } else {
-          self.dispatch(IoEvent::GetPods).await;
+          (Some(selector), Some(namespace)) => IoEvent::GetPodsBySelector {
+            namespace,
+            selector,
+          },
+          (Some(node_name), None)
+            if self.data.selected.pod_selector_resource.as_deref() == Some("node") =>
+          {
+            IoEvent::GetPodsByNode { node_name }
+          }
+          _ => IoEvent::GetPods,
         }
       }
-      ActiveBlock::Services => {
-        self.dispatch(IoEvent::GetServices).await;
-      }
-      ActiveBlock::ConfigMaps => {
-        self.dispatch(IoEvent::GetConfigMaps).await;
-      }
-      ActiveBlock::StatefulSets => {
-        self.dispatch(IoEvent::GetStatefulSets).await;
-      }
+      ActiveBlock::Services => IoEvent::GetServices,
+      ActiveBlock::ConfigMaps => IoEvent::GetConfigMaps,
+      ActiveBlock::StatefulSets => IoEvent::GetStatefulSets,
       ActiveBlock::ReplicaSets => {
-        self.dispatch(IoEvent::GetReplicaSets).await;
-      }
-      ActiveBlock::Deployments => {
-        self.dispatch(IoEvent::GetDeployments).await;
-      }
-      ActiveBlock::Jobs => {
-        self.dispatch(IoEvent::GetJobs).await;
-      }
-      ActiveBlock::DaemonSets => {
-        self.dispatch(IoEvent::GetDaemonSets).await;
-      }
-      ActiveBlock::CronJobs => {
-        self.dispatch(IoEvent::GetCronJobs).await;
-      }
-      ActiveBlock::Secrets => {
-        self.dispatch(IoEvent::GetSecrets).await;
-      }
-      ActiveBlock::ReplicationControllers => {
-        self.dispatch(IoEvent::GetReplicationControllers).await;
-      }
-      ActiveBlock::StorageClasses => {
-        self.dispatch(IoEvent::GetStorageClasses).await;
-      }
-      ActiveBlock::Roles => {
-        self.dispatch(IoEvent::GetRoles).await;
-      }
-      ActiveBlock::RoleBindings => {
-        self.dispatch(IoEvent::GetRoleBindings).await;
-      }
-      ActiveBlock::ClusterRoles => {
-        self.dispatch(IoEvent::GetClusterRoles).await;
+        // In a deployment drill-down, refresh only that deployment's ReplicaSets
+        match (
+          self.data.selected.replica_set_owner.clone(),
+          self.data.selected.replica_set_owner_ns.clone(),
+        ) {
+          (Some(owner), Some(namespace)) => IoEvent::GetReplicaSetsByOwner { namespace, owner },
+          _ => IoEvent::GetReplicaSets,
+        }
       }
-      ActiveBlock::ClusterRoleBindings => {
-        self.dispatch(IoEvent::GetClusterRoleBinding).await;
+      ActiveBlock::Deployments => IoEvent::GetDeployments,
+      ActiveBlock::Jobs => IoEvent::GetJobs,
+      ActiveBlock::DaemonSets => IoEvent::GetDaemonSets,
+      ActiveBlock::CronJobs => IoEvent::GetCronJobs,
+      ActiveBlock::Secrets => IoEvent::GetSecrets,
+      ActiveBlock::ReplicationControllers => IoEvent::GetReplicationControllers,
+      ActiveBlock::StorageClasses => IoEvent::GetStorageClasses,
+      ActiveBlock::Roles => IoEvent::GetRoles,
+      ActiveBlock::RoleBindings => IoEvent::GetRoleBindings,
+      ActiveBlock::ClusterRoles => IoEvent::GetClusterRoles,
+      ActiveBlock::ClusterRoleBindings => IoEvent::GetClusterRoleBinding,
+      ActiveBlock::Ingresses => IoEvent::GetIngress,
+      ActiveBlock::PersistentVolumeClaims => IoEvent::GetPvcs,
+      ActiveBlock::PersistentVolumes => IoEvent::GetPvs,
+      ActiveBlock::ServiceAccounts => IoEvent::GetServiceAccounts,
+      ActiveBlock::Events => IoEvent::GetEvents,
+      ActiveBlock::NetworkPolicies => IoEvent::GetNetworkPolicies,
+      ActiveBlock::ResourceQuotas => IoEvent::GetResourceQuotas,
+      ActiveBlock::LimitRanges => IoEvent::GetLimitRanges,
+      ActiveBlock::PodDisruptionBudgets => IoEvent::GetPdbs,
+      ActiveBlock::CustomResourceDefinitions => IoEvent::GetCrds,
+      ActiveBlock::ApiResources => IoEvent::GetApiResources,
+      ActiveBlock::PodsByNode => IoEvent::GetNodePodSummary,
+      ActiveBlock::DynamicResource => IoEvent::GetDynamicRes,
+      _ => return None,
+    };
+    Some(event)
+  }
+
+  pub async fn dispatch_by_active_block(&mut self, active_block: ActiveBlock) {
+    match active_block {
+      // Discovery rarely changes within a session; `F5` fetches it again.
+      ActiveBlock::ApiResources if !self.data.api_resources.items.is_empty() => {}
+      ActiveBlock::Logs if !self.is_streaming && !self.log_previous => {
+        self.dispatch_stream(IoStreamEvent::GetPodLogs(false)).await;
       }
-      ActiveBlock::Ingresses => {
-        self.dispatch(IoEvent::GetIngress).await;
+      _ => {
+        if let Some(event) = self.fetch_event(active_block) {
+          self.dispatch(event).await;
+        }
       }
-      ActiveBlock::PersistentVolumeClaims => {
-        self.dispatch(IoEvent::GetPvcs).await;
+    }
+  }
+
+  /// Fetch what the current route shows. Runs on every poll interval, and
+  /// with `force` from the refresh key, which also skips the wait between
+  /// context probes.
+  pub async fn poll_current_route(&mut self, force: bool) {
+    if self.pod_alerts.is_some() {
+      self.dispatch(IoEvent::CheckPodAlerts).await;
+    }
+    match self.get_current_route().id {
+      RouteId::Home => {
+        if self.data.clis.is_empty() {
+          self.dispatch_cmd(IoCmdEvent::GetCliInfo).await;
+        }
+        self.dispatch(IoEvent::GetNamespaces).await;
+        self.dispatch(IoEvent::GetNodes).await;
+
+        let mut active_block = self.get_current_route().active_block;
+        if active_block == ActiveBlock::Namespaces {
+          active_block = self.get_prev_route().active_block;
+        }
+        if force && active_block == ActiveBlock::ApiResources {
+          self.dispatch(IoEvent::GetApiResources).await;
+        } else if !force && active_block == ActiveBlock::Containers && self.watched_pod().is_some()
+        {
+          // the watch keeps the pod up to date without re-listing the namespace
+        } else if !force && active_block == ActiveBlock::Events && self.watched_events().is_some() {
+          // the watch adds events as they happen; only their ages move on
+          self.refresh_event_rows();
+        } else {
+          self.dispatch_by_active_block(active_block).await;
+        }
+        if active_block == ActiveBlock::Containers {
+          self.dispatch_pod_events().await;
+          self.dispatch_container_usage().await;
+        }
       }
-      ActiveBlock::PersistentVolumes => {
-        self.dispatch(IoEvent::GetPvs).await;
+      RouteId::Contexts if force || self.data.context_probes.is_due(Instant::now()) => {
+        self.data.context_probes.start_round(Instant::now());
+        self.dispatch(IoEvent::ProbeContexts).await;
       }
-      ActiveBlock::ServiceAccounts => {
-        self.dispatch(IoEvent::GetServiceAccounts).await;
+      RouteId::Utilization => {
+        self.dispatch(IoEvent::GetMetrics).await;
       }
-      ActiveBlock::Events => {
-        self.dispatch(IoEvent::GetEvents).await;
+      RouteId::Troubleshoot
+        if self.get_current_route().active_block == ActiveBlock::Troubleshoot =>
+      {
+        self.dispatch(IoEvent::GetTroubleshootFindings).await;
       }
-      ActiveBlock::DynamicResource => {
-        self.dispatch(IoEvent::GetDynamicRes).await;
+      // the dashboard only sums what the overview polls anyway; node metrics
+      // come with the nodes
+      RouteId::Dashboard => {
+        self.dispatch(IoEvent::GetNamespaces).await;
+        self.dispatch(IoEvent::GetNodes).await;
+        self.dispatch(IoEvent::GetPods).await;
       }
-      ActiveBlock::Logs if !self.is_streaming && !self.log_previous => {
-        self.dispatch_stream(IoStreamEvent::GetPodLogs(false)).await;
+      RouteId::Watchlist if self.get_current_route().active_block == ActiveBlock::Watchlist => {
+        self.dispatch(IoEvent::GetWatchlist).await;
       }
       _ => {}
     }
@@ -1591,10 +2958,12 @@ impl App {
     let mut did_refresh = false;
     if self.refresh {
       if !first_render {
+        self.begin_reconnect();
         self.dispatch(IoEvent::RefreshClient).await;
         self.dispatch_stream(IoStreamEvent::RefreshClient).await;
       }
       self.dispatch(IoEvent::GetKubeConfig).await;
+      self.dispatch(IoEvent::GetServerVersion).await;
       self.cache_essential_data().await;
       self.queue_background_resource_cache();
       self.refresh = false;
@@ -1607,44 +2976,41 @@ impl App {
     }
 
     // make network requests only in intervals to avoid hogging up the network
-    if self.tick_count.is_multiple_of(self.tick_until_poll) || self.is_routing {
-      // Safety-net kubeconfig reload (~60s) in case the file watcher misses an event
-      if self.tick_until_poll > 0
-        && self.tick_count > 0
-        && self.tick_count.is_multiple_of(self.tick_until_poll * 12)
-      {
-        self.dispatch(IoEvent::GetKubeConfig).await;
-      }
+    let global_poll_due =
+      !self.polling_paused && self.tick_count.is_multiple_of(self.tick_until_poll);
+    // a view with its own `poll_rates` entry is polled on that instead
+    let view = self.get_current_route().active_block;
+    let now = Instant::now();
+    let poll_due = match self.poll_schedule.is_due(view, now) {
+      Some(due) => !self.polling_paused && due,
+      None => global_poll_due,
+    };
+    if self.is_routing {
+      self.sync_pod_watch().await;
+    }
+    self.sync_event_watch().await;
+    // Safety-net kubeconfig reload (~60s) in case the file watcher misses an event
+    if global_poll_due
+      && self.tick_until_poll > 0
+      && self.tick_count > 0
+      && self.tick_count.is_multiple_of(self.tick_until_poll * 12)
+    {
+      self.dispatch(IoEvent::GetKubeConfig).await;
+    }
+    if poll_due || self.is_routing {
+      self.note_view_opened();
       // make periodic network calls based on active route and active block to avoid hogging
-      match self.get_current_route().id {
-        RouteId::Home => {
-          if self.data.clis.is_empty() {
-            self.dispatch_cmd(IoCmdEvent::GetCliInfo).await;
-          }
-          self.dispatch(IoEvent::GetNamespaces).await;
-          self.dispatch(IoEvent::GetNodes).await;
-
-          let active_block = self.get_current_route().active_block;
-          if active_block == ActiveBlock::Namespaces {
-            self
-              .dispatch_by_active_block(self.get_prev_route().active_block)
-              .await;
-          } else {
-            self.dispatch_by_active_block(active_block).await;
-          }
-        }
-        RouteId::Utilization => {
-          self.dispatch(IoEvent::GetMetrics).await;
-        }
-        RouteId::Troubleshoot
-          if self.get_current_route().active_block == ActiveBlock::Troubleshoot =>
-        {
-          self.dispatch(IoEvent::GetTroubleshootFindings).await;
-        }
-        _ => {}
-      }
+      self.poll_current_route(false).await;
+      self.poll_schedule.mark_polled(view, now);
       self.is_routing = false;
+    } else if !self.polling_paused && self.tick_count.is_multiple_of(ROLLOUT_POLL_TICKS) {
+      if self.rollout_in_progress() {
+        self.dispatch(IoEvent::GetDeployments).await;
+      } else if self.following_rollout() {
+        self.poll_followed_rollout().await;
+      }
     }
+    self.end_completed_rollout_follow();
 
     self.tick_count += 1;
   }
@@ -1733,6 +3099,7 @@ mod tests {
     // test first render — essential data loads immediately, background cache is deferred
     app.on_tick(true).await;
     assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetKubeConfig);
+    assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetServerVersion);
     assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetNamespaces);
     assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetNodes);
     assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetPods);
@@ -1749,6 +3116,23 @@ mod tests {
     assert_eq!(app.tick_count, 1);
   }
 
+  #[test]
+  fn test_highlight_changes_window_from_config() {
+    let mut app = App::default();
+    assert_eq!(app.highlight_changes(), None);
+    assert_eq!(app.view_load(ActiveBlock::Pods).highlight_changes, None);
+
+    app.config.highlight_changes = true;
+    assert_eq!(app.highlight_changes(), Some(chrono::Duration::seconds(30)));
+    app.config.highlight_changes_secs = Some(90);
+    assert_eq!(
+      app.view_load(ActiveBlock::Pods).highlight_changes,
+      Some(chrono::Duration::seconds(90))
+    );
+    app.config.highlight_changes_secs = Some(0);
+    assert_eq!(app.highlight_changes(), None);
+  }
+
   #[test]
   fn test_handle_error_preserves_only_last_100_errors() {
     let mut app = App::default();
@@ -1800,6 +3184,268 @@ mod tests {
     assert!(app.status_message.is_empty());
   }
 
+  #[test]
+  fn test_needs_redraw_only_after_a_change_or_while_loading() {
+    let mut app = App::default();
+    app
+      .data
+      .load_states
+      .insert(ActiveBlock::Pods, LoadState::Loaded);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
+    let mut drawn = app.revision();
+    assert!(!app.needs_redraw(drawn));
+
+    app.add_log_records(vec!["line".into()]);
+    assert!(app.needs_redraw(drawn));
+
+    drawn = app.revision();
+    app.loading_counter = 1;
+    assert!(app.needs_redraw(drawn));
+    app.loading_complete();
+    assert!(app.needs_redraw(drawn));
+    assert!(!app.needs_redraw(app.revision()));
+
+    // an expiring toast is a change, a tick with nothing to clear is not
+    let now = Instant::now();
+    app.status_message.show_at("Copied", now);
+    drawn = app.revision();
+    app.clear_expired_status_message(now);
+    assert!(!app.needs_redraw(drawn));
+    app.clear_expired_status_message(now + app.status_message.duration);
+    assert!(app.needs_redraw(drawn));
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_skips_polling_while_paused() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let mut app = App {
+      tick_until_poll: 1,
+      refresh: false,
+      polling_paused: true,
+      io_tx: Some(sync_io_tx),
+      ..App::default()
+    };
+    app.route_utilization();
+    app.on_tick(false).await;
+    assert_eq!(
+      sync_io_rx.try_recv().unwrap(),
+      IoEvent::GetMetrics,
+      "navigating still fetches the new view"
+    );
+
+    app.on_tick(false).await;
+    assert!(sync_io_rx.try_recv().is_err());
+
+    app.polling_paused = false;
+    app.on_tick(false).await;
+    assert_eq!(sync_io_rx.try_recv().unwrap(), IoEvent::GetMetrics);
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_follows_a_progressing_rollout_between_polls() {
+    use k8s_openapi::api::apps::v1::Deployment;
+
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let mut app = App {
+      tick_until_poll: 100,
+      tick_count: ROLLOUT_POLL_TICKS,
+      refresh: false,
+      io_tx: Some(sync_io_tx),
+      ..App::default()
+    };
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
+    app.is_routing = false;
+    let (_, mut deployments): (Vec<deployments::KubeDeployment>, Vec<Deployment>) =
+      test_utils::convert_resource_from_file("deployments");
+    app
+      .data
+      .deployments
+      .set_items(vec![deployments[0].clone().into()]);
+
+    // A finished rollout waits for the regular poll
+    app.on_tick(false).await;
+    assert!(sync_io_rx.try_recv().is_err());
+
+    deployments[0].metadata.generation = Some(2);
+    app
+      .data
+      .deployments
+      .set_items(vec![deployments[0].clone().into()]);
+    app.tick_count = ROLLOUT_POLL_TICKS * 2;
+    app.on_tick(false).await;
+    assert_eq!(sync_io_rx.try_recv().unwrap(), IoEvent::GetDeployments);
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_probes_contexts_once_per_interval() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let mut app = App {
+      tick_until_poll: 1,
+      refresh: false,
+      io_tx: Some(sync_io_tx),
+      ..App::default()
+    };
+    app.route_contexts();
+
+    app.on_tick(false).await;
+    assert_eq!(sync_io_rx.try_recv().unwrap(), IoEvent::ProbeContexts);
+
+    // The next poll comes well within the probe interval.
+    app.on_tick(false).await;
+    assert!(sync_io_rx.try_recv().is_err());
+  }
+
+  #[test]
+  fn test_select_tab_by_name_opens_resource_and_main_tabs() {
+    let mut app = App::default();
+    let names = app.view_names();
+    assert_eq!(names.first().map(String::as_str), Some("pods"));
+    assert!(names.contains(&"all-contexts".to_owned()));
+    assert!(!names.contains(&"active-context".to_owned()));
+
+    assert!(app.select_tab_by_name("ConfigMaps"));
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::ConfigMaps
+    );
+    assert_eq!(app.active_tab_name(), "ConfigMaps");
+
+    assert!(app.select_tab_by_name("all-contexts"));
+    assert_eq!(app.get_current_route().id, RouteId::Contexts);
+    assert_eq!(app.active_tab_name(), "All Contexts");
+
+    assert!(!app.select_tab_by_name("nope"));
+    assert_eq!(app.get_current_route().id, RouteId::Contexts);
+  }
+
+  #[tokio::test]
+  async fn test_pod_watch_follows_the_containers_view() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(50);
+    let (sync_io_stream_tx, mut sync_io_stream_rx) = mpsc::channel::<IoStreamEvent>(50);
+    let mut app = App {
+      io_tx: Some(sync_io_tx),
+      io_stream_tx: Some(sync_io_stream_tx),
+      tick_until_poll: 1,
+      refresh: false,
+      ..App::default()
+    };
+    let mut pod = KubePod::default();
+    pod.name = "web".into();
+    pod.namespace = "shop".into();
+    app.data.pods.set_items(vec![pod.clone()]);
+    app.data.selected.pod = Some("web".into());
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
+
+    app.on_tick(false).await;
+    let watched = WatchedPod {
+      namespace: "shop".into(),
+      name: "web".into(),
+    };
+    assert_eq!(app.watched_pod(), Some(watched.clone()));
+    assert_eq!(
+      sync_io_stream_rx.try_recv().unwrap(),
+      IoStreamEvent::WatchPod {
+        namespace: "shop".into(),
+        name: "web".into(),
+      }
+    );
+    let rx = app.pod_watch_rx();
+
+    // While watched, a regular poll leaves the pods to the watch.
+    while sync_io_rx.try_recv().is_ok() {}
+    app.on_tick(false).await;
+    let polled: Vec<IoEvent> = std::iter::from_fn(|| sync_io_rx.try_recv().ok()).collect();
+    assert!(!polled.contains(&IoEvent::GetPods));
+    assert!(
+      polled.contains(&IoEvent::GetPodEvents {
+        name: "web".into(),
+        namespace: "shop".into(),
+      }),
+      "the pod's events are kept fresh"
+    );
+    assert!(
+      polled.contains(&IoEvent::GetContainerUsage {
+        name: "web".into(),
+        namespace: "shop".into(),
+      }),
+      "and so is its containers' usage"
+    );
+
+    // Updates land in the pods list and the containers view.
+    pod.status = "Running".into();
+    pod.containers = vec![KubeContainer::default()];
+    app.update_watched_pod(pod);
+    assert_eq!(app.data.pods.items[0].status, "Running");
+    assert_eq!(app.data.containers.items.len(), 1);
+
+    // Leaving the view ends the watch.
+    app.pop_navigation_stack();
+    app.on_tick(false).await;
+    assert_eq!(app.watched_pod(), None);
+    assert_eq!(*rx.borrow(), None);
+    assert!(sync_io_stream_rx.try_recv().is_err());
+  }
+
+  #[tokio::test]
+  async fn test_on_tick_polls_a_view_at_its_own_rate() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(50);
+    let config = crate::config::KdashConfig {
+      poll_rates: [("events".to_owned(), 60_000)].into(),
+      ..Default::default()
+    };
+    let mut app = App {
+      io_tx: Some(sync_io_tx),
+      tick_until_poll: 4,
+      tick_count: 1,
+      refresh: false,
+      poll_schedule: PollSchedule::from_config(&config),
+      ..App::default()
+    };
+    app.route_to_resource_view(ActiveBlock::Events);
+    app.is_routing = false;
+    let polled = |rx: &mut mpsc::Receiver<IoEvent>| {
+      std::iter::from_fn(|| rx.try_recv().ok()).collect::<Vec<_>>()
+    };
+
+    // Due straight away although the global poll is not.
+    app.on_tick(false).await;
+    assert!(polled(&mut sync_io_rx).contains(&IoEvent::GetEvents));
+
+    // The global poll no longer fetches it until its own rate has passed.
+    app.tick_count = 4;
+    app.on_tick(false).await;
+    assert!(polled(&mut sync_io_rx).is_empty());
+
+    // Views without a rate keep the global one.
+    app.route_to_resource_view(ActiveBlock::Pods);
+    app.is_routing = false;
+    app.tick_count = 8;
+    app.on_tick(false).await;
+    assert!(polled(&mut sync_io_rx).contains(&IoEvent::GetPods));
+  }
+
+  #[tokio::test]
+  async fn test_begin_shutdown_closes_the_task_channels() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let (sync_io_stream_tx, mut sync_io_stream_rx) = mpsc::channel::<IoStreamEvent>(10);
+    let (sync_io_cmd_tx, mut sync_io_cmd_rx) = mpsc::channel::<IoCmdEvent>(10);
+    let mut app = App {
+      io_tx: Some(sync_io_tx),
+      io_stream_tx: Some(sync_io_stream_tx),
+      io_cmd_tx: Some(sync_io_cmd_tx),
+      ..App::default()
+    };
+
+    app.begin_shutdown();
+    app.dispatch(IoEvent::GetNamespaces).await;
+
+    // Once the senders are gone the task loops see `None` and exit.
+    assert_eq!(sync_io_rx.recv().await, None);
+    assert_eq!(sync_io_stream_rx.recv().await, None);
+    assert_eq!(sync_io_cmd_rx.recv().await, None);
+  }
+
   #[tokio::test]
   async fn test_on_tick_refresh_tick_limit() {
     let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(500);
@@ -1821,6 +3467,7 @@ mod tests {
     app.on_tick(false).await;
     assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::RefreshClient);
     assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetKubeConfig);
+    assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetServerVersion);
     assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetNamespaces);
     assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetNodes);
     assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetPods);
@@ -1835,11 +3482,46 @@ mod tests {
     assert_eq!(sync_io_cmd_rx.recv().await.unwrap(), IoCmdEvent::GetCliInfo);
 
     assert!(!app.refresh);
+    assert_eq!(app.connecting_to.as_deref(), Some("the current context"));
     assert!(app.background_cache_pending);
     assert!(!app.is_routing);
     assert_eq!(app.tick_count, 3);
   }
 
+  #[tokio::test]
+  async fn test_a_long_gap_between_ticks_reconnects() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(500);
+    let mut app = App {
+      io_tx: Some(sync_io_tx),
+      refresh: false,
+      ..App::default()
+    };
+    let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+    app.note_tick_time(start);
+    app.note_tick_time(start + Duration::from_secs(1));
+    assert!(!app.refresh);
+
+    // a shell had the terminal: no ticks on purpose
+    app.reset_tick_time();
+    app.note_tick_time(start + Duration::from_secs(600));
+    assert!(!app.refresh);
+
+    app.note_tick_time(start + Duration::from_secs(1200));
+    assert!(app.refresh);
+    assert!(app.reconnecting_after_sleep);
+    app.on_tick(false).await;
+    assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::RefreshClient);
+    assert!(app.connecting_to.is_some());
+
+    // a clock set back is not a sleep
+    app.refresh = false;
+    app.note_tick_time(start);
+    assert!(!app.refresh);
+
+    app.reset();
+    assert!(!app.reconnecting_after_sleep);
+  }
+
   #[tokio::test]
   async fn test_on_tick_dispatches_background_cache_on_followup_tick() {
     let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(500);
@@ -1849,6 +3531,7 @@ mod tests {
       tick_count: 1,
       refresh: false,
       background_cache_pending: true,
+      eager_load: true,
       io_tx: Some(sync_io_tx),
       ..App::default()
     };
@@ -1892,11 +3575,45 @@ mod tests {
       sync_io_rx.recv().await.unwrap(),
       IoEvent::GetNetworkPolicies
     );
+    assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetResourceQuotas);
+    assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetLimitRanges);
+    assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetPdbs);
     assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetMetrics);
 
     assert!(!app.background_cache_pending);
     assert_eq!(app.tick_count, 2);
   }
+
+  #[tokio::test]
+  async fn test_background_cache_fetches_opened_and_prewarmed_views_only() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(500);
+
+    let mut app = App {
+      tick_until_poll: 5,
+      tick_count: 1,
+      refresh: false,
+      background_cache_pending: true,
+      io_tx: Some(sync_io_tx),
+      opened_views: HashSet::from([ActiveBlock::Deployments]),
+      ..App::default()
+    };
+
+    app.on_tick(false).await;
+
+    assert_eq!(
+      sync_io_rx.recv().await.unwrap(),
+      IoEvent::DiscoverDynamicRes
+    );
+    assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetDeployments);
+    // events are pre-warmed when `prewarm` is unset
+    assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetEvents);
+    assert!(sync_io_rx.try_recv().is_err());
+
+    // a view is kept fresh once it has been polled
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Secrets);
+    app.note_view_opened();
+    assert!(app.opened_views.contains(&ActiveBlock::Secrets));
+  }
   #[tokio::test]
   async fn test_on_tick_routing() {
     let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(500);
@@ -2042,6 +3759,10 @@ mod tests {
     assert_eq!(app.main_tabs.index, 3);
     assert_eq!(app.get_current_route().id, RouteId::Troubleshoot);
 
+    app.route_dashboard();
+    assert_eq!(app.main_tabs.index, 4);
+    assert_eq!(app.get_current_route().id, RouteId::Dashboard);
+
     app.route_home();
     assert_eq!(app.main_tabs.index, 0);
     assert_eq!(app.get_current_route().id, RouteId::Home);
@@ -2160,6 +3881,60 @@ mod tests {
     assert!(!app.is_loading());
   }
 
+  #[test]
+  fn test_finish_load_tracks_each_view() {
+    let mut app = App::default();
+    assert_eq!(app.view_load(ActiveBlock::Pods).state, LoadState::Loaded);
+
+    app.finish_load(IoEvent::GetPods, app.errors_recorded());
+    assert_eq!(app.view_load(ActiveBlock::Pods).state, LoadState::Empty);
+
+    app.data.node_pods.set_items(vec![KubeNodePods::default()]);
+    app.finish_load(IoEvent::GetNodePodSummary, app.errors_recorded());
+    assert_eq!(
+      app.view_load(ActiveBlock::PodsByNode).state,
+      LoadState::Loaded
+    );
+
+    let errors_before = app.errors_recorded();
+    app.handle_error(anyhow!("Failed to get deployments. forbidden"));
+    app.finish_load(IoEvent::GetDeployments, errors_before);
+    assert_eq!(
+      app.view_load(ActiveBlock::Deployments).state,
+      LoadState::Error("Failed to get deployments. forbidden".into())
+    );
+    assert_eq!(
+      app.data.failed_events.get(&ActiveBlock::Deployments),
+      Some(&IoEvent::GetDeployments)
+    );
+    // A later successful fetch forgets the failure.
+    app.finish_load(IoEvent::GetDeployments, app.errors_recorded());
+    assert!(app.data.failed_events.is_empty());
+    assert_eq!(
+      app.view_load(ActiveBlock::Deployments).state,
+      LoadState::Empty
+    );
+    // Other views keep their own state.
+    assert_eq!(app.view_load(ActiveBlock::Pods).state, LoadState::Empty);
+
+    app.tick_count = 7;
+    app.loading_counter = 1;
+    assert_eq!(
+      app.view_load(ActiveBlock::Jobs),
+      ViewLoad {
+        state: LoadState::Loading,
+        tick: 7,
+        has_more: false,
+        highlight_changes: None,
+        problems_only: false,
+        follow_selection: true,
+      }
+    );
+
+    app.reset();
+    assert!(app.data.load_states.is_empty());
+  }
+
   #[test]
   fn test_new_honors_hide_info_on_start_config() {
     let (io_tx, _io_rx) = mpsc::channel::<IoEvent>(1);
@@ -2175,6 +3950,22 @@ mod tests {
     assert!(!app.show_info_bar);
   }
 
+  #[test]
+  fn test_new_honors_start_on_dashboard_config() {
+    let (io_tx, _io_rx) = mpsc::channel::<IoEvent>(1);
+    let (io_stream_tx, _stream_rx) = mpsc::channel::<IoStreamEvent>(1);
+    let (io_cmd_tx, _cmd_rx) = mpsc::channel::<IoCmdEvent>(1);
+
+    let config = KdashConfig {
+      start_on_dashboard: true,
+      ..KdashConfig::default()
+    };
+    let app = App::new(io_tx, io_stream_tx, io_cmd_tx, false, 1, 100, config);
+
+    assert_eq!(app.get_current_route().id, RouteId::Dashboard);
+    assert_eq!(app.main_tabs.index, 4);
+  }
+
   #[test]
   fn test_new_defaults_show_info_bar_to_true() {
     let (io_tx, _io_rx) = mpsc::channel::<IoEvent>(1);
@@ -2234,6 +4025,36 @@ mod tests {
     assert_eq!(app.theme, ThemeName::Macchiato);
   }
 
+  #[test]
+  fn test_apply_config_follows_a_changed_default_theme_only() {
+    let mut app = App::default();
+    app.cycle_theme();
+    let picked = app.theme;
+
+    app.apply_config(KdashConfig {
+      log_tail_lines: Some(50),
+      ..KdashConfig::default()
+    });
+    assert_eq!(app.theme, picked);
+    assert_eq!(app.config.log_tail_lines, Some(50));
+
+    app.apply_config(KdashConfig {
+      default_theme: Some("custom".into()),
+      custom_theme: Some(crate::ui::theme::CustomThemeConfig::default()),
+      ..KdashConfig::default()
+    });
+    assert_eq!(app.theme, ThemeName::Custom);
+    assert_eq!(app.palette.name, ThemeName::Custom);
+
+    // dropping the custom theme leaves it for the default
+    app.apply_config(KdashConfig {
+      default_theme: Some("custom".into()),
+      ..KdashConfig::default()
+    });
+    assert_eq!(app.theme, ThemeName::Macchiato);
+    assert!(app.custom_palette.is_none());
+  }
+
   #[test]
   fn test_new_honors_default_theme_config() {
     let (io_tx, _io_rx) = mpsc::channel::<IoEvent>(1);
@@ -2248,7 +4069,7 @@ mod tests {
 
     assert_eq!(app.theme, ThemeName::GruvboxDark);
     assert_eq!(app.palette.name, ThemeName::GruvboxDark);
-    assert!(app.is_dark());
+    assert!(app.palette.is_dark);
   }
 
   #[test]
@@ -2346,6 +4167,43 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_open_representative_pod_logs_drills_down_under_the_logs() {
+    let mut app = App::default();
+    app.route_home();
+    app.data.pods.filter = "stale".into();
+    let pods = ["web-1", "web-2"]
+      .into_iter()
+      .map(|name| {
+        let mut pod = KubePod::default();
+        pod.name = name.into();
+        pod.namespace = "team-a".into();
+        pod
+      })
+      .collect();
+
+    app.open_representative_pod_logs(
+      "team-a".into(),
+      "app=web".into(),
+      "deployment".into(),
+      pods,
+      1,
+    );
+
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Logs);
+    assert_eq!(app.data.selected.pod.as_deref(), Some("web-2"));
+    assert_eq!(app.data.logs.id, "agg:web-2");
+    assert_eq!(
+      app.active_log_stream,
+      Some(IoStreamEvent::GetPodAllContainerLogs)
+    );
+    assert!(app.data.pods.filter.is_empty());
+
+    app.pop_navigation_stack();
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
+    assert_eq!(app.data.selected.pod_selector.as_deref(), Some("app=web"));
+  }
+
   #[tokio::test]
   async fn test_dispatch_without_sender_does_not_set_loading() {
     let mut app = App::default();
@@ -2477,6 +4335,27 @@ mod tests {
     );
   }
 
+  #[tokio::test]
+  async fn test_dispatch_by_active_block_keeps_node_drilldown() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(16);
+
+    let mut app = App {
+      io_tx: Some(sync_io_tx),
+      ..App::default()
+    };
+    app.data.selected.pod_selector = Some("node-a".into());
+    app.data.selected.pod_selector_resource = Some("node".into());
+
+    app.dispatch_by_active_block(ActiveBlock::Pods).await;
+
+    assert_eq!(
+      sync_io_rx.recv().await.unwrap(),
+      IoEvent::GetPodsByNode {
+        node_name: "node-a".into(),
+      }
+    );
+  }
+
   #[tokio::test]
   async fn test_dispatch_by_active_block_logs_dispatches_only_when_not_streaming() {
     let (sync_io_stream_tx, mut sync_io_stream_rx) = mpsc::channel::<IoStreamEvent>(16);
@@ -2629,6 +4508,119 @@ mod tests {
     assert!(!app.show_port_forwards);
   }
 
+  #[test]
+  fn test_export_is_requested_then_written_after_draw() {
+    use super::export::TableText;
+
+    let mut app = App::default();
+    app.route_home();
+    app.request_export(ExportFormat::Csv);
+    assert_eq!(app.status_message.text(), "Nothing to export in this view");
+
+    app.data.pods.set_items(vec![KubePod::default()]);
+    app.request_export(ExportFormat::Csv);
+    assert_eq!(
+      app.data.pods.export,
+      TableExport::Requested(ExportFormat::Csv)
+    );
+
+    // The draw fills in the rows shown.
+    app.data.pods.export = TableExport::Ready(
+      ExportFormat::Csv,
+      TableText {
+        headers: vec!["Name".into()],
+        rows: vec![vec!["web".into()]],
+      },
+    );
+    let dir = std::env::temp_dir().join(format!("kdash-app-export-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    app.write_pending_export(&dir);
+    assert!(app.status_message.text().starts_with(&format!(
+      "Exported 1 row to {}",
+      dir.join("kdash-pods-").display()
+    )));
+    assert_eq!(app.data.pods.export, TableExport::Idle);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    // Write failures are reported instead of lost.
+    app.data.pods.export = TableExport::Ready(ExportFormat::Json, TableText::default());
+    app.write_pending_export(&dir);
+    assert!(app.api_error.starts_with("Failed to write export to"));
+  }
+
+  #[test]
+  fn test_save_logs_writes_markers_with_the_lines() {
+    use chrono::TimeZone;
+
+    let mut app = App::default();
+    let now = chrono::Local
+      .with_ymd_and_hms(2026, 3, 2, 14, 3, 22)
+      .unwrap()
+      .fixed_offset();
+    let dir = std::env::temp_dir().join(format!("kdash-save-logs-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    app.save_logs(&dir, now);
+    assert_eq!(app.status_message.text(), "No log lines to save");
+
+    app.data.logs = LogsState::new("shop:web-1:app".into());
+    app.data.logs.add_records(vec!["started".into()]);
+    app.mark_logs("deploy", now);
+    assert_eq!(app.status_message.text(), "Marked the logs at 14:03:22");
+    app.save_logs(&dir, now);
+
+    let path = dir.join("kdash-logs-shop-web-1-app-20260302-140322.log");
+    assert_eq!(
+      app.status_message.text(),
+      format!("Saved 2 lines, 1 marker to {}", path.display())
+    );
+    assert_eq!(
+      std::fs::read_to_string(&path).unwrap(),
+      "started\n--- MARK 14:03:22: deploy ---\n"
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn test_request_row_copy_needs_a_row() {
+    let mut app = App::default();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
+    app.request_row_copy();
+    assert_eq!(app.status_message.text(), "Nothing to copy in this view");
+
+    app.data.pods.set_items(vec![KubePod::default()]);
+    app.request_row_copy();
+    assert_eq!(app.data.pods.export, TableExport::RowRequested);
+
+    // The draw found no selected row.
+    app.data.pods.export = TableExport::RowReady(TableText::default());
+    app.write_pending_export(Path::new("."));
+    assert_eq!(app.status_message.text(), "No row selected to copy");
+    assert_eq!(app.data.pods.export, TableExport::Idle);
+  }
+
+  #[tokio::test]
+  async fn test_restart_port_forward_only_restarts_failed_forwards() {
+    let mut app = App::default();
+    push_forward(&mut app, 0, 8080, PortForwardStatus::Active);
+    push_forward(&mut app, 1, 9090, PortForwardStatus::Failed("boom".into()));
+
+    app.restart_port_forward(0).await;
+    assert_eq!(app.port_forwards.len(), 2);
+    assert_eq!(
+      app.status_message.text(),
+      "Only a failed port-forward can be restarted"
+    );
+
+    // The dead entry makes way for the new forward the stream task spawns.
+    app.restart_port_forward(1).await;
+    assert_eq!(app.port_forwards.len(), 1);
+    assert_eq!(app.port_forwards[0].id, 0);
+    assert_eq!(
+      app.status_message.text(),
+      "Starting port-forward pods/web-1 9090:80"
+    );
+  }
+
   #[test]
   fn test_set_port_forward_status_updates_matching_id_only() {
     let mut app = App::default();
@@ -1,17 +1,24 @@
-use std::collections::VecDeque;
+use std::{borrow::Cow, collections::VecDeque};
 
 use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, Utc};
+use kube::api::ObjectMeta;
 use ratatui::{
-  layout::Rect,
+  layout::{Position, Rect},
   style::{Modifier, Style},
   text::{Line as RatatuiLine, Span},
   widgets::{Block, List, ListItem, ListState, TableState},
   Frame,
 };
 use serde::Serialize;
+use syntect::{highlighting::HighlightState, parsing::ParseState};
 
-use super::{ActiveBlock, App, Route};
-use crate::{handlers::ScrollEvent, network::Network};
+use super::{
+  export::{ExportFormat, TableExport},
+  redact::Redactor,
+  utils, ActiveBlock, App, Route,
+};
+use crate::{handlers::ScrollEvent, network::Network, ui::theme::ThemeName};
 
 #[async_trait]
 pub trait AppResource {
@@ -39,6 +46,50 @@ pub fn labels_to_selector(labels: &std::collections::BTreeMap<String, String>) -
 /// Minimal trait for types that expose a resource name (used by filtering/display).
 pub trait Named {
   fn get_name(&self) -> &String;
+
+  /// Namespace of a namespaced resource; `None` for cluster-scoped rows.
+  fn get_namespace(&self) -> Option<&str> {
+    None
+  }
+
+  /// Metadata of the kubernetes object behind the row; `None` for rows that
+  /// are not objects.
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    None
+  }
+
+  /// Exact creation time in RFC 3339, shown for the selected row next to
+  /// the compact age column.
+  fn get_created(&self) -> Option<String> {
+    utils::to_timestamp(self.metadata()?.creation_timestamp.as_ref())
+  }
+
+  /// When the object was created or last changed state, whichever is later;
+  /// recent changes are highlighted when `highlight_changes` is on.
+  fn get_changed(&self) -> Option<DateTime<Utc>> {
+    utils::latest_time(&self.metadata()?.creation_timestamp)
+  }
+}
+
+/// A row picked for a batch action, or the selected one, by name and
+/// namespace so it survives the list being re-polled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarkedRow {
+  pub name: String,
+  pub namespace: Option<String>,
+}
+
+impl MarkedRow {
+  pub fn of<T: Named>(item: &T) -> Self {
+    MarkedRow {
+      name: item.get_name().clone(),
+      namespace: item.get_namespace().map(str::to_owned),
+    }
+  }
+
+  pub fn matches<T: Named>(&self, item: &T) -> bool {
+    self.name == *item.get_name() && self.namespace.as_deref() == item.get_namespace()
+  }
 }
 
 pub trait KubeResource<T: Serialize>: Named {
@@ -51,6 +102,11 @@ pub trait KubeResource<T: Serialize>: Named {
       Err(_) => "".into(),
     }
   }
+
+  /// `metadata.resourceVersion` of the original kubernetes resource
+  fn resource_version(&self) -> Option<String> {
+    self.metadata()?.resource_version.clone()
+  }
 }
 
 pub trait Scrollable {
@@ -145,12 +201,47 @@ pub struct StatefulTable<T> {
   /// When a filter is active, maps visible row index → `items` index.
   /// Empty when no filter is applied.
   pub filtered_indices: Vec<usize>,
+  /// Healthy rows were left out of `filtered_indices` on the last draw, so
+  /// it applies even when empty.
+  pub problems_only: bool,
+  /// Rows selected with `Space` for a batch action.
+  pub marked: Vec<MarkedRow>,
+  /// The row selected when the table was last drawn, so the selection can
+  /// follow it after a refresh reorders the rows.
+  pub selected_row: Option<MarkedRow>,
+  /// `items` were replaced since the table was last drawn.
+  pub items_replaced: bool,
+  pub column_scroll: ColumnScroll,
+  /// A CSV/JSON export waiting for the next draw to collect the shown rows.
+  pub export: TableExport,
+}
+
+/// Columns scrolled off the left edge of a table with `<` / `>`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ColumnScroll {
+  pub offset: usize,
+  /// Keep the `Name` column in place while the others scroll.
+  pub pin_name: bool,
 }
 
 pub trait FilterableTable {
   fn is_filter_active(&self) -> bool;
   fn count_label(&self) -> String;
+  fn is_empty(&self) -> bool;
   fn filter_parts_mut(&mut self) -> (&mut String, &mut bool, &mut TableState);
+  fn marked(&self) -> &[MarkedRow];
+  /// Add the row to the selection, or drop it if it is already selected.
+  fn toggle_marked(&mut self, row: MarkedRow);
+  fn clear_marked(&mut self);
+  fn column_scroll_mut(&mut self) -> &mut ColumnScroll;
+  /// Rows shown after the selected one, counting only filter matches.
+  fn rows_below_selection(&self) -> usize;
+  /// Ask the next draw to collect the shown rows for an export.
+  fn request_export(&mut self, format: ExportFormat);
+  /// Ask the next draw to collect the selected row for the clipboard.
+  fn request_row_copy(&mut self);
+  /// The export state, leaving the table idle.
+  fn take_export(&mut self) -> TableExport;
 }
 
 impl<T> StatefulTable<T> {
@@ -161,6 +252,12 @@ impl<T> StatefulTable<T> {
       filter: String::new(),
       filter_active: false,
       filtered_indices: Vec::new(),
+      problems_only: false,
+      marked: Vec::new(),
+      selected_row: None,
+      items_replaced: false,
+      column_scroll: ColumnScroll::default(),
+      export: TableExport::Idle,
     }
   }
 
@@ -174,9 +271,13 @@ impl<T> StatefulTable<T> {
     table
   }
 
+  /// Replace the rows, keeping the selected index. Resource tables then move
+  /// the selection back onto the resource it was on when they are drawn,
+  /// unless that is turned off.
   pub fn set_items(&mut self, items: Vec<T>) {
     let item_len = items.len();
     self.items = items;
+    self.items_replaced = true;
     if !self.items.is_empty() {
       let i = self.state.selected().map_or(0, |i| {
         if i > 0 && i < item_len {
@@ -198,16 +299,62 @@ impl<T> FilterableTable for StatefulTable<T> {
   }
 
   fn count_label(&self) -> String {
-    if self.filter.is_empty() {
+    if self.filter.is_empty() && !self.problems_only {
       self.items.len().to_string()
     } else {
       format!("{}/{}", self.filtered_indices.len(), self.items.len())
     }
   }
 
+  fn is_empty(&self) -> bool {
+    self.items.is_empty()
+  }
+
   fn filter_parts_mut(&mut self) -> (&mut String, &mut bool, &mut TableState) {
     (&mut self.filter, &mut self.filter_active, &mut self.state)
   }
+
+  fn marked(&self) -> &[MarkedRow] {
+    &self.marked
+  }
+
+  fn toggle_marked(&mut self, row: MarkedRow) {
+    match self.marked.iter().position(|marked| *marked == row) {
+      Some(i) => {
+        self.marked.remove(i);
+      }
+      None => self.marked.push(row),
+    }
+  }
+
+  fn clear_marked(&mut self) {
+    self.marked.clear();
+  }
+
+  fn column_scroll_mut(&mut self) -> &mut ColumnScroll {
+    &mut self.column_scroll
+  }
+
+  fn rows_below_selection(&self) -> usize {
+    let shown = if self.filter.is_empty() && !self.problems_only {
+      self.items.len()
+    } else {
+      self.filtered_indices.len()
+    };
+    shown.saturating_sub(self.state.selected().map_or(0, |i| i + 1))
+  }
+
+  fn request_export(&mut self, format: ExportFormat) {
+    self.export = TableExport::Requested(format);
+  }
+
+  fn request_row_copy(&mut self) {
+    self.export = TableExport::RowRequested;
+  }
+
+  fn take_export(&mut self) -> TableExport {
+    std::mem::take(&mut self.export)
+  }
 }
 
 impl<T> Scrollable for StatefulTable<T> {
@@ -215,7 +362,7 @@ impl<T> Scrollable for StatefulTable<T> {
     self.state.selected()
   }
   fn length(&self) -> usize {
-    if self.filter_active {
+    if self.filter_active || self.problems_only {
       self.filtered_indices.len()
     } else {
       self.items.len()
@@ -229,28 +376,30 @@ impl<T> Scrollable for StatefulTable<T> {
   }
 }
 
-impl<T: Clone> StatefulTable<T> {
-  /// A clone of the currently selected item.
+impl<T> StatefulTable<T> {
+  /// The currently selected item.
   /// When a filter is active, maps the visual index through `filtered_indices`
   /// so the correct item is returned regardless of filtering.
-  pub fn get_selected_item_copy(&self) -> Option<T> {
-    if !self.items.is_empty() {
-      self.state.selected().and_then(|i| {
-        if self.filtered_indices.is_empty() {
-          self.items.get(i).cloned()
-        } else {
-          self
-            .filtered_indices
-            .get(i)
-            .and_then(|&real| self.items.get(real).cloned())
-        }
-      })
+  pub fn get_selected_item(&self) -> Option<&T> {
+    let i = self.state.selected()?;
+    if self.filtered_indices.is_empty() && !self.problems_only {
+      self.items.get(i)
     } else {
-      None
+      self
+        .filtered_indices
+        .get(i)
+        .and_then(|&real| self.items.get(real))
     }
   }
 }
 
+impl<T: Clone> StatefulTable<T> {
+  /// A clone of the currently selected item.
+  pub fn get_selected_item_copy(&self) -> Option<T> {
+    self.get_selected_item().cloned()
+  }
+}
+
 #[derive(Clone)]
 pub struct TabRoute {
   pub title: String,
@@ -261,6 +410,9 @@ pub struct TabsState {
   pub items: Vec<TabRoute>,
   pub index: usize,
   pub scroll_start: usize,
+  /// Where each visible tab was drawn on the last frame, by item index, so
+  /// mouse clicks can be mapped back to a tab.
+  pub rects: Vec<(usize, Rect)>,
 }
 
 impl TabsState {
@@ -269,8 +421,17 @@ impl TabsState {
       items,
       index: 0,
       scroll_start: 0,
+      rects: vec![],
     }
   }
+  /// Index of the tab drawn at this cell, if any.
+  pub fn tab_at(&self, column: u16, row: u16) -> Option<usize> {
+    self
+      .rects
+      .iter()
+      .find(|(_, rect)| rect.contains(Position::new(column, row)))
+      .map(|(index, _)| *index)
+  }
   pub fn set_index(&mut self, index: usize) -> &TabRoute {
     self.index = index;
     &self.items[self.index]
@@ -291,17 +452,34 @@ impl TabsState {
   }
 }
 
+/// Both renderings of the resource in the YAML view: the summary it opens on
+/// and the full YAML.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct YamlDetail {
+  pub summary: String,
+  pub yaml: String,
+  /// The full YAML is shown instead of the summary.
+  pub full: bool,
+}
+
 #[derive(Debug)]
 pub struct ScrollableTxt {
   items: Vec<String>,
   pub offset: usize,
   /// Pre-joined text, computed once when items change.
   txt_cache: String,
-  /// Cached syntax-highlighted lines, reused across render frames.
-  /// Invalidated when content or theme changes.
+  /// Cached syntax-highlighted lines, reused across render frames. Filled
+  /// lazily up to the visible window and invalidated when content or theme
+  /// changes.
   pub highlighted_lines: Vec<RatatuiLine<'static>>,
-  /// The theme used to produce `highlighted_lines` (true = dark theme).
-  pub highlight_is_dark: bool,
+  /// The theme used to produce `highlighted_lines`.
+  pub highlight_theme: Option<ThemeName>,
+  /// Parser and highlighter state after the last highlighted line, so the
+  /// next window resumes where the previous one stopped.
+  pub highlight_state: Option<(ParseState, HighlightState)>,
+  /// The lines redact mode masked, `None` where a line is left as is, and the
+  /// redactor that masked them.
+  redacted: Option<(Redactor, Vec<Option<String>>)>,
 }
 
 impl PartialEq for ScrollableTxt {
@@ -319,7 +497,9 @@ impl ScrollableTxt {
       offset: 0,
       txt_cache: String::new(),
       highlighted_lines: Vec::new(),
-      highlight_is_dark: false,
+      highlight_theme: None,
+      highlight_state: None,
+      redacted: None,
     }
   }
 
@@ -331,13 +511,49 @@ impl ScrollableTxt {
       items,
       offset: 0,
       highlighted_lines: Vec::new(),
-      highlight_is_dark: false,
+      highlight_theme: None,
+      highlight_state: None,
+      redacted: None,
     }
   }
 
   pub fn get_txt(&self) -> &str {
     &self.txt_cache
   }
+
+  /// Mask the text with `redactor`, unless it already was.
+  pub fn redact(&mut self, redactor: &Redactor) {
+    if self.redacted.as_ref().is_some_and(|(by, _)| by == redactor) {
+      return;
+    }
+    let lines = redactor
+      .redact_lines(&self.txt_cache)
+      .into_iter()
+      .map(|line| match line {
+        Cow::Owned(masked) => Some(masked),
+        Cow::Borrowed(_) => None,
+      })
+      .collect();
+    self.redacted = Some((redactor.clone(), lines));
+  }
+
+  /// Line `index` as [`Self::redact`] masked it, if it did.
+  pub fn redacted_line(&self, index: usize) -> Option<&str> {
+    self
+      .redacted
+      .as_ref()
+      .and_then(|(_, lines)| lines.get(index)?.as_deref())
+  }
+
+  /// Number of lines as the highlighter sees them: a trailing newline does not
+  /// start another line.
+  pub fn line_count(&self) -> usize {
+    if self.txt_cache.is_empty() {
+      0
+    } else {
+      self.items.len() - usize::from(self.txt_cache.ends_with('\n'))
+    }
+  }
 }
 
 impl Scrollable for ScrollableTxt {
@@ -355,20 +571,68 @@ impl Scrollable for ScrollableTxt {
   }
 }
 
-// TODO implement line buffer to avoid gathering too much data in memory
-const MAX_LOG_RECORDS: usize = 10_000;
+/// Records kept in the log buffer unless `log_max_lines` sets another cap;
+/// the oldest are dropped past it.
+pub const MAX_LOG_RECORDS: usize = 10_000;
 
 #[derive(Debug, Clone)]
 pub struct LogsState {
   /// Stores the log messages to be displayed
-  ///
-  /// (original_message, (wrapped_message, wrapped_at_width))
-  #[allow(clippy::type_complexity)]
-  records: VecDeque<(String, Option<(Vec<ListItem<'static>>, u16)>)>,
+  records: VecDeque<LogRecord>,
   wrapped_length: usize,
   viewport_height: usize,
   pub state: ListState,
   pub id: String,
+  /// Text searched for in the buffer, matched case-insensitively
+  pub search: String,
+  /// The search is being typed
+  pub search_active: bool,
+  /// The search the wrapped records were highlighted for
+  highlighted_search: String,
+  /// First display row of each record matching `search`, as of the last render
+  match_rows: Vec<usize>,
+  /// Records that arrived while following was paused
+  new_below: usize,
+  /// Pretty-print JSON records, or None to show them raw
+  json: Option<JsonLogFormat>,
+  /// Styles the `[pod]` prefix of merged logs is colored with, picked per
+  /// pod; empty leaves the prefix plain
+  source_styles: Vec<Style>,
+  /// Records kept before the oldest are dropped
+  max_records: usize,
+  /// Records dropped from the front to stay within `max_records`
+  dropped: usize,
+  /// Masks sensitive values on display while redact mode is on
+  redactor: Option<Redactor>,
+}
+
+/// A log line, or a marker dropped between them, with its display rows
+/// cached at the width they were wrapped to.
+#[derive(Debug, Clone)]
+struct LogRecord {
+  text: String,
+  /// Dropped by [`LogsState::add_marker`], not streamed
+  marker: bool,
+  wrapped: Option<(Vec<ListItem<'static>>, u16)>,
+}
+
+impl LogRecord {
+  fn new(text: String, marker: bool) -> Self {
+    LogRecord {
+      text,
+      marker,
+      wrapped: None,
+    }
+  }
+}
+
+/// How JSON log records are shown when JSON formatting is on.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsonLogFormat {
+  /// Field shown on its own as the first line, e.g. `msg`
+  pub message_field: Option<String>,
+  /// Style of the object keys, or None to leave them plain
+  pub key_style: Option<Style>,
 }
 
 impl LogsState {
@@ -379,6 +643,63 @@ impl LogsState {
       wrapped_length: 0,
       viewport_height: 0,
       id,
+      search: String::new(),
+      search_active: false,
+      highlighted_search: String::new(),
+      match_rows: vec![],
+      new_below: 0,
+      json: None,
+      source_styles: vec![],
+      max_records: MAX_LOG_RECORDS,
+      dropped: 0,
+      redactor: None,
+    }
+  }
+
+  /// Keep at most `max` records, dropping the oldest past it.
+  pub fn with_max_records(mut self, max: usize) -> Self {
+    self.max_records = max.max(1);
+    self
+  }
+
+  /// How many of the oldest records were dropped to stay within the cap.
+  pub fn dropped(&self) -> usize {
+    self.dropped
+  }
+
+  /// Pretty-print JSON records with `format`, or show them raw with None.
+  /// Only the display changes; copying and searching see the raw records.
+  pub fn set_json_format(&mut self, format: Option<JsonLogFormat>) {
+    if self.json != format {
+      self.json = format;
+      self
+        .records
+        .iter_mut()
+        .for_each(|record| record.wrapped = None);
+    }
+  }
+
+  /// Mask sensitive values with `redactor`, or show records as they are with
+  /// None. Like JSON formatting, only the display changes.
+  pub fn set_redactor(&mut self, redactor: Option<&Redactor>) {
+    if self.redactor.as_ref() != redactor {
+      self.redactor = redactor.cloned();
+      self
+        .records
+        .iter_mut()
+        .for_each(|record| record.wrapped = None);
+    }
+  }
+
+  /// Color the `[pod]` prefix of each record with one of `styles`, the same
+  /// one for every line of a pod, as for logs merged from several pods.
+  pub fn set_source_styles(&mut self, styles: Vec<Style>) {
+    if self.source_styles != styles {
+      self.source_styles = styles;
+      self
+        .records
+        .iter_mut()
+        .for_each(|record| record.wrapped = None);
     }
   }
 
@@ -386,7 +707,7 @@ impl LogsState {
   pub fn get_plain_text(&self) -> String {
     self.records.iter().fold(String::new(), |mut acc, v| {
       acc.push('\n');
-      acc.push_str(v.0.as_str());
+      acc.push_str(v.text.as_str());
       acc
     })
   }
@@ -404,6 +725,13 @@ impl LogsState {
     let available_lines = logs_area.height as usize;
     self.viewport_height = available_lines;
     let wrap_width = logs_area.width.max(1);
+    if self.highlighted_search != self.search {
+      self.highlighted_search = self.search.clone();
+      self
+        .records
+        .iter_mut()
+        .for_each(|record| record.wrapped = None);
+    }
     let mut items = if wrap {
       self.wrapped_items(wrap_width, style)
     } else {
@@ -413,6 +741,7 @@ impl LogsState {
 
     if follow {
       self.unselect();
+      self.new_below = 0;
       let wrapped_lines_to_skip = items.len().saturating_sub(available_lines);
       items = items.into_iter().skip(wrapped_lines_to_skip).collect();
     }
@@ -430,30 +759,69 @@ impl LogsState {
   /// Add a record to be displayed
   #[cfg(test)]
   pub fn add_record(&mut self, record: String) {
-    self.records.push_back((record, None));
-    while self.records.len() > MAX_LOG_RECORDS {
-      self.records.pop_front();
-    }
+    self.count_new_below(1);
+    self.records.push_back(LogRecord::new(record, false));
+    self.evict_oldest();
   }
 
   /// Add multiple records in a batch
   pub fn add_records(&mut self, records: Vec<String>) {
+    self.count_new_below(records.len());
     for record in records {
-      self.records.push_back((record, None));
+      self.records.push_back(LogRecord::new(record, false));
     }
-    while self.records.len() > MAX_LOG_RECORDS {
+    self.evict_oldest();
+  }
+
+  fn evict_oldest(&mut self) {
+    while self.records.len() > self.max_records {
       self.records.pop_front();
+      self.dropped += 1;
     }
   }
 
-  /// Get the last n raw log lines (for dedup on reconnect)
+  /// Drop a marker line stamped with `at`, and with `note` unless it is
+  /// blank, after the newest record. Markers are copied and saved with the
+  /// rest of the logs.
+  pub fn add_marker(&mut self, note: &str, at: DateTime<FixedOffset>) {
+    let time = at.format("%H:%M:%S");
+    let marker = match note.trim() {
+      "" => format!("{}{} ---", LOG_MARKER_PREFIX, time),
+      note => format!("{}{}: {} ---", LOG_MARKER_PREFIX, time, note),
+    };
+    self.count_new_below(1);
+    self.records.push_back(LogRecord::new(marker, true));
+    self.evict_oldest();
+  }
+
+  /// Markers dropped into the buffer and not evicted since.
+  pub fn marker_count(&self) -> usize {
+    self.records.iter().filter(|record| record.marker).count()
+  }
+
+  /// The records as saved to a file: one per line, markers included.
+  pub fn saved_text(&self) -> String {
+    self.records.iter().fold(String::new(), |mut acc, v| {
+      acc.push_str(v.text.as_str());
+      acc.push('\n');
+      acc
+    })
+  }
+
+  pub fn line_count(&self) -> usize {
+    self.records.len()
+  }
+
+  /// Get the last n raw log lines (for dedup on reconnect); markers are not
+  /// from the stream, so they are left out
   pub fn last_n_records(&self, n: usize) -> Vec<&str> {
     self
       .records
       .iter()
       .rev()
+      .filter(|record| !record.marker)
       .take(n)
-      .map(|(s, _)| s.as_str())
+      .map(|record| record.text.as_str())
       .collect()
   }
 
@@ -461,6 +829,62 @@ impl LogsState {
     self.state.select(None);
   }
 
+  /// A selected row means the view is paused, so arriving lines land below it.
+  fn count_new_below(&mut self, count: usize) {
+    if self.state.selected().is_some() {
+      self.new_below += count;
+    }
+  }
+
+  /// The display row the paused view is at (1-based) and the lines that
+  /// arrived since it was paused, or None while following.
+  pub fn paused_position(&self) -> Option<(usize, usize)> {
+    self.state.selected().map(|row| (row + 1, self.new_below))
+  }
+
+  /// Select the next (or previous) match after the selected row, wrapping
+  /// around the buffer. Returns false when nothing matches.
+  pub fn jump_to_match(&mut self, forward: bool) -> bool {
+    let current = self.state.selected();
+    let row = if forward {
+      self
+        .match_rows
+        .iter()
+        .find(|&&row| current.is_none_or(|c| row > c))
+        .or(self.match_rows.first())
+    } else {
+      self
+        .match_rows
+        .iter()
+        .rev()
+        .find(|&&row| current.is_none_or(|c| row < c))
+        .or(self.match_rows.last())
+    };
+    match row.copied() {
+      Some(row) => {
+        self.state.select(Some(row));
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// The selected match as (1-based position, total), or just the total when
+  /// the selection is not on a match.
+  pub fn match_position(&self) -> (Option<usize>, usize) {
+    let position = self
+      .state
+      .selected()
+      .and_then(|row| self.match_rows.iter().position(|&m| m == row))
+      .map(|i| i + 1);
+    (position, self.match_rows.len())
+  }
+
+  pub fn clear_search(&mut self) {
+    self.search.clear();
+    self.search_active = false;
+  }
+
   pub fn freeze_follow_position(&mut self) {
     if self.state.selected().is_none() {
       let offset = self.wrapped_length.saturating_sub(self.viewport_height);
@@ -468,49 +892,261 @@ impl LogsState {
     }
   }
 
-  /// One ListItem per record, unwrapped. The List widget truncates lines wider
-  /// than the viewport, so long lines stay on a single row instead of reflowing.
-  fn unwrapped_items(&self, style: Style) -> Vec<ListItem<'static>> {
-    self
-      .records
-      .iter()
-      .map(|record| ListItem::new(Span::styled(record.0.clone(), style)))
-      .collect()
+  /// One ListItem per display line, unwrapped. The List widget truncates lines
+  /// wider than the viewport, so long lines stay on a single row instead of reflowing.
+  fn unwrapped_items(&mut self, style: Style) -> Vec<ListItem<'static>> {
+    let search = self.search.as_str();
+    let json = self.json.as_ref();
+    let mut match_rows = vec![];
+    let mut items = vec![];
+    for record in &self.records {
+      if !match_ranges(&record.text, search).is_empty() {
+        match_rows.push(items.len());
+      }
+      let key_style = json.and_then(|format| format.key_style);
+      let source = source_style(&record.text, &self.source_styles);
+      let record_style = record_style(record.marker, style);
+      let lines = display_lines(&record.text, json, self.redactor.as_ref());
+      for (i, line) in lines.into_iter().enumerate() {
+        let line_key_style = key_style.filter(|_| i > 0);
+        let line_source = source.filter(|_| i == 0);
+        items.push(ListItem::new(log_line(
+          line,
+          search,
+          record_style,
+          line_key_style,
+          line_source,
+        )));
+      }
+    }
+    self.match_rows = match_rows;
+    items
   }
 
   fn wrapped_items(&mut self, width: u16, style: Style) -> Vec<ListItem<'static>> {
     let logs_area_width = width as usize;
-
-    self
+    let search = self.search.as_str();
+    let json = self.json.as_ref();
+    let key_style = json.and_then(|format| format.key_style);
+    let source_styles = &self.source_styles;
+    let redactor = self.redactor.as_ref();
+    let mut match_rows = vec![];
+    let mut row = 0;
+
+    let items = self
       .records
       .iter_mut()
       .flat_map(|record| {
-        if let Some(wrapped) = &record.1 {
+        if !match_ranges(&record.text, search).is_empty() {
+          match_rows.push(row);
+        }
+        if let Some(wrapped) = &record.wrapped {
           if wrapped.1 == width {
+            row += wrapped.0.len();
             return wrapped.0.clone();
           }
         }
 
-        record.1 = Some((
-          textwrap::wrap(record.0.as_ref(), logs_area_width)
-            .into_iter()
-            .map(|line| line.to_string())
-            .map(|line| Span::styled(line, style))
-            .map(ListItem::new)
-            .collect::<Vec<ListItem<'_>>>(),
-          width,
-        ));
-
-        record
-          .1
+        let mut wrapped_lines = vec![];
+        let source = source_style(&record.text, source_styles);
+        let record_style = record_style(record.marker, style);
+        for (i, line) in display_lines(&record.text, json, redactor)
+          .into_iter()
+          .enumerate()
+        {
+          // keys open a pretty-printed line, so only its first piece has one
+          for (part, piece) in textwrap::wrap(&line, logs_area_width).iter().enumerate() {
+            let piece_key_style = key_style.filter(|_| i > 0 && part == 0);
+            let piece_source = source.filter(|_| i == 0 && part == 0);
+            wrapped_lines.push(ListItem::new(log_line(
+              piece.to_string(),
+              search,
+              record_style,
+              piece_key_style,
+              piece_source,
+            )));
+          }
+        }
+        record.wrapped = Some((wrapped_lines, width));
+
+        let wrapped = record
+          .wrapped
           .as_ref()
           .map(|wrapped| wrapped.0.clone())
-          .unwrap_or_default()
+          .unwrap_or_default();
+        row += wrapped.len();
+        wrapped
       })
-      .collect()
+      .collect();
+    self.match_rows = match_rows;
+    items
+  }
+}
+
+/// Opens the marker lines [`LogsState::add_marker`] drops into the logs.
+const LOG_MARKER_PREFIX: &str = "--- MARK ";
+
+/// Markers are reversed, like search matches, so they stand out in any theme.
+fn record_style(marker: bool, style: Style) -> Style {
+  if marker {
+    style.add_modifier(Modifier::REVERSED | Modifier::BOLD)
+  } else {
+    style
+  }
+}
+
+/// The lines a record is shown as: a JSON object pretty-printed when `json` is
+/// set, with the message field first when it has one, or the record as is.
+/// Text before the object, such as a timestamp, stays on the first line.
+/// Sensitive `key=value` and `"key": "value"` pairs are masked by `redactor`.
+fn display_lines(
+  record: &str,
+  json: Option<&JsonLogFormat>,
+  redactor: Option<&Redactor>,
+) -> Vec<String> {
+  let lines = json
+    .and_then(|format| json_display_lines(record, format))
+    .unwrap_or_else(|| vec![record.to_owned()]);
+  match redactor {
+    Some(redactor) => lines
+      .into_iter()
+      .map(|line| redactor.redact_pairs(&line).into_owned())
+      .collect(),
+    None => lines,
+  }
+}
+
+fn json_display_lines(record: &str, format: &JsonLogFormat) -> Option<Vec<String>> {
+  let (prefix, body) = record.split_at(record.find('{')?);
+  let serde_json::Value::Object(mut object) = serde_json::from_str(body.trim_end()).ok()? else {
+    return None;
+  };
+  let message = format
+    .message_field
+    .as_deref()
+    .and_then(|field| match object.get(field) {
+      Some(serde_json::Value::String(message)) => Some(message.clone()),
+      _ => None,
+    });
+  let mut lines = vec![];
+  if let Some(message) = message {
+    if let Some(field) = format.message_field.as_deref() {
+      object.remove(field);
+    }
+    lines.push(format!("{}{}", prefix, message));
+    if object.is_empty() {
+      return Some(lines);
+    }
+  }
+  let pretty = serde_json::to_string_pretty(&serde_json::Value::Object(object)).ok()?;
+  let mut pretty_lines = pretty.lines();
+  if lines.is_empty() {
+    lines.push(format!("{}{}", prefix, pretty_lines.next()?));
+  } else {
+    lines.push(pretty_lines.next()?.to_owned());
+  }
+  lines.extend(pretty_lines.map(str::to_owned));
+  Some(lines)
+}
+
+/// Byte offset just past the quoted key opening a pretty-printed JSON line.
+fn json_key_end(line: &str) -> Option<usize> {
+  let start = line.len() - line.trim_start().len();
+  let rest = line[start..].strip_prefix('"')?;
+  let mut escaped = false;
+  for (i, c) in rest.char_indices() {
+    match c {
+      _ if escaped => escaped = false,
+      '\\' => escaped = true,
+      '"' => {
+        let end = start + 1 + i + 1;
+        return line[end..].starts_with(':').then_some(end);
+      }
+      _ => {}
+    }
+  }
+  None
+}
+
+/// Byte offset just past a `[source]` prefix opening a record.
+fn source_prefix_end(record: &str) -> Option<usize> {
+  record.strip_prefix('[')?.find(']').map(|close| close + 2)
+}
+
+/// The style for the source prefix of `record`, the same for every record
+/// from one source.
+fn source_style(record: &str, styles: &[Style]) -> Option<Style> {
+  if styles.is_empty() {
+    return None;
+  }
+  let source = &record[..source_prefix_end(record)?];
+  let hash = source.bytes().fold(0usize, |hash, b| {
+    hash.wrapping_mul(31).wrapping_add(b as usize)
+  });
+  Some(styles[hash % styles.len()])
+}
+
+/// A display line with its JSON key, if any, in `key_style`, and its source
+/// prefix, if any, in `source_style`.
+fn log_line(
+  line: String,
+  search: &str,
+  style: Style,
+  key_style: Option<Style>,
+  source_style: Option<Style>,
+) -> RatatuiLine<'static> {
+  if let Some((source_style, end)) = source_style.zip(source_prefix_end(&line)) {
+    let mut spans = highlight_matches(line[..end].to_owned(), search, source_style).spans;
+    spans.extend(log_line(line[end..].to_owned(), search, style, key_style, None).spans);
+    return RatatuiLine::from(spans);
+  }
+  match key_style.zip(json_key_end(&line)) {
+    Some((key_style, end)) => {
+      let mut spans = highlight_matches(line[..end].to_owned(), search, key_style).spans;
+      spans.extend(highlight_matches(line[end..].to_owned(), search, style).spans);
+      RatatuiLine::from(spans)
+    }
+    None => highlight_matches(line, search, style),
   }
 }
 
+/// Byte ranges of `needle` in `line`, ignoring ASCII case.
+fn match_ranges(line: &str, needle: &str) -> Vec<(usize, usize)> {
+  if needle.is_empty() {
+    return vec![];
+  }
+  let haystack = line.to_ascii_lowercase();
+  let needle = needle.to_ascii_lowercase();
+  haystack
+    .match_indices(needle.as_str())
+    .map(|(start, m)| (start, start + m.len()))
+    .collect()
+}
+
+/// A log line with every match of `search` reversed, so matches stand out in any theme.
+fn highlight_matches(line: String, search: &str, style: Style) -> RatatuiLine<'static> {
+  let ranges = match_ranges(&line, search);
+  if ranges.is_empty() {
+    return RatatuiLine::from(Span::styled(line, style));
+  }
+  let mut spans = vec![];
+  let mut last = 0;
+  for (start, end) in ranges {
+    if start > last {
+      spans.push(Span::styled(line[last..start].to_owned(), style));
+    }
+    spans.push(Span::styled(
+      line[start..end].to_owned(),
+      style.add_modifier(Modifier::REVERSED),
+    ));
+    last = end;
+  }
+  if last < line.len() {
+    spans.push(Span::styled(line[last..].to_owned(), style));
+  }
+  RatatuiLine::from(spans)
+}
+
 impl Scrollable for LogsState {
   fn current_pos(&self) -> Option<usize> {
     self.state.selected()
@@ -528,8 +1164,7 @@ impl Scrollable for LogsState {
 
 #[cfg(test)]
 mod tests {
-  use k8s_openapi::api::core::v1::Namespace;
-  use kube::api::ObjectMeta;
+  use k8s_openapi::{api::core::v1::Namespace, apimachinery::pkg::apis::meta::v1::Time};
   use ratatui::{backend::TestBackend, buffer::Buffer, layout::Position, Terminal};
 
   use super::*;
@@ -568,6 +1203,31 @@ mod tests {
     )
   }
 
+  #[test]
+  fn test_rows_read_their_times_and_version_from_the_metadata() {
+    let namespace = KubeNs::from(Namespace {
+      metadata: ObjectMeta {
+        name: Some("shop".into()),
+        creation_timestamp: Some(Time("2024-03-01T10:00:00Z".parse().unwrap())),
+        resource_version: Some("42".into()),
+        ..ObjectMeta::default()
+      },
+      ..Namespace::default()
+    });
+    assert_eq!(
+      namespace.get_created().as_deref(),
+      Some("2024-03-01T10:00:00Z")
+    );
+    assert_eq!(namespace.resource_version().as_deref(), Some("42"));
+    assert_eq!(
+      namespace.get_changed(),
+      Some("2024-03-01T10:00:00Z".parse().unwrap())
+    );
+    assert_eq!(KubeNs::from(Namespace::default()).get_created(), None);
+    assert_eq!(KubeNs::from(Namespace::default()).get_changed(), None);
+    assert_eq!(KubeNs::from(Namespace::default()).resource_version(), None);
+  }
+
   #[test]
   fn test_stateful_table() {
     let mut sft: StatefulTable<KubeNs> = StatefulTable::new();
@@ -979,9 +1639,9 @@ mod tests {
     assert_eq!(log.records.len(), MAX_LOG_RECORDS);
 
     // Oldest records should have been evicted — first record should be 100
-    assert_eq!(log.records.front().unwrap().0, "record 100");
+    assert_eq!(log.records.front().unwrap().text, "record 100");
     assert_eq!(
-      log.records.back().unwrap().0,
+      log.records.back().unwrap().text,
       format!("record {}", MAX_LOG_RECORDS + 99)
     );
   }
@@ -996,9 +1656,9 @@ mod tests {
     }
 
     assert_eq!(log.records.len(), MAX_LOG_RECORDS);
-    assert_eq!(log.records.front().unwrap().0, "record 0");
+    assert_eq!(log.records.front().unwrap().text, "record 0");
     assert_eq!(
-      log.records.back().unwrap().0,
+      log.records.back().unwrap().text,
       format!("record {}", MAX_LOG_RECORDS - 1)
     );
   }
@@ -1013,13 +1673,23 @@ mod tests {
 
     assert_eq!(log.records.len(), MAX_LOG_RECORDS);
     // First record should be evicted
-    assert_eq!(log.records.front().unwrap().0, "record 1");
+    assert_eq!(log.records.front().unwrap().text, "record 1");
     assert_eq!(
-      log.records.back().unwrap().0,
+      log.records.back().unwrap().text,
       format!("record {}", MAX_LOG_RECORDS)
     );
   }
 
+  #[test]
+  fn test_logs_state_configured_cap_counts_dropped_records() {
+    let mut log = LogsState::new("capped".into()).with_max_records(3);
+    log.add_records((0..5).map(|i| format!("record {}", i)).collect());
+    log.add_record("record 5".into());
+
+    assert_eq!(log.dropped(), 3);
+    assert_eq!(log.get_plain_text(), "\nrecord 3\nrecord 4\nrecord 5");
+  }
+
   #[test]
   fn test_logs_state_empty() {
     let log = LogsState::new("empty".into());
@@ -1094,4 +1764,289 @@ mod tests {
 
     assert_eq!(log.state.selected(), Some(2));
   }
+
+  #[test]
+  fn test_logs_state_counts_lines_arriving_while_paused() {
+    let mut log = LogsState::new("paused".into());
+    let backend = TestBackend::new(12, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    log.add_records(vec!["alpha".into(), "beta".into(), "gamma".into()]);
+    terminal
+      .draw(|f| log.render_list(f, f.area(), Block::default(), Style::default(), true, true))
+      .unwrap();
+    assert_eq!(log.paused_position(), None);
+
+    log.freeze_follow_position();
+    log.add_records(vec!["delta".into(), "epsilon".into()]);
+    assert_eq!(log.paused_position(), Some((2, 2)));
+
+    // following again clears the counter
+    terminal
+      .draw(|f| log.render_list(f, f.area(), Block::default(), Style::default(), true, true))
+      .unwrap();
+    assert_eq!(log.paused_position(), None);
+    log.freeze_follow_position();
+    assert_eq!(log.paused_position(), Some((4, 0)));
+  }
+
+  #[test]
+  fn test_logs_state_pretty_prints_json_records_on_demand() {
+    let mut log = LogsState::new("json".into());
+    let backend = TestBackend::new(60, 8);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let key_style = Style::default().fg(ratatui::style::Color::Green);
+
+    log.add_record(r#"2024-01-01T00:00:00Z {"msg":"started","port":8080}"#.into());
+    log.add_record("plain line".into());
+
+    let rows = |logs: &mut LogsState, term: &mut Terminal<TestBackend>| {
+      term
+        .draw(|f| {
+          logs.render_list(
+            f,
+            f.area(),
+            Block::default(),
+            Style::default(),
+            false,
+            false,
+          )
+        })
+        .unwrap();
+      let buffer = term.backend().buffer().clone();
+      (0..buffer.area.height)
+        .map(|y| {
+          (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect::<String>()
+            .trim_end()
+            .to_owned()
+        })
+        .filter(|row| !row.is_empty())
+        .collect::<Vec<_>>()
+    };
+
+    log.set_json_format(Some(JsonLogFormat {
+      message_field: None,
+      key_style: Some(key_style),
+    }));
+    assert_eq!(
+      rows(&mut log, &mut terminal),
+      vec![
+        "2024-01-01T00:00:00Z {",
+        r#"  "msg": "started","#,
+        r#"  "port": 8080"#,
+        "}",
+        "plain line",
+      ]
+    );
+    let buffer = terminal.backend().buffer();
+    assert_eq!(
+      buffer[(2, 1)].fg,
+      ratatui::style::Color::Green,
+      "keys are colored"
+    );
+    assert_ne!(buffer[(10, 1)].fg, ratatui::style::Color::Green);
+
+    log.set_json_format(Some(JsonLogFormat {
+      message_field: Some("msg".into()),
+      key_style: None,
+    }));
+    assert_eq!(
+      rows(&mut log, &mut terminal),
+      vec![
+        "2024-01-01T00:00:00Z started",
+        "{",
+        r#"  "port": 8080"#,
+        "}",
+        "plain line",
+      ]
+    );
+
+    log.set_json_format(None);
+    assert_eq!(
+      rows(&mut log, &mut terminal),
+      vec![
+        r#"2024-01-01T00:00:00Z {"msg":"started","port":8080}"#,
+        "plain line",
+      ],
+      "turning it off shows the raw records again"
+    );
+    assert!(log
+      .get_plain_text()
+      .contains(r#"{"msg":"started","port":8080}"#));
+  }
+
+  #[test]
+  fn test_json_key_end_finds_quoted_keys_only() {
+    assert_eq!(json_key_end(r#"  "msg": "hi""#), Some(7));
+    assert_eq!(json_key_end(r#"  "a\"b": 1"#), Some(8));
+    assert_eq!(json_key_end(r#"  "just a string","#), None);
+    assert_eq!(json_key_end("}"), None);
+  }
+
+  #[test]
+  fn test_log_line_colors_the_source_prefix_per_source() {
+    let styles = [
+      Style::default().fg(ratatui::style::Color::Green),
+      Style::default().fg(ratatui::style::Color::Blue),
+      Style::default().fg(ratatui::style::Color::Magenta),
+    ];
+    let web = source_style("[web-7d9f-x2k] started", &styles);
+    assert!(web.is_some());
+    assert_eq!(source_style("[web-7d9f-x2k] listening", &styles), web);
+    assert_eq!(source_style("no prefix", &styles), None);
+    assert_eq!(source_style("[web-7d9f-x2k] started", &[]), None);
+
+    let line = log_line(
+      "[web] ready".into(),
+      "",
+      Style::default(),
+      None,
+      Some(styles[1]),
+    );
+    let spans: Vec<_> = line
+      .spans
+      .iter()
+      .map(|span| (span.content.as_ref(), span.style))
+      .collect();
+    assert_eq!(
+      spans,
+      vec![("[web]", styles[1]), (" ready", Style::default())]
+    );
+  }
+
+  #[test]
+  fn test_log_markers_are_stamped_highlighted_and_saved() {
+    use chrono::TimeZone;
+
+    let mut log = LogsState::new("marks".into());
+    log.add_record("GET /health 200".into());
+    let at = chrono::Local
+      .with_ymd_and_hms(2026, 3, 2, 14, 3, 22)
+      .unwrap()
+      .fixed_offset();
+    log.add_marker("  payments start failing ", at);
+    log.add_record("GET /pay 500".into());
+    log.add_marker("", at);
+
+    assert_eq!(
+      log.saved_text(),
+      "GET /health 200\n--- MARK 14:03:22: payments start failing ---\nGET /pay 500\n--- MARK 14:03:22 ---\n"
+    );
+    assert_eq!(log.marker_count(), 2);
+    assert_eq!(log.line_count(), 4);
+    // reconnects dedup against streamed lines only
+    assert_eq!(
+      log.last_n_records(2),
+      vec!["GET /pay 500", "GET /health 200"]
+    );
+
+    // a streamed line shaped like a marker is still a log line
+    log.add_record("--- MARK 09:00:00 ---".into());
+    assert_eq!(log.marker_count(), 2);
+    assert_eq!(log.last_n_records(1), vec!["--- MARK 09:00:00 ---"]);
+
+    let style = Style::default();
+    assert_eq!(
+      record_style(true, style),
+      style.add_modifier(Modifier::REVERSED | Modifier::BOLD)
+    );
+    assert_eq!(record_style(false, style), style);
+  }
+
+  #[test]
+  fn test_logs_state_masks_sensitive_values_with_a_redactor() {
+    let mut log = LogsState::new("redact".into());
+    log.add_record("login user=ann token=s3cr3t".into());
+    log.add_record(r#"{"msg":"ok","api-key":"abc"}"#.into());
+    let rendered = |logs: &mut LogsState, wrap: bool| -> Vec<String> {
+      let mut terminal = Terminal::new(TestBackend::new(40, 2)).unwrap();
+      terminal
+        .draw(|f| logs.render_list(f, f.area(), Block::default(), Style::default(), true, wrap))
+        .unwrap();
+      let buffer = terminal.backend().buffer();
+      (0..2)
+        .map(|y| {
+          (0..40)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect::<String>()
+            .trim_end()
+            .to_owned()
+        })
+        .collect()
+    };
+
+    let redactor = Redactor::new(["token", "key"]);
+    log.set_redactor(Some(&redactor));
+    let masked = vec![
+      "login user=ann token=REDACTED".to_owned(),
+      r#"{"msg":"ok","api-key":"REDACTED"}"#.to_owned(),
+    ];
+    assert_eq!(rendered(&mut log, true), masked);
+    assert_eq!(rendered(&mut log, false), masked);
+    // copies keep the records as they are
+    assert!(log.get_plain_text().contains("token=s3cr3t"));
+
+    log.set_redactor(None);
+    assert_eq!(rendered(&mut log, true)[0], "login user=ann token=s3cr3t");
+  }
+
+  #[test]
+  fn test_logs_state_search_highlights_and_jumps_between_matches() {
+    let mut log = LogsState::new("search".into());
+    let backend = TestBackend::new(12, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    log.add_record("ERROR one".into());
+    log.add_record("fine line here".into());
+    log.add_record("two error".into());
+    log.search = "error".into();
+
+    terminal
+      .draw(|f| log.render_list(f, f.area(), Block::default(), Style::default(), false, true))
+      .unwrap();
+
+    // "fine line here" wraps onto two rows, so the second match is on row 3
+    assert_eq!(log.match_position(), (None, 2));
+    let buffer = terminal.backend().buffer();
+    let reversed = |x: u16, y: u16| buffer[(x, y)].modifier.contains(Modifier::REVERSED);
+    assert!(reversed(0, 0));
+    assert!(!reversed(5, 0));
+    assert!(reversed(4, 3));
+
+    assert!(log.jump_to_match(true));
+    assert_eq!(log.state.selected(), Some(0));
+    assert!(log.jump_to_match(true));
+    assert_eq!(log.state.selected(), Some(3));
+    assert_eq!(log.match_position(), (Some(2), 2));
+    assert!(log.jump_to_match(true));
+    assert_eq!(
+      log.state.selected(),
+      Some(0),
+      "wraps around to the first match"
+    );
+    assert!(log.jump_to_match(false));
+    assert_eq!(
+      log.state.selected(),
+      Some(3),
+      "wraps back to the last match"
+    );
+
+    log.search = "missing".into();
+    terminal
+      .draw(|f| {
+        log.render_list(
+          f,
+          f.area(),
+          Block::default(),
+          Style::default(),
+          false,
+          false,
+        )
+      })
+      .unwrap();
+    assert!(!log.jump_to_match(true));
+    assert_eq!(log.match_position(), (None, 0));
+  }
 }
@@ -2,24 +2,31 @@ use std::vec;
 
 use async_trait::async_trait;
 use chrono::Utc;
-use k8s_openapi::api::networking::v1::NetworkPolicy;
+use k8s_openapi::{
+  api::networking::v1::{NetworkPolicy, NetworkPolicyPeer, NetworkPolicyPort},
+  apimachinery::pkg::util::intstr::IntOrString,
+};
+use kube::api::ObjectMeta;
 use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
+  layout::{Constraint, Rect},
+  text::Line,
+  widgets::{Cell, Paragraph},
   Frame,
 };
 
 use super::{
   models::{AppResource, KubeResource, Named},
+  pods::label_selector_rule,
   utils, ActiveBlock, App,
 };
 use crate::{
   draw_resource_tab,
   network::Network,
   ui::utils::{
-    describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, responsive_columns, style_text,
-    title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+    copy_and_escape_title_line, describe_yaml_and_esc_hint, draw_describe_block,
+    draw_resource_block, draw_yaml_block, get_describe_active, get_resource_title, help_bold_line,
+    layout_block_top_border, style_label, style_text, style_warning, title_style,
+    title_with_dual_style, vertical_chunks, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -66,10 +73,141 @@ impl From<NetworkPolicy> for KubeNetworkPolicy {
   }
 }
 
+/// A rule line of a direction that no rule opens.
+const DENY_ALL: &str = "  deny all: no rule allows traffic";
+
+impl KubeNetworkPolicy {
+  /// The directions the policy applies to. Without `policyTypes` a policy
+  /// always covers ingress, and egress once it has egress rules.
+  fn applies_to(&self, direction: &str) -> bool {
+    let spec = self.k8s_obj.spec.as_ref();
+    match spec.and_then(|s| s.policy_types.as_ref()) {
+      Some(types) => types.iter().any(|t| t == direction),
+      None => direction == "Ingress" || spec.is_some_and(|s| s.egress.is_some()),
+    }
+  }
+
+  /// Directions the policy covers without allowing any traffic, e.g.
+  /// `["Ingress"]` for the usual default-deny policy.
+  pub fn denied_directions(&self) -> Vec<&'static str> {
+    let spec = self.k8s_obj.spec.as_ref();
+    let ingress_rules = spec.and_then(|s| s.ingress.as_ref()).map_or(0, Vec::len);
+    let egress_rules = spec.and_then(|s| s.egress.as_ref()).map_or(0, Vec::len);
+    [("Ingress", ingress_rules), ("Egress", egress_rules)]
+      .into_iter()
+      .filter(|(direction, rules)| *rules == 0 && self.applies_to(direction))
+      .map(|(direction, _)| direction)
+      .collect()
+  }
+
+  /// The policy in plain lines: the pods it selects, then one heading per
+  /// direction it covers with a line per rule, e.g.
+  /// `  from pods app=web in namespaces team=a · ports 443/TCP`.
+  pub fn rules(&self) -> Vec<String> {
+    let Some(spec) = self.k8s_obj.spec.as_ref() else {
+      return vec![];
+    };
+    let selected = spec
+      .pod_selector
+      .as_ref()
+      .map(label_selector_rule)
+      .filter(|selector| !selector.is_empty())
+      .map_or("all pods".into(), |selector| format!("pods {}", selector));
+    let mut lines = vec![format!("Selects {} in {}", selected, self.namespace)];
+
+    if self.applies_to("Ingress") {
+      lines.push("Ingress".into());
+      let rules = spec.ingress.as_deref().unwrap_or_default();
+      if rules.is_empty() {
+        lines.push(DENY_ALL.into());
+      }
+      for rule in rules {
+        lines.push(rule_line(
+          "from",
+          rule.from.as_deref(),
+          rule.ports.as_deref(),
+        ));
+      }
+    }
+    if self.applies_to("Egress") {
+      lines.push("Egress".into());
+      let rules = spec.egress.as_deref().unwrap_or_default();
+      if rules.is_empty() {
+        lines.push(DENY_ALL.into());
+      }
+      for rule in rules {
+        lines.push(rule_line("to", rule.to.as_deref(), rule.ports.as_deref()));
+      }
+    }
+    lines
+  }
+}
+
+/// One rule: its peers, or anywhere when there are none, and its ports.
+fn rule_line(
+  preposition: &str,
+  peers: Option<&[NetworkPolicyPeer]>,
+  ports: Option<&[NetworkPolicyPort]>,
+) -> String {
+  let peers = match peers.filter(|p| !p.is_empty()) {
+    Some(peers) => peers.iter().map(peer_rule).collect::<Vec<_>>().join("; "),
+    None => "anywhere".into(),
+  };
+  let ports = match ports.filter(|p| !p.is_empty()) {
+    Some(ports) => format!(
+      "ports {}",
+      ports.iter().map(port_rule).collect::<Vec<_>>().join(", ")
+    ),
+    None => "all ports".into(),
+  };
+  format!("  {} {} · {}", preposition, peers, ports)
+}
+
+fn peer_rule(peer: &NetworkPolicyPeer) -> String {
+  if let Some(block) = &peer.ip_block {
+    return match block.except.as_ref().filter(|e| !e.is_empty()) {
+      Some(except) => format!("cidr {} except {}", block.cidr, except.join(", ")),
+      None => format!("cidr {}", block.cidr),
+    };
+  }
+  let pods = peer
+    .pod_selector
+    .as_ref()
+    .map(label_selector_rule)
+    .filter(|selector| !selector.is_empty())
+    .map_or("all pods".into(), |selector| format!("pods {}", selector));
+  match &peer.namespace_selector {
+    None => pods,
+    Some(selector) => match label_selector_rule(selector) {
+      selector if selector.is_empty() => format!("{} in all namespaces", pods),
+      selector => format!("{} in namespaces {}", pods, selector),
+    },
+  }
+}
+
+/// `443/TCP`, `8000-9000/TCP` for a range, or `all/UDP` without a port.
+fn port_rule(port: &NetworkPolicyPort) -> String {
+  let number = match (&port.port, port.end_port) {
+    (Some(IntOrString::Int(start)), Some(end)) => format!("{}-{}", start, end),
+    (Some(IntOrString::Int(p)), None) => p.to_string(),
+    (Some(IntOrString::String(name)), _) => name.to_owned(),
+    (None, _) => "all".into(),
+  };
+  format!("{}/{}", number, port.protocol.as_deref().unwrap_or("TCP"))
+}
+
 impl Named for KubeNetworkPolicy {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<NetworkPolicy> for KubeNetworkPolicy {
@@ -85,6 +223,20 @@ pub struct NetworkPolicyResource {}
 #[async_trait]
 impl AppResource for NetworkPolicyResource {
   fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    if block == ActiveBlock::Describe {
+      let title = title_with_dual_style(
+        get_resource_title(
+          app,
+          NW_POLICY_TITLE,
+          get_describe_active(block),
+          app.data.network_policies.items.len(),
+        ),
+        copy_and_escape_title_line(NW_POLICY_TITLE, app.palette),
+        app.palette,
+      );
+      draw_network_policy_describe(f, app, area, title);
+      return;
+    }
     draw_resource_tab!(
       NW_POLICY_TITLE,
       block,
@@ -105,7 +257,7 @@ impl AppResource for NetworkPolicyResource {
   }
 }
 
-const NP_COLUMNS: [ColumnDef; 5] = [
+pub(crate) const NP_COLUMNS: [ColumnDef; 5] = [
   ColumnDef::all("Namespace", 20, 20, 20),
   ColumnDef::all("Name", 20, 20, 20),
   ColumnDef::all("Pod Selector", 30, 30, 30),
@@ -114,7 +266,7 @@ const NP_COLUMNS: [ColumnDef; 5] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::NetworkPolicies);
   let title = get_resource_title(
     app,
     NW_POLICY_TITLE,
@@ -122,7 +274,7 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
     app.data.network_policies.items.len(),
   );
 
-  let (headers, widths) = responsive_columns(&NP_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::NetworkPolicies, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -131,22 +283,62 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       title,
       inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
       resource: &mut app.data.network_policies,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.pod_selector.to_owned()),
-        Cell::from(c.policy_types.to_owned()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      // a policy that denies a direction outright is usually the surprising one
+      let style = if c.denied_directions().is_empty() {
+        style_text(app.palette)
+      } else {
+        style_warning(app.palette)
+      };
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.pod_selector.to_owned()),
+          Cell::from(c.policy_types.to_owned()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style)
     },
     app.palette,
-    is_loading,
+    load,
+  );
+}
+
+/// Describe output under the policy's rules in plain lines, with directions
+/// it denies outright in the warning colour.
+fn draw_network_policy_describe(f: &mut Frame<'_>, app: &mut App, area: Rect, title: Line<'_>) {
+  let Some(policy) = app.data.network_policies.get_selected_item_copy() else {
+    draw_describe_block(f, app, area, title);
+    return;
+  };
+  let palette = app.palette;
+  let lines: Vec<Line<'_>> = policy
+    .rules()
+    .into_iter()
+    .map(|line| {
+      if line == DENY_ALL {
+        Line::styled(line, style_warning(palette))
+      } else if line.starts_with(' ') {
+        Line::styled(line, style_text(palette))
+      } else {
+        Line::styled(line, style_label(palette))
+      }
+    })
+    .collect();
+  let height = (lines.len() as u16 + 1).min(area.height / 2);
+  let chunks = vertical_chunks(vec![Constraint::Length(height), Constraint::Min(0)], area);
+  f.render_widget(
+    Paragraph::new(lines).block(layout_block_top_border(
+      Line::from(title_style(" Rules ", palette)),
+      palette,
+    )),
+    chunks[0],
   );
+  draw_describe_block(f, app, chunks[1], title);
 }
 
 #[cfg(test)]
@@ -171,5 +363,75 @@ mod tests {
         policy_types: "Egress,Ingress".into(),
       }
     );
+    assert!(nw_policys[3].denied_directions().is_empty());
+  }
+
+  #[test]
+  fn test_nw_policy_rules() {
+    let (nw_policys, _): (Vec<KubeNetworkPolicy>, Vec<_>) =
+      convert_resource_from_file("network_policy");
+
+    assert_eq!(
+      nw_policys[0].rules(),
+      vec![
+        "Selects pods app=webapp in default",
+        "Ingress",
+        "  from pods app=webapp · ports 443/TCP, 80/TCP",
+        "  from all pods · all ports",
+        "  from cidr 172.16.0.0/16 · ports 443/TCP, 80/TCP",
+        "  from cidr 0.0.0.0/0 · ports 443/TCP, 80/TCP",
+        "Egress",
+        "  to pods k8s-app=kube-dns in all namespaces · ports 53/UDP",
+        "  to all pods · all ports",
+        "  to pods app=webapp · ports 443/TCP, 80/TCP",
+      ]
+    );
+    // Egress only: ingress is not covered, so nothing is denied.
+    assert!(!nw_policys[2].rules().contains(&"Ingress".to_owned()));
+  }
+
+  #[test]
+  fn test_nw_policy_default_deny() {
+    let policy = KubeNetworkPolicy::from(
+      serde_json::from_value::<NetworkPolicy>(serde_json::json!({
+        "metadata": { "name": "default-deny", "namespace": "team-a" },
+        "spec": { "podSelector": {}, "policyTypes": ["Ingress", "Egress"] }
+      }))
+      .unwrap(),
+    );
+    assert_eq!(policy.denied_directions(), vec!["Ingress", "Egress"]);
+    assert_eq!(
+      policy.rules(),
+      vec![
+        "Selects all pods in team-a",
+        "Ingress",
+        DENY_ALL,
+        "Egress",
+        DENY_ALL
+      ]
+    );
+
+    // Without policyTypes only ingress is covered.
+    let ingress_only = KubeNetworkPolicy::from(
+      serde_json::from_value::<NetworkPolicy>(serde_json::json!({
+        "metadata": { "name": "deny-ingress", "namespace": "team-a" },
+        "spec": {
+          "podSelector": { "matchLabels": { "app": "db" } },
+          "ingress": [{
+            "from": [{
+              "namespaceSelector": { "matchLabels": { "team": "a" } },
+              "podSelector": { "matchLabels": { "app": "web" } }
+            }],
+            "ports": [{ "port": 5432, "endPort": 5434 }]
+          }]
+        }
+      }))
+      .unwrap(),
+    );
+    assert!(ingress_only.denied_directions().is_empty());
+    assert_eq!(
+      ingress_only.rules()[2],
+      "  from pods app=web in namespaces team=a · ports 5432-5434/TCP"
+    );
   }
 }
@@ -0,0 +1,261 @@
+//! What the pods on a node hold of it: requests and limits summed over the
+//! node's pods against its allocatable, like the "Allocated resources" of
+//! `kubectl describe node`. The scheduler packs nodes by requests, so requests
+//! close to allocatable explain why no more pods land on a node; limits over
+//! it mean the node is over-committed. Worked out from the pods the Nodes
+//! view lists anyway, skipping completed pods as they hold nothing.
+use std::str::FromStr;
+
+use k8s_openapi::api::core::v1::{Node, Pod};
+use kubectl_view_allocations::qty::Qty;
+
+use super::pods::{format_cpu, format_mem, pod_limits, pod_requests};
+
+/// One pod on the node and what it requests.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodePodRequests {
+  pub namespace: String,
+  pub name: String,
+  pub cpu: Option<Qty>,
+  pub mem: Option<Qty>,
+}
+
+impl NodePodRequests {
+  /// `shop/web-1`, its CPU and its memory request.
+  pub fn cells(&self) -> [String; 3] {
+    [
+      format!("{}/{}", self.namespace, self.name),
+      format_cpu(self.cpu.clone()),
+      format_mem(self.mem.clone()),
+    ]
+  }
+}
+
+/// An amount held of a node, e.g. `requests 300m (4%)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AllocatedAmount {
+  pub text: String,
+  /// More than the node's allocatable.
+  pub over: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeAllocation {
+  pub cpu_requests: Qty,
+  pub mem_requests: Qty,
+  pub cpu_limits: Qty,
+  pub mem_limits: Qty,
+  pub cpu_allocatable: Option<Qty>,
+  pub mem_allocatable: Option<Qty>,
+  /// `status.allocatable.pods`, the most pods the node takes.
+  pub max_pods: Option<Qty>,
+  /// The pods on the node, heaviest CPU request first.
+  pub pods: Vec<NodePodRequests>,
+}
+
+impl NodeAllocation {
+  pub fn of(node: &Node, pods: &[Pod]) -> Self {
+    let allocatable = |resource: &str| {
+      let quantity = node.status.as_ref()?.allocatable.as_ref()?.get(resource)?;
+      Qty::from_str(&quantity.0).ok()
+    };
+    let node_name = node.metadata.name.as_deref();
+    let mut allocation = NodeAllocation {
+      cpu_allocatable: allocatable("cpu"),
+      mem_allocatable: allocatable("memory"),
+      max_pods: allocatable("pods"),
+      ..NodeAllocation::default()
+    };
+    for pod in pods {
+      let phase = pod.status.as_ref().and_then(|s| s.phase.as_deref());
+      let Some(spec) = pod.spec.as_ref() else {
+        continue;
+      };
+      let on_node = node_name.is_some() && spec.node_name.as_deref() == node_name;
+      if !on_node || matches!(phase, Some("Succeeded" | "Failed")) {
+        continue;
+      }
+      let (cpu, mem) = pod_requests(spec);
+      let (cpu_limit, mem_limit) = pod_limits(spec);
+      for (total, qty) in [
+        (&mut allocation.cpu_requests, &cpu),
+        (&mut allocation.mem_requests, &mem),
+        (&mut allocation.cpu_limits, &cpu_limit),
+        (&mut allocation.mem_limits, &mem_limit),
+      ] {
+        if let Some(qty) = qty {
+          *total += qty;
+        }
+      }
+      allocation.pods.push(NodePodRequests {
+        namespace: pod.metadata.namespace.clone().unwrap_or_default(),
+        name: pod.metadata.name.clone().unwrap_or_default(),
+        cpu,
+        mem,
+      });
+    }
+    let weight = |qty: &Option<Qty>| qty.as_ref().map_or(0.0, f64::from);
+    allocation.pods.sort_by(|a, b| {
+      weight(&b.cpu)
+        .total_cmp(&weight(&a.cpu))
+        .then_with(|| weight(&b.mem).total_cmp(&weight(&a.mem)))
+        .then_with(|| a.name.cmp(&b.name))
+    });
+    allocation
+  }
+
+  /// CPU requests and limits, as shares of allocatable.
+  pub fn cpu(&self) -> [AllocatedAmount; 2] {
+    [
+      amount(
+        "requests",
+        &self.cpu_requests,
+        &self.cpu_allocatable,
+        format_cpu,
+      ),
+      amount(
+        "limits",
+        &self.cpu_limits,
+        &self.cpu_allocatable,
+        format_cpu,
+      ),
+    ]
+  }
+
+  /// Memory requests and limits, as shares of allocatable.
+  pub fn mem(&self) -> [AllocatedAmount; 2] {
+    [
+      amount(
+        "requests",
+        &self.mem_requests,
+        &self.mem_allocatable,
+        format_mem,
+      ),
+      amount(
+        "limits",
+        &self.mem_limits,
+        &self.mem_allocatable,
+        format_mem,
+      ),
+    ]
+  }
+
+  /// `12 of 110 pods`, over when the node takes no more.
+  pub fn pod_count(&self) -> AllocatedAmount {
+    let count = self.pods.len();
+    match &self.max_pods {
+      Some(max) => {
+        let max = f64::from(max).round();
+        AllocatedAmount {
+          text: format!("{} of {} pods", count, max),
+          over: count as f64 >= max,
+        }
+      }
+      None => AllocatedAmount {
+        text: format!("{} pods", count),
+        over: false,
+      },
+    }
+  }
+}
+
+fn amount(
+  label: &str,
+  qty: &Qty,
+  allocatable: &Option<Qty>,
+  format: fn(Option<Qty>) -> String,
+) -> AllocatedAmount {
+  let value = format(Some(qty.clone()));
+  let share = allocatable
+    .as_ref()
+    .map(f64::from)
+    .filter(|total| *total > 0.0)
+    .map(|total| f64::from(qty) / total * 100.0);
+  match share {
+    Some(share) => AllocatedAmount {
+      text: format!("{} {} ({:.0}%)", label, value, share),
+      over: share > 100.0,
+    },
+    None => AllocatedAmount {
+      text: format!("{} {}", label, value),
+      over: false,
+    },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pod(name: &str, node: &str, phase: &str, cpu: (&str, &str), mem: (&str, &str)) -> Pod {
+    serde_json::from_value(serde_json::json!({
+      "metadata": { "name": name, "namespace": "shop" },
+      "spec": {
+        "nodeName": node,
+        "containers": [{
+          "name": "app",
+          "resources": {
+            "requests": { "cpu": cpu.0, "memory": mem.0 },
+            "limits": { "cpu": cpu.1, "memory": mem.1 }
+          }
+        }]
+      },
+      "status": { "phase": phase }
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn test_node_allocation_sums_the_pods_on_the_node() {
+    let node: Node = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "node-a" },
+      "status": { "allocatable": { "cpu": "2", "memory": "4Gi", "pods": "3" } }
+    }))
+    .unwrap();
+    let pods = [
+      pod(
+        "web",
+        "node-a",
+        "Running",
+        ("500m", "1500m"),
+        ("1Gi", "2Gi"),
+      ),
+      pod("db", "node-a", "Running", ("1", "1"), ("2Gi", "3Gi")),
+      pod("done", "node-a", "Succeeded", ("1", "1"), ("1Gi", "1Gi")),
+      pod("other", "node-b", "Running", ("1", "1"), ("1Gi", "1Gi")),
+    ];
+
+    let allocation = NodeAllocation::of(&node, &pods);
+    assert_eq!(
+      allocation
+        .pods
+        .iter()
+        .map(NodePodRequests::cells)
+        .collect::<Vec<_>>(),
+      vec![
+        ["shop/db".to_owned(), "1000m".into(), "2048Mi".into()],
+        ["shop/web".to_owned(), "500m".into(), "1024Mi".into()],
+      ]
+    );
+    let [requests, limits] = allocation.cpu();
+    assert_eq!(requests.text, "requests 1500m (75%)");
+    assert!(!requests.over);
+    // limits past allocatable are over-committed
+    assert_eq!(limits.text, "limits 2500m (125%)");
+    assert!(limits.over);
+    let [mem_requests, mem_limits] = allocation.mem();
+    assert_eq!(mem_requests.text, "requests 3072Mi (75%)");
+    assert_eq!(mem_limits.text, "limits 5120Mi (125%)");
+    assert_eq!(
+      allocation.pod_count(),
+      AllocatedAmount {
+        text: "2 of 3 pods".into(),
+        over: false
+      }
+    );
+
+    let without_allocatable = NodeAllocation::of(&Node::default(), &[]);
+    assert_eq!(without_allocatable.cpu()[0].text, "requests 0m");
+    assert_eq!(without_allocatable.pod_count().text, "0 pods");
+  }
+}
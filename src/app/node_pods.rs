@@ -0,0 +1,571 @@
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use k8s_openapi::api::core::v1::{Node, Pod};
+use kube::{api::ListParams, Api};
+use kubectl_view_allocations::{qty::Qty, GroupBy};
+use ratatui::{
+  layout::Rect,
+  text::{Line, Span},
+  widgets::Cell,
+  Frame,
+};
+
+use super::{
+  key_binding::DEFAULT_KEYBINDING,
+  metrics::{TopPodUsage, UtilizationQualifier},
+  models::{AppResource, KubeResource, Named},
+  nodes::KubeNode,
+  pods::{format_cpu, format_mem, pod_requests, KubePod},
+  ActiveBlock, App,
+};
+use crate::{
+  draw_resource_tab,
+  network::Network,
+  ui::{
+    theme::Palette,
+    utils::{
+      action_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
+      get_cluster_wide_resource_title, get_describe_active, get_resource_title, help_bold_line,
+      style_caution, style_label, style_text, title_with_dual_style, ColumnDef, ResourceTableProps,
+      ViewTier,
+    },
+  },
+};
+
+/// Bucket for pods the scheduler has not placed on a node yet.
+pub const PENDING_BUCKET: &str = "Pending";
+
+/// Column the pods-by-node view is sorted on, largest first.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum NodePodsSort {
+  #[default]
+  Pods,
+  CpuRequests,
+  MemRequests,
+}
+
+impl NodePodsSort {
+  pub fn next(self) -> Self {
+    match self {
+      Self::Pods => Self::CpuRequests,
+      Self::CpuRequests => Self::MemRequests,
+      Self::MemRequests => Self::Pods,
+    }
+  }
+
+  fn label(self) -> &'static str {
+    match self {
+      Self::Pods => "pods",
+      Self::CpuRequests => "CPU requests",
+      Self::MemRequests => "memory requests",
+    }
+  }
+}
+
+/// Pods scheduled on one node, with their summed requests.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KubeNodePods {
+  /// Node name, or [`PENDING_BUCKET`] for unscheduled pods.
+  pub name: String,
+  pub is_pending: bool,
+  pub pods: usize,
+  pub cpu_requests: Qty,
+  pub mem_requests: Qty,
+  pub cpu_allocatable: Option<Qty>,
+  pub mem_allocatable: Option<Qty>,
+}
+
+impl Named for KubeNodePods {
+  fn get_name(&self) -> &String {
+    &self.name
+  }
+}
+
+/// Group pods by `spec.nodeName`. Every listed node gets a bucket, so idle
+/// nodes show up with zero pods. Completed pods are skipped because they no
+/// longer hold their requests on the node.
+pub fn group_pods_by_node(nodes: &[Node], pods: &[Pod]) -> Vec<KubeNodePods> {
+  let allocatable = |node: &Node, resource: &str| {
+    let quantity = node.status.as_ref()?.allocatable.as_ref()?.get(resource)?;
+    Qty::from_str(&quantity.0).ok()
+  };
+  let mut buckets: BTreeMap<String, KubeNodePods> = nodes
+    .iter()
+    .filter_map(|node| {
+      let name = node.metadata.name.clone()?;
+      let bucket = KubeNodePods {
+        name: name.clone(),
+        cpu_allocatable: allocatable(node, "cpu"),
+        mem_allocatable: allocatable(node, "memory"),
+        ..KubeNodePods::default()
+      };
+      Some((name, bucket))
+    })
+    .collect();
+  let mut pending = KubeNodePods {
+    name: PENDING_BUCKET.into(),
+    is_pending: true,
+    ..KubeNodePods::default()
+  };
+
+  for pod in pods {
+    let phase = pod.status.as_ref().and_then(|s| s.phase.as_deref());
+    if matches!(phase, Some("Succeeded" | "Failed")) {
+      continue;
+    }
+    let Some(spec) = pod.spec.as_ref() else {
+      continue;
+    };
+    let bucket = match spec.node_name.as_deref().filter(|n| !n.is_empty()) {
+      Some(node) => buckets
+        .entry(node.to_owned())
+        .or_insert_with(|| KubeNodePods {
+          name: node.to_owned(),
+          ..KubeNodePods::default()
+        }),
+      None => &mut pending,
+    };
+    let (cpu, mem) = pod_requests(spec);
+    bucket.pods += 1;
+    if let Some(cpu) = cpu {
+      bucket.cpu_requests += &cpu;
+    }
+    if let Some(mem) = mem {
+      bucket.mem_requests += &mem;
+    }
+  }
+
+  let mut items: Vec<_> = buckets.into_values().collect();
+  if pending.pods > 0 {
+    items.push(pending);
+  }
+  items
+}
+
+/// Sort largest first, ties by name. The Pending bucket stays last so the
+/// comparison is between nodes only.
+pub fn sort_node_pods(items: &mut [KubeNodePods], sort: NodePodsSort) {
+  items.sort_by(|a, b| {
+    a.is_pending
+      .cmp(&b.is_pending)
+      .then_with(|| match sort {
+        NodePodsSort::Pods => b.pods.cmp(&a.pods),
+        NodePodsSort::CpuRequests => b.cpu_requests.cmp(&a.cpu_requests),
+        NodePodsSort::MemRequests => b.mem_requests.cmp(&a.mem_requests),
+      })
+      .then_with(|| a.name.cmp(&b.name))
+  });
+}
+
+/// CPU and memory a pod is using, as far as the loaded metrics tell.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodUsage {
+  pub cpu: Option<Qty>,
+  pub mem: Option<Qty>,
+}
+
+/// Usage per `(namespace, pod)` from what is already loaded: the utilization
+/// rows when they are grouped down to pods, otherwise the last `kubectl top
+/// pods` run.
+pub fn pod_usage(
+  rows: &[UtilizationQualifier],
+  group_by: &[GroupBy],
+  top: &[TopPodUsage],
+) -> HashMap<(String, String), PodUsage> {
+  let mut usage: HashMap<(String, String), PodUsage> = HashMap::new();
+  let position = |wanted: GroupBy| group_by.iter().position(|g| *g == wanted);
+  if let (Some(kind_at), Some(ns_at), Some(pod_at)) = (
+    position(GroupBy::Resource),
+    position(GroupBy::Namespace),
+    position(GroupBy::Pod),
+  ) {
+    for (path, qtys, _) in rows {
+      let (Some(kind), Some(ns), Some(pod)) =
+        (path.get(kind_at), path.get(ns_at), path.get(pod_at))
+      else {
+        continue;
+      };
+      let Some(used) = qtys.as_ref().and_then(|q| q.utilization.clone()) else {
+        continue;
+      };
+      let entry = usage.entry((ns.clone(), pod.clone())).or_default();
+      match kind.as_str() {
+        "cpu" => entry.cpu = Some(used),
+        "memory" => entry.mem = Some(used),
+        _ => {}
+      }
+    }
+  }
+  if usage.is_empty() {
+    for pod in top {
+      usage.insert(
+        (pod.namespace.clone(), pod.name.clone()),
+        PodUsage {
+          cpu: pod.cpu.parse().ok(),
+          mem: pod.memory.parse().ok(),
+        },
+      );
+    }
+  }
+  usage
+}
+
+/// Fill in the usage of a node's pods and sort the heaviest first, by memory
+/// then CPU. Pods without reported usage are weighed by their requests.
+pub fn sort_pods_by_usage(pods: &mut [KubePod], usage: &HashMap<(String, String), PodUsage>) {
+  let weight = |pod: &KubePod| {
+    let used = usage.get(&(pod.namespace.clone(), pod.name.clone()));
+    let (cpu_requests, mem_requests) = pod
+      .get_k8s_obj()
+      .spec
+      .as_ref()
+      .map(pod_requests)
+      .unwrap_or_default();
+    (
+      used
+        .and_then(|u| u.mem.clone())
+        .or(mem_requests)
+        .unwrap_or_default(),
+      used
+        .and_then(|u| u.cpu.clone())
+        .or(cpu_requests)
+        .unwrap_or_default(),
+    )
+  };
+  for pod in pods.iter_mut() {
+    if let Some(used) = usage.get(&(pod.namespace.clone(), pod.name.clone())) {
+      pod.cpu = used
+        .cpu
+        .clone()
+        .map(|q| format_cpu(Some(q)))
+        .unwrap_or_default();
+      pod.mem = used
+        .mem
+        .clone()
+        .map(|q| format_mem(Some(q)))
+        .unwrap_or_default();
+    }
+  }
+  pods.sort_by_cached_key(|pod| (std::cmp::Reverse(weight(pod)), pod.name.clone()));
+}
+
+/// Header of a node's pod list: what the node can hold and, with
+/// metrics-server, how much of it is in use.
+pub(crate) fn node_allocatable_line(node: &KubeNode, palette: Palette) -> Line<'static> {
+  let mut text = format!(
+    "{} · allocatable CPU {}, memory {}",
+    node.name, node.cpu_a, node.mem_a
+  );
+  if node.has_usage {
+    text.push_str(&format!(
+      " · used CPU {} ({}%), memory {} ({}%)",
+      node.cpu, node.cpu_percent, node.mem, node.mem_percent
+    ));
+  }
+  Line::from(vec![
+    Span::styled("Node: ", style_label(palette)),
+    Span::styled(text, style_text(palette)),
+  ])
+}
+
+static NODE_PODS_TITLE: &str = "Pods by Node";
+
+pub struct NodePodsResource {}
+
+#[async_trait]
+impl AppResource for NodePodsResource {
+  fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    draw_resource_tab!(
+      NODE_PODS_TITLE,
+      block,
+      f,
+      app,
+      area,
+      Self::render,
+      draw_block,
+      app.data.node_pods
+    );
+  }
+
+  async fn get_resource(nw: &Network<'_>) {
+    let lp = ListParams::default();
+    let pods = match Api::<Pod>::all(nw.client.clone()).list(&lp).await {
+      Ok(list) => list.items,
+      Err(e) => {
+        nw.handle_error(anyhow!("Failed to get pods. {}", e)).await;
+        return;
+      }
+    };
+    // Without nodes the view still works, just without idle nodes and allocatable.
+    let nodes = Api::<Node>::all(nw.client.clone())
+      .list(&lp)
+      .await
+      .map(|list| list.items)
+      .unwrap_or_default();
+
+    let mut items = group_pods_by_node(&nodes, &pods);
+    let mut app = nw.app.lock().await;
+    sort_node_pods(&mut items, app.data.node_pods_sort);
+    app.data.node_pods.set_items(items);
+  }
+}
+
+pub(crate) const NODE_PODS_COLUMNS: [ColumnDef; 4] = [
+  ColumnDef::all("Node", 40, 40, 40),
+  ColumnDef::all("Pods", 10, 10, 10),
+  ColumnDef::all("CPU Requests", 25, 25, 25),
+  ColumnDef::all("Memory Requests", 25, 25, 25),
+];
+
+/// `300m (4%)`, with the share of allocatable when the node reports it.
+fn requests_cell(
+  requests: &Qty,
+  allocatable: Option<&Qty>,
+  format: fn(Option<Qty>) -> String,
+) -> String {
+  let value = format(Some(requests.clone()));
+  match allocatable.filter(|a| !a.is_zero()) {
+    Some(allocatable) => format!("{} ({:.0}%)", value, requests.calc_percentage(allocatable)),
+    None => value,
+  }
+}
+
+fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  let load = app.view_load(ActiveBlock::PodsByNode);
+  let title = get_cluster_wide_resource_title(
+    NODE_PODS_TITLE,
+    app.data.node_pods.items.len(),
+    &format!("(sorted by {})", app.data.node_pods_sort.label()),
+  );
+
+  let columns = app.column_layout(ActiveBlock::PodsByNode, ViewTier::Compact);
+
+  draw_resource_block(
+    f,
+    area,
+    ResourceTableProps {
+      title,
+      inline_help: help_bold_line(
+        format!(
+          "{} · {} · {} ",
+          action_hint("pods", DEFAULT_KEYBINDING.submit.key),
+          action_hint("describe", DEFAULT_KEYBINDING.describe_resource.key),
+          action_hint("sort", DEFAULT_KEYBINDING.cycle_node_pods_sort.key)
+        ),
+        app.palette,
+      ),
+      resource: &mut app.data.node_pods,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
+    },
+    |c| {
+      let style = if c.is_pending {
+        style_caution(app.palette)
+      } else {
+        style_text(app.palette)
+      };
+      columns
+        .row(vec![
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.pods.to_string()),
+          Cell::from(requests_cell(
+            &c.cpu_requests,
+            c.cpu_allocatable.as_ref(),
+            format_cpu,
+          )),
+          Cell::from(requests_cell(
+            &c.mem_requests,
+            c.mem_allocatable.as_ref(),
+            format_mem,
+          )),
+        ])
+        .style(style)
+    },
+    app.palette,
+    load,
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::app::test_utils::*;
+
+  fn grouped() -> Vec<KubeNodePods> {
+    let nodes = load_resource_from_file::<Node>("nodes");
+    let pods = load_resource_from_file::<Pod>("pods");
+    group_pods_by_node(&nodes.items, &pods.items)
+  }
+
+  fn summary(items: &[KubeNodePods]) -> Vec<(&str, usize, String, String)> {
+    items
+      .iter()
+      .map(|b| {
+        (
+          b.name.as_str(),
+          b.pods,
+          format_cpu(Some(b.cpu_requests.clone())),
+          format_mem(Some(b.mem_requests.clone())),
+        )
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_group_pods_by_node() {
+    let items = grouped();
+
+    // Failed and Succeeded pods are not counted; unscheduled ones are Pending.
+    assert_eq!(
+      summary(&items),
+      vec![
+        (
+          "gke-hello-hipster-default-pool-9e6f6ffb-q16l",
+          2,
+          "300m".into(),
+          "128Mi".into()
+        ),
+        (
+          "gke-hello-hipster-default-pool-9e6f6ffb-xzbc",
+          3,
+          "300m".into(),
+          "348Mi".into()
+        ),
+        (
+          "k3d-my-kdash-cluster-server-0",
+          1,
+          "0m".into(),
+          "0Ki".into()
+        ),
+        ("Pending", 3, "600m".into(), "500Mi".into()),
+      ]
+    );
+    assert!(items[3].is_pending);
+    assert_eq!(
+      requests_cell(
+        &items[0].cpu_requests,
+        items[0].cpu_allocatable.as_ref(),
+        format_cpu
+      ),
+      "300m (4%)"
+    );
+    // Node not in the node list: no allocatable to compare against.
+    assert_eq!(
+      requests_cell(
+        &items[1].cpu_requests,
+        items[1].cpu_allocatable.as_ref(),
+        format_cpu
+      ),
+      "300m"
+    );
+  }
+
+  #[test]
+  fn test_sort_node_pods_keeps_pending_last() {
+    let mut items = grouped();
+
+    sort_node_pods(&mut items, NodePodsSort::Pods);
+    let names: Vec<_> = items.iter().map(|b| (b.name.as_str(), b.pods)).collect();
+    assert_eq!(
+      names,
+      vec![
+        ("gke-hello-hipster-default-pool-9e6f6ffb-xzbc", 3),
+        ("gke-hello-hipster-default-pool-9e6f6ffb-q16l", 2),
+        ("k3d-my-kdash-cluster-server-0", 1),
+        ("Pending", 3),
+      ]
+    );
+
+    sort_node_pods(&mut items, NodePodsSort::MemRequests);
+    assert_eq!(
+      items[0].name,
+      "gke-hello-hipster-default-pool-9e6f6ffb-xzbc"
+    );
+    assert_eq!(
+      items[1].name,
+      "gke-hello-hipster-default-pool-9e6f6ffb-q16l"
+    );
+
+    // Equal CPU requests fall back to the name.
+    sort_node_pods(&mut items, NodePodsSort::CpuRequests);
+    assert_eq!(
+      items[0].name,
+      "gke-hello-hipster-default-pool-9e6f6ffb-q16l"
+    );
+    assert_eq!(items[3].name, "Pending");
+  }
+
+  #[test]
+  fn test_sort_pods_by_usage_puts_heaviest_first() {
+    use kubectl_view_allocations::QtyByQualifier;
+
+    let used = |quantity: &str| {
+      // QtyByQualifier is #[non_exhaustive], so no struct literal
+      let mut qtys = QtyByQualifier::default();
+      qtys.utilization = Some(Qty::from_str(quantity).unwrap());
+      Some(qtys)
+    };
+    let row = |kind: &str, pod: &str, quantity: &str| {
+      let path = [kind, "node-a", "shop", pod].map(String::from).to_vec();
+      (path, used(quantity), None)
+    };
+    let rows = vec![
+      (vec!["memory".to_string()], used("1Gi"), None),
+      row("cpu", "web", "250m"),
+      row("memory", "web", "128Mi"),
+      row("cpu", "db", "50m"),
+      row("memory", "db", "512Mi"),
+    ];
+    let group_by = [
+      GroupBy::Resource,
+      GroupBy::Node,
+      GroupBy::Namespace,
+      GroupBy::Pod,
+    ];
+    let usage = pod_usage(&rows, &group_by, &[]);
+    assert_eq!(usage.len(), 2);
+
+    let pod = |name: &str| {
+      let mut pod = KubePod::default();
+      pod.namespace = "shop".into();
+      pod.name = name.into();
+      pod
+    };
+    let mut pods = vec![pod("idle"), pod("web"), pod("db")];
+    sort_pods_by_usage(&mut pods, &usage);
+    let sorted: Vec<_> = pods
+      .iter()
+      .map(|p| (p.name.as_str(), p.cpu.as_str(), p.mem.as_str()))
+      .collect();
+    assert_eq!(
+      sorted,
+      vec![
+        ("db", "50m", "512Mi"),
+        ("web", "250m", "128Mi"),
+        ("idle", "", "")
+      ]
+    );
+
+    // Without pod-level utilization rows, `kubectl top` usage is used.
+    let top = [TopPodUsage {
+      namespace: "shop".into(),
+      name: "idle".into(),
+      cpu: "5m".into(),
+      memory: "2Gi".into(),
+    }];
+    let top_usage = pod_usage(&rows[..1], &group_by, &top);
+    sort_pods_by_usage(&mut pods, &top_usage);
+    assert_eq!(pods[0].name, "idle");
+    assert_eq!(pods[0].mem, "2048Mi");
+  }
+
+  #[test]
+  fn test_node_pods_sort_cycles() {
+    assert_eq!(NodePodsSort::Pods.next(), NodePodsSort::CpuRequests);
+    assert_eq!(NodePodsSort::CpuRequests.next(), NodePodsSort::MemRequests);
+    assert_eq!(NodePodsSort::MemRequests.next(), NodePodsSort::Pods);
+  }
+}
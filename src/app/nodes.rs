@@ -1,56 +1,86 @@
+use std::collections::BTreeMap;
+
 use anyhow::anyhow;
 use async_trait::async_trait;
-use chrono::Utc;
-use k8s_openapi::api::core::v1::{Node, Pod};
+use chrono::{DateTime, Utc};
+use k8s_openapi::{
+  api::core::v1::{Node, Pod},
+  apimachinery::pkg::api::resource::Quantity,
+};
 use kube::{
-  api::{ListParams, ObjectList, TypeMeta},
+  api::{ListParams, ObjectList, ObjectMeta, TypeMeta},
   core::ListMeta,
   Api,
 };
 use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
+  layout::{Constraint, Rect},
+  style::{Modifier, Style},
+  text::{Line, Span},
+  widgets::{Cell, Paragraph},
   Frame,
 };
 use tokio::sync::MutexGuard;
 
 use super::{
-  metrics::{self, KubeNodeMetrics},
+  metrics::{self, KubeNodeMetrics, MetricsSource},
   models::{AppResource, KubeResource, Named},
+  node_allocation::{AllocatedAmount, NodeAllocation},
   utils::{self, UNKNOWN},
   ActiveBlock, App,
 };
 use crate::{
   app::key_binding::DEFAULT_KEYBINDING,
+  cmd::IoCmdEvent,
   network::Network,
+  ui::theme::Palette,
   ui::utils::{
     action_hint, copy_and_escape_title_line, describe_and_yaml_hint, draw_describe_block,
-    draw_resource_block, draw_yaml_block, get_cluster_wide_resource_title, get_describe_active,
-    help_bold_line, responsive_columns, style_failure, style_success, title_with_dual_style,
-    wide_hint, ColumnDef, ResourceTableProps, ViewTier,
+    draw_diff_block, draw_resource_block, draw_yaml_block, gauge_cell, gauge_line,
+    get_cluster_wide_resource_title, get_describe_active, help_bold_line, horizontal_chunks,
+    layout_block_default, style_caution, style_failure, style_label, style_success, style_text,
+    style_warning, title_with_dual_style, vertical_chunks, wide_hint, ColumnDef,
+    ResourceTableProps, ViewTier,
   },
 };
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct KubeNode {
   pub name: String,
   pub status: String,
   pub role: String,
   pub version: String,
   pub pods: i32,
+  /// Pressure conditions currently `True`, short-named (`Memory`, `Disk`,
+  /// `PID`, `Network`).
+  pub pressures: Vec<String>,
   pub cpu: String,
   pub mem: String,
   pub cpu_a: String,
   pub mem_a: String,
+  pub cpu_c: String,
+  pub mem_c: String,
+  /// Whether metrics-server reported usage for this node; `cpu`/`mem` are
+  /// placeholders otherwise.
+  pub has_usage: bool,
   pub cpu_percent: String,
   pub mem_percent: String,
   pub age: String,
+  /// Requests and limits of the pods on the node, for its describe view.
+  pub allocation: NodeAllocation,
   k8s_obj: Node,
 }
 
 static NODE_LABEL_PREFIX: &str = "node-role.kubernetes.io/";
 static NODE_LABEL_ROLE: &str = "kubernetes.io/role";
 static NONE_ROLE: &str = "<none>";
+static NOT_READY: &str = "Not Ready";
+/// Node conditions that signal trouble when `True`, with their column labels.
+static PRESSURE_CONDITIONS: [(&str, &str); 4] = [
+  ("MemoryPressure", "Memory"),
+  ("DiskPressure", "Disk"),
+  ("PIDPressure", "PID"),
+  ("NetworkUnavailable", "Network"),
+];
 
 impl KubeNode {
   pub fn from_api_with_pods(
@@ -64,7 +94,7 @@ impl KubeNode {
       .as_ref()
       .is_some_and(|s| s.unschedulable.unwrap_or(false));
 
-    let (status, version, cpu_a, mem_a) = match &node.status {
+    let (status, version, cpu_a, mem_a, cpu_c, mem_c) = match &node.status {
       Some(node_status) => {
         let status = if *unschedulable {
           Some("Unschedulable".into())
@@ -75,7 +105,7 @@ impl KubeNode {
               .find(|c| c.type_ == "Ready" && c.status == "True")
             {
               Some(cond) => Some(cond.type_.clone()),
-              _ => Some("Not Ready".into()),
+              _ => Some(NOT_READY.into()),
             },
             _ => None,
           }
@@ -85,18 +115,31 @@ impl KubeNode {
           .as_ref()
           .map(|i| i.kubelet_version.clone());
 
-        let (cpu, mem) = node_status.allocatable.as_ref().map_or((None, None), |a| {
-          (
-            a.get("cpu").map(|q| q.0.clone()),
-            a.get("memory").map(|q| q.0.clone()),
-          )
-        });
+        let (cpu, mem) = cpu_and_mem(node_status.allocatable.as_ref());
+        let (cpu_c, mem_c) = cpu_and_mem(node_status.capacity.as_ref());
 
-        (status, version, cpu, mem)
+        (status, version, cpu, mem, cpu_c, mem_c)
       }
-      None => (None, None, None, None),
+      None => (None, None, None, None, None, None),
     };
 
+    let pressures = node
+      .status
+      .as_ref()
+      .and_then(|s| s.conditions.as_ref())
+      .map(|conds| {
+        PRESSURE_CONDITIONS
+          .iter()
+          .filter(|(type_, _)| {
+            conds
+              .iter()
+              .any(|c| c.type_ == *type_ && c.status == "True")
+          })
+          .map(|(_, label)| label.to_string())
+          .collect()
+      })
+      .unwrap_or_default();
+
     let pod_count = pods_list.iter().fold(0, |acc, pod| {
       let p_node_name = pod.spec.as_ref().and_then(|spec| spec.node_name.clone());
       p_node_name.map_or(acc, |v| if v == node_name { acc + 1 } else { acc })
@@ -118,7 +161,7 @@ impl KubeNode {
         .join(","),
       None => NONE_ROLE.into(),
     };
-    let (cpu, cpu_percent, mem, mem_percent) = match app
+    let (cpu, cpu_percent, mem, mem_percent, has_usage) = match app
       .data
       .node_metrics
       .iter_mut()
@@ -140,6 +183,7 @@ impl KubeNode {
           cpu_percent.to_string(),
           nm.mem.clone(),
           mem_percent.to_string(),
+          true,
         )
       }
       None => (
@@ -147,6 +191,7 @@ impl KubeNode {
         String::from("0"),
         String::from("0Mi"),
         String::from("0"),
+        false,
       ),
     };
 
@@ -160,22 +205,62 @@ impl KubeNode {
       },
       version: version.unwrap_or_default(),
       pods: pod_count,
+      pressures,
       age: utils::to_age(node.metadata.creation_timestamp.as_ref(), Utc::now()),
       cpu,
       mem,
       cpu_a: utils::cpu_to_milli(cpu_a.unwrap_or_default()),
       mem_a: utils::mem_to_mi(mem_a.unwrap_or_default()),
+      cpu_c: utils::cpu_to_milli(cpu_c.unwrap_or_default()),
+      mem_c: utils::mem_to_mi(mem_c.unwrap_or_default()),
+      has_usage,
       cpu_percent,
       mem_percent,
+      allocation: NodeAllocation::of(node, &pods_list.items),
       k8s_obj: utils::sanitize_obj(node.to_owned()),
     }
   }
 }
 
+fn cpu_and_mem(
+  quantities: Option<&BTreeMap<String, Quantity>>,
+) -> (Option<String>, Option<String>) {
+  quantities.map_or((None, None), |q| {
+    (
+      q.get("cpu").map(|q| q.0.clone()),
+      q.get("memory").map(|q| q.0.clone()),
+    )
+  })
+}
+
 impl Named for KubeNode {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
+
+  /// Creation or the last condition transition; heartbeats do not count.
+  fn get_changed(&self) -> Option<DateTime<Utc>> {
+    let conditions = self
+      .k8s_obj
+      .status
+      .as_ref()
+      .and_then(|s| s.conditions.as_ref())
+      .into_iter()
+      .flatten()
+      .filter_map(|c| c.last_transition_time.as_ref());
+    utils::latest_time(
+      self
+        .k8s_obj
+        .metadata
+        .creation_timestamp
+        .iter()
+        .chain(conditions),
+    )
+  }
 }
 
 impl KubeResource<Node> for KubeNode {
@@ -192,7 +277,7 @@ pub struct NodeResource {}
 impl AppResource for NodeResource {
   fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
     match block {
-      ActiveBlock::Describe => draw_describe_block(
+      ActiveBlock::Describe => draw_node_describe(
         f,
         app,
         area,
@@ -220,6 +305,20 @@ impl AppResource for NodeResource {
           app.palette,
         ),
       ),
+      ActiveBlock::Diff => draw_diff_block(
+        f,
+        app,
+        area,
+        title_with_dual_style(
+          get_cluster_wide_resource_title(
+            NODES_TITLE,
+            app.data.nodes.items.len(),
+            get_describe_active(block),
+          ),
+          copy_and_escape_title_line(NODES_TITLE, app.palette),
+          app.palette,
+        ),
+      ),
       ActiveBlock::Pods => crate::app::pods::draw_block_as_sub(f, app, area),
       ActiveBlock::Containers => crate::app::pods::draw_containers_block(f, app, area),
       ActiveBlock::Logs => crate::app::pods::draw_logs_block(f, app, area),
@@ -263,6 +362,19 @@ impl AppResource for NodeResource {
 }
 
 async fn get_node_metrics(nw: &Network<'_>) {
+  match nw.metrics_source().await {
+    MetricsSource::KubectlTop => {
+      // `node_metrics` is filled in by the CmdRunner when `kubectl top` returns
+      nw.app.lock().await.dispatch_cmd(IoCmdEvent::TopNodes).await;
+      return;
+    }
+    MetricsSource::Unavailable => {
+      nw.app.lock().await.data.node_metrics = vec![];
+      return;
+    }
+    MetricsSource::Unknown | MetricsSource::Api => {}
+  }
+
   let api_node_metrics: Api<metrics::NodeMetrics> = Api::all(nw.client.clone());
 
   match api_node_metrics.list(&ListParams::default()).await {
@@ -275,37 +387,46 @@ async fn get_node_metrics(nw: &Network<'_>) {
         .collect();
 
       app.data.node_metrics = items;
+      app.data.metrics_source = MetricsSource::Api;
     }
     Err(_) => {
       let mut app = nw.app.lock().await;
       app.data.node_metrics = vec![];
-      // lets not show error as it will always be showing up and be annoying
-      // TODO may be show once and then disable polling
+      // not reported: `kubectl top` is tried next and only its failure shows
+      app.data.metrics_source = MetricsSource::KubectlTop;
+      app.dispatch_cmd(IoCmdEvent::TopNodes).await;
     }
   };
 }
 
-const NODE_COLUMNS: [ColumnDef; 12] = [
-  ColumnDef::all("Name", 30, 25, 25),
+pub(crate) const NODE_COLUMNS: [ColumnDef; 13] = [
+  ColumnDef::all("Name", 26, 22, 20),
   ColumnDef::all("Status", 12, 10, 10),
-  ColumnDef::all("Roles", 12, 12, 10),
-  ColumnDef::all("Version", 12, 10, 10),
+  ColumnDef::all("Roles", 10, 10, 10),
+  ColumnDef::all("Version", 10, 10, 8),
   ColumnDef::all("Pods", 6, 6, 5),
+  ColumnDef::all("Pressure", 14, 10, 10),
   ColumnDef::standard("CPU", 6, 5),
   ColumnDef::standard("Mem", 6, 5),
   ColumnDef::all("CPU %", 6, 6, 5),
   ColumnDef::all("Mem %", 6, 6, 5),
   ColumnDef::wide("CPU/A", 5),
   ColumnDef::wide("Mem/A", 5),
-  ColumnDef::all("Age", 10, 10, 10),
+  ColumnDef::all("Age", 10, 8, 7),
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
-  let title = get_cluster_wide_resource_title(NODES_TITLE, app.data.nodes.items.len(), "");
+  let load = app.view_load(ActiveBlock::Nodes);
+  let note = app
+    .metrics_note()
+    .map(|note| format!("({}) ", note))
+    .unwrap_or_default();
+  let title = get_cluster_wide_resource_title(NODES_TITLE, app.data.nodes.items.len(), &note);
 
   let tier = ViewTier::from_width(area.width, app.wide_columns);
-  let (headers, widths) = responsive_columns(&NODE_COLUMNS, tier);
+  let columns = app.column_layout(ActiveBlock::Nodes, tier);
+  let cpu_width = columns.cell_width("CPU %", area.width).unwrap_or(0);
+  let mem_width = columns.cell_width("Mem %", area.width).unwrap_or(0);
 
   draw_resource_block(
     f,
@@ -322,40 +443,331 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
         app.palette,
       ),
       resource: &mut app.data.nodes,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      let style = if c.status != "Ready" {
-        style_failure(app.palette)
-      } else {
-        style_success(app.palette)
-      };
-      let mut cells = vec![
+      let style = node_row_style(c, app.palette);
+      let cells = vec![
         Cell::from(c.name.to_owned()),
         Cell::from(c.status.to_owned()),
         Cell::from(c.role.to_owned()),
         Cell::from(c.version.to_owned()),
         Cell::from(c.pods.to_string()),
+        pressure_cell(&c.pressures, app.palette),
+        Cell::from(c.cpu.to_owned()),
+        Cell::from(c.mem.to_owned()),
+        gauge_cell(
+          &c.cpu_percent,
+          cpu_width,
+          app.palette,
+          app.enhanced_graphics,
+        ),
+        gauge_cell(
+          &c.mem_percent,
+          mem_width,
+          app.palette,
+          app.enhanced_graphics,
+        ),
+        Cell::from(c.cpu_a.to_owned()),
+        Cell::from(c.mem_a.to_owned()),
+        Cell::from(c.age.to_owned()),
       ];
-      if tier >= ViewTier::Standard {
-        cells.push(Cell::from(c.cpu.to_owned()));
-        cells.push(Cell::from(c.mem.to_owned()));
-      }
-      cells.push(Cell::from(c.cpu_percent.to_owned()));
-      cells.push(Cell::from(c.mem_percent.to_owned()));
-      if tier >= ViewTier::Wide {
-        cells.push(Cell::from(c.cpu_a.to_owned()));
-        cells.push(Cell::from(c.mem_a.to_owned()));
-      }
-      cells.push(Cell::from(c.age.to_owned()));
-      Row::new(cells).style(style)
+      columns.row(cells).style(style)
     },
     app.palette,
-    is_loading,
+    load,
+  );
+}
+
+/// Height of the headroom pane: borders plus one row each for CPU, memory and
+/// pods.
+const HEADROOM_HEIGHT: u16 = 5;
+/// Height of the pane with the node's conditions, taints and labels.
+const NODE_INFO_HEIGHT: u16 = 5;
+/// Pod rows shown above the describe output; the rest are counted.
+const MAX_POD_ROWS: usize = 8;
+/// Rows the describe output keeps at the least before panes are dropped.
+const MIN_DESCRIBE_HEIGHT: u16 = 8;
+
+/// Describe output for the selected node, under the panes that sum up where
+/// it stands: capacity, allocatable, what its pods request and use, its
+/// conditions, taints and labels, and the pods with their requests. Panes
+/// the height cannot fit are dropped, the pod list first.
+fn draw_node_describe(f: &mut Frame<'_>, app: &mut App, area: Rect, title: Line<'_>) {
+  let Some(node) = app.data.nodes.get_selected_item_copy() else {
+    draw_describe_block(f, app, area, title);
+    return;
+  };
+  let pod_rows = node.allocation.pods.len().min(MAX_POD_ROWS) as u16;
+  let pods_height = if pod_rows == 0 { 0 } else { pod_rows + 2 };
+  let mut heights = vec![];
+  for height in [HEADROOM_HEIGHT, NODE_INFO_HEIGHT, pods_height] {
+    let used: u16 = heights.iter().sum();
+    if area.height >= used + height + MIN_DESCRIBE_HEIGHT {
+      heights.push(height);
+    } else {
+      break;
+    }
+  }
+  let mut constraints: Vec<Constraint> = heights.iter().map(|&h| Constraint::Length(h)).collect();
+  constraints.push(Constraint::Min(0));
+  let chunks = vertical_chunks(constraints, area);
+  if !heights.is_empty() {
+    draw_node_headroom(f, app, chunks[0], &node);
+  }
+  if heights.len() > 1 {
+    draw_node_info(f, app, chunks[1], &node);
+  }
+  if heights.len() > 2 && pods_height > 0 {
+    draw_node_pod_requests(f, app, chunks[2], &node);
+  }
+  draw_describe_block(f, app, chunks[heights.len()], title);
+}
+
+fn allocated_span(amount: AllocatedAmount, palette: Palette) -> Span<'static> {
+  let style = if amount.over {
+    style_warning(palette)
+  } else {
+    style_text(palette)
+  };
+  Span::styled(amount.text, style)
+}
+
+fn draw_node_headroom(f: &mut Frame<'_>, app: &App, area: Rect, node: &KubeNode) {
+  let title = if node.has_usage {
+    " Headroom (capacity / allocatable / used of allocatable) "
+  } else {
+    " Headroom (capacity / allocatable; no metrics-server usage) "
+  };
+  let block = layout_block_default(title, app.palette);
+  let inner = block.inner(area);
+  f.render_widget(block, area);
+
+  let rows = vertical_chunks(vec![Constraint::Length(1); 3], inner);
+  for (label, capacity, allocatable, used, pct, [requests, limits], row) in [
+    (
+      "CPU",
+      &node.cpu_c,
+      &node.cpu_a,
+      &node.cpu,
+      &node.cpu_percent,
+      node.allocation.cpu(),
+      rows[0],
+    ),
+    (
+      "Memory",
+      &node.mem_c,
+      &node.mem_a,
+      &node.mem,
+      &node.mem_percent,
+      node.allocation.mem(),
+      rows[1],
+    ),
+  ] {
+    let cols = horizontal_chunks(
+      vec![
+        Constraint::Length(8),
+        Constraint::Length(18),
+        Constraint::Length(21),
+        Constraint::Length(24),
+        Constraint::Length(22),
+        Constraint::Fill(1),
+      ],
+      row,
+    );
+    f.render_widget(
+      Paragraph::new(Span::styled(label, style_label(app.palette))),
+      cols[0],
+    );
+    f.render_widget(
+      Paragraph::new(format!("capacity {}", capacity)).style(style_text(app.palette)),
+      cols[1],
+    );
+    f.render_widget(
+      Paragraph::new(format!("allocatable {}", allocatable)).style(style_text(app.palette)),
+      cols[2],
+    );
+    f.render_widget(
+      Paragraph::new(allocated_span(requests, app.palette)),
+      cols[3],
+    );
+    f.render_widget(Paragraph::new(allocated_span(limits, app.palette)), cols[4]);
+    if node.has_usage {
+      let pct = utils::convert_to_f64(pct);
+      f.render_widget(
+        Paragraph::new(gauge_line(
+          "used ".into(),
+          pct,
+          format!("{} ({:.0}%)", used, pct),
+          cols[5].width,
+          app.palette,
+          app.enhanced_graphics,
+        )),
+        cols[5],
+      );
+    }
+  }
+  f.render_widget(
+    Paragraph::new(Line::from(vec![
+      Span::styled(format!("{:<8}", "Pods"), style_label(app.palette)),
+      allocated_span(node.allocation.pod_count(), app.palette),
+    ])),
+    rows[2],
   );
 }
 
+/// `Ready=True · DiskPressure=True`, the conditions that signal trouble in
+/// the failure colour.
+fn node_conditions_line(node: &Node, palette: Palette) -> Line<'static> {
+  let mut spans = vec![Span::styled("Conditions: ", style_label(palette))];
+  let conditions = node
+    .status
+    .as_ref()
+    .and_then(|s| s.conditions.as_ref())
+    .into_iter()
+    .flatten();
+  for (i, condition) in conditions.enumerate() {
+    if i > 0 {
+      spans.push(Span::styled(" · ", style_text(palette)));
+    }
+    // Ready is the one condition that is good when true
+    let healthy = (condition.type_ == "Ready") == (condition.status == "True");
+    let style = if healthy {
+      style_text(palette)
+    } else {
+      style_failure(palette)
+    };
+    spans.push(Span::styled(
+      format!("{}={}", condition.type_, condition.status),
+      style,
+    ));
+  }
+  if spans.len() == 1 {
+    spans.push(Span::styled("-", style_text(palette)));
+  }
+  Line::from(spans)
+}
+
+/// `key=value:Effect` of each taint, or `none`.
+fn node_taints(node: &Node) -> String {
+  let taints: Vec<String> = node
+    .spec
+    .as_ref()
+    .and_then(|spec| spec.taints.as_ref())
+    .into_iter()
+    .flatten()
+    .map(|taint| match taint.value.as_deref() {
+      Some(value) if !value.is_empty() => format!("{}={}:{}", taint.key, value, taint.effect),
+      _ => format!("{}:{}", taint.key, taint.effect),
+    })
+    .collect();
+  if taints.is_empty() {
+    "none".into()
+  } else {
+    taints.join(", ")
+  }
+}
+
+fn draw_node_info(f: &mut Frame<'_>, app: &App, area: Rect, node: &KubeNode) {
+  let palette = app.palette;
+  let obj = node.get_k8s_obj();
+  let labels = obj
+    .metadata
+    .labels
+    .iter()
+    .flatten()
+    .map(|(key, value)| format!("{}={}", key, value))
+    .collect::<Vec<_>>()
+    .join(", ");
+  let field = |label: &'static str, value: String| {
+    Line::from(vec![
+      Span::styled(label, style_label(palette)),
+      Span::styled(value, style_text(palette)),
+    ])
+  };
+  f.render_widget(
+    Paragraph::new(vec![
+      node_conditions_line(obj, palette),
+      field("Taints: ", node_taints(obj)),
+      field(
+        "Labels: ",
+        if labels.is_empty() {
+          "-".into()
+        } else {
+          labels
+        },
+      ),
+    ])
+    .block(layout_block_default(" Node ", palette)),
+    area,
+  );
+}
+
+fn draw_node_pod_requests(f: &mut Frame<'_>, app: &App, area: Rect, node: &KubeNode) {
+  let palette = app.palette;
+  let pods = &node.allocation.pods;
+  let shown = if pods.len() > MAX_POD_ROWS {
+    MAX_POD_ROWS - 1
+  } else {
+    pods.len()
+  };
+  let name_width = pods[..shown]
+    .iter()
+    .map(|pod| pod.namespace.chars().count() + pod.name.chars().count() + 1)
+    .max()
+    .unwrap_or(0);
+  let mut lines: Vec<Line<'_>> = pods[..shown]
+    .iter()
+    .map(|pod| {
+      let [name, cpu, mem] = pod.cells();
+      Line::styled(
+        format!(
+          "{:<width$}  CPU {:>7}  memory {:>8}",
+          name,
+          cpu,
+          mem,
+          width = name_width
+        ),
+        style_text(palette),
+      )
+    })
+    .collect();
+  if shown < pods.len() {
+    lines.push(Line::styled(
+      format!("+{} more", pods.len() - shown),
+      style_label(palette),
+    ));
+  }
+  let title = format!(" Pods ({}) · heaviest CPU request first ", pods.len());
+  f.render_widget(
+    Paragraph::new(lines).block(layout_block_default(&title, palette)),
+    area,
+  );
+}
+
+/// A NotReady node is bold red so it stands out from the rest of the table; a
+/// Ready node under pressure is shown in the warning colour.
+fn node_row_style(node: &KubeNode, palette: Palette) -> Style {
+  if node.status == NOT_READY {
+    style_failure(palette).add_modifier(Modifier::BOLD)
+  } else if node.status != "Ready" {
+    style_failure(palette)
+  } else if !node.pressures.is_empty() {
+    style_caution(palette)
+  } else {
+    style_success(palette)
+  }
+}
+
+fn pressure_cell(pressures: &[String], palette: Palette) -> Cell<'static> {
+  if pressures.is_empty() {
+    Cell::from("-")
+  } else {
+    Cell::from(pressures.join(",")).style(style_failure(palette))
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use tokio::sync::Mutex;
@@ -395,13 +807,196 @@ mod tests {
         role: "control-plane,master".into(),
         version: "v1.20.6+k3s1".into(),
         pods: 5,
+        pressures: vec!["Disk".into()],
         cpu: "1414m".into(),
         mem: "590Mi".into(),
         cpu_a: "8000m".into(),
         mem_a: "31967Mi".into(),
+        cpu_c: "8000m".into(),
+        mem_c: "31967Mi".into(),
+        has_usage: true,
         cpu_percent: "17".into(),
         mem_percent: "1".into(),
+        allocation: nodes[0].allocation.clone(),
+      }
+    );
+    // the running pods on the node, not the failed ones
+    let allocation = &nodes[0].allocation;
+    assert_eq!(
+      allocation
+        .pods
+        .iter()
+        .map(|pod| pod.name.as_str())
+        .collect::<Vec<_>>(),
+      vec![
+        "cartservice-67b89ffc69-s5qp8",
+        "paymentservice-6658569876-d6r72"
+      ]
+    );
+    assert_eq!(allocation.cpu()[0].text, "requests 300m (4%)");
+    assert_eq!(allocation.mem()[1].text, "limits 256Mi (1%)");
+  }
+
+  fn kube_node() -> KubeNode {
+    let app = Mutex::new(App::default());
+    let mut app = app.blocking_lock();
+    let pods = ObjectList {
+      types: TypeMeta::default(),
+      metadata: ListMeta::default(),
+      items: vec![],
+    };
+    KubeNode::from_api_with_pods(&Node::default(), &pods, &mut app)
+  }
+
+  #[test]
+  fn test_node_row_style() {
+    let palette = crate::ui::theme::palette_for(crate::ui::theme::ThemeName::Macchiato);
+    let mut node = KubeNode {
+      status: "Ready".into(),
+      ..kube_node()
+    };
+    assert_eq!(node_row_style(&node, palette), style_success(palette));
+    node.pressures = vec!["Memory".into()];
+    assert_eq!(node_row_style(&node, palette), style_caution(palette));
+    node.status = NOT_READY.into();
+    assert_eq!(
+      node_row_style(&node, palette),
+      style_failure(palette).add_modifier(Modifier::BOLD)
+    );
+  }
+
+  #[test]
+  fn test_pressure_cell() {
+    let palette = crate::ui::theme::palette_for(crate::ui::theme::ThemeName::Macchiato);
+    assert_eq!(pressure_cell(&[], palette), Cell::from("-"));
+    assert_eq!(
+      pressure_cell(&["Disk".into(), "PID".into()], palette),
+      Cell::from("Disk,PID").style(style_failure(palette))
+    );
+  }
+
+  #[test]
+  fn test_node_describe_shows_headroom() {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let render = |node: KubeNode| {
+      let mut app = App::default();
+      app.data.nodes.set_items(vec![node]);
+      let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+      terminal
+        .draw(|f| draw_node_describe(f, &mut app, f.area(), Line::from(" Nodes ")))
+        .unwrap();
+      let buffer = terminal.backend().buffer().clone();
+      (0..buffer.area.height)
+        .map(|y| {
+          (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+    };
+    let node = KubeNode {
+      cpu: "1414m".into(),
+      cpu_a: "8000m".into(),
+      cpu_c: "8000m".into(),
+      cpu_percent: "17".into(),
+      mem_a: "31967Mi".into(),
+      ..kube_node()
+    };
+
+    let lines = render(KubeNode {
+      has_usage: true,
+      ..node.clone()
+    });
+    assert!(lines[0].contains("Headroom (capacity / allocatable / used of allocatable)"));
+    assert!(lines[1].contains("capacity 8000m"), "{:?}", lines[1]);
+    assert!(lines[1].contains("allocatable 8000m"), "{:?}", lines[1]);
+    assert!(lines[1].contains("1414m (17%)"), "{:?}", lines[1]);
+    assert!(lines[2].contains("allocatable 31967Mi"), "{:?}", lines[2]);
+
+    let without_usage = render(node);
+    assert!(without_usage[0].contains("no metrics-server usage"));
+    assert!(!without_usage[1].contains("used"), "{:?}", without_usage[1]);
+  }
+
+  #[test]
+  fn test_node_describe_lists_taints_pods_and_flags_overcommit() {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let node: Node = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "node-a", "labels": { "zone": "a" } },
+      "spec": { "taints": [{ "key": "gpu", "value": "true", "effect": "NoSchedule" }] },
+      "status": {
+        "allocatable": { "cpu": "1", "memory": "1Gi", "pods": "110" },
+        "conditions": [
+          { "type": "Ready", "status": "True" },
+          { "type": "DiskPressure", "status": "True" }
+        ]
       }
+    }))
+    .unwrap();
+    let pod: Pod = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web", "namespace": "shop" },
+      "spec": {
+        "nodeName": "node-a",
+        "containers": [{
+          "name": "app",
+          "resources": {
+            "requests": { "cpu": "500m", "memory": "256Mi" },
+            "limits": { "cpu": "2", "memory": "512Mi" }
+          }
+        }]
+      },
+      "status": { "phase": "Running" }
+    }))
+    .unwrap();
+    let pods = ObjectList {
+      types: TypeMeta::default(),
+      metadata: ListMeta::default(),
+      items: vec![pod],
+    };
+    let mut app = App::default();
+    let node = {
+      let locked = Mutex::new(App::default());
+      let mut locked = locked.blocking_lock();
+      KubeNode::from_api_with_pods(&node, &pods, &mut locked)
+    };
+    app.data.nodes.set_items(vec![node]);
+    let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+    terminal
+      .draw(|f| draw_node_describe(f, &mut app, f.area(), Line::from(" Nodes ")))
+      .unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    let lines: Vec<String> = (0..buffer.area.height)
+      .map(|y| {
+        (0..buffer.area.width)
+          .map(|x| buffer[(x, y)].symbol())
+          .collect::<String>()
+      })
+      .collect();
+    let screen = lines.join("\n");
+
+    assert!(lines[1].contains("requests 500m (50%)"), "{:?}", lines[1]);
+    assert!(lines[2].contains("limits 512Mi (50%)"), "{:?}", lines[2]);
+    assert!(lines[3].contains("1 of 110 pods"), "{:?}", lines[3]);
+    assert!(screen.contains("Conditions: Ready=True · DiskPressure=True"));
+    assert!(screen.contains("Taints: gpu=true:NoSchedule"));
+    assert!(screen.contains("Labels: zone=a"));
+    assert!(screen.contains("Pods (1) · heaviest CPU request first"));
+    assert!(screen.contains("shop/web  CPU    500m  memory    256Mi"));
+
+    // CPU limits of twice the allocatable are over-committed
+    let column = |text: &str| {
+      let at = lines[1].find(text).unwrap();
+      lines[1][..at].chars().count() as u16
+    };
+    assert_eq!(
+      buffer[(column("limits 2000m (200%)"), 1)].style().fg,
+      style_warning(app.palette).fg
+    );
+    assert_eq!(
+      buffer[(column("requests"), 1)].style().fg,
+      style_text(app.palette).fg
     );
   }
 }
@@ -1,7 +1,11 @@
 use anyhow::anyhow;
 use async_trait::async_trait;
 use k8s_openapi::api::core::v1::Namespace;
-use kube::{api::ListParams, config::Kubeconfig, Api, Error};
+use kube::{
+  api::{DeleteParams, ListParams, ObjectMeta, PostParams},
+  config::Kubeconfig,
+  Api, Error,
+};
 use log::warn;
 use ratatui::{
   layout::{Constraint, Rect},
@@ -16,7 +20,7 @@ use super::{
   ActiveBlock, App,
 };
 use crate::{
-  network::Network,
+  network::{IoEvent, Network},
   ui::{
     utils::{
       action_hint, filter_by_resource_name, filter_cursor_position, filter_status_parts, help_part,
@@ -56,6 +60,10 @@ impl Named for KubeNs {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<Namespace> for KubeNs {
@@ -207,7 +215,13 @@ impl AppResource for NamespaceResource {
 
       f.render_stateful_widget(table, area, &mut app.data.namespaces.state);
     } else {
-      loading(f, block, area, app.is_loading(), app.palette);
+      loading(
+        f,
+        block,
+        area,
+        &app.view_load(ActiveBlock::Namespaces),
+        app.palette,
+      );
     }
 
     if app.ns_filter_active {
@@ -241,6 +255,69 @@ impl AppResource for NamespaceResource {
   }
 }
 
+/// Create a namespace, switch to it and select its row.
+pub async fn create_namespace(nw: &Network<'_>, name: &str) {
+  let namespace = Namespace {
+    metadata: ObjectMeta {
+      name: Some(name.to_owned()),
+      ..ObjectMeta::default()
+    },
+    ..Namespace::default()
+  };
+  let created = match Api::<Namespace>::all(nw.client.clone())
+    .create(&PostParams::default(), &namespace)
+    .await
+  {
+    Ok(created) => created,
+    Err(e) => {
+      nw.handle_error(anyhow!("Failed to create namespace {}. {}", name, e))
+        .await;
+      return;
+    }
+  };
+
+  let mut app = nw.app.lock().await;
+  // Listed in name order like the API does, so the row stays put on refresh.
+  let mut items = app.data.namespaces.items.clone();
+  items.retain(|ns| ns.name != name);
+  items.push(KubeNs::from(created));
+  items.sort_by(|a, b| a.name.cmp(&b.name));
+  let index = items.iter().position(|ns| ns.name == name);
+  app.data.namespaces.set_items(items);
+  app.data.namespaces.state.select(index);
+
+  app.set_status_message(format!("Created namespace {}", name));
+  app.data.selected.ns = Some(name.to_owned());
+  app.cache_essential_data().await;
+  app.queue_background_resource_cache();
+}
+
+/// Delete a namespace. The row shows `Terminating` until the namespace
+/// controller has removed its contents and the namespace is gone.
+pub async fn delete_namespace(nw: &Network<'_>, name: &str) {
+  if let Err(e) = Api::<Namespace>::all(nw.client.clone())
+    .delete(name, &DeleteParams::default())
+    .await
+  {
+    nw.handle_error(anyhow!("Failed to delete namespace {}. {}", name, e))
+      .await;
+    return;
+  }
+
+  let mut app = nw.app.lock().await;
+  if let Some(ns) = app
+    .data
+    .namespaces
+    .items
+    .iter_mut()
+    .find(|ns| ns.name == name)
+  {
+    ns.status = "Terminating".into();
+  }
+  app.set_status_message(format!("Deleting namespace {}", name));
+  app.dispatch(IoEvent::GetNamespaces).await;
+}
+
 fn row_cell_mapper(s: &KubeNs) -> Row<'static> {
   Row::new(vec![
     Cell::from(s.name.to_owned()),
@@ -0,0 +1,220 @@
+//! The `Ctrl-n` namespace picker: fuzzy-filter every namespace, with an entry
+//! for all namespaces on top, and switch the scope of the namespaced views in
+//! one step instead of scrolling the namespaces block.
+use ratatui::widgets::ListState;
+
+use super::{finder::fuzzy_score, models::Scrollable, App};
+
+/// Label of the entry that clears the namespace filter.
+pub const ALL_NAMESPACES: &str = "all namespaces";
+
+/// One row of the picker.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NsChoice {
+  All,
+  Namespace(String),
+}
+
+impl NsChoice {
+  pub fn label(&self) -> &str {
+    match self {
+      NsChoice::All => ALL_NAMESPACES,
+      NsChoice::Namespace(name) => name,
+    }
+  }
+
+  /// The namespace to select, `None` for all namespaces.
+  pub fn namespace(&self) -> Option<String> {
+    match self {
+      NsChoice::All => None,
+      NsChoice::Namespace(name) => Some(name.clone()),
+    }
+  }
+}
+
+/// State of the picker overlay: the query and the choices matching it.
+#[derive(Debug)]
+pub struct NsPicker {
+  pub query: String,
+  choices: Vec<NsChoice>,
+  /// Indices into `choices`, best match first.
+  matches: Vec<usize>,
+  /// The scope when the picker was opened, highlighted in the list.
+  pub active: NsChoice,
+  pub state: ListState,
+}
+
+impl NsPicker {
+  pub fn new(namespaces: Vec<String>, active: Option<String>) -> Self {
+    let active = active.map_or(NsChoice::All, NsChoice::Namespace);
+    let mut picker = NsPicker {
+      query: String::new(),
+      choices: std::iter::once(NsChoice::All)
+        .chain(namespaces.into_iter().map(NsChoice::Namespace))
+        .collect(),
+      matches: vec![],
+      active,
+      state: ListState::default(),
+    };
+    picker.update_matches();
+    // start on the active scope so Enter keeps it and arrows move from it
+    let start = picker
+      .matches
+      .iter()
+      .position(|&i| picker.choices[i] == picker.active);
+    picker.state.select(start.or(Some(0)));
+    picker
+  }
+
+  pub fn push(&mut self, c: char) {
+    self.query.push(c);
+    self.update_matches();
+  }
+
+  pub fn pop(&mut self) {
+    self.query.pop();
+    self.update_matches();
+  }
+
+  pub fn matches(&self) -> impl Iterator<Item = &NsChoice> {
+    self.matches.iter().map(|&i| &self.choices[i])
+  }
+
+  pub fn selected(&self) -> Option<&NsChoice> {
+    let i = *self.matches.get(self.state.selected()?)?;
+    self.choices.get(i)
+  }
+
+  /// Rank by score, then list order, keeping all namespaces on top while
+  /// the query is empty.
+  fn update_matches(&mut self) {
+    let mut scored: Vec<(i64, usize)> = self
+      .choices
+      .iter()
+      .enumerate()
+      .filter_map(|(i, choice)| fuzzy_score(&self.query, choice.label()).map(|score| (score, i)))
+      .collect();
+    scored.sort_by_key(|&(score, i)| (-score, i));
+    self.matches = scored.into_iter().map(|(_, i)| i).collect();
+    self.state.select((!self.matches.is_empty()).then_some(0));
+  }
+}
+
+impl Scrollable for NsPicker {
+  fn scroll_to(&mut self, index: usize) {
+    if !self.matches.is_empty() {
+      self.state.select(Some(index));
+    }
+  }
+  fn current_pos(&self) -> Option<usize> {
+    self.state.selected()
+  }
+  fn length(&self) -> usize {
+    self.matches.len()
+  }
+  fn wraps(&self) -> bool {
+    false
+  }
+}
+
+impl App {
+  /// Open the picker over the loaded namespaces.
+  pub fn open_ns_picker(&mut self) {
+    let namespaces = self
+      .data
+      .namespaces
+      .items
+      .iter()
+      .map(|ns| ns.name.clone())
+      .collect();
+    self.ns_picker = Some(NsPicker::new(namespaces, self.data.selected.ns.clone()));
+  }
+
+  pub fn close_ns_picker(&mut self) {
+    self.ns_picker = None;
+  }
+
+  /// Scope the namespaced views to `ns`, or to all namespaces for `None`,
+  /// and re-fetch them. Leaving a namespace for all of them remembers it for
+  /// the all-namespaces toggle.
+  pub async fn switch_namespace(&mut self, ns: Option<String>) {
+    if ns == self.data.selected.ns {
+      return;
+    }
+    if ns.is_none() {
+      self.data.selected.previous_ns = self.data.selected.ns.take();
+    }
+    self.data.selected.ns = ns;
+    self.cache_essential_data().await;
+    self.queue_background_resource_cache();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn picker(active: Option<&str>) -> NsPicker {
+    NsPicker::new(
+      vec!["default".into(), "kube-system".into(), "team-a".into()],
+      active.map(str::to_owned),
+    )
+  }
+
+  fn labels(picker: &NsPicker) -> Vec<&str> {
+    picker.matches().map(NsChoice::label).collect()
+  }
+
+  #[test]
+  fn test_ns_picker_lists_all_namespaces_first_and_selects_the_active_one() {
+    let scoped = picker(Some("team-a"));
+    assert_eq!(
+      labels(&scoped),
+      vec![ALL_NAMESPACES, "default", "kube-system", "team-a"]
+    );
+    assert_eq!(
+      scoped.selected(),
+      Some(&NsChoice::Namespace("team-a".into()))
+    );
+    assert_eq!(scoped.active, NsChoice::Namespace("team-a".into()));
+
+    assert_eq!(picker(None).selected(), Some(&NsChoice::All));
+  }
+
+  #[test]
+  fn test_ns_picker_filters_fuzzily() {
+    let mut picker = picker(None);
+    for c in "ksys".chars() {
+      picker.push(c);
+    }
+    assert_eq!(labels(&picker), vec!["kube-system"]);
+    assert_eq!(
+      picker.selected().and_then(NsChoice::namespace),
+      Some("kube-system".into())
+    );
+
+    picker.pop();
+    picker.pop();
+    picker.pop();
+    picker.pop();
+    picker.push('a');
+    picker.push('l');
+    assert_eq!(labels(&picker)[0], ALL_NAMESPACES);
+
+    picker.push('z');
+    assert_eq!(picker.selected(), None);
+  }
+
+  #[tokio::test]
+  async fn test_switch_namespace_remembers_the_namespace_left_for_all() {
+    let mut app = App::default();
+    app.data.selected.ns = Some("team-a".into());
+
+    app.switch_namespace(None).await;
+    assert_eq!(app.data.selected.ns, None);
+    assert_eq!(app.data.selected.previous_ns, Some("team-a".into()));
+
+    app.switch_namespace(Some("default".into())).await;
+    assert_eq!(app.data.selected.ns, Some("default".into()));
+  }
+}
@@ -0,0 +1,200 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use k8s_openapi::{
+  api::policy::v1::PodDisruptionBudget, apimachinery::pkg::util::intstr::IntOrString,
+};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
+
+use super::{
+  models::{AppResource, KubeResource, Named},
+  utils, ActiveBlock, App,
+};
+use crate::{
+  draw_resource_tab,
+  network::Network,
+  ui::utils::{
+    describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
+    get_describe_active, get_resource_title, help_bold_line, style_text, style_warning,
+    title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+  },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct KubePdb {
+  pub namespace: String,
+  pub name: String,
+  pub min_available: String,
+  pub max_unavailable: String,
+  pub current_healthy: i32,
+  pub desired_healthy: i32,
+  pub allowed_disruptions: i32,
+  pub age: String,
+  k8s_obj: PodDisruptionBudget,
+}
+
+/// `2` or `50%`, or `N/A` when unset, as `kubectl get pdb` shows it.
+fn format_int_or_string(value: Option<&IntOrString>) -> String {
+  match value {
+    Some(IntOrString::Int(i)) => i.to_string(),
+    Some(IntOrString::String(s)) => s.to_owned(),
+    None => "N/A".into(),
+  }
+}
+
+impl From<PodDisruptionBudget> for KubePdb {
+  fn from(pdb: PodDisruptionBudget) -> Self {
+    let spec = pdb.spec.as_ref();
+    let status = pdb.status.as_ref();
+
+    Self {
+      namespace: pdb.metadata.namespace.clone().unwrap_or_default(),
+      name: pdb.metadata.name.clone().unwrap_or_default(),
+      min_available: format_int_or_string(spec.and_then(|s| s.min_available.as_ref())),
+      max_unavailable: format_int_or_string(spec.and_then(|s| s.max_unavailable.as_ref())),
+      current_healthy: status.map_or(0, |s| s.current_healthy),
+      desired_healthy: status.map_or(0, |s| s.desired_healthy),
+      allowed_disruptions: status.map_or(0, |s| s.disruptions_allowed),
+      age: utils::to_age(pdb.metadata.creation_timestamp.as_ref(), Utc::now()),
+      k8s_obj: utils::sanitize_obj(pdb),
+    }
+  }
+}
+
+impl KubePdb {
+  /// No pod may be evicted, so a node drain will wait on this budget.
+  pub fn blocks_disruptions(&self) -> bool {
+    self.allowed_disruptions == 0
+  }
+}
+
+impl Named for KubePdb {
+  fn get_name(&self) -> &String {
+    &self.name
+  }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
+}
+
+impl KubeResource<PodDisruptionBudget> for KubePdb {
+  fn get_k8s_obj(&self) -> &PodDisruptionBudget {
+    &self.k8s_obj
+  }
+}
+
+static PDBS_TITLE: &str = "PodDisruptionBudgets";
+
+pub struct PdbResource {}
+
+#[async_trait]
+impl AppResource for PdbResource {
+  fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    draw_resource_tab!(
+      PDBS_TITLE,
+      block,
+      f,
+      app,
+      area,
+      Self::render,
+      draw_block,
+      app.data.pdbs
+    );
+  }
+
+  async fn get_resource(nw: &Network<'_>) {
+    let items: Vec<KubePdb> = nw.get_namespaced_resources(PodDisruptionBudget::into).await;
+
+    let mut app = nw.app.lock().await;
+    app.data.pdbs.set_items(items);
+  }
+}
+
+pub(crate) const PDB_COLUMNS: [ColumnDef; 8] = [
+  ColumnDef::all("Namespace", 14, 14, 14),
+  ColumnDef::all("Name", 20, 20, 20),
+  ColumnDef::all("Min Available", 10, 10, 10),
+  ColumnDef::all("Max Unavailable", 10, 10, 10),
+  ColumnDef::all("Current Healthy", 10, 10, 10),
+  ColumnDef::all("Desired Healthy", 10, 10, 10),
+  ColumnDef::all("Allowed Disruptions", 12, 12, 12),
+  ColumnDef::all("Age", 8, 8, 8),
+];
+
+fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  let load = app.view_load(ActiveBlock::PodDisruptionBudgets);
+  let title = get_resource_title(app, PDBS_TITLE, "", app.data.pdbs.items.len());
+
+  let columns = app.column_layout(ActiveBlock::PodDisruptionBudgets, ViewTier::Compact);
+
+  draw_resource_block(
+    f,
+    area,
+    ResourceTableProps {
+      title,
+      inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
+      resource: &mut app.data.pdbs,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
+    },
+    |c| {
+      let style = if c.blocks_disruptions() {
+        style_warning(app.palette)
+      } else {
+        style_text(app.palette)
+      };
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.min_available.to_owned()),
+          Cell::from(c.max_unavailable.to_owned()),
+          Cell::from(c.current_healthy.to_string()),
+          Cell::from(c.desired_healthy.to_string()),
+          Cell::from(c.allowed_disruptions.to_string()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style)
+    },
+    app.palette,
+    load,
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::app::test_utils::*;
+
+  #[test]
+  fn test_pdbs_from_api() {
+    let (pdbs, pdb_list): (Vec<KubePdb>, Vec<_>) = convert_resource_from_file("pdbs");
+
+    assert_eq!(pdbs.len(), 2);
+    assert_eq!(
+      pdbs[0],
+      KubePdb {
+        namespace: "default".into(),
+        name: "web-pdb".into(),
+        min_available: "2".into(),
+        max_unavailable: "N/A".into(),
+        current_healthy: 3,
+        desired_healthy: 2,
+        allowed_disruptions: 1,
+        age: utils::to_age(Some(&get_time("2024-05-02T09:00:00Z")), Utc::now()),
+        k8s_obj: pdb_list[0].clone(),
+      }
+    );
+    assert!(!pdbs[0].blocks_disruptions());
+
+    assert_eq!(pdbs[1].min_available, "N/A");
+    assert_eq!(pdbs[1].max_unavailable, "0");
+    assert_eq!(pdbs[1].allowed_disruptions, 0);
+    assert!(pdbs[1].blocks_disruptions());
+  }
+}
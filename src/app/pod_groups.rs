@@ -0,0 +1,526 @@
+//! The pods view grouped under the workload that owns each pod, found from
+//! its controller owner reference. Pods of a ReplicaSet made by a Deployment
+//! go under the Deployment, known from their `pod-template-hash` label, and
+//! pods without a controller go under an `Unmanaged` group per namespace.
+//! Each group has a header row with how many of its pods are ready, and can
+//! be collapsed to just that row.
+use std::collections::{BTreeMap, HashSet};
+
+use super::{
+  health::Health,
+  models::{KubeResource, MarkedRow, Named},
+  pods::KubePod,
+  ActiveBlock, App,
+};
+
+const UNMANAGED: &str = "Unmanaged";
+
+const POD_TEMPLATE_HASH: &str = "pod-template-hash";
+
+/// The workload a group of pods belongs to. Unmanaged pods have an empty
+/// kind and name.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PodOwner {
+  pub namespace: String,
+  pub kind: String,
+  pub name: String,
+}
+
+impl PodOwner {
+  fn of(pod: &KubePod) -> Self {
+    let metadata = &pod.get_k8s_obj().metadata;
+    let (kind, name) = metadata
+      .owner_references
+      .iter()
+      .flatten()
+      .find(|owner| owner.controller == Some(true))
+      .map(|owner| {
+        let hash = metadata
+          .labels
+          .as_ref()
+          .and_then(|labels| labels.get(POD_TEMPLATE_HASH));
+        let deployment = hash.and_then(|hash| {
+          (owner.kind == "ReplicaSet")
+            .then(|| owner.name.strip_suffix(hash.as_str())?.strip_suffix('-'))
+            .flatten()
+        });
+        match deployment {
+          Some(deployment) => ("Deployment".to_owned(), deployment.to_owned()),
+          None => (owner.kind.clone(), owner.name.clone()),
+        }
+      })
+      .unwrap_or_default();
+    PodOwner {
+      namespace: pod.namespace.clone(),
+      kind,
+      name,
+    }
+  }
+
+  pub fn is_unmanaged(&self) -> bool {
+    self.kind.is_empty()
+  }
+
+  /// `Deployment/web`, or `Unmanaged`.
+  pub fn label(&self) -> String {
+    if self.is_unmanaged() {
+      UNMANAGED.to_owned()
+    } else {
+      format!("{}/{}", self.kind, self.name)
+    }
+  }
+}
+
+/// A group header with the roll-up of its pods.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PodGroup {
+  pub owner: PodOwner,
+  /// Pods with every container ready.
+  pub ready: usize,
+  pub total: usize,
+  pub restarts: i32,
+  pub collapsed: bool,
+  healthy: bool,
+  label: String,
+}
+
+impl PodGroup {
+  /// e.g. `3/3 Ready`.
+  pub fn status(&self) -> String {
+    format!("{}/{} Ready", self.ready, self.total)
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PodGroupRow {
+  Header(PodGroup),
+  /// A pod, by its index in the pods list it was grouped from.
+  Pod {
+    index: usize,
+    name: String,
+    namespace: String,
+    healthy: bool,
+    /// Whether it is the last pod of its group, for the tree prefix.
+    last: bool,
+    /// The group it is listed under.
+    owner: PodOwner,
+  },
+}
+
+impl PodGroupRow {
+  /// The group of a header, or the group a pod is listed under.
+  pub fn owner(&self) -> &PodOwner {
+    match self {
+      PodGroupRow::Header(group) => &group.owner,
+      PodGroupRow::Pod { owner, .. } => owner,
+    }
+  }
+}
+
+impl Named for PodGroupRow {
+  fn get_name(&self) -> &String {
+    match self {
+      PodGroupRow::Header(group) => &group.label,
+      PodGroupRow::Pod { name, .. } => name,
+    }
+  }
+
+  fn get_namespace(&self) -> Option<&str> {
+    match self {
+      PodGroupRow::Header(group) => Some(&group.owner.namespace),
+      PodGroupRow::Pod { namespace, .. } => Some(namespace),
+    }
+  }
+}
+
+/// A header is healthy while all of its pods are, so problems-only keeps
+/// the header of a group with a failing pod.
+impl Health for PodGroupRow {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    match self {
+      PodGroupRow::Header(group) => group.healthy,
+      PodGroupRow::Pod { healthy, .. } => *healthy,
+    }
+  }
+}
+
+/// Header and pod rows for `pods`, with the pods of collapsed groups left
+/// out. Groups are sorted by namespace, kind and name with the unmanaged
+/// group last in its namespace; pods keep their order within a group.
+pub fn group_rows(pods: &[KubePod], collapsed: &HashSet<PodOwner>) -> Vec<PodGroupRow> {
+  let mut groups: BTreeMap<(String, bool, PodOwner), Vec<usize>> = BTreeMap::new();
+  for (index, pod) in pods.iter().enumerate() {
+    let owner = PodOwner::of(pod);
+    groups
+      .entry((owner.namespace.clone(), owner.is_unmanaged(), owner))
+      .or_default()
+      .push(index);
+  }
+
+  let mut rows = vec![];
+  for ((_, _, owner), indices) in groups {
+    let members: Vec<&KubePod> = indices.iter().map(|&i| &pods[i]).collect();
+    let is_collapsed = collapsed.contains(&owner);
+    rows.push(PodGroupRow::Header(PodGroup {
+      ready: members
+        .iter()
+        .filter(|pod| pod.ready.1 > 0 && pod.ready.0 == pod.ready.1)
+        .count(),
+      total: members.len(),
+      restarts: members.iter().map(|pod| pod.restarts).sum(),
+      collapsed: is_collapsed,
+      healthy: members.iter().all(|pod| pod.is_healthy()),
+      label: owner.label(),
+      owner: owner.clone(),
+    }));
+    if is_collapsed {
+      continue;
+    }
+    let count = indices.len();
+    rows.extend(
+      indices
+        .into_iter()
+        .enumerate()
+        .map(|(n, index)| PodGroupRow::Pod {
+          index,
+          name: pods[index].name.clone(),
+          namespace: pods[index].namespace.clone(),
+          healthy: pods[index].is_healthy(),
+          last: n + 1 == count,
+          owner: owner.clone(),
+        }),
+    );
+  }
+  rows
+}
+
+impl App {
+  /// Whether the pods tab shows its pods grouped. The pods of one workload
+  /// or node are listed as they are.
+  pub fn pods_grouped(&self) -> bool {
+    self.data.group_pods && self.data.selected.pod_selector.is_none()
+  }
+
+  /// Switch the pods view between a flat list and groups per workload,
+  /// keeping the selected pod selected.
+  pub fn toggle_pod_grouping(&mut self) {
+    self.data.group_pods = !self.data.group_pods;
+    if self.data.group_pods {
+      let selected = self
+        .data
+        .pods
+        .get_selected_item()
+        .map(|pod| pod.name.clone());
+      self.refresh_pod_groups();
+      let row = self.data.pod_groups.items.iter().position(
+        |row| matches!(row, PodGroupRow::Pod { name, .. } if Some(name) == selected.as_ref()),
+      );
+      self.data.pod_groups.state.select(row.or(Some(0)));
+      self.set_status_message("Grouping pods by workload");
+    } else {
+      if self.data.pods.state.selected().is_none() && !self.data.pods.items.is_empty() {
+        self.data.pods.state.select(Some(0));
+      }
+      self.set_status_message("Listing every pod");
+    }
+  }
+
+  /// Regroup the pods as they are now, applying the pods filter to the
+  /// grouped rows too.
+  pub fn refresh_pod_groups(&mut self) {
+    let rows = group_rows(&self.data.pods.items, &self.data.collapsed_pod_groups);
+    self.data.pod_groups.filter = self.data.pods.filter.clone();
+    self.data.pod_groups.filter_active = self.data.pods.filter_active;
+    self.data.pod_groups.set_items(rows);
+  }
+
+  /// Point the pods list at the pod of the selected grouped row, so every
+  /// pod action works as in the flat list. Nothing is selected while a
+  /// header is.
+  pub fn sync_grouped_pod_selection(&mut self) {
+    if !self.pods_grouped() || self.get_current_route().active_block != ActiveBlock::Pods {
+      return;
+    }
+    let index = match self.data.pod_groups.get_selected_item() {
+      Some(PodGroupRow::Pod {
+        name, namespace, ..
+      }) => self
+        .data
+        .pods
+        .items
+        .iter()
+        .position(|pod| &pod.name == name && &pod.namespace == namespace),
+      _ => None,
+    };
+    self.data.pods.filtered_indices.clear();
+    self.data.pods.problems_only = false;
+    self.data.pods.state.select(index);
+  }
+
+  /// Collapse or expand the group whose header is selected. `false` when a
+  /// pod is selected instead.
+  pub fn toggle_selected_pod_group(&mut self) -> bool {
+    let Some(PodGroupRow::Header(group)) = self.data.pod_groups.get_selected_item() else {
+      return false;
+    };
+    let owner = group.owner.clone();
+    if !self.data.collapsed_pod_groups.remove(&owner) {
+      self.data.collapsed_pod_groups.insert(owner);
+    }
+    self.refresh_pod_groups();
+    true
+  }
+
+  /// Collapse or expand the group under the cursor, whether its header or
+  /// one of its pods is selected, leaving the cursor on its header.
+  pub fn toggle_pod_group_at_cursor(&mut self) {
+    let Some(owner) = self
+      .data
+      .pod_groups
+      .get_selected_item()
+      .map(|row| row.owner().clone())
+    else {
+      return;
+    };
+    if !self.data.collapsed_pod_groups.remove(&owner) {
+      self.data.collapsed_pod_groups.insert(owner.clone());
+    }
+    self.refresh_pod_groups();
+    self.select_pod_group_row(
+      |row| matches!(row, PodGroupRow::Header(group) if group.owner == owner),
+    );
+  }
+
+  /// Collapse every group to its header, leaving the cursor on the header of
+  /// the group it was in.
+  pub fn collapse_all_pod_groups(&mut self) {
+    let owner = self
+      .data
+      .pod_groups
+      .get_selected_item()
+      .map(|row| row.owner().clone());
+    let owners = group_rows(&self.data.pods.items, &HashSet::new())
+      .into_iter()
+      .filter_map(|row| match row {
+        PodGroupRow::Header(group) => Some(group.owner),
+        PodGroupRow::Pod { .. } => None,
+      });
+    self.data.collapsed_pod_groups.extend(owners);
+    self.refresh_pod_groups();
+    if let Some(owner) = owner {
+      self.select_pod_group_row(
+        |row| matches!(row, PodGroupRow::Header(group) if group.owner == owner),
+      );
+    }
+    self.set_status_message("Collapsed every group");
+  }
+
+  /// Expand every group, leaving the cursor on the row it was on.
+  pub fn expand_all_pod_groups(&mut self) {
+    let selected = self.data.pod_groups.get_selected_item().map(MarkedRow::of);
+    self.data.collapsed_pod_groups.clear();
+    self.refresh_pod_groups();
+    if let Some(selected) = selected {
+      self.select_pod_group_row(|row| selected.matches(row));
+    }
+    self.set_status_message("Expanded every group");
+  }
+
+  /// Move the cursor onto the first grouped row matching `wanted`, through
+  /// the pods filter when one is on.
+  fn select_pod_group_row(&mut self, wanted: impl Fn(&PodGroupRow) -> bool) {
+    let table = &mut self.data.pod_groups;
+    let Some(real) = table.items.iter().position(wanted) else {
+      return;
+    };
+    let index = if table.filtered_indices.is_empty() && !table.problems_only {
+      Some(real)
+    } else {
+      table.filtered_indices.iter().position(|&i| i == real)
+    };
+    if let Some(index) = index {
+      table.state.select(Some(index));
+      table.selected_row = Some(MarkedRow::of(&table.items[real]));
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use k8s_openapi::{
+    api::core::v1::Pod,
+    apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference},
+  };
+
+  use super::*;
+
+  fn pod(
+    name: &str,
+    owner: Option<(&str, &str)>,
+    hash: Option<&str>,
+    ready: (i32, i32),
+  ) -> KubePod {
+    let mut pod = KubePod::from(Pod {
+      metadata: ObjectMeta {
+        name: Some(name.into()),
+        namespace: Some("shop".into()),
+        labels: hash.map(|hash| BTreeMap::from([(POD_TEMPLATE_HASH.to_owned(), hash.to_owned())])),
+        owner_references: owner.map(|(kind, owner)| {
+          vec![OwnerReference {
+            kind: kind.into(),
+            name: owner.into(),
+            controller: Some(true),
+            ..Default::default()
+          }]
+        }),
+        ..Default::default()
+      },
+      ..Default::default()
+    });
+    pod.status = "Running".into();
+    pod.ready = ready;
+    pod
+  }
+
+  fn pods() -> Vec<KubePod> {
+    vec![
+      pod("debug", None, None, (1, 1)),
+      pod(
+        "web-7d9f-a",
+        Some(("ReplicaSet", "web-7d9f")),
+        Some("7d9f"),
+        (1, 1),
+      ),
+      pod("db-0", Some(("StatefulSet", "db")), None, (0, 1)),
+      pod(
+        "web-7d9f-b",
+        Some(("ReplicaSet", "web-7d9f")),
+        Some("7d9f"),
+        (1, 1),
+      ),
+      pod("legacy-x", Some(("ReplicaSet", "legacy")), None, (1, 1)),
+    ]
+  }
+
+  fn labels(rows: &[PodGroupRow]) -> Vec<String> {
+    rows
+      .iter()
+      .map(|row| match row {
+        PodGroupRow::Header(group) => format!("{} {}", group.label, group.status()),
+        PodGroupRow::Pod { name, .. } => format!("  {}", name),
+      })
+      .collect()
+  }
+
+  #[test]
+  fn test_group_rows_group_pods_under_their_workload() {
+    let rows = group_rows(&pods(), &HashSet::new());
+    assert_eq!(
+      labels(&rows),
+      vec![
+        "Deployment/web 2/2 Ready",
+        "  web-7d9f-a",
+        "  web-7d9f-b",
+        "ReplicaSet/legacy 1/1 Ready",
+        "  legacy-x",
+        "StatefulSet/db 0/1 Ready",
+        "  db-0",
+        "Unmanaged 1/1 Ready",
+        "  debug",
+      ]
+    );
+    assert!(rows[0].is_healthy());
+    assert!(!rows[5].is_healthy());
+    assert!(matches!(
+      rows[2],
+      PodGroupRow::Pod {
+        index: 3,
+        last: true,
+        ..
+      }
+    ));
+  }
+
+  #[test]
+  fn test_collapsed_groups_keep_only_their_header() {
+    let collapsed = HashSet::from([PodOwner {
+      namespace: "shop".into(),
+      kind: "Deployment".into(),
+      name: "web".into(),
+    }]);
+    let rows = group_rows(&pods(), &collapsed);
+    assert_eq!(
+      labels(&rows)[..2],
+      ["Deployment/web 2/2 Ready", "ReplicaSet/legacy 1/1 Ready"]
+    );
+    assert!(matches!(&rows[0], PodGroupRow::Header(group) if group.collapsed));
+  }
+
+  #[test]
+  fn test_grouped_selection_drives_the_pod_actions() {
+    let mut app = App::default();
+    app.route_to_resource_view(ActiveBlock::Pods);
+    app.data.pods.set_items(pods());
+    app.data.pods.state.select(Some(3));
+    app.toggle_pod_grouping();
+    assert!(app.pods_grouped());
+    // web-7d9f-b stays selected
+    assert_eq!(app.data.pod_groups.state.selected(), Some(2));
+
+    app.data.pod_groups.state.select(Some(0));
+    app.sync_grouped_pod_selection();
+    assert_eq!(app.data.pods.get_selected_item(), None);
+    assert!(app.toggle_selected_pod_group());
+    assert_eq!(app.data.pod_groups.items.len(), 7);
+
+    app.data.pod_groups.state.select(Some(4));
+    app.sync_grouped_pod_selection();
+    assert_eq!(app.data.pods.get_selected_item().unwrap().name, "db-0");
+    assert!(!app.toggle_selected_pod_group());
+
+    app.toggle_pod_grouping();
+    assert!(!app.pods_grouped());
+    assert_eq!(app.data.pods.get_selected_item().unwrap().name, "db-0");
+  }
+
+  #[test]
+  fn test_fold_and_expand_all_groups_keep_the_cursor_in_its_group() {
+    let mut app = App::default();
+    app.route_to_resource_view(ActiveBlock::Pods);
+    app.data.pods.set_items(pods());
+    app.toggle_pod_grouping();
+    let selected = |on: &App| labels(&[on.data.pod_groups.get_selected_item().unwrap().clone()]);
+
+    // folding from a pod row folds its group and lands on the header
+    app.data.pod_groups.state.select(Some(2));
+    app.toggle_pod_group_at_cursor();
+    assert_eq!(selected(&app), ["Deployment/web 2/2 Ready"]);
+    assert_eq!(app.data.pod_groups.items.len(), 7);
+
+    app.data.pod_groups.state.select(Some(4));
+    app.collapse_all_pod_groups();
+    assert_eq!(app.data.pod_groups.items.len(), 4);
+    assert_eq!(selected(&app), ["StatefulSet/db 0/1 Ready"]);
+    assert_eq!(app.status_message.text(), "Collapsed every group");
+
+    // folded groups stay folded when the pods are re-polled in another order
+    let mut repolled = pods();
+    repolled.reverse();
+    app.data.pods.set_items(repolled);
+    app.refresh_pod_groups();
+    assert_eq!(app.data.pod_groups.items.len(), 4);
+
+    app.toggle_pod_group_at_cursor();
+    assert_eq!(app.data.pod_groups.items.len(), 5);
+    app.data.pod_groups.state.select(Some(3));
+    assert_eq!(selected(&app), ["  db-0"]);
+    app.expand_all_pod_groups();
+    assert!(app.data.collapsed_pod_groups.is_empty());
+    assert_eq!(app.data.pod_groups.items.len(), 9);
+    assert_eq!(selected(&app), ["  db-0"]);
+  }
+}
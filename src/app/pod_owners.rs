@@ -0,0 +1,158 @@
+//! The controllers that own a described pod, from its immediate owner up to
+//! the root one, e.g. `ReplicaSet/web-7f9c` under `Deployment/web`. Each
+//! step follows the controller owner reference of the object before it, so
+//! operators that own ordinary workloads show up as the root. The walk stops
+//! at a node, which owns static pods, at an owner that cannot be read, or after
+//! [`MAX_OWNER_DEPTH`] steps.
+use anyhow::anyhow;
+use k8s_openapi::{api::core::v1::Pod, apimachinery::pkg::apis::meta::v1::OwnerReference};
+use kube::{
+  core::{ApiResource, DynamicObject, GroupVersion},
+  Api,
+};
+use log::warn;
+
+use super::{models::KubeResource, pods::KubePod};
+use crate::network::Network;
+
+/// Owner references followed before giving up on finding the root.
+pub const MAX_OWNER_DEPTH: usize = 10;
+
+/// The controller chain of one pod, immediate owner first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PodControllers {
+  pub namespace: String,
+  pub pod: String,
+  /// `Kind/name` of each controller, e.g. `ReplicaSet/web-7f9c`.
+  pub chain: Vec<String>,
+}
+
+impl PodControllers {
+  /// Only the pod's own controller, shown until the chain is fetched.
+  pub fn immediate(pod: &KubePod) -> Self {
+    Self {
+      namespace: pod.namespace.clone(),
+      pod: pod.name.clone(),
+      chain: controller_ref(pod.get_k8s_obj().metadata.owner_references.as_deref())
+        .map(owner_label)
+        .into_iter()
+        .collect(),
+    }
+  }
+
+  pub fn is_for(&self, namespace: &str, pod: &str) -> bool {
+    self.namespace == namespace && self.pod == pod
+  }
+
+  /// `Deployment/web (via ReplicaSet/web-7f9c)`, the controllers between the
+  /// root and the pod listed from the root down, or `none` for a pod no
+  /// controller owns.
+  pub fn label(&self) -> String {
+    match self.chain.split_last() {
+      None => "none".to_owned(),
+      Some((root, [])) => root.clone(),
+      Some((root, below)) => {
+        let below: Vec<&str> = below.iter().rev().map(String::as_str).collect();
+        format!("{} (via {})", root, below.join(", "))
+      }
+    }
+  }
+}
+
+/// The owner reference marked as the controller, if any.
+pub fn controller_ref(owners: Option<&[OwnerReference]>) -> Option<&OwnerReference> {
+  owners?.iter().find(|owner| owner.controller == Some(true))
+}
+
+/// `Kind/name` of an owner reference.
+pub fn owner_label(owner: &OwnerReference) -> String {
+  format!("{}/{}", owner.kind, owner.name)
+}
+
+/// Walk the controller owner references of pod `name` up to the root one.
+pub async fn get_pod_controllers(nw: &Network<'_>, name: &str, namespace: &str) {
+  let pods: Api<Pod> = Api::namespaced(nw.client.clone(), namespace);
+  let pod = match pods.get(name).await {
+    Ok(pod) => pod,
+    Err(e) => {
+      nw.handle_error(anyhow!("Failed to get pod {}. {}", name, e))
+        .await;
+      return;
+    }
+  };
+  let mut chain = vec![];
+  let mut owner = controller_ref(pod.metadata.owner_references.as_deref()).cloned();
+  while let Some(current) = owner.take() {
+    chain.push(owner_label(&current));
+    if current.kind == "Node" || chain.len() >= MAX_OWNER_DEPTH {
+      break;
+    }
+    let Ok(group_version) = current.api_version.parse::<GroupVersion>() else {
+      break;
+    };
+    let resource = ApiResource::from_gvk(&group_version.with_kind(&current.kind));
+    let api: Api<DynamicObject> = Api::namespaced_with(nw.client.clone(), namespace, &resource);
+    match api.get(&current.name).await {
+      Ok(object) => owner = controller_ref(object.metadata.owner_references.as_deref()).cloned(),
+      // the owner's kind may be cluster-scoped or not readable with our RBAC;
+      // what was found so far is still worth showing
+      Err(e) => warn!("Stopped at owner {}: {}", owner_label(&current), e),
+    }
+  }
+
+  let mut app = nw.app.lock().await;
+  app.data.pod_controllers = Some(PodControllers {
+    namespace: namespace.to_owned(),
+    pod: name.to_owned(),
+    chain,
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn owner(kind: &str, name: &str, controller: bool) -> OwnerReference {
+    OwnerReference {
+      api_version: "apps/v1".into(),
+      kind: kind.into(),
+      name: name.into(),
+      controller: Some(controller),
+      ..OwnerReference::default()
+    }
+  }
+
+  #[test]
+  fn test_controller_ref_skips_owners_that_are_not_the_controller() {
+    let owners = [
+      owner("ConfigMap", "settings", false),
+      owner("ReplicaSet", "web-7f9c", true),
+    ];
+    assert_eq!(
+      controller_ref(Some(&owners)).map(owner_label).as_deref(),
+      Some("ReplicaSet/web-7f9c")
+    );
+    assert_eq!(controller_ref(Some(&owners[..1])), None);
+    assert_eq!(controller_ref(None), None);
+  }
+
+  #[test]
+  fn test_label_names_the_root_controller_first() {
+    let mut controllers = PodControllers {
+      namespace: "shop".into(),
+      pod: "web-7f9c-abcde".into(),
+      chain: vec![],
+    };
+    assert_eq!(controllers.label(), "none");
+    controllers.chain.push("Job/backup-123".into());
+    assert_eq!(controllers.label(), "Job/backup-123");
+    controllers.chain.push("CronJob/backup".into());
+    controllers.chain.push("Backup/nightly".into());
+    assert_eq!(
+      controllers.label(),
+      "Backup/nightly (via CronJob/backup, Job/backup-123)"
+    );
+    assert!(controllers.is_for("shop", "web-7f9c-abcde"));
+    assert!(!controllers.is_for("default", "web-7f9c-abcde"));
+  }
+}
@@ -1,31 +1,51 @@
+use anyhow::anyhow;
 use async_trait::async_trait;
+use std::str::FromStr;
+
 use chrono::{DateTime, Utc};
-use k8s_openapi::api::core::v1::{
-  Container, ContainerPort, ContainerState, ContainerStateWaiting, ContainerStatus, Pod, PodSpec,
-  PodStatus,
+use k8s_openapi::{
+  api::core::v1::{
+    Container, ContainerPort, ContainerState, ContainerStateWaiting, ContainerStatus,
+    EphemeralContainer, NodeSelectorRequirement, NodeSelectorTerm, Pod, PodAffinityTerm, PodSpec,
+    PodStatus, Probe, Toleration, Volume, VolumeMount, WeightedPodAffinityTerm,
+  },
+  apimachinery::pkg::{apis::meta::v1::LabelSelector, util::intstr::IntOrString},
+  ByteString,
 };
+use kube::api::{Api, EvictParams, ObjectMeta};
+use kubectl_view_allocations::qty::Qty;
 use ratatui::{
-  layout::Rect,
-  style::Style,
-  widgets::{Cell, Row},
+  layout::{Constraint, Rect},
+  style::{Modifier, Style},
+  text::{Line, Span},
+  widgets::{Cell, Paragraph, Wrap},
   Frame,
 };
 
 use super::{
+  events::PodEvents,
+  health::Health,
   key_binding::DEFAULT_KEYBINDING,
-  models::{AppResource, KubeResource, Named},
+  models::{AppResource, JsonLogFormat, KubeResource, Named, Scrollable, ScrollableTxt},
+  node_pods::node_allocatable_line,
+  pod_groups::PodGroupRow,
+  pod_owners::PodControllers,
+  redact::Redactor,
+  secrets::KubeSecret,
   utils::{self, UNKNOWN},
   ActiveBlock, App,
 };
 use crate::{
   network::Network,
+  ui::glyphs::glyphs,
   ui::theme::Palette,
   ui::utils::{
     action_hint, copy_and_escape_title_line, copy_scroll_and_escape_title_line,
-    describe_yaml_and_logs_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, help_part, layout_block_top_border,
-    loading, mixed_bold_line, responsive_columns, style_caution, style_failure, style_help,
-    style_success, style_text, title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps,
+    describe_yaml_and_logs_hint, draw_describe_block, draw_diff_block, draw_resource_block,
+    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, help_part,
+    layout_block_top_border, loading, mixed_bold_line, style_caution, style_failure, style_help,
+    style_label, style_primary, style_secondary, style_success, style_text, style_warning,
+    title_style, title_with_dual_style, vertical_chunks, wide_hint, ColumnDef, ResourceTableProps,
     ViewTier,
   },
 };
@@ -37,8 +57,21 @@ pub struct KubePod {
   pub ready: (i32, i32),
   pub status: String,
   pub restarts: i32,
+  /// Age of the most recent container restart, set only while it is within
+  /// [`RECENT_RESTART_WINDOW`] so an old count reads apart from an active loop.
+  pub last_restart: Option<String>,
+  /// Time since the newest container start, `-` while none is running, so a
+  /// pod created days ago but restarted a minute ago reads as up a minute.
+  pub uptime: String,
   pub cpu: String,
   pub mem: String,
+  /// Sum of the regular containers' requests and limits, `-` when none is set;
+  /// limits read `≥` the sum when some container has none.
+  pub cpu_requests: String,
+  pub cpu_limits: String,
+  pub mem_requests: String,
+  pub mem_limits: String,
+  pub qos: QosClass,
   pub node: String,
   pub ip: String,
   pub age: String,
@@ -49,19 +82,515 @@ pub struct KubePod {
 #[derive(Clone, Default, Debug, PartialEq)]
 pub struct KubeContainer {
   pub name: String,
+  /// The image in the pod spec.
   pub image: String,
+  /// The image the container status reports, empty before it has started.
+  /// Differs from `image` while a new image is being pulled or rolled out.
+  pub running_image: String,
+  pub pull_policy: String,
   pub ready: String,
+  /// The reason for the state, e.g. `CrashLoopBackOff`, or `Running`.
   pub status: String,
+  pub state: ContainerStateKind,
   pub restarts: i32,
+  /// How the previous instance ended, from `lastState.terminated`.
+  pub last_termination: Option<LastTermination>,
   pub liveliness_probe: bool,
   pub readiness_probe: bool,
+  /// Each configured probe, in readiness, liveness, startup order.
+  pub probes: Vec<ContainerProbe>,
+  /// Volume mounts in spec order, each with the pod volume behind it.
+  pub mounts: Vec<ContainerMount>,
+  /// Environment variables and `envFrom` sources, with where each comes from.
+  pub env: Vec<ContainerEnv>,
   pub ports: String,
+  pub cpu_requests: String,
+  pub cpu_limits: String,
+  pub mem_requests: String,
+  pub mem_limits: String,
   pub age: String,
   pub pod_name: String,
-  pub init: bool,
+  pub kind: ContainerKind,
   k8s_obj: Option<Container>,
 }
 
+/// Why a container's previous instance stopped, so a crash reads at a glance
+/// without describing the pod.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LastTermination {
+  /// e.g. `OOMKilled`, `Error` or `Completed`.
+  pub reason: String,
+  pub exit_code: i32,
+  /// Age of `finishedAt`, empty when unknown.
+  pub finished: String,
+}
+
+impl LastTermination {
+  fn from_api(status: &ContainerStatus, now: DateTime<Utc>) -> Option<Self> {
+    let terminated = status.last_state.as_ref()?.terminated.as_ref()?;
+    Some(LastTermination {
+      reason: terminated
+        .reason
+        .clone()
+        .unwrap_or_else(|| format!("exit {}", terminated.exit_code)),
+      exit_code: terminated.exit_code,
+      finished: terminated
+        .finished_at
+        .as_ref()
+        .map(|at| utils::to_age(Some(at), now))
+        .unwrap_or_default(),
+    })
+  }
+
+  /// The kernel killed it for going over its memory limit.
+  pub fn is_oom_killed(&self) -> bool {
+    self.reason == "OOMKilled"
+  }
+
+  /// OOM kills stand out from other crashes as they point at the memory
+  /// limit; clean exits stay quiet.
+  pub fn style(&self, palette: Palette) -> Style {
+    if self.is_oom_killed() {
+      style_failure(palette).add_modifier(Modifier::BOLD)
+    } else if self.exit_code == 0 {
+      style_help(palette)
+    } else {
+      style_caution(palette)
+    }
+  }
+}
+
+/// A readiness, liveness or startup probe, laid out like `kubectl describe`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContainerProbe {
+  pub kind: &'static str,
+  /// What the kubelet checks, e.g. `http-get http://:8080/healthz`.
+  pub action: String,
+  /// e.g. `delay=0s timeout=1s period=10s #success=1 #failure=3`.
+  pub timing: String,
+}
+
+impl ContainerProbe {
+  fn from_api(kind: &'static str, probe: &Probe) -> Self {
+    let port = |port: &IntOrString| match port {
+      IntOrString::Int(port) => port.to_string(),
+      IntOrString::String(name) => name.clone(),
+    };
+    let action = if let Some(http) = &probe.http_get {
+      format!(
+        "http-get {}://{}:{}{}",
+        http.scheme.as_deref().unwrap_or("HTTP").to_lowercase(),
+        http.host.as_deref().unwrap_or_default(),
+        port(&http.port),
+        http.path.as_deref().unwrap_or("/")
+      )
+    } else if let Some(tcp) = &probe.tcp_socket {
+      format!(
+        "tcp-socket {}:{}",
+        tcp.host.as_deref().unwrap_or_default(),
+        port(&tcp.port)
+      )
+    } else if let Some(exec) = &probe.exec {
+      format!(
+        "exec [{}]",
+        exec
+          .command
+          .iter()
+          .flatten()
+          .cloned()
+          .collect::<Vec<_>>()
+          .join(" ")
+      )
+    } else if let Some(grpc) = &probe.grpc {
+      match grpc
+        .service
+        .as_deref()
+        .filter(|service| !service.is_empty())
+      {
+        Some(service) => format!("grpc <pod>:{} {}", grpc.port, service),
+        None => format!("grpc <pod>:{}", grpc.port),
+      }
+    } else {
+      "unknown".to_owned()
+    };
+    // Unset fields take the API defaults, as `kubectl describe` shows them.
+    let timing = format!(
+      "delay={}s timeout={}s period={}s #success={} #failure={}",
+      probe.initial_delay_seconds.unwrap_or(0),
+      probe.timeout_seconds.unwrap_or(1),
+      probe.period_seconds.unwrap_or(10),
+      probe.success_threshold.unwrap_or(1),
+      probe.failure_threshold.unwrap_or(3)
+    );
+    ContainerProbe {
+      kind,
+      action,
+      timing,
+    }
+  }
+
+  fn list(container: &Container) -> Vec<Self> {
+    [
+      ("Readiness", &container.readiness_probe),
+      ("Liveness", &container.liveness_probe),
+      ("Startup", &container.startup_probe),
+    ]
+    .into_iter()
+    .filter_map(|(kind, probe)| probe.as_ref().map(|probe| Self::from_api(kind, probe)))
+    .collect()
+  }
+}
+
+/// A volume mount joined with the pod volume it mounts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContainerMount {
+  pub name: String,
+  /// The mount path, with the sub path when only part of the volume is mounted.
+  pub path: String,
+  pub read_only: bool,
+  /// The volume kind and where its data comes from, e.g. `configMap app-config`.
+  pub source: String,
+}
+
+impl ContainerMount {
+  fn list(mounts: Option<&[VolumeMount]>, volumes: &[Volume]) -> Vec<Self> {
+    mounts
+      .unwrap_or_default()
+      .iter()
+      .map(|mount| {
+        let sub_path = mount
+          .sub_path
+          .as_deref()
+          .or(mount.sub_path_expr.as_deref())
+          .filter(|sub_path| !sub_path.is_empty());
+        ContainerMount {
+          name: mount.name.clone(),
+          path: match sub_path {
+            Some(sub_path) => format!("{} (subPath {})", mount.mount_path, sub_path),
+            None => mount.mount_path.clone(),
+          },
+          read_only: mount.read_only.unwrap_or(false),
+          source: volumes
+            .iter()
+            .find(|volume| volume.name == mount.name)
+            .map_or_else(|| "not in pod volumes".to_owned(), volume_source),
+        }
+      })
+      .collect()
+  }
+
+  /// One line per mount with the columns aligned: name, path, ro/rw, source.
+  pub fn lines(mounts: &[Self]) -> Vec<String> {
+    let name_width = mounts.iter().map(|m| m.name.len()).max().unwrap_or(0);
+    let path_width = mounts.iter().map(|m| m.path.len()).max().unwrap_or(0);
+    mounts
+      .iter()
+      .map(|m| {
+        format!(
+          "{:<name_width$}  {:<path_width$}  {}  {}",
+          m.name,
+          m.path,
+          if m.read_only { "ro" } else { "rw" },
+          m.source
+        )
+      })
+      .collect()
+  }
+}
+
+/// The volume kind as it is spelled in the pod spec, with its source.
+fn volume_source(volume: &Volume) -> String {
+  if let Some(cm) = &volume.config_map {
+    format!("configMap {}", cm.name)
+  } else if let Some(secret) = &volume.secret {
+    format!(
+      "secret {}",
+      secret.secret_name.as_deref().unwrap_or_default()
+    )
+  } else if let Some(pvc) = &volume.persistent_volume_claim {
+    format!("persistentVolumeClaim {}", pvc.claim_name)
+  } else if let Some(empty_dir) = &volume.empty_dir {
+    match empty_dir.medium.as_deref().filter(|m| !m.is_empty()) {
+      Some(medium) => format!("emptyDir ({})", medium),
+      None => "emptyDir".to_owned(),
+    }
+  } else if let Some(host_path) = &volume.host_path {
+    format!("hostPath {}", host_path.path)
+  } else if let Some(projected) = &volume.projected {
+    let sources: Vec<String> = projected
+      .sources
+      .iter()
+      .flatten()
+      .filter_map(|source| {
+        if let Some(cm) = &source.config_map {
+          Some(format!("configMap {}", cm.name))
+        } else if let Some(secret) = &source.secret {
+          Some(format!("secret {}", secret.name))
+        } else if source.service_account_token.is_some() {
+          Some("serviceAccountToken".to_owned())
+        } else if source.downward_api.is_some() {
+          Some("downwardAPI".to_owned())
+        } else {
+          source
+            .cluster_trust_bundle
+            .as_ref()
+            .map(|_| "clusterTrustBundle".to_owned())
+        }
+      })
+      .collect();
+    format!("projected ({})", sources.join(", "))
+  } else if volume.downward_api.is_some() {
+    "downwardAPI".to_owned()
+  } else if let Some(ephemeral) = &volume.ephemeral {
+    match ephemeral
+      .volume_claim_template
+      .as_ref()
+      .and_then(|t| t.spec.storage_class_name.as_deref())
+    {
+      Some(class) => format!("ephemeral (storageClass {})", class),
+      None => "ephemeral".to_owned(),
+    }
+  } else if let Some(csi) = &volume.csi {
+    format!("csi {}", csi.driver)
+  } else if let Some(nfs) = &volume.nfs {
+    format!("nfs {}:{}", nfs.server, nfs.path)
+  } else if let Some(image) = &volume.image {
+    format!("image {}", image.reference.as_deref().unwrap_or_default())
+  } else {
+    "other".to_owned()
+  }
+}
+
+/// Where an environment variable of a container gets its value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EnvSource {
+  /// A literal `value` in the spec.
+  Value(String),
+  ConfigMapKey {
+    name: String,
+    key: String,
+  },
+  SecretKey {
+    name: String,
+    key: String,
+  },
+  /// `fieldRef`, e.g. `metadata.name`.
+  Field(String),
+  /// `resourceFieldRef`, e.g. `limits.memory`.
+  Resource(String),
+  /// `envFrom`: every key of a ConfigMap, behind an optional prefix.
+  AllOfConfigMap {
+    name: String,
+    prefix: String,
+  },
+  /// `envFrom`: every key of a Secret, behind an optional prefix.
+  AllOfSecret {
+    name: String,
+    prefix: String,
+  },
+}
+
+/// An environment variable, or a whole `envFrom` source, of a container.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContainerEnv {
+  /// Empty for `envFrom` sources.
+  pub name: String,
+  pub source: EnvSource,
+}
+
+impl ContainerEnv {
+  /// `envFrom` sources first, as `kubectl describe` lists them, then `env`.
+  fn list(container: &Container) -> Vec<Self> {
+    let env_from = container.env_from.iter().flatten().filter_map(|from| {
+      let prefix = from.prefix.clone().unwrap_or_default();
+      let source = if let Some(cm) = &from.config_map_ref {
+        EnvSource::AllOfConfigMap {
+          name: cm.name.clone(),
+          prefix,
+        }
+      } else {
+        EnvSource::AllOfSecret {
+          name: from.secret_ref.as_ref()?.name.clone(),
+          prefix,
+        }
+      };
+      Some(ContainerEnv {
+        name: String::new(),
+        source,
+      })
+    });
+    let env = container.env.iter().flatten().map(|var| {
+      let from = var.value_from.as_ref();
+      let source = if let Some(cm) = from.and_then(|f| f.config_map_key_ref.as_ref()) {
+        EnvSource::ConfigMapKey {
+          name: cm.name.clone(),
+          key: cm.key.clone(),
+        }
+      } else if let Some(secret) = from.and_then(|f| f.secret_key_ref.as_ref()) {
+        EnvSource::SecretKey {
+          name: secret.name.clone(),
+          key: secret.key.clone(),
+        }
+      } else if let Some(field) = from.and_then(|f| f.field_ref.as_ref()) {
+        EnvSource::Field(field.field_path.clone())
+      } else if let Some(resource) = from.and_then(|f| f.resource_field_ref.as_ref()) {
+        EnvSource::Resource(resource.resource.clone())
+      } else {
+        EnvSource::Value(var.value.clone().unwrap_or_default())
+      };
+      ContainerEnv {
+        name: var.name.clone(),
+        source,
+      }
+    });
+    env_from.chain(env).collect()
+  }
+
+  pub fn is_secret(&self) -> bool {
+    matches!(
+      self.source,
+      EnvSource::SecretKey { .. } | EnvSource::AllOfSecret { .. }
+    )
+  }
+
+  /// One line per variable with the names aligned, e.g.
+  /// `DB_PASSWORD  from secret db-creds key password`. Secret values are
+  /// only shown when `secrets` is given, looked up in those of `namespace`.
+  pub fn lines(env: &[Self], secrets: Option<&[KubeSecret]>, namespace: &str) -> Vec<String> {
+    let secret = |name: &str| {
+      secrets.map(|secrets| {
+        secrets
+          .iter()
+          .find(|s| s.name == name && s.namespace == namespace)
+      })
+    };
+    let decoded = |bytes: &ByteString| String::from_utf8_lossy(&bytes.0).into_owned();
+    let name_width = env.iter().map(|e| e.name.len()).max().unwrap_or(0);
+    let mut lines = vec![];
+    for var in env {
+      let source = match &var.source {
+        EnvSource::Value(value) => value.clone(),
+        EnvSource::ConfigMapKey { name, key } => format!("from configMap {} key {}", name, key),
+        EnvSource::SecretKey { name, key } => {
+          let source = format!("from secret {} key {}", name, key);
+          match secret(name) {
+            Some(Some(s)) => match s.data.get(key) {
+              Some(v) => format!("{} = {}", source, decoded(v)),
+              None => format!("{} = (no such key)", source),
+            },
+            Some(None) => format!("{} = (secret not loaded)", source),
+            None => source,
+          }
+        }
+        EnvSource::Field(path) => format!("from field {}", path),
+        EnvSource::Resource(resource) => format!("from resource {}", resource),
+        EnvSource::AllOfConfigMap { name, prefix } => {
+          lines.push(all_of("configMap", name, prefix));
+          continue;
+        }
+        EnvSource::AllOfSecret { name, prefix } => {
+          let header = all_of("secret", name, prefix);
+          match secret(name) {
+            Some(Some(s)) => {
+              lines.push(header);
+              lines.extend(
+                s.data
+                  .iter()
+                  .map(|(key, v)| format!("  {}{} = {}", prefix, key, decoded(v))),
+              );
+            }
+            Some(None) => lines.push(format!("{} (secret not loaded)", header)),
+            None => lines.push(header),
+          }
+          continue;
+        }
+      };
+      lines.push(format!("{:<name_width$}  {}", var.name, source));
+    }
+    lines
+  }
+}
+
+fn all_of(kind: &str, name: &str, prefix: &str) -> String {
+  if prefix.is_empty() {
+    format!("all of {} {}", kind, name)
+  } else {
+    format!("all of {} {}, prefixed {}", kind, name, prefix)
+  }
+}
+
+/// The expanded environment block of the selected container.
+#[derive(Debug)]
+pub struct ContainerEnvView {
+  pub txt: ScrollableTxt,
+  /// Show the values of secret references.
+  pub reveal: bool,
+}
+
+/// Quality of service class, which decides eviction order under node pressure:
+/// BestEffort pods go first, Guaranteed pods last.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum QosClass {
+  Guaranteed,
+  Burstable,
+  #[default]
+  BestEffort,
+}
+
+impl QosClass {
+  pub fn label(self) -> &'static str {
+    match self {
+      QosClass::Guaranteed => "Guaranteed",
+      QosClass::Burstable => "Burstable",
+      QosClass::BestEffort => "BestEffort",
+    }
+  }
+}
+
+/// Where a container sits in the pod spec. Init containers run to completion
+/// before the main containers start; ephemeral containers are added later by
+/// `kubectl debug`.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum ContainerKind {
+  #[default]
+  Regular,
+  Init,
+  Ephemeral,
+}
+
+/// Which of the states the API reports a container is in; the reason for it
+/// is kept in `KubeContainer::status`.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum ContainerStateKind {
+  /// No status reported yet.
+  #[default]
+  Unknown,
+  Waiting,
+  Running,
+  Terminated,
+}
+
+impl ContainerStateKind {
+  pub fn label(self) -> &'static str {
+    match self {
+      ContainerStateKind::Unknown => "<none>",
+      ContainerStateKind::Waiting => "Waiting",
+      ContainerStateKind::Running => "Running",
+      ContainerStateKind::Terminated => "Terminated",
+    }
+  }
+}
+
+impl ContainerKind {
+  pub fn label(self) -> &'static str {
+    match self {
+      ContainerKind::Regular => "",
+      ContainerKind::Init => "init",
+      ContainerKind::Ephemeral => "ephemeral",
+    }
+  }
+}
+
 impl Named for KubeContainer {
   fn get_name(&self) -> &String {
     &self.name
@@ -90,6 +619,11 @@ impl KubePod {
       .map(|spec| spec.containers.clone())
       .unwrap_or_default();
     let ready_total = main_containers.len() as i32;
+    let volumes = pod
+      .spec
+      .as_ref()
+      .and_then(|spec| spec.volumes.clone())
+      .unwrap_or_default();
     let (status, cr, restarts, ready_count, containers) = match &pod.status {
       Some(status) => {
         let (mut cr, mut rc) = (0, 0);
@@ -111,7 +645,9 @@ impl KubePod {
               pod_name.to_owned(),
               age.to_owned(),
               &status.container_statuses,
-              false,
+              ContainerKind::Regular,
+              &volumes,
+              now,
             )
           })
           .collect();
@@ -130,13 +666,37 @@ impl KubePod {
               pod_name.to_owned(),
               age.to_owned(),
               &status.init_container_statuses,
-              true,
+              ContainerKind::Init,
+              &volumes,
+              now,
             )
           })
           .collect();
 
-        // merge containers and init-containers into single array
+        let mut ephemeral_containers: Vec<KubeContainer> = pod
+          .spec
+          .as_ref()
+          .and_then(|spec| spec.ephemeral_containers.as_ref())
+          .map(|ephemeral| {
+            ephemeral
+              .iter()
+              .map(|c| {
+                KubeContainer::from_ephemeral(
+                  c,
+                  pod_name.to_owned(),
+                  age.to_owned(),
+                  &status.ephemeral_container_statuses,
+                  &volumes,
+                  now,
+                )
+              })
+              .collect()
+          })
+          .unwrap_or_default();
+
+        // merge containers, init-containers and ephemeral containers into single array
         containers.append(&mut init_containers);
+        containers.append(&mut ephemeral_containers);
 
         let status_name = get_status(status, &pod);
         let ready_count = if has_container_statuses || !is_pending_like_status(&status_name) {
@@ -159,9 +719,16 @@ impl KubePod {
       namespace: pod.metadata.namespace.clone().unwrap_or_default(),
       ready: (cr, ready_count),
       restarts,
+      last_restart: get_recent_restart(&pod, now),
+      uptime: get_uptime(&pod, now),
       // TODO implement pod metrics
       cpu: String::default(),
       mem: String::default(),
+      cpu_requests: format_cpu(sum_quantities(&main_containers, Bound::Requests, CPU)),
+      cpu_limits: format_limits(&main_containers, CPU, format_cpu),
+      mem_requests: format_mem(sum_quantities(&main_containers, Bound::Requests, MEMORY)),
+      mem_limits: format_limits(&main_containers, MEMORY, format_mem),
+      qos: pod.spec.as_ref().map(get_qos_class).unwrap_or_default(),
       node: pod
         .spec
         .as_ref()
@@ -184,6 +751,413 @@ impl Named for KubePod {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
+
+  /// Creation, condition transitions and container starts and exits, so a
+  /// restarted container marks its pod.
+  fn get_changed(&self) -> Option<DateTime<Utc>> {
+    let status = self.k8s_obj.status.as_ref();
+    let conditions = status
+      .and_then(|s| s.conditions.as_ref())
+      .into_iter()
+      .flatten()
+      .filter_map(|c| c.last_transition_time.as_ref());
+    let containers = status
+      .and_then(|s| s.container_statuses.as_ref())
+      .into_iter()
+      .flatten()
+      .flat_map(|c| {
+        let running = c.state.as_ref().and_then(|s| s.running.as_ref());
+        let terminated = c.state.as_ref().and_then(|s| s.terminated.as_ref());
+        [
+          running.and_then(|r| r.started_at.as_ref()),
+          terminated.and_then(|t| t.finished_at.as_ref()),
+        ]
+      })
+      .flatten();
+    utils::latest_time(
+      self
+        .k8s_obj
+        .metadata
+        .creation_timestamp
+        .iter()
+        .chain(conditions)
+        .chain(containers),
+    )
+  }
+}
+
+/// A pod condition, or a readiness gate the pod is still waiting to hear about.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodCondition {
+  pub type_: String,
+  /// `True`, `False` or `Unknown`; `None` for a readiness gate whose
+  /// condition nothing has reported yet.
+  pub status: Option<String>,
+  pub reason: String,
+  /// Why the condition does not hold, e.g. `containers with unready status: [web]`.
+  pub message: String,
+  /// Listed under `spec.readinessGates`, so Ready waits for it.
+  pub readiness_gate: bool,
+}
+
+impl PodCondition {
+  pub fn holds(&self) -> bool {
+    self.status.as_deref() == Some("True")
+  }
+
+  /// `Ready: False  ContainersNotReady: containers with unready status: [web]`,
+  /// or `gate example.com/lb-registered: not reported`.
+  pub fn detail(&self) -> String {
+    let gate = if self.readiness_gate { "gate " } else { "" };
+    let status = self.status.as_deref().unwrap_or("not reported");
+    let explanation = [self.reason.as_str(), self.message.as_str()]
+      .into_iter()
+      .filter(|part| !part.is_empty())
+      .collect::<Vec<_>>()
+      .join(": ");
+    if explanation.is_empty() {
+      format!("{}{}: {}", gate, self.type_, status)
+    } else {
+      format!("{}{}: {}  {}", gate, self.type_, status, explanation)
+    }
+  }
+}
+
+/// The scheduler pods without a `spec.schedulerName` get.
+const DEFAULT_SCHEDULER: &str = "default-scheduler";
+
+/// Where a pod runs, or is about to, and what the scheduler said about it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PodScheduling {
+  /// `spec.nodeName`, empty until the pod is bound.
+  pub node: String,
+  /// `status.nominatedNodeName`, set while preemption makes room for the pod.
+  pub nominated_node: String,
+  /// The `PodScheduled` condition's status, e.g. `True`, if it is reported.
+  pub scheduled: Option<String>,
+  pub reason: String,
+  /// The scheduler's explanation, e.g. `0/5 nodes are available: ...`.
+  pub message: String,
+  /// `spec.schedulerName`.
+  pub scheduler: String,
+}
+
+impl PodScheduling {
+  /// Whether a scheduler other than the cluster's default places the pod.
+  pub fn has_custom_scheduler(&self) -> bool {
+    self.scheduler != DEFAULT_SCHEDULER
+  }
+
+  pub fn is_failing(&self) -> bool {
+    self.scheduled.as_deref() == Some("False")
+  }
+}
+
+/// The pod a live watch follows while its containers are shown.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WatchedPod {
+  pub namespace: String,
+  pub name: String,
+}
+
+impl KubePod {
+  /// Whether a controller, such as a ReplicaSet or StatefulSet, owns the pod
+  /// and would recreate it when deleted.
+  pub fn is_controller_managed(&self) -> bool {
+    self
+      .k8s_obj
+      .metadata
+      .owner_references
+      .iter()
+      .flatten()
+      .any(|owner| owner.controller == Some(true))
+  }
+
+  /// Whether a container of the pod, init containers included, is in
+  /// `CrashLoopBackOff`.
+  pub fn is_crash_looping(&self) -> bool {
+    self.status.ends_with("CrashLoopBackOff")
+  }
+
+  /// Whether the pod has been deleted and is shutting down.
+  pub fn is_terminating(&self) -> bool {
+    self.k8s_obj.metadata.deletion_timestamp.is_some()
+  }
+
+  /// Each status condition's type and whether it holds, in status order,
+  /// followed by the readiness gates nothing has reported on.
+  pub fn conditions(&self) -> Vec<(String, bool)> {
+    self
+      .condition_details()
+      .into_iter()
+      .map(|c| {
+        let holds = c.holds();
+        (c.type_, holds)
+      })
+      .collect()
+  }
+
+  /// The status conditions, with custom readiness gate conditions marked,
+  /// then the gates that have no condition yet. A gate without its condition
+  /// keeps the pod from turning Ready with nothing else hinting at why.
+  pub fn condition_details(&self) -> Vec<PodCondition> {
+    let gates: Vec<&str> = self
+      .k8s_obj
+      .spec
+      .as_ref()
+      .and_then(|spec| spec.readiness_gates.as_ref())
+      .into_iter()
+      .flatten()
+      .map(|gate| gate.condition_type.as_str())
+      .collect();
+    let status_conditions = self
+      .k8s_obj
+      .status
+      .as_ref()
+      .and_then(|status| status.conditions.as_ref());
+    let mut conditions: Vec<PodCondition> = status_conditions
+      .into_iter()
+      .flatten()
+      .map(|c| PodCondition {
+        type_: c.type_.clone(),
+        status: Some(c.status.clone()),
+        reason: c.reason.clone().unwrap_or_default(),
+        message: c.message.clone().unwrap_or_default(),
+        readiness_gate: gates.contains(&c.type_.as_str()),
+      })
+      .collect();
+    for gate in gates {
+      if !conditions.iter().any(|c| c.type_ == gate) {
+        conditions.push(PodCondition {
+          type_: gate.to_owned(),
+          readiness_gate: true,
+          ..PodCondition::default()
+        });
+      }
+    }
+    conditions
+  }
+
+  pub fn scheduling(&self) -> PodScheduling {
+    let status = self.k8s_obj.status.as_ref();
+    let condition = status
+      .and_then(|status| status.conditions.as_ref())
+      .and_then(|conditions| conditions.iter().find(|c| c.type_ == "PodScheduled"));
+    PodScheduling {
+      node: self.node.clone(),
+      nominated_node: status
+        .and_then(|status| status.nominated_node_name.clone())
+        .unwrap_or_default(),
+      scheduled: condition.map(|c| c.status.clone()),
+      reason: condition.and_then(|c| c.reason.clone()).unwrap_or_default(),
+      message: condition
+        .and_then(|c| c.message.clone())
+        .unwrap_or_default(),
+      scheduler: self
+        .k8s_obj
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.scheduler_name.clone())
+        .unwrap_or_else(|| DEFAULT_SCHEDULER.to_owned()),
+    }
+  }
+  /// The pod's node selector, tolerations and affinity rules, one line per
+  /// rule under a heading per kind. Empty when the pod sets none of them.
+  pub fn scheduling_constraints(&self) -> Vec<String> {
+    let Some(spec) = self.k8s_obj.spec.as_ref() else {
+      return vec![];
+    };
+    let mut lines = vec![];
+    let mut section = |heading: &str, rules: Vec<String>| {
+      if !rules.is_empty() {
+        lines.push(heading.to_owned());
+        lines.extend(rules.into_iter().map(|rule| format!("  {rule}")));
+      }
+    };
+
+    section(
+      "Node selector",
+      spec
+        .node_selector
+        .iter()
+        .flatten()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect(),
+    );
+    section(
+      "Tolerations",
+      spec
+        .tolerations
+        .iter()
+        .flatten()
+        .map(toleration_rule)
+        .collect(),
+    );
+
+    let affinity = spec.affinity.as_ref();
+    let node = affinity.and_then(|a| a.node_affinity.as_ref());
+    let mut node_rules: Vec<String> = node
+      .and_then(|n| {
+        n.required_during_scheduling_ignored_during_execution
+          .as_ref()
+      })
+      .map(|selector| selector.node_selector_terms.iter())
+      .into_iter()
+      .flatten()
+      .map(|term| format!("required: {}", node_term_rule(term)))
+      .collect();
+    node_rules.extend(
+      node
+        .and_then(|n| {
+          n.preferred_during_scheduling_ignored_during_execution
+            .as_ref()
+        })
+        .into_iter()
+        .flatten()
+        .map(|pref| {
+          format!(
+            "preferred (weight {}): {}",
+            pref.weight,
+            node_term_rule(&pref.preference)
+          )
+        }),
+    );
+    section("Node affinity", node_rules);
+
+    let pod = affinity.and_then(|a| a.pod_affinity.as_ref());
+    section(
+      "Pod affinity",
+      pod_term_rules(
+        pod.and_then(|p| {
+          p.required_during_scheduling_ignored_during_execution
+            .as_ref()
+        }),
+        pod.and_then(|p| {
+          p.preferred_during_scheduling_ignored_during_execution
+            .as_ref()
+        }),
+      ),
+    );
+    let anti = affinity.and_then(|a| a.pod_anti_affinity.as_ref());
+    section(
+      "Pod anti-affinity",
+      pod_term_rules(
+        anti.and_then(|p| {
+          p.required_during_scheduling_ignored_during_execution
+            .as_ref()
+        }),
+        anti.and_then(|p| {
+          p.preferred_during_scheduling_ignored_during_execution
+            .as_ref()
+        }),
+      ),
+    );
+    lines
+  }
+}
+
+/// `key=value:Effect`, like `kubectl describe`, with the operator spelled out
+/// for `Exists` and eviction delays kept.
+fn toleration_rule(toleration: &Toleration) -> String {
+  let key = toleration.key.as_deref().unwrap_or_default();
+  let mut rule = match (toleration.operator.as_deref(), key) {
+    (Some("Exists"), "") => "any taint".to_owned(),
+    (Some("Exists"), key) => format!("{key} exists"),
+    (_, key) => match toleration.value.as_deref() {
+      Some(value) if !value.is_empty() => format!("{key}={value}"),
+      _ => key.to_owned(),
+    },
+  };
+  if let Some(effect) = toleration.effect.as_deref().filter(|e| !e.is_empty()) {
+    rule.push_str(&format!(":{effect}"));
+  }
+  if let Some(seconds) = toleration.toleration_seconds {
+    rule.push_str(&format!(" for {seconds}s"));
+  }
+  rule
+}
+
+/// A node selector term's requirements, all of which must match.
+fn node_term_rule(term: &NodeSelectorTerm) -> String {
+  let labels = term
+    .match_expressions
+    .iter()
+    .flatten()
+    .map(requirement_rule);
+  let fields = term
+    .match_fields
+    .iter()
+    .flatten()
+    .map(|req| format!("field {}", requirement_rule(req)));
+  labels.chain(fields).collect::<Vec<_>>().join(", ")
+}
+
+fn requirement_rule(req: &NodeSelectorRequirement) -> String {
+  expression_rule(&req.key, &req.operator, req.values.as_deref())
+}
+
+/// `key In [a, b]`, or just `key Exists` for operators without values.
+fn expression_rule(key: &str, operator: &str, values: Option<&[String]>) -> String {
+  match values {
+    Some(values) if !values.is_empty() => format!("{key} {operator} [{}]", values.join(", ")),
+    _ => format!("{key} {operator}"),
+  }
+}
+
+fn pod_term_rules(
+  required: Option<&Vec<PodAffinityTerm>>,
+  preferred: Option<&Vec<WeightedPodAffinityTerm>>,
+) -> Vec<String> {
+  let required = required
+    .into_iter()
+    .flatten()
+    .map(|term| format!("required: {}", pod_term_rule(term)));
+  let preferred = preferred.into_iter().flatten().map(|weighted| {
+    format!(
+      "preferred (weight {}): {}",
+      weighted.weight,
+      pod_term_rule(&weighted.pod_affinity_term)
+    )
+  });
+  required.chain(preferred).collect()
+}
+
+/// Pods matching the selector, per topology domain, e.g.
+/// `app=web per kubernetes.io/hostname`.
+fn pod_term_rule(term: &PodAffinityTerm) -> String {
+  let selector = term
+    .label_selector
+    .as_ref()
+    .map(label_selector_rule)
+    .filter(|selector| !selector.is_empty())
+    .unwrap_or_else(|| "all pods".to_owned());
+  let mut rule = format!("{selector} per {}", term.topology_key);
+  if let Some(namespaces) = term.namespaces.as_ref().filter(|ns| !ns.is_empty()) {
+    rule.push_str(&format!(" in {}", namespaces.join(", ")));
+  }
+  rule
+}
+
+pub(crate) fn label_selector_rule(selector: &LabelSelector) -> String {
+  let labels = selector
+    .match_labels
+    .iter()
+    .flatten()
+    .map(|(key, value)| format!("{key}={value}"));
+  let expressions = selector
+    .match_expressions
+    .iter()
+    .flatten()
+    .map(|req| expression_rule(&req.key, &req.operator, req.values.as_deref()));
+  labels.chain(expressions).collect::<Vec<_>>().join(", ")
 }
 
 impl KubeResource<Pod> for KubePod {
@@ -200,7 +1174,7 @@ impl AppResource for PodResource {
   fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
     match block {
       ActiveBlock::Containers => draw_containers_block(f, app, area),
-      ActiveBlock::Describe => draw_describe_block(
+      ActiveBlock::Describe => draw_pod_describe_block(
         f,
         app,
         area,
@@ -230,6 +1204,21 @@ impl AppResource for PodResource {
           app.palette,
         ),
       ),
+      ActiveBlock::Diff => draw_diff_block(
+        f,
+        app,
+        area,
+        title_with_dual_style(
+          get_resource_title(
+            app,
+            PODS_TITLE,
+            get_describe_active(block),
+            app.data.pods.items.len(),
+          ),
+          copy_and_escape_title_line(PODS_TITLE, app.palette),
+          app.palette,
+        ),
+      ),
       ActiveBlock::Logs => draw_logs_block(f, app, area),
       ActiveBlock::Namespaces => Self::render(app.get_prev_route().active_block, f, app, area),
       _ => draw_block(f, app, area),
@@ -237,7 +1226,13 @@ impl AppResource for PodResource {
   }
 
   async fn get_resource(nw: &Network<'_>) {
-    let items: Vec<KubePod> = nw.get_namespaced_resources(Pod::into).await;
+    let label_selector = {
+      let app = nw.app.lock().await;
+      app.data.selected.label_selector.clone()
+    };
+    let items: Vec<KubePod> = nw
+      .get_namespaced_resources_matching(label_selector.as_deref(), Pod::into)
+      .await;
 
     let mut app = nw.app.lock().await;
     if app.data.selected.pod.is_some() {
@@ -256,7 +1251,33 @@ impl AppResource for PodResource {
   }
 }
 
-fn capitalize_first(s: &str) -> String {
+/// Evict a pod through the eviction subresource, which, unlike a delete,
+/// waits for the pod's PodDisruptionBudgets to allow it.
+pub async fn evict_pod(nw: &Network<'_>, name: &str, namespace: &str) {
+  let api: Api<Pod> = Api::namespaced(nw.client.clone(), namespace);
+  match api.evict(name, &EvictParams::default()).await {
+    Ok(_) => {
+      let mut app = nw.app.lock().await;
+      app.set_status_message(format!("Evicting pod {}", name));
+      app.dispatch_by_active_block(ActiveBlock::Pods).await;
+    }
+    Err(e) => nw.handle_error(anyhow!(eviction_error(name, &e))).await,
+  }
+}
+
+/// The API answers 429 Too Many Requests when a PodDisruptionBudget does not
+/// allow the eviction now; its message names the budget.
+fn eviction_error(name: &str, error: &kube::Error) -> String {
+  match error {
+    kube::Error::Api(status) if status.code == 429 => format!(
+      "Eviction of pod {} is blocked by a PodDisruptionBudget: {}",
+      name, status.message
+    ),
+    _ => format!("Failed to evict pod {}. {}", name, error),
+  }
+}
+
+fn capitalize_first(s: &str) -> String {
   let mut chars = s.chars();
   match chars.next() {
     None => String::new(),
@@ -264,8 +1285,36 @@ fn capitalize_first(s: &str) -> String {
   }
 }
 
+/// The title suffix while following a rollout, with where the rollout is at.
+fn followed_rollout_title(app: &App) -> String {
+  if app.data.selected.followed_rollout.is_none() {
+    return String::new();
+  }
+  match app.followed_deployment().map(|d| d.rollout()) {
+    Some(rollout) => format!(
+      "(following rollout: {}, {}/{} updated) ",
+      rollout.state.label(),
+      rollout.updated,
+      rollout.desired
+    ),
+    None => "(following rollout) ".to_owned(),
+  }
+}
+
+/// While following a rollout, rows read by where the pod is in it: ready,
+/// still coming up, or an old one terminating.
+fn rollout_row_style(pod: &KubePod, palette: Palette) -> Style {
+  if pod.is_terminating() {
+    style_help(palette)
+  } else if pod.ready.1 > 0 && pod.ready.0 == pod.ready.1 {
+    style_success(palette)
+  } else {
+    style_caution(palette)
+  }
+}
+
 pub(crate) fn draw_block_as_sub(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::Pods);
   let parent = app
     .data
     .selected
@@ -274,10 +1323,44 @@ pub(crate) fn draw_block_as_sub(f: &mut Frame<'_>, app: &mut App, area: Rect) {
     .map(capitalize_first)
     .unwrap_or_default();
   let base = format!("{} -> Pods", parent);
-  let title = get_resource_title(app, &base, &String::new(), app.data.pods.items.len());
+  let on_node = app.data.selected.pod_selector_resource.as_deref() == Some("node");
+  let suffix = if on_node {
+    "(by memory, CPU used) ".to_owned()
+  } else {
+    followed_rollout_title(app)
+  };
+  let title = get_resource_title(app, &base, &suffix, app.data.pods.items.len());
+
+  // A node's pods all share the Node column, so it shows their usage instead,
+  // under a line with what the node can hold.
+  let node = app
+    .data
+    .nodes
+    .items
+    .iter()
+    .find(|node| on_node && app.data.selected.pod_selector.as_ref() == Some(&node.name));
+  let area = match node {
+    Some(node) => {
+      let chunks = vertical_chunks(vec![Constraint::Length(1), Constraint::Min(0)], area);
+      f.render_widget(
+        Paragraph::new(node_allocatable_line(node, app.palette)),
+        chunks[0],
+      );
+      chunks[1]
+    }
+    None => area,
+  };
 
   let tier = ViewTier::from_width(area.width, app.wide_columns);
-  let (headers, widths) = responsive_columns(&POD_COLUMNS, tier);
+  let mut columns = app.column_layout(ActiveBlock::Pods, tier);
+  if on_node {
+    for header in columns.headers.iter_mut().filter(|h| **h == "Node") {
+      *header = "Used CPU/Mem";
+    }
+  }
+  let restart_threshold = app.restart_threshold();
+  let following_rollout = app.data.selected.followed_rollout.is_some();
+  let names = app.short_names(app.data.pods.items.iter().map(|pod| pod.name.as_str()));
 
   draw_resource_block(
     f,
@@ -295,47 +1378,100 @@ pub(crate) fn draw_block_as_sub(f: &mut Frame<'_>, app: &mut App, area: Rect) {
         app.palette,
       ),
       resource: &mut app.data.pods,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      let style = get_resource_row_style(c.status.as_str(), c.ready, app.palette);
-      let mut cells = vec![
+      let style = if following_rollout {
+        rollout_row_style(c, app.palette)
+      } else {
+        get_resource_row_style(c.status.as_str(), c.ready, app.palette)
+      };
+      let cells = vec![
         Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
+        Cell::from(names.get(&c.name).to_owned()),
         Cell::from(format!("{}/{}", c.ready.0, c.ready.1)),
         Cell::from(c.status.to_owned()),
-        Cell::from(c.restarts.to_string()),
+        restarts_cell(c, restart_threshold, app.palette),
+        Cell::from(c.uptime.to_owned()),
+        qos_cell(c.qos, app.palette),
+        if on_node {
+          Cell::from(used_cell(c))
+        } else {
+          Cell::from(c.node.to_owned())
+        },
+        Cell::from(c.ip.to_owned()),
+        Cell::from(format!("{}/{}", c.cpu_requests, c.cpu_limits)),
+        Cell::from(format!("{}/{}", c.mem_requests, c.mem_limits)),
+        Cell::from(c.age.to_owned()),
       ];
-      if tier >= ViewTier::Standard {
-        cells.push(Cell::from(c.node.to_owned()));
-        cells.push(Cell::from(c.ip.to_owned()));
-      }
-      cells.push(Cell::from(c.age.to_owned()));
-      Row::new(cells).style(style)
+      columns.row(cells).style(style)
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
-const POD_COLUMNS: [ColumnDef; 8] = [
-  ColumnDef::all("Namespace", 25, 20, 20),
-  ColumnDef::all("Name", 35, 25, 25),
-  ColumnDef::all("Ready", 10, 8, 8),
-  ColumnDef::all("Status", 10, 10, 10),
-  ColumnDef::all("Restarts", 10, 7, 7),
-  ColumnDef::standard("Node", 12, 12),
-  ColumnDef::standard("IP", 10, 10),
-  ColumnDef::all("Age", 10, 8, 8),
+/// `120m/256Mi`, `-` for what the metrics do not report.
+fn used_cell(pod: &KubePod) -> String {
+  let or_dash = |s: &str| {
+    if s.is_empty() {
+      "-".to_owned()
+    } else {
+      s.to_owned()
+    }
+  };
+  format!("{}/{}", or_dash(&pod.cpu), or_dash(&pod.mem))
+}
+
+pub(crate) const POD_COLUMNS: [ColumnDef; 12] = [
+  ColumnDef::all("Namespace", 22, 15, 11),
+  ColumnDef::all("Name", 32, 21, 17),
+  ColumnDef::all("Ready", 9, 7, 6),
+  ColumnDef::all("Status", 10, 10, 9),
+  ColumnDef::all("Restarts", 9, 7, 6),
+  ColumnDef::all("Uptime", 9, 7, 5),
+  ColumnDef::standard("QoS", 9, 8),
+  ColumnDef::standard("Node", 8, 7),
+  ColumnDef::standard("IP", 9, 7),
+  ColumnDef::wide("CPU Req/Lim", 9),
+  ColumnDef::wide("Mem Req/Lim", 9),
+  ColumnDef::all("Age", 9, 7, 5),
 ];
 
+/// BestEffort pods are evicted first under node pressure, so they stand out.
+fn qos_cell(qos: QosClass, palette: Palette) -> Cell<'static> {
+  let cell = Cell::from(qos.label());
+  if qos == QosClass::BestEffort {
+    cell.style(style_caution(palette))
+  } else {
+    cell
+  }
+}
+
+/// Pods title, with the active label selector appended so the scope is visible.
+fn get_pods_title(app: &App) -> String {
+  let suffix = app
+    .data
+    .selected
+    .label_selector
+    .as_ref()
+    .map(|selector| format!("(labels: {}) ", selector))
+    .unwrap_or_default();
+  get_resource_title(app, PODS_TITLE, &suffix, app.data.pods.items.len())
+}
+
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
-  let title = get_resource_title(app, PODS_TITLE, "", app.data.pods.items.len());
+  if app.pods_grouped() {
+    return draw_grouped_block(f, app, area);
+  }
+  let load = app.view_load(ActiveBlock::Pods);
+  let title = get_pods_title(app);
 
   let tier = ViewTier::from_width(area.width, app.wide_columns);
-  let (headers, widths) = responsive_columns(&POD_COLUMNS, tier);
+  let columns = app.column_layout(ActiveBlock::Pods, tier);
+  let restart_threshold = app.restart_threshold();
+  let names = app.short_names(app.data.pods.items.iter().map(|pod| pod.name.as_str()));
 
   draw_resource_block(
     f,
@@ -352,47 +1488,234 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
         app.palette,
       ),
       resource: &mut app.data.pods,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
       let style = get_resource_row_style(c.status.as_str(), c.ready, app.palette);
-      let mut cells = vec![
-        Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
-        Cell::from(format!("{}/{}", c.ready.0, c.ready.1)),
-        Cell::from(c.status.to_owned()),
-        Cell::from(c.restarts.to_string()),
-      ];
-      if tier >= ViewTier::Standard {
-        cells.push(Cell::from(c.node.to_owned()));
-        cells.push(Cell::from(c.ip.to_owned()));
+      let cells = pod_cells(
+        c,
+        names.get(&c.name).to_owned(),
+        restart_threshold,
+        app.palette,
+      );
+      columns.row(cells).style(style)
+    },
+    app.palette,
+    load,
+  );
+}
+
+/// One cell per [`POD_COLUMNS`] entry, with `name` in the name column.
+fn pod_cells(
+  c: &KubePod,
+  name: String,
+  restart_threshold: i32,
+  palette: Palette,
+) -> Vec<Cell<'static>> {
+  vec![
+    Cell::from(c.namespace.to_owned()),
+    Cell::from(name),
+    Cell::from(format!("{}/{}", c.ready.0, c.ready.1)),
+    Cell::from(c.status.to_owned()),
+    restarts_cell(c, restart_threshold, palette),
+    Cell::from(c.uptime.to_owned()),
+    qos_cell(c.qos, palette),
+    Cell::from(c.node.to_owned()),
+    Cell::from(c.ip.to_owned()),
+    Cell::from(format!("{}/{}", c.cpu_requests, c.cpu_limits)),
+    Cell::from(format!("{}/{}", c.mem_requests, c.mem_limits)),
+    Cell::from(c.age.to_owned()),
+  ]
+}
+
+/// The pods tab with each workload's pods under a header row of its own.
+fn draw_grouped_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  app.refresh_pod_groups();
+  let load = app.view_load(ActiveBlock::Pods);
+  let title = get_pods_title(app);
+
+  let tier = ViewTier::from_width(area.width, app.wide_columns);
+  let columns = app.column_layout(ActiveBlock::Pods, tier);
+  let restart_threshold = app.restart_threshold();
+  let names = app.short_names(app.data.pods.items.iter().map(|pod| pod.name.as_str()));
+  let pods = &app.data.pods.items;
+  let glyphs = glyphs();
+
+  draw_resource_block(
+    f,
+    area,
+    ResourceTableProps {
+      title,
+      inline_help: help_bold_line(
+        format!(
+          "{} · {}/{}:fold/expand all · {} · {}",
+          action_hint("fold group", DEFAULT_KEYBINDING.toggle_group.key),
+          DEFAULT_KEYBINDING.collapse_all_groups.key.symbol(),
+          DEFAULT_KEYBINDING.expand_all_groups.key.symbol(),
+          action_hint("ungroup", DEFAULT_KEYBINDING.group_pods.key),
+          describe_yaml_and_logs_hint(),
+        ),
+        app.palette,
+      ),
+      resource: &mut app.data.pod_groups,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
+    },
+    |row| match row {
+      PodGroupRow::Header(group) => {
+        let marker = if group.collapsed {
+          glyphs.collapsed
+        } else {
+          glyphs.expanded
+        };
+        let mut cells = vec![Cell::from(""); POD_COLUMNS.len()];
+        cells[0] = Cell::from(group.owner.namespace.to_owned());
+        cells[1] = Cell::from(format!("{} {}", marker, group.owner.label()));
+        cells[3] = Cell::from(group.status());
+        cells[4] = Cell::from(group.restarts.to_string());
+        let style = if row.is_healthy() {
+          Style::default()
+        } else {
+          style_caution(app.palette)
+        };
+        columns.row(cells).style(style.add_modifier(Modifier::BOLD))
+      }
+      PodGroupRow::Pod { index, last, .. } => {
+        let c = &pods[*index];
+        let prefix = if *last {
+          glyphs.tree_edge
+        } else {
+          glyphs.tree_branch
+        };
+        let name = format!("{} {}", prefix.trim_start(), names.get(&c.name));
+        let style = get_resource_row_style(c.status.as_str(), c.ready, app.palette);
+        columns
+          .row(pod_cells(c, name, restart_threshold, app.palette))
+          .style(style)
       }
-      cells.push(Cell::from(c.age.to_owned()));
-      Row::new(cells).style(style)
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
-const CONTAINER_COLUMNS: [ColumnDef; 9] = [
-  ColumnDef::all("Name", 20, 20, 20),
-  ColumnDef::all("Image", 25, 25, 25),
-  ColumnDef::all("Init", 5, 5, 5),
+pub(crate) const CONTAINER_COLUMNS: [ColumnDef; 14] = [
+  ColumnDef::all("Name", 13, 13, 13),
+  ColumnDef::all("Image", 15, 15, 15),
+  ColumnDef::all("Pull Policy", 7, 7, 7),
+  ColumnDef::all("Type", 7, 7, 7),
   ColumnDef::all("Ready", 5, 5, 5),
-  ColumnDef::all("State", 10, 10, 10),
+  ColumnDef::all("State", 9, 9, 9),
   ColumnDef::all("Restarts", 5, 5, 5),
-  ColumnDef::all("Probes(L/R)", 10, 10, 10),
-  ColumnDef::all("Ports", 10, 10, 10),
-  ColumnDef::all("Age", 10, 10, 10),
+  ColumnDef::all("Probes(L/R)", 7, 7, 7),
+  ColumnDef::all("CPU Req/Lim", 8, 8, 8),
+  ColumnDef::all("CPU Used", 7, 7, 7),
+  ColumnDef::all("Mem Req/Lim", 8, 8, 8),
+  ColumnDef::all("Mem Used", 7, 7, 7),
+  ColumnDef::all("Ports", 6, 6, 6),
+  ColumnDef::all("Age", 6, 6, 6),
 ];
 
+/// Share of its limit at which a container's usage reads as a caution.
+const USAGE_CAUTION_PERCENT: f64 = 90f64;
+
+/// `120m (60%)` of a container's usage and the share of its limit, or just
+/// the usage without a limit; `-` without metrics.
+fn container_usage_cell(
+  used: Option<&Qty>,
+  limit: &str,
+  format: fn(Option<Qty>) -> String,
+  palette: Palette,
+) -> Cell<'static> {
+  let Some(used) = used else {
+    return Cell::from("-");
+  };
+  let label = format(Some(used.clone()));
+  match Qty::from_str(limit).ok().filter(|limit| !limit.is_zero()) {
+    Some(limit) => {
+      let percent = used.calc_percentage(&limit);
+      let cell = Cell::from(format!("{} ({:.0}%)", label, percent));
+      if percent >= USAGE_CAUTION_PERCENT {
+        cell.style(style_caution(palette))
+      } else {
+        cell
+      }
+    }
+    None => Cell::from(label),
+  }
+}
+
 pub(crate) fn draw_containers_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
-  let title = get_container_title(app, app.data.containers.items.len(), "");
+  let pod = app
+    .data
+    .selected
+    .pod
+    .as_ref()
+    .and_then(|name| app.data.pods.items.iter().find(|pod| &pod.name == name));
+  let area = match pod {
+    Some(pod) => {
+      let live = app
+        .watched_pod()
+        .is_some_and(|watched| watched.name == pod.name);
+      let mut lines = vec![pod_status_line(pod, live, app.palette)];
+      lines.extend(unmet_condition_lines(pod, app.palette));
+      lines.extend(pod_event_lines(
+        app.data.pod_events.as_ref(),
+        pod,
+        app.palette,
+      ));
+      let chunks = vertical_chunks(
+        vec![Constraint::Length(lines.len() as u16), Constraint::Min(0)],
+        area,
+      );
+      f.render_widget(Paragraph::new(lines), chunks[0]);
+      chunks[1]
+    }
+    None => area,
+  };
+  let area = match app.data.containers.get_selected_item_copy() {
+    Some(container) => {
+      refresh_container_env(app, &container);
+      let expanded = app.data.container_mounts.as_ref();
+      let env = app.data.container_env.as_ref();
+      let mounts = container_mount_lines(&container, expanded.is_some(), app.palette);
+      let expanded_height = expanded.or(env.map(|env| &env.txt)).map_or(0, |txt| {
+        let lines = txt.get_txt().lines().count() as u16;
+        (lines + 1).min(area.height / 2)
+      });
+      let chunks = vertical_chunks(
+        vec![
+          Constraint::Min(0),
+          Constraint::Length(
+            3 + (container.probes.len().max(1)
+              + mounts.len()
+              + usize::from(container.last_termination.is_some())) as u16,
+          ),
+          Constraint::Length(expanded_height),
+        ],
+        area,
+      );
+      draw_container_detail(f, app.palette, &container, mounts, chunks[1]);
+      if let Some(txt) = expanded {
+        draw_container_mounts(f, app.palette, txt, chunks[2]);
+      } else if let Some(env) = env {
+        draw_container_env(f, app.palette, env, app.redactor(), chunks[2]);
+      }
+      chunks[0]
+    }
+    None => area,
+  };
+
+  let load = app.view_load(ActiveBlock::Containers);
+  let note = app
+    .metrics_note()
+    .map(|note| format!("({}) ", note))
+    .unwrap_or_default();
+  let title = get_container_title(app, app.data.containers.items.len(), note);
+  let usage = app.data.container_usage.clone();
 
-  let (headers, widths) = responsive_columns(&CONTAINER_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::Containers, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -413,183 +1736,1235 @@ pub(crate) fn draw_containers_block(f: &mut Frame<'_>, app: &mut App, area: Rect
         app.palette,
       ),
       resource: &mut app.data.containers,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      let style = get_resource_row_style(c.status.as_str(), (0, 0), app.palette);
-      Row::new(vec![
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.image.to_owned()),
-        Cell::from(c.init.to_string()),
-        Cell::from(c.ready.to_owned()),
-        Cell::from(c.status.to_owned()),
-        Cell::from(c.restarts.to_string()),
-        Cell::from(format!("{}/{}", c.liveliness_probe, c.readiness_probe,)),
-        Cell::from(c.ports.to_owned()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style)
+      // A running init container is still holding the pod back, and a
+      // running one that is not ready yet is still starting, so both read as
+      // in progress rather than healthy.
+      let starting = c.state == ContainerStateKind::Running
+        && (c.kind == ContainerKind::Init || c.ready == "false");
+      let style = if starting {
+        style_caution(app.palette)
+      } else if c.is_failing() {
+        style_failure(app.palette)
+      } else {
+        get_resource_row_style(c.status.as_str(), (0, 0), app.palette)
+      };
+      let image = Cell::from(short_image(c.shown_image()).to_owned());
+      let image = if c.is_image_changing() {
+        image.style(style_caution(app.palette))
+      } else {
+        image
+      };
+      let used = usage.as_ref().and_then(|usage| usage.get(&c.name));
+      columns
+        .row(vec![
+          Cell::from(c.name.to_owned()),
+          image,
+          Cell::from(c.pull_policy.to_owned()),
+          Cell::from(c.kind.label()),
+          Cell::from(c.ready.to_owned()),
+          container_status_cell(c, app.palette),
+          Cell::from(c.restarts.to_string()),
+          Cell::from(format!("{}/{}", c.liveliness_probe, c.readiness_probe,)),
+          Cell::from(format!("{}/{}", c.cpu_requests, c.cpu_limits)),
+          container_usage_cell(
+            used.and_then(|u| u.cpu.as_ref()),
+            &c.cpu_limits,
+            format_cpu,
+            app.palette,
+          ),
+          Cell::from(format!("{}/{}", c.mem_requests, c.mem_limits)),
+          container_usage_cell(
+            used.and_then(|u| u.mem.as_ref()),
+            &c.mem_limits,
+            format_mem,
+            app.palette,
+          ),
+          Cell::from(c.ports.to_owned()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style)
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
-fn get_container_title<S: AsRef<str>>(app: &App, container_len: usize, suffix: S) -> String {
-  let base = match &app.data.selected.pod_selector_resource {
-    Some(resource) => format!("{} -> Pods", capitalize_first(resource)),
-    None => PODS_TITLE.to_string(),
-  };
-  let suffix = format!("-> Containers [{}] {}", container_len, suffix.as_ref());
-  get_resource_title(app, &base, &suffix, app.data.pods.items.len())
+/// The state and its reason, followed by how the previous instance ended
+/// once it restarted, e.g. `Running · last OOMKilled`.
+fn container_status_cell(container: &KubeContainer, palette: Palette) -> Cell<'static> {
+  match &container.last_termination {
+    Some(last) => Cell::from(Line::from(vec![
+      Span::raw(format!("{} · ", container.state_label())),
+      Span::styled(format!("last {}", last.reason), last.style(palette)),
+    ])),
+    None => Cell::from(container.state_label()),
+  }
 }
 
-pub(crate) fn draw_logs_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_aggregate = app.data.logs.id.starts_with("agg:");
+/// `Last exit: OOMKilled  exit 137  5m ago  memory limit 256Mi` in the
+/// container detail pane.
+fn last_termination_line(
+  container: &KubeContainer,
+  last: &LastTermination,
+  palette: Palette,
+) -> Line<'static> {
+  let mut spans = vec![
+    Span::styled(format!("{:<10} ", "Last exit:"), style_label(palette)),
+    Span::styled(last.reason.clone(), last.style(palette)),
+    Span::styled(format!("  exit {}", last.exit_code), style_text(palette)),
+  ];
+  if !last.finished.is_empty() {
+    spans.push(Span::styled(
+      format!("  {} ago", last.finished),
+      style_help(palette),
+    ));
+  }
+  if last.is_oom_killed() {
+    spans.push(Span::styled(
+      format!("  memory limit {}", container.mem_limits),
+      style_caution(palette),
+    ));
+  }
+  Line::from(spans)
+}
 
-  let (title, hint) = if is_aggregate {
-    let resource = app
-      .data
-      .selected
-      .pod_selector_resource
-      .as_deref()
-      .map(capitalize_first)
-      .unwrap_or_default();
-    let agg_name = app.data.logs.id.strip_prefix("agg:").unwrap_or_default();
-    (
-      format!(" {} -> Logs ({}) ", resource, agg_name),
-      help_bold_line(
-        format!(
-          "{} · {} · {}:back ",
-          action_hint("copy", DEFAULT_KEYBINDING.copy_to_clipboard.key),
-          action_hint(
-            if app.log_auto_scroll {
-              "pause scroll"
-            } else {
-              "resume scroll"
-            },
-            DEFAULT_KEYBINDING.log_auto_scroll.key
-          ),
-          DEFAULT_KEYBINDING.esc.key.symbol()
-        ),
-        app.palette,
+/// `Pod: Running · 1/2 ready · 3 restarts · PodScheduled ✓ Ready ✗ · live`,
+/// the pod-level state above its containers.
+fn pod_status_line(pod: &KubePod, live: bool, palette: Palette) -> Line<'static> {
+  let mut spans = vec![
+    Span::styled(format!("{:<10} ", "Pod:"), style_label(palette)),
+    Span::styled(
+      pod.status.clone(),
+      get_resource_row_style(pod.status.as_str(), pod.ready, palette),
+    ),
+    Span::styled(
+      format!(
+        " · {}/{} ready · {} restarts ·",
+        pod.ready.0, pod.ready.1, pod.restarts
       ),
-    )
-  } else {
-    let selected_container = app.data.selected.container.clone();
-    let container_name = selected_container.unwrap_or_default();
-    let logs_label = if app.log_previous {
-      format!("-> Logs ({} · previous) ", container_name)
+      style_text(palette),
+    ),
+  ];
+  let failing: Vec<String> = pod
+    .containers
+    .iter()
+    .filter(|c| c.is_failing())
+    .map(|c| format!("{} ({})", c.name, c.status))
+    .collect();
+  if !failing.is_empty() {
+    spans.push(Span::styled(
+      format!(" failing: {} ·", failing.join(", ")),
+      style_failure(palette),
+    ));
+  }
+  for (condition, holds) in pod.conditions() {
+    spans.push(Span::styled(format!(" {}", condition), style_text(palette)));
+    spans.push(if holds {
+      Span::styled(format!(" {}", glyphs().ok), style_success(palette))
     } else {
-      format!("-> Logs ({}) ", container_name)
-    };
-    (
-      get_container_title(app, app.data.containers.items.len(), logs_label),
-      copy_scroll_and_escape_title_line("Containers", app.log_auto_scroll, app.palette),
-    )
-  };
+      Span::styled(format!(" {}", glyphs().fail), style_failure(palette))
+    });
+  }
+  if live {
+    spans.push(Span::styled(" · live", style_help(palette)));
+  }
+  Line::from(spans)
+}
 
-  let title = title_with_dual_style(title, hint, app.palette);
+/// Most unmet conditions listed under the pod status line.
+const MAX_UNMET_CONDITION_LINES: usize = 4;
 
-  let block = layout_block_top_border(title, app.palette);
+/// One line per condition that does not hold, with its reason and message,
+/// since that is the explanation of a pod stuck not ready.
+fn unmet_condition_lines(pod: &KubePod, palette: Palette) -> Vec<Line<'static>> {
+  pod
+    .condition_details()
+    .into_iter()
+    .filter(|c| !c.holds())
+    .take(MAX_UNMET_CONDITION_LINES)
+    .map(|c| {
+      let style = if c.status.as_deref() == Some("False") {
+        style_failure(palette)
+      } else {
+        style_caution(palette)
+      };
+      Line::from(vec![
+        Span::styled(format!("{:<10} ", ""), style_label(palette)),
+        Span::styled(c.detail(), style),
+      ])
+    })
+    .collect()
+}
 
-  let selected_container = app.data.selected.container.clone();
-  let container_name = selected_container.unwrap_or_default();
+/// Most recent events listed under the pod status line.
+const MAX_POD_EVENT_LINES: usize = 5;
 
-  if container_name == app.data.logs.id || is_aggregate {
-    app.data.logs.render_list(
-      f,
-      area,
-      block,
-      style_text(app.palette),
-      app.log_auto_scroll,
-      app.log_wrap,
-    );
-  } else {
-    loading(f, block, area, app.is_loading(), app.palette);
+/// The pod's latest events, newest first with warnings highlighted, or a
+/// note that it has none. Nothing until they are fetched.
+fn pod_event_lines(
+  pod_events: Option<&PodEvents>,
+  pod: &KubePod,
+  palette: Palette,
+) -> Vec<Line<'static>> {
+  let Some(pod_events) = pod_events.filter(|events| events.is_for(&pod.namespace, &pod.name))
+  else {
+    return vec![];
+  };
+  let label = |first: bool| {
+    Span::styled(
+      format!("{:<10} ", if first { "Events:" } else { "" }),
+      style_label(palette),
+    )
+  };
+  if pod_events.events.is_empty() {
+    return vec![Line::from(vec![
+      label(true),
+      Span::styled("none", style_help(palette)),
+    ])];
   }
+  pod_events
+    .events
+    .iter()
+    .take(MAX_POD_EVENT_LINES)
+    .enumerate()
+    .map(|(i, event)| {
+      let style = if event.is_warning() {
+        style_warning(palette)
+      } else {
+        style_text(palette)
+      };
+      let count = if event.count > 1 {
+        format!(" (x{})", event.count)
+      } else {
+        String::new()
+      };
+      Line::from(vec![
+        label(i == 0),
+        Span::styled(format!("{:<5} ", event.age), style_help(palette)),
+        Span::styled(
+          format!(
+            "{:<8} {}: {}{}",
+            event.type_, event.reason, event.message, count
+          ),
+          style,
+        ),
+      ])
+    })
+    .collect()
 }
 
-fn get_resource_row_style(status: &str, ready: (i32, i32), palette: Palette) -> Style {
-  if status == "Running" && ready.0 == ready.1 {
-    style_success(palette)
-  } else if status == "Completed" || status == "Succeeded" {
-    // Done & inactive — de-emphasize so it reads apart from active (green)
-    // and in-progress (amber) rows.
-    style_help(palette)
-  } else if [
-    "ContainerCreating",
-    "PodInitializing",
-    "Pending",
-    "Initialized",
-  ]
-  .contains(&status)
-  {
-    style_caution(palette)
+/// The selected container's full image, then its probes one per line, or a
+/// note that it has none, then its volume mounts.
+fn draw_container_detail(
+  f: &mut Frame<'_>,
+  palette: Palette,
+  container: &KubeContainer,
+  mounts: Vec<Line<'static>>,
+  area: Rect,
+) {
+  let mut image = vec![
+    Span::styled(format!("{:<10} ", "Image:"), style_label(palette)),
+    Span::styled(container.shown_image().to_owned(), style_text(palette)),
+  ];
+  if container.is_image_changing() {
+    image.push(Span::styled(
+      format!("  (spec: {}, rolling out)", container.image),
+      style_caution(palette),
+    ));
+  }
+  if !container.pull_policy.is_empty() {
+    image.push(Span::styled(
+      format!("  pull {}", container.pull_policy),
+      style_help(palette),
+    ));
+  }
+  let mut lines = vec![Line::from(image)];
+  if let Some(last) = &container.last_termination {
+    lines.push(last_termination_line(container, last, palette));
+  }
+  let probes = &container.probes;
+  if probes.is_empty() {
+    lines.push(Line::styled(
+      "No readiness, liveness or startup probes configured",
+      style_help(palette),
+    ));
   } else {
-    style_failure(palette)
+    lines.extend(probes.iter().map(|probe| {
+      Line::from(vec![
+        Span::styled(
+          format!("{:<10} ", format!("{}:", probe.kind)),
+          style_label(palette),
+        ),
+        Span::styled(format!("{}  ", probe.action), style_text(palette)),
+        Span::styled(probe.timing.clone(), style_help(palette)),
+      ])
+    }));
   }
+  lines.extend(mounts);
+  lines.push(container_env_line(container, palette));
+  f.render_widget(
+    Paragraph::new(lines).block(layout_block_top_border(
+      Line::from(title_style(" Image, probes, mounts & env ", palette)),
+      palette,
+    )),
+    area,
+  );
 }
 
-fn is_pending_like_status(status: &str) -> bool {
-  [
-    "ContainerCreating",
-    "PodInitializing",
-    "Pending",
-    "Initialized",
-  ]
-  .contains(&status)
-}
-
-impl KubeContainer {
-  pub fn from_api(
-    container: &Container,
-    pod_name: String,
-    age: String,
-    c_stats_ref: &Option<Vec<ContainerStatus>>,
-    init: bool,
-  ) -> Self {
-    let (mut ready, mut status, mut restarts) = ("false".to_string(), "<none>".to_string(), 0);
-    if let Some(c_stats) = c_stats_ref {
-      if let Some(c_stat) = c_stats.iter().find(|cs| cs.name == container.name) {
-        ready = c_stat.ready.to_string();
-        status = get_container_state(c_stat.state.clone());
-        restarts = c_stat.restart_count;
-      }
-    }
+/// Mounts shown in the detail pane before the rest fold behind a count.
+const INLINE_MOUNTS: usize = 3;
 
-    KubeContainer {
-      name: container.name.clone(),
-      pod_name,
-      image: container.image.clone().unwrap_or_default(),
-      ready,
-      status,
-      restarts,
-      liveliness_probe: container.liveness_probe.is_some(),
-      readiness_probe: container.readiness_probe.is_some(),
-      ports: get_container_ports(&container.ports).unwrap_or_default(),
-      age,
-      init,
-      k8s_obj: None,
-    }
+/// The detail pane's mount rows: all of them when few, else the first few and
+/// a count of the rest. Once expanded they are listed in their own block.
+fn container_mount_lines(
+  container: &KubeContainer,
+  expanded: bool,
+  palette: Palette,
+) -> Vec<Line<'static>> {
+  let label = |first: bool| {
+    let text = if first { "Mounts:" } else { "" };
+    Span::styled(format!("{:<10} ", text), style_label(palette))
+  };
+  let mounts = &container.mounts;
+  let toggle = action_hint(
+    "expand/fold",
+    DEFAULT_KEYBINDING.toggle_container_mounts.key,
+  );
+  if mounts.is_empty() {
+    return vec![Line::from(vec![
+      label(true),
+      Span::styled("none", style_help(palette)),
+    ])];
+  }
+  if expanded {
+    return vec![Line::from(vec![
+      label(true),
+      Span::styled(
+        format!("{} · {}", mounts.len(), toggle),
+        style_help(palette),
+      ),
+    ])];
+  }
+  let mut lines: Vec<Line<'static>> = ContainerMount::lines(mounts)
+    .into_iter()
+    .take(INLINE_MOUNTS)
+    .enumerate()
+    .map(|(i, line)| Line::from(vec![label(i == 0), Span::styled(line, style_text(palette))]))
+    .collect();
+  if mounts.len() > INLINE_MOUNTS {
+    lines.push(Line::from(vec![
+      label(false),
+      Span::styled(
+        format!("… {} more · {}", mounts.len() - INLINE_MOUNTS, toggle),
+        style_help(palette),
+      ),
+    ]));
   }
+  lines
 }
 
-fn get_container_state(os: Option<ContainerState>) -> String {
-  match os {
+/// The detail pane's env row: how many variables and sources there are, and
+/// how many come from secrets. The variables themselves are listed expanded.
+fn container_env_line(container: &KubeContainer, palette: Palette) -> Line<'static> {
+  let label = Span::styled(format!("{:<10} ", "Env:"), style_label(palette));
+  let env = &container.env;
+  if env.is_empty() {
+    return Line::from(vec![label, Span::styled("none", style_help(palette))]);
+  }
+  let secrets = env.iter().filter(|e| e.is_secret()).count();
+  let mut summary = env.len().to_string();
+  if secrets > 0 {
+    summary.push_str(&format!(" · {} from secrets", secrets));
+  }
+  Line::from(vec![
+    label,
+    Span::styled(summary, style_text(palette)),
+    Span::styled(
+      format!(
+        " · {}",
+        action_hint("expand/fold", DEFAULT_KEYBINDING.toggle_container_env.key)
+      ),
+      style_help(palette),
+    ),
+  ])
+}
+
+/// The text of the expanded env block of `container`, with secret values when
+/// they are revealed and redact mode is off.
+pub fn container_env_text(app: &App, container: &KubeContainer, reveal: bool) -> String {
+  let namespace = app
+    .data
+    .pods
+    .items
+    .iter()
+    .find(|pod| pod.name == container.pod_name)
+    .map(|pod| pod.namespace.as_str())
+    .unwrap_or_default();
+  let secrets = (reveal && !app.redact).then_some(app.data.secrets.items.as_slice());
+  ContainerEnv::lines(&container.env, secrets, namespace).join("\n")
+}
+
+/// Keep the expanded env block in step with the selected container and with
+/// secrets loaded after the values were revealed.
+fn refresh_container_env(app: &mut App, container: &KubeContainer) {
+  let Some(reveal) = app.data.container_env.as_ref().map(|env| env.reveal) else {
+    return;
+  };
+  let text = container_env_text(app, container, reveal);
+  if let Some(env) = app.data.container_env.as_mut() {
+    if env.txt.get_txt() != text {
+      let offset = env.txt.offset;
+      env.txt = ScrollableTxt::with_string(text);
+      env.txt.scroll_to(offset);
+    }
+  }
+}
+
+fn draw_container_env(
+  f: &mut Frame<'_>,
+  palette: Palette,
+  env: &ContainerEnvView,
+  redactor: Option<&Redactor>,
+  area: Rect,
+) {
+  let lines: Vec<Line<'_>> = env
+    .txt
+    .get_txt()
+    .lines()
+    .map(|line| {
+      let line = match redactor {
+        Some(redactor) => redactor.redact_env_line(line),
+        None => line.into(),
+      };
+      Line::styled(line.into_owned(), style_text(palette))
+    })
+    .collect();
+  let reveal = if env.reveal {
+    "hide secrets"
+  } else {
+    "show secrets"
+  };
+  f.render_widget(
+    Paragraph::new(lines)
+      .block(layout_block_top_border(
+        title_with_dual_style(
+          " Environment ".into(),
+          help_bold_line(
+            format!(
+              "{} · {}",
+              action_hint(reveal, DEFAULT_KEYBINDING.decode_secret.key),
+              action_hint("fold", DEFAULT_KEYBINDING.toggle_container_env.key)
+            ),
+            palette,
+          ),
+          palette,
+        ),
+        palette,
+      ))
+      .scroll((env.txt.offset as u16, 0)),
+    area,
+  );
+}
+
+fn draw_container_mounts(f: &mut Frame<'_>, palette: Palette, txt: &ScrollableTxt, area: Rect) {
+  let lines: Vec<Line<'_>> = txt
+    .get_txt()
+    .lines()
+    .map(|line| Line::styled(line.to_owned(), style_text(palette)))
+    .collect();
+  f.render_widget(
+    Paragraph::new(lines)
+      .block(layout_block_top_border(
+        title_with_dual_style(
+          " Volume mounts ".into(),
+          help_bold_line(
+            action_hint("fold", DEFAULT_KEYBINDING.toggle_container_mounts.key),
+            palette,
+          ),
+          palette,
+        ),
+        palette,
+      ))
+      .scroll((txt.offset as u16, 0)),
+    area,
+  );
+}
+
+/// Describe output under a short scheduling summary of the pod, so why a
+/// pending pod is not placed reads without scrolling to its events.
+fn draw_pod_describe_block(f: &mut Frame<'_>, app: &mut App, area: Rect, title: Line<'_>) {
+  let Some(pod) = app.data.pods.get_selected_item_copy() else {
+    draw_describe_block(f, app, area, title);
+    return;
+  };
+  let scheduling = pod.scheduling();
+  let scheduling_constraints = pod.scheduling_constraints();
+
+  let palette = app.palette;
+  let field = |label: &'static str, value: &str| {
+    let value = if value.is_empty() { "-" } else { value };
+    vec![
+      Span::styled(format!("{label}: "), style_label(palette)),
+      Span::styled(value.to_owned(), style_text(palette)),
+    ]
+  };
+  let mut placement = field("Node", &scheduling.node);
+  placement.push(Span::raw("   "));
+  placement.extend(field("Nominated node", &scheduling.nominated_node));
+
+  let controllers = app
+    .data
+    .pod_controllers
+    .clone()
+    .filter(|controllers| controllers.is_for(&pod.namespace, &pod.name))
+    .unwrap_or_else(|| PodControllers::immediate(&pod));
+  let mut ownership = if scheduling.has_custom_scheduler() {
+    vec![
+      Span::styled("Scheduler: ", style_label(palette)),
+      Span::styled(
+        format!("{} (custom)", scheduling.scheduler),
+        style_warning(palette),
+      ),
+    ]
+  } else {
+    field("Scheduler", &scheduling.scheduler)
+  };
+  ownership.push(Span::raw("   "));
+  ownership.extend(field("Controller", &controllers.label()));
+
+  let condition_style = if scheduling.is_failing() {
+    style_warning(palette)
+  } else {
+    style_text(palette)
+  };
+  let condition = [
+    scheduling.scheduled.as_deref().unwrap_or("-"),
+    &scheduling.reason,
+    &scheduling.message,
+  ]
+  .into_iter()
+  .filter(|part| !part.is_empty())
+  .collect::<Vec<_>>()
+  .join(" · ");
+  let condition = Line::from(vec![
+    Span::styled("PodScheduled: ", style_label(palette)),
+    Span::styled(condition, condition_style),
+  ]);
+
+  let constraints = pod_constraints_summary(&scheduling_constraints, palette);
+
+  // The scheduler message can be long; give it up to three wrapped lines.
+  let wrapped = (condition.width() as u16)
+    .div_ceil(area.width.max(1))
+    .clamp(1, 3);
+  let expanded = app.data.pod_constraints.as_ref().map(|txt| {
+    let lines = txt.get_txt().lines().count() as u16;
+    (lines + 1).min(area.height / 2)
+  });
+  let chunks = vertical_chunks(
+    vec![
+      Constraint::Length(4 + wrapped),
+      Constraint::Length(expanded.unwrap_or(0)),
+      Constraint::Min(0),
+    ],
+    area,
+  );
+  f.render_widget(
+    Paragraph::new(vec![
+      Line::from(placement),
+      Line::from(ownership),
+      condition,
+      constraints,
+    ])
+    .block(layout_block_top_border(
+      Line::from(title_style(" Scheduling ", palette)),
+      palette,
+    ))
+    .wrap(Wrap { trim: true }),
+    chunks[0],
+  );
+  if let Some(txt) = app.data.pod_constraints.as_ref() {
+    let lines: Vec<Line<'_>> = txt
+      .get_txt()
+      .lines()
+      .map(|line| {
+        if line.starts_with(' ') {
+          Line::styled(line.to_owned(), style_text(palette))
+        } else {
+          Line::styled(line.to_owned(), style_label(palette))
+        }
+      })
+      .collect();
+    f.render_widget(
+      Paragraph::new(lines)
+        .block(layout_block_top_border(
+          title_with_dual_style(
+            " Scheduling constraints ".into(),
+            help_bold_line(
+              action_hint("fold", DEFAULT_KEYBINDING.toggle_pod_constraints.key),
+              palette,
+            ),
+            palette,
+          ),
+          palette,
+        ))
+        .scroll((txt.offset as u16, 0)),
+      chunks[1],
+    );
+  }
+  draw_describe_block(f, app, chunks[2], title);
+}
+
+/// One line counting each kind of constraint, with the key that expands them.
+fn pod_constraints_summary(constraints: &[String], palette: Palette) -> Line<'static> {
+  let mut counts: Vec<(String, usize)> = vec![];
+  for line in constraints {
+    match counts.last_mut() {
+      Some((_, count)) if line.starts_with(' ') => *count += 1,
+      _ => counts.push((line.to_owned(), 0)),
+    }
+  }
+  let summary = if counts.is_empty() {
+    "none".to_owned()
+  } else {
+    let counts: Vec<String> = counts
+      .iter()
+      .map(|(kind, count)| format!("{kind} {count}"))
+      .collect();
+    format!(
+      "{} · {}",
+      counts.join(" · "),
+      action_hint("expand/fold", DEFAULT_KEYBINDING.toggle_pod_constraints.key)
+    )
+  };
+  Line::from(vec![
+    Span::styled("Constraints: ", style_label(palette)),
+    Span::styled(summary, style_text(palette)),
+  ])
+}
+
+fn get_container_title<S: AsRef<str>>(app: &App, container_len: usize, suffix: S) -> String {
+  let base = match &app.data.selected.pod_selector_resource {
+    Some(resource) => format!("{} -> Pods", capitalize_first(resource)),
+    None => PODS_TITLE.to_string(),
+  };
+  let suffix = format!("-> Containers [{}] {}", container_len, suffix.as_ref());
+  get_resource_title(app, &base, &suffix, app.data.pods.items.len())
+}
+
+/// ` · since 10m` when the log view is limited to a time window.
+fn log_since_label(app: &App) -> String {
+  match app.log_since {
+    Some(since) if !app.log_previous => {
+      format!(" · since {}", humantime::format_duration(since))
+    }
+    _ => String::new(),
+  }
+}
+
+/// ` · reconnecting…` while a dropped log stream is being reopened.
+fn log_reconnecting_label(app: &App) -> &'static str {
+  if app.log_reconnecting > 0 {
+    " · reconnecting…"
+  } else {
+    ""
+  }
+}
+
+/// ` · no wrap` when long lines are cut at the right edge instead of wrapped.
+fn log_wrap_label(app: &App) -> &'static str {
+  if app.log_wrap {
+    ""
+  } else {
+    " · no wrap"
+  }
+}
+
+/// ` · 1200 older lines dropped` once the buffer is over its cap, so a
+/// scroll to the top is known not to be the start of the stream.
+fn log_dropped_label(app: &App) -> String {
+  match app.data.logs.dropped() {
+    0 => String::new(),
+    1 => " · 1 older line dropped".into(),
+    n => format!(" · {} older lines dropped", n),
+  }
+}
+
+/// ` · json` while JSON log lines are pretty-printed.
+fn log_json_label(app: &App) -> &'static str {
+  if app.log_json {
+    " · json"
+  } else {
+    ""
+  }
+}
+
+/// `[FOLLOWING]` while tailing, or where the paused view is and how many lines
+/// arrived below it since.
+fn log_follow_label(app: &App) -> String {
+  if app.log_auto_scroll {
+    return "[FOLLOWING]".into();
+  }
+  match app.data.logs.paused_position() {
+    Some((line, 0)) => format!("[PAUSED @ line {}]", line),
+    Some((line, 1)) => format!("[PAUSED @ line {} · 1 new line below]", line),
+    Some((line, new)) => format!("[PAUSED @ line {} · {} new lines below]", line, new),
+    None => "[PAUSED]".into(),
+  }
+}
+
+/// ` · /term 2/5` while the log view is searched, with the selected match.
+fn log_search_label(app: &App) -> String {
+  let logs = &app.data.logs;
+  if logs.search_active {
+    return format!(" · /{}{}", logs.search, glyphs().cursor);
+  }
+  if logs.search.is_empty() {
+    return String::new();
+  }
+  match logs.match_position() {
+    (_, 0) => format!(" · /{} no matches", logs.search),
+    (Some(position), total) => format!(" · /{} {}/{}", logs.search, position, total),
+    (None, total) => format!(" · /{} {} matches", logs.search, total),
+  }
+}
+
+pub(crate) fn draw_logs_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  let is_aggregate = app.data.logs.id.starts_with("agg:");
+
+  let (title, hint) = if is_aggregate {
+    let resource = app
+      .data
+      .selected
+      .pod_selector_resource
+      .as_deref()
+      .map(capitalize_first)
+      .unwrap_or_default();
+    let agg_name = app.data.logs.id.strip_prefix("agg:").unwrap_or_default();
+    (
+      format!(
+        " {} -> Logs ({}{}{}{}{}{}{}) {} ",
+        resource,
+        agg_name,
+        log_wrap_label(app),
+        log_json_label(app),
+        log_since_label(app),
+        log_reconnecting_label(app),
+        log_dropped_label(app),
+        log_search_label(app),
+        log_follow_label(app)
+      ),
+      help_bold_line(
+        format!(
+          "{} · {} · {} · {}:back ",
+          action_hint("copy", DEFAULT_KEYBINDING.copy_to_clipboard.key),
+          action_hint(
+            if app.log_auto_scroll {
+              "pause scroll"
+            } else {
+              "resume scroll"
+            },
+            DEFAULT_KEYBINDING.log_auto_scroll.key
+          ),
+          action_hint(
+            if app.log_wrap { "unwrap" } else { "wrap" },
+            DEFAULT_KEYBINDING.toggle_log_wrap.key
+          ),
+          DEFAULT_KEYBINDING.esc.key.symbol()
+        ),
+        app.palette,
+      ),
+    )
+  } else {
+    let selected_container = app.data.selected.container.clone();
+    let container_name = selected_container.unwrap_or_default();
+    let logs_label = if app.log_previous {
+      format!(
+        "-> Logs ({} · previous{}{}{}{}) {} ",
+        container_name,
+        log_wrap_label(app),
+        log_json_label(app),
+        log_dropped_label(app),
+        log_search_label(app),
+        log_follow_label(app)
+      )
+    } else {
+      format!(
+        "-> Logs ({}{}{}{}{}{}{}) {} ",
+        container_name,
+        log_wrap_label(app),
+        log_json_label(app),
+        log_since_label(app),
+        log_reconnecting_label(app),
+        log_dropped_label(app),
+        log_search_label(app),
+        log_follow_label(app)
+      )
+    };
+    (
+      get_container_title(app, app.data.containers.items.len(), logs_label),
+      copy_scroll_and_escape_title_line(
+        "Containers",
+        app.log_auto_scroll,
+        app.log_wrap,
+        app.palette,
+      ),
+    )
+  };
+
+  let title = title_with_dual_style(title, hint, app.palette);
+
+  let block = layout_block_top_border(title, app.palette);
+
+  let selected_container = app.data.selected.container.clone();
+  let container_name = selected_container.unwrap_or_default();
+
+  if container_name == app.data.logs.id || is_aggregate {
+    let json = app.log_json.then(|| JsonLogFormat {
+      message_field: app.config.log_json_message_field.clone(),
+      key_style: app.enhanced_graphics.then(|| style_label(app.palette)),
+    });
+    app.data.logs.set_json_format(json);
+    let source_styles = if is_aggregate {
+      vec![
+        style_primary(app.palette),
+        style_secondary(app.palette),
+        style_success(app.palette),
+        style_warning(app.palette),
+        style_label(app.palette),
+        style_caution(app.palette),
+      ]
+    } else {
+      vec![]
+    };
+    app.data.logs.set_source_styles(source_styles);
+    app.redact_logs();
+    app.data.logs.render_list(
+      f,
+      area,
+      block,
+      style_text(app.palette),
+      app.log_auto_scroll,
+      app.log_wrap,
+    );
+  } else {
+    loading(f, block, area, &app.global_load(), app.palette);
+  }
+}
+
+fn get_resource_row_style(status: &str, ready: (i32, i32), palette: Palette) -> Style {
+  if status == "Running" && ready.0 == ready.1 {
+    style_success(palette)
+  } else if status == "Completed" || status == "Succeeded" {
+    // Done & inactive — de-emphasize so it reads apart from active (green)
+    // and in-progress (amber) rows.
+    style_help(palette)
+  } else if [
+    "ContainerCreating",
+    "PodInitializing",
+    "Pending",
+    "Initialized",
+  ]
+  .contains(&status)
+  {
+    style_caution(palette)
+  } else {
+    style_failure(palette)
+  }
+}
+
+fn is_pending_like_status(status: &str) -> bool {
+  [
+    "ContainerCreating",
+    "PodInitializing",
+    "Pending",
+    "Initialized",
+  ]
+  .contains(&status)
+}
+
+impl KubeContainer {
+  pub fn from_api(
+    container: &Container,
+    pod_name: String,
+    age: String,
+    c_stats_ref: &Option<Vec<ContainerStatus>>,
+    kind: ContainerKind,
+    volumes: &[Volume],
+    now: DateTime<Utc>,
+  ) -> Self {
+    let (mut ready, mut status, mut restarts) = ("false".to_string(), "<none>".to_string(), 0);
+    let mut state = ContainerStateKind::Unknown;
+    let mut running_image = String::new();
+    let mut last_termination = None;
+    if let Some(c_stats) = c_stats_ref {
+      if let Some(c_stat) = c_stats.iter().find(|cs| cs.name == container.name) {
+        ready = c_stat.ready.to_string();
+        (state, status) = get_container_state(c_stat.state.clone());
+        restarts = c_stat.restart_count;
+        running_image = c_stat.image.clone();
+        last_termination = LastTermination::from_api(c_stat, now);
+      }
+    }
+
+    KubeContainer {
+      name: container.name.clone(),
+      pod_name,
+      image: container.image.clone().unwrap_or_default(),
+      running_image,
+      pull_policy: container.image_pull_policy.clone().unwrap_or_default(),
+      ready,
+      status,
+      state,
+      restarts,
+      last_termination,
+      liveliness_probe: container.liveness_probe.is_some(),
+      readiness_probe: container.readiness_probe.is_some(),
+      probes: ContainerProbe::list(container),
+      mounts: ContainerMount::list(container.volume_mounts.as_deref(), volumes),
+      env: ContainerEnv::list(container),
+      ports: get_container_ports(&container.ports).unwrap_or_default(),
+      cpu_requests: format_cpu(container_quantity(container, Bound::Requests, CPU)),
+      cpu_limits: format_cpu(container_quantity(container, Bound::Limits, CPU)),
+      mem_requests: format_mem(container_quantity(container, Bound::Requests, MEMORY)),
+      mem_limits: format_mem(container_quantity(container, Bound::Limits, MEMORY)),
+      age,
+      kind,
+      k8s_obj: None,
+    }
+  }
+
+  /// Ephemeral containers carry the same fields as regular ones in a separate
+  /// type; map the ones shown in the list across.
+  pub fn from_ephemeral(
+    container: &EphemeralContainer,
+    pod_name: String,
+    age: String,
+    c_stats_ref: &Option<Vec<ContainerStatus>>,
+    volumes: &[Volume],
+    now: DateTime<Utc>,
+  ) -> Self {
+    let container = Container {
+      name: container.name.clone(),
+      image: container.image.clone(),
+      image_pull_policy: container.image_pull_policy.clone(),
+      ports: container.ports.clone(),
+      liveness_probe: container.liveness_probe.clone(),
+      readiness_probe: container.readiness_probe.clone(),
+      startup_probe: container.startup_probe.clone(),
+      resources: container.resources.clone(),
+      volume_mounts: container.volume_mounts.clone(),
+      env: container.env.clone(),
+      env_from: container.env_from.clone(),
+      ..Container::default()
+    };
+    Self::from_api(
+      &container,
+      pod_name,
+      age,
+      c_stats_ref,
+      ContainerKind::Ephemeral,
+      volumes,
+      now,
+    )
+  }
+}
+
+impl KubeContainer {
+  /// The state with its reason when it has one, e.g.
+  /// `Waiting: CrashLoopBackOff`, `Terminated: OOMKilled` or `Running`.
+  pub fn state_label(&self) -> String {
+    let state = self.state.label();
+    if self.status == state || self.state == ContainerStateKind::Unknown {
+      self.status.clone()
+    } else {
+      format!("{}: {}", state, self.status)
+    }
+  }
+
+  /// Whether the container is the one holding its pod back: stuck waiting
+  /// for something other than being created, or exited unsuccessfully.
+  pub fn is_failing(&self) -> bool {
+    match self.state {
+      ContainerStateKind::Waiting => !is_pending_like_status(&self.status),
+      ContainerStateKind::Terminated => self.status != "Completed",
+      ContainerStateKind::Running | ContainerStateKind::Unknown => false,
+    }
+  }
+
+  /// The running image when known, else the spec image.
+  pub fn shown_image(&self) -> &str {
+    if self.running_image.is_empty() {
+      &self.image
+    } else {
+      &self.running_image
+    }
+  }
+
+  /// Whether the container runs another image than its spec asks for, i.e.
+  /// the new image is still being pulled or rolled out.
+  pub fn is_image_changing(&self) -> bool {
+    // Some runtimes report only the image ID, which cannot be compared.
+    !self.running_image.is_empty()
+      && !self.running_image.starts_with("sha256:")
+      && normalize_image(&self.running_image) != normalize_image(&self.image)
+  }
+}
+
+/// The image with Docker Hub's implied registry, `library/` and `:latest`
+/// filled in, as the container status reports it.
+fn normalize_image(image: &str) -> String {
+  let (name, reference) = match image.find('@') {
+    Some(at) => image.split_at(at),
+    None => match image.rfind(':') {
+      Some(colon) if !image[colon..].contains('/') => image.split_at(colon),
+      _ => (image, ":latest"),
+    },
+  };
+  let first = name.split('/').next().unwrap_or_default();
+  let has_registry = name.contains('/') && (first.contains(['.', ':']) || first == "localhost");
+  let name = match (has_registry, name.contains('/')) {
+    (true, _) => name.to_owned(),
+    (false, true) => format!("docker.io/{}", name),
+    (false, false) => format!("docker.io/library/{}", name),
+  };
+  format!("{}{}", name, reference)
+}
+
+/// The last path segment of an image, e.g. `adservice:v0.2.2` for
+/// `gcr.io/google-samples/microservices-demo/adservice:v0.2.2`, so the tag
+/// stays visible in a narrow column.
+fn short_image(image: &str) -> &str {
+  let name_end = image.find('@').unwrap_or(image.len());
+  match image[..name_end].rfind('/') {
+    Some(slash) => &image[slash + 1..],
+    None => image,
+  }
+}
+
+/// How long after a container restart the pods view keeps flagging it.
+const RECENT_RESTART_WINDOW: chrono::Duration = chrono::Duration::minutes(10);
+
+fn get_recent_restart(pod: &Pod, now: DateTime<Utc>) -> Option<String> {
+  let finished_at = pod
+    .status
+    .as_ref()?
+    .container_statuses
+    .as_ref()?
+    .iter()
+    .filter_map(|cs| {
+      cs.last_state
+        .as_ref()?
+        .terminated
+        .as_ref()?
+        .finished_at
+        .as_ref()
+    })
+    .max_by_key(|time| time.0)?;
+  if now.signed_duration_since(utils::time_to_chrono(finished_at)) > RECENT_RESTART_WINDOW {
+    return None;
+  }
+  Some(utils::to_age_secs(Some(finished_at), now))
+}
+
+fn get_uptime(pod: &Pod, now: DateTime<Utc>) -> String {
+  pod
+    .status
+    .as_ref()
+    .and_then(|status| status.container_statuses.as_ref())
+    .into_iter()
+    .flatten()
+    .filter_map(|cs| cs.state.as_ref()?.running.as_ref()?.started_at.as_ref())
+    .max_by_key(|time| time.0)
+    .map_or_else(
+      || "-".to_owned(),
+      |started_at| utils::to_age_secs(Some(started_at), now),
+    )
+}
+
+/// Restart count, green at zero, warning colour below the threshold and
+/// failure colour at or above it.
+fn restarts_cell(pod: &KubePod, threshold: i32, palette: Palette) -> Cell<'static> {
+  let text = match &pod.last_restart {
+    Some(age) => format!("{} ({} ago)", pod.restarts, age),
+    None => pod.restarts.to_string(),
+  };
+  let style = if pod.restarts == 0 {
+    style_success(palette)
+  } else if pod.restarts < threshold {
+    style_caution(palette)
+  } else {
+    style_failure(palette)
+  };
+  Cell::from(text).style(style)
+}
+
+const CPU: &str = "cpu";
+const MEMORY: &str = "memory";
+
+#[derive(Clone, Copy)]
+enum Bound {
+  Requests,
+  Limits,
+}
+
+/// A container's request (or limit) for one resource, if it sets one.
+fn container_quantity(container: &Container, bound: Bound, resource: &str) -> Option<Qty> {
+  let resources = container.resources.as_ref()?;
+  let quantities = match bound {
+    Bound::Requests => resources.requests.as_ref(),
+    Bound::Limits => resources.limits.as_ref(),
+  }?;
+  Qty::from_str(&quantities.get(resource)?.0).ok()
+}
+
+/// Total across containers, counting only the ones that set a value. `None`
+/// when no container sets one, so "unset" never reads as zero.
+fn sum_quantities(containers: &[Container], bound: Bound, resource: &str) -> Option<Qty> {
+  containers
+    .iter()
+    .filter_map(|c| container_quantity(c, bound, resource))
+    .reduce(|total, qty| &total + &qty)
+}
+
+/// Summed limits of `containers`, formatted with `format`. A container without
+/// a limit is unbounded, so when only some set one the sum is marked as a
+/// lower bound, e.g. `≥1000m`.
+fn format_limits(
+  containers: &[Container],
+  resource: &str,
+  format: fn(Option<Qty>) -> String,
+) -> String {
+  let total = format(sum_quantities(containers, Bound::Limits, resource));
+  let unbounded = containers
+    .iter()
+    .any(|c| container_quantity(c, Bound::Limits, resource).is_none());
+  if unbounded && total != "-" {
+    format!("≥{}", total)
+  } else {
+    total
+  }
+}
+
+/// Summed CPU and memory requests of a pod's regular containers, as shown in
+/// the pods view. `None` for a resource no container requests.
+pub(crate) fn pod_requests(spec: &PodSpec) -> (Option<Qty>, Option<Qty>) {
+  (
+    sum_quantities(&spec.containers, Bound::Requests, CPU),
+    sum_quantities(&spec.containers, Bound::Requests, MEMORY),
+  )
+}
+
+/// Summed CPU and memory limits of a pod's regular containers, `None` for a
+/// resource no container limits.
+pub(crate) fn pod_limits(spec: &PodSpec) -> (Option<Qty>, Option<Qty>) {
+  (
+    sum_quantities(&spec.containers, Bound::Limits, CPU),
+    sum_quantities(&spec.containers, Bound::Limits, MEMORY),
+  )
+}
+
+/// The QoS class as the kubelet computes it (`GetPodQOS`): only non-zero CPU
+/// and memory values count, summed over regular and init containers. The pod is
+/// Guaranteed when every container limits both resources and the summed
+/// requests match the summed limits, BestEffort when nothing is set at all, and
+/// Burstable otherwise.
+fn get_qos_class(spec: &PodSpec) -> QosClass {
+  let containers = spec
+    .containers
+    .iter()
+    .chain(spec.init_containers.iter().flatten());
+  let mut requests: Vec<(&str, Qty)> = vec![];
+  let mut limits: Vec<(&str, Qty)> = vec![];
+  let mut is_guaranteed = true;
+  for container in containers {
+    for resource in [CPU, MEMORY] {
+      if let Some(qty) = container_quantity(container, Bound::Requests, resource) {
+        add_quantity(&mut requests, resource, qty);
+      }
+    }
+    let mut limited = 0;
+    for resource in [CPU, MEMORY] {
+      if let Some(qty) = container_quantity(container, Bound::Limits, resource) {
+        if add_quantity(&mut limits, resource, qty) {
+          limited += 1;
+        }
+      }
+    }
+    if limited < 2 {
+      is_guaranteed = false;
+    }
+  }
+
+  if requests.is_empty() && limits.is_empty() {
+    return QosClass::BestEffort;
+  }
+  let requests_match_limits = requests.len() == limits.len()
+    && requests.iter().all(|(resource, request)| {
+      limits
+        .iter()
+        .any(|(name, limit)| name == resource && limit.cmp(request).is_eq())
+    });
+  if is_guaranteed && requests_match_limits {
+    QosClass::Guaranteed
+  } else {
+    QosClass::Burstable
+  }
+}
+
+/// Add a non-zero quantity to the running total for its resource. Returns
+/// whether it counted.
+fn add_quantity<'a>(totals: &mut Vec<(&'a str, Qty)>, resource: &'a str, qty: Qty) -> bool {
+  if qty.is_zero() {
+    return false;
+  }
+  match totals.iter_mut().find(|(name, _)| *name == resource) {
+    Some((_, total)) => *total += &qty,
+    None => totals.push((resource, qty)),
+  }
+  true
+}
+
+pub(crate) fn format_cpu(qty: Option<Qty>) -> String {
+  match qty {
+    Some(qty) => format!("{}m", (f64::from(&qty) * 1000f64).round()),
+    None => "-".into(),
+  }
+}
+
+pub(crate) fn format_mem(qty: Option<Qty>) -> String {
+  const MI: f64 = 1024f64 * 1024f64;
+  match qty {
+    Some(qty) if f64::from(&qty) < MI => format!("{}Ki", (f64::from(&qty) / 1024f64).round()),
+    Some(qty) => format!("{}Mi", (f64::from(&qty) / MI).round()),
+    None => "-".into(),
+  }
+}
+
+fn get_container_state(os: Option<ContainerState>) -> (ContainerStateKind, String) {
+  match os {
     Some(s) => {
       if let Some(sw) = s.waiting {
-        sw.reason.unwrap_or_else(|| "Waiting".into())
+        (
+          ContainerStateKind::Waiting,
+          sw.reason.unwrap_or_else(|| "Waiting".into()),
+        )
       } else if let Some(st) = s.terminated {
-        st.reason.unwrap_or_else(|| "Terminating".into())
+        (
+          ContainerStateKind::Terminated,
+          st.reason.unwrap_or_else(|| "Terminating".into()),
+        )
       } else if s.running.is_some() {
-        "Running".into()
+        (ContainerStateKind::Running, "Running".into())
       } else {
-        "<none>".into()
+        (ContainerStateKind::Unknown, "<none>".into())
       }
     }
-    None => "<none>".into(),
+    None => (ContainerStateKind::Unknown, "<none>".into()),
   }
 }
 
@@ -738,11 +3113,39 @@ fn get_container_ports(ports_ref: &Option<Vec<ContainerPort>>) -> Option<String>
   })
 }
 
-#[cfg(test)]
-mod tests {
-  use super::*;
-  use crate::app::test_utils::*;
-  use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::app::test_utils::*;
+  use k8s_openapi::{
+    api::core::v1::{ContainerStateTerminated, Secret},
+    apimachinery::pkg::apis::meta::v1::ObjectMeta,
+  };
+
+  #[test]
+  fn test_eviction_error_explains_pdb_block() {
+    use kube::core::Status;
+
+    let blocked = kube::Error::Api(
+      Status::failure(
+        "Cannot evict pod as it would violate the pod's disruption budget.",
+        "TooManyRequests",
+      )
+      .with_code(429)
+      .boxed(),
+    );
+    assert_eq!(
+      eviction_error("web-1", &blocked),
+      "Eviction of pod web-1 is blocked by a PodDisruptionBudget: Cannot evict pod as it would violate the pod's disruption budget."
+    );
+
+    let missing = kube::Error::Api(
+      Status::failure("pods \"web-1\" not found", "NotFound")
+        .with_code(404)
+        .boxed(),
+    );
+    assert!(eviction_error("web-1", &missing).starts_with("Failed to evict pod web-1."));
+  }
 
   #[test]
   fn test_get_container_title() {
@@ -773,6 +3176,86 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_get_pods_title_shows_label_selector() {
+    let mut app = App::default();
+    assert_eq!(get_pods_title(&app), " Pods (ns: all) [0] ");
+    app.data.selected.label_selector = Some("app=nginx".into());
+    assert_eq!(
+      get_pods_title(&app),
+      " Pods (ns: all) [0] (labels: app=nginx) "
+    );
+  }
+
+  #[test]
+  fn test_log_wrap_label() {
+    let mut app = App::default();
+    assert_eq!(log_wrap_label(&app), "", "wrapping is the default");
+    app.log_wrap = false;
+    assert_eq!(log_wrap_label(&app), " · no wrap");
+  }
+
+  #[test]
+  fn test_log_search_label() {
+    let mut app = App::default();
+    assert_eq!(log_search_label(&app), "");
+    app.data.logs.search_active = true;
+    app.data.logs.search = "err".into();
+    assert_eq!(log_search_label(&app), " · /err▏");
+    app.data.logs.search_active = false;
+    assert_eq!(log_search_label(&app), " · /err no matches");
+  }
+
+  #[test]
+  fn test_log_since_label() {
+    let mut app = App::default();
+    assert_eq!(log_since_label(&app), "");
+    app.log_since = Some(std::time::Duration::from_secs(600));
+    assert_eq!(log_since_label(&app), " · since 10m");
+    app.log_previous = true;
+    assert_eq!(log_since_label(&app), "", "previous logs ignore the window");
+  }
+
+  #[test]
+  fn test_log_reconnecting_label() {
+    let mut app = App::default();
+    assert_eq!(log_reconnecting_label(&app), "");
+    app.log_reconnecting = 2;
+    assert_eq!(log_reconnecting_label(&app), " · reconnecting…");
+  }
+
+  #[test]
+  fn test_log_dropped_label_follows_the_configured_cap() {
+    let mut app = App::default();
+    app.config.log_max_lines = Some(2);
+    app.data.logs = app.new_logs_state("web".into());
+    app.add_log_records(vec!["a".into(), "b".into()]);
+    assert_eq!(log_dropped_label(&app), "");
+    app.add_log_records(vec!["c".into()]);
+    assert_eq!(log_dropped_label(&app), " · 1 older line dropped");
+    app.add_log_records(vec!["d".into(), "e".into()]);
+    assert_eq!(log_dropped_label(&app), " · 3 older lines dropped");
+  }
+
+  #[test]
+  fn test_log_follow_label() {
+    let mut app = App::default();
+    assert_eq!(log_follow_label(&app), "[FOLLOWING]");
+    app.log_auto_scroll = false;
+    app.data.logs.freeze_follow_position();
+    assert_eq!(log_follow_label(&app), "[PAUSED @ line 1]");
+    app.data.logs.add_records(vec!["a".into()]);
+    assert_eq!(
+      log_follow_label(&app),
+      "[PAUSED @ line 1 · 1 new line below]"
+    );
+    app.data.logs.add_records(vec!["b".into(), "c".into()]);
+    assert_eq!(
+      log_follow_label(&app),
+      "[PAUSED @ line 1 · 3 new lines below]"
+    );
+  }
+
   #[test]
   fn test_capitalize_first() {
     assert_eq!(capitalize_first("deployment"), "Deployment");
@@ -781,6 +3264,28 @@ mod tests {
     assert_eq!(capitalize_first("A"), "A");
   }
 
+  /// Probes of the same action and timing, one per kind.
+  fn probes(kinds: &[&'static str], action: &str, timing: &str) -> Vec<ContainerProbe> {
+    kinds
+      .iter()
+      .map(|kind| ContainerProbe {
+        kind,
+        action: action.into(),
+        timing: timing.into(),
+      })
+      .collect()
+  }
+
+  /// The service account token the fixture pods mount.
+  fn token_mount(secret: &str) -> Vec<ContainerMount> {
+    vec![ContainerMount {
+      name: secret.into(),
+      path: "/var/run/secrets/kubernetes.io/serviceaccount".into(),
+      read_only: true,
+      source: format!("secret {}", secret),
+    }]
+  }
+
   #[test]
   fn test_pod_from_api() {
     let now = Utc::now();
@@ -800,23 +3305,48 @@ mod tests {
         ready: (0, 1),
         status: "Pending".into(),
         restarts: 0,
+        last_restart: None,
+        uptime: "-".into(),
         cpu: "".into(),
         mem: "".into(),
+        cpu_requests: "200m".into(),
+        cpu_limits: "300m".into(),
+        mem_requests: "180Mi".into(),
+        mem_limits: "300Mi".into(),
+        qos: QosClass::Burstable,
         node: "".into(),
         ip: "".into(),
         age: utils::to_age(Some(&get_time("2021-04-27T10:13:58Z")), now),
         containers: vec![KubeContainer {
           name: "server".into(),
           image: "gcr.io/google-samples/microservices-demo/adservice:v0.2.2".into(),
+          running_image: String::new(),
+          pull_policy: "IfNotPresent".into(),
           ready: "false".into(),
           status: "<none>".into(),
+          state: ContainerStateKind::Unknown,
           restarts: 0,
+          last_termination: None,
           liveliness_probe: true,
           readiness_probe: true,
+          probes: probes(
+            &["Readiness", "Liveness"],
+            "exec [/bin/grpc_health_probe -addr=:9555]",
+            "delay=20s timeout=1s period=15s #success=1 #failure=3",
+          ),
+          mounts: token_mount("default-token-f72m5"),
+          env: vec![ContainerEnv {
+            name: "PORT".into(),
+            source: EnvSource::Value("9555".into()),
+          }],
           ports: "9555".into(),
+          cpu_requests: "200m".into(),
+          cpu_limits: "300m".into(),
+          mem_requests: "180Mi".into(),
+          mem_limits: "300Mi".into(),
           age: utils::to_age(Some(&get_time("2021-04-27T10:13:58Z")), now),
           pod_name: "adservice-f787c8dcd-tb6x2".into(),
-          init: false,
+          kind: ContainerKind::Regular,
           k8s_obj: None,
         }],
         k8s_obj: pods_list[0].clone()
@@ -830,23 +3360,52 @@ mod tests {
         ready: (0, 1),
         status: "CrashLoopBackOff".into(),
         restarts: 896,
+        last_restart: None,
+        uptime: "-".into(),
         cpu: "".into(),
         mem: "".into(),
+        cpu_requests: "200m".into(),
+        cpu_limits: "300m".into(),
+        mem_requests: "64Mi".into(),
+        mem_limits: "128Mi".into(),
+        qos: QosClass::Burstable,
         node: "gke-hello-hipster-default-pool-9e6f6ffb-q16l".into(),
         ip: "10.24.1.9".into(),
         age: utils::to_age(Some(&get_time("2021-04-27T10:13:57Z")), now),
         containers: vec![KubeContainer {
           name: "server".into(),
           image: "gcr.io/google-samples/microservices-demo/cartservice:v0.2.2".into(),
+          running_image: "gcr.io/google-samples/microservices-demo/cartservice:v0.2.2".into(),
+          pull_policy: "IfNotPresent".into(),
           ready: "false".into(),
           status: "CrashLoopBackOff".into(),
+          state: ContainerStateKind::Waiting,
           restarts: 896,
+          last_termination: Some(LastTermination {
+            reason: "Error".into(),
+            exit_code: 139,
+            finished: utils::to_age(Some(&get_time("2021-04-30T16:21:27Z")), now),
+          }),
           liveliness_probe: true,
           readiness_probe: true,
+          probes: probes(
+            &["Readiness", "Liveness"],
+            "exec [/bin/grpc_health_probe -addr=:7070 -rpc-timeout=5s]",
+            "delay=15s timeout=1s period=10s #success=1 #failure=3",
+          ),
+          mounts: token_mount("default-token-f72m5"),
+          env: vec![ContainerEnv {
+            name: "REDIS_ADDR".into(),
+            source: EnvSource::Value("redis-cart:6379".into()),
+          },],
           ports: "7070".into(),
+          cpu_requests: "200m".into(),
+          cpu_limits: "300m".into(),
+          mem_requests: "64Mi".into(),
+          mem_limits: "128Mi".into(),
           age: utils::to_age(Some(&get_time("2021-04-27T10:13:57Z")), now),
           pod_name: "cartservice-67b89ffc69-s5qp8".into(),
-          init: false,
+          kind: ContainerKind::Regular,
           k8s_obj: None,
         }],
         k8s_obj: pods_list[1].clone()
@@ -860,23 +3419,58 @@ mod tests {
         ready: (1, 1),
         status: "Running".into(),
         restarts: 3,
+        last_restart: None,
+        uptime: utils::to_age_secs(Some(&get_time("2021-04-27T10:15:15Z")), now),
         cpu: "".into(),
         mem: "".into(),
+        cpu_requests: "100m".into(),
+        cpu_limits: "200m".into(),
+        mem_requests: "64Mi".into(),
+        mem_limits: "128Mi".into(),
+        qos: QosClass::Burstable,
         node: "gke-hello-hipster-default-pool-9e6f6ffb-xzbc".into(),
         ip: "10.24.0.3".into(),
         age: utils::to_age(Some(&get_time("2021-04-27T10:13:56Z")), now),
         containers: vec![KubeContainer {
           name: "server".into(),
           image: "gcr.io/google-samples/microservices-demo/emailservice:v0.2.2".into(),
+          running_image: "gcr.io/google-samples/microservices-demo/emailservice:v0.2.2".into(),
+          pull_policy: "IfNotPresent".into(),
           ready: "true".into(),
           status: "Running".into(),
+          state: ContainerStateKind::Running,
           restarts: 3,
+          last_termination: Some(LastTermination {
+            reason: "Error".into(),
+            exit_code: 1,
+            finished: utils::to_age(Some(&get_time("2021-04-27T10:14:47Z")), now),
+          }),
           liveliness_probe: true,
           readiness_probe: true,
+          probes: probes(
+            &["Readiness", "Liveness"],
+            "exec [/bin/grpc_health_probe -addr=:8080]",
+            "delay=0s timeout=1s period=5s #success=1 #failure=3",
+          ),
+          mounts: token_mount("default-token-f72m5"),
+          env: vec![
+            ContainerEnv {
+              name: "PORT".into(),
+              source: EnvSource::Value("8080".into()),
+            },
+            ContainerEnv {
+              name: "DISABLE_PROFILER".into(),
+              source: EnvSource::Value("1".into()),
+            },
+          ],
           ports: "8080".into(),
+          cpu_requests: "100m".into(),
+          cpu_limits: "200m".into(),
+          mem_requests: "64Mi".into(),
+          mem_limits: "128Mi".into(),
           age: utils::to_age(Some(&get_time("2021-04-27T10:13:56Z")), now),
           pod_name: "emailservice-5f8fc7dbb4-5lqdb".into(),
-          init: false,
+          kind: ContainerKind::Regular,
           k8s_obj: None,
         }],
         k8s_obj: pods_list[3].clone()
@@ -899,16 +3493,68 @@ mod tests {
       vec![KubeContainer {
         name: "server".into(),
         image: "gcr.io/google-samples/microservices-demo/frontend:v0.2.2".into(),
+        running_image: String::new(),
+        pull_policy: "IfNotPresent".into(),
         ready: "false".into(),
         status: "<none>".into(),
+        state: ContainerStateKind::Unknown,
         restarts: 0,
+        last_termination: None,
         liveliness_probe: true,
         readiness_probe: true,
+        probes: probes(
+          &["Readiness", "Liveness"],
+          "http-get http://:8080/_healthz",
+          "delay=10s timeout=1s period=10s #success=1 #failure=3",
+        ),
+        mounts: token_mount("default-token-f72m5"),
+        env: vec![
+          ContainerEnv {
+            name: "PORT".into(),
+            source: EnvSource::Value("8080".into()),
+          },
+          ContainerEnv {
+            name: "PRODUCT_CATALOG_SERVICE_ADDR".into(),
+            source: EnvSource::Value("productcatalogservice:3550".into()),
+          },
+          ContainerEnv {
+            name: "CURRENCY_SERVICE_ADDR".into(),
+            source: EnvSource::Value("currencyservice:7000".into()),
+          },
+          ContainerEnv {
+            name: "CART_SERVICE_ADDR".into(),
+            source: EnvSource::Value("cartservice:7070".into()),
+          },
+          ContainerEnv {
+            name: "RECOMMENDATION_SERVICE_ADDR".into(),
+            source: EnvSource::Value("recommendationservice:8080".into()),
+          },
+          ContainerEnv {
+            name: "SHIPPING_SERVICE_ADDR".into(),
+            source: EnvSource::Value("shippingservice:50051".into()),
+          },
+          ContainerEnv {
+            name: "CHECKOUT_SERVICE_ADDR".into(),
+            source: EnvSource::Value("checkoutservice:5050".into()),
+          },
+          ContainerEnv {
+            name: "AD_SERVICE_ADDR".into(),
+            source: EnvSource::Value("adservice:9555".into()),
+          },
+          ContainerEnv {
+            name: "ENV_PLATFORM".into(),
+            source: EnvSource::Value("gcp".into()),
+          },
+        ],
         ports: "8080".into(),
+        cpu_requests: "100m".into(),
+        cpu_limits: "200m".into(),
+        mem_requests: "64Mi".into(),
+        mem_limits: "128Mi".into(),
         age: utils::to_age(Some(&get_time("2021-04-27T10:14:48Z")), now),
         pod_name: "frontend-5c4745dfdb-6k8wf".into(),
         k8s_obj: None,
-        init: false,
+        kind: ContainerKind::Regular,
       }]
     );
     assert_eq!(
@@ -923,24 +3569,83 @@ mod tests {
         ready: (0, 0),
         status: "Preempting".into(),
         restarts: 0,
+        last_restart: None,
+        uptime: "-".into(),
         cpu: "".into(),
         mem: "".into(),
+        cpu_requests: "100m".into(),
+        cpu_limits: "200m".into(),
+        mem_requests: "64Mi".into(),
+        mem_limits: "128Mi".into(),
+        qos: QosClass::Burstable,
         node: "gke-hello-hipster-default-pool-9e6f6ffb-q16l".into(),
         ip: "".into(),
         age: utils::to_age(Some(&get_time("2021-04-27T10:13:57Z")), now),
         containers: vec![KubeContainer {
           name: "server".into(),
           image: "gcr.io/google-samples/microservices-demo/frontend:v0.2.2".into(),
+          running_image: String::new(),
+          pull_policy: "IfNotPresent".into(),
           ready: "false".into(),
           status: "<none>".into(),
+          state: ContainerStateKind::Unknown,
           restarts: 0,
+          last_termination: None,
           liveliness_probe: false,
           readiness_probe: true,
+          probes: probes(
+            &["Readiness"],
+            "http-get http://:8080/_healthz",
+            "delay=10s timeout=1s period=10s #success=1 #failure=3",
+          ),
+          mounts: token_mount("default-token-f72m5"),
+          env: vec![
+            ContainerEnv {
+              name: "PORT".into(),
+              source: EnvSource::Value("8080".into()),
+            },
+            ContainerEnv {
+              name: "PRODUCT_CATALOG_SERVICE_ADDR".into(),
+              source: EnvSource::Value("productcatalogservice:3550".into()),
+            },
+            ContainerEnv {
+              name: "CURRENCY_SERVICE_ADDR".into(),
+              source: EnvSource::Value("currencyservice:7000".into()),
+            },
+            ContainerEnv {
+              name: "CART_SERVICE_ADDR".into(),
+              source: EnvSource::Value("cartservice:7070".into()),
+            },
+            ContainerEnv {
+              name: "RECOMMENDATION_SERVICE_ADDR".into(),
+              source: EnvSource::Value("recommendationservice:8080".into()),
+            },
+            ContainerEnv {
+              name: "SHIPPING_SERVICE_ADDR".into(),
+              source: EnvSource::Value("shippingservice:50051".into()),
+            },
+            ContainerEnv {
+              name: "CHECKOUT_SERVICE_ADDR".into(),
+              source: EnvSource::Value("checkoutservice:5050".into()),
+            },
+            ContainerEnv {
+              name: "AD_SERVICE_ADDR".into(),
+              source: EnvSource::Value("adservice:9555".into()),
+            },
+            ContainerEnv {
+              name: "ENV_PLATFORM".into(),
+              source: EnvSource::Value("gcp".into()),
+            },
+          ],
           ports: "8080/HTTP".into(),
+          cpu_requests: "100m".into(),
+          cpu_limits: "200m".into(),
+          mem_requests: "64Mi".into(),
+          mem_limits: "128Mi".into(),
           age: utils::to_age(Some(&get_time("2021-04-27T10:13:57Z")), now),
           pod_name: "frontend-5c4745dfdb-qz7fg".into(),
           k8s_obj: None,
-          init: false,
+          kind: ContainerKind::Regular,
         }],
         k8s_obj: pods_list[5].clone()
       }
@@ -953,24 +3658,83 @@ mod tests {
         ready: (0, 0),
         status: "Failed".into(),
         restarts: 0,
+        last_restart: None,
+        uptime: "-".into(),
         cpu: "".into(),
         mem: "".into(),
+        cpu_requests: "100m".into(),
+        cpu_limits: "200m".into(),
+        mem_requests: "64Mi".into(),
+        mem_limits: "128Mi".into(),
+        qos: QosClass::Burstable,
         node: "gke-hello-hipster-default-pool-9e6f6ffb-q16l".into(),
         ip: "".into(),
         age: utils::to_age(Some(&get_time("2021-04-27T10:14:48Z")), now),
         containers: vec![KubeContainer {
           name: "server".into(),
           image: "gcr.io/google-samples/microservices-demo/frontend:v0.2.2".into(),
+          running_image: String::new(),
+          pull_policy: "IfNotPresent".into(),
           ready: "false".into(),
           status: "<none>".into(),
+          state: ContainerStateKind::Unknown,
           restarts: 0,
+          last_termination: None,
           liveliness_probe: true,
           readiness_probe: true,
+          probes: probes(
+            &["Readiness", "Liveness"],
+            "http-get http://:8080/_healthz",
+            "delay=10s timeout=1s period=10s #success=1 #failure=3",
+          ),
+          mounts: token_mount("default-token-f72m5"),
+          env: vec![
+            ContainerEnv {
+              name: "PORT".into(),
+              source: EnvSource::Value("8080".into()),
+            },
+            ContainerEnv {
+              name: "PRODUCT_CATALOG_SERVICE_ADDR".into(),
+              source: EnvSource::Value("productcatalogservice:3550".into()),
+            },
+            ContainerEnv {
+              name: "CURRENCY_SERVICE_ADDR".into(),
+              source: EnvSource::Value("currencyservice:7000".into()),
+            },
+            ContainerEnv {
+              name: "CART_SERVICE_ADDR".into(),
+              source: EnvSource::Value("cartservice:7070".into()),
+            },
+            ContainerEnv {
+              name: "RECOMMENDATION_SERVICE_ADDR".into(),
+              source: EnvSource::Value("recommendationservice:8080".into()),
+            },
+            ContainerEnv {
+              name: "SHIPPING_SERVICE_ADDR".into(),
+              source: EnvSource::Value("shippingservice:50051".into()),
+            },
+            ContainerEnv {
+              name: "CHECKOUT_SERVICE_ADDR".into(),
+              source: EnvSource::Value("checkoutservice:5050".into()),
+            },
+            ContainerEnv {
+              name: "AD_SERVICE_ADDR".into(),
+              source: EnvSource::Value("adservice:9555".into()),
+            },
+            ContainerEnv {
+              name: "ENV_PLATFORM".into(),
+              source: EnvSource::Value("gcp".into()),
+            },
+          ],
           ports: "8080, 8081/UDP, Foo:8082/UDP, 8083".into(),
+          cpu_requests: "100m".into(),
+          cpu_limits: "200m".into(),
+          mem_requests: "64Mi".into(),
+          mem_limits: "128Mi".into(),
           age: utils::to_age(Some(&get_time("2021-04-27T10:14:48Z")), now),
           pod_name: "frontend-5c4745dfdb-6k8wf".into(),
           k8s_obj: None,
-          init: false,
+          kind: ContainerKind::Regular,
         }],
         k8s_obj: pods_list[6].clone()
       }
@@ -983,8 +3747,15 @@ mod tests {
         ready: (0, 1),
         status: "Init:1/2".into(),
         restarts: 0,
+        last_restart: None,
+        uptime: "-".into(),
         cpu: "".into(),
         mem: "".into(),
+        cpu_requests: "-".into(),
+        cpu_limits: "-".into(),
+        mem_requests: "-".into(),
+        mem_limits: "-".into(),
+        qos: QosClass::BestEffort,
         node: "k3d-my-kdash-cluster-server-0".into(),
         ip: "10.42.0.20".into(),
         age: utils::to_age(Some(&get_time("2021-06-18T08:57:56Z")), now),
@@ -992,110 +3763,709 @@ mod tests {
           KubeContainer {
             name: "main-busybox".into(),
             image: "busybox".into(),
+            running_image: "busybox".into(),
+            pull_policy: "Always".into(),
             ready: "false".into(),
             status: "PodInitializing".into(),
+            state: ContainerStateKind::Waiting,
             restarts: 0,
+            last_termination: None,
             liveliness_probe: false,
             readiness_probe: false,
+            probes: vec![],
+            mounts: token_mount("default-token-b7z5m"),
+            env: vec![],
             ports: "".into(),
+            cpu_requests: "-".into(),
+            cpu_limits: "-".into(),
+            mem_requests: "-".into(),
+            mem_limits: "-".into(),
             age: utils::to_age(Some(&get_time("2021-06-18T08:57:56Z")), now),
             pod_name: "pod-init-container".into(),
             k8s_obj: None,
-            init: false,
+            kind: ContainerKind::Regular,
           },
           KubeContainer {
             name: "init-busybox1".into(),
             image: "busybox".into(),
+            running_image: "docker.io/library/busybox:latest".into(),
+            pull_policy: "Always".into(),
             ready: "true".into(),
             status: "Completed".into(),
+            state: ContainerStateKind::Terminated,
             restarts: 0,
+            last_termination: None,
             liveliness_probe: false,
             readiness_probe: false,
+            probes: vec![],
+            mounts: token_mount("default-token-b7z5m"),
+            env: vec![],
             ports: "".into(),
+            cpu_requests: "-".into(),
+            cpu_limits: "-".into(),
+            mem_requests: "-".into(),
+            mem_limits: "-".into(),
             age: utils::to_age(Some(&get_time("2021-06-18T08:57:56Z")), now),
             pod_name: "pod-init-container".into(),
             k8s_obj: None,
-            init: true,
+            kind: ContainerKind::Init,
           },
           KubeContainer {
             name: "init-busybox2".into(),
             image: "busybox".into(),
+            running_image: "docker.io/library/busybox:latest".into(),
+            pull_policy: "Always".into(),
             ready: "false".into(),
             status: "Running".into(),
+            state: ContainerStateKind::Running,
             restarts: 0,
+            last_termination: None,
             liveliness_probe: false,
             readiness_probe: false,
+            probes: vec![],
+            mounts: token_mount("default-token-b7z5m"),
+            env: vec![],
             ports: "".into(),
+            cpu_requests: "-".into(),
+            cpu_limits: "-".into(),
+            mem_requests: "-".into(),
+            mem_limits: "-".into(),
             age: utils::to_age(Some(&get_time("2021-06-18T08:57:56Z")), now),
             pod_name: "pod-init-container".into(),
             k8s_obj: None,
-            init: true,
+            kind: ContainerKind::Init,
           }
         ],
         k8s_obj: pods_list[11].clone()
       }
     );
     assert_eq!(
-      pods[12],
-      KubePod {
-        namespace: "default".into(),
-        name: "pod-init-container-2".into(),
-        ready: (0, 1),
-        status: "Completed".into(),
-        restarts: 0,
-        cpu: "".into(),
-        mem: "".into(),
-        node: "k3d-my-kdash-cluster-server-0".into(),
-        ip: "10.42.0.21".into(),
-        age: utils::to_age(Some(&get_time("2021-06-18T09:26:11Z")), now),
-        containers: vec![
-          KubeContainer {
-            name: "main-busybox".into(),
-            image: "busybox".into(),
-            ready: "false".into(),
-            status: "Completed".into(),
-            restarts: 0,
-            liveliness_probe: false,
-            readiness_probe: false,
-            ports: "".into(),
-            age: utils::to_age(Some(&get_time("2021-06-18T09:26:11Z")), now),
-            pod_name: "pod-init-container-2".into(),
-            k8s_obj: None,
-            init: false,
+      pods[12],
+      KubePod {
+        namespace: "default".into(),
+        name: "pod-init-container-2".into(),
+        ready: (0, 1),
+        status: "Completed".into(),
+        restarts: 0,
+        last_restart: None,
+        uptime: "-".into(),
+        cpu: "".into(),
+        mem: "".into(),
+        cpu_requests: "-".into(),
+        cpu_limits: "-".into(),
+        mem_requests: "-".into(),
+        mem_limits: "-".into(),
+        qos: QosClass::BestEffort,
+        node: "k3d-my-kdash-cluster-server-0".into(),
+        ip: "10.42.0.21".into(),
+        age: utils::to_age(Some(&get_time("2021-06-18T09:26:11Z")), now),
+        containers: vec![
+          KubeContainer {
+            name: "main-busybox".into(),
+            image: "busybox".into(),
+            running_image: "docker.io/library/busybox:latest".into(),
+            pull_policy: "Always".into(),
+            ready: "false".into(),
+            status: "Completed".into(),
+            state: ContainerStateKind::Terminated,
+            restarts: 0,
+            last_termination: None,
+            liveliness_probe: false,
+            readiness_probe: false,
+            probes: vec![],
+            mounts: token_mount("default-token-b7z5m"),
+            env: vec![],
+            ports: "".into(),
+            cpu_requests: "-".into(),
+            cpu_limits: "-".into(),
+            mem_requests: "-".into(),
+            mem_limits: "-".into(),
+            age: utils::to_age(Some(&get_time("2021-06-18T09:26:11Z")), now),
+            pod_name: "pod-init-container-2".into(),
+            k8s_obj: None,
+            kind: ContainerKind::Regular,
+          },
+          KubeContainer {
+            name: "init-busybox1".into(),
+            image: "busybox".into(),
+            running_image: "docker.io/library/busybox:latest".into(),
+            pull_policy: "Always".into(),
+            ready: "true".into(),
+            status: "Completed".into(),
+            state: ContainerStateKind::Terminated,
+            restarts: 0,
+            last_termination: None,
+            liveliness_probe: false,
+            readiness_probe: false,
+            probes: vec![],
+            mounts: token_mount("default-token-b7z5m"),
+            env: vec![],
+            ports: "".into(),
+            cpu_requests: "-".into(),
+            cpu_limits: "-".into(),
+            mem_requests: "-".into(),
+            mem_limits: "-".into(),
+            age: utils::to_age(Some(&get_time("2021-06-18T09:26:11Z")), now),
+            pod_name: "pod-init-container-2".into(),
+            k8s_obj: None,
+            kind: ContainerKind::Init,
+          },
+          KubeContainer {
+            name: "init-busybox2".into(),
+            image: "busybox".into(),
+            running_image: "docker.io/library/busybox:latest".into(),
+            pull_policy: "Always".into(),
+            ready: "true".into(),
+            status: "Completed".into(),
+            state: ContainerStateKind::Terminated,
+            restarts: 0,
+            last_termination: None,
+            liveliness_probe: false,
+            readiness_probe: false,
+            probes: vec![],
+            mounts: token_mount("default-token-b7z5m"),
+            env: vec![],
+            ports: "".into(),
+            cpu_requests: "-".into(),
+            cpu_limits: "-".into(),
+            mem_requests: "-".into(),
+            mem_limits: "-".into(),
+            age: utils::to_age(Some(&get_time("2021-06-18T09:26:11Z")), now),
+            pod_name: "pod-init-container-2".into(),
+            k8s_obj: None,
+            kind: ContainerKind::Init,
+          }
+        ],
+        k8s_obj: pods_list[12].clone()
+      }
+    );
+    // TODO add tests for NodeLost case
+  }
+
+  #[test]
+  fn test_container_probes_describe_each_handler() {
+    let container: Container = serde_json::from_value(serde_json::json!({
+      "name": "app",
+      "readinessProbe": {
+        "httpGet": { "path": "/ready", "port": "http", "scheme": "HTTPS" },
+        "periodSeconds": 5
+      },
+      "livenessProbe": { "tcpSocket": { "port": 6379 }, "failureThreshold": 6 },
+      "startupProbe": { "grpc": { "port": 50051 }, "initialDelaySeconds": 30 }
+    }))
+    .unwrap();
+
+    assert_eq!(
+      ContainerProbe::list(&container),
+      vec![
+        ContainerProbe {
+          kind: "Readiness",
+          action: "http-get https://:http/ready".into(),
+          timing: "delay=0s timeout=1s period=5s #success=1 #failure=3".into(),
+        },
+        ContainerProbe {
+          kind: "Liveness",
+          action: "tcp-socket :6379".into(),
+          timing: "delay=0s timeout=1s period=10s #success=1 #failure=6".into(),
+        },
+        ContainerProbe {
+          kind: "Startup",
+          action: "grpc <pod>:50051".into(),
+          timing: "delay=30s timeout=1s period=10s #success=1 #failure=3".into(),
+        },
+      ]
+    );
+    assert!(ContainerProbe::list(&Container::default()).is_empty());
+  }
+
+  #[test]
+  fn test_container_mounts_join_pod_volumes() {
+    let pod: Pod = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web", "namespace": "default" },
+      "spec": {
+        "containers": [{
+          "name": "app",
+          "volumeMounts": [
+            { "name": "config", "mountPath": "/etc/app/app.yaml", "subPath": "app.yaml", "readOnly": true },
+            { "name": "data", "mountPath": "/data" },
+            { "name": "cache", "mountPath": "/tmp/cache" },
+            { "name": "kube-api-access", "mountPath": "/var/run/secrets/kubernetes.io/serviceaccount", "readOnly": true },
+            { "name": "gone", "mountPath": "/gone" }
+          ]
+        }],
+        "volumes": [
+          { "name": "config", "configMap": { "name": "app-config" } },
+          { "name": "data", "persistentVolumeClaim": { "claimName": "web-data" } },
+          { "name": "cache", "emptyDir": { "medium": "Memory" } },
+          { "name": "kube-api-access", "projected": { "sources": [
+            { "serviceAccountToken": { "path": "token" } },
+            { "configMap": { "name": "kube-root-ca.crt" } },
+            { "downwardAPI": {} }
+          ] } }
+        ]
+      },
+      "status": {}
+    }))
+    .unwrap();
+    let pod = KubePod::from(pod);
+
+    let sources: Vec<_> = pod.containers[0]
+      .mounts
+      .iter()
+      .map(|m| (m.path.as_str(), m.read_only, m.source.as_str()))
+      .collect();
+    assert_eq!(
+      sources,
+      vec![
+        (
+          "/etc/app/app.yaml (subPath app.yaml)",
+          true,
+          "configMap app-config"
+        ),
+        ("/data", false, "persistentVolumeClaim web-data"),
+        ("/tmp/cache", false, "emptyDir (Memory)"),
+        (
+          "/var/run/secrets/kubernetes.io/serviceaccount",
+          true,
+          "projected (serviceAccountToken, configMap kube-root-ca.crt, downwardAPI)"
+        ),
+        ("/gone", false, "not in pod volumes"),
+      ]
+    );
+
+    let lines = ContainerMount::lines(&pod.containers[0].mounts[1..3]);
+    assert_eq!(
+      lines,
+      vec![
+        "data   /data       rw  persistentVolumeClaim web-data",
+        "cache  /tmp/cache  rw  emptyDir (Memory)",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_container_mount_lines_fold_long_lists() {
+    let mount = |name: &str| ContainerMount {
+      name: name.into(),
+      path: format!("/{}", name),
+      read_only: false,
+      source: "emptyDir".into(),
+    };
+    let text =
+      |lines: Vec<Line<'_>>| -> Vec<String> { lines.iter().map(|line| line.to_string()).collect() };
+    let palette = crate::ui::theme::palette_for(crate::ui::theme::ThemeName::Macchiato);
+
+    let mut container = KubeContainer::default();
+    assert_eq!(
+      text(container_mount_lines(&container, false, palette)),
+      vec!["Mounts:    none"]
+    );
+
+    container.mounts = vec![mount("a"), mount("b")];
+    assert_eq!(
+      text(container_mount_lines(&container, false, palette)),
+      vec![
+        "Mounts:    a  /a  rw  emptyDir",
+        "           b  /b  rw  emptyDir"
+      ]
+    );
+
+    container.mounts = ["a", "b", "c", "d", "e"].map(mount).to_vec();
+    let folded = text(container_mount_lines(&container, false, palette));
+    assert_eq!(folded.len(), INLINE_MOUNTS + 1);
+    assert_eq!(folded[3], "           … 2 more · z:expand/fold");
+    assert_eq!(
+      text(container_mount_lines(&container, true, palette)),
+      vec!["Mounts:    5 · z:expand/fold"]
+    );
+  }
+
+  #[test]
+  fn test_container_env_lists_sources_and_hides_secrets() {
+    let container: Container = serde_json::from_value(serde_json::json!({
+      "name": "app",
+      "envFrom": [
+        { "configMapRef": { "name": "app-config" } },
+        { "secretRef": { "name": "api-keys" }, "prefix": "API_" }
+      ],
+      "env": [
+        { "name": "PORT", "value": "8080" },
+        { "name": "DB_PASSWORD", "valueFrom": { "secretKeyRef": { "name": "db-creds", "key": "password" } } },
+        { "name": "LOG_LEVEL", "valueFrom": { "configMapKeyRef": { "name": "app-config", "key": "level" } } },
+        { "name": "POD_NAME", "valueFrom": { "fieldRef": { "fieldPath": "metadata.name" } } },
+        { "name": "MEM", "valueFrom": { "resourceFieldRef": { "resource": "limits.memory" } } }
+      ]
+    }))
+    .unwrap();
+    let env = ContainerEnv::list(&container);
+    assert_eq!(env.iter().filter(|e| e.is_secret()).count(), 2);
+    assert_eq!(
+      ContainerEnv::lines(&env, None, "shop"),
+      vec![
+        "all of configMap app-config",
+        "all of secret api-keys, prefixed API_",
+        "PORT         8080",
+        "DB_PASSWORD  from secret db-creds key password",
+        "LOG_LEVEL    from configMap app-config key level",
+        "POD_NAME     from field metadata.name",
+        "MEM          from resource limits.memory",
+      ]
+    );
+
+    let secret: Secret = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "db-creds", "namespace": "shop" },
+      "data": { "password": "aHVudGVyMg==" }
+    }))
+    .unwrap();
+    let secrets = vec![KubeSecret::from(secret)];
+    let revealed = ContainerEnv::lines(&env, Some(&secrets), "shop");
+    assert_eq!(
+      revealed[1],
+      "all of secret api-keys, prefixed API_ (secret not loaded)"
+    );
+    assert_eq!(
+      revealed[3],
+      "DB_PASSWORD  from secret db-creds key password = hunter2"
+    );
+    // secrets of another namespace are not used
+    assert_eq!(
+      ContainerEnv::lines(&env, Some(&secrets), "default")[3],
+      "DB_PASSWORD  from secret db-creds key password = (secret not loaded)"
+    );
+  }
+
+  #[test]
+  fn test_pod_scheduling_reports_unschedulable_condition() {
+    let pending: Pod = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "big", "namespace": "default" },
+      "spec": { "containers": [{ "name": "app" }] },
+      "status": {
+        "phase": "Pending",
+        "nominatedNodeName": "node-b",
+        "conditions": [{
+          "type": "PodScheduled",
+          "status": "False",
+          "reason": "Unschedulable",
+          "message": "0/5 nodes are available: 5 Insufficient memory."
+        }]
+      }
+    }))
+    .unwrap();
+
+    let scheduling = KubePod::from(pending).scheduling();
+    assert_eq!(
+      scheduling,
+      PodScheduling {
+        node: String::new(),
+        nominated_node: "node-b".into(),
+        scheduled: Some("False".into()),
+        reason: "Unschedulable".into(),
+        message: "0/5 nodes are available: 5 Insufficient memory.".into(),
+        scheduler: "default-scheduler".into(),
+      }
+    );
+    assert!(scheduling.is_failing());
+    assert!(!scheduling.has_custom_scheduler());
+
+    let (pods, _): (Vec<KubePod>, Vec<_>) = convert_resource_from_file("pods");
+    assert_eq!(
+      pods[0].scheduling().message,
+      "0/2 nodes are available: 2 Insufficient cpu."
+    );
+    let running = pods[1].scheduling();
+    assert_eq!(running.node, pods[1].node);
+    assert_eq!(running.scheduled.as_deref(), Some("True"));
+    assert!(!running.is_failing());
+
+    let mut batch = pods[1].clone();
+    batch.k8s_obj.spec.as_mut().unwrap().scheduler_name = Some("volcano".into());
+    let batch = batch.scheduling();
+    assert_eq!(batch.scheduler, "volcano");
+    assert!(batch.has_custom_scheduler());
+  }
+
+  #[test]
+  fn test_pod_status_line_shows_conditions_and_live_marker() {
+    let pod: Pod = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web", "namespace": "default" },
+      "spec": { "containers": [{ "name": "app" }] },
+      "status": {
+        "phase": "Running",
+        "conditions": [
+          { "type": "PodScheduled", "status": "True" },
+          { "type": "Ready", "status": "False" }
+        ],
+        "containerStatuses": [{
+          "name": "app", "image": "nginx", "imageID": "", "ready": false, "restartCount": 3,
+          "state": { "running": {} }
+        }]
+      }
+    }))
+    .unwrap();
+    let pod = KubePod::from(pod);
+    assert_eq!(
+      pod.conditions(),
+      vec![("PodScheduled".into(), true), ("Ready".into(), false)]
+    );
+
+    let palette = crate::ui::theme::palette_for(crate::ui::theme::ThemeName::Macchiato);
+    let text = |live| {
+      pod_status_line(&pod, live, palette)
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect::<String>()
+    };
+    assert_eq!(
+      text(true),
+      "Pod:       Running · 0/1 ready · 3 restarts · PodScheduled ✓ Ready ✗ · live"
+    );
+    assert!(!text(false).ends_with("live"));
+  }
+
+  #[test]
+  fn test_containers_show_their_own_state_and_name_the_failing_one() {
+    let status = |name: &str, ready: bool, state: serde_json::Value| {
+      serde_json::json!({
+        "name": name, "image": "nginx", "imageID": "", "ready": ready, "restartCount": 0,
+        "state": state
+      })
+    };
+    let pod: Pod = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web", "namespace": "default" },
+      "spec": { "containers": [
+        { "name": "app" }, { "name": "proxy" }, { "name": "cache" }, { "name": "agent" }
+      ] },
+      "status": {
+        "phase": "Running",
+        "containerStatuses": [
+          status("app", true, serde_json::json!({ "running": {} })),
+          status("proxy", false, serde_json::json!({ "waiting": { "reason": "CrashLoopBackOff" } })),
+          status("cache", false, serde_json::json!({ "waiting": { "reason": "ContainerCreating" } })),
+          status("agent", false, serde_json::json!({ "terminated": { "reason": "OOMKilled", "exitCode": 137 } })),
+        ]
+      }
+    }))
+    .unwrap();
+    let pod = KubePod::from(pod);
+    let states: Vec<_> = pod
+      .containers
+      .iter()
+      .map(|c| (c.state_label(), c.is_failing()))
+      .collect();
+    assert_eq!(
+      states,
+      vec![
+        ("Running".to_string(), false),
+        ("Waiting: CrashLoopBackOff".into(), true),
+        ("Waiting: ContainerCreating".into(), false),
+        ("Terminated: OOMKilled".into(), true),
+      ]
+    );
+
+    let palette = crate::ui::theme::palette_for(crate::ui::theme::ThemeName::Macchiato);
+    let line = pod_status_line(&pod, false, palette);
+    let failing = line
+      .spans
+      .iter()
+      .find(|span| span.content.contains("failing"))
+      .unwrap();
+    assert_eq!(
+      failing.content,
+      " failing: proxy (CrashLoopBackOff), agent (OOMKilled) ·"
+    );
+    assert_eq!(failing.style, style_failure(palette));
+  }
+
+  #[test]
+  fn test_pod_conditions_include_readiness_gates_and_explain_unmet_ones() {
+    let pod: Pod = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web", "namespace": "default" },
+      "spec": {
+        "containers": [{ "name": "app" }],
+        "readinessGates": [
+          { "conditionType": "example.com/lb-registered" },
+          { "conditionType": "example.com/warmed-up" }
+        ]
+      },
+      "status": {
+        "phase": "Running",
+        "conditions": [
+          { "type": "PodScheduled", "status": "True" },
+          { "type": "example.com/warmed-up", "status": "True" },
+          {
+            "type": "Ready",
+            "status": "False",
+            "reason": "ReadinessGatesNotReady",
+            "message": "corresponding condition of pod readiness gate \"example.com/lb-registered\" does not exist."
+          }
+        ]
+      }
+    }))
+    .unwrap();
+    let pod = KubePod::from(pod);
+
+    let details = pod.condition_details();
+    assert_eq!(details.len(), 4);
+    assert!(details[1].readiness_gate && details[1].holds());
+    assert!(!details[2].readiness_gate);
+    assert_eq!(
+      details[3],
+      PodCondition {
+        type_: "example.com/lb-registered".into(),
+        readiness_gate: true,
+        ..PodCondition::default()
+      }
+    );
+    assert_eq!(
+      pod.conditions().last(),
+      Some(&("example.com/lb-registered".to_string(), false))
+    );
+
+    let palette = crate::ui::theme::palette_for(crate::ui::theme::ThemeName::Macchiato);
+    let lines: Vec<String> = unmet_condition_lines(&pod, palette)
+      .iter()
+      .map(|line| {
+        line
+          .spans
+          .iter()
+          .map(|span| span.content.as_ref())
+          .collect()
+      })
+      .collect();
+    assert_eq!(
+      lines,
+      vec![
+        "           Ready: False  ReadinessGatesNotReady: corresponding condition of pod readiness gate \"example.com/lb-registered\" does not exist.",
+        "           gate example.com/lb-registered: not reported",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_pod_event_lines_list_the_pods_latest_events() {
+    use crate::app::events::KubeEvent;
+    use k8s_openapi::api::core::v1::Event;
+
+    let pod = KubePod {
+      name: "web".into(),
+      namespace: "default".into(),
+      ..KubePod::default()
+    };
+    let event = |type_: &str, reason: &str, count: i32| -> KubeEvent {
+      let event: Event = serde_json::from_value(serde_json::json!({
+        "metadata": { "name": reason, "namespace": "default" },
+        "involvedObject": { "kind": "Pod", "name": "web" },
+        "type": type_,
+        "reason": reason,
+        "message": format!("{} message", reason),
+        "count": count,
+      }))
+      .unwrap();
+      event.into()
+    };
+    let palette = crate::ui::theme::palette_for(crate::ui::theme::ThemeName::Macchiato);
+    let text =
+      |line: &Line<'_>| -> String { line.spans.iter().map(|s| s.content.as_ref()).collect() };
+
+    assert!(
+      pod_event_lines(None, &pod, palette).is_empty(),
+      "not fetched yet"
+    );
+    let other = PodEvents::new("default", "db", vec![event("Normal", "Pulled", 1)]);
+    assert!(pod_event_lines(Some(&other), &pod, palette).is_empty());
+    let none = PodEvents::new("default", "web", vec![]);
+    assert_eq!(
+      text(&pod_event_lines(Some(&none), &pod, palette)[0]),
+      "Events:    none"
+    );
+
+    let events = PodEvents::new(
+      "default",
+      "web",
+      vec![
+        event("Warning", "BackOff", 12),
+        event("Normal", "Pulled", 1),
+      ],
+    );
+    let lines = pod_event_lines(Some(&events), &pod, palette);
+    assert_eq!(lines.len(), 2);
+    assert!(text(&lines[0]).starts_with("Events:"));
+    assert!(text(&lines[0]).ends_with("Warning  BackOff: BackOff message (x12)"));
+    assert_eq!(lines[0].spans[2].style, style_warning(palette));
+    assert!(text(&lines[1]).starts_with("           "));
+    assert!(text(&lines[1]).ends_with("Normal   Pulled: Pulled message"));
+    assert_eq!(lines[1].spans[2].style, style_text(palette));
+  }
+
+  #[test]
+  fn test_pod_scheduling_constraints_are_formatted_per_rule() {
+    let pod: Pod = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web", "namespace": "default" },
+      "spec": {
+        "containers": [{ "name": "app" }],
+        "nodeSelector": { "disktype": "ssd" },
+        "tolerations": [
+          { "key": "dedicated", "operator": "Equal", "value": "gpu", "effect": "NoSchedule" },
+          {
+            "key": "node.kubernetes.io/not-ready",
+            "operator": "Exists",
+            "effect": "NoExecute",
+            "tolerationSeconds": 300
           },
-          KubeContainer {
-            name: "init-busybox1".into(),
-            image: "busybox".into(),
-            ready: "true".into(),
-            status: "Completed".into(),
-            restarts: 0,
-            liveliness_probe: false,
-            readiness_probe: false,
-            ports: "".into(),
-            age: utils::to_age(Some(&get_time("2021-06-18T09:26:11Z")), now),
-            pod_name: "pod-init-container-2".into(),
-            k8s_obj: None,
-            init: true,
+          { "operator": "Exists" }
+        ],
+        "affinity": {
+          "nodeAffinity": {
+            "requiredDuringSchedulingIgnoredDuringExecution": {
+              "nodeSelectorTerms": [{
+                "matchExpressions": [
+                  { "key": "kubernetes.io/arch", "operator": "In", "values": ["amd64", "arm64"] },
+                  { "key": "spot", "operator": "DoesNotExist" }
+                ]
+              }]
+            },
+            "preferredDuringSchedulingIgnoredDuringExecution": [{
+              "weight": 50,
+              "preference": {
+                "matchExpressions": [
+                  { "key": "zone", "operator": "In", "values": ["a"] }
+                ]
+              }
+            }]
           },
-          KubeContainer {
-            name: "init-busybox2".into(),
-            image: "busybox".into(),
-            ready: "true".into(),
-            status: "Completed".into(),
-            restarts: 0,
-            liveliness_probe: false,
-            readiness_probe: false,
-            ports: "".into(),
-            age: utils::to_age(Some(&get_time("2021-06-18T09:26:11Z")), now),
-            pod_name: "pod-init-container-2".into(),
-            k8s_obj: None,
-            init: true,
+          "podAntiAffinity": {
+            "preferredDuringSchedulingIgnoredDuringExecution": [{
+              "weight": 100,
+              "podAffinityTerm": {
+                "labelSelector": { "matchLabels": { "app": "web" } },
+                "topologyKey": "kubernetes.io/hostname",
+                "namespaces": ["default"]
+              }
+            }]
           }
-        ],
-        k8s_obj: pods_list[12].clone()
+        }
       }
+    }))
+    .unwrap();
+
+    assert_eq!(
+      KubePod::from(pod).scheduling_constraints(),
+      vec![
+        "Node selector",
+        "  disktype=ssd",
+        "Tolerations",
+        "  dedicated=gpu:NoSchedule",
+        "  node.kubernetes.io/not-ready exists:NoExecute for 300s",
+        "  any taint",
+        "Node affinity",
+        "  required: kubernetes.io/arch In [amd64, arm64], spot DoesNotExist",
+        "  preferred (weight 50): zone In [a]",
+        "Pod anti-affinity",
+        "  preferred (weight 100): app=web per kubernetes.io/hostname in default",
+      ]
     );
-    // TODO add tests for NodeLost case
+    assert!(KubePod::default().scheduling_constraints().is_empty());
   }
 
   #[test]
@@ -1126,4 +4496,556 @@ mod tests {
     assert_eq!(pod.ready, (0, 1));
     assert_eq!(pod.status, "Pending");
   }
+
+  #[test]
+  fn test_pod_lists_init_and_ephemeral_containers() {
+    let running = ContainerState {
+      running: Some(Default::default()),
+      ..Default::default()
+    };
+    let status_for = |name: &str, state: ContainerState| ContainerStatus {
+      name: name.into(),
+      state: Some(state),
+      ..Default::default()
+    };
+    let pod = Pod {
+      metadata: ObjectMeta {
+        name: Some("debugged".into()),
+        ..Default::default()
+      },
+      spec: Some(PodSpec {
+        containers: vec![Container {
+          name: "app".into(),
+          ..Default::default()
+        }],
+        init_containers: Some(vec![Container {
+          name: "migrate".into(),
+          ..Default::default()
+        }]),
+        ephemeral_containers: Some(vec![EphemeralContainer {
+          name: "debugger".into(),
+          image: Some("busybox".into()),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      }),
+      status: Some(PodStatus {
+        phase: Some("Running".into()),
+        container_statuses: Some(vec![status_for("app", running.clone())]),
+        init_container_statuses: Some(vec![status_for(
+          "migrate",
+          ContainerState {
+            terminated: Some(ContainerStateTerminated {
+              reason: Some("Completed".into()),
+              ..Default::default()
+            }),
+            ..Default::default()
+          },
+        )]),
+        ephemeral_container_statuses: Some(vec![status_for("debugger", running)]),
+        ..Default::default()
+      }),
+    };
+
+    let pod = KubePod::from(pod);
+
+    let summary: Vec<_> = pod
+      .containers
+      .iter()
+      .map(|c| (c.name.as_str(), c.kind, c.status.as_str()))
+      .collect();
+    assert_eq!(
+      summary,
+      vec![
+        ("app", ContainerKind::Regular, "Running"),
+        ("migrate", ContainerKind::Init, "Completed"),
+        ("debugger", ContainerKind::Ephemeral, "Running"),
+      ]
+    );
+    assert_eq!(pod.containers[2].image, "busybox");
+    assert_eq!(
+      pod.ready,
+      (0, 1),
+      "only regular containers count towards ready"
+    );
+  }
+
+  #[test]
+  fn test_pod_resources_sum_containers_and_mark_unset() {
+    use std::collections::BTreeMap;
+
+    use k8s_openapi::{
+      api::core::v1::ResourceRequirements, apimachinery::pkg::api::resource::Quantity,
+    };
+
+    let quantities = |cpu: &str, mem: &str| {
+      Some(BTreeMap::from([
+        ("cpu".to_string(), Quantity(cpu.into())),
+        ("memory".to_string(), Quantity(mem.into())),
+      ]))
+    };
+    let pod = Pod {
+      spec: Some(PodSpec {
+        containers: vec![
+          Container {
+            name: "app".into(),
+            resources: Some(ResourceRequirements {
+              requests: quantities("250m", "256Mi"),
+              limits: quantities("1", "1Gi"),
+              ..Default::default()
+            }),
+            ..Default::default()
+          },
+          Container {
+            name: "sidecar".into(),
+            resources: Some(ResourceRequirements {
+              requests: quantities("0.1", "64Mi"),
+              ..Default::default()
+            }),
+            ..Default::default()
+          },
+        ],
+        ..Default::default()
+      }),
+      status: Some(PodStatus::default()),
+      ..Default::default()
+    };
+
+    let pod = KubePod::from(pod);
+
+    assert_eq!(
+      (
+        pod.cpu_requests.as_str(),
+        pod.cpu_limits.as_str(),
+        pod.mem_requests.as_str(),
+        pod.mem_limits.as_str()
+      ),
+      ("350m", "≥1000m", "320Mi", "≥1024Mi"),
+      "the sidecar's missing limit leaves the pod's total open"
+    );
+    let sidecar = &pod.containers[1];
+    assert_eq!(sidecar.cpu_requests, "100m");
+    assert_eq!(sidecar.cpu_limits, "-", "an unset limit is not zero");
+    assert_eq!(sidecar.mem_limits, "-");
+  }
+
+  #[test]
+  fn test_format_resource_quantities() {
+    let qty = |s: &str| Some(Qty::from_str(s).unwrap());
+    assert_eq!(format_cpu(qty("1.5")), "1500m");
+    assert_eq!(format_cpu(None), "-");
+    assert_eq!(format_mem(qty("2Gi")), "2048Mi");
+    assert_eq!(format_mem(qty("512Ki")), "512Ki");
+    assert_eq!(format_mem(qty("128M")), "122Mi");
+    assert_eq!(format_mem(None), "-");
+  }
+
+  #[test]
+  fn test_get_qos_class() {
+    use std::collections::BTreeMap;
+
+    use k8s_openapi::{
+      api::core::v1::ResourceRequirements, apimachinery::pkg::api::resource::Quantity,
+    };
+
+    let quantities = |pairs: &[(&str, &str)]| {
+      Some(
+        pairs
+          .iter()
+          .map(|(name, qty)| (name.to_string(), Quantity(qty.to_string())))
+          .collect::<BTreeMap<_, _>>(),
+      )
+    };
+    let container = |requests: &[(&str, &str)], limits: &[(&str, &str)]| Container {
+      resources: Some(ResourceRequirements {
+        requests: quantities(requests),
+        limits: quantities(limits),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+    let spec = |containers: Vec<Container>, init: Vec<Container>| PodSpec {
+      containers,
+      init_containers: Some(init),
+      ..Default::default()
+    };
+    let full = [("cpu", "500m"), ("memory", "256Mi")];
+
+    assert_eq!(
+      get_qos_class(&spec(vec![Container::default()], vec![])),
+      QosClass::BestEffort
+    );
+    assert_eq!(
+      get_qos_class(&spec(vec![container(&[("cpu", "0")], &[])], vec![])),
+      QosClass::BestEffort,
+      "zero quantities do not count"
+    );
+    assert_eq!(
+      get_qos_class(&spec(
+        vec![container(&full, &full)],
+        vec![container(&full, &full)]
+      )),
+      QosClass::Guaranteed
+    );
+    assert_eq!(
+      get_qos_class(&spec(
+        vec![container(&[("cpu", "0.5"), ("memory", "256Mi")], &full)],
+        vec![]
+      )),
+      QosClass::Guaranteed,
+      "equal quantities in different units still match"
+    );
+    assert_eq!(
+      get_qos_class(&spec(
+        vec![container(&full, &full), Container::default()],
+        vec![]
+      )),
+      QosClass::Burstable,
+      "one unlimited container makes the pod burstable"
+    );
+    assert_eq!(
+      get_qos_class(&spec(
+        vec![container(&full, &full)],
+        vec![container(&[("cpu", "100m")], &[])]
+      )),
+      QosClass::Burstable,
+      "init containers count too"
+    );
+    assert_eq!(
+      get_qos_class(&spec(
+        vec![container(&[("cpu", "100m"), ("memory", "256Mi")], &full)],
+        vec![]
+      )),
+      QosClass::Burstable
+    );
+  }
+
+  #[test]
+  fn test_recent_restart_is_flagged_within_window() {
+    use k8s_openapi::{
+      api::core::v1::ContainerStateTerminated, apimachinery::pkg::apis::meta::v1::Time,
+    };
+
+    let now = Utc::now();
+    let pod_restarted_at = |finished_at: DateTime<Utc>| Pod {
+      status: Some(PodStatus {
+        container_statuses: Some(vec![ContainerStatus {
+          restart_count: 4,
+          last_state: Some(ContainerState {
+            terminated: Some(ContainerStateTerminated {
+              finished_at: Some(Time(
+                finished_at.to_rfc3339().parse().expect("valid timestamp"),
+              )),
+              ..Default::default()
+            }),
+            ..Default::default()
+          }),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let recent = KubePod::from_pod_at(pod_restarted_at(now - chrono::Duration::minutes(3)), now);
+    assert_eq!(recent.restarts, 4);
+    assert_eq!(recent.last_restart.as_deref(), Some("3m"));
+
+    let old = KubePod::from_pod_at(pod_restarted_at(now - chrono::Duration::hours(2)), now);
+    assert_eq!(old.last_restart, None);
+  }
+
+  #[test]
+  fn test_uptime_counts_from_the_newest_container_start() {
+    use k8s_openapi::{
+      api::core::v1::ContainerStateRunning, apimachinery::pkg::apis::meta::v1::Time,
+    };
+
+    let now = Utc::now();
+    let at = |ago: chrono::Duration| Time((now - ago).to_rfc3339().parse().unwrap());
+    let running = |started_at| ContainerStatus {
+      state: Some(ContainerState {
+        running: Some(ContainerStateRunning {
+          started_at: Some(started_at),
+        }),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+    let pod = |statuses| Pod {
+      metadata: ObjectMeta {
+        creation_timestamp: Some(at(chrono::Duration::days(3))),
+        ..Default::default()
+      },
+      status: Some(PodStatus {
+        container_statuses: Some(statuses),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let restarted = KubePod::from_pod_at(
+      pod(vec![
+        running(at(chrono::Duration::days(3))),
+        running(at(chrono::Duration::seconds(75))),
+      ]),
+      now,
+    );
+    assert_eq!(restarted.age, "3d");
+    assert_eq!(restarted.uptime, "1m15s");
+
+    let waiting = KubePod::from_pod_at(pod(vec![ContainerStatus::default()]), now);
+    assert_eq!(waiting.uptime, "-");
+  }
+
+  #[test]
+  fn test_container_image_rollout_and_short_name() {
+    let (pods, _): (Vec<KubePod>, Vec<_>) = convert_resource_from_file("pods");
+    // `busybox` in the spec is reported as `docker.io/library/busybox:latest`.
+    let init = &pods[11].containers[1];
+    assert_eq!(init.running_image, "docker.io/library/busybox:latest");
+    assert!(!init.is_image_changing());
+    // Not started yet: the spec image is shown and nothing is rolling.
+    let pending = &pods[0].containers[0];
+    assert_eq!(pending.running_image, "");
+    assert_eq!(pending.shown_image(), pending.image);
+    assert!(!pending.is_image_changing());
+    assert_eq!(short_image(pending.shown_image()), "adservice:v0.2.2");
+
+    let mut rolling = pending.clone();
+    rolling.image = "gcr.io/google-samples/microservices-demo/adservice:v0.3.0".into();
+    rolling.running_image = "gcr.io/google-samples/microservices-demo/adservice:v0.2.2".into();
+    assert!(rolling.is_image_changing());
+    rolling.running_image = "sha256:4f3c2b1a".into();
+    assert!(!rolling.is_image_changing());
+
+    assert_eq!(short_image("nginx@sha256:abc"), "nginx@sha256:abc");
+    assert_eq!(short_image("localhost:5000/team/app:1.0"), "app:1.0");
+    assert_eq!(
+      normalize_image("localhost:5000/app"),
+      "localhost:5000/app:latest"
+    );
+    assert_eq!(normalize_image("team/app:1.0"), "docker.io/team/app:1.0");
+  }
+
+  #[test]
+  fn test_pod_changed_at_latest_container_start() {
+    let pod: Pod = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web", "creationTimestamp": "2024-03-01T10:00:00Z" },
+      "status": {
+        "conditions": [{ "type": "Ready", "status": "True", "lastTransitionTime": "2024-03-01T10:01:00Z" }],
+        "containerStatuses": [{
+          "name": "app", "image": "app", "imageID": "", "ready": true, "restartCount": 1,
+          "state": { "running": { "startedAt": "2024-03-01T12:00:00Z" } },
+          "lastState": { "terminated": { "exitCode": 1, "finishedAt": "2024-03-01T11:59:58Z" } }
+        }]
+      }
+    }))
+    .unwrap();
+    assert_eq!(
+      KubePod::from(pod).get_changed(),
+      Some("2024-03-01T12:00:00Z".parse().unwrap())
+    );
+    assert_eq!(KubePod::default().get_changed(), None);
+  }
+
+  #[test]
+  fn test_restarts_cell_escalates_colour() {
+    let palette = crate::ui::theme::palette_for(crate::ui::theme::ThemeName::Macchiato);
+    let mut pod = KubePod::default();
+    assert_eq!(
+      restarts_cell(&pod, 5, palette),
+      Cell::from("0").style(style_success(palette))
+    );
+    pod.restarts = 2;
+    assert_eq!(
+      restarts_cell(&pod, 5, palette),
+      Cell::from("2").style(style_caution(palette))
+    );
+    pod.restarts = 5;
+    pod.last_restart = Some("40s".into());
+    assert_eq!(
+      restarts_cell(&pod, 5, palette),
+      Cell::from("5 (40s ago)").style(style_failure(palette))
+    );
+  }
+
+  #[test]
+  fn test_last_termination_is_shown_with_oom_kills_standing_out() {
+    use k8s_openapi::{
+      api::core::v1::ContainerStateTerminated, apimachinery::pkg::apis::meta::v1::Time,
+    };
+
+    let palette = crate::ui::theme::palette_for(crate::ui::theme::ThemeName::Macchiato);
+    let now = Utc::now();
+    let status = |reason: &str, exit_code: i32| ContainerStatus {
+      name: "web".into(),
+      restart_count: 2,
+      last_state: Some(ContainerState {
+        terminated: Some(ContainerStateTerminated {
+          reason: Some(reason.into()),
+          exit_code,
+          finished_at: Some(Time(
+            (now - chrono::Duration::minutes(5))
+              .to_rfc3339()
+              .parse()
+              .expect("valid timestamp"),
+          )),
+          ..Default::default()
+        }),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+    let container = |container_status: ContainerStatus| {
+      let spec = Container {
+        name: "web".into(),
+        ..Container::default()
+      };
+      KubeContainer::from_api(
+        &spec,
+        "web-1".into(),
+        "1h".into(),
+        &Some(vec![container_status]),
+        ContainerKind::Regular,
+        &[],
+        now,
+      )
+    };
+
+    let mut oom = container(status("OOMKilled", 137));
+    oom.status = "Running".into();
+    oom.mem_limits = "256Mi".into();
+    let last = oom.last_termination.clone().expect("a last termination");
+    assert_eq!(
+      last,
+      LastTermination {
+        reason: "OOMKilled".into(),
+        exit_code: 137,
+        finished: "5m".into(),
+      }
+    );
+    assert_eq!(
+      last.style(palette),
+      style_failure(palette).add_modifier(Modifier::BOLD)
+    );
+    assert_eq!(
+      container_status_cell(&oom, palette),
+      Cell::from(Line::from(vec![
+        Span::raw("Running · "),
+        Span::styled("last OOMKilled", last.style(palette)),
+      ]))
+    );
+    let detail: String = last_termination_line(&oom, &last, palette)
+      .spans
+      .iter()
+      .map(|span| span.content.as_ref())
+      .collect();
+    assert_eq!(
+      detail,
+      "Last exit: OOMKilled  exit 137  5m ago  memory limit 256Mi"
+    );
+
+    let error = container(status("Error", 1)).last_termination.unwrap();
+    assert_eq!(error.style(palette), style_caution(palette));
+    assert!(container(ContainerStatus {
+      name: "web".into(),
+      ..Default::default()
+    })
+    .last_termination
+    .is_none());
+  }
+
+  #[test]
+  fn test_node_drilldown_shows_allocatable_and_usage() {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use crate::app::nodes::KubeNode;
+
+    let mut app = App::default();
+    app.data.selected.pod_selector = Some("node-a".into());
+    app.data.selected.pod_selector_resource = Some("node".into());
+    let mut node = KubeNode::default();
+    node.name = "node-a".into();
+    node.cpu_a = "4000m".into();
+    node.mem_a = "15Gi".into();
+    node.cpu = "900m".into();
+    node.cpu_percent = "22".into();
+    node.mem = "6144Mi".into();
+    node.mem_percent = "40".into();
+    node.has_usage = true;
+    app.data.nodes.set_items(vec![node]);
+    app.data.pods.set_items(vec![KubePod {
+      name: "web".into(),
+      node: "node-a".into(),
+      cpu: "250m".into(),
+      mem: "128Mi".into(),
+      ..KubePod::default()
+    }]);
+
+    let mut terminal = Terminal::new(TestBackend::new(160, 8)).unwrap();
+    terminal
+      .draw(|f| draw_block_as_sub(f, &mut app, f.area()))
+      .unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    let lines: Vec<String> = (0..buffer.area.height)
+      .map(|y| {
+        (0..buffer.area.width)
+          .map(|x| buffer[(x, y)].symbol())
+          .collect()
+      })
+      .collect();
+    assert!(
+      lines[0].contains(
+        "Node: node-a · allocatable CPU 4000m, memory 15Gi · used CPU 900m (22%), memory 6144Mi (40%)"
+      ),
+      "{:?}",
+      lines[0]
+    );
+    assert!(lines[1].contains("Node -> Pods"), "{:?}", lines[1]);
+    assert!(lines[2].contains("Used CPU/Mem"), "{:?}", lines[2]);
+    assert!(lines[3].contains("250m/128Mi"), "{:?}", lines[3]);
+  }
+
+  #[test]
+  fn test_grouped_pods_draw_a_header_per_group() {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let mut app = App::default();
+    app.data.group_pods = true;
+    app.data.pods.set_items(vec![
+      KubePod {
+        name: "debug".into(),
+        namespace: "shop".into(),
+        status: "Running".into(),
+        ready: (1, 1),
+        ..KubePod::default()
+      },
+      KubePod {
+        name: "tools".into(),
+        namespace: "shop".into(),
+        status: "Pending".into(),
+        ready: (0, 1),
+        ..KubePod::default()
+      },
+    ]);
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 6)).unwrap();
+    terminal
+      .draw(|f| draw_block(f, &mut app, f.area()))
+      .unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    let lines: Vec<String> = (0..buffer.area.height)
+      .map(|y| {
+        (0..buffer.area.width)
+          .map(|x| buffer[(x, y)].symbol())
+          .collect()
+      })
+      .collect();
+    assert!(lines[0].contains("fold group"), "{:?}", lines[0]);
+    assert!(lines[2].contains("▾ Unmanaged"), "{:?}", lines[2]);
+    assert!(lines[2].contains("1/2 Ready"), "{:?}", lines[2]);
+    assert!(lines[3].contains("├─ debug"), "{:?}", lines[3]);
+    assert!(lines[4].contains("└─ tools"), "{:?}", lines[4]);
+  }
 }
@@ -0,0 +1,165 @@
+//! Per-view poll intervals set with `poll_rates` in the config file, in
+//! milliseconds, e.g. `poll_rates: { events: 1000, persistentvolumes: 60000 }`.
+//! Views without an entry are polled at the global `--poll-rate`.
+use std::{
+  collections::HashMap,
+  time::{Duration, Instant},
+};
+
+use log::warn;
+
+use super::{columns, view_slug, ActiveBlock};
+use crate::config::KdashConfig;
+
+/// Main views without columns of their own that can still get a rate.
+pub(super) const MAIN_VIEWS: [(ActiveBlock, &str); 4] = [
+  (ActiveBlock::Utilization, "utilization"),
+  (ActiveBlock::Troubleshoot, "troubleshoot"),
+  (ActiveBlock::Dashboard, "dashboard"),
+  (ActiveBlock::Watchlist, "watchlist"),
+];
+
+/// The view a config file names, like `--view` names.
+pub(super) fn view_block(view: &str) -> Option<ActiveBlock> {
+  let view = view_slug(view);
+  columns::view_block(&view).or_else(|| {
+    MAIN_VIEWS
+      .iter()
+      .find(|(_, name)| *name == view)
+      .map(|(block, _)| *block)
+  })
+}
+
+/// When each view with its own poll rate was last polled.
+#[derive(Debug, Default)]
+pub struct PollSchedule {
+  rates: HashMap<ActiveBlock, Duration>,
+  last_polled: HashMap<ActiveBlock, Instant>,
+}
+
+impl PollSchedule {
+  /// The rates under `poll_rates`; unknown views and zero rates are left out.
+  pub fn from_config(config: &KdashConfig) -> Self {
+    let rates = config
+      .poll_rates
+      .iter()
+      .filter(|(_, millis)| **millis > 0)
+      .filter_map(|(view, millis)| Some((view_block(view)?, Duration::from_millis(*millis))))
+      .collect();
+    Self {
+      rates,
+      last_polled: HashMap::new(),
+    }
+  }
+
+  /// Whether `block` is due for a poll at `now`, or `None` when it has no
+  /// rate of its own and follows the global poll rate.
+  pub fn is_due(&self, block: ActiveBlock, now: Instant) -> Option<bool> {
+    let rate = self.rates.get(&block)?;
+    Some(
+      self
+        .last_polled
+        .get(&block)
+        .is_none_or(|polled| now.duration_since(*polled) >= *rate),
+    )
+  }
+
+  pub fn mark_polled(&mut self, block: ActiveBlock, now: Instant) {
+    if self.rates.contains_key(&block) {
+      self.last_polled.insert(block, now);
+    }
+  }
+}
+
+/// One warning per unknown view or zero rate under `poll_rates`.
+pub fn poll_rate_warnings(config: &KdashConfig) -> Vec<String> {
+  let mut warnings = vec![];
+  for (view, millis) in &config.poll_rates {
+    if view_block(view).is_none() {
+      warnings.push(format!(
+        "Unknown view in poll_rates config: {}. Valid views: {}",
+        view,
+        columns::view_names()
+          .chain(MAIN_VIEWS.iter().map(|(_, name)| *name))
+          .collect::<Vec<_>>()
+          .join(", ")
+      ));
+    } else if *millis == 0 {
+      warnings.push(format!(
+        "Poll rate for {} must be above 0 ms; using the global poll rate",
+        view
+      ));
+    }
+  }
+
+  for warning in &warnings {
+    warn!("{}", warning);
+  }
+  warnings
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use super::*;
+
+  fn config_with(rates: &[(&str, u64)]) -> KdashConfig {
+    KdashConfig {
+      poll_rates: rates
+        .iter()
+        .map(|(view, millis)| (view.to_string(), *millis))
+        .collect::<BTreeMap<_, _>>(),
+      ..KdashConfig::default()
+    }
+  }
+
+  #[test]
+  fn test_poll_schedule_spaces_polls_by_view_rate() {
+    let schedule_config = config_with(&[("Events", 1000), ("persistentvolumes", 60_000)]);
+    let mut schedule = PollSchedule::from_config(&schedule_config);
+    let start = Instant::now();
+
+    // views without a rate follow the global poll
+    assert_eq!(schedule.is_due(ActiveBlock::Pods, start), None);
+    // never polled yet
+    assert_eq!(schedule.is_due(ActiveBlock::Events, start), Some(true));
+
+    schedule.mark_polled(ActiveBlock::Events, start);
+    schedule.mark_polled(ActiveBlock::PersistentVolumes, start);
+    let later = start + Duration::from_millis(1000);
+    assert_eq!(schedule.is_due(ActiveBlock::Events, later), Some(true));
+    assert_eq!(
+      schedule.is_due(ActiveBlock::PersistentVolumes, later),
+      Some(false)
+    );
+    assert_eq!(
+      schedule.is_due(
+        ActiveBlock::PersistentVolumes,
+        start + Duration::from_secs(60)
+      ),
+      Some(true)
+    );
+  }
+
+  #[test]
+  fn test_poll_rate_warnings_name_unknown_views_and_zero_rates() {
+    let config = config_with(&[("pods", 0), ("widgets", 500), ("dashboard", 5000)]);
+    let warnings = poll_rate_warnings(&config);
+
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(
+      warnings[0],
+      "Poll rate for pods must be above 0 ms; using the global poll rate"
+    );
+    assert!(
+      warnings[1].starts_with("Unknown view in poll_rates config: widgets. Valid views: pods,")
+    );
+    assert!(warnings[1].ends_with("troubleshoot, dashboard, watchlist"));
+    // a zero rate is ignored rather than polling on every tick
+    assert_eq!(
+      PollSchedule::from_config(&config).is_due(ActiveBlock::Pods, Instant::now()),
+      None
+    );
+  }
+}
@@ -0,0 +1,100 @@
+//! Which views the background cache fetches. A view is only fetched once it
+//! has been opened, so a session that looks at two tabs of a dozen lists two
+//! kinds of resources, not all of them. Views under `prewarm` in the config
+//! file are fetched from the start, and `--eager-load` fetches every view as
+//! before.
+use std::collections::HashSet;
+
+use log::warn;
+
+use super::{columns, poll_rates, ActiveBlock, App};
+use crate::{config::KdashConfig, network::IoEvent};
+
+/// Pre-warmed when `prewarm` is unset, as YAML summaries list recent events.
+const DEFAULT_PREWARM: [ActiveBlock; 1] = [ActiveBlock::Events];
+
+/// The views under `prewarm`; unknown names are left out.
+pub fn prewarm_views(config: &KdashConfig) -> HashSet<ActiveBlock> {
+  match &config.prewarm {
+    Some(views) => views
+      .iter()
+      .filter_map(|view| poll_rates::view_block(view))
+      .collect(),
+    None => DEFAULT_PREWARM.into_iter().collect(),
+  }
+}
+
+/// One warning per unknown view under `prewarm`.
+pub fn prewarm_warnings(config: &KdashConfig) -> Vec<String> {
+  let warnings: Vec<String> = config
+    .prewarm
+    .iter()
+    .flatten()
+    .filter(|view| poll_rates::view_block(view).is_none())
+    .map(|view| {
+      format!(
+        "Unknown view in prewarm config: {}. Valid views: {}",
+        view,
+        columns::view_names()
+          .chain(poll_rates::MAIN_VIEWS.iter().map(|(_, name)| *name))
+          .collect::<Vec<_>>()
+          .join(", ")
+      )
+    })
+    .collect();
+  for warning in &warnings {
+    warn!("{}", warning);
+  }
+  warnings
+}
+
+impl App {
+  /// Remember that the current view was opened, so the background cache
+  /// keeps it fresh from now on.
+  pub(super) fn note_view_opened(&mut self) {
+    let block = self.active_home_cache_block();
+    self.opened_views.insert(block);
+  }
+
+  /// Whether the background cache should fetch `event`.
+  pub(super) fn prefetches(&self, event: &IoEvent) -> bool {
+    self.eager_load
+      || event
+        .view_block()
+        .is_some_and(|block| self.opened_views.contains(&block) || self.prewarm.contains(&block))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_prewarm_views_default_to_events() {
+    assert_eq!(
+      prewarm_views(&KdashConfig::default()),
+      HashSet::from([ActiveBlock::Events])
+    );
+    let config = KdashConfig {
+      prewarm: Some(vec![
+        "Deployments".into(),
+        "utilization".into(),
+        "nope".into(),
+      ]),
+      ..KdashConfig::default()
+    };
+    assert_eq!(
+      prewarm_views(&config),
+      HashSet::from([ActiveBlock::Deployments, ActiveBlock::Utilization])
+    );
+    let warnings = prewarm_warnings(&config);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].starts_with("Unknown view in prewarm config: nope. Valid views: "));
+
+    let none = KdashConfig {
+      prewarm: Some(vec![]),
+      ..KdashConfig::default()
+    };
+    assert!(prewarm_views(&none).is_empty());
+  }
+}
@@ -3,11 +3,8 @@ use chrono::Utc;
 use k8s_openapi::{
   api::core::v1::PersistentVolumeClaim, apimachinery::pkg::api::resource::Quantity,
 };
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
-};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
 
 use super::{
   models::{AppResource, KubeResource, Named},
@@ -19,8 +16,8 @@ use crate::{
   network::Network,
   ui::utils::{
     describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, responsive_columns, style_caution,
-    style_failure, style_success, title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+    get_describe_active, get_resource_title, help_bold_line, style_caution, style_failure,
+    style_success, title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -87,6 +84,14 @@ impl Named for KubePVC {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<PersistentVolumeClaim> for KubePVC {
@@ -124,7 +129,7 @@ impl AppResource for PvcResource {
   }
 }
 
-const PVC_COLUMNS: [ColumnDef; 8] = [
+pub(crate) const PVC_COLUMNS: [ColumnDef; 8] = [
   ColumnDef::all("Namespace", 10, 10, 10),
   ColumnDef::all("Name", 10, 10, 10),
   ColumnDef::all("Status", 10, 10, 10),
@@ -136,7 +141,7 @@ const PVC_COLUMNS: [ColumnDef; 8] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::PersistentVolumeClaims);
   let title = get_resource_title(
     app,
     PVC_TITLE,
@@ -144,7 +149,7 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
     app.data.persistent_volume_claims.items.len(),
   );
 
-  let (headers, widths) = responsive_columns(&PVC_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::PersistentVolumeClaims, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -153,8 +158,8 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       title,
       inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
       resource: &mut app.data.persistent_volume_claims,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
       let style = if c.status == "Pending" {
@@ -164,20 +169,21 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       } else {
         style_success(app.palette)
       };
-      Row::new(vec![
-        Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.status.to_owned()),
-        Cell::from(c.volume.to_owned()),
-        Cell::from(c.capacity.to_owned()),
-        Cell::from(c.access_modes.to_owned()),
-        Cell::from(c.storage_class.to_owned()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style)
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.status.to_owned()),
+          Cell::from(c.volume.to_owned()),
+          Cell::from(c.capacity.to_owned()),
+          Cell::from(c.access_modes.to_owned()),
+          Cell::from(c.storage_class.to_owned()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style)
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -1,11 +1,8 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use k8s_openapi::{api::core::v1::PersistentVolume, apimachinery::pkg::api::resource::Quantity};
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
-};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
 
 use super::{
   models::{AppResource, KubeResource, Named},
@@ -17,8 +14,8 @@ use crate::{
   network::Network,
   ui::utils::{
     describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, responsive_columns, style_caution,
-    style_failure, style_success, title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+    get_describe_active, get_resource_title, help_bold_line, style_caution, style_failure,
+    style_success, title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -104,6 +101,10 @@ impl Named for KubePV {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<PersistentVolume> for KubePV {
@@ -139,7 +140,7 @@ impl AppResource for PvResource {
   }
 }
 
-const PV_COLUMNS: [ColumnDef; 9] = [
+pub(crate) const PV_COLUMNS: [ColumnDef; 9] = [
   ColumnDef::all("Name", 20, 20, 20),
   ColumnDef::all("Capacity", 10, 10, 10),
   ColumnDef::all("Access Modes", 10, 10, 10),
@@ -152,10 +153,10 @@ const PV_COLUMNS: [ColumnDef; 9] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::PersistentVolumes);
   let title = get_resource_title(app, PV_TITLE, "", app.data.persistent_volumes.items.len());
 
-  let (headers, widths) = responsive_columns(&PV_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::PersistentVolumes, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -164,8 +165,8 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       title,
       inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
       resource: &mut app.data.persistent_volumes,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
       let style = if c.status == "Pending" {
@@ -175,21 +176,22 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       } else {
         style_success(app.palette)
       };
-      Row::new(vec![
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.capacity.to_owned()),
-        Cell::from(c.access_modes.to_owned()),
-        Cell::from(c.reclaim_policy.to_owned()),
-        Cell::from(c.status.to_owned()),
-        Cell::from(c.claim.to_owned()),
-        Cell::from(c.storage_class.to_owned()),
-        Cell::from(c.reason.to_owned()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style)
+      columns
+        .row(vec![
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.capacity.to_owned()),
+          Cell::from(c.access_modes.to_owned()),
+          Cell::from(c.reclaim_policy.to_owned()),
+          Cell::from(c.status.to_owned()),
+          Cell::from(c.claim.to_owned()),
+          Cell::from(c.storage_class.to_owned()),
+          Cell::from(c.reason.to_owned()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style)
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -0,0 +1,125 @@
+//! `--read-only`: KDash refuses every change to the cluster, so a stray key
+//! on a shared or production cluster cannot delete, scale, restart or edit
+//! anything. Changes are refused where they start (a confirmation, a prompt,
+//! an edit) and again where they are sent, in case a new path skips the first.
+use super::{
+  actions::{ConfirmAction, InputAction},
+  App,
+};
+
+pub const READ_ONLY_MESSAGE: &str = "Read-only mode is on (--read-only): changes are disabled";
+
+impl ConfirmAction {
+  /// Whether confirming would change the cluster.
+  pub fn is_mutating(&self, app: &App) -> bool {
+    match self {
+      ConfirmAction::Io(event) => event.audit_action(app).is_some(),
+      ConfirmAction::Cmd(event) => event.audit_action().is_some(),
+      ConfirmAction::Macro { events, .. } => {
+        events.iter().any(|event| event.audit_action(app).is_some())
+      }
+    }
+  }
+}
+
+impl InputAction {
+  /// Whether the prompt only collects the value for a change to the cluster.
+  pub fn is_mutating(&self) -> bool {
+    match self {
+      InputAction::Scale { .. }
+      | InputAction::CreateNamespace
+      | InputAction::RolloutUndo { .. } => true,
+      InputAction::PortForward { .. }
+      | InputAction::LabelSelector
+      | InputAction::LogSince
+      | InputAction::LogMark => false,
+    }
+  }
+}
+
+impl App {
+  /// In read-only mode, explain with a toast that changes are off and return
+  /// true so the caller stops.
+  pub fn refuse_in_read_only(&mut self) -> bool {
+    if self.read_only {
+      self.set_status_message(READ_ONLY_MESSAGE);
+    }
+    self.read_only
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use tokio::sync::mpsc;
+
+  use super::*;
+  use crate::{
+    app::{
+      actions::{InputModal, Modal},
+      ActiveBlock,
+    },
+    network::IoEvent,
+  };
+
+  fn read_only_app() -> App {
+    App {
+      read_only: true,
+      ..App::default()
+    }
+  }
+
+  fn delete_pod() -> IoEvent {
+    IoEvent::DeleteResource {
+      block: ActiveBlock::Pods,
+      name: "web-1".into(),
+      namespace: Some("shop".into()),
+    }
+  }
+
+  #[test]
+  fn test_read_only_refuses_change_confirmations_and_prompts() {
+    let mut app = read_only_app();
+    app.open_modal(Modal::confirm("Delete", "Delete pod web-1?", delete_pod()));
+    assert!(app.modal.is_none());
+    assert_eq!(app.status_message.text(), READ_ONLY_MESSAGE);
+
+    app.open_input_modal(InputModal {
+      title: "Create namespace".into(),
+      prompt: "Name".into(),
+      buffer: String::new(),
+      error: None,
+      action: InputAction::CreateNamespace,
+    });
+    assert!(app.input_modal.is_none());
+
+    // prompts that change nothing still open
+    app.open_input_modal(InputModal {
+      title: "Since".into(),
+      prompt: "Duration".into(),
+      buffer: String::new(),
+      error: None,
+      action: InputAction::LogSince,
+    });
+    assert!(app.input_modal.is_some());
+
+    let mut writable = App::default();
+    writable.open_modal(Modal::confirm("Delete", "Delete pod web-1?", delete_pod()));
+    assert!(writable.modal.is_some());
+  }
+
+  #[tokio::test]
+  async fn test_read_only_never_sends_a_change() {
+    let (io_tx, mut io_rx) = mpsc::channel::<IoEvent>(10);
+    let mut app = App {
+      io_tx: Some(io_tx),
+      ..read_only_app()
+    };
+    app.dispatch(delete_pod()).await;
+    assert!(io_rx.try_recv().is_err());
+    assert!(!app.is_loading());
+
+    // reads go through as usual
+    app.dispatch(IoEvent::GetPods).await;
+    assert_eq!(io_rx.try_recv().unwrap(), IoEvent::GetPods);
+  }
+}
@@ -0,0 +1,392 @@
+//! Redact mode, for screen sharing and recordings. While it is on, values
+//! that look sensitive are masked in the describe, YAML, diff and log views
+//! and in a container's environment as they are drawn, so turning it on hides
+//! what is already on screen. The redactor is built when the config loads,
+//! and a text is masked once, not on every frame. A value is masked when its key contains one of
+//! `redact_patterns`, ignoring case: a YAML or describe `key: value`, a
+//! `key=value` label, annotation or flag, a JSON `"key": "value"`, or the
+//! `value:` of an env entry whose `name:` matches. The data of a Secret is
+//! masked whatever its keys, and decoding secrets or revealing them in the
+//! environment is refused. The connection info overlay, which never holds a
+//! credential, masks flags such as `--token=...` of an exec plugin as well.
+//! Copies to the clipboard are left as they are.
+use std::{borrow::Cow, sync::OnceLock};
+
+use regex::{Captures, Regex};
+
+use super::App;
+use crate::config::KdashConfig;
+
+pub const REDACTED: &str = "REDACTED";
+
+/// Key patterns masked when `redact_patterns` is not set.
+pub const DEFAULT_REDACT_PATTERNS: [&str; 7] = [
+  "password",
+  "passwd",
+  "token",
+  "secret",
+  "credential",
+  "apikey",
+  "api-key",
+];
+
+/// `key=value`, as in labels, annotations and command-line flags.
+fn pair_regex() -> &'static Regex {
+  static PAIR: OnceLock<Regex> = OnceLock::new();
+  PAIR.get_or_init(|| Regex::new(r"([\w./-]+)=([^\s,]+)").expect("static regex should parse"))
+}
+
+/// `"key": "value"`, as in the last-applied-configuration annotation.
+fn json_regex() -> &'static Regex {
+  static JSON: OnceLock<Regex> = OnceLock::new();
+  JSON.get_or_init(|| {
+    Regex::new(r#""([^"\\]*)"(\s*:\s*)"(?:[^"\\]|\\.)*""#).expect("static regex should parse")
+  })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Redactor {
+  patterns: Vec<String>,
+}
+
+/// A `key: value` line: where the key starts, the key, and where the value
+/// starts, if there is one on the line.
+struct KeyLine<'a> {
+  indent: usize,
+  key: &'a str,
+  value_at: Option<usize>,
+}
+
+fn key_line(line: &str) -> Option<KeyLine<'_>> {
+  let indent = line.len() - line.trim_start_matches([' ', '\t', '-', '+']).len();
+  let rest = &line[indent..];
+  let colon = rest
+    .find(": ")
+    .or_else(|| rest.strip_suffix(':').map(str::len))?;
+  let key = rest[..colon].trim_matches('"');
+  if key.is_empty() || key.starts_with(['{', '[', '#']) {
+    return None;
+  }
+  let value_at = rest[colon + 1..]
+    .find(|c: char| !c.is_whitespace())
+    .map(|at| indent + colon + 1 + at);
+  Some(KeyLine {
+    indent,
+    key,
+    value_at,
+  })
+}
+
+impl Redactor {
+  pub fn new<S: AsRef<str>>(patterns: impl IntoIterator<Item = S>) -> Self {
+    Self {
+      patterns: patterns
+        .into_iter()
+        .map(|pattern| pattern.as_ref().trim().to_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .collect(),
+    }
+  }
+
+  pub fn from_config(config: &KdashConfig) -> Self {
+    match &config.redact_patterns {
+      Some(patterns) => Self::new(patterns),
+      None => Self::new(DEFAULT_REDACT_PATTERNS),
+    }
+  }
+
+  pub fn is_sensitive(&self, key: &str) -> bool {
+    let key = key.to_lowercase();
+    self.patterns.iter().any(|pattern| key.contains(pattern))
+  }
+
+  /// Mask the `key=value` and `"key": "value"` pairs of a line whose key is
+  /// sensitive.
+  pub fn redact_pairs<'a>(&self, line: &'a str) -> Cow<'a, str> {
+    let line = pair_regex().replace_all(line, |caps: &Captures<'_>| {
+      if self.is_sensitive(&caps[1]) {
+        format!("{}={}", &caps[1], REDACTED)
+      } else {
+        caps[0].to_owned()
+      }
+    });
+    let masked = json_regex().replace_all(&line, |caps: &Captures<'_>| {
+      if self.is_sensitive(&caps[1]) {
+        format!("\"{}\"{}\"{}\"", &caps[1], &caps[2], REDACTED)
+      } else {
+        caps[0].to_owned()
+      }
+    });
+    match masked {
+      Cow::Borrowed(_) => line,
+      Cow::Owned(masked) => Cow::Owned(masked),
+    }
+  }
+
+  /// `text` line by line, with sensitive values masked.
+  pub fn redact_lines<'a>(&self, text: &'a str) -> Vec<Cow<'a, str>> {
+    let is_secret = text
+      .lines()
+      .any(|line| line == "kind: Secret" || line.trim_end() == "Type:  Opaque");
+    // lines indented deeper than this are the value of a masked key
+    let mut masked_below: Option<usize> = None;
+    let mut sensitive_env = false;
+    let mut lines = Vec::new();
+    for line in text.lines() {
+      let parsed = key_line(line);
+      let indent = line.len() - line.trim_start().len();
+      if let Some(depth) = masked_below {
+        if line.trim().is_empty() || indent > depth {
+          lines.push(match &parsed {
+            Some(KeyLine {
+              value_at: Some(at), ..
+            }) => Cow::Owned(format!("{}{}", &line[..*at], REDACTED)),
+            Some(_) => Cow::Borrowed(line),
+            None if line.trim().is_empty() => Cow::Borrowed(line),
+            None => Cow::Owned(format!("{}{}", &line[..indent], REDACTED)),
+          });
+          continue;
+        }
+        masked_below = None;
+      }
+      let after_env_name = std::mem::take(&mut sensitive_env);
+      let Some(KeyLine {
+        indent: key_at,
+        key,
+        value_at,
+      }) = parsed
+      else {
+        lines.push(self.redact_pairs(line));
+        continue;
+      };
+      let secret_data = is_secret && key_at == 0 && matches!(key, "data" | "stringData");
+      let sensitive = secret_data || self.is_sensitive(key) || (after_env_name && key == "value");
+      if key == "name" {
+        sensitive_env = value_at.is_some_and(|at| self.is_sensitive(&line[at..]));
+      }
+      match value_at {
+        Some(at) if sensitive => {
+          let value = line[at..].trim();
+          if matches!(value, "|" | ">" | "|-" | ">-") {
+            masked_below = Some(indent);
+            lines.push(Cow::Borrowed(line));
+          } else {
+            lines.push(Cow::Owned(format!("{}{}", &line[..at], REDACTED)));
+          }
+        }
+        None if sensitive => {
+          masked_below = Some(indent);
+          lines.push(Cow::Borrowed(line));
+        }
+        _ => lines.push(self.redact_pairs(line)),
+      }
+    }
+    lines
+  }
+
+  /// A line of a container's environment, `NAME  value`, with the value
+  /// masked for a sensitive name. References such as `from secret db key
+  /// password` are kept, as they hold no value.
+  pub fn redact_env_line<'a>(&self, line: &'a str) -> Cow<'a, str> {
+    let Some((name, rest)) = line.trim_start().split_once("  ") else {
+      return Cow::Borrowed(line);
+    };
+    let source = rest.trim_start();
+    if !self.is_sensitive(name) || source.starts_with("from ") || source.is_empty() {
+      return Cow::Borrowed(line);
+    }
+    Cow::Owned(format!(
+      "{}{}",
+      &line[..line.len() - source.len()],
+      REDACTED
+    ))
+  }
+}
+
+impl App {
+  /// The redactor while redact mode is on.
+  pub fn redactor(&self) -> Option<&Redactor> {
+    self.redact.then_some(&self.redactor)
+  }
+
+  /// Mask the describe text, if redact mode is on, for
+  /// [`ScrollableTxt::redacted_line`](super::models::ScrollableTxt::redacted_line).
+  pub fn redact_describe(&mut self) {
+    if self.redact {
+      self.data.describe_out.redact(&self.redactor);
+    }
+  }
+
+  /// Mask the log buffer as redact mode says.
+  pub fn redact_logs(&mut self) {
+    self
+      .data
+      .logs
+      .set_redactor(self.redact.then_some(&self.redactor));
+  }
+
+  pub fn toggle_redact(&mut self) {
+    self.redact = !self.redact;
+    self.set_status_message(if self.redact {
+      "Redact mode on: sensitive values are masked"
+    } else {
+      "Redact mode off"
+    });
+  }
+
+  /// Refuse to show secret values while redact mode is on, saying why.
+  pub fn refuse_in_redact_mode(&mut self) -> bool {
+    if self.redact {
+      self.set_status_message("Secret values stay hidden in redact mode");
+    }
+    self.redact
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn redact(text: &str) -> String {
+    Redactor::new(DEFAULT_REDACT_PATTERNS)
+      .redact_lines(text)
+      .join("\n")
+  }
+
+  #[test]
+  fn test_redact_lines_masks_values_of_sensitive_keys() {
+    let yaml = r#"apiVersion: v1
+kind: Pod
+metadata:
+  labels:
+    app: web
+    api-key: abc123
+  annotations:
+    kubectl.kubernetes.io/last-applied-configuration: |
+      {"metadata":{"name":"web","annotations":{"auth-token":"s3cr3t"}}}
+spec:
+  containers:
+  - args:
+    - --token=s3cr3t
+    - --port=80
+    env:
+    - name: DB_PASSWORD
+      value: hunter2
+    - name: LOG_LEVEL
+      value: debug"#;
+    assert_eq!(
+      redact(yaml),
+      r#"apiVersion: v1
+kind: Pod
+metadata:
+  labels:
+    app: web
+    api-key: REDACTED
+  annotations:
+    kubectl.kubernetes.io/last-applied-configuration: |
+      {"metadata":{"name":"web","annotations":{"auth-token":"REDACTED"}}}
+spec:
+  containers:
+  - args:
+    - --token=REDACTED
+    - --port=80
+    env:
+    - name: DB_PASSWORD
+      value: REDACTED
+    - name: LOG_LEVEL
+      value: debug"#
+    );
+
+    let describe = "Labels:       app=web\n              session-token=abc\nEnvironment:\n      DB_PASSWORD:  hunter2\n      PORT:         80";
+    assert_eq!(
+      redact(describe),
+      "Labels:       app=web\n              session-token=REDACTED\nEnvironment:\n      DB_PASSWORD:  REDACTED\n      PORT:         80"
+    );
+  }
+
+  #[test]
+  fn test_redact_lines_masks_all_secret_data_and_nested_values() {
+    let secret = "apiVersion: v1\ndata:\n  config.json: eyJhIjoxfQ==\n  user: YWRtaW4=\nkind: Secret\nmetadata:\n  name: creds\ntype: Opaque";
+    assert_eq!(
+      redact(secret),
+      "apiVersion: v1\ndata:\n  config.json: REDACTED\n  user: REDACTED\nkind: Secret\nmetadata:\n  name: creds\ntype: Opaque"
+    );
+    // the same keys of a ConfigMap are left alone, bar sensitive ones
+    let config_map =
+      "data:\n  config.json: '{}'\n  password: |\n    line one\n    line two\nkind: ConfigMap";
+    assert_eq!(
+      redact(config_map),
+      "data:\n  config.json: '{}'\n  password: |\n    REDACTED\n    REDACTED\nkind: ConfigMap"
+    );
+  }
+
+  #[test]
+  fn test_redact_env_line_keeps_references() {
+    let redactor = Redactor::new(["password", "token"]);
+    assert_eq!(
+      redactor.redact_env_line("DB_PASSWORD  hunter2"),
+      "DB_PASSWORD  REDACTED"
+    );
+    assert_eq!(
+      redactor.redact_env_line("DB_PASSWORD  from secret db key password"),
+      "DB_PASSWORD  from secret db key password"
+    );
+    assert_eq!(
+      redactor.redact_env_line("PORT         80"),
+      "PORT         80"
+    );
+    assert!(!Redactor::new(Vec::<String>::new()).is_sensitive("password"));
+  }
+
+  #[test]
+  fn test_toggle_redact_refuses_revealing_secrets() {
+    let mut app = App::default();
+    assert_eq!(app.redactor(), None);
+    assert!(!app.refuse_in_redact_mode());
+    app.toggle_redact();
+    assert!(app.redactor().unwrap().is_sensitive("X-Api-Key"));
+    assert!(app.refuse_in_redact_mode());
+    assert_eq!(
+      app.status_message.text(),
+      "Secret values stay hidden in redact mode"
+    );
+    app.apply_config(KdashConfig {
+      redact_patterns: Some(vec!["license".into()]),
+      ..KdashConfig::default()
+    });
+    let redactor = app.redactor().unwrap();
+    assert!(redactor.is_sensitive("LICENSE_KEY"));
+    assert!(!redactor.is_sensitive("password"));
+  }
+
+  #[test]
+  fn test_redacted_describe_lines_are_cached_per_text_and_redactor() {
+    let mut app = App::default();
+    app.data.describe_out = super::super::models::ScrollableTxt::with_string(
+      "token: abc
+name: web"
+        .into(),
+    );
+    app.redact_describe();
+    assert_eq!(app.data.describe_out.redacted_line(0), None);
+
+    app.toggle_redact();
+    app.redact_describe();
+    assert_eq!(
+      app.data.describe_out.redacted_line(0),
+      Some("token: REDACTED")
+    );
+    assert_eq!(app.data.describe_out.redacted_line(1), None);
+
+    app.apply_config(KdashConfig {
+      redact_patterns: Some(vec!["name".into()]),
+      ..KdashConfig::default()
+    });
+    app.redact_describe();
+    assert_eq!(app.data.describe_out.redacted_line(0), None);
+    assert_eq!(
+      app.data.describe_out.redacted_line(1),
+      Some("name: REDACTED")
+    );
+  }
+}
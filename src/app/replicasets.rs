@@ -1,11 +1,12 @@
+use anyhow::anyhow;
 use async_trait::async_trait;
 use chrono::Utc;
 use k8s_openapi::api::apps::v1::ReplicaSet;
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
+use kube::{
+  api::{ListParams, ObjectMeta},
+  Api,
 };
+use ratatui::{layout::Rect, widgets::Cell, Frame};
 
 use super::{
   models::{self, AppResource, KubeResource, Named},
@@ -18,7 +19,7 @@ use crate::{
   network::Network,
   ui::utils::{
     action_hint, describe_yaml_and_logs_hint, draw_describe_block, draw_resource_block,
-    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, responsive_columns,
+    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, style_help,
     style_text, title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
@@ -30,6 +31,8 @@ pub struct KubeReplicaSet {
   pub desired: i32,
   pub current: i32,
   pub ready: i32,
+  /// Name of the controlling Deployment, or `-` for a standalone ReplicaSet.
+  pub owner: String,
   pub age: String,
   k8s_obj: ReplicaSet,
 }
@@ -51,15 +54,36 @@ impl From<ReplicaSet> for KubeReplicaSet {
         .map_or(0, |s| s.replicas.unwrap_or_default()),
       current,
       ready,
+      owner: deployment_owner(&rps).unwrap_or_else(|| "-".into()),
       k8s_obj: utils::sanitize_obj(rps),
     }
   }
 }
 
+/// The Deployment controlling this ReplicaSet, taken from its controller owner
+/// reference.
+fn deployment_owner(rps: &ReplicaSet) -> Option<String> {
+  rps
+    .metadata
+    .owner_references
+    .as_ref()?
+    .iter()
+    .find(|owner| owner.controller == Some(true) && owner.kind == "Deployment")
+    .map(|owner| owner.name.clone())
+}
+
 impl Named for KubeReplicaSet {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<ReplicaSet> for KubeReplicaSet {
@@ -107,56 +131,124 @@ impl AppResource for ReplicaSetResource {
   }
 }
 
-const RS_COLUMNS: [ColumnDef; 6] = [
-  ColumnDef::all("Namespace", 25, 25, 25),
-  ColumnDef::all("Name", 35, 35, 35),
-  ColumnDef::all("Desired", 10, 10, 10),
-  ColumnDef::all("Current", 10, 10, 10),
-  ColumnDef::all("Ready", 10, 10, 10),
-  ColumnDef::all("Age", 10, 10, 10),
+/// List the ReplicaSets a Deployment controls, for the deployment drill-down.
+pub async fn get_replica_sets_by_owner(nw: &Network<'_>, namespace: &str, owner: &str) {
+  let api: Api<ReplicaSet> = Api::namespaced(nw.client.clone(), namespace);
+  match api.list(&ListParams::default()).await {
+    Ok(list) => {
+      let items: Vec<KubeReplicaSet> = list
+        .into_iter()
+        .map(KubeReplicaSet::from)
+        .filter(|rs| rs.owner == owner)
+        .collect();
+      let mut app = nw.app.lock().await;
+      app.data.replica_sets.set_items(items);
+    }
+    Err(e) => {
+      nw.handle_error(anyhow!(
+        "Failed to get replicasets for deployment '{}'. {}",
+        owner,
+        e
+      ))
+      .await;
+    }
+  }
+}
+
+pub(crate) const RS_COLUMNS: [ColumnDef; 7] = [
+  ColumnDef::all("Namespace", 20, 20, 20),
+  ColumnDef::all("Name", 30, 30, 30),
+  ColumnDef::all("Deployment", 20, 20, 20),
+  ColumnDef::all("Desired", 7, 7, 7),
+  ColumnDef::all("Current", 7, 7, 7),
+  ColumnDef::all("Ready", 7, 7, 7),
+  ColumnDef::all("Age", 9, 9, 9),
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
   let title = get_resource_title(
     app,
     REPLICA_SETS_TITLE,
     "",
     app.data.replica_sets.items.len(),
   );
+  let inline_help = format!(
+    "{} · {}",
+    action_hint("pods", DEFAULT_KEYBINDING.submit.key),
+    describe_yaml_and_logs_hint()
+  );
+  draw_replica_sets_table(f, app, area, title, inline_help);
+}
+
+/// ReplicaSets of one Deployment, drawn inside the Deployments tab when
+/// drilling down from a deployment.
+pub(crate) fn draw_block_as_sub(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  let owner = app
+    .data
+    .selected
+    .replica_set_owner
+    .clone()
+    .unwrap_or_default();
+  let base = format!("Deployment {} -> ReplicaSets", owner);
+  let title = get_resource_title(app, base.as_str(), "", app.data.replica_sets.items.len());
+  let inline_help = format!(
+    "{} · {} · {}:back ",
+    action_hint("pods", DEFAULT_KEYBINDING.submit.key),
+    describe_yaml_and_logs_hint().trim_end(),
+    DEFAULT_KEYBINDING.esc.key.symbol()
+  );
+  draw_replica_sets_table(f, app, area, title, inline_help);
+}
 
-  let (headers, widths) = responsive_columns(&RS_COLUMNS, ViewTier::Compact);
+fn draw_replica_sets_table(
+  f: &mut Frame<'_>,
+  app: &mut App,
+  area: Rect,
+  title: String,
+  inline_help: String,
+) {
+  let load = app.view_load(ActiveBlock::ReplicaSets);
+  let columns = app.column_layout(ActiveBlock::ReplicaSets, ViewTier::Compact);
+  let names = app.short_names(
+    app
+      .data
+      .replica_sets
+      .items
+      .iter()
+      .map(|rs| rs.name.as_str()),
+  );
 
   draw_resource_block(
     f,
     area,
     ResourceTableProps {
       title,
-      inline_help: help_bold_line(
-        format!(
-          "{} · {}",
-          action_hint("pods", DEFAULT_KEYBINDING.submit.key),
-          describe_yaml_and_logs_hint()
-        ),
-        app.palette,
-      ),
+      inline_help: help_bold_line(inline_help, app.palette),
       resource: &mut app.data.replica_sets,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.desired.to_string()),
-        Cell::from(c.current.to_string()),
-        Cell::from(c.ready.to_string()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      // Old revisions scaled to zero are muted so the active one stands out.
+      let style = if c.desired == 0 {
+        style_help(app.palette)
+      } else {
+        style_text(app.palette)
+      };
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(names.get(&c.name).to_owned()),
+          Cell::from(c.owner.to_owned()),
+          Cell::from(c.desired.to_string()),
+          Cell::from(c.current.to_string()),
+          Cell::from(c.ready.to_string()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style)
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -181,7 +273,22 @@ mod tests {
         desired: 1,
         current: 1,
         ready: 1,
+        owner: "metrics-server".into(),
       }
     );
   }
+
+  #[test]
+  fn test_deployment_owner_requires_controller_deployment() {
+    let (_, rpls_list): (Vec<KubeReplicaSet>, Vec<ReplicaSet>) =
+      convert_resource_from_file("replicasets");
+    let mut rs = rpls_list[0].clone();
+    assert_eq!(deployment_owner(&rs), Some("metrics-server".into()));
+
+    rs.metadata.owner_references.as_mut().unwrap()[0].controller = None;
+    assert_eq!(deployment_owner(&rs), None);
+
+    rs.metadata.owner_references = None;
+    assert_eq!(KubeReplicaSet::from(rs).owner, "-");
+  }
 }
@@ -3,11 +3,8 @@ use std::collections::BTreeMap;
 use async_trait::async_trait;
 use chrono::Utc;
 use k8s_openapi::api::core::v1::ReplicationController;
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
-};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
 
 use super::{
   models::{self, AppResource, KubeResource, Named},
@@ -20,8 +17,8 @@ use crate::{
   network::Network,
   ui::utils::{
     action_hint, describe_yaml_logs_and_esc_hint, draw_describe_block, draw_resource_block,
-    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, responsive_columns,
-    style_text, title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, style_text,
+    title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -100,6 +97,14 @@ impl Named for KubeReplicationController {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<ReplicationController> for KubeReplicationController {
@@ -149,7 +154,7 @@ impl AppResource for ReplicationControllerResource {
   }
 }
 
-const RC_COLUMNS: [ColumnDef; 9] = [
+pub(crate) const RC_COLUMNS: [ColumnDef; 9] = [
   ColumnDef::all("Namespace", 15, 15, 15),
   ColumnDef::all("Name", 15, 15, 15),
   ColumnDef::all("Desired", 10, 10, 10),
@@ -162,7 +167,7 @@ const RC_COLUMNS: [ColumnDef; 9] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::ReplicationControllers);
   let title = get_resource_title(
     app,
     RPL_CTRL_TITLE,
@@ -170,7 +175,7 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
     app.data.replication_controllers.items.len(),
   );
 
-  let (headers, widths) = responsive_columns(&RC_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::ReplicationControllers, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -186,25 +191,26 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
         app.palette,
       ),
       resource: &mut app.data.replication_controllers,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.desired.to_string()),
-        Cell::from(c.current.to_string()),
-        Cell::from(c.ready.to_string()),
-        Cell::from(c.containers.to_owned()),
-        Cell::from(c.images.to_owned()),
-        Cell::from(c.selector.to_owned()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.desired.to_string()),
+          Cell::from(c.current.to_string()),
+          Cell::from(c.ready.to_string()),
+          Cell::from(c.containers.to_owned()),
+          Cell::from(c.images.to_owned()),
+          Cell::from(c.selector.to_owned()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -0,0 +1,273 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use k8s_openapi::{api::core::v1::ResourceQuota, apimachinery::pkg::api::resource::Quantity};
+use kube::api::ObjectMeta;
+use kubectl_view_allocations::qty::Qty;
+use ratatui::{
+  layout::Rect,
+  text::{Line, Span},
+  widgets::Cell,
+  Frame,
+};
+
+use super::{
+  models::{AppResource, KubeResource, Named},
+  utils, ActiveBlock, App,
+};
+use crate::{
+  draw_resource_tab,
+  network::Network,
+  ui::{
+    theme::Palette,
+    utils::{
+      describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
+      get_describe_active, get_resource_title, help_bold_line, style_caution, style_failure,
+      style_text, title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+    },
+  },
+};
+
+/// Share of a quota in use at which it is flagged as nearly exhausted.
+const QUOTA_WARN_RATIO: f64 = 0.8;
+
+/// Usage of one resource tracked by a quota, e.g. `requests.cpu 1500m/2`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuotaUsage {
+  pub resource: String,
+  pub used: String,
+  pub hard: String,
+  /// `used / hard`; `None` when either value cannot be parsed.
+  pub ratio: Option<f64>,
+}
+
+impl QuotaUsage {
+  fn new(resource: &str, used: Option<&Quantity>, hard: &Quantity) -> Self {
+    let used_str = used.map_or_else(|| "0".to_owned(), |q| q.0.clone());
+    let ratio = match (used_str.parse::<Qty>(), hard.0.parse::<Qty>()) {
+      (Ok(used_qty), Ok(hard_qty)) if hard_qty.is_zero() => {
+        Some(if used_qty.is_zero() { 1.0 } else { f64::MAX })
+      }
+      (Ok(used_qty), Ok(hard_qty)) => Some(f64::from(&used_qty) / f64::from(&hard_qty)),
+      _ => None,
+    };
+    QuotaUsage {
+      resource: resource.to_owned(),
+      used: used_str,
+      hard: hard.0.clone(),
+      ratio,
+    }
+  }
+
+  /// At or over the hard limit: the next matching object will be rejected.
+  pub fn is_exhausted(&self) -> bool {
+    self.ratio.is_some_and(|r| r >= 1.0)
+  }
+
+  pub fn is_near_limit(&self) -> bool {
+    self.ratio.is_some_and(|r| r >= QUOTA_WARN_RATIO)
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct KubeResourceQuota {
+  pub namespace: String,
+  pub name: String,
+  pub usage: Vec<QuotaUsage>,
+  pub age: String,
+  k8s_obj: ResourceQuota,
+}
+
+impl From<ResourceQuota> for KubeResourceQuota {
+  fn from(quota: ResourceQuota) -> Self {
+    // status.hard is what the quota controller enforces; fall back to the spec
+    // until the controller has observed the quota.
+    let empty = BTreeMap::new();
+    let status = quota.status.as_ref();
+    let hard = status
+      .and_then(|s| s.hard.as_ref())
+      .or_else(|| quota.spec.as_ref().and_then(|s| s.hard.as_ref()))
+      .unwrap_or(&empty);
+    let used = status.and_then(|s| s.used.as_ref()).unwrap_or(&empty);
+    let usage = hard
+      .iter()
+      .map(|(resource, hard)| QuotaUsage::new(resource, used.get(resource), hard))
+      .collect();
+
+    KubeResourceQuota {
+      namespace: quota.metadata.namespace.clone().unwrap_or_default(),
+      name: quota.metadata.name.clone().unwrap_or_default(),
+      usage,
+      age: utils::to_age(quota.metadata.creation_timestamp.as_ref(), Utc::now()),
+      k8s_obj: utils::sanitize_obj(quota),
+    }
+  }
+}
+
+impl Named for KubeResourceQuota {
+  fn get_name(&self) -> &String {
+    &self.name
+  }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
+}
+
+impl KubeResource<ResourceQuota> for KubeResourceQuota {
+  fn get_k8s_obj(&self) -> &ResourceQuota {
+    &self.k8s_obj
+  }
+}
+
+static RESOURCE_QUOTAS_TITLE: &str = "ResourceQuotas";
+
+pub struct ResourceQuotaResource {}
+
+#[async_trait]
+impl AppResource for ResourceQuotaResource {
+  fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    draw_resource_tab!(
+      RESOURCE_QUOTAS_TITLE,
+      block,
+      f,
+      app,
+      area,
+      Self::render,
+      draw_block,
+      app.data.resource_quotas
+    );
+  }
+
+  async fn get_resource(nw: &Network<'_>) {
+    let items: Vec<KubeResourceQuota> = nw.get_namespaced_resources(ResourceQuota::into).await;
+
+    let mut app = nw.app.lock().await;
+    app.data.resource_quotas.set_items(items);
+  }
+}
+
+pub(crate) const RQ_COLUMNS: [ColumnDef; 4] = [
+  ColumnDef::all("Namespace", 15, 15, 15),
+  ColumnDef::all("Name", 20, 20, 20),
+  ColumnDef::all("Used/Hard", 55, 55, 55),
+  ColumnDef::all("Age", 10, 10, 10),
+];
+
+/// `resource used/hard` entries, each coloured by how close it is to its limit.
+fn usage_line(usage: &[QuotaUsage], palette: Palette) -> Line<'static> {
+  let mut spans = vec![];
+  for (i, u) in usage.iter().enumerate() {
+    if i > 0 {
+      spans.push(Span::styled(", ", style_text(palette)));
+    }
+    let style = if u.is_exhausted() {
+      style_failure(palette)
+    } else if u.is_near_limit() {
+      style_caution(palette)
+    } else {
+      style_text(palette)
+    };
+    spans.push(Span::styled(
+      format!("{} {}/{}", u.resource, u.used, u.hard),
+      style,
+    ));
+  }
+  Line::from(spans)
+}
+
+fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  let load = app.view_load(ActiveBlock::ResourceQuotas);
+  let title = get_resource_title(
+    app,
+    RESOURCE_QUOTAS_TITLE,
+    "",
+    app.data.resource_quotas.items.len(),
+  );
+
+  let columns = app.column_layout(ActiveBlock::ResourceQuotas, ViewTier::Compact);
+
+  draw_resource_block(
+    f,
+    area,
+    ResourceTableProps {
+      title,
+      inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
+      resource: &mut app.data.resource_quotas,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
+    },
+    |c| {
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(usage_line(&c.usage, app.palette)),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
+    },
+    app.palette,
+    load,
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::app::test_utils::*;
+
+  #[test]
+  fn test_resource_quotas_from_api() {
+    let (quotas, quota_list): (Vec<KubeResourceQuota>, Vec<_>) =
+      convert_resource_from_file("resource_quotas");
+
+    assert_eq!(quotas.len(), 1);
+    assert_eq!(quotas[0].namespace, "team-a");
+    assert_eq!(quotas[0].name, "compute");
+    assert_eq!(
+      quotas[0].age,
+      utils::to_age(Some(&get_time("2024-03-01T10:00:00Z")), Utc::now())
+    );
+    assert_eq!(quotas[0].k8s_obj, quota_list[0]);
+
+    let usage: Vec<_> = quotas[0]
+      .usage
+      .iter()
+      .map(|u| {
+        (
+          u.resource.as_str(),
+          u.used.as_str(),
+          u.hard.as_str(),
+          u.is_near_limit(),
+          u.is_exhausted(),
+        )
+      })
+      .collect();
+    assert_eq!(
+      usage,
+      vec![
+        ("limits.memory", "3Gi", "8Gi", false, false),
+        ("pods", "10", "10", true, true),
+        ("requests.cpu", "1700m", "2", true, false),
+        // Not used yet: no entry in status.used
+        ("services", "0", "5", false, false),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_quota_usage_zero_hard_limit_is_exhausted() {
+    let usage = QuotaUsage::new("pods", None, &Quantity("0".into()));
+    assert!(usage.is_exhausted());
+
+    let unparseable = QuotaUsage::new("pods", Some(&Quantity("x".into())), &Quantity("1".into()));
+    assert_eq!(unparseable.ratio, None);
+    assert!(!unparseable.is_near_limit());
+  }
+}
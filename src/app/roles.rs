@@ -1,9 +1,14 @@
+use anyhow::anyhow;
 use async_trait::async_trait;
 use chrono::Utc;
-use k8s_openapi::api::rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding};
+use k8s_openapi::api::rbac::v1::{
+  ClusterRole, ClusterRoleBinding, PolicyRule, Role, RoleBinding, RoleRef, Subject,
+};
+use kube::{api::ObjectMeta, Api, Error};
 use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
+  layout::{Constraint, Rect},
+  text::Line,
+  widgets::{Cell, Paragraph},
   Frame,
 };
 
@@ -17,8 +22,8 @@ use crate::{
   network::Network,
   ui::utils::{
     describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, responsive_columns, style_text,
-    title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+    get_describe_active, get_resource_title, help_bold_line, layout_block_default, style_failure,
+    style_text, title_with_dual_style, vertical_chunks, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -35,6 +40,7 @@ pub struct KubeRoleBinding {
   pub namespace: String,
   pub name: String,
   pub role: String,
+  pub subjects: String,
   pub age: String,
   k8s_obj: RoleBinding,
 }
@@ -50,10 +56,134 @@ pub struct KubeClusterRole {
 pub struct KubeClusterRoleBinding {
   pub name: String,
   pub role: String,
+  pub subjects: String,
   pub age: String,
   k8s_obj: ClusterRoleBinding,
 }
 
+/// The rules a binding grants, resolved from the Role or ClusterRole it
+/// references. Shown above the describe output of a binding.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BindingRules {
+  /// `Kind/name` of the referenced role.
+  pub role: String,
+  /// `None` when the referenced role does not exist.
+  pub rules: Option<Vec<PolicyRule>>,
+}
+
+fn format_role_ref(role_ref: &RoleRef) -> String {
+  format!("{}/{}", role_ref.kind, role_ref.name)
+}
+
+/// Subjects as `Kind/name`, with the namespace for service accounts
+/// (`ServiceAccount/monitoring/kiali`).
+fn format_subjects(subjects: Option<&Vec<Subject>>) -> String {
+  let subjects: Vec<String> = subjects
+    .into_iter()
+    .flatten()
+    .map(|s| match &s.namespace {
+      Some(ns) if s.kind == "ServiceAccount" => format!("{}/{}/{}", s.kind, ns, s.name),
+      _ => format!("{}/{}", s.kind, s.name),
+    })
+    .collect();
+  if subjects.is_empty() {
+    "-".into()
+  } else {
+    subjects.join(", ")
+  }
+}
+
+/// One rule on a line, e.g. `pods, deployments.apps [web]: get, list`.
+/// Resources outside the core group carry their API group like `kubectl
+/// describe role` shows them.
+pub fn format_policy_rule(rule: &PolicyRule) -> String {
+  let groups = rule.api_groups.clone().unwrap_or_default();
+  let mut targets: Vec<String> = vec![];
+  for resource in rule.resources.iter().flatten() {
+    if groups.is_empty() {
+      targets.push(resource.clone());
+    }
+    for group in &groups {
+      if group.is_empty() {
+        targets.push(resource.clone());
+      } else {
+        targets.push(format!("{}.{}", resource, group));
+      }
+    }
+  }
+  targets.extend(rule.non_resource_urls.iter().flatten().cloned());
+  let mut line = targets.join(", ");
+  if let Some(names) = rule.resource_names.as_ref().filter(|n| !n.is_empty()) {
+    line.push_str(&format!(" [{}]", names.join(", ")));
+  }
+  format!("{}: {}", line, rule.verbs.join(", "))
+}
+
+/// Fetch the Role or ClusterRole a binding references. Role references are
+/// resolved in the binding's namespace.
+pub async fn get_binding_rules(nw: &Network<'_>, kind: &str, name: &str, namespace: Option<&str>) {
+  let role = format!("{}/{}", kind, name);
+  let result = match (kind, namespace) {
+    ("Role", Some(ns)) => Api::<Role>::namespaced(nw.client.clone(), ns)
+      .get(name)
+      .await
+      .map(|r| r.rules),
+    _ => Api::<ClusterRole>::all(nw.client.clone())
+      .get(name)
+      .await
+      .map(|r| r.rules),
+  };
+  let rules = match result {
+    Ok(rules) => Some(rules.unwrap_or_default()),
+    Err(Error::Api(status)) if status.is_not_found() => None,
+    Err(e) => {
+      nw.handle_error(anyhow!("Failed to get {}. {}", role, e))
+        .await;
+      return;
+    }
+  };
+  let mut app = nw.app.lock().await;
+  app.data.binding_rules = Some(BindingRules { role, rules });
+}
+
+/// Describe view of a binding, with the effective rules of its role on top.
+fn draw_binding_describe(f: &mut Frame<'_>, app: &mut App, area: Rect, title: Line<'_>) {
+  let Some(binding_rules) = app.data.binding_rules.clone() else {
+    draw_describe_block(f, app, area, title);
+    return;
+  };
+  let (lines, style) = match &binding_rules.rules {
+    Some(rules) if rules.is_empty() => (
+      vec![Line::from("The role has no rules")],
+      style_text(app.palette),
+    ),
+    Some(rules) => (
+      rules
+        .iter()
+        .map(|r| Line::from(format_policy_rule(r)))
+        .collect(),
+      style_text(app.palette),
+    ),
+    None => (
+      vec![Line::from(format!(
+        "{} not found, so this binding grants nothing",
+        binding_rules.role
+      ))],
+      style_failure(app.palette),
+    ),
+  };
+  let height = (lines.len() as u16 + 2).min(area.height / 2).max(3);
+  let chunks = vertical_chunks(vec![Constraint::Length(height), Constraint::Min(0)], area);
+  let pane_title = format!(" Effective rules ({}) ", binding_rules.role);
+  f.render_widget(
+    Paragraph::new(lines)
+      .style(style)
+      .block(layout_block_default(&pane_title, app.palette)),
+    chunks[0],
+  );
+  draw_describe_block(f, app, chunks[1], title);
+}
+
 impl From<Role> for KubeRole {
   fn from(role: Role) -> Self {
     KubeRole {
@@ -69,6 +199,14 @@ impl Named for KubeRole {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<Role> for KubeRole {
@@ -104,17 +242,17 @@ impl AppResource for RoleResource {
   }
 }
 
-const ROLE_COLUMNS: [ColumnDef; 3] = [
+pub(crate) const ROLE_COLUMNS: [ColumnDef; 3] = [
   ColumnDef::all("Namespace", 40, 40, 40),
   ColumnDef::all("Name", 40, 40, 40),
   ColumnDef::all("Age", 20, 20, 20),
 ];
 
 fn draw_roles_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::Roles);
   let title = get_resource_title(app, ROLES_TITLE, "", app.data.roles.items.len());
 
-  let (headers, widths) = responsive_columns(&ROLE_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::Roles, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -123,19 +261,20 @@ fn draw_roles_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       title,
       inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
       resource: &mut app.data.roles,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -156,6 +295,10 @@ impl Named for KubeClusterRole {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<ClusterRole> for KubeClusterRole {
@@ -191,13 +334,13 @@ impl AppResource for ClusterRoleResource {
   }
 }
 
-const CR_COLUMNS: [ColumnDef; 2] = [
+pub(crate) const CR_COLUMNS: [ColumnDef; 2] = [
   ColumnDef::all("Name", 50, 50, 50),
   ColumnDef::all("Age", 50, 50, 50),
 ];
 
 fn draw_cluster_roles_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::ClusterRoles);
   let title = get_resource_title(
     app,
     CLUSTER_ROLES_TITLE,
@@ -205,7 +348,7 @@ fn draw_cluster_roles_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
     app.data.cluster_roles.items.len(),
   );
 
-  let (headers, widths) = responsive_columns(&CR_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::ClusterRoles, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -214,18 +357,19 @@ fn draw_cluster_roles_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       title,
       inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
       resource: &mut app.data.cluster_roles,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      columns
+        .row(vec![
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -234,7 +378,8 @@ impl From<RoleBinding> for KubeRoleBinding {
     KubeRoleBinding {
       namespace: role_binding.metadata.namespace.clone().unwrap_or_default(),
       name: role_binding.metadata.name.clone().unwrap_or_default(),
-      role: role_binding.role_ref.name.clone(),
+      role: format_role_ref(&role_binding.role_ref),
+      subjects: format_subjects(role_binding.subjects.as_ref()),
       age: utils::to_age(
         role_binding.metadata.creation_timestamp.as_ref(),
         Utc::now(),
@@ -247,6 +392,14 @@ impl Named for KubeRoleBinding {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<RoleBinding> for KubeRoleBinding {
@@ -262,6 +415,20 @@ pub struct RoleBindingResource {}
 #[async_trait]
 impl AppResource for RoleBindingResource {
   fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    if block == ActiveBlock::Describe {
+      let title = title_with_dual_style(
+        get_resource_title(
+          app,
+          ROLE_BINDINGS_TITLE,
+          get_describe_active(block),
+          app.data.role_bindings.items.len(),
+        ),
+        crate::ui::utils::copy_and_escape_title_line(ROLE_BINDINGS_TITLE, app.palette),
+        app.palette,
+      );
+      draw_binding_describe(f, app, area, title);
+      return;
+    }
     draw_resource_tab!(
       ROLE_BINDINGS_TITLE,
       block,
@@ -282,15 +449,16 @@ impl AppResource for RoleBindingResource {
   }
 }
 
-const RB_COLUMNS: [ColumnDef; 4] = [
-  ColumnDef::all("Namespace", 20, 20, 20),
-  ColumnDef::all("Name", 30, 30, 30),
-  ColumnDef::all("Role", 30, 30, 30),
-  ColumnDef::all("Age", 20, 20, 20),
+pub(crate) const RB_COLUMNS: [ColumnDef; 5] = [
+  ColumnDef::all("Namespace", 15, 15, 15),
+  ColumnDef::all("Name", 20, 20, 20),
+  ColumnDef::all("Role", 20, 20, 20),
+  ColumnDef::all("Subjects", 35, 35, 35),
+  ColumnDef::all("Age", 10, 10, 10),
 ];
 
 fn draw_role_bindings_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::RoleBindings);
   let title = get_resource_title(
     app,
     ROLE_BINDINGS_TITLE,
@@ -298,7 +466,7 @@ fn draw_role_bindings_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
     app.data.role_bindings.items.len(),
   );
 
-  let (headers, widths) = responsive_columns(&RB_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::RoleBindings, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -307,20 +475,22 @@ fn draw_role_bindings_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       title,
       inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
       resource: &mut app.data.role_bindings,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.role.to_owned()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.role.to_owned()),
+          Cell::from(c.subjects.to_owned()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -328,7 +498,8 @@ impl From<ClusterRoleBinding> for KubeClusterRoleBinding {
   fn from(crb: ClusterRoleBinding) -> Self {
     KubeClusterRoleBinding {
       name: crb.metadata.name.clone().unwrap_or_default(),
-      role: format!("{}/{}", crb.role_ref.kind, crb.role_ref.name),
+      role: format_role_ref(&crb.role_ref),
+      subjects: format_subjects(crb.subjects.as_ref()),
       age: utils::to_age(crb.metadata.creation_timestamp.as_ref(), Utc::now()),
       k8s_obj: utils::sanitize_obj(crb),
     }
@@ -339,6 +510,10 @@ impl Named for KubeClusterRoleBinding {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<ClusterRoleBinding> for KubeClusterRoleBinding {
@@ -354,6 +529,20 @@ pub struct ClusterRoleBindingResource {}
 #[async_trait]
 impl AppResource for ClusterRoleBindingResource {
   fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    if block == ActiveBlock::Describe {
+      let title = title_with_dual_style(
+        get_resource_title(
+          app,
+          CLUSTER_ROLES_BINDING_TITLE,
+          get_describe_active(block),
+          app.data.cluster_role_bindings.items.len(),
+        ),
+        crate::ui::utils::copy_and_escape_title_line(CLUSTER_ROLES_BINDING_TITLE, app.palette),
+        app.palette,
+      );
+      draw_binding_describe(f, app, area, title);
+      return;
+    }
     draw_resource_tab!(
       CLUSTER_ROLES_BINDING_TITLE,
       block,
@@ -374,14 +563,15 @@ impl AppResource for ClusterRoleBindingResource {
   }
 }
 
-const CRB_COLUMNS: [ColumnDef; 3] = [
-  ColumnDef::all("Name", 40, 40, 40),
-  ColumnDef::all("Role", 40, 40, 40),
-  ColumnDef::all("Age", 20, 20, 20),
+pub(crate) const CRB_COLUMNS: [ColumnDef; 4] = [
+  ColumnDef::all("Name", 25, 25, 25),
+  ColumnDef::all("Role", 25, 25, 25),
+  ColumnDef::all("Subjects", 40, 40, 40),
+  ColumnDef::all("Age", 10, 10, 10),
 ];
 
 fn draw_cluster_role_binding_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::ClusterRoleBindings);
   let title = get_resource_title(
     app,
     CLUSTER_ROLES_BINDING_TITLE,
@@ -389,7 +579,7 @@ fn draw_cluster_role_binding_block(f: &mut Frame<'_>, app: &mut App, area: Rect)
     app.data.cluster_role_bindings.items.len(),
   );
 
-  let (headers, widths) = responsive_columns(&CRB_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::ClusterRoleBindings, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -398,28 +588,33 @@ fn draw_cluster_role_binding_block(f: &mut Frame<'_>, app: &mut App, area: Rect)
       title,
       inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
       resource: &mut app.data.cluster_role_bindings,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.role.to_owned()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      columns
+        .row(vec![
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.role.to_owned()),
+          Cell::from(c.subjects.to_owned()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
 #[cfg(test)]
 mod tests {
   use chrono::Utc;
+  use k8s_openapi::api::rbac::v1::PolicyRule;
 
   use crate::app::{
-    roles::{KubeClusterRole, KubeClusterRoleBinding, KubeRole, KubeRoleBinding},
+    roles::{
+      format_policy_rule, KubeClusterRole, KubeClusterRoleBinding, KubeRole, KubeRoleBinding,
+    },
     test_utils::{convert_resource_from_file, get_time},
     utils,
   };
@@ -467,7 +662,8 @@ mod tests {
       KubeRoleBinding {
         namespace: "default".to_string(),
         name: "kiali".into(),
-        role: "kiali-viewer".into(),
+        role: "Role/kiali-viewer".into(),
+        subjects: "ServiceAccount/monitoring/kiali-service-account".into(),
         age: utils::to_age(Some(&get_time("2022-06-27T16:33:07Z")), Utc::now()),
         k8s_obj: rolebindings_list[0].clone(),
       }
@@ -485,9 +681,40 @@ mod tests {
       KubeClusterRoleBinding {
         name: "admin-user".into(),
         role: "ClusterRole/cluster-admin".into(),
+        subjects: "ServiceAccount/kube-system/power-user, ServiceAccount/kube-system/admin-user"
+          .into(),
         age: utils::to_age(Some(&get_time("2022-03-02T16:50:53Z")), Utc::now()),
         k8s_obj: cluster_role_bindings_list[0].clone(),
       }
     )
   }
+
+  #[test]
+  fn test_format_policy_rule() {
+    let (roles, _): (Vec<KubeRole>, Vec<_>) = convert_resource_from_file("roles");
+    let rules = roles[0].k8s_obj.rules.clone().unwrap();
+    assert_eq!(
+      format_policy_rule(&rules[0]),
+      "configmaps, endpoints, pods/log: get, list, watch"
+    );
+
+    let scoped = PolicyRule {
+      api_groups: Some(vec!["apps".into(), "".into()]),
+      resources: Some(vec!["deployments".into()]),
+      resource_names: Some(vec!["web".into()]),
+      verbs: vec!["get".into(), "patch".into()],
+      ..PolicyRule::default()
+    };
+    assert_eq!(
+      format_policy_rule(&scoped),
+      "deployments.apps, deployments [web]: get, patch"
+    );
+
+    let non_resource = PolicyRule {
+      non_resource_urls: Some(vec!["/healthz".into()]),
+      verbs: vec!["get".into()],
+      ..PolicyRule::default()
+    };
+    assert_eq!(format_policy_rule(&non_resource), "/healthz: get");
+  }
 }
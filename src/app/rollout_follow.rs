@@ -0,0 +1,190 @@
+//! Follow a deployment's pods through a rollout (`Shift+W`): the pods view
+//! narrows to the deployment's pods, old and new ReplicaSets alike, oldest
+//! first, and refreshes about every second until the rollout completes.
+use super::{
+  deployments::{KubeDeployment, RolloutState},
+  models::KubeResource,
+  pods::KubePod,
+  ActiveBlock, App, RouteId,
+};
+use crate::network::IoEvent;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FollowedRollout {
+  pub deployment: String,
+  pub namespace: String,
+  /// Whether the rollout has been seen in progress. Following a deployment
+  /// that is already rolled out waits for the next rollout to finish.
+  progressed: bool,
+}
+
+impl App {
+  /// Open the pods of `deployment` and follow them until its rollout is done.
+  pub async fn follow_rollout(&mut self, deployment: &KubeDeployment, selector: String) {
+    self
+      .dispatch_resource_pods(
+        deployment.namespace.clone(),
+        selector,
+        "deployment".to_owned(),
+        RouteId::Home,
+      )
+      .await;
+    self.data.selected.followed_rollout = Some(FollowedRollout {
+      deployment: deployment.name.clone(),
+      namespace: deployment.namespace.clone(),
+      progressed: deployment.rollout().state == RolloutState::Progressing,
+    });
+    sort_by_creation(&mut self.data.pods.items);
+    self.set_status_message(format!(
+      "Following the rollout of deployment {}",
+      deployment.name
+    ));
+  }
+
+  /// Whether the pods view is following a rollout.
+  pub fn following_rollout(&self) -> bool {
+    self.get_current_route().active_block == ActiveBlock::Pods
+      && self.data.selected.followed_rollout.is_some()
+  }
+
+  /// The followed deployment as last fetched.
+  pub fn followed_deployment(&self) -> Option<&KubeDeployment> {
+    let followed = self.data.selected.followed_rollout.as_ref()?;
+    self
+      .data
+      .deployments
+      .items
+      .iter()
+      .find(|d| d.name == followed.deployment && d.namespace == followed.namespace)
+  }
+
+  /// Refresh the followed deployment and its pods.
+  pub async fn poll_followed_rollout(&mut self) {
+    self.dispatch(IoEvent::GetDeployments).await;
+    if let Some(event) = self.fetch_event(ActiveBlock::Pods) {
+      self.dispatch(event).await;
+    }
+  }
+
+  /// Stop following once the rollout has completed, leaving the pods in view.
+  pub fn end_completed_rollout_follow(&mut self) {
+    let Some(state) = self.followed_deployment().map(|d| d.rollout().state) else {
+      return;
+    };
+    let Some(followed) = self.data.selected.followed_rollout.as_mut() else {
+      return;
+    };
+    match state {
+      RolloutState::Progressing => followed.progressed = true,
+      RolloutState::Complete if followed.progressed => {
+        let name = followed.deployment.clone();
+        self.stop_following_rollout();
+        self.set_status_message(format!("Rollout of deployment {} complete", name));
+      }
+      _ => {}
+    }
+  }
+
+  pub fn stop_following_rollout(&mut self) {
+    self.data.selected.followed_rollout = None;
+  }
+}
+
+/// Oldest first, so old pods terminating sit above the new ones coming up.
+pub fn sort_by_creation(pods: &mut [KubePod]) {
+  pods.sort_by_key(|pod| {
+    pod
+      .get_k8s_obj()
+      .metadata
+      .creation_timestamp
+      .as_ref()
+      .map(|t| t.0)
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use k8s_openapi::api::apps::v1::Deployment;
+  use tokio::sync::mpsc;
+
+  use super::*;
+  use crate::app::test_utils::*;
+
+  fn deployment(generation: i64, observed: i64) -> KubeDeployment {
+    let mut deployment: Deployment = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web", "namespace": "shop", "generation": generation },
+      "spec": { "replicas": 2 },
+      "status": {
+        "observedGeneration": observed,
+        "replicas": 2,
+        "updatedReplicas": 2,
+        "availableReplicas": 2
+      }
+    }))
+    .unwrap();
+    deployment.metadata.uid = Some("uid".into());
+    KubeDeployment::from(deployment)
+  }
+
+  #[test]
+  fn test_sort_by_creation_puts_oldest_first() {
+    let (mut pods, _): (Vec<KubePod>, Vec<_>) = convert_resource_from_file("pods");
+    pods.reverse();
+    sort_by_creation(&mut pods);
+    let created: Vec<_> = pods
+      .iter()
+      .map(|p| p.get_k8s_obj().metadata.creation_timestamp.clone())
+      .collect();
+    assert!(created.windows(2).all(|w| w[0] <= w[1]));
+  }
+
+  #[tokio::test]
+  async fn test_follow_rollout_ends_when_the_rollout_completes() {
+    let (io_tx, mut io_rx) = mpsc::channel::<IoEvent>(10);
+    let mut app = App {
+      io_tx: Some(io_tx),
+      ..App::default()
+    };
+    app.route_home();
+    let progressing = deployment(2, 1);
+    app.data.deployments.set_items(vec![progressing.clone()]);
+
+    app.follow_rollout(&progressing, "app=web".into()).await;
+    assert!(app.following_rollout());
+    assert_eq!(
+      io_rx.recv().await.unwrap(),
+      IoEvent::GetPodsBySelector {
+        namespace: "shop".into(),
+        selector: "app=web".into(),
+      }
+    );
+
+    app.poll_followed_rollout().await;
+    assert_eq!(io_rx.recv().await.unwrap(), IoEvent::GetDeployments);
+
+    app.end_completed_rollout_follow();
+    assert!(app.following_rollout());
+
+    app.data.deployments.set_items(vec![deployment(2, 2)]);
+    app.end_completed_rollout_follow();
+    assert!(!app.following_rollout());
+    assert_eq!(
+      app.status_message.text(),
+      "Rollout of deployment web complete"
+    );
+    // the pods stay in view
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
+  }
+
+  #[tokio::test]
+  async fn test_following_a_finished_rollout_waits_for_the_next() {
+    let mut app = App::default();
+    app.route_home();
+    let done = deployment(1, 1);
+    app.data.deployments.set_items(vec![done.clone()]);
+
+    app.follow_rollout(&done, "app=web".into()).await;
+    app.end_completed_rollout_follow();
+    assert!(app.following_rollout());
+  }
+}
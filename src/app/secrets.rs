@@ -3,13 +3,11 @@ use std::collections::BTreeMap;
 use async_trait::async_trait;
 use chrono::Utc;
 use k8s_openapi::{api::core::v1::Secret, ByteString};
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
-};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
 
 use super::{
+  consumers::{draw_consumers_describe, ConsumedKind},
   models::{AppResource, KubeResource, Named},
   utils::{self},
   ActiveBlock, App,
@@ -19,8 +17,8 @@ use crate::{
   network::Network,
   ui::utils::{
     describe_yaml_decode_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, responsive_columns, style_text,
-    title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+    get_describe_active, get_resource_title, help_bold_line, style_text, title_with_dual_style,
+    ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -68,6 +66,14 @@ impl Named for KubeSecret {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<Secret> for KubeSecret {
@@ -83,6 +89,20 @@ pub struct SecretResource {}
 #[async_trait]
 impl AppResource for SecretResource {
   fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    if block == ActiveBlock::Describe {
+      let title = title_with_dual_style(
+        get_resource_title(
+          app,
+          SECRETS_TITLE,
+          get_describe_active(block),
+          app.data.secrets.items.len(),
+        ),
+        crate::ui::utils::copy_and_escape_title_line(SECRETS_TITLE, app.palette),
+        app.palette,
+      );
+      draw_consumers_describe(f, app, area, title, ConsumedKind::Secret);
+      return;
+    }
     draw_resource_tab!(
       SECRETS_TITLE,
       block,
@@ -103,7 +123,7 @@ impl AppResource for SecretResource {
   }
 }
 
-const SECRET_COLUMNS: [ColumnDef; 5] = [
+pub(crate) const SECRET_COLUMNS: [ColumnDef; 5] = [
   ColumnDef::all("Namespace", 25, 25, 25),
   ColumnDef::all("Name", 30, 30, 30),
   ColumnDef::all("Type", 25, 25, 25),
@@ -112,10 +132,10 @@ const SECRET_COLUMNS: [ColumnDef; 5] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::Secrets);
   let title = get_resource_title(app, SECRETS_TITLE, "", app.data.secrets.items.len());
 
-  let (headers, widths) = responsive_columns(&SECRET_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::Secrets, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -124,21 +144,22 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       title,
       inline_help: help_bold_line(describe_yaml_decode_and_esc_hint(), app.palette),
       resource: &mut app.data.secrets,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.type_.to_owned()),
-        Cell::from(c.data.len().to_string()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.type_.to_owned()),
+          Cell::from(c.data.len().to_string()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
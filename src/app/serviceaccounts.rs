@@ -1,11 +1,8 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use k8s_openapi::api::core::v1::ServiceAccount;
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
-};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
 
 use super::{
   models::{AppResource, KubeResource, Named},
@@ -17,8 +14,8 @@ use crate::{
   network::Network,
   ui::utils::{
     describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, responsive_columns, style_text,
-    title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+    get_describe_active, get_resource_title, help_bold_line, style_text, title_with_dual_style,
+    ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -48,6 +45,14 @@ impl Named for KubeSvcAcct {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<ServiceAccount> for KubeSvcAcct {
@@ -83,7 +88,7 @@ impl AppResource for SvcAcctResource {
   }
 }
 
-const SA_COLUMNS: [ColumnDef; 4] = [
+pub(crate) const SA_COLUMNS: [ColumnDef; 4] = [
   ColumnDef::all("Namespace", 30, 30, 30),
   ColumnDef::all("Name", 30, 30, 30),
   ColumnDef::all("Secrets", 20, 20, 20),
@@ -91,7 +96,7 @@ const SA_COLUMNS: [ColumnDef; 4] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::ServiceAccounts);
   let title = get_resource_title(
     app,
     SVC_ACCT_TITLE,
@@ -99,7 +104,7 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
     app.data.service_accounts.items.len(),
   );
 
-  let (headers, widths) = responsive_columns(&SA_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::ServiceAccounts, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -108,20 +113,21 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       title,
       inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
       resource: &mut app.data.service_accounts,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.namespace.to_owned()),
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.secrets.to_string()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      columns
+        .row(vec![
+          Cell::from(c.namespace.to_owned()),
+          Cell::from(c.name.to_owned()),
+          Cell::from(c.secrets.to_string()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
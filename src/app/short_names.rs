@@ -0,0 +1,154 @@
+//! Shortened names for the rows controllers name after themselves plus a
+//! generated hash, such as a Deployment's ReplicaSets and pods or a
+//! CronJob's Jobs. The hash is replaced with `…`, so `web-7d9f8b6c4-x2k9p`
+//! reads `web-…-x2k9p`. A name that would then read like another row's is
+//! left whole, so every shortened list stays unambiguous.
+use std::collections::HashMap;
+
+/// Characters Kubernetes draws generated names and template hashes from; no
+/// vowels, so they rarely spell a word.
+const SAFE_ALPHABET: &str = "bcdfghjklmnpqrstvwxz2456789";
+
+/// Length of the random suffix `generateName` appends, as for pods.
+const GENERATED_SUFFIX_LEN: usize = 5;
+
+/// A `pod-template-hash`, or the scheduled time a CronJob names its Jobs
+/// with.
+fn is_controller_hash(segment: &str) -> bool {
+  let safe = (6..=10).contains(&segment.len())
+    && segment.chars().all(|c| SAFE_ALPHABET.contains(c))
+    && segment.chars().any(|c| c.is_ascii_digit());
+  let scheduled = segment.len() >= 8 && segment.chars().all(|c| c.is_ascii_digit());
+  safe || scheduled
+}
+
+fn is_generated_suffix(segment: &str) -> bool {
+  segment.len() == GENERATED_SUFFIX_LEN && segment.chars().all(|c| SAFE_ALPHABET.contains(c))
+}
+
+/// `name` without its controller hash, or `None` when it has none.
+fn shorten(name: &str) -> Option<String> {
+  let segments: Vec<&str> = name.split('-').collect();
+  match segments.as_slice() {
+    [base @ .., hash, suffix]
+      if !base.is_empty() && is_controller_hash(hash) && is_generated_suffix(suffix) =>
+    {
+      Some(format!("{}-…-{}", base.join("-"), suffix))
+    }
+    [base @ .., hash] if !base.is_empty() && is_controller_hash(hash) => {
+      Some(format!("{}-…", base.join("-")))
+    }
+    _ => None,
+  }
+}
+
+/// The shortened form of each name in a list that has one and stays unique.
+#[derive(Debug, Default)]
+pub struct ShortNames(HashMap<String, String>);
+
+impl ShortNames {
+  pub fn new<'a>(names: impl IntoIterator<Item = &'a str>) -> Self {
+    let names: Vec<&str> = names.into_iter().collect();
+    let shortened: Vec<(&str, String)> = names
+      .iter()
+      .filter_map(|name| shorten(name).map(|short| (*name, short)))
+      .collect();
+    let mut uses: HashMap<&str, usize> = HashMap::new();
+    for (_, short) in &shortened {
+      *uses.entry(short.as_str()).or_default() += 1;
+    }
+    let unique = |short: &str| uses.get(short) == Some(&1) && !names.contains(&short);
+    Self(
+      shortened
+        .iter()
+        .filter(|(_, short)| unique(short))
+        .map(|(name, short)| (name.to_string(), short.clone()))
+        .collect(),
+    )
+  }
+
+  /// The name to show for `name`.
+  pub fn get<'a>(&'a self, name: &'a str) -> &'a str {
+    self.0.get(name).map_or(name, String::as_str)
+  }
+}
+
+impl super::App {
+  /// Show names with or without their controller hashes in pod, ReplicaSet
+  /// and Job tables.
+  pub fn toggle_short_names(&mut self) {
+    self.short_names = !self.short_names;
+    self.set_status_message(if self.short_names {
+      "Showing short names"
+    } else {
+      "Showing full names"
+    });
+  }
+
+  /// The names to show for a table of `names`: shortened while short names
+  /// are on, else as they are.
+  pub fn short_names<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> ShortNames {
+    if self.short_names {
+      ShortNames::new(names)
+    } else {
+      ShortNames::default()
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_shorten_drops_controller_hashes_only() {
+    assert_eq!(
+      shorten("web-api-7d9f8b6c4-x2k9p").as_deref(),
+      Some("web-api-…-x2k9p")
+    );
+    assert_eq!(shorten("web-api-7d9f8b6c4").as_deref(), Some("web-api-…"));
+    assert_eq!(shorten("backup-28391234").as_deref(), Some("backup-…"));
+    assert_eq!(
+      shorten("backup-28391234-qz7vd").as_deref(),
+      Some("backup-…-qz7vd")
+    );
+    // statefulset ordinals, a daemonset's pods and plain names keep their names
+    assert_eq!(shorten("db-0"), None);
+    assert_eq!(shorten("fluentd-x2k9p"), None);
+    assert_eq!(shorten("payments-service"), None);
+    assert_eq!(shorten("7d9f8b6c4"), None);
+  }
+
+  #[test]
+  fn test_short_names_stay_unambiguous() {
+    let names = ShortNames::new([
+      "web-7d9f8b6c4-x2k9p",
+      "web-7d9f8b6c4-p5q8n",
+      "web-7d9f8b6c4",
+      "web-5c6b4d7f9",
+      "api-68b7c5d99",
+      "api-…",
+    ]);
+    assert_eq!(names.get("web-7d9f8b6c4-x2k9p"), "web-…-x2k9p");
+    assert_eq!(names.get("web-7d9f8b6c4-p5q8n"), "web-…-p5q8n");
+    // two ReplicaSets of one Deployment would both read `web-…`
+    assert_eq!(names.get("web-7d9f8b6c4"), "web-7d9f8b6c4");
+    assert_eq!(names.get("web-5c6b4d7f9"), "web-5c6b4d7f9");
+    assert_eq!(names.get("api-68b7c5d99"), "api-68b7c5d99");
+    assert_eq!(names.get("unknown"), "unknown");
+  }
+
+  #[test]
+  fn test_short_names_follow_the_toggle() {
+    let mut app = super::super::App::default();
+    assert_eq!(
+      app.short_names(["web-7d9f8b6c4"]).get("web-7d9f8b6c4"),
+      "web-7d9f8b6c4"
+    );
+    app.toggle_short_names();
+    assert_eq!(
+      app.short_names(["web-7d9f8b6c4"]).get("web-7d9f8b6c4"),
+      "web-…"
+    );
+  }
+}
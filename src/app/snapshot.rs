@@ -0,0 +1,376 @@
+//! Save the whole active namespace at once for a bug report: every kind
+//! below is listed concurrently and written as one multi-document YAML file
+//! per kind into a timestamped directory in the working directory.
+//!
+//! Managed fields are dropped, and Secret values, as well as the values of
+//! keys `redact_patterns` matches, are redacted unless `snapshot_secrets` is
+//! set in the config file. The directory and its files are readable by the
+//! current user only.
+use std::{
+  io::{self, Write},
+  path::{Path, PathBuf},
+};
+
+use anyhow::anyhow;
+use chrono::{DateTime, FixedOffset};
+use futures::stream::{FuturesUnordered, StreamExt};
+use kube::{api::DynamicObject, Api};
+use log::warn;
+use serde_json::Value;
+
+use super::{
+  columns,
+  dynamic::api_resource_for_block,
+  redact::Redactor,
+  utils::{self, create_private_dir, create_private_file},
+  ActiveBlock,
+};
+use crate::network::Network;
+
+/// The kinds a snapshot holds, in the order they are reported.
+const SNAPSHOT_BLOCKS: [ActiveBlock; 21] = [
+  ActiveBlock::Pods,
+  ActiveBlock::Deployments,
+  ActiveBlock::StatefulSets,
+  ActiveBlock::DaemonSets,
+  ActiveBlock::ReplicaSets,
+  ActiveBlock::ReplicationControllers,
+  ActiveBlock::Jobs,
+  ActiveBlock::CronJobs,
+  ActiveBlock::Services,
+  ActiveBlock::Ingresses,
+  ActiveBlock::NetworkPolicies,
+  ActiveBlock::ConfigMaps,
+  ActiveBlock::Secrets,
+  ActiveBlock::PersistentVolumeClaims,
+  ActiveBlock::ServiceAccounts,
+  ActiveBlock::Roles,
+  ActiveBlock::RoleBindings,
+  ActiveBlock::ResourceQuotas,
+  ActiveBlock::LimitRanges,
+  ActiveBlock::PodDisruptionBudgets,
+  ActiveBlock::Events,
+];
+
+const REDACTED: &str = "<redacted>";
+
+const LAST_APPLIED: &str = "kubectl.kubernetes.io/last-applied-configuration";
+
+/// The objects of one kind, named like the view that lists them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SnapshotKind {
+  pub name: &'static str,
+  pub objects: Vec<Value>,
+}
+
+/// `obj` as written to a snapshot: with its type filled in, as list items
+/// come without one, and without managed fields.
+fn snapshot_object(mut obj: Value, api_version: &str, kind: &str) -> Value {
+  if let Some(map) = obj.as_object_mut() {
+    map
+      .entry("apiVersion")
+      .or_insert_with(|| Value::from(api_version));
+    map.entry("kind").or_insert_with(|| Value::from(kind));
+  }
+  if let Some(metadata) = obj.get_mut("metadata").and_then(Value::as_object_mut) {
+    metadata.remove("managedFields");
+  }
+  obj
+}
+
+/// Replace the values of a Secret, keeping its keys. The last-applied
+/// annotation is dropped too, as it holds the values as applied.
+fn redact_secret(secret: &mut Value) {
+  for field in ["data", "stringData"] {
+    if let Some(values) = secret.get_mut(field).and_then(Value::as_object_mut) {
+      for value in values.values_mut() {
+        *value = Value::from(REDACTED);
+      }
+    }
+  }
+  if let Some(annotations) = secret
+    .pointer_mut("/metadata/annotations")
+    .and_then(Value::as_object_mut)
+  {
+    annotations.remove(LAST_APPLIED);
+  }
+}
+
+/// Write each kind that has objects to `<kind>.yaml` in a new
+/// `kdash-snapshot-<namespace>-<timestamp>` directory in `dir`, and return
+/// the directory. Each document goes through `redactor` when there is one.
+pub fn write_snapshot(
+  dir: &Path,
+  namespace: &str,
+  now: DateTime<FixedOffset>,
+  kinds: &[SnapshotKind],
+  redactor: Option<&Redactor>,
+) -> io::Result<PathBuf> {
+  let path = dir.join(format!(
+    "kdash-snapshot-{}-{}",
+    namespace,
+    now.format("%Y%m%d-%H%M%S")
+  ));
+  create_private_dir(&path)?;
+  for kind in kinds.iter().filter(|kind| !kind.objects.is_empty()) {
+    let documents: Vec<String> = kind
+      .objects
+      .iter()
+      .map(|obj| {
+        let document = serde_saphyr::to_string(obj).map_err(io::Error::other)?;
+        Ok(match redactor {
+          Some(redactor) => {
+            let mut redacted = redactor.redact_lines(&document).join("\n");
+            redacted.push('\n');
+            redacted
+          }
+          None => document,
+        })
+      })
+      .collect::<io::Result<_>>()?;
+    create_private_file(&path.join(format!("{}.yaml", kind.name)))?
+      .write_all(documents.join("---\n").as_bytes())?;
+  }
+  Ok(path)
+}
+
+/// List every snapshot kind in `namespace` at once, reporting progress as
+/// each comes in, then write them to the working directory.
+pub async fn snapshot_namespace(nw: &Network<'_>, namespace: &str) {
+  let (keep_secrets, redactor) = {
+    let app = nw.app.lock().await;
+    (app.config.snapshot_secrets, app.redactor.clone())
+  };
+  let mut fetches: FuturesUnordered<_> = SNAPSHOT_BLOCKS
+    .iter()
+    .enumerate()
+    .filter_map(|(order, &block)| {
+      let name = columns::view_name(block)?;
+      let (api_resource, _) = api_resource_for_block(block, None)?;
+      let api: Api<DynamicObject> =
+        Api::namespaced_with(nw.client.clone(), namespace, &api_resource);
+      Some(async move {
+        let objects = api.list(&Default::default()).await.map(|list| {
+          list
+            .items
+            .into_iter()
+            .filter_map(|obj| serde_json::to_value(obj).ok())
+            .map(|obj| snapshot_object(obj, &api_resource.api_version, &api_resource.kind))
+            .collect::<Vec<_>>()
+        });
+        (order, block, name, objects)
+      })
+    })
+    .collect();
+
+  let total = fetches.len();
+  let mut results = vec![];
+  while let Some(result) = fetches.next().await {
+    results.push(result);
+    nw.app.lock().await.set_status_message(format!(
+      "Taking a snapshot of {}: {}/{} kinds",
+      namespace,
+      results.len(),
+      total
+    ));
+  }
+  results.sort_by_key(|(order, ..)| *order);
+
+  let mut kinds = vec![];
+  let mut failed = vec![];
+  for (_, block, name, objects) in results {
+    match objects {
+      Ok(mut objects) => {
+        if block == ActiveBlock::Secrets && !keep_secrets {
+          objects.iter_mut().for_each(redact_secret);
+        }
+        kinds.push(SnapshotKind { name, objects });
+      }
+      Err(e) => {
+        warn!("Snapshot of {} in {} failed: {}", name, namespace, e);
+        failed.push(name);
+      }
+    }
+  }
+
+  let dir = std::env::current_dir().unwrap_or_default();
+  let mut app = nw.app.lock().await;
+  let redactor = (!keep_secrets).then_some(&redactor);
+  match write_snapshot(&dir, namespace, utils::now(), &kinds, redactor) {
+    Ok(path) => {
+      let objects: usize = kinds.iter().map(|kind| kind.objects.len()).sum();
+      let mut message = format!(
+        "Saved {} objects from {} to {}",
+        objects,
+        namespace,
+        path.display()
+      );
+      if !failed.is_empty() {
+        message.push_str(&format!("; could not list {}", failed.join(", ")));
+      }
+      app.set_status_message(message);
+    }
+    Err(e) => app.handle_error(anyhow!(
+      "Failed to save the snapshot of {} to {}. {}",
+      namespace,
+      dir.display(),
+      e
+    )),
+  }
+}
+
+impl super::App {
+  /// Ask for a snapshot of the active namespace; all namespaces would be too
+  /// much for one bundle.
+  pub async fn request_snapshot(&mut self) {
+    match self.data.selected.ns.clone() {
+      Some(namespace) => {
+        self.set_status_message(format!("Taking a snapshot of {}", namespace));
+        self
+          .dispatch(crate::network::IoEvent::SnapshotNamespace { namespace })
+          .await;
+      }
+      None => self.set_status_message("Select a namespace to take a snapshot of"),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::TimeZone;
+  use serde_json::json;
+  use tokio::sync::mpsc;
+
+  use std::fs;
+
+  use super::*;
+  use crate::{
+    app::{redact::DEFAULT_REDACT_PATTERNS, App},
+    network::IoEvent,
+  };
+
+  #[test]
+  fn test_snapshot_objects_are_typed_and_redacted() {
+    let obj = snapshot_object(
+      json!({ "metadata": { "name": "web", "managedFields": [{ "manager": "kubectl" }] } }),
+      "v1",
+      "Pod",
+    );
+    assert_eq!(
+      obj,
+      json!({ "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "web" } })
+    );
+
+    let mut secret = json!({
+      "metadata": { "name": "db", "annotations": { LAST_APPLIED: "{}", "team": "a" } },
+      "data": { "password": "aHVudGVyMg==" },
+      "stringData": { "user": "admin" },
+      "type": "Opaque",
+    });
+    redact_secret(&mut secret);
+    assert_eq!(
+      secret,
+      json!({
+        "metadata": { "name": "db", "annotations": { "team": "a" } },
+        "data": { "password": REDACTED },
+        "stringData": { "user": REDACTED },
+        "type": "Opaque",
+      })
+    );
+  }
+
+  #[test]
+  fn test_write_snapshot_writes_a_file_per_kind() {
+    let dir = std::env::temp_dir().join(format!("kdash-snapshot-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let now = chrono::Local
+      .with_ymd_and_hms(2026, 3, 4, 5, 6, 7)
+      .unwrap()
+      .fixed_offset();
+    let kinds = [
+      SnapshotKind {
+        name: "pods",
+        objects: vec![
+          json!({ "kind": "Pod", "metadata": { "name": "a" } }),
+          json!({ "kind": "Pod", "metadata": { "name": "b" } }),
+        ],
+      },
+      SnapshotKind {
+        name: "secrets",
+        objects: vec![],
+      },
+    ];
+
+    let path = write_snapshot(&dir, "shop", now, &kinds, None).unwrap();
+    assert_eq!(path, dir.join("kdash-snapshot-shop-20260304-050607"));
+    let pods = fs::read_to_string(path.join("pods.yaml")).unwrap();
+    assert_eq!(pods.matches("---").count(), 1);
+    assert!(pods.contains("name: a") && pods.contains("name: b"));
+    // kinds without objects get no file
+    assert!(!path.join("secrets.yaml").exists());
+    // a second snapshot in the same second does not overwrite the first
+    assert!(write_snapshot(&dir, "shop", now, &kinds, None).is_err());
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn test_write_snapshot_is_private_and_redacted() {
+    let dir = std::env::temp_dir().join(format!(
+      "kdash-snapshot-private-test-{}",
+      std::process::id()
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    let now = chrono::Local
+      .with_ymd_and_hms(2026, 3, 4, 5, 6, 7)
+      .unwrap()
+      .fixed_offset();
+    let kinds = [SnapshotKind {
+      name: "configmaps",
+      objects: vec![json!({
+        "kind": "ConfigMap",
+        "metadata": { "name": "app" },
+        "data": { "db_password": "hunter2", "mode": "fast" },
+      })],
+    }];
+    let redactor = Redactor::new(DEFAULT_REDACT_PATTERNS);
+
+    let path = write_snapshot(&dir, "shop", now, &kinds, Some(&redactor)).unwrap();
+    let file = path.join("configmaps.yaml");
+    let config_maps = fs::read_to_string(&file).unwrap();
+    assert!(config_maps.contains("db_password: REDACTED"));
+    assert!(config_maps.contains("mode: fast") && config_maps.ends_with('\n'));
+    #[cfg(unix)]
+    {
+      use std::os::unix::fs::PermissionsExt;
+      let mode = |entry: &Path| fs::metadata(entry).unwrap().permissions().mode() & 0o777;
+      assert_eq!(mode(&path), 0o700);
+      assert_eq!(mode(&file), 0o600);
+    }
+    fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_request_snapshot_needs_a_namespace() {
+    let (io_tx, mut io_rx) = mpsc::channel::<IoEvent>(10);
+    let mut app = App {
+      io_tx: Some(io_tx),
+      ..App::default()
+    };
+    app.data.selected.ns = None;
+    app.request_snapshot().await;
+    assert!(io_rx.try_recv().is_err());
+    assert_eq!(
+      app.status_message.text(),
+      "Select a namespace to take a snapshot of"
+    );
+
+    app.data.selected.ns = Some("shop".into());
+    app.request_snapshot().await;
+    assert_eq!(
+      io_rx.try_recv().unwrap(),
+      IoEvent::SnapshotNamespace {
+        namespace: "shop".into()
+      }
+    );
+  }
+}
@@ -1,11 +1,8 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use k8s_openapi::api::apps::v1::StatefulSet;
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
-};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, widgets::Cell, Frame};
 
 use super::{
   models::{self, AppResource, KubeResource, Named},
@@ -18,8 +15,8 @@ use crate::{
   network::Network,
   ui::utils::{
     action_hint, describe_yaml_and_logs_hint, draw_describe_block, draw_resource_block,
-    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, responsive_columns,
-    style_text, title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
+    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, style_text,
+    title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -73,6 +70,14 @@ impl Named for KubeStatefulSet {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<StatefulSet> for KubeStatefulSet {
@@ -120,7 +125,7 @@ impl AppResource for StatefulSetResource {
   }
 }
 
-const STFS_COLUMNS: [ColumnDef; 7] = [
+pub(crate) const STFS_COLUMNS: [ColumnDef; 7] = [
   ColumnDef::all("Namespace", 25, 20, 18),
   ColumnDef::all("Name", 30, 25, 22),
   ColumnDef::all("Ready", 10, 10, 10),
@@ -131,11 +136,11 @@ const STFS_COLUMNS: [ColumnDef; 7] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::StatefulSets);
   let title = get_resource_title(app, STFS_TITLE, "", app.data.stateful_sets.items.len());
 
   let tier = ViewTier::from_width(area.width, app.wide_columns);
-  let (headers, widths) = responsive_columns(&STFS_COLUMNS, tier);
+  let columns = app.column_layout(ActiveBlock::StatefulSets, tier);
 
   draw_resource_block(
     f,
@@ -152,27 +157,23 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
         app.palette,
       ),
       resource: &mut app.data.stateful_sets,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      let mut cells = vec![
+      let cells = vec![
         Cell::from(c.namespace.to_owned()),
         Cell::from(c.name.to_owned()),
         Cell::from(c.ready.to_owned()),
         Cell::from(c.service.to_owned()),
+        Cell::from(c.update_strategy.to_owned()),
+        Cell::from(c.replicas.to_owned()),
+        Cell::from(c.age.to_owned()),
       ];
-      if tier >= ViewTier::Standard {
-        cells.push(Cell::from(c.update_strategy.to_owned()));
-      }
-      if tier >= ViewTier::Wide {
-        cells.push(Cell::from(c.replicas.to_owned()));
-      }
-      cells.push(Cell::from(c.age.to_owned()));
-      Row::new(cells).style(style_text(app.palette))
+      columns.row(cells).style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
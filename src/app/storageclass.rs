@@ -1,11 +1,8 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use k8s_openapi::api::storage::v1::StorageClass;
-use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
-  Frame,
-};
+use kube::api::ObjectMeta;
+use ratatui::{layout::Rect, style::Modifier, widgets::Cell, Frame};
 
 use super::{
   models::{AppResource, KubeResource, Named},
@@ -18,7 +15,7 @@ use crate::{
   ui::utils::{
     describe_yaml_and_esc_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
     get_cluster_wide_resource_title, get_describe_active, get_resource_title, help_bold_line,
-    responsive_columns, style_text, title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+    style_text, title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -29,13 +26,34 @@ pub struct KubeStorageClass {
   pub reclaim_policy: String,
   pub volume_binding_mode: String,
   pub allow_volume_expansion: bool,
+  /// Marked with the `is-default-class` annotation; used for PVCs that name no class.
+  pub is_default: bool,
   pub age: String,
   k8s_obj: StorageClass,
 }
 
+/// The GA annotation and the beta one older clusters still set.
+const DEFAULT_CLASS_ANNOTATIONS: [&str; 2] = [
+  "storageclass.kubernetes.io/is-default-class",
+  "storageclass.beta.kubernetes.io/is-default-class",
+];
+
+fn is_default_class(storage_class: &StorageClass) -> bool {
+  storage_class
+    .metadata
+    .annotations
+    .as_ref()
+    .is_some_and(|annotations| {
+      DEFAULT_CLASS_ANNOTATIONS
+        .iter()
+        .any(|key| annotations.get(*key).is_some_and(|v| v == "true"))
+    })
+}
+
 impl From<StorageClass> for KubeStorageClass {
   fn from(storage_class: StorageClass) -> Self {
     KubeStorageClass {
+      is_default: is_default_class(&storage_class),
       name: storage_class.metadata.name.clone().unwrap_or_default(),
       provisioner: storage_class.provisioner.clone(),
       reclaim_policy: storage_class.reclaim_policy.clone().unwrap_or_default(),
@@ -57,6 +75,10 @@ impl Named for KubeStorageClass {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<StorageClass> for KubeStorageClass {
@@ -92,24 +114,24 @@ impl AppResource for StorageClassResource {
   }
 }
 
-const SC_COLUMNS: [ColumnDef; 6] = [
-  ColumnDef::all("Name", 10, 10, 10),
-  ColumnDef::all("Provisioner", 20, 20, 20),
-  ColumnDef::all("Reclaim Policy", 10, 10, 10),
-  ColumnDef::all("Volume Binding Mode", 20, 20, 20),
-  ColumnDef::all("Allow Volume Expansion", 20, 20, 20),
+pub(crate) const SC_COLUMNS: [ColumnDef; 6] = [
+  ColumnDef::all("Name", 20, 20, 20),
+  ColumnDef::all("Provisioner", 25, 25, 25),
+  ColumnDef::all("Reclaim Policy", 12, 12, 12),
+  ColumnDef::all("Volume Binding Mode", 18, 18, 18),
+  ColumnDef::all("Allow Volume Expansion", 15, 15, 15),
   ColumnDef::all("Age", 10, 10, 10),
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::StorageClasses);
   let title = get_cluster_wide_resource_title(
     STORAGE_CLASSES_LABEL,
     app.data.storage_classes.items.len(),
     "",
   );
 
-  let (headers, widths) = responsive_columns(&SC_COLUMNS, ViewTier::Compact);
+  let columns = app.column_layout(ActiveBlock::StorageClasses, ViewTier::Compact);
 
   draw_resource_block(
     f,
@@ -118,22 +140,29 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       title,
       inline_help: help_bold_line(describe_yaml_and_esc_hint(), app.palette),
       resource: &mut app.data.storage_classes,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
-      Row::new(vec![
-        Cell::from(c.name.to_owned()),
-        Cell::from(c.provisioner.to_owned()),
-        Cell::from(c.reclaim_policy.to_owned()),
-        Cell::from(c.volume_binding_mode.to_owned()),
-        Cell::from(c.allow_volume_expansion.to_string()),
-        Cell::from(c.age.to_owned()),
-      ])
-      .style(style_text(app.palette))
+      let name = if c.is_default {
+        Cell::from(format!("{} (default)", c.name))
+          .style(style_text(app.palette).add_modifier(Modifier::BOLD))
+      } else {
+        Cell::from(c.name.to_owned())
+      };
+      columns
+        .row(vec![
+          name,
+          Cell::from(c.provisioner.to_owned()),
+          Cell::from(c.reclaim_policy.to_owned()),
+          Cell::from(c.volume_binding_mode.to_owned()),
+          Cell::from(c.allow_volume_expansion.to_string()),
+          Cell::from(c.age.to_owned()),
+        ])
+        .style(style_text(app.palette))
     },
     app.palette,
-    is_loading,
+    load,
   );
 }
 
@@ -160,9 +189,16 @@ mod tests {
         reclaim_policy: "Delete".into(),
         volume_binding_mode: "Immediate".into(),
         allow_volume_expansion: false,
+        is_default: false,
         age: utils::to_age(Some(&get_time("2021-12-14T11:08:59Z")), Utc::now()),
         k8s_obj: storage_classes_list[0].clone(),
       }
     );
+    let defaults: Vec<_> = storage_classes
+      .iter()
+      .filter(|sc| sc.is_default)
+      .map(|sc| sc.name.as_str())
+      .collect();
+    assert_eq!(defaults, vec!["gp2"]);
   }
 }
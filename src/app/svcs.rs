@@ -1,24 +1,39 @@
+use anyhow::anyhow;
 use async_trait::async_trait;
 use chrono::Utc;
-use k8s_openapi::api::core::v1::{Service, ServicePort};
+use k8s_openapi::{
+  api::{
+    core::v1::{Service, ServicePort},
+    discovery::v1::EndpointSlice,
+  },
+  apimachinery::pkg::util::intstr::IntOrString,
+};
+use kube::{
+  api::{ListParams, ObjectMeta},
+  Api,
+};
 use ratatui::{
-  layout::Rect,
-  widgets::{Cell, Row},
+  layout::{Constraint, Rect},
+  text::{Line, Span},
+  widgets::{Cell, Paragraph},
   Frame,
 };
 
 use super::{
-  models::{AppResource, KubeResource, Named},
+  models::{self, AppResource, KubeResource, Named},
   utils::{self, UNKNOWN},
   ActiveBlock, App,
 };
 use crate::{
   draw_resource_tab,
   network::Network,
+  ui::glyphs::glyphs,
+  ui::theme::Palette,
   ui::utils::{
-    describe_and_yaml_hint, draw_describe_block, draw_resource_block, draw_yaml_block,
-    get_describe_active, get_resource_title, help_bold_line, responsive_columns, style_caution,
-    style_text, title_with_dual_style, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
+    copy_and_escape_title_line, describe_and_yaml_hint, draw_describe_block, draw_resource_block,
+    draw_yaml_block, get_describe_active, get_resource_title, help_bold_line, layout_block_default,
+    style_caution, style_failure, style_help, style_label, style_success, style_text,
+    title_with_dual_style, vertical_chunks, wide_hint, ColumnDef, ResourceTableProps, ViewTier,
   },
 };
 
@@ -94,10 +109,155 @@ impl From<Service> for KubeSvc {
   }
 }
 
+impl KubeSvc {
+  /// The fields networking questions turn on, for the describe view.
+  pub fn detail(&self) -> ServiceDetail {
+    ServiceDetail::from_api(&self.k8s_obj)
+  }
+}
+
+/// One port of a Service, as the detail lists it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServicePortDetail {
+  pub name: String,
+  pub port: i32,
+  /// The container port traffic goes to, by number or name; the port itself
+  /// when unset.
+  pub target_port: String,
+  pub node_port: Option<i32>,
+  pub protocol: String,
+}
+
+/// Type, addresses, ports, selector and session affinity of a Service.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServiceDetail {
+  pub type_: String,
+  pub cluster_ips: Vec<String>,
+  pub external_ips: Vec<String>,
+  /// Addresses or hostnames the load balancer has been given.
+  pub lb_ingress: Vec<String>,
+  pub external_name: Option<String>,
+  pub ports: Vec<ServicePortDetail>,
+  /// `app=web`, one per label, sorted.
+  pub selector: Vec<String>,
+  /// `ClientIP (timeout 10800s)` or `None`.
+  pub session_affinity: String,
+}
+
+impl ServiceDetail {
+  pub fn from_api(service: &Service) -> Self {
+    let Some(spec) = &service.spec else {
+      return ServiceDetail {
+        type_: UNKNOWN.into(),
+        ..Default::default()
+      };
+    };
+    let cluster_ips = match &spec.cluster_ips {
+      Some(ips) if !ips.is_empty() => ips.clone(),
+      _ => spec.cluster_ip.iter().cloned().collect(),
+    };
+    let lb_ingress = service
+      .status
+      .as_ref()
+      .and_then(|status| status.load_balancer.as_ref())
+      .and_then(|lb| lb.ingress.as_ref())
+      .map(|ingresses| {
+        ingresses
+          .iter()
+          .filter_map(|ingress| ingress.ip.clone().or_else(|| ingress.hostname.clone()))
+          .collect()
+      })
+      .unwrap_or_default();
+    let ports = spec
+      .ports
+      .iter()
+      .flatten()
+      .map(|port| ServicePortDetail {
+        name: port.name.clone().unwrap_or_default(),
+        port: port.port,
+        target_port: port.target_port.as_ref().map_or(
+          port.port.to_string(),
+          |target| match target {
+            IntOrString::Int(number) => number.to_string(),
+            IntOrString::String(name) => name.clone(),
+          },
+        ),
+        node_port: port.node_port,
+        protocol: port.protocol.clone().unwrap_or_else(|| "TCP".into()),
+      })
+      .collect();
+    let selector = spec
+      .selector
+      .iter()
+      .flatten()
+      .map(|(k, v)| format!("{}={}", k, v))
+      .collect();
+    let affinity = spec
+      .session_affinity
+      .clone()
+      .unwrap_or_else(|| "None".into());
+    let timeout = spec
+      .session_affinity_config
+      .as_ref()
+      .and_then(|config| config.client_ip.as_ref())
+      .and_then(|client_ip| client_ip.timeout_seconds);
+    let session_affinity = match timeout {
+      Some(timeout) if affinity == "ClientIP" => format!("{} (timeout {}s)", affinity, timeout),
+      _ => affinity,
+    };
+
+    ServiceDetail {
+      type_: spec.type_.clone().unwrap_or_else(|| "ClusterIP".into()),
+      cluster_ips,
+      external_ips: spec.external_ips.clone().unwrap_or_default(),
+      lb_ingress,
+      external_name: spec.external_name.clone(),
+      ports,
+      selector,
+      session_affinity,
+    }
+  }
+
+  /// A LoadBalancer the cloud provider has not given an address yet.
+  pub fn is_lb_pending(&self) -> bool {
+    self.type_ == "LoadBalancer" && self.lb_ingress.is_empty()
+  }
+}
+
+impl ServicePortDetail {
+  /// `http  80 → 8080  node 30723  TCP`
+  pub fn line(&self) -> String {
+    let mut line = format!(
+      "{:<10} {} {} {}",
+      if self.name.is_empty() {
+        "-"
+      } else {
+        &self.name
+      },
+      self.port,
+      glyphs().arrow,
+      self.target_port
+    );
+    if let Some(node_port) = self.node_port {
+      line.push_str(&format!("  node {}", node_port));
+    }
+    line.push_str(&format!("  {}", self.protocol));
+    line
+  }
+}
+
 impl Named for KubeSvc {
   fn get_name(&self) -> &String {
     &self.name
   }
+
+  fn get_namespace(&self) -> Option<&str> {
+    Some(&self.namespace)
+  }
+
+  fn metadata(&self) -> Option<&ObjectMeta> {
+    Some(&self.k8s_obj.metadata)
+  }
 }
 
 impl KubeResource<Service> for KubeSvc {
@@ -106,6 +266,102 @@ impl KubeResource<Service> for KubeSvc {
   }
 }
 
+impl models::HasPodSelector for KubeSvc {
+  fn pod_label_selector(&self) -> Option<String> {
+    (!self.selector.is_empty()).then(|| self.selector.clone())
+  }
+}
+
+/// One endpoint address behind a Service.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceEndpoint {
+  pub address: String,
+  /// `pod/web-1`, or `-` when the endpoint has no target.
+  pub target: String,
+  pub node: String,
+  pub ready: bool,
+}
+
+/// The endpoints a Service routes to, gathered from its EndpointSlices.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServiceEndpoints {
+  pub endpoints: Vec<ServiceEndpoint>,
+  /// `http:8080/TCP`, once per port across the slices.
+  pub ports: Vec<String>,
+}
+
+impl ServiceEndpoints {
+  pub fn from_slices(slices: &[EndpointSlice]) -> Self {
+    let mut endpoints = vec![];
+    let mut ports: Vec<String> = vec![];
+    for slice in slices {
+      for port in slice.ports.iter().flatten() {
+        let port = format!(
+          "{}{}/{}",
+          port
+            .name
+            .as_deref()
+            .filter(|name| !name.is_empty())
+            .map(|name| format!("{}:", name))
+            .unwrap_or_default(),
+          port.port.map_or("-".into(), |p| p.to_string()),
+          port.protocol.as_deref().unwrap_or("TCP")
+        );
+        if !ports.contains(&port) {
+          ports.push(port);
+        }
+      }
+      for endpoint in &slice.endpoints {
+        endpoints.push(ServiceEndpoint {
+          address: endpoint.addresses.join(","),
+          target: endpoint.target_ref.as_ref().map_or("-".into(), |target| {
+            format!(
+              "{}/{}",
+              target.kind.as_deref().unwrap_or_default().to_lowercase(),
+              target.name.as_deref().unwrap_or_default()
+            )
+          }),
+          node: endpoint.node_name.clone().unwrap_or_default(),
+          // An unset condition means ready.
+          ready: endpoint
+            .conditions
+            .as_ref()
+            .and_then(|c| c.ready)
+            .unwrap_or(true),
+        });
+      }
+    }
+    // Ready endpoints first, then by address.
+    endpoints.sort_by(|a, b| b.ready.cmp(&a.ready).then(a.address.cmp(&b.address)));
+    ServiceEndpoints { endpoints, ports }
+  }
+
+  pub fn ready_count(&self) -> usize {
+    self.endpoints.iter().filter(|e| e.ready).count()
+  }
+}
+
+/// Fetch the EndpointSlices of a Service for its describe view.
+pub async fn get_service_endpoints(nw: &Network<'_>, name: &str, namespace: &str) {
+  let api: Api<EndpointSlice> = Api::namespaced(nw.client.clone(), namespace);
+  let lp = ListParams::default().labels(&format!("kubernetes.io/service-name={}", name));
+  let slices = match api.list(&lp).await {
+    Ok(list) => list.items,
+    Err(e) => {
+      nw.handle_error(anyhow!(
+        "Failed to get endpoints for service {}. {}",
+        name,
+        e
+      ))
+      .await;
+      return;
+    }
+  };
+
+  let mut app = nw.app.lock().await;
+  app.data.service_endpoints = Some(ServiceEndpoints::from_slices(&slices));
+}
+
 static SERVICES_TITLE: &str = "Services";
 
 pub struct SvcResource {}
@@ -113,6 +369,20 @@ pub struct SvcResource {}
 #[async_trait]
 impl AppResource for SvcResource {
   fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    if block == ActiveBlock::Describe {
+      let title = title_with_dual_style(
+        get_resource_title(
+          app,
+          SERVICES_TITLE,
+          get_describe_active(block),
+          app.data.services.items.len(),
+        ),
+        copy_and_escape_title_line(SERVICES_TITLE, app.palette),
+        app.palette,
+      );
+      draw_service_describe(f, app, area, title);
+      return;
+    }
     draw_resource_tab!(
       SERVICES_TITLE,
       block,
@@ -132,7 +402,7 @@ impl AppResource for SvcResource {
   }
 }
 
-const SVC_COLUMNS: [ColumnDef; 9] = [
+pub(crate) const SVC_COLUMNS: [ColumnDef; 9] = [
   ColumnDef::all("Namespace", 10, 10, 8),
   ColumnDef::all("Name", 25, 20, 18),
   ColumnDef::all("Type", 10, 8, 8),
@@ -145,11 +415,11 @@ const SVC_COLUMNS: [ColumnDef; 9] = [
 ];
 
 fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::Services);
   let title = get_resource_title(app, SERVICES_TITLE, "", app.data.services.items.len());
 
   let tier = ViewTier::from_width(area.width, app.wide_columns);
-  let (headers, widths) = responsive_columns(&SVC_COLUMNS, tier);
+  let columns = app.column_layout(ActiveBlock::Services, tier);
 
   draw_resource_block(
     f,
@@ -161,8 +431,8 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
         app.palette,
       ),
       resource: &mut app.data.services,
-      table_headers: headers,
-      column_widths: widths,
+      table_headers: columns.headers.clone(),
+      column_widths: columns.widths.clone(),
     },
     |c| {
       let style = if c.external_ip == "<pending>" {
@@ -170,26 +440,202 @@ fn draw_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       } else {
         style_text(app.palette)
       };
-      let mut cells = vec![
+      let cells = vec![
         Cell::from(c.namespace.to_owned()),
         Cell::from(c.name.to_owned()),
         Cell::from(c.type_.to_owned()),
         Cell::from(c.cluster_ip.to_owned()),
         Cell::from(c.external_ip.to_owned()),
         Cell::from(c.ports.to_owned()),
+        Cell::from(c.selector.to_owned()),
+        Cell::from(c.session_affinity.to_owned()),
+        Cell::from(c.age.to_owned()),
       ];
-      if tier >= ViewTier::Standard {
-        cells.push(Cell::from(c.selector.to_owned()));
-      }
-      if tier >= ViewTier::Wide {
-        cells.push(Cell::from(c.session_affinity.to_owned()));
-      }
-      cells.push(Cell::from(c.age.to_owned()));
-      Row::new(cells).style(style)
+      columns.row(cells).style(style)
     },
     app.palette,
-    is_loading,
+    load,
+  );
+}
+
+/// Most endpoint rows shown above the describe output.
+const MAX_ENDPOINT_ROWS: usize = 8;
+/// Most port rows shown in the service detail.
+const MAX_PORT_ROWS: usize = 6;
+
+/// The detail lines of a Service: type and affinity, its addresses, ports
+/// and selector. A load balancer still waiting for an address says so.
+fn service_detail_lines(detail: &ServiceDetail, palette: Palette) -> Vec<Line<'static>> {
+  let label = |text: &str| Span::styled(format!("{:<14} ", text), style_label(palette));
+  let list = |values: &[String]| {
+    if values.is_empty() {
+      "-".to_owned()
+    } else {
+      values.join(", ")
+    }
+  };
+  let mut lines = vec![
+    Line::from(vec![
+      label("Type:"),
+      Span::styled(detail.type_.clone(), style_text(palette)),
+      Span::styled(
+        format!("  session affinity {}", detail.session_affinity),
+        style_help(palette),
+      ),
+    ]),
+    Line::from(vec![
+      label("Cluster IPs:"),
+      Span::styled(list(&detail.cluster_ips), style_text(palette)),
+    ]),
+  ];
+  if !detail.external_ips.is_empty() {
+    lines.push(Line::from(vec![
+      label("External IPs:"),
+      Span::styled(list(&detail.external_ips), style_text(palette)),
+    ]));
+  }
+  if let Some(external_name) = &detail.external_name {
+    lines.push(Line::from(vec![
+      label("External name:"),
+      Span::styled(external_name.clone(), style_text(palette)),
+    ]));
+  }
+  if detail.is_lb_pending() {
+    lines.push(Line::from(vec![
+      label("Load balancer:"),
+      Span::styled(
+        "<pending> · no external address assigned yet",
+        style_caution(palette),
+      ),
+    ]));
+  } else if !detail.lb_ingress.is_empty() {
+    lines.push(Line::from(vec![
+      label("Load balancer:"),
+      Span::styled(list(&detail.lb_ingress), style_text(palette)),
+    ]));
+  }
+  if detail.ports.is_empty() {
+    lines.push(Line::from(vec![
+      label("Ports:"),
+      Span::styled("none", style_help(palette)),
+    ]));
+  }
+  for (i, port) in detail.ports.iter().take(MAX_PORT_ROWS).enumerate() {
+    lines.push(Line::from(vec![
+      label(if i == 0 { "Ports:" } else { "" }),
+      Span::styled(port.line(), style_text(palette)),
+    ]));
+  }
+  if detail.ports.len() > MAX_PORT_ROWS {
+    lines.push(Line::from(vec![
+      label(""),
+      Span::styled(
+        format!("… {} more", detail.ports.len() - MAX_PORT_ROWS),
+        style_help(palette),
+      ),
+    ]));
+  }
+  lines.push(Line::from(vec![
+    label("Selector:"),
+    if detail.selector.is_empty() {
+      Span::styled("none, endpoints are managed by hand", style_help(palette))
+    } else {
+      Span::styled(list(&detail.selector), style_text(palette))
+    },
+  ]));
+  lines
+}
+
+/// Describe output under the Service's detail and, once they have been
+/// fetched, its endpoints, so a Service that routes to nothing is plain on
+/// one screen.
+fn draw_service_describe(f: &mut Frame<'_>, app: &mut App, area: Rect, title: Line<'_>) {
+  let palette = app.palette;
+  let detail = app
+    .data
+    .services
+    .get_selected_item_copy()
+    .map(|svc| service_detail_lines(&svc.detail(), palette))
+    .unwrap_or_default();
+  let outer = vertical_chunks(
+    vec![
+      Constraint::Length(if detail.is_empty() {
+        0
+      } else {
+        detail.len() as u16 + 2
+      }),
+      Constraint::Min(0),
+    ],
+    area,
+  );
+  if !detail.is_empty() {
+    f.render_widget(
+      Paragraph::new(detail).block(layout_block_default(" Service ", palette)),
+      outer[0],
+    );
+  }
+  let Some(endpoints) = app.data.service_endpoints.clone() else {
+    draw_describe_block(f, app, outer[1], title);
+    return;
+  };
+  let ready = endpoints.ready_count();
+  let not_ready = endpoints.endpoints.len() - ready;
+  let ports = if endpoints.ports.is_empty() {
+    String::new()
+  } else {
+    format!(" · ports {}", endpoints.ports.join(" "))
+  };
+  let summary = if ready == 0 {
+    Line::styled(
+      format!(
+        "{} No ready endpoints: the service routes to nothing ({} not ready){}",
+        glyphs().warning,
+        not_ready,
+        ports
+      ),
+      style_failure(palette),
+    )
+  } else {
+    Line::styled(
+      format!("{} ready, {} not ready{}", ready, not_ready, ports),
+      style_success(palette),
+    )
+  };
+
+  let mut lines = vec![summary];
+  for endpoint in endpoints.endpoints.iter().take(MAX_ENDPOINT_ROWS) {
+    let (mark, style) = if endpoint.ready {
+      (glyphs().ok, style_text(palette))
+    } else {
+      (glyphs().fail, style_caution(palette))
+    };
+    lines.push(Line::from(vec![
+      Span::styled(
+        format!("{} {:<16} {}  ", mark, endpoint.address, endpoint.target),
+        style,
+      ),
+      Span::styled(endpoint.node.clone(), style_help(palette)),
+    ]));
+  }
+  if endpoints.endpoints.len() > MAX_ENDPOINT_ROWS {
+    lines.push(Line::styled(
+      format!("… {} more", endpoints.endpoints.len() - MAX_ENDPOINT_ROWS),
+      style_help(palette),
+    ));
+  }
+
+  let chunks = vertical_chunks(
+    vec![
+      Constraint::Length(lines.len() as u16 + 2),
+      Constraint::Min(0),
+    ],
+    outer[1],
   );
+  f.render_widget(
+    Paragraph::new(lines).block(layout_block_default(" Endpoints ", palette)),
+    chunks[0],
+  );
+  draw_describe_block(f, app, chunks[1], title);
 }
 
 fn get_ports(s_ports: &Option<Vec<ServicePort>>) -> Option<String> {
@@ -201,7 +647,13 @@ fn get_ports(s_ports: &Option<Vec<ServicePort>>) -> Option<String> {
         if let Some(name) = s_port.name.clone() {
           port = format!("{}:", name);
         }
-        port = format!("{}{}►{}", port, s_port.port, s_port.node_port.unwrap_or(0));
+        port = format!(
+          "{}{}{}{}",
+          port,
+          s_port.port,
+          glyphs().port_arrow,
+          s_port.node_port.unwrap_or(0)
+        );
         if let Some(protocol) = s_port.protocol.clone() {
           if protocol != "TCP" {
             port = format!("{}/{}", port, s_port.protocol.clone().unwrap());
@@ -247,8 +699,10 @@ fn get_lb_ext_ips(service: &Service, external_ips: Option<Vec<String>>) -> Optio
 mod tests {
   use super::*;
   use crate::app::test_utils::*;
+  use crate::ui::theme::{palette_for, ThemeName};
   use k8s_openapi::api::core::v1::{
-    LoadBalancerIngress, LoadBalancerStatus, ServiceSpec, ServiceStatus,
+    ClientIPConfig, LoadBalancerIngress, LoadBalancerStatus, ServiceSpec, ServiceStatus,
+    SessionAffinityConfig,
   };
   use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 
@@ -363,6 +817,103 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_service_detail() {
+    let (svcs, _): (Vec<KubeSvc>, Vec<_>) = convert_resource_from_file("svcs");
+    let traefik = svcs[4].detail();
+    assert_eq!(traefik.type_, "LoadBalancer");
+    assert_eq!(traefik.cluster_ips, vec!["10.43.235.227"]);
+    assert_eq!(traefik.lb_ingress, vec!["172.20.0.2"]);
+    assert!(!traefik.is_lb_pending());
+    assert_eq!(traefik.selector, vec!["app=traefik", "release=traefik"]);
+    assert_eq!(traefik.session_affinity, "None");
+    assert_eq!(
+      traefik.ports[0].line(),
+      "http       80 → http  node 30723  TCP"
+    );
+
+    let mut pending = make_lb_service(None);
+    let spec = pending.spec.as_mut().unwrap();
+    spec.session_affinity = Some("ClientIP".into());
+    spec.session_affinity_config = Some(SessionAffinityConfig {
+      client_ip: Some(ClientIPConfig {
+        timeout_seconds: Some(10800),
+      }),
+    });
+    spec.ports = Some(vec![ServicePort {
+      port: 443,
+      target_port: Some(IntOrString::Int(8443)),
+      protocol: Some("TCP".into()),
+      ..Default::default()
+    }]);
+    let detail = ServiceDetail::from_api(&pending);
+    assert!(detail.is_lb_pending());
+    assert_eq!(detail.session_affinity, "ClientIP (timeout 10800s)");
+    assert_eq!(detail.ports[0].line(), "-          443 → 8443  TCP");
+
+    let lines: Vec<String> = service_detail_lines(&detail, palette_for(ThemeName::Macchiato))
+      .iter()
+      .map(|line| line.to_string())
+      .collect();
+    assert!(lines
+      .iter()
+      .any(|line| line.contains("<pending> · no external address assigned yet")));
+    assert!(lines
+      .iter()
+      .any(|line| line.contains("none, endpoints are managed by hand")));
+  }
+
+  #[test]
+  fn test_service_endpoints_from_slices() {
+    let slice: EndpointSlice = serde_json::from_value(serde_json::json!({
+      "addressType": "IPv4",
+      "metadata": { "name": "web-abcde", "namespace": "default" },
+      "ports": [{ "name": "http", "port": 8080, "protocol": "TCP" }],
+      "endpoints": [
+        {
+          "addresses": ["10.42.0.9"],
+          "conditions": { "ready": false },
+          "nodeName": "node-b",
+          "targetRef": { "kind": "Pod", "name": "web-2", "namespace": "default" }
+        },
+        {
+          "addresses": ["10.42.0.5"],
+          "nodeName": "node-a",
+          "targetRef": { "kind": "Pod", "name": "web-1", "namespace": "default" }
+        }
+      ]
+    }))
+    .unwrap();
+    // A second slice repeating the port does not list it twice.
+    let mut other = slice.clone();
+    other.endpoints = vec![];
+
+    let endpoints = ServiceEndpoints::from_slices(&[slice, other]);
+    assert_eq!(endpoints.ports, vec!["http:8080/TCP"]);
+    assert_eq!(endpoints.ready_count(), 1);
+    assert_eq!(
+      endpoints.endpoints,
+      vec![
+        ServiceEndpoint {
+          address: "10.42.0.5".into(),
+          target: "pod/web-1".into(),
+          node: "node-a".into(),
+          ready: true,
+        },
+        ServiceEndpoint {
+          address: "10.42.0.9".into(),
+          target: "pod/web-2".into(),
+          node: "node-b".into(),
+          ready: false,
+        },
+      ]
+    );
+
+    let empty = ServiceEndpoints::from_slices(&[]);
+    assert_eq!(empty.ready_count(), 0);
+    assert!(empty.ports.is_empty());
+  }
+
   #[test]
   fn test_get_lb_ext_ips_both_lb_and_external() {
     let svc = make_lb_service(Some(vec!["10.0.0.1", "10.0.0.2"]));
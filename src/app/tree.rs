@@ -2,6 +2,8 @@
 //! kubectl-view-allocations (CC0-1.0) after its 3.0 release made the
 //! `tree` module private.
 
+use crate::ui::glyphs::glyphs;
+
 #[derive(Debug, Clone)]
 struct TreeNode {
   parent: Option<usize>,
@@ -11,9 +13,7 @@ struct TreeNode {
 
 fn level_to_string(level: &[bool]) -> String {
   const EMPTY: &str = "   ";
-  const EDGE: &str = " └─";
-  const PIPE: &str = " │ ";
-  const BRANCH: &str = " ├─";
+  let glyphs = glyphs();
 
   let mut prefix = String::new();
   if !level.is_empty() {
@@ -22,9 +22,9 @@ fn level_to_string(level: &[bool]) -> String {
       let is_last_col = col == last_col;
       let s = match (*is_last_child, is_last_col) {
         (true, false) => EMPTY,
-        (true, true) => EDGE,
-        (false, false) => PIPE,
-        (false, true) => BRANCH,
+        (true, true) => glyphs.tree_edge,
+        (false, false) => glyphs.tree_pipe,
+        (false, true) => glyphs.tree_branch,
       };
       prefix.push_str(s);
     }
@@ -9,7 +9,7 @@ use ratatui::{
 use super::types::Severity;
 use crate::app::key_binding::DEFAULT_KEYBINDING;
 use crate::app::models::FilterableTable;
-use crate::app::App;
+use crate::app::{ActiveBlock, App};
 use crate::ui::utils::{
   action_hint, describe_and_yaml_hint, draw_route_resource_block, filter_cursor_position,
   filter_status_parts, help_part, mixed_bold_line, responsive_columns, style_caution,
@@ -27,7 +27,7 @@ const FINDING_COLUMNS: [ColumnDef; 6] = [
 
 pub fn render_troubleshoot(f: &mut Frame<'_>, app: &mut App, area: Rect) {
   let palette = app.palette;
-  let is_loading = app.is_loading();
+  let load = app.view_load(ActiveBlock::Troubleshoot);
   let title = format!(
     " Troubleshoot (ns: {}) [{}] ",
     app
@@ -85,7 +85,7 @@ pub fn render_troubleshoot(f: &mut Frame<'_>, app: &mut App, area: Rect) {
       .style(style)
     },
     palette,
-    is_loading,
+    load,
   );
 
   if filter_active {
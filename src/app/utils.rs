@@ -1,6 +1,21 @@
-use chrono::{DateTime, Duration, Utc};
+use std::{
+  fs::{self, File, OpenOptions},
+  io,
+  path::Path,
+  str::FromStr,
+  sync::OnceLock,
+};
+
+use chrono::{
+  format::{Item, StrftimeItems},
+  DateTime, Duration, FixedOffset, Local, SecondsFormat, Utc,
+};
+use chrono_tz::Tz;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
 use kube::{Resource, ResourceExt};
+use log::warn;
+
+use crate::config::KdashConfig;
 
 pub fn sanitize_obj<K: Resource>(mut obj: K) -> K {
   obj.managed_fields_mut().clear();
@@ -10,11 +25,159 @@ pub fn sanitize_obj<K: Resource>(mut obj: K) -> K {
 pub static UNKNOWN: &str = "Unknown";
 
 /// Convert a k8s-openapi `Time` (jiff::Timestamp) to a chrono `DateTime<Utc>`.
-fn time_to_chrono(time: &Time) -> DateTime<Utc> {
+pub fn time_to_chrono(time: &Time) -> DateTime<Utc> {
   DateTime::from_timestamp(time.0.as_second(), time.0.subsec_nanosecond() as u32)
     .unwrap_or_default()
 }
 
+/// strftime-style format for absolute times, from `--time-format` or
+/// `time_format` in the config file. Unset keeps the built-in formats.
+static TIME_FORMAT: OnceLock<String> = OnceLock::new();
+
+/// Format of local times, such as those of the error and audit logs, when no
+/// time format is configured.
+const DEFAULT_LOCAL_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Take the time format from the CLI, else the config file. An invalid one
+/// is reported and the defaults are kept.
+pub fn initialize_time_format(cli: Option<String>, config: &KdashConfig) -> Vec<String> {
+  let Some(format) = cli.or_else(|| config.time_format.clone()) else {
+    return vec![];
+  };
+  if is_valid_time_format(&format) {
+    let _ = TIME_FORMAT.set(format);
+    return vec![];
+  }
+  let warning = format!(
+    "Invalid time format '{}', using the default. See strftime for the specifiers",
+    format
+  );
+  warn!("{}", warning);
+  vec![warning]
+}
+
+/// Whether chrono understands every specifier, as formatting with an unknown
+/// one fails at render time.
+fn is_valid_time_format(format: &str) -> bool {
+  !format.trim().is_empty() && !StrftimeItems::new(format).any(|item| item == Item::Error)
+}
+
+/// The time zone times are shown in, from `--timezone`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DisplayZone {
+  #[default]
+  Local,
+  Utc,
+  Named(Tz),
+}
+
+impl FromStr for DisplayZone {
+  type Err = String;
+
+  fn from_str(zone: &str) -> Result<Self, Self::Err> {
+    let zone = zone.trim();
+    if zone.eq_ignore_ascii_case("local") {
+      Ok(Self::Local)
+    } else if zone.eq_ignore_ascii_case("utc") {
+      Ok(Self::Utc)
+    } else {
+      zone.parse().map(Self::Named).map_err(|_| {
+        "unknown time zone. Use UTC, local or an IANA name such as Europe/Berlin".to_owned()
+      })
+    }
+  }
+}
+
+impl DisplayZone {
+  /// `time` as a wall-clock time in this zone.
+  pub fn convert(self, time: DateTime<Utc>) -> DateTime<FixedOffset> {
+    match self {
+      Self::Local => time.with_timezone(&Local).fixed_offset(),
+      Self::Utc => time.fixed_offset(),
+      Self::Named(tz) => time.with_timezone(&tz).fixed_offset(),
+    }
+  }
+}
+
+/// The zone from `--timezone`. Unset keeps local time, and RFC 3339 UTC for
+/// absolute times without a configured format.
+static TIME_ZONE: OnceLock<DisplayZone> = OnceLock::new();
+
+pub fn initialize_time_zone(zone: Option<DisplayZone>) {
+  if let Some(zone) = zone {
+    let _ = TIME_ZONE.set(zone);
+  }
+}
+
+/// The current time in the configured time zone, for file names and entries
+/// KDash records itself.
+pub fn now() -> DateTime<FixedOffset> {
+  TIME_ZONE
+    .get()
+    .copied()
+    .unwrap_or_default()
+    .convert(Utc::now())
+}
+
+/// An absolute time in the configured format and time zone. Without a
+/// format it is RFC 3339, in UTC (`2024-03-01T10:00:00Z`) unless a time zone
+/// was chosen.
+pub fn format_time(time: DateTime<Utc>) -> String {
+  format_time_with(
+    time,
+    TIME_FORMAT.get().map(String::as_str),
+    TIME_ZONE.get().copied(),
+  )
+}
+
+fn format_time_with(
+  time: DateTime<Utc>,
+  format: Option<&str>,
+  zone: Option<DisplayZone>,
+) -> String {
+  match (format, zone) {
+    (Some(format), zone) => zone
+      .unwrap_or_default()
+      .convert(time)
+      .format(format)
+      .to_string(),
+    (None, Some(zone)) => zone
+      .convert(time)
+      .to_rfc3339_opts(SecondsFormat::Secs, true),
+    (None, None) => time.to_rfc3339_opts(SecondsFormat::Secs, true),
+  }
+}
+
+/// The current time in the configured format and time zone, for entries
+/// KDash records itself.
+pub fn format_now() -> String {
+  let format = TIME_FORMAT
+    .get()
+    .map_or(DEFAULT_LOCAL_TIME_FORMAT, String::as_str);
+  now().format(format).to_string()
+}
+
+/// An RFC 3339 time from an API object, reformatted with [`format_time`];
+/// left as is when it does not parse.
+pub fn format_rfc3339(time: &str) -> String {
+  DateTime::parse_from_rfc3339(time).map_or_else(
+    |_| time.to_owned(),
+    |parsed| format_time(parsed.with_timezone(&Utc)),
+  )
+}
+
+/// The latest of `times`, e.g. of an object's creation and its conditions'
+/// last transitions.
+pub fn latest_time<'a>(times: impl IntoIterator<Item = &'a Time>) -> Option<DateTime<Utc>> {
+  times.into_iter().map(time_to_chrono).max()
+}
+
+/// The exact time behind an age column, e.g. `2024-03-01T10:00:00Z`.
+pub fn to_timestamp(timestamp: Option<&Time>) -> Option<String> {
+  timestamp.map(|time| format_time(time_to_chrono(time)))
+}
+
+/// Compact age of `timestamp`, e.g. `3d4h`, `25m` or `40s` under a minute.
 pub fn to_age(timestamp: Option<&Time>, against: DateTime<Utc>) -> String {
   match timestamp {
     Some(time) => {
@@ -39,7 +202,13 @@ pub fn to_age_secs(timestamp: Option<&Time>, against: DateTime<Utc>) -> String {
   }
 }
 
+/// Format a duration as an age. Seconds are shown when `with_secs` is set, as
+/// long as the age is under an hour, and always under a minute so a new
+/// object does not read `0m`.
 pub fn duration_to_age(duration: Duration, with_secs: bool) -> String {
+  // A clock slightly ahead of the API server would give a negative age.
+  let duration = duration.max(Duration::zero());
+  let with_secs = with_secs || duration.num_minutes() == 0;
   let mut out = String::new();
   if duration.num_weeks() != 0 {
     out.push_str(format!("{}w", duration.num_weeks()).as_str());
@@ -62,10 +231,8 @@ pub fn duration_to_age(duration: Duration, with_secs: bool) -> String {
       out.push_str(format!("{}s", secs).as_str());
     }
   }
-  if out.is_empty() && with_secs {
+  if out.is_empty() {
     "0s".into()
-  } else if out.is_empty() {
-    "0m".into()
   } else {
     out
   }
@@ -137,6 +304,26 @@ pub fn friendly_type_name<T>() -> String {
   }
 }
 
+/// Whether an error is the API server rejecting the credentials, as when a
+/// cloud provider's exec-issued token expires mid-session. Most errors reach
+/// the UI formatted into a message, so the text is checked as well as the
+/// error chain.
+pub fn is_auth_failure(e: &anyhow::Error) -> bool {
+  e.chain().any(|cause| {
+    cause
+      .downcast_ref::<kube::Error>()
+      .is_some_and(|error| match error {
+        kube::Error::Auth(_) => true,
+        kube::Error::Api(status) => status.code == 401 || status.reason == "Unauthorized",
+        _ => false,
+      })
+      || {
+        let message = cause.to_string().to_lowercase();
+        message.contains("unauthorized") || message.contains("auth error:")
+      }
+  })
+}
+
 /// Clean up an error message for UI display by extracting the root cause
 /// and stripping verbose Rust type wrapper noise.
 pub fn sanitize_error_message(e: &anyhow::Error) -> String {
@@ -230,6 +417,25 @@ fn extract_inner_message(s: &str) -> Option<String> {
   }
 }
 
+/// Create a directory only the current user can enter, for files holding
+/// cluster data such as Secrets.
+pub fn create_private_dir(path: &Path) -> io::Result<()> {
+  let mut builder = fs::DirBuilder::new();
+  #[cfg(unix)]
+  std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+  builder.create(path)
+}
+
+/// Create a new file only the current user can read, failing if something is
+/// already at `path` rather than writing through it.
+pub fn create_private_file(path: &Path) -> io::Result<File> {
+  let mut options = OpenOptions::new();
+  options.write(true).create_new(true);
+  #[cfg(unix)]
+  std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+  options.open(path)
+}
+
 #[cfg(test)]
 #[allow(clippy::float_cmp)]
 mod tests {
@@ -412,6 +618,82 @@ mod tests {
       String::from("2676w14h")
     );
   }
+  #[test]
+  fn test_to_timestamp() {
+    use super::to_timestamp;
+
+    assert_eq!(
+      to_timestamp(Some(&get_time("15-4-2021 14:09:35"))),
+      Some("2021-04-15T14:09:35Z".into())
+    );
+    assert_eq!(to_timestamp(None), None);
+  }
+
+  #[test]
+  fn test_time_format_is_validated_and_applied() {
+    use super::{format_time_with, is_valid_time_format};
+
+    assert!(is_valid_time_format("%d/%m/%Y %H:%M"));
+    assert!(!is_valid_time_format("%d/%m/%Y %Q"));
+    assert!(!is_valid_time_format(" "));
+
+    let time = to_utc("15-4-2021 14:09:35");
+    assert_eq!(format_time_with(time, None, None), "2021-04-15T14:09:35Z");
+    // a configured format renders in local time
+    assert_eq!(
+      format_time_with(time, Some("%d/%m/%Y %H:%M"), None),
+      time
+        .with_timezone(&chrono::Local)
+        .format("%d/%m/%Y %H:%M")
+        .to_string()
+    );
+  }
+
+  #[test]
+  fn test_time_zone_is_parsed_and_applied() {
+    use super::{format_time_with, DisplayZone};
+
+    assert_eq!("utc".parse(), Ok(DisplayZone::Utc));
+    assert_eq!(" Local ".parse(), Ok(DisplayZone::Local));
+    assert_eq!(
+      "America/New_York".parse(),
+      Ok(DisplayZone::Named(chrono_tz::America::New_York))
+    );
+    assert_eq!(
+      "Europe/Lodnon".parse::<DisplayZone>(),
+      Err("unknown time zone. Use UTC, local or an IANA name such as Europe/Berlin".into())
+    );
+
+    let time = to_utc("15-4-2021 14:09:35");
+    let tokyo = Some(DisplayZone::Named(chrono_tz::Asia::Tokyo));
+    assert_eq!(
+      format_time_with(time, None, tokyo),
+      "2021-04-15T23:09:35+09:00"
+    );
+    assert_eq!(
+      format_time_with(time, None, Some(DisplayZone::Utc)),
+      "2021-04-15T14:09:35Z"
+    );
+    assert_eq!(
+      format_time_with(time, Some("%H:%M %Z"), tokyo),
+      "23:09 +09:00"
+    );
+  }
+
+  #[test]
+  fn test_invalid_time_format_warns_and_keeps_the_default() {
+    use super::initialize_time_format;
+
+    let config = crate::config::KdashConfig {
+      time_format: Some("%Q".into()),
+      ..Default::default()
+    };
+    let warnings = initialize_time_format(None, &config);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("Invalid time format '%Q'"));
+    assert!(initialize_time_format(None, &Default::default()).is_empty());
+  }
+
   #[test]
   fn test_to_age() {
     use std::time::SystemTime;
@@ -420,7 +702,22 @@ mod tests {
 
     assert_eq!(
       to_age(Some(&Time(chrono_to_jiff(Utc::now()))), Utc::now()),
-      String::from("0m")
+      String::from("0s")
+    );
+    assert_eq!(
+      to_age(
+        Some(&get_time("15-4-2021 14:09:35")),
+        to_utc("15-4-2021 14:10:00")
+      ),
+      String::from("25s")
+    );
+    // Ahead of the server clock.
+    assert_eq!(
+      to_age(
+        Some(&get_time("15-4-2021 14:10:05")),
+        to_utc("15-4-2021 14:10:00")
+      ),
+      String::from("0s")
     );
     assert_eq!(
       to_age(
@@ -562,6 +859,20 @@ mod tests {
     assert!(!result.contains("ConnectError"));
   }
 
+  #[test]
+  fn test_is_auth_failure_finds_rejected_credentials_in_messages() {
+    let status = kube::core::Status::failure("Unauthorized", "Unauthorized").with_code(401);
+    let api_error = kube::Error::Api(status.boxed());
+    assert!(super::is_auth_failure(&anyhow::anyhow!(
+      "Failed to get resource Pods. {}",
+      api_error
+    )));
+    assert!(super::is_auth_failure(&anyhow::anyhow!(api_error)));
+    assert!(!super::is_auth_failure(&anyhow::anyhow!(
+      "Failed to get resource Pods. forbidden"
+    )));
+  }
+
   #[test]
   fn test_sanitize_error_message_simple() {
     let e = anyhow::anyhow!("connection refused");
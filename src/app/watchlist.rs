@@ -0,0 +1,566 @@
+//! Resources pinned with `b` to a personal watchlist, kept in
+//! `<state dir>/kdash/watchlist.json` across sessions. The Watchlist view
+//! fetches each pin of the current context by name, whatever its kind or
+//! namespace, and shows its status as its own view would. A pin whose object
+//! is no longer in the cluster stays listed as gone until it is unpinned.
+use std::{
+  fs, io,
+  path::{Path, PathBuf},
+};
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use futures::future::join_all;
+use k8s_openapi::api::{
+  apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet},
+  batch::v1::Job,
+  core::v1::{PersistentVolume, PersistentVolumeClaim, Pod, ReplicationController},
+};
+use log::warn;
+use ratatui::{
+  layout::Rect,
+  widgets::{Cell, Row},
+  Frame,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::{
+  columns,
+  daemonsets::KubeDaemonSet,
+  deployments::KubeDeployment,
+  health::Health,
+  jobs::KubeJob,
+  key_binding::DEFAULT_KEYBINDING,
+  models::{AppResource, FilterableTable, Named},
+  pods::KubePod,
+  pvcs::KubePVC,
+  pvs::KubePV,
+  replicasets::KubeReplicaSet,
+  replication_controllers::KubeReplicationController,
+  statefulsets::KubeStatefulSet,
+  utils::sanitize_error_message,
+  ActiveBlock, App,
+};
+use crate::{
+  network::Network,
+  ui::utils::{
+    action_hint, copy_and_escape_title_line, draw_describe_block, draw_route_resource_block,
+    filter_cursor_position, filter_status_parts, get_describe_active, get_resource_title,
+    help_part, mixed_bold_line, responsive_columns, style_caution, style_failure, style_text,
+    title_with_dual_style, ColumnDef, ResourceTableProps, ViewTier,
+  },
+};
+
+/// A resource on the watchlist.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Pin {
+  /// Context the resource was pinned in; only its own context lists it.
+  pub context: String,
+  /// The view it was pinned from, as `--view` names it, e.g. `deployments`.
+  pub view: String,
+  pub namespace: Option<String>,
+  pub name: String,
+}
+
+impl Pin {
+  pub fn block(&self) -> Option<ActiveBlock> {
+    columns::view_block(&self.view)
+  }
+
+  fn label(&self) -> String {
+    match &self.namespace {
+      Some(ns) => format!("{}/{}", ns, self.name),
+      None => self.name.clone(),
+    }
+  }
+}
+
+/// What the last fetch found for a pin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PinStatus {
+  Loading,
+  Live {
+    status: String,
+    healthy: bool,
+  },
+  /// The object is no longer in the cluster.
+  Gone,
+  Failed(String),
+}
+
+impl PinStatus {
+  pub fn text(&self) -> &str {
+    match self {
+      Self::Loading => "…",
+      Self::Live { status, .. } => status,
+      Self::Gone => "gone",
+      Self::Failed(error) => error,
+    }
+  }
+}
+
+/// A row of the Watchlist view.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatchRow {
+  pub pin: Pin,
+  /// Kind as `kubectl` names it, e.g. `deployment`.
+  pub kind: String,
+  pub status: PinStatus,
+}
+
+impl Named for WatchRow {
+  fn get_name(&self) -> &String {
+    &self.pin.name
+  }
+
+  fn get_namespace(&self) -> Option<&str> {
+    self.pin.namespace.as_deref()
+  }
+}
+
+/// Live and healthy; gone and failed pins are problems.
+impl Health for WatchRow {
+  const CHECKED: bool = true;
+
+  fn is_healthy(&self) -> bool {
+    matches!(
+      self.status,
+      PinStatus::Loading | PinStatus::Live { healthy: true, .. }
+    )
+  }
+}
+
+/// The watchlist file.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct Watchlist {
+  pub pins: Vec<Pin>,
+}
+
+pub fn watchlist_path() -> Option<PathBuf> {
+  crate::last_state::state_file("watchlist.json")
+}
+
+/// A missing file is an empty watchlist; an unreadable one is reported and
+/// left alone until the next pin overwrites it.
+pub fn load_from_path(path: &Path) -> Vec<Pin> {
+  let Ok(contents) = fs::read_to_string(path) else {
+    return vec![];
+  };
+  match serde_json::from_str::<Watchlist>(&contents) {
+    Ok(watchlist) => watchlist.pins,
+    Err(e) => {
+      warn!("Ignoring watchlist {}: {}", path.display(), e);
+      vec![]
+    }
+  }
+}
+
+pub fn save_to_path(pins: &[Pin], path: &Path) -> io::Result<()> {
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir)?;
+  }
+  let watchlist = Watchlist {
+    pins: pins.to_vec(),
+  };
+  let json = serde_json::to_string_pretty(&watchlist).map_err(io::Error::other)?;
+  fs::write(path, json)
+}
+
+/// The status of a fetched object as its own view shows it. Kinds without a
+/// status column are only said to be present.
+pub(crate) fn live_status(block: ActiveBlock, object: serde_json::Value) -> PinStatus {
+  fn live<K, R>(object: serde_json::Value, status: impl Fn(&R) -> String) -> PinStatus
+  where
+    K: DeserializeOwned,
+    R: From<K> + Health,
+  {
+    match serde_json::from_value::<K>(object) {
+      Ok(object) => {
+        let row = R::from(object);
+        PinStatus::Live {
+          status: status(&row),
+          healthy: row.is_healthy(),
+        }
+      }
+      Err(e) => PinStatus::Failed(format!("Unreadable object: {}", e)),
+    }
+  }
+
+  match block {
+    ActiveBlock::Pods => live::<Pod, KubePod>(object, |pod| {
+      format!("{} {}/{}", pod.status, pod.ready.0, pod.ready.1)
+    }),
+    ActiveBlock::Deployments => {
+      live::<Deployment, KubeDeployment>(object, |d| format!("{} ready", d.ready))
+    }
+    ActiveBlock::StatefulSets => {
+      live::<StatefulSet, KubeStatefulSet>(object, |s| format!("{} ready", s.ready))
+    }
+    ActiveBlock::DaemonSets => {
+      live::<DaemonSet, KubeDaemonSet>(object, |d| format!("{}/{} ready", d.ready, d.desired))
+    }
+    ActiveBlock::ReplicaSets => {
+      live::<ReplicaSet, KubeReplicaSet>(object, |r| format!("{}/{} ready", r.ready, r.desired))
+    }
+    ActiveBlock::ReplicationControllers => {
+      live::<ReplicationController, KubeReplicationController>(object, |r| {
+        format!("{}/{} ready", r.ready, r.desired)
+      })
+    }
+    ActiveBlock::Jobs => live::<Job, KubeJob>(object, |j| j.status_summary.clone()),
+    // a node's row also counts its pods, so only its readiness is read
+    ActiveBlock::Nodes => {
+      let ready = object
+        .pointer("/status/conditions")
+        .and_then(serde_json::Value::as_array)
+        .is_some_and(|conditions| {
+          conditions
+            .iter()
+            .any(|c| c["type"] == "Ready" && c["status"] == "True")
+        });
+      PinStatus::Live {
+        status: if ready { "Ready" } else { "NotReady" }.into(),
+        healthy: ready,
+      }
+    }
+    ActiveBlock::PersistentVolumeClaims => {
+      live::<PersistentVolumeClaim, KubePVC>(object, |p| p.status.clone())
+    }
+    ActiveBlock::PersistentVolumes => {
+      live::<PersistentVolume, KubePV>(object, |p| p.status.clone())
+    }
+    _ => PinStatus::Live {
+      status: "Present".into(),
+      healthy: true,
+    },
+  }
+}
+
+impl App {
+  fn context_name(&self) -> String {
+    self
+      .data
+      .active_context
+      .as_ref()
+      .map(|ctx| ctx.name.clone())
+      .unwrap_or_default()
+  }
+
+  /// The pin for a row of `block`, or `None` for views that are not a kind
+  /// of resource with a name of its own, such as custom resources.
+  pub fn pin_for(
+    &self,
+    block: ActiveBlock,
+    name: String,
+    namespace: Option<String>,
+  ) -> Option<Pin> {
+    Some(Pin {
+      context: self.context_name(),
+      view: columns::view_name(block)?.to_owned(),
+      namespace,
+      name,
+    })
+  }
+
+  /// Pins of the current context, in the order they were pinned.
+  pub fn context_pins(&self) -> Vec<Pin> {
+    let context = self.context_name();
+    self
+      .watchlist
+      .iter()
+      .filter(|pin| pin.context == context)
+      .cloned()
+      .collect()
+  }
+
+  /// Pin `pin`, or unpin it if it is pinned already, and save the watchlist.
+  pub fn toggle_pin(&mut self, pin: Pin) {
+    let label = pin.label();
+    if let Some(index) = self.watchlist.iter().position(|pinned| *pinned == pin) {
+      self.watchlist.remove(index);
+      self.set_status_message(format!("Unpinned {}", label));
+    } else {
+      self.watchlist.push(pin);
+      self.set_status_message(format!("Pinned {} to the watchlist", label));
+    }
+    let statuses = self
+      .data
+      .watchlist
+      .items
+      .iter()
+      .map(|row| (row.pin.clone(), row.status.clone()))
+      .collect();
+    self.set_watch_statuses(statuses);
+    if let Some(path) = &self.watchlist_path {
+      if let Err(e) = save_to_path(&self.watchlist, path) {
+        self.handle_error(anyhow!(
+          "Unable to save the watchlist to {}: {}",
+          path.display(),
+          e
+        ));
+      }
+    }
+  }
+
+  /// Show the current context's pins with the statuses found for them; pins
+  /// not fetched yet are loading.
+  pub fn set_watch_statuses(&mut self, statuses: Vec<(Pin, PinStatus)>) {
+    let rows = self
+      .context_pins()
+      .into_iter()
+      .map(|pin| {
+        let status = statuses
+          .iter()
+          .find(|(fetched, _)| *fetched == pin)
+          .map_or(PinStatus::Loading, |(_, status)| status.clone());
+        let kind = pin
+          .block()
+          .map_or_else(|| pin.view.clone(), |block| self.resource_kind_label(block));
+        WatchRow { pin, kind, status }
+      })
+      .collect();
+    self.data.watchlist.set_items(rows);
+  }
+}
+
+const WATCHLIST_COLUMNS: [ColumnDef; 4] = [
+  ColumnDef::all("Kind", 15, 15, 15),
+  ColumnDef::all("Namespace", 20, 20, 20),
+  ColumnDef::all("Name", 35, 35, 35),
+  ColumnDef::all("Status", 30, 30, 30),
+];
+
+fn render_watchlist(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  let palette = app.palette;
+  let load = app.view_load(ActiveBlock::Watchlist);
+  let elsewhere = app.watchlist.len() - app.context_pins().len();
+  let title = format!(
+    " Watchlist (context: {}) [{}]{} ",
+    app.context_name(),
+    app.data.watchlist.count_label(),
+    match elsewhere {
+      0 => String::new(),
+      n => format!(" {} in other contexts", n),
+    }
+  );
+  let title_width = title.chars().count();
+  let watchlist = &mut app.data.watchlist;
+  let filter = watchlist.filter.clone();
+  let filter_active = watchlist.filter_active;
+
+  let mut inline_help = vec![];
+  inline_help.extend(filter_status_parts(&filter, filter_active));
+  if !filter_active {
+    inline_help.push(help_part(format!(
+      " · {} · {} ",
+      action_hint("describe", DEFAULT_KEYBINDING.submit.key),
+      action_hint("unpin", DEFAULT_KEYBINDING.pin.key)
+    )));
+    if watchlist.items.is_empty() {
+      inline_help.push(help_part(format!(
+        "· {} on a resource to pin it ",
+        DEFAULT_KEYBINDING.pin.key
+      )));
+    }
+  }
+
+  let (headers, widths) = responsive_columns(&WATCHLIST_COLUMNS, ViewTier::Compact);
+
+  draw_route_resource_block(
+    f,
+    area,
+    ResourceTableProps {
+      title,
+      inline_help: mixed_bold_line(inline_help, palette),
+      resource: watchlist,
+      table_headers: headers,
+      column_widths: widths,
+    },
+    |row| {
+      let style = match row.status {
+        PinStatus::Gone => style_failure(palette),
+        PinStatus::Failed(_) => style_caution(palette),
+        PinStatus::Live { healthy: false, .. } => style_failure(palette),
+        _ => style_text(palette),
+      };
+      Row::new(vec![
+        Cell::from(row.kind.clone()),
+        Cell::from(row.pin.namespace.clone().unwrap_or_default()),
+        Cell::from(row.pin.name.clone()),
+        Cell::from(row.status.text().to_owned()),
+      ])
+      .style(style)
+    },
+    palette,
+    load,
+  );
+
+  if filter_active {
+    f.set_cursor_position(filter_cursor_position(area, title_width, &filter));
+  }
+}
+
+pub struct WatchlistResource;
+
+#[async_trait]
+impl AppResource for WatchlistResource {
+  fn render(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
+    match block {
+      ActiveBlock::Describe => draw_describe_block(
+        f,
+        app,
+        area,
+        title_with_dual_style(
+          get_resource_title(
+            app,
+            "Watchlist",
+            get_describe_active(block),
+            app.data.watchlist.items.len(),
+          ),
+          copy_and_escape_title_line("Watchlist", app.palette),
+          app.palette,
+        ),
+      ),
+      _ => render_watchlist(f, app, area),
+    }
+  }
+
+  async fn get_resource(network: &Network<'_>) {
+    let pins = network.app.lock().await.context_pins();
+    let statuses = join_all(pins.into_iter().map(|pin| async move {
+      let Some(block) = pin.block() else {
+        return (pin, PinStatus::Failed("Not a resource view".into()));
+      };
+      let Some(api) = network
+        .dynamic_api_for_block(block, pin.namespace.as_deref())
+        .await
+      else {
+        return (pin, PinStatus::Failed("Not a resource view".into()));
+      };
+      let status = match api.get_opt(&pin.name).await {
+        Ok(Some(object)) => match serde_json::to_value(object) {
+          Ok(object) => live_status(block, object),
+          Err(e) => PinStatus::Failed(format!("Unreadable object: {}", e)),
+        },
+        Ok(None) => PinStatus::Gone,
+        Err(e) => PinStatus::Failed(sanitize_error_message(&anyhow!(e))),
+      };
+      (pin, status)
+    }))
+    .await;
+
+    network.app.lock().await.set_watch_statuses(statuses);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::app::contexts::KubeContext;
+  use std::{
+    env,
+    time::{SystemTime, UNIX_EPOCH},
+  };
+
+  fn app_in(context: &str) -> App {
+    let mut app = App::default();
+    app.data.active_context = Some(KubeContext {
+      name: context.into(),
+      ..KubeContext::default()
+    });
+    app
+  }
+
+  #[test]
+  fn test_toggle_pin_lists_the_current_context_only() {
+    let mut app = app_in("prod");
+    let pin = app
+      .pin_for(ActiveBlock::Deployments, "web".into(), Some("shop".into()))
+      .unwrap();
+    assert_eq!(pin.view, "deployments");
+    app.toggle_pin(pin.clone());
+    app.toggle_pin(
+      app
+        .pin_for(ActiveBlock::Nodes, "node-1".into(), None)
+        .unwrap(),
+    );
+    assert_eq!(app.status_message.text(), "Pinned node-1 to the watchlist");
+
+    let rows = &app.data.watchlist.items;
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].kind, "deployment");
+    assert_eq!(rows[0].status, PinStatus::Loading);
+
+    app.data.active_context.as_mut().unwrap().name = "dev".into();
+    assert!(app.context_pins().is_empty());
+    app.data.active_context.as_mut().unwrap().name = "prod".into();
+
+    app.set_watch_statuses(vec![(pin.clone(), PinStatus::Gone)]);
+    assert_eq!(app.data.watchlist.items[0].status, PinStatus::Gone);
+    assert!(!app.data.watchlist.items[0].is_healthy());
+
+    app.toggle_pin(pin);
+    assert_eq!(app.status_message.text(), "Unpinned shop/web");
+    assert_eq!(app.watchlist.len(), 1);
+    assert_eq!(app.data.watchlist.items[0].pin.name, "node-1");
+    // custom resources have no view of their own to fetch them by
+    assert_eq!(
+      app.pin_for(ActiveBlock::DynamicResource, "x".into(), None),
+      None
+    );
+  }
+
+  #[test]
+  fn test_live_status_reads_the_object_like_its_view() {
+    let pod = serde_json::json!({
+      "apiVersion": "v1",
+      "kind": "Pod",
+      "metadata": {"name": "web", "namespace": "shop"},
+      "status": {"phase": "Pending"}
+    });
+    assert_eq!(
+      live_status(ActiveBlock::Pods, pod),
+      PinStatus::Live {
+        status: "Pending 0/0".into(),
+        healthy: false
+      }
+    );
+    let config_map = serde_json::json!({
+      "apiVersion": "v1",
+      "kind": "ConfigMap",
+      "metadata": {"name": "settings"}
+    });
+    assert_eq!(
+      live_status(ActiveBlock::ConfigMaps, config_map),
+      PinStatus::Live {
+        status: "Present".into(),
+        healthy: true
+      }
+    );
+  }
+
+  #[test]
+  fn test_watchlist_round_trips_through_its_file() {
+    let suffix = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .expect("system time should be after epoch")
+      .as_nanos();
+    let dir = env::temp_dir().join(format!("kdash-watchlist-tests-{}", suffix));
+    let path = dir.join("kdash").join("watchlist.json");
+    assert!(load_from_path(&path).is_empty());
+
+    let pins = vec![Pin {
+      context: "prod".into(),
+      view: "pods".into(),
+      namespace: Some("shop".into()),
+      name: "web-0".into(),
+    }];
+    save_to_path(&pins, &path).expect("watchlist should be written");
+    assert_eq!(load_from_path(&path), pins);
+
+    fs::write(&path, "not json").unwrap();
+    assert!(load_from_path(&path).is_empty());
+    let _ = fs::remove_dir_all(dir);
+  }
+}
@@ -1,3 +1,7 @@
+//! The banner shown above `--help` and, when asked for, as a startup splash
+//! with where KDash is about to connect.
+use std::time::Duration;
+
 pub const BANNER: &str = r"
 ██╗  ██╗     
 ██║ ██╔╝     
@@ -6,3 +10,68 @@ pub const BANNER: &str = r"
 ██║  ██╗     
 ╚═╝  ╚═╝          
 ";
+
+/// The splash stays up at least this long so it can be read.
+pub const SPLASH_DURATION: Duration = Duration::from_secs(1);
+/// How long the splash waits for the API server before moving on.
+pub const SPLASH_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// What the API server said to the splash's version request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ServerStatus {
+  Reachable { version: String },
+  Unreachable(String),
+  TimedOut,
+}
+
+/// The banner, then the context about to be used.
+pub fn splash_header(context: Option<&str>) -> String {
+  format!(
+    "{}\nKDash v{}\nContext: {}\n",
+    BANNER,
+    env!("CARGO_PKG_VERSION"),
+    context.unwrap_or("<none>")
+  )
+}
+
+pub fn server_line(status: &ServerStatus) -> String {
+  match status {
+    ServerStatus::Reachable { version } => format!("Server:  reachable ({})", version),
+    ServerStatus::Unreachable(reason) => format!("Server:  unreachable: {}", reason),
+    ServerStatus::TimedOut => format!(
+      "Server:  no answer within {}s",
+      SPLASH_PROBE_TIMEOUT.as_secs()
+    ),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_splash_header_names_context() {
+    let header = splash_header(Some("prod"));
+    assert!(header.starts_with(BANNER));
+    assert!(header.ends_with("\nContext: prod\n"));
+    assert!(splash_header(None).ends_with("\nContext: <none>\n"));
+  }
+
+  #[test]
+  fn test_server_line_per_status() {
+    assert_eq!(
+      server_line(&ServerStatus::Reachable {
+        version: "v1.30.2".into()
+      }),
+      "Server:  reachable (v1.30.2)"
+    );
+    assert_eq!(
+      server_line(&ServerStatus::Unreachable("connection refused".into())),
+      "Server:  unreachable: connection refused"
+    );
+    assert_eq!(
+      server_line(&ServerStatus::TimedOut),
+      "Server:  no answer within 3s"
+    );
+  }
+}
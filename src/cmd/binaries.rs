@@ -0,0 +1,229 @@
+//! The kubectl and helm binaries KDash runs: `--kubectl-path` / `--helm-path`,
+//! then `kubectl_path` / `helm_path` from the config file, then the plain names
+//! looked up on `PATH`.
+use std::{
+  env,
+  path::{Path, PathBuf},
+  sync::OnceLock,
+};
+
+use log::warn;
+
+use crate::config::KdashConfig;
+
+const KUBECTL: &str = "kubectl";
+const HELM: &str = "helm";
+
+static BINARIES: OnceLock<Binaries> = OnceLock::new();
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Binaries {
+  kubectl: String,
+  helm: String,
+}
+
+impl Default for Binaries {
+  fn default() -> Self {
+    Binaries {
+      kubectl: KUBECTL.into(),
+      helm: HELM.into(),
+    }
+  }
+}
+
+fn binaries() -> &'static Binaries {
+  BINARIES.get_or_init(Binaries::default)
+}
+
+pub fn kubectl() -> &'static str {
+  &binaries().kubectl
+}
+
+pub fn helm() -> &'static str {
+  &binaries().helm
+}
+
+/// Map a command's program onto the configured binary, so CLI info probes
+/// and custom entries that run `kubectl` or `helm` use the same one.
+pub fn resolve(program: &str) -> &str {
+  match program {
+    KUBECTL => kubectl(),
+    HELM => helm(),
+    _ => program,
+  }
+}
+
+/// Set the binaries for this run. Returns a warning for each one that cannot
+/// be run, once here instead of on every command. helm is optional, so only
+/// an explicitly configured helm is checked.
+pub fn initialize_binaries(
+  kubectl_path: Option<String>,
+  helm_path: Option<String>,
+  config: &KdashConfig,
+) -> Vec<String> {
+  let helm_configured = helm_path.is_some() || config.helm_path.is_some();
+  let resolved = resolve_binaries(kubectl_path, helm_path, config);
+
+  let path_env = env::var_os("PATH");
+  let path_dirs: Vec<PathBuf> = path_env
+    .as_ref()
+    .map(|paths| env::split_paths(paths).collect())
+    .unwrap_or_default();
+  let mut warnings = vec![];
+  if find_executable(&resolved.kubectl, &path_dirs).is_none() {
+    warnings.push(missing_binary_warning(KUBECTL, &resolved.kubectl));
+  }
+  if helm_configured && find_executable(&resolved.helm, &path_dirs).is_none() {
+    warnings.push(missing_binary_warning(HELM, &resolved.helm));
+  }
+  let _ = BINARIES.set(resolved);
+
+  for warning in &warnings {
+    warn!("{}", warning);
+  }
+
+  warnings
+}
+
+fn resolve_binaries(
+  kubectl_path: Option<String>,
+  helm_path: Option<String>,
+  config: &KdashConfig,
+) -> Binaries {
+  Binaries {
+    kubectl: kubectl_path
+      .or_else(|| config.kubectl_path.clone())
+      .unwrap_or_else(|| KUBECTL.into()),
+    helm: helm_path
+      .or_else(|| config.helm_path.clone())
+      .unwrap_or_else(|| HELM.into()),
+  }
+}
+
+fn missing_binary_warning(name: &str, program: &str) -> String {
+  format!(
+    "{} not found or not executable: {}. Set --{}-path or {}_path in the config file.",
+    name, program, name, name
+  )
+}
+
+/// A program given with a directory is checked as is; a bare name is looked
+/// up in `path_dirs`, like the OS does when spawning it.
+fn find_executable(program: &str, path_dirs: &[PathBuf]) -> Option<PathBuf> {
+  let path = Path::new(program);
+  if path.components().count() > 1 {
+    return is_executable(path).then(|| path.to_path_buf());
+  }
+  path_dirs.iter().find_map(|dir| {
+    let candidate = dir.join(program);
+    if is_executable(&candidate) {
+      return Some(candidate);
+    }
+    let exe = candidate.with_extension(env::consts::EXE_EXTENSION);
+    (!env::consts::EXE_EXTENSION.is_empty() && is_executable(&exe)).then_some(exe)
+  })
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+  use std::os::unix::fs::PermissionsExt;
+  path
+    .metadata()
+    .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+  path.is_file()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::{
+    fs,
+    time::{SystemTime, UNIX_EPOCH},
+  };
+
+  fn temp_test_dir(name: &str) -> PathBuf {
+    let suffix = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .expect("system time should be after epoch")
+      .as_nanos();
+    let path = env::temp_dir().join(format!(
+      "kdash-binaries-tests-{}-{}-{}",
+      name,
+      std::process::id(),
+      suffix
+    ));
+    fs::create_dir_all(&path).expect("temp test dir should be created");
+    path
+  }
+
+  #[cfg(unix)]
+  fn write_file(path: &Path, executable: bool) {
+    use std::os::unix::fs::PermissionsExt;
+    fs::write(path, "#!/bin/sh\n").unwrap();
+    let mode = if executable { 0o755 } else { 0o644 };
+    fs::set_permissions(path, fs::Permissions::from_mode(mode)).unwrap();
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_find_executable_checks_paths_and_path_dirs() {
+    let dir = temp_test_dir("find");
+    write_file(&dir.join("kubectl.1.27"), true);
+    write_file(&dir.join("helm"), false);
+    let path_dirs = vec![PathBuf::from("/nonexistent"), dir.clone()];
+
+    assert_eq!(
+      find_executable("kubectl.1.27", &path_dirs),
+      Some(dir.join("kubectl.1.27"))
+    );
+    let absolute = dir.join("kubectl.1.27").display().to_string();
+    assert_eq!(
+      find_executable(&absolute, &[]),
+      Some(dir.join("kubectl.1.27"))
+    );
+    // Present but not executable.
+    assert_eq!(find_executable("helm", &path_dirs), None);
+    assert_eq!(find_executable("kubectl", &path_dirs), None);
+    let _ = fs::remove_dir_all(dir);
+  }
+
+  #[test]
+  fn test_resolve_binaries_prefers_flags_then_config() {
+    let config = KdashConfig {
+      kubectl_path: Some("/opt/kubectl".into()),
+      helm_path: Some("/opt/helm".into()),
+      ..KdashConfig::default()
+    };
+
+    assert_eq!(
+      resolve_binaries(Some("kubectl.1.27".into()), None, &config),
+      Binaries {
+        kubectl: "kubectl.1.27".into(),
+        helm: "/opt/helm".into(),
+      }
+    );
+    assert_eq!(
+      resolve_binaries(None, None, &KdashConfig::default()),
+      Binaries::default()
+    );
+  }
+
+  #[test]
+  fn test_resolve_maps_only_known_binaries() {
+    assert_eq!(resolve("docker"), "docker");
+    assert_eq!(resolve(KUBECTL), kubectl());
+    assert_eq!(resolve(HELM), helm());
+  }
+
+  #[test]
+  fn test_missing_binary_warning_names_the_settings() {
+    assert_eq!(
+      missing_binary_warning(KUBECTL, "/opt/bin/kubectl"),
+      "kubectl not found or not executable: /opt/bin/kubectl. Set --kubectl-path or kubectl_path in the config file."
+    );
+  }
+}
@@ -1,9 +1,18 @@
-use std::process::{Command, ExitStatus, Stdio};
+use std::{
+  fs,
+  io::Write,
+  path::{Path, PathBuf},
+  process::{Command, ExitStatus, Stdio},
+};
 
-use super::{is_valid_kubectl_arg, push_context_arg};
+use super::{binaries, is_valid_kubectl_arg, push_context_arg};
+use crate::app::{
+  manifest::{parse_manifest, ManifestObject},
+  utils::{create_private_dir, create_private_file},
+};
 
-/// The resource to open in `$EDITOR` via `kubectl edit`. `namespace` is `None`
-/// for cluster-scoped kinds (nodes, PVs, cluster roles, …).
+/// The resource to edit in `$EDITOR`. `namespace` is `None` for cluster-scoped
+/// kinds (nodes, PVs, cluster roles, …).
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EditTarget {
   pub namespace: Option<String>,
@@ -13,10 +22,24 @@ pub struct EditTarget {
   pub context: Option<String>,
 }
 
+/// Fetch the live YAML with `program get_args`, write it to `file` and open it
+/// with `editor`. Applying the result is left to the `CmdRunner` so the TUI is
+/// back before the API answers.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EditCommand {
   pub program: String,
-  pub args: Vec<String>,
+  pub get_args: Vec<String>,
+  /// Editor program followed by its arguments; the file path is appended.
+  pub editor: Vec<String>,
+  pub file: PathBuf,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EditOutcome {
+  /// The file was saved unchanged (or the editor quit without saving).
+  Unchanged,
+  /// The edited manifest, to be applied with `kubectl apply -f`.
+  Changed(PathBuf),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -30,6 +53,8 @@ pub enum EditPrepareError {
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum EditRunError {
+  Fetch(String),
+  TempFile(String),
   Spawn(String),
   Wait(String),
   Exit(String),
@@ -49,24 +74,139 @@ impl std::fmt::Display for EditPrepareError {
 impl std::fmt::Display for EditRunError {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     match self {
-      Self::Spawn(message) => write!(f, "Unable to start kubectl edit: {message}"),
-      Self::Wait(message) => write!(f, "Unable to wait for kubectl edit: {message}"),
-      Self::Exit(message) => write!(f, "kubectl edit exited unsuccessfully: {message}"),
+      Self::Fetch(message) => write!(f, "Unable to fetch the resource YAML: {message}"),
+      Self::TempFile(message) => write!(f, "Unable to use the temporary edit file: {message}"),
+      Self::Spawn(message) => write!(f, "Unable to start the editor: {message}"),
+      Self::Wait(message) => write!(f, "Unable to wait for the editor: {message}"),
+      Self::Exit(message) => write!(f, "Editor exited unsuccessfully: {message}"),
     }
   }
 }
 
-/// Validate the target and build the `kubectl edit <kind> <name> [-n <ns>]`
-/// command. kubectl drops the user into `$EDITOR` and applies the saved changes.
-pub fn prepare_edit(target: &EditTarget) -> Result<EditCommand, EditPrepareError> {
+/// The editor to run, from `$VISUAL`, then `$EDITOR`, then `vi` (`notepad` on
+/// Windows) like kubectl. The value is split on whitespace so `code --wait`
+/// works.
+pub fn resolve_editor(visual: Option<String>, editor: Option<String>) -> Vec<String> {
+  let configured = [visual, editor]
+    .into_iter()
+    .flatten()
+    .map(|value| {
+      value
+        .split_whitespace()
+        .map(String::from)
+        .collect::<Vec<_>>()
+    })
+    .find(|words| !words.is_empty());
+  configured.unwrap_or_else(|| vec![if cfg!(windows) { "notepad" } else { "vi" }.into()])
+}
+
+/// Validate the target and build the commands to fetch and edit it.
+pub fn prepare_edit(
+  target: &EditTarget,
+  editor: Vec<String>,
+) -> Result<EditCommand, EditPrepareError> {
   validate_target(target)?;
-  Ok(build_edit_command(target))
+  Ok(build_edit_command(target, editor))
+}
+
+/// Fetch the YAML, let the user edit it and report whether anything changed.
+/// Like `kubectl edit`, the YAML, which may hold Secret data, goes to a new
+/// file only the user can read, in a directory of its own. It is removed
+/// unless there are changes to apply.
+pub fn run_edit(command: &EditCommand) -> Result<EditOutcome, EditRunError> {
+  let output = Command::new(&command.program)
+    .args(&command.get_args)
+    .stdin(Stdio::null())
+    .output()
+    .map_err(|error| EditRunError::Fetch(error.to_string()))?;
+  if !output.status.success() {
+    return Err(EditRunError::Fetch(
+      String::from_utf8_lossy(&output.stderr).trim().to_string(),
+    ));
+  }
+  let original = String::from_utf8_lossy(&output.stdout).to_string();
+  write_edit_file(&command.file, &original).map_err(|error| {
+    remove_edit_file(&command.file);
+    EditRunError::TempFile(error.to_string())
+  })?;
+
+  let edited = open_editor(&command.editor, &command.file).and_then(|()| {
+    fs::read_to_string(&command.file).map_err(|e| EditRunError::TempFile(e.to_string()))
+  });
+  match edited {
+    Ok(edited) if edited != original => Ok(EditOutcome::Changed(command.file.clone())),
+    result => {
+      // Nothing to apply: an aborted editor (e.g. vim's `:cq`) discards the edit.
+      remove_edit_file(&command.file);
+      result.map(|_| EditOutcome::Unchanged)
+    }
+  }
+}
+
+fn write_edit_file(file: &Path, yaml: &str) -> std::io::Result<()> {
+  if let Some(dir) = file.parent() {
+    create_private_dir(dir)?;
+  }
+  create_private_file(file)?.write_all(yaml.as_bytes())
+}
+
+/// Remove an edit file and the directory made for it, once the edit is
+/// applied or abandoned.
+pub fn remove_edit_file(file: &Path) {
+  let _ = fs::remove_file(file);
+  if let Some(dir) = file.parent() {
+    let _ = fs::remove_dir(dir);
+  }
+}
+
+/// The objects of an edited manifest, for the apply status to follow once it
+/// is applied; none when it cannot be read back.
+pub fn edited_objects(file: &Path) -> Vec<ManifestObject> {
+  fs::read_to_string(file)
+    .ok()
+    .and_then(|text| parse_manifest(&text).ok())
+    .unwrap_or_default()
+}
+
+/// `apply -f <file> [--context <ctx>]` for an edited manifest.
+pub fn build_apply_args(file: &Path, context: Option<&str>) -> Vec<String> {
+  let mut args = vec![
+    "apply".into(),
+    "-f".into(),
+    file.to_string_lossy().to_string(),
+  ];
+  push_context_arg(&mut args, context);
+  args
+}
+
+/// `kubectl apply -f -`, for a manifest written to stdin.
+pub fn build_apply_stdin_args(context: Option<&str>) -> Vec<String> {
+  let mut args = vec!["apply".into(), "-f".into(), "-".into()];
+  push_context_arg(&mut args, context);
+  args
 }
 
-pub fn run_edit(command: &EditCommand) -> Result<(), EditRunError> {
-  let mut child = Command::new(&command.program);
+/// `kubectl apply --dry-run=server`: the API server validates the manifest
+/// and runs its admission webhooks without persisting anything.
+pub fn build_dry_run_apply_args(file: &Path, context: Option<&str>) -> Vec<String> {
+  let mut args = vec![
+    "apply".into(),
+    "--dry-run=server".into(),
+    "-f".into(),
+    file.to_string_lossy().to_string(),
+  ];
+  push_context_arg(&mut args, context);
+  args
+}
+
+fn open_editor(editor: &[String], file: &Path) -> Result<(), EditRunError> {
+  let Some((program, args)) = editor.split_first() else {
+    return Err(EditRunError::Spawn("no editor configured".into()));
+  };
+  let mut child = Command::new(program);
   child
-    .args(&command.args)
+    .args(args)
+    .arg(file)
     .stdin(Stdio::inherit())
     .stdout(Stdio::inherit())
     .stderr(Stdio::inherit());
@@ -107,19 +247,46 @@ fn validate_component(value: &str, error: EditPrepareError) -> Result<(), EditPr
   }
 }
 
-fn build_edit_command(target: &EditTarget) -> EditCommand {
-  let mut args = vec!["edit".into(), target.kind.clone(), target.name.clone()];
+fn build_edit_command(target: &EditTarget, editor: Vec<String>) -> EditCommand {
+  let mut get_args = vec!["get".into(), target.kind.clone(), target.name.clone()];
   if let Some(namespace) = target.namespace.as_ref() {
-    args.push("-n".into());
-    args.push(namespace.clone());
+    get_args.push("-n".into());
+    get_args.push(namespace.clone());
   }
-  push_context_arg(&mut args, target.context.as_deref());
+  get_args.push("-o".into());
+  get_args.push("yaml".into());
+  push_context_arg(&mut get_args, target.context.as_deref());
   EditCommand {
-    program: "kubectl".into(),
-    args,
+    program: binaries::kubectl().into(),
+    get_args,
+    editor,
+    file: temp_file_path(target),
   }
 }
 
+/// A fresh directory per edit, so a rejected edit kept for the user is never
+/// overwritten by the next one and no other user can plant the file first.
+fn temp_file_path(target: &EditTarget) -> PathBuf {
+  let slug: String = format!("{}-{}", target.kind, target.name)
+    .chars()
+    .map(|c| {
+      if c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+        c
+      } else {
+        '_'
+      }
+    })
+    .collect();
+  std::env::temp_dir()
+    .join(format!(
+      "kdash-edit-{}-{}-{}",
+      slug,
+      std::process::id(),
+      chrono::Local::now().format("%Y%m%d%H%M%S%3f")
+    ))
+    .join(format!("{}.yaml", slug))
+}
+
 fn format_exit_status(status: ExitStatus) -> String {
   status
     .code()
@@ -130,100 +297,244 @@ fn format_exit_status(status: ExitStatus) -> String {
 mod tests {
   use super::*;
 
-  #[test]
-  fn test_prepare_edit_builds_namespaced_command() {
-    let command = prepare_edit(&EditTarget {
+  fn deployment_target(context: Option<&str>) -> EditTarget {
+    EditTarget {
       namespace: Some("default".into()),
       kind: "deployment".into(),
       name: "web".into(),
-      context: None,
-    })
-    .expect("edit command should prepare");
+      context: context.map(String::from),
+    }
+  }
+
+  #[test]
+  fn test_prepare_edit_builds_namespaced_command() {
+    let command = prepare_edit(&deployment_target(None), vec!["vi".into()])
+      .expect("edit command should prepare");
 
     assert_eq!(command.program, "kubectl");
     assert_eq!(
-      command.args,
-      vec!["edit", "deployment", "web", "-n", "default"]
+      command.get_args,
+      vec!["get", "deployment", "web", "-n", "default", "-o", "yaml"]
+    );
+    assert_eq!(command.editor, vec!["vi"]);
+    assert_eq!(command.file.file_name().unwrap(), "deployment-web.yaml");
+    let dir_name = command.file.parent().unwrap().file_name().unwrap();
+    assert!(
+      dir_name
+        .to_string_lossy()
+        .starts_with("kdash-edit-deployment-web-"),
+      "{dir_name:?}"
     );
   }
 
   #[test]
   fn test_prepare_edit_omits_namespace_for_cluster_scoped() {
-    let command = prepare_edit(&EditTarget {
-      namespace: None,
-      kind: "node".into(),
-      name: "node-1".into(),
-      context: None,
-    })
+    let command = prepare_edit(
+      &EditTarget {
+        namespace: None,
+        kind: "node".into(),
+        name: "node-1".into(),
+        context: None,
+      },
+      vec!["vi".into()],
+    )
     .expect("edit command should prepare");
 
-    assert_eq!(command.args, vec!["edit", "node", "node-1"]);
+    assert_eq!(
+      command.get_args,
+      vec!["get", "node", "node-1", "-o", "yaml"]
+    );
   }
 
   #[test]
   fn test_prepare_edit_includes_selected_context() {
-    let command = prepare_edit(&EditTarget {
-      namespace: Some("default".into()),
-      kind: "deployment".into(),
-      name: "web".into(),
-      context: Some("prod".into()),
-    })
-    .expect("edit command should prepare");
+    let command = prepare_edit(&deployment_target(Some("prod")), vec!["vi".into()])
+      .expect("edit command should prepare");
 
     assert_eq!(
-      command.args,
+      command.get_args,
       vec![
-        "edit",
+        "get",
         "deployment",
         "web",
         "-n",
         "default",
+        "-o",
+        "yaml",
         "--context",
         "prod"
       ]
     );
+    assert_eq!(
+      build_apply_stdin_args(Some("prod")),
+      vec!["apply", "-f", "-", "--context", "prod"]
+    );
   }
 
   #[test]
   fn test_prepare_edit_rejects_invalid_values() {
+    let prepare = |target: EditTarget| prepare_edit(&target, vec!["vi".into()]);
+
     assert_eq!(
-      prepare_edit(&EditTarget {
+      prepare(EditTarget {
         namespace: Some("default; rm -rf /".into()),
-        kind: "deployment".into(),
-        name: "web".into(),
-        context: None,
+        ..deployment_target(None)
       }),
       Err(EditPrepareError::InvalidNamespace)
     );
 
     assert_eq!(
-      prepare_edit(&EditTarget {
-        namespace: Some("default".into()),
+      prepare(EditTarget {
         kind: "deploy`whoami`".into(),
-        name: "web".into(),
-        context: None,
+        ..deployment_target(None)
       }),
       Err(EditPrepareError::InvalidKind)
     );
 
     assert_eq!(
-      prepare_edit(&EditTarget {
-        namespace: Some("default".into()),
-        kind: "deployment".into(),
+      prepare(EditTarget {
         name: String::new(),
-        context: None,
+        ..deployment_target(None)
       }),
       Err(EditPrepareError::InvalidName)
     );
 
     assert_eq!(
-      prepare_edit(&EditTarget {
-        namespace: Some("default".into()),
-        kind: "deployment".into(),
-        name: "web".into(),
-        context: Some("prod`whoami`".into()),
-      }),
+      prepare(deployment_target(Some("prod`whoami`"))),
       Err(EditPrepareError::InvalidContext)
     );
   }
+
+  #[test]
+  fn test_resolve_editor_prefers_visual_then_editor() {
+    assert_eq!(
+      resolve_editor(Some("code --wait".into()), Some("nano".into())),
+      vec!["code", "--wait"]
+    );
+    assert_eq!(
+      resolve_editor(Some("  ".into()), Some("nano".into())),
+      vec!["nano"]
+    );
+    let fallback = if cfg!(windows) { "notepad" } else { "vi" };
+    assert_eq!(resolve_editor(None, None), vec![fallback]);
+  }
+
+  #[test]
+  fn test_build_apply_args() {
+    assert_eq!(
+      build_apply_args(Path::new("/tmp/web.yaml"), Some("prod")),
+      vec!["apply", "-f", "/tmp/web.yaml", "--context", "prod"]
+    );
+    assert_eq!(
+      build_apply_args(Path::new("/tmp/web.yaml"), None),
+      vec!["apply", "-f", "/tmp/web.yaml"]
+    );
+    assert_eq!(
+      build_dry_run_apply_args(Path::new("/tmp/web.yaml"), Some("prod")),
+      vec![
+        "apply",
+        "--dry-run=server",
+        "-f",
+        "/tmp/web.yaml",
+        "--context",
+        "prod"
+      ]
+    );
+  }
+
+  /// An edit whose fetch prints `replicas: 1` and whose editor runs `script`
+  /// with the temp file as `$0`. Each test uses its own `name` so the temp
+  /// files of tests running in parallel never collide.
+  #[cfg(unix)]
+  fn scripted_edit(name: &str, script: &str) -> EditCommand {
+    let target = EditTarget {
+      name: name.into(),
+      ..deployment_target(None)
+    };
+    let mut command = prepare_edit(&target, vec![]).expect("edit command should prepare");
+    command.program = "sh".into();
+    command.get_args = vec!["-c".into(), "printf 'replicas: 1\\n'".into()];
+    command.editor = vec!["sh".into(), "-c".into(), script.into()];
+    command
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_run_edit_keeps_changed_file() {
+    let command = scripted_edit("changed", "printf 'replicas: 3\\n' > \"$0\"");
+
+    let outcome = run_edit(&command).expect("edit should run");
+
+    assert_eq!(outcome, EditOutcome::Changed(command.file.clone()));
+    assert_eq!(fs::read_to_string(&command.file).unwrap(), "replicas: 3\n");
+    remove_edit_file(&command.file);
+    assert!(!command.file.parent().unwrap().exists());
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_edited_objects_reads_back_the_manifest() {
+    let command = scripted_edit(
+      "read-back",
+      "printf 'apiVersion: apps/v1\\nkind: Deployment\\nmetadata:\\n  name: web\\n  namespace: shop\\n' > \"$0\"",
+    );
+
+    run_edit(&command).expect("edit should run");
+
+    assert_eq!(
+      edited_objects(&command.file),
+      vec![ManifestObject {
+        kind: "Deployment".into(),
+        name: "web".into(),
+        namespace: Some("shop".into()),
+      }]
+    );
+    remove_edit_file(&command.file);
+    assert_eq!(edited_objects(&command.file), vec![]);
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_run_edit_writes_a_private_file() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let command = scripted_edit("private", "printf 'replicas: 3\\n' > \"$0\"");
+
+    run_edit(&command).expect("edit should run");
+
+    let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+    assert_eq!(mode(&command.file), 0o600);
+    assert_eq!(mode(command.file.parent().unwrap()), 0o700);
+    remove_edit_file(&command.file);
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_run_edit_discards_unchanged_or_aborted_edits() {
+    let unchanged = scripted_edit("unchanged", "true");
+    assert_eq!(run_edit(&unchanged), Ok(EditOutcome::Unchanged));
+    assert!(!unchanged.file.parent().unwrap().exists());
+
+    let aborted = scripted_edit("aborted", "printf 'replicas: 3\\n' > \"$0\"; exit 1");
+    assert_eq!(run_edit(&aborted), Err(EditRunError::Exit("1".into())));
+    assert!(!aborted.file.parent().unwrap().exists());
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn test_run_edit_reports_fetch_errors() {
+    let mut command = scripted_edit("missing", "true");
+    command.get_args = vec![
+      "-c".into(),
+      "echo 'deployments.apps \"web\" not found' >&2; exit 1".into(),
+    ];
+
+    assert_eq!(
+      run_edit(&command),
+      Err(EditRunError::Fetch(
+        "deployments.apps \"web\" not found".into()
+      ))
+    );
+    assert!(!command.file.exists());
+  }
 }
@@ -1,23 +1,34 @@
+pub mod binaries;
 pub mod edit;
 pub mod port_forward;
 pub mod shell;
+pub mod top;
 
 use std::{
   collections::BTreeSet,
   io,
+  path::PathBuf,
   process::{Output, Stdio},
   sync::Arc,
+  time::Instant,
 };
 
 use anyhow::anyhow;
 use log::{error, info};
 use regex::Regex;
 use serde_json::Value as JValue;
-use tokio::sync::Mutex;
+use tokio::{
+  io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+  sync::Mutex,
+};
 
 use crate::{
-  app::{self, models::ScrollableTxt, App, Cli},
+  app::{
+    self, actions::Modal, describe_cache::DescribeKey, manifest::ManifestObject,
+    models::ScrollableTxt, App, Cli,
+  },
   config::{CliInfoConfig, CliInfoEntry},
+  network::{impersonation::impersonation, insecure_tls},
 };
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -27,7 +38,67 @@ pub enum IoCmdEvent {
     kind: String,
     value: String,
     ns: Option<String>,
+    /// Caches the output under this version of the object when set.
+    resource_version: Option<String>,
+  },
+  /// `kubectl top nodes`, used when the metrics API cannot be queried directly.
+  TopNodes,
+  /// `kubectl top pods` in `ns`, or in all namespaces.
+  TopPods {
+    ns: Option<String>,
+  },
+  /// `kubectl apply` a manifest saved from `$EDITOR`, after a server-side
+  /// dry-run accepts it.
+  ApplyEdit {
+    kind: String,
+    name: String,
+    file: PathBuf,
+    /// Apply without the dry-run, once the user overrode its rejection.
+    skip_dry_run: bool,
   },
+  /// `kubectl apply -f -` a manifest from the clipboard.
+  ApplyManifest {
+    manifest: String,
+    /// The objects in it, as listed in the confirmation.
+    objects: Vec<ManifestObject>,
+  },
+  /// `kubectl rollout undo` a deployment, to the previous revision or `revision`.
+  RolloutUndo {
+    name: String,
+    namespace: String,
+    revision: Option<u32>,
+  },
+}
+
+impl IoCmdEvent {
+  /// Cache key of a describe request; `None` when the object's
+  /// resourceVersion is unknown.
+  pub fn describe_key(&self) -> Option<DescribeKey> {
+    match self {
+      IoCmdEvent::GetDescribe {
+        kind,
+        value,
+        ns,
+        resource_version: Some(resource_version),
+      } => Some(DescribeKey {
+        kind: kind.clone(),
+        namespace: ns.clone(),
+        name: value.clone(),
+        resource_version: resource_version.clone(),
+      }),
+      _ => None,
+    }
+  }
+
+  /// The `(kind, name, namespace)` of the object a describe request is for.
+  pub fn described_object(self) -> Option<(String, String, Option<String>)> {
+    match self {
+      IoCmdEvent::GetDescribe {
+        kind, value, ns, ..
+      } => Some((kind, value, ns)),
+      _ => None,
+    }
+  }
 }
 
 #[derive(Clone)]
@@ -57,12 +128,16 @@ pub(crate) fn is_valid_kubectl_arg(s: &str) -> bool {
 /// Append `--context <name>` when an in-app context is selected, so kubectl
 /// targets the same cluster the `Shift+C` context switch points at rather than
 /// the kubeconfig's `current-context` (#532). No-op when no context is selected,
-/// matching the kube-rs client which then infers from the kubeconfig.
+/// matching the kube-rs client which then infers from the kubeconfig. With
+/// `--as`, kubectl impersonates the same user as the client, and with
+/// `--insecure-skip-tls-verify` it skips the same certificate check.
 pub(crate) fn push_context_arg(args: &mut Vec<String>, context: Option<&str>) {
   if let Some(context) = context {
     args.push("--context".into());
     args.push(context.into());
   }
+  args.extend(impersonation().kubectl_args());
+  args.extend(insecure_tls::kubectl_args(insecure_tls::insecure_tls()));
 }
 
 const VERSION_REGEX: &str = r"\b(v[0-9]+\.[0-9]+\.[0-9]+)\b";
@@ -99,19 +174,161 @@ impl<'a> CmdRunner<'a> {
   }
 
   pub async fn handle_cmd_event(&mut self, io_event: IoCmdEvent) {
+    let audit = io_event.audit_action();
+    let errors_before = self.app.lock().await.errors_recorded();
     match io_event {
       IoCmdEvent::GetCliInfo => {
         self.get_cli_info().await;
       }
-      IoCmdEvent::GetDescribe { kind, value, ns } => {
-        self.get_describe(kind, value, ns).await;
+      IoCmdEvent::GetDescribe { .. } => {
+        self.get_describe(io_event).await;
+      }
+      IoCmdEvent::TopNodes => {
+        self.get_top_nodes().await;
+      }
+      IoCmdEvent::TopPods { ns } => {
+        self.get_top_pods(ns).await;
+      }
+      IoCmdEvent::ApplyEdit {
+        kind,
+        name,
+        file,
+        skip_dry_run,
+      } => {
+        self.apply_edit(kind, name, file, skip_dry_run).await;
+      }
+      IoCmdEvent::ApplyManifest { manifest, objects } => {
+        self.apply_manifest(manifest, objects).await;
+      }
+      IoCmdEvent::RolloutUndo {
+        name,
+        namespace,
+        revision,
+      } => {
+        self.rollout_undo(name, namespace, revision).await;
       }
     };
 
     let mut app = self.app.lock().await;
+    if let Some(audit) = audit {
+      let succeeded = app.errors_recorded() == errors_before;
+      app.record_audit(audit, succeeded);
+    }
     app.loading_complete();
   }
 
+  /// Run a command with its output streamed into the command-output overlay
+  /// line by line, ending on its exit status. Returns the same `Output` as
+  /// [`run_cmd`], for the caller to report on.
+  async fn run_cmd_streamed(&self, cmd: &str, args: &[&str]) -> Result<Output, io::Error> {
+    self
+      .app
+      .lock()
+      .await
+      .open_cmd_output(command_line(cmd, args));
+    self.stream_cmd(cmd, args, None).await
+  }
+
+  /// Like `run_cmd_streamed`, with `input` written to the command's stdin.
+  async fn run_cmd_streamed_with_input(
+    &self,
+    cmd: &str,
+    args: &[&str],
+    input: String,
+  ) -> Result<Output, io::Error> {
+    self
+      .app
+      .lock()
+      .await
+      .open_cmd_output(command_line(cmd, args));
+    self.stream_cmd(cmd, args, Some(input)).await
+  }
+
+  /// Like `run_cmd_streamed`, but below the output of the command before it.
+  async fn continue_cmd_streamed(&self, cmd: &str, args: &[&str]) -> Result<Output, io::Error> {
+    self
+      .app
+      .lock()
+      .await
+      .continue_cmd_output(command_line(cmd, args));
+    self.stream_cmd(cmd, args, None).await
+  }
+
+  async fn stream_cmd(
+    &self,
+    cmd: &str,
+    args: &[&str],
+    input: Option<String>,
+  ) -> Result<Output, io::Error> {
+    let spawned = tokio::process::Command::new(cmd)
+      .args(args)
+      .kill_on_drop(true)
+      .stdin(if input.is_some() {
+        Stdio::piped()
+      } else {
+        Stdio::null()
+      })
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped())
+      .spawn();
+    let mut child = match spawned {
+      Ok(child) => child,
+      Err(e) => {
+        let mut app = self.app.lock().await;
+        app.push_cmd_output_line(e.to_string(), true);
+        app.finish_cmd_output(false, None);
+        return Err(e);
+      }
+    };
+
+    if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+      // written alongside reading the output, so a large input cannot block
+      // on a full output pipe; dropping stdin closes it
+      tokio::spawn(async move {
+        let _ = stdin.write_all(input.as_bytes()).await;
+      });
+    }
+
+    let (mut stdout, mut stderr) = match (child.stdout.take(), child.stderr.take()) {
+      (Some(out), Some(err)) => (BufReader::new(out).lines(), BufReader::new(err).lines()),
+      _ => return child.wait_with_output().await,
+    };
+    let (mut out_text, mut err_text) = (String::new(), String::new());
+    let (mut out_open, mut err_open) = (true, true);
+    while out_open || err_open {
+      let (line, is_stderr) = tokio::select! {
+        line = stdout.next_line(), if out_open => (line, false),
+        line = stderr.next_line(), if err_open => (line, true),
+      };
+      match line {
+        Ok(Some(line)) => {
+          let text = if is_stderr {
+            &mut err_text
+          } else {
+            &mut out_text
+          };
+          text.push_str(&line);
+          text.push('\n');
+          self.app.lock().await.push_cmd_output_line(line, is_stderr);
+        }
+        _ if is_stderr => err_open = false,
+        _ => out_open = false,
+      }
+    }
+
+    let status = child.wait().await?;
+    self
+      .app
+      .lock()
+      .await
+      .finish_cmd_output(status.success(), status.code());
+    Ok(Output {
+      status,
+      stdout: out_text.into_bytes(),
+      stderr: err_text.into_bytes(),
+    })
+  }
+
   async fn handle_error(&self, e: anyhow::Error) {
     error!("{:?}", e);
     let mut app = self.app.lock().await;
@@ -133,7 +350,7 @@ impl<'a> CmdRunner<'a> {
       if !disabled_defaults.contains("kubectl client")
         || !disabled_defaults.contains("kubectl server")
       {
-        let kubectl_probe = match run_cmd("kubectl", &["version", "-o", "json"]).await {
+        let kubectl_probe = match run_cmd(binaries::kubectl(), &["version", "-o", "json"]).await {
           Ok(out) => {
             if out.status.success() {
               let out = String::from_utf8_lossy(&out.stdout);
@@ -228,7 +445,14 @@ impl<'a> CmdRunner<'a> {
   }
 
   // TODO temp solution, should build this from API response
-  async fn get_describe(&self, kind: String, value: String, ns: Option<String>) {
+  async fn get_describe(&self, event: IoCmdEvent) {
+    let cache_key = event.describe_key();
+    let IoCmdEvent::GetDescribe {
+      kind, value, ns, ..
+    } = event
+    else {
+      return;
+    };
     if !is_valid_kubectl_arg(&kind) || !is_valid_kubectl_arg(&value) {
       self
         .handle_error(anyhow!("Invalid characters in resource kind or name"))
@@ -269,10 +493,16 @@ impl<'a> CmdRunner<'a> {
 
     let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
 
-    match run_cmd("kubectl", &arg_refs).await {
+    match run_cmd(binaries::kubectl(), &arg_refs).await {
       Ok(output) if output.status.success() => {
         let out = String::from_utf8_lossy(&output.stdout).to_string();
         let mut app = self.app.lock().await;
+        if let Some(key) = cache_key {
+          app
+            .data
+            .describe_cache
+            .insert(key, out.clone(), Instant::now());
+        }
         app.data.describe_out = ScrollableTxt::with_string(out);
       }
       Ok(output) => {
@@ -300,10 +530,296 @@ impl<'a> CmdRunner<'a> {
       }
     }
   }
+
+  async fn get_top_nodes(&self) {
+    match self.run_top(top::build_top_nodes_args).await {
+      Some(out) => {
+        let mut app = self.app.lock().await;
+        app.data.node_metrics = top::parse_top_nodes(&out);
+      }
+      // The nodes view works without usage, so this is not worth an error.
+      None => self.app.lock().await.data.node_metrics = vec![],
+    }
+  }
+
+  async fn get_top_pods(&self, ns: Option<String>) {
+    if ns.as_deref().is_some_and(|ns| !is_valid_kubectl_arg(ns)) {
+      self
+        .handle_error(anyhow!("Invalid characters in namespace"))
+        .await;
+      return;
+    }
+    match self
+      .run_top(|context| top::build_top_pods_args(ns.as_deref(), context))
+      .await
+    {
+      Some(out) => {
+        let mut app = self.app.lock().await;
+        app.data.pod_top_usage = top::parse_top_pods(&out, ns.as_deref());
+      }
+      None => {
+        self.app.lock().await.data.pod_top_usage = vec![];
+        self.handle_error(anyhow!("Failed to extract pod utilization metrics. Make sure you have a metrics-server deployed on your cluster.")).await;
+      }
+    }
+  }
+
+  /// Run `kubectl top` with the args from `build_args`. When it fails the
+  /// metrics source is marked unavailable, so neither path is polled again
+  /// until the client is refreshed.
+  async fn run_top(&self, build_args: impl FnOnce(Option<&str>) -> Vec<String>) -> Option<String> {
+    let context = {
+      let app = self.app.lock().await;
+      app.data.selected.context.clone()
+    };
+    if context.as_deref().is_some_and(|c| !is_valid_kubectl_arg(c)) {
+      self
+        .handle_error(anyhow!("Invalid characters in context"))
+        .await;
+      return None;
+    }
+
+    let args = build_args(context.as_deref());
+    let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+    match run_cmd(binaries::kubectl(), &arg_refs).await {
+      Ok(output) if output.status.success() => {
+        Some(String::from_utf8_lossy(&output.stdout).to_string())
+      }
+      result => {
+        match result {
+          Ok(output) => error!(
+            "kubectl top failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+          ),
+          Err(e) => error!("kubectl top failed: {:?}", e),
+        }
+        self.app.lock().await.set_metrics_unavailable();
+        None
+      }
+    }
+  }
+
+  /// Apply an edited manifest once a server-side dry-run accepts it, so
+  /// schema and webhook rejections surface before anything changes. A
+  /// rejected dry-run offers to apply anyway. Once applied, the edited object
+  /// is watched until it is ready, and the temp file is removed, as it is when
+  /// the offer is declined; when the API rejects the apply itself,
+  /// it is kept so the edits are not lost, and the user is told where.
+  async fn apply_edit(&self, kind: String, name: String, file: PathBuf, skip_dry_run: bool) {
+    let context = {
+      let app = self.app.lock().await;
+      app.data.selected.context.clone()
+    };
+    if let Some(ref context) = context {
+      if !is_valid_kubectl_arg(context) {
+        self
+          .handle_error(anyhow!("Invalid characters in context"))
+          .await;
+        return;
+      }
+    }
+
+    if !skip_dry_run {
+      let args = edit::build_dry_run_apply_args(&file, context.as_deref());
+      let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+      let error = match self.run_cmd_streamed(binaries::kubectl(), &arg_refs).await {
+        Ok(output) if output.status.success() => None,
+        Ok(output) => Some(String::from_utf8_lossy(&output.stderr).trim().to_string()),
+        Err(e) => Some(format!("{:?}", e)),
+      };
+      if let Some(error) = error {
+        self
+          .handle_error(anyhow!(
+            "The server-side dry-run rejected the changes to {} '{}', nothing was applied: {}",
+            kind,
+            name,
+            error
+          ))
+          .await;
+        let mut app = self.app.lock().await;
+        app.open_modal(Modal::confirm(
+          "Apply anyway?",
+          format!(
+            "The dry-run of {} '{}' failed. Apply the changes without it?",
+            kind, name
+          ),
+          IoCmdEvent::ApplyEdit {
+            kind,
+            name,
+            file,
+            skip_dry_run: true,
+          },
+        ));
+        return;
+      }
+    }
+
+    let args = edit::build_apply_args(&file, context.as_deref());
+    let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = if skip_dry_run {
+      self.run_cmd_streamed(binaries::kubectl(), &arg_refs).await
+    } else {
+      self
+        .continue_cmd_streamed(binaries::kubectl(), &arg_refs)
+        .await
+    };
+
+    let error = match result {
+      Ok(output) if output.status.success() => {
+        let objects = edit::edited_objects(&file);
+        edit::remove_edit_file(&file);
+        let mut app = self.app.lock().await;
+        app.set_status_message(format!("Applied changes to {} '{}'", kind, name));
+        // Re-poll the active view on the next tick to show the change.
+        app.tick_count = 0;
+        if !objects.is_empty() {
+          app.watch_applied(objects).await;
+        }
+        return;
+      }
+      Ok(output) => String::from_utf8_lossy(&output.stderr).trim().to_string(),
+      Err(e) => format!("{:?}", e),
+    };
+    self
+      .handle_error(anyhow!(
+        "Unable to apply changes to {} '{}': {}\nYour edits are kept in {}",
+        kind,
+        name,
+        error,
+        file.display()
+      ))
+      .await
+  }
+
+  /// Pipe a manifest from the clipboard to `kubectl apply -f -`.
+  /// Once applied, its objects are watched until they are ready.
+  async fn apply_manifest(&self, manifest: String, objects: Vec<ManifestObject>) {
+    let context = {
+      let app = self.app.lock().await;
+      app.data.selected.context.clone()
+    };
+    if let Some(ref context) = context {
+      if !is_valid_kubectl_arg(context) {
+        self
+          .handle_error(anyhow!("Invalid characters in context"))
+          .await;
+        return;
+      }
+    }
+
+    let args = edit::build_apply_stdin_args(context.as_deref());
+    let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+    let error = match self
+      .run_cmd_streamed_with_input(binaries::kubectl(), &arg_refs, manifest)
+      .await
+    {
+      Ok(output) if output.status.success() => {
+        let mut app = self.app.lock().await;
+        app.set_status_message(match objects.as_slice() {
+          [object] => format!("Applied {} from the clipboard", object),
+          objects => format!("Applied {} objects from the clipboard", objects.len()),
+        });
+        // Re-poll the active view on the next tick to show the change.
+        app.tick_count = 0;
+        app.watch_applied(objects).await;
+        return;
+      }
+      Ok(output) => String::from_utf8_lossy(&output.stderr).trim().to_string(),
+      Err(e) => format!("{:?}", e),
+    };
+    self
+      .handle_error(anyhow!(
+        "Unable to apply the manifest from the clipboard: {}",
+        error
+      ))
+      .await
+  }
+
+  async fn rollout_undo(&self, name: String, namespace: String, revision: Option<u32>) {
+    let context = {
+      let app = self.app.lock().await;
+      app.data.selected.context.clone()
+    };
+    if [Some(&name), Some(&namespace), context.as_ref()]
+      .into_iter()
+      .flatten()
+      .any(|arg| !is_valid_kubectl_arg(arg))
+    {
+      self
+        .handle_error(anyhow!(
+          "Invalid characters in deployment, namespace or context"
+        ))
+        .await;
+      return;
+    }
+
+    let args = build_rollout_undo_args(&name, &namespace, revision, context.as_deref());
+    let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    let error = match self.run_cmd_streamed(binaries::kubectl(), &arg_refs).await {
+      Ok(output) if output.status.success() => {
+        let out = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        let mut app = self.app.lock().await;
+        app.set_status_message(if out.is_empty() {
+          format!("Rolled back deployment '{}'", name)
+        } else {
+          out
+        });
+        // Re-poll the active view on the next tick to show the rollback.
+        app.tick_count = 0;
+        return;
+      }
+      Ok(output) => rollout_undo_error(&name, String::from_utf8_lossy(&output.stderr).trim()),
+      Err(e) => format!("Unable to roll back deployment '{}': {:?}", name, e),
+    };
+    self.handle_error(anyhow!(error)).await
+  }
 }
 
 // utils
 
+fn command_line(cmd: &str, args: &[&str]) -> String {
+  std::iter::once(cmd)
+    .chain(args.iter().copied())
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+fn build_rollout_undo_args(
+  name: &str,
+  namespace: &str,
+  revision: Option<u32>,
+  context: Option<&str>,
+) -> Vec<String> {
+  let mut args = vec![
+    "rollout".into(),
+    "undo".into(),
+    format!("deployment/{}", name),
+    "-n".into(),
+    namespace.into(),
+  ];
+  if let Some(revision) = revision {
+    args.push(format!("--to-revision={}", revision));
+  }
+  push_context_arg(&mut args, context);
+  args
+}
+
+/// kubectl's error for a failed undo, reworded when there is no revision to
+/// roll back to, which is the common case for a fresh deployment.
+fn rollout_undo_error(name: &str, stderr: &str) -> String {
+  if stderr.contains("no rollout history found") {
+    format!(
+      "Deployment '{}' has no previous revision to roll back to",
+      name
+    )
+  } else if stderr.contains("unable to find specified revision") {
+    format!("Deployment '{}' has no such revision: {}", name, stderr)
+  } else {
+    format!("Unable to roll back deployment '{}': {}", name, stderr)
+  }
+}
+
 fn build_cli(name: &str, version: Option<String>, index: u8) -> app::Cli {
   app::Cli {
     name: name.to_owned(),
@@ -378,7 +894,7 @@ async fn run_cli_entry(entry: &CliInfoEntry) -> CliProbe {
     return CliProbe::Version(None);
   };
   let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
-  match run_cmd(program, &arg_refs).await {
+  match run_cmd(binaries::resolve(program), &arg_refs).await {
     Ok(result) if result.status.success() => {
       let value = String::from_utf8_lossy(&result.stdout);
       if let Some(ref re) = entry.regex {
@@ -429,6 +945,62 @@ mod tests {
   use super::CliProbe;
   use crate::config::{CliInfoConfig, CliInfoEntry};
 
+  #[cfg(unix)]
+  #[tokio::test]
+  async fn test_run_cmd_streamed_fills_the_output_overlay() {
+    use std::sync::Arc;
+
+    use tokio::sync::Mutex;
+
+    use super::{App, CmdRunner};
+
+    let app = Arc::new(Mutex::new(App::default()));
+    let runner = CmdRunner::new(&app);
+    let output = runner
+      .run_cmd_streamed("sh", &["-c", "echo configured; echo warned >&2; exit 3"])
+      .await
+      .unwrap();
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "configured\n");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "warned\n");
+
+    let app = app.lock().await;
+    let pane = app.cmd_output.as_ref().unwrap();
+    assert_eq!(
+      pane.command,
+      "sh -c echo configured; echo warned >&2; exit 3"
+    );
+    let mut lines: Vec<_> = pane
+      .lines
+      .iter()
+      .map(|l| (l.text.as_str(), l.stderr))
+      .collect();
+    lines.sort();
+    assert_eq!(lines, vec![("configured", false), ("warned", true)]);
+    assert_eq!(pane.status_line(), "exit 3 (failed)");
+  }
+
+  #[cfg(unix)]
+  #[tokio::test]
+  async fn test_run_cmd_streamed_with_input_writes_stdin() {
+    use std::sync::Arc;
+
+    use tokio::sync::Mutex;
+
+    use super::{App, CmdRunner};
+
+    let app = Arc::new(Mutex::new(App::default()));
+    let runner = CmdRunner::new(&app);
+    let output = runner
+      .run_cmd_streamed_with_input("sh", &["-c", "sed 's/^/read /'"], "kind: Pod\n".into())
+      .await
+      .unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "read kind: Pod\n");
+    let app = app.lock().await;
+    assert_eq!(app.cmd_output.as_ref().unwrap().status_line(), "exit 0");
+  }
+
   #[test]
   fn test_is_valid_arg_accepts_normal_input() {
     // Normal k8s resource names should pass
@@ -463,6 +1035,42 @@ mod tests {
     assert!(super::is_valid_kubectl_arg(""));
   }
 
+  #[test]
+  fn test_build_rollout_undo_args() {
+    assert_eq!(
+      super::build_rollout_undo_args("web", "team-a", None, None),
+      ["rollout", "undo", "deployment/web", "-n", "team-a"]
+    );
+    assert_eq!(
+      super::build_rollout_undo_args("web", "team-a", Some(3), Some("prod")),
+      [
+        "rollout",
+        "undo",
+        "deployment/web",
+        "-n",
+        "team-a",
+        "--to-revision=3",
+        "--context",
+        "prod"
+      ]
+    );
+  }
+
+  #[test]
+  fn test_rollout_undo_error_explains_missing_history() {
+    assert_eq!(
+      super::rollout_undo_error(
+        "web",
+        "error: no rollout history found for deployment \"web\""
+      ),
+      "Deployment 'web' has no previous revision to roll back to"
+    );
+    assert_eq!(
+      super::rollout_undo_error("web", "error: forbidden"),
+      "Unable to roll back deployment 'web': error: forbidden"
+    );
+  }
+
   #[test]
   fn test_disabled_default_set_normalizes_labels() {
     let set = super::disabled_default_set(&CliInfoConfig {
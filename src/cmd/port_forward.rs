@@ -1,4 +1,4 @@
-use super::{is_valid_kubectl_arg, push_context_arg};
+use super::{binaries, is_valid_kubectl_arg, push_context_arg};
 
 /// A pod or service to forward to a local port. `kind` is the kubectl resource
 /// type (`pods` / `services`); ports are validated as `u16` so they never need
@@ -43,7 +43,7 @@ impl std::fmt::Display for PortForwardPrepareError {
 /// Validate the target and build the
 /// `kubectl port-forward <kind>/<name> -n <ns> <local>:<remote>` command. The
 /// child is run in the background (it stays open until killed), unlike the
-/// foreground `kubectl edit` / `exec` commands.
+/// foreground `$EDITOR` / `exec` commands.
 pub fn prepare_port_forward(
   target: &PortForwardTarget,
 ) -> Result<PortForwardCommand, PortForwardPrepareError> {
@@ -66,7 +66,7 @@ pub fn prepare_port_forward(
   push_context_arg(&mut args, target.context.as_deref());
 
   Ok(PortForwardCommand {
-    program: "kubectl".into(),
+    program: binaries::kubectl().into(),
     args,
   })
 }
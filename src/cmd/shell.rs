@@ -2,7 +2,7 @@ use std::process::{Command, ExitStatus, Stdio};
 
 use anyhow::anyhow;
 
-use super::{is_valid_kubectl_arg, push_context_arg};
+use super::{binaries, is_valid_kubectl_arg, push_context_arg};
 
 const SHELL_CANDIDATES: [&str; 2] = ["/bin/bash", "/bin/sh"];
 
@@ -154,7 +154,7 @@ fn build_shell_exec_command(target: &ShellExecTarget, shell: &str) -> ShellExecC
     shell.into(),
   ]);
   ShellExecCommand {
-    program: "kubectl".into(),
+    program: binaries::kubectl().into(),
     args,
     shell: shell.into(),
   }
@@ -174,7 +174,7 @@ fn probe_shell_support(target: &ShellExecTarget, shell: &str) -> ShellProbeResul
     "-c".into(),
     "exit".into(),
   ]);
-  let output = Command::new("kubectl")
+  let output = Command::new(binaries::kubectl())
     .args(&args)
     .stdin(Stdio::null())
     .stdout(Stdio::null())
@@ -0,0 +1,160 @@
+use crate::app::{
+  metrics::{KubeNodeMetrics, TopPodUsage},
+  utils,
+};
+
+use super::push_context_arg;
+
+/// `top nodes --no-headers [--context <ctx>]`
+pub fn build_top_nodes_args(context: Option<&str>) -> Vec<String> {
+  let mut args = vec!["top".into(), "nodes".into(), "--no-headers".into()];
+  push_context_arg(&mut args, context);
+  args
+}
+
+/// `top pods (-n <ns> | -A) --no-headers [--context <ctx>]`
+pub fn build_top_pods_args(namespace: Option<&str>, context: Option<&str>) -> Vec<String> {
+  let mut args = vec!["top".into(), "pods".into()];
+  match namespace {
+    Some(ns) => {
+      args.push("-n".into());
+      args.push(ns.into());
+    }
+    None => args.push("-A".into()),
+  }
+  args.push("--no-headers".into());
+  push_context_arg(&mut args, context);
+  args
+}
+
+/// Parse `kubectl top nodes --no-headers`: `NAME CPU(cores) CPU% MEMORY(bytes) MEMORY%`.
+/// Nodes kubectl has no metrics for (shown as `<unknown>`) are skipped.
+pub fn parse_top_nodes(out: &str) -> Vec<KubeNodeMetrics> {
+  out
+    .lines()
+    .filter_map(|line| {
+      let cols: Vec<&str> = line.split_whitespace().collect();
+      let [name, cpu, cpu_percent, mem, mem_percent] = cols[..] else {
+        return None;
+      };
+      Some(KubeNodeMetrics {
+        name: name.into(),
+        cpu: utils::cpu_to_milli(cpu.into()),
+        cpu_percent: parse_percent(cpu_percent)?,
+        mem: utils::mem_to_mi(mem.into()),
+        mem_percent: parse_percent(mem_percent)?,
+      })
+    })
+    .collect()
+}
+
+/// Parse `kubectl top pods --no-headers`: `NAMESPACE NAME CPU MEMORY` with `-A`,
+/// or `NAME CPU MEMORY` for the single `namespace` passed with `-n`.
+pub fn parse_top_pods(out: &str, namespace: Option<&str>) -> Vec<TopPodUsage> {
+  out
+    .lines()
+    .filter_map(|line| {
+      let cols: Vec<&str> = line.split_whitespace().collect();
+      let (ns, name, cpu, memory) = match (namespace, &cols[..]) {
+        (Some(ns), [name, cpu, memory]) => (ns, *name, *cpu, *memory),
+        (None, [ns, name, cpu, memory]) => (*ns, *name, *cpu, *memory),
+        _ => return None,
+      };
+      Some(TopPodUsage {
+        namespace: ns.into(),
+        name: name.into(),
+        cpu: cpu.into(),
+        memory: memory.into(),
+      })
+    })
+    .collect()
+}
+
+fn parse_percent(value: &str) -> Option<f64> {
+  value.strip_suffix('%')?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_build_top_args() {
+    assert_eq!(
+      build_top_nodes_args(Some("prod")),
+      vec!["top", "nodes", "--no-headers", "--context", "prod"]
+    );
+    assert_eq!(
+      build_top_pods_args(Some("team-a"), None),
+      vec!["top", "pods", "-n", "team-a", "--no-headers"]
+    );
+    assert_eq!(
+      build_top_pods_args(None, None),
+      vec!["top", "pods", "-A", "--no-headers"]
+    );
+  }
+
+  #[test]
+  fn test_parse_top_nodes() {
+    let out = "\
+node-1   250m   12%   2888180Ki   37%
+node-2   <unknown>   <unknown>   <unknown>   <unknown>
+node-3   1   50%   2Gi   25%
+";
+    assert_eq!(
+      parse_top_nodes(out),
+      vec![
+        KubeNodeMetrics {
+          name: "node-1".into(),
+          cpu: "250m".into(),
+          cpu_percent: 12.0,
+          mem: "2820Mi".into(),
+          mem_percent: 37.0,
+        },
+        KubeNodeMetrics {
+          name: "node-3".into(),
+          cpu: "1000m".into(),
+          cpu_percent: 50.0,
+          mem: "2048Mi".into(),
+          mem_percent: 25.0,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_parse_top_pods() {
+    let all = "\
+kube-system   coredns-5d78c9869d-abcde   3m   12Mi
+team-a        web-1                      120m 256Mi
+";
+    assert_eq!(
+      parse_top_pods(all, None),
+      vec![
+        TopPodUsage {
+          namespace: "kube-system".into(),
+          name: "coredns-5d78c9869d-abcde".into(),
+          cpu: "3m".into(),
+          memory: "12Mi".into(),
+        },
+        TopPodUsage {
+          namespace: "team-a".into(),
+          name: "web-1".into(),
+          cpu: "120m".into(),
+          memory: "256Mi".into(),
+        },
+      ]
+    );
+
+    let single = "web-1   120m   256Mi\n";
+    assert_eq!(
+      parse_top_pods(single, Some("team-a")),
+      vec![TopPodUsage {
+        namespace: "team-a".into(),
+        name: "web-1".into(),
+        cpu: "120m".into(),
+        memory: "256Mi".into(),
+      }]
+    );
+  }
+}
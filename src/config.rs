@@ -10,6 +10,7 @@ use std::{
 use log::warn;
 use regex::Regex;
 use serde::Deserialize;
+use serde_json::Value;
 
 #[derive(Clone, Debug, Default, Deserialize, PartialEq)]
 #[serde(default)]
@@ -25,9 +26,90 @@ pub struct KdashConfig {
   /// Full user-defined palette that joins the `t` / `Alt+t` theme cycle.
   pub custom_theme: Option<crate::ui::theme::CustomThemeConfig>,
   pub log_tail_lines: Option<u32>,
+  /// Field of JSON log lines shown first as the message when they are
+  /// pretty-printed, e.g. `msg`.
+  pub log_json_message_field: Option<String>,
+  /// Log lines kept in memory per stream; the oldest are dropped past it.
+  /// Defaults to 10,000.
+  pub log_max_lines: Option<usize>,
+  /// Pod restart count at which the restarts column turns red. Counts below
+  /// it (but above zero) are shown in the warning colour.
+  pub restart_threshold: Option<u32>,
+  /// Keep the Name column in place when scrolling table columns. Defaults to true.
+  pub pin_name_column: Option<bool>,
+  /// Columns to show per resource view, in order, e.g. `pods: [Name, Status]`.
+  /// Views without an entry keep their default columns.
+  pub columns: Option<BTreeMap<String, Vec<String>>>,
   pub cli_info: Option<CliInfoConfig>,
+  /// kubectl binary to run instead of `kubectl` from `PATH`.
+  pub kubectl_path: Option<String>,
+  /// helm binary to run instead of `helm` from `PATH`.
+  pub helm_path: Option<String>,
   pub hide_logo: bool,
   pub hide_info_on_start: bool,
+  /// Open on the cluster dashboard instead of the pods view.
+  pub start_on_dashboard: bool,
+  /// Capture mouse clicks, e.g. to select tabs. Off by default because it
+  /// takes over the terminal's own text selection.
+  pub mouse_capture: bool,
+  /// Show the banner with connection status for a second before the UI,
+  /// like `--splash`.
+  pub splash: bool,
+  /// Make URLs clickable with OSC 8 hyperlinks, like `--hyperlinks`. Off by
+  /// default as not every terminal understands them.
+  pub hyperlinks: bool,
+  /// Web console URL to open the selected resource in, with `{context}`,
+  /// `{cluster}`, `{namespace}`, `{kind}` and `{name}` filled in.
+  pub console_url: Option<String>,
+  /// Console URLs by context name, used over `console_url` for that context.
+  pub console_urls: BTreeMap<String, String>,
+  /// Poll intervals in milliseconds by view name, e.g. `events: 1000`, used
+  /// over the global poll rate for that view.
+  pub poll_rates: BTreeMap<String, u64>,
+  /// strftime-style format for absolute times, like `--time-format`, e.g.
+  /// `%d/%m/%Y %H:%M`. Ages are not affected.
+  pub time_format: Option<String>,
+  /// Glyphs to draw with, like `--glyphs`: `unicode` (the default),
+  /// `nerd-font` or `ascii`.
+  pub glyphs: Option<String>,
+  /// Highlight rows whose resource was created or changed state recently,
+  /// fading out over `highlight_changes_secs`. Off by default.
+  pub highlight_changes: bool,
+  /// How long a change stays highlighted, in seconds. Defaults to 30.
+  pub highlight_changes_secs: Option<u64>,
+  /// Changes that ask for confirmation first, e.g. `[delete, scale]`. Unset
+  /// confirms the destructive ones: delete, evict, restart, undo, apply and
+  /// trigger. An empty list confirms nothing.
+  pub confirm: Option<Vec<String>>,
+  /// Refuse every change to the cluster, like `--read-only`.
+  pub read_only: bool,
+  /// Views fetched in the background before they are first opened, e.g.
+  /// `[pods, deployments]`. Unset pre-warms events only.
+  pub prewarm: Option<Vec<String>>,
+  /// Keep Secret values, and those of keys `redact_patterns` matches, in
+  /// namespace snapshots (`Ctrl-s`), which redact them by default.
+  pub snapshot_secrets: bool,
+  /// Start in redact mode (`Alt-r`), which masks sensitive values on screen.
+  pub redact: bool,
+  /// Keys whose values redact mode masks, matched as case-insensitive
+  /// substrings, e.g. `[password, token, license]`. Unset masks passwords,
+  /// tokens, secrets, credentials and API keys.
+  pub redact_patterns: Option<Vec<String>>,
+  /// Named sets of settings picked with `--profile`, each laid over the rest
+  /// of the file: mappings merge key by key, other values replace.
+  pub profiles: BTreeMap<String, Value>,
+  /// Named runbooks of restarts, scales and deletes, each run by a key.
+  pub macros: Vec<MacroConfig>,
+}
+
+impl KdashConfig {
+  /// The console URL template for `context`, falling back to `console_url`.
+  pub fn console_url_for(&self, context: Option<&str>) -> Option<&str> {
+    context
+      .and_then(|name| self.console_urls.get(name))
+      .or(self.console_url.as_ref())
+      .map(String::as_str)
+  }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
@@ -36,6 +118,31 @@ pub struct KeybindingOverrides {
   pub values: BTreeMap<String, String>,
 }
 
+/// A quick-action macro: steps run in order after one confirmation.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct MacroConfig {
+  pub name: String,
+  /// The key that runs it, written like a keybinding override, e.g. `alt+1`.
+  pub key: String,
+  pub steps: Vec<MacroStepConfig>,
+}
+
+/// One step of a macro: an action on resources named in one view.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct MacroStepConfig {
+  /// `restart`, `scale` or `delete`.
+  pub action: String,
+  /// The view of the resources, as `--view` names it, e.g. `deployments`.
+  pub view: String,
+  /// Namespace of the resources; the selected one when unset.
+  pub namespace: Option<String>,
+  pub names: Vec<String>,
+  /// Replica count to scale to.
+  pub replicas: Option<u32>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
 pub struct ThemeConfig {
   pub dark: Option<BTreeMap<String, String>>,
@@ -95,47 +202,116 @@ pub fn config_path() -> Option<PathBuf> {
   config_path_from(env::var_os("KDASH_CONFIG"), dirs::config_dir())
 }
 
-fn parse_config(contents: &str, path: &Path) -> LoadedConfig {
+fn yaml_options() -> serde_saphyr::Options {
   // Restrict boolean resolution to `true`/`false` (YAML 1.2 core schema, matching
   // the old serde_yaml). Without this, serde_saphyr resolves bare `n`/`y`/`t`/`f`
   // as booleans, breaking existing unquoted single-letter keybindings.
-  let options = serde_saphyr::options! {
+  serde_saphyr::options! {
     strict_booleans: true
+  }
+}
+
+/// Lay `profile` over `base`: mappings are merged key by key, anything else
+/// in the profile replaces what the base has.
+fn merge_profile(base: &mut Value, profile: Value) {
+  match (base, profile) {
+    (Value::Object(base), Value::Object(profile)) => {
+      for (key, value) in profile {
+        match base.get_mut(&key) {
+          Some(existing) => merge_profile(existing, value),
+          None => {
+            base.insert(key, value);
+          }
+        }
+      }
+    }
+    (base, profile) => *base = profile,
+  }
+}
+
+fn unknown_profile(name: &str, path: &Path, profiles: Option<&Value>) -> String {
+  let names: Vec<&str> = profiles
+    .and_then(Value::as_object)
+    .map(|profiles| profiles.keys().map(String::as_str).collect())
+    .unwrap_or_default();
+  let known = if names.is_empty() {
+    "it has none".to_owned()
+  } else {
+    format!("it has {}", names.join(", "))
   };
-  match serde_saphyr::from_str_with_options::<KdashConfig>(contents, options) {
-    Ok(config) => LoadedConfig {
-      config,
-      warning: None,
-    },
-    Err(error) => LoadedConfig {
-      config: KdashConfig::default(),
-      warning: Some(format!(
-        "Failed to parse config file {}: {}. Using defaults.",
+  format!(
+    "Profile '{}' is not in config file {}; {}",
+    name,
+    path.display(),
+    known
+  )
+}
+
+fn parse_config(contents: &str, path: &Path, profile: Option<&str>) -> Result<KdashConfig, String> {
+  let parse_error = |error: serde_saphyr::Error| {
+    format!("Failed to parse config file {}: {}", path.display(), error)
+  };
+  let Some(name) = profile else {
+    return serde_saphyr::from_str_with_options::<KdashConfig>(contents, yaml_options())
+      .map_err(parse_error);
+  };
+  let mut config =
+    serde_saphyr::from_str_with_options::<Value>(contents, yaml_options()).map_err(parse_error)?;
+  if config.is_null() {
+    config = Value::Object(Default::default());
+  }
+  let overrides = config
+    .get("profiles")
+    .and_then(|profiles| profiles.get(name))
+    .cloned()
+    .ok_or_else(|| unknown_profile(name, path, config.get("profiles")))?;
+  merge_profile(&mut config, overrides);
+  // JSON is YAML, so the merged file goes through the same parser as the file.
+  serde_saphyr::from_str_with_options::<KdashConfig>(&config.to_string(), yaml_options()).map_err(
+    |error| {
+      format!(
+        "Failed to parse profile '{}' of config file {}: {}",
+        name,
         path.display(),
         error
-      )),
+      )
     },
-  }
+  )
 }
 
-pub fn load_config_from_path(path: &Path) -> LoadedConfig {
+/// Read and parse the config file, with `profile` laid over it when given. A
+/// missing file is the default config, and has no profiles.
+pub fn read_config(path: &Path, profile: Option<&str>) -> Result<KdashConfig, String> {
   match fs::read_to_string(path) {
-    Ok(contents) => parse_config(&contents, path),
-    Err(error) if error.kind() == ErrorKind::NotFound => LoadedConfig::default(),
+    Ok(contents) => parse_config(&contents, path, profile),
+    Err(error) if error.kind() == ErrorKind::NotFound => match profile {
+      Some(name) => Err(unknown_profile(name, path, None)),
+      None => Ok(KdashConfig::default()),
+    },
+    Err(error) => Err(format!(
+      "Failed to read config file {}: {}",
+      path.display(),
+      error
+    )),
+  }
+}
+
+pub fn load_config_from_path(path: &Path, profile: Option<&str>) -> LoadedConfig {
+  match read_config(path, profile) {
+    Ok(config) => LoadedConfig {
+      config,
+      warning: None,
+    },
     Err(error) => LoadedConfig {
       config: KdashConfig::default(),
-      warning: Some(format!(
-        "Failed to read config file {}: {}. Using defaults.",
-        path.display(),
-        error
-      )),
+      warning: Some(format!("{}. Using defaults.", error)),
     },
   }
 }
 
-pub fn load_config() -> LoadedConfig {
+pub fn load_config(profile: Option<&str>) -> LoadedConfig {
   let loaded = config_path()
-    .map(|path| load_config_from_path(&path))
+    .map(|path| load_config_from_path(&path, profile))
     .unwrap_or_default();
 
   if let Some(warning) = &loaded.warning {
@@ -198,7 +374,7 @@ mod tests {
     )
     .expect("config fixture should be written");
 
-    let loaded = load_config_from_path(&path);
+    let loaded = load_config_from_path(&path, None);
 
     assert_eq!(
       loaded
@@ -258,7 +434,7 @@ mod tests {
     let dir = temp_test_dir("missing");
     let path = dir.join("missing.yaml");
 
-    let loaded = load_config_from_path(&path);
+    let loaded = load_config_from_path(&path, None);
 
     assert_eq!(loaded.config, KdashConfig::default());
     assert!(loaded.warning.is_none());
@@ -272,7 +448,7 @@ mod tests {
     let path = dir.join("config.yaml");
     fs::write(&path, "keybindings: [").expect("config fixture should be written");
 
-    let loaded = load_config_from_path(&path);
+    let loaded = load_config_from_path(&path, None);
 
     assert_eq!(loaded.config, KdashConfig::default());
     assert!(loaded
@@ -289,7 +465,7 @@ mod tests {
     let path = dir.join("config.yaml");
     fs::write(&path, "keybindings:\n  quit: ctrl+q\n").expect("config fixture should be written");
 
-    let loaded = load_config_from_path(&path);
+    let loaded = load_config_from_path(&path, None);
 
     assert!(loaded.config.keybindings.is_some());
     assert!(loaded.config.theme.is_none());
@@ -300,6 +476,52 @@ mod tests {
     fs::remove_dir_all(dir).expect("temp test dir should be removed");
   }
 
+  #[test]
+  fn test_profile_is_laid_over_the_base_config() {
+    let dir = temp_test_dir("profile");
+    let path = dir.join("config.yaml");
+    fs::write(
+      &path,
+      "default_theme: latte\nlog_tail_lines: 250\nkeybindings:\n  quit: ctrl+q\n  describe_resource: y\npoll_rates:\n  events: 1000\nprofiles:\n  prod:\n    read_only: true\n    default_theme: gruvbox\n    keybindings:\n      quit: q\n  kind:\n    poll_rates: null\n",
+    )
+    .expect("config fixture should be written");
+
+    let base = load_config_from_path(&path, None).config;
+    assert!(!base.read_only);
+    assert_eq!(base.default_theme.as_deref(), Some("latte"));
+
+    let prod = load_config_from_path(&path, Some("prod"));
+    assert!(prod.warning.is_none());
+    assert!(prod.config.read_only);
+    assert_eq!(prod.config.default_theme.as_deref(), Some("gruvbox"));
+    assert_eq!(prod.config.log_tail_lines, Some(250));
+    // mappings merge, and a bare `y` is still a key, not a boolean
+    let keys = prod
+      .config
+      .keybindings
+      .expect("keybindings should be merged")
+      .values;
+    assert_eq!(keys.get("quit").map(String::as_str), Some("q"));
+    assert_eq!(keys.get("describe_resource").map(String::as_str), Some("y"));
+    assert_eq!(prod.config.poll_rates.get("events"), Some(&1000));
+
+    let kind = load_config_from_path(&path, Some("kind")).config;
+    assert!(kind.poll_rates.is_empty());
+
+    assert_eq!(
+      read_config(&path, Some("staging")),
+      Err(format!(
+        "Profile 'staging' is not in config file {}; it has kind, prod",
+        path.display()
+      ))
+    );
+    assert!(read_config(&dir.join("missing.yaml"), Some("prod"))
+      .unwrap_err()
+      .ends_with("it has none"));
+
+    fs::remove_dir_all(dir).expect("temp test dir should be removed");
+  }
+
   #[test]
   fn test_cli_info_defaults_hide_missing_binaries() {
     let config: KdashConfig =
@@ -336,7 +558,7 @@ mod tests {
     // Guards the documented sample config (and the new default_theme /
     // custom_theme keys) against schema drift.
     let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/kdash.sample-config.yaml");
-    let loaded = load_config_from_path(&path);
+    let loaded = load_config_from_path(&path, None);
 
     assert!(
       loaded.warning.is_none(),
@@ -360,6 +582,88 @@ mod tests {
     assert_eq!(custom.accent.as_deref(), Some("#FF00AA"));
   }
 
+  #[test]
+  fn test_restart_threshold_parses() {
+    let config: KdashConfig = serde_saphyr::from_str(
+      "restart_threshold: 10
+",
+    )
+    .expect("config should parse");
+
+    assert_eq!(config.restart_threshold, Some(10));
+  }
+
+  #[test]
+  fn test_highlight_changes_parses_and_defaults_off() {
+    assert!(!KdashConfig::default().highlight_changes);
+    let config: KdashConfig = serde_saphyr::from_str(
+      "highlight_changes: true
+highlight_changes_secs: 60
+",
+    )
+    .expect("config should parse");
+
+    assert!(config.highlight_changes);
+    assert_eq!(config.highlight_changes_secs, Some(60));
+  }
+
+  #[test]
+  fn test_console_url_prefers_the_context_template() {
+    let config: KdashConfig = serde_saphyr::from_str(
+      "console_url: https://console/{cluster}/{name}\nconsole_urls:\n  prod: https://prod-console/{kind}/{name}\n",
+    )
+    .expect("config should parse");
+
+    assert_eq!(
+      config.console_url_for(Some("prod")),
+      Some("https://prod-console/{kind}/{name}")
+    );
+    assert_eq!(
+      config.console_url_for(Some("dev")),
+      Some("https://console/{cluster}/{name}")
+    );
+    assert_eq!(
+      config.console_url_for(None),
+      Some("https://console/{cluster}/{name}")
+    );
+    assert_eq!(KdashConfig::default().console_url_for(Some("prod")), None);
+  }
+
+  #[test]
+  fn test_poll_rates_parse_by_view() {
+    let config: KdashConfig =
+      serde_saphyr::from_str("poll_rates:\n  events: 1000\n  persistentvolumes: 60000\n")
+        .expect("config should parse");
+
+    assert_eq!(config.poll_rates.get("events"), Some(&1000));
+    assert_eq!(config.poll_rates.get("persistentvolumes"), Some(&60_000));
+    assert!(KdashConfig::default().poll_rates.is_empty());
+  }
+
+  #[test]
+  fn test_macros_parse_with_their_steps() {
+    let config: KdashConfig = serde_saphyr::from_str(
+      "macros:\n  - name: restart-frontend\n    key: alt+1\n    steps:\n      - action: scale\n        view: deployments\n        names: [web, api]\n        replicas: 2\n",
+    )
+    .expect("config should parse");
+
+    assert_eq!(
+      config.macros,
+      vec![MacroConfig {
+        name: "restart-frontend".into(),
+        key: "alt+1".into(),
+        steps: vec![MacroStepConfig {
+          action: "scale".into(),
+          view: "deployments".into(),
+          namespace: None,
+          names: vec!["web".into(), "api".into()],
+          replicas: Some(2),
+        }],
+      }]
+    );
+    assert!(KdashConfig::default().macros.is_empty());
+  }
+
   #[test]
   fn test_hide_logo_and_info_default_to_false() {
     let config: KdashConfig = serde_saphyr::from_str("").expect("empty config should parse");
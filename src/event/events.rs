@@ -11,17 +11,23 @@ use crossterm::event::{self, Event as CEvent, KeyEvent, MouseEvent};
 use log::{error, info, warn};
 use notify::{RecursiveMode, Watcher};
 
-#[derive(Debug, Clone, Copy)]
+/// How long a watched file has to stay unchanged before a change is sent.
+const WATCH_SETTLE: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone)]
 /// Configuration for event handling.
 pub struct EventConfig {
   /// The tick rate at which the application will sent an tick event.
   pub tick_rate: Duration,
+  /// The KDash config file to watch for live reload, if any.
+  pub config_path: Option<PathBuf>,
 }
 
 impl Default for EventConfig {
   fn default() -> EventConfig {
     EventConfig {
       tick_rate: Duration::from_millis(250),
+      config_path: None,
     }
   }
 }
@@ -35,6 +41,10 @@ pub enum Event<I, J> {
   Tick,
   /// The kubeconfig file changed on disk.
   KubeConfigChange,
+  /// The KDash config file changed on disk.
+  ConfigChange,
+  /// Text pasted into the terminal (bracketed paste).
+  Paste(String),
 }
 
 /// A small event handler that wrap crossterm input and tick event. Each event
@@ -46,10 +56,12 @@ pub struct Events {
 }
 
 impl Events {
-  /// Constructs an new instance of `Events` with the default config.
-  pub fn new(tick_rate: u64) -> Events {
+  /// Constructs an new instance of `Events` with the default config,
+  /// watching `config_path` for changes when given.
+  pub fn new(tick_rate: u64, config_path: Option<PathBuf>) -> Events {
     Events::with_config(EventConfig {
       tick_rate: Duration::from_millis(tick_rate),
+      config_path,
     })
   }
 
@@ -75,6 +87,11 @@ impl Events {
                 break; // receiver dropped, app is shutting down
               }
             }
+            Ok(CEvent::Paste(text)) => {
+              if event_tx.send(Event::Paste(text)).is_err() {
+                break; // receiver dropped, app is shutting down
+              }
+            }
             Ok(_) => {}
             Err(e) => {
               error!("Failed to read terminal event: {:?}", e);
@@ -96,6 +113,9 @@ impl Events {
 
     // Start kubeconfig file watcher for live sync (#315)
     start_kubeconfig_watcher(tx.clone());
+    if let Some(path) = config.config_path {
+      start_config_watcher(path, tx.clone());
+    }
 
     Events { rx, _tx: tx }
   }
@@ -135,9 +155,30 @@ fn kubeconfig_watch_paths() -> Vec<PathBuf> {
 /// Start a file watcher thread for kubeconfig files. Sends `Event::KubeConfigChange`
 /// on the provided channel when any watched file is modified.
 fn start_kubeconfig_watcher(tx: mpsc::Sender<Event<KeyEvent, MouseEvent>>) {
-  let paths = kubeconfig_watch_paths();
+  start_file_watcher(
+    kubeconfig_watch_paths(),
+    "kubeconfig",
+    || Event::KubeConfigChange,
+    tx,
+  );
+}
+
+/// Start a file watcher thread for the KDash config file. Sends
+/// `Event::ConfigChange` when it is saved.
+fn start_config_watcher(path: PathBuf, tx: mpsc::Sender<Event<KeyEvent, MouseEvent>>) {
+  start_file_watcher(vec![path], "config", || Event::ConfigChange, tx);
+}
+
+/// Watch `paths` on a thread of its own and send `event()` once the files
+/// settle after a change.
+fn start_file_watcher(
+  paths: Vec<PathBuf>,
+  what: &'static str,
+  event: fn() -> Event<KeyEvent, MouseEvent>,
+  tx: mpsc::Sender<Event<KeyEvent, MouseEvent>>,
+) {
   if paths.is_empty() {
-    info!("No kubeconfig paths to watch");
+    info!("No {} paths to watch", what);
     return;
   }
 
@@ -148,7 +189,7 @@ fn start_kubeconfig_watcher(tx: mpsc::Sender<Event<KeyEvent, MouseEvent>>) {
     }) {
       Ok(w) => w,
       Err(e) => {
-        warn!("Failed to create kubeconfig file watcher: {}", e);
+        warn!("Failed to create {} file watcher: {}", what, e);
         return;
       }
     };
@@ -176,38 +217,40 @@ fn start_kubeconfig_watcher(tx: mpsc::Sender<Event<KeyEvent, MouseEvent>>) {
         if let Err(e) = watcher.watch(&dir, RecursiveMode::NonRecursive) {
           warn!("Failed to watch {:?}: {}", dir, e);
         } else {
-          info!("Watching kubeconfig directory: {:?}", dir);
+          info!("Watching {} directory: {:?}", what, dir);
         }
       }
     }
 
-    // Debounce: ignore rapid successive events (editors do multiple writes)
-    let debounce = Duration::from_secs(2);
-    let mut last_sent = Instant::now() - debounce;
-
-    for res in notify_rx {
-      match res {
-        Ok(event) => {
-          // Only react to events that touch our target kubeconfig files
-          let dominated = event
-            .paths
-            .iter()
-            .any(|p| p.file_name().is_some_and(|f| target_filenames.contains(f)));
-          if !dominated {
-            continue;
-          }
-          if last_sent.elapsed() >= debounce {
-            info!("Kubeconfig file change detected: {:?}", event.kind);
-            if tx.send(Event::KubeConfigChange).is_err() {
-              break; // receiver dropped, app is shutting down
-            }
-            last_sent = Instant::now();
-          }
-        }
-        Err(e) => {
-          warn!("Kubeconfig watcher error: {:?}", e);
+    let touches_target = |res: &notify::Result<notify::Event>| match res {
+      Ok(event) => event
+        .paths
+        .iter()
+        .any(|p| p.file_name().is_some_and(|f| target_filenames.contains(f))),
+      Err(e) => {
+        warn!("{} watcher error: {:?}", what, e);
+        false
+      }
+    };
+
+    while let Ok(res) = notify_rx.recv() {
+      // Only react to events that touch our target files
+      if !touches_target(&res) {
+        continue;
+      }
+      // Debounce: editors and kubectl write in several steps, so wait until
+      // the file has been quiet for a moment and send one event
+      loop {
+        match notify_rx.recv_timeout(WATCH_SETTLE) {
+          Ok(_) => continue,
+          Err(mpsc::RecvTimeoutError::Timeout) => break,
+          Err(mpsc::RecvTimeoutError::Disconnected) => return,
         }
       }
+      info!("{} file change detected", what);
+      if tx.send(event()).is_err() {
+        break; // receiver dropped, app is shutting down
+      }
     }
   });
 }
@@ -235,12 +278,14 @@ mod tests {
   #[test]
   fn test_events_produces_tick() {
     // Events should produce at least one Tick within a reasonable time
-    let events = Events::new(50); // 50ms tick rate
+    let events = Events::new(50, None); // 50ms tick rate
     match events.next() {
       Ok(Event::Tick) => {}             // expected
       Ok(Event::Input(_)) => {}         // possible if terminal sends something
       Ok(Event::MouseInput(_)) => {}    // possible
       Ok(Event::KubeConfigChange) => {} // possible if kubeconfig watcher fires
+      Ok(Event::ConfigChange) => {}     // not watched here
+      Ok(Event::Paste(_)) => {}         // possible if terminal sends something
       Err(e) => panic!("Events::next() returned error: {:?}", e),
     }
   }
@@ -256,7 +301,7 @@ mod tests {
   #[test]
   fn test_events_receiver_drop_stops_sender() {
     // Create events, then drop the Events struct — the sender thread should exit gracefully
-    let events = Events::new(50);
+    let events = Events::new(50, None);
     // Get one event to ensure the thread is running
     let _ = events.next();
     // Drop events — the thread should detect the receiver is gone and break
@@ -281,6 +326,7 @@ mod tests {
   fn test_event_config_default() {
     let config = EventConfig::default();
     assert_eq!(config.tick_rate, std::time::Duration::from_millis(250));
+    assert!(config.config_path.is_none());
   }
 
   #[test]
@@ -387,4 +433,32 @@ mod tests {
 
     fs::remove_dir_all(dir).unwrap();
   }
+
+  #[test]
+  fn test_config_watcher_sends_one_event_per_save() {
+    use std::fs;
+
+    let dir = env::temp_dir().join(format!("kdash-config-watcher-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let config_file = dir.join("config.yaml");
+    fs::write(&config_file, "tick_rate: 1").unwrap();
+
+    let (tx, rx) = mpsc::channel::<Event<KeyEvent, MouseEvent>>();
+    start_config_watcher(config_file.clone(), tx);
+    thread::sleep(Duration::from_millis(200));
+
+    // other files in the directory are ignored
+    fs::write(dir.join("other.yaml"), "x").unwrap();
+    // a save in several writes settles into one event
+    fs::write(&config_file, "").unwrap();
+    fs::write(&config_file, "hide_logo: true").unwrap();
+
+    match rx.recv_timeout(Duration::from_secs(5)) {
+      Ok(Event::ConfigChange) => {}
+      other => panic!("Expected ConfigChange, got: {:?}", other.is_ok()),
+    }
+    assert!(rx.recv_timeout(WATCH_SETTLE * 2).is_err());
+
+    fs::remove_dir_all(dir).unwrap();
+  }
 }
@@ -4,6 +4,11 @@ use std::str::FromStr;
 
 use crossterm::event;
 
+use crate::ui::glyphs::glyphs;
+
+/// The highest function key terminals report, F24.
+const MAX_FUNCTION_KEY: u8 = 24;
+
 /// Represents an key.
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
 pub enum Key {
@@ -34,32 +39,8 @@ pub enum Key {
   PageUp,
   /// Page Down key
   PageDown,
-  /// F0 key
-  F0,
-  /// F1 key
-  F1,
-  /// F2 key
-  F2,
-  /// F3 key
-  F3,
-  /// F4 key
-  F4,
-  /// F5 key
-  F5,
-  /// F6 key
-  F6,
-  /// F7 key
-  F7,
-  /// F8 key
-  F8,
-  /// F9 key
-  F9,
-  /// F10 key
-  F10,
-  /// F11 key
-  F11,
-  /// F12 key
-  F12,
+  /// Function key F1 to F24 (F0 on some keyboards)
+  F(u8),
   Char(char),
   Ctrl(char),
   Alt(char),
@@ -68,61 +49,38 @@ pub enum Key {
 }
 
 impl Key {
-  /// Returns the function key corresponding to the given number
-  ///
-  /// 1 -> F1, etc...
-  ///
-  /// # Panics
-  ///
-  /// If `n == 0 || n > 12`
-  pub fn from_f(n: u8) -> Key {
-    match n {
-      0 => Key::F0,
-      1 => Key::F1,
-      2 => Key::F2,
-      3 => Key::F3,
-      4 => Key::F4,
-      5 => Key::F5,
-      6 => Key::F6,
-      7 => Key::F7,
-      8 => Key::F8,
-      9 => Key::F9,
-      10 => Key::F10,
-      11 => Key::F11,
-      12 => Key::F12,
-      _ => panic!("unknown function key: F{}", n),
-    }
-  }
-
   /// Compact glyph form for `key:label` hints — no angle brackets.
   /// Uses universal Unicode key glyphs (`↹`, `⇧↹`, `⏎`, `←→↑↓`) so the
-  /// rendered hints match across platforms (fixtures stay stable).
+  /// rendered hints match across platforms (fixtures stay stable), or their
+  /// names with the ASCII glyph set.
   pub fn symbol(&self) -> String {
     match self {
       Key::Char(' ') => "Space".into(),
       Key::Char(c) => c.to_string(),
+      Key::Ctrl(' ') => "Ctrl+Space".into(),
       Key::Ctrl(c) => format!("Ctrl+{}", c),
+      Key::Alt(' ') => "Alt+Space".into(),
       Key::Alt(c) => format!("Alt+{}", c),
       Key::Shift(' ') => "Shift+Space".into(),
-      Key::Shift(c) => c.to_ascii_uppercase().to_string(),
-      Key::Enter => "⏎".into(),
-      Key::Tab => "↹".into(),
-      Key::BackTab => "⇧↹".into(),
+      Key::Shift(c) if c.is_ascii_alphabetic() => c.to_ascii_uppercase().to_string(),
+      Key::Shift(c) => format!("Shift+{}", c),
+      Key::Enter => glyphs().key_enter.into(),
+      Key::Tab => glyphs().key_tab.into(),
+      Key::BackTab => glyphs().key_back_tab.into(),
       Key::Esc => "Esc".into(),
       Key::Backspace => "Backspace".into(),
-      Key::Left => "←".into(),
-      Key::Right => "→".into(),
-      Key::Up => "↑".into(),
-      Key::Down => "↓".into(),
+      Key::Left => glyphs().key_left.into(),
+      Key::Right => glyphs().key_right.into(),
+      Key::Up => glyphs().key_up.into(),
+      Key::Down => glyphs().key_down.into(),
       Key::Ins => "Ins".into(),
       Key::Delete => "Del".into(),
       Key::Home => "Home".into(),
       Key::End => "End".into(),
       Key::PageUp => "PgUp".into(),
       Key::PageDown => "PgDn".into(),
+      Key::F(n) => format!("F{}", n),
       Key::Unknown => "?".into(),
-      // F0–F12 — Debug renders as `F3`, etc.
-      other => format!("{:?}", other),
     }
   }
 }
@@ -140,10 +98,11 @@ impl fmt::Display for Key {
       Key::Shift(c) if c.is_ascii_alphabetic() => write!(f, "<{}>", c.to_ascii_uppercase()),
       Key::Shift(c) => write!(f, "<Shift+{}>", c),
       Key::Char(c) => write!(f, "<{}>", c),
-      Key::Left => write!(f, "<←>"),
-      Key::Right => write!(f, "<→>"),
-      Key::Up => write!(f, "<↑>"),
-      Key::Down => write!(f, "<↓>"),
+      Key::Left => write!(f, "<{}>", glyphs().key_left),
+      Key::Right => write!(f, "<{}>", glyphs().key_right),
+      Key::Up => write!(f, "<{}>", glyphs().key_up),
+      Key::Down => write!(f, "<{}>", glyphs().key_down),
+      Key::F(n) => write!(f, "<F{}>", n),
       _ => write!(f, "<{:?}>", self),
     }
   }
@@ -153,7 +112,13 @@ impl FromStr for Key {
   type Err = String;
 
   fn from_str(input: &str) -> Result<Self, Self::Err> {
-    let normalized = input.trim().trim_matches(['<', '>']);
+    // `Display` wraps keys in angle brackets, which may themselves be keys
+    let trimmed = input.trim();
+    let normalized = trimmed
+      .strip_prefix('<')
+      .and_then(|inner| inner.strip_suffix('>'))
+      .filter(|inner| !inner.is_empty())
+      .unwrap_or(trimmed);
     if normalized.is_empty() {
       return Err("key cannot be empty".into());
     }
@@ -163,18 +128,24 @@ impl FromStr for Key {
         .chars()
         .next()
         .expect("single-character string must have one char");
-      return if c.is_ascii_uppercase() {
-        Ok(Key::Shift(c.to_ascii_lowercase()))
-      } else {
-        Ok(Key::Char(c))
-      };
+      // the glyphs `Display` and `symbol` show, so both forms parse back
+      return Ok(match c {
+        '←' => Key::Left,
+        '→' => Key::Right,
+        '↑' => Key::Up,
+        '↓' => Key::Down,
+        '⏎' => Key::Enter,
+        '↹' => Key::Tab,
+        c if c.is_ascii_uppercase() => Key::Shift(c.to_ascii_lowercase()),
+        c => Key::Char(c),
+      });
     }
 
     let lower = normalized.to_lowercase();
     let key = match lower.as_str() {
       "enter" | "return" => Key::Enter,
       "tab" => Key::Tab,
-      "backtab" | "back-tab" | "shift+tab" => Key::BackTab,
+      "backtab" | "back-tab" | "shift+tab" | "⇧↹" => Key::BackTab,
       "backspace" => Key::Backspace,
       "esc" | "escape" => Key::Esc,
       "left" | "leftarrow" | "left-arrow" => Key::Left,
@@ -185,8 +156,8 @@ impl FromStr for Key {
       "delete" | "del" => Key::Delete,
       "home" => Key::Home,
       "end" => Key::End,
-      "pageup" | "page-up" => Key::PageUp,
-      "pagedown" | "page-down" => Key::PageDown,
+      "pageup" | "page-up" | "pgup" => Key::PageUp,
+      "pagedown" | "page-down" | "pgdn" => Key::PageDown,
       "space" => Key::Char(' '),
       _ => {
         if let Some(rest) = lower.strip_prefix("ctrl+") {
@@ -202,8 +173,8 @@ impl FromStr for Key {
           let value = rest
             .parse::<u8>()
             .map_err(|_| format!("unsupported key '{}'", input))?;
-          if value <= 12 {
-            return Ok(Key::from_f(value));
+          if value <= MAX_FUNCTION_KEY {
+            return Ok(Key::F(value));
           }
         }
         return Err(format!("unsupported key '{}'", input));
@@ -285,7 +256,7 @@ impl From<event::KeyEvent> for Key {
       event::KeyEvent {
         code: event::KeyCode::F(n),
         ..
-      } => Key::from_f(n),
+      } => Key::F(n),
       event::KeyEvent {
         code: event::KeyCode::Enter,
         ..
@@ -313,7 +284,11 @@ impl From<event::KeyEvent> for Key {
           Key::Ctrl(normalized)
         } else if modifiers.contains(event::KeyModifiers::ALT) {
           Key::Alt(normalized)
-        } else if modifiers.contains(event::KeyModifiers::SHIFT) {
+        } else if modifiers.contains(event::KeyModifiers::SHIFT)
+          && (c.is_ascii_alphabetic() || c == ' ')
+        {
+          // Shift is already applied to symbols like `?`, which some
+          // terminals still report with the modifier
           Key::Shift(normalized)
         } else {
           Key::Char(c)
@@ -341,7 +316,7 @@ mod tests {
     assert_eq!(format!("{}", Key::Shift('d')), "<D>");
     assert_eq!(format!("{}", Key::Char('c')), "<c>");
     assert_eq!(format!("{}", Key::Enter), "<Enter>");
-    assert_eq!(format!("{}", Key::F10), "<F10>");
+    assert_eq!(format!("{}", Key::F(10)), "<F10>");
   }
   #[test]
   fn test_key_from_event() {
@@ -351,7 +326,7 @@ mod tests {
     );
     assert_eq!(
       Key::from(event::KeyEvent::from(event::KeyCode::F(2))),
-      Key::F2
+      Key::F(2)
     );
     assert_eq!(
       Key::from(event::KeyEvent::from(event::KeyCode::Char('J'))),
@@ -395,12 +370,78 @@ mod tests {
     assert_eq!("shift+d".parse::<Key>(), Ok(Key::Shift('d')));
     assert_eq!("space".parse::<Key>(), Ok(Key::Char(' ')));
     assert_eq!("page-down".parse::<Key>(), Ok(Key::PageDown));
-    assert_eq!("F10".parse::<Key>(), Ok(Key::F10));
+    assert_eq!("F10".parse::<Key>(), Ok(Key::F(10)));
     assert_eq!("tab".parse::<Key>(), Ok(Key::Tab));
     assert_eq!("shift+tab".parse::<Key>(), Ok(Key::BackTab));
     assert_eq!("backtab".parse::<Key>(), Ok(Key::BackTab));
   }
 
+  #[test]
+  fn test_key_from_event_maps_function_keys_and_shifted_symbols() {
+    let shifted = |c| event::KeyEvent::new(event::KeyCode::Char(c), event::KeyModifiers::SHIFT);
+    assert_eq!(
+      Key::from(event::KeyEvent::from(event::KeyCode::F(0))),
+      Key::F(0)
+    );
+    assert_eq!(
+      Key::from(event::KeyEvent::from(event::KeyCode::F(24))),
+      Key::F(24)
+    );
+    assert_eq!(
+      Key::from(event::KeyEvent::new(
+        event::KeyCode::BackTab,
+        event::KeyModifiers::SHIFT
+      )),
+      Key::BackTab
+    );
+    assert_eq!(Key::from(shifted('?')), Key::Char('?'));
+    assert_eq!(Key::from(shifted('1')), Key::Char('1'));
+    assert_eq!(Key::from(shifted(' ')), Key::Shift(' '));
+    assert_eq!(
+      Key::from(event::KeyEvent::from(event::KeyCode::CapsLock)),
+      Key::Unknown
+    );
+    assert!("f25".parse::<Key>().is_err());
+  }
+
+  #[test]
+  fn test_display_and_symbol_parse_back_to_the_key() {
+    let mut keys = vec![
+      Key::Enter,
+      Key::Tab,
+      Key::BackTab,
+      Key::Backspace,
+      Key::Esc,
+      Key::Left,
+      Key::Right,
+      Key::Up,
+      Key::Down,
+      Key::Ins,
+      Key::Delete,
+      Key::Home,
+      Key::End,
+      Key::PageUp,
+      Key::PageDown,
+      Key::Char(' '),
+      Key::Char('<'),
+      Key::Char('>'),
+      Key::Char('/'),
+      Key::Ctrl(' '),
+      Key::Alt(' '),
+      Key::Shift(' '),
+      Key::Shift('1'),
+    ];
+    keys.extend((0..=MAX_FUNCTION_KEY).map(Key::F));
+    for c in 'a'..='z' {
+      keys.extend([Key::Char(c), Key::Ctrl(c), Key::Alt(c), Key::Shift(c)]);
+    }
+
+    for key in keys {
+      assert_eq!(key.to_string().parse::<Key>(), Ok(key), "{}", key);
+      assert_eq!(key.symbol().parse::<Key>(), Ok(key), "{}", key.symbol());
+    }
+  }
+
   #[test]
   fn test_uppercase_and_shift_string_parse_equally() {
     assert_eq!("D".parse::<Key>(), "shift+d".parse::<Key>());
@@ -1,32 +1,49 @@
 use anyhow::anyhow;
-use crossterm::event::{KeyEvent, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use k8s_openapi::api::rbac::v1::RoleRef;
 use kubectl_view_allocations::GroupBy;
 use serde::Serialize;
 use std::{
   fs,
+  io::{self, Write},
   path::{Path, PathBuf},
+  time::{Duration, Instant},
 };
 
 use crate::{
   app::{
-    actions::{InputAction, InputModal, InputSubmit, Modal, ResourceAction},
-    key_binding::DEFAULT_KEYBINDING,
+    actions::{ConfirmAction, InputAction, InputModal, InputSubmit, Modal, ResourceAction},
+    configmaps::KubeConfigMap,
+    console::{self, ConsoleTarget},
+    dynamic::KubeDynamicKind,
+    events::KubeEvent,
+    export::ExportFormat,
+    key_binding::{DEFAULT_KEYBINDING, GOTO_VIEWS},
+    load_state::{ListPages, PAGE_PREFETCH_ROWS},
     models::{
-      HasPodSelector, KubeResource, Scrollable, ScrollableTxt, StatefulList, StatefulTable,
+      HasPodSelector, KubeResource, MarkedRow, Named, Scrollable, ScrollableTxt, StatefulList,
+      StatefulTable,
     },
+    node_pods::sort_node_pods,
+    pods::{self, ContainerEnvView, ContainerMount},
     secrets::KubeSecret,
     troubleshoot::ResourceKind,
-    ActiveBlock, App, PendingEdit, PendingShellExec, Route, RouteId,
+    utils, ActiveBlock, App, PendingEdit, PendingShellExec, Route, RouteId,
   },
   cmd::IoCmdEvent,
   event::Key,
   network::{stream::IoStreamEvent, IoEvent, ResourcePatch},
+  ui::summary,
 };
 
-/// Handles Enter/`o` key on a workload resource: describe/yaml, drill-down to pods, or aggregate logs.
+/// Handles Enter/`o` key on a workload resource: yaml, drill-down to pods, or aggregate logs.
 macro_rules! handle_workload_action {
   ($key:expr, $app:expr, $field:ident, $kind:expr) => {
-    if $key == DEFAULT_KEYBINDING.aggregate_logs.key {
+    if $key == DEFAULT_KEYBINDING.jump_to_logs.key {
+      if let Some(res) = $app.data.$field.get_selected_item_copy() {
+        jump_to_logs($app, &res, $kind).await;
+      }
+    } else if $key == DEFAULT_KEYBINDING.aggregate_logs.key {
       // `o` key — aggregate logs from all pods
       if let Some(res) = $app.data.$field.get_selected_item_copy() {
         if let Some(selector) = res.pod_label_selector() {
@@ -42,18 +59,7 @@ macro_rules! handle_workload_action {
         }
       }
     } else if let Some(res) = handle_block_action($key, &$app.data.$field) {
-      let ok = handle_describe_decode_or_yaml_action(
-        $key,
-        $app,
-        &res,
-        IoCmdEvent::GetDescribe {
-          kind: $kind.to_owned(),
-          value: res.name.to_owned(),
-          ns: Some(res.namespace.to_owned()),
-        },
-      )
-      .await;
-      if !ok {
+      if !handle_decode_or_yaml_action($key, $app, &res).await {
         // Enter key pressed — drill down to the resource's pods
         if let Some(selector) = res.pod_label_selector() {
           $app
@@ -70,33 +76,19 @@ macro_rules! handle_workload_action {
   };
 }
 
-/// Dispatches block action (describe/yaml/decode) for standard resource types.
-/// Wraps the entire match expression. Special-case arms go in the `extra` block.
+/// Dispatches block action (yaml/decode, describe on Enter) for standard
+/// resource types. Wraps the entire match expression. Special-case arms go in
+/// the `extra` block.
 macro_rules! handle_resource_action {
   ($match_expr:expr, $key:expr, $app:expr,
-    namespaced: [ $(($block:path, $field:ident, $kind:expr)),* $(,)? ],
-    cluster: [ $(($cblock:path, $cfield:ident, $ckind:expr)),* $(,)? ],
+    [ $(($block:path, $field:ident)),* $(,)? ],
     extra: { $($extra_arms:tt)* }
   ) => {
     match $match_expr {
       $(
         $block => {
           if let Some(res) = handle_block_action($key, &$app.data.$field) {
-            handle_leaf_resource_action(
-              $key,
-              $app,
-              &res,
-              $kind.to_owned(),
-              Some(res.namespace.to_owned()),
-            )
-            .await;
-          }
-        }
-      )*
-      $(
-        $cblock => {
-          if let Some(res) = handle_block_action($key, &$app.data.$cfield) {
-            handle_leaf_resource_action($key, $app, &res, $ckind.to_owned(), None).await;
+            handle_leaf_resource_action($key, $app, &res).await;
           }
         }
       )*
@@ -122,6 +114,19 @@ macro_rules! handle_resource_scroll {
 }
 
 pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
+  handle_key(key, key_event, app).await;
+  // A change the `confirm` config does not ask about runs as soon as its
+  // confirmation would have opened.
+  if app
+    .modal
+    .as_ref()
+    .is_some_and(|modal| !app.needs_confirmation(&modal.on_confirm))
+  {
+    confirm_modal(app).await;
+  }
+}
+
+async fn handle_key(key: Key, key_event: KeyEvent, app: &mut App) {
   let _ = key_event;
 
   // Overlay modals and the action menu consume input before anything else.
@@ -137,10 +142,51 @@ pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
     handle_action_menu_key(key, app).await;
     return;
   }
+  if app.finder.is_some() {
+    handle_finder_key(key, app);
+    return;
+  }
+  if app.ns_picker.is_some() {
+    handle_ns_picker_key(key, app).await;
+    return;
+  }
+  if key == DEFAULT_KEYBINDING.paste_from_clipboard.key && text_input_active(app) {
+    paste_from_clipboard(app);
+    return;
+  }
   if app.show_port_forwards {
     handle_port_forwards_key(key, app).await;
     return;
   }
+  if app.show_audit_log {
+    handle_audit_log_key(key, app);
+    return;
+  }
+  if app.connection_info.is_some() {
+    if key == DEFAULT_KEYBINDING.esc.key || key == DEFAULT_KEYBINDING.connection_info.key {
+      app.close_connection_info();
+    }
+    return;
+  }
+  if app.show_api_stats {
+    if key == DEFAULT_KEYBINDING.esc.key || key == DEFAULT_KEYBINDING.api_stats.key {
+      app.show_api_stats = false;
+    }
+    return;
+  }
+  if app.apply_status.is_some() {
+    if key == DEFAULT_KEYBINDING.esc.key {
+      app.close_apply_status();
+    }
+    return;
+  }
+  if app.cmd_output.is_some() {
+    handle_cmd_output_key(key, app);
+    return;
+  }
+
+  // Grouped pods act on the pod of the selected row, if any.
+  app.sync_grouped_pod_selection();
 
   let resource_filter_active = app
     .current_resource_table()
@@ -159,11 +205,30 @@ pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
     && handle_namespace_filter_key(key, app)
   {
     // Namespace filter captured the key — done
+  } else if app.get_current_route().active_block == ActiveBlock::Help
+    && app.help_filter_active
+    && handle_help_filter_key(key, app)
+  {
+    // Help filter captured the key — done
+  } else if app.get_current_route().active_block == ActiveBlock::Logs
+    && app.data.logs.search_active
+    && handle_log_search_key(key, app)
+  {
+    // Log search captured the key — done
+  } else if app
+    .goto_pending
+    .take()
+    .is_some_and(|at| at.elapsed() < GOTO_TIMEOUT)
+  {
+    handle_goto_key(key, app);
+  } else if key == DEFAULT_KEYBINDING.goto_view.key && !groups_on_goto_key(app) {
+    app.goto_pending = Some(Instant::now());
+    app.set_status_message(goto_hint());
   } else {
     // First handle any global event and then move to route event
     match key {
       _ if key == DEFAULT_KEYBINDING.esc.key => {
-        handle_escape(app);
+        handle_escape(app).await;
       }
       _ if key == DEFAULT_KEYBINDING.quit.key || key == DEFAULT_KEYBINDING.quit.alt.unwrap() => {
         app.should_quit = true;
@@ -192,7 +257,12 @@ pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
       _ if key == DEFAULT_KEYBINDING.home.key => {
         handle_block_scroll(app, ScrollEvent::Absolute(0), false).await;
       }
-      _ if key == DEFAULT_KEYBINDING.end.key => {
+      _ if (key == DEFAULT_KEYBINDING.end.key || Some(key) == DEFAULT_KEYBINDING.end.alt)
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        jump_to_log_bottom(app).await;
+      }
+      _ if key == DEFAULT_KEYBINDING.end.key || Some(key) == DEFAULT_KEYBINDING.end.alt => {
         handle_block_scroll(app, ScrollEvent::End, false).await;
       }
       // In the log view 't'/'w' toggle log options instead of theme/wide.
@@ -207,6 +277,33 @@ pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
       {
         app.log_wrap = !app.log_wrap;
       }
+      _ if key == DEFAULT_KEYBINDING.toggle_log_json.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        app.log_json = !app.log_json;
+      }
+      _ if key == DEFAULT_KEYBINDING.log_since.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        open_log_since_input(app);
+      }
+      _ if key == DEFAULT_KEYBINDING.log_mark.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        open_log_mark_input(app);
+      }
+      _ if key == DEFAULT_KEYBINDING.log_search.key
+        && app.get_current_route().active_block == ActiveBlock::Logs =>
+      {
+        start_log_search(app);
+      }
+      _ if (key == DEFAULT_KEYBINDING.log_search_next.key
+        || key == DEFAULT_KEYBINDING.log_search_prev.key)
+        && app.get_current_route().active_block == ActiveBlock::Logs
+        && !app.data.logs.search.is_empty() =>
+      {
+        jump_to_log_match(app, key == DEFAULT_KEYBINDING.log_search_next.key);
+      }
       _ if key == DEFAULT_KEYBINDING.toggle_theme.key => {
         app.cycle_theme();
         app.set_status_message(format!("Theme: {}", app.palette.name));
@@ -218,15 +315,68 @@ pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
       _ if key == DEFAULT_KEYBINDING.toggle_wide_columns.key => {
         app.wide_columns = !app.wide_columns;
       }
+      _ if key == DEFAULT_KEYBINDING.cycle_layout.key => {
+        app.cycle_layout();
+      }
+      _ if key == DEFAULT_KEYBINDING.cycle_footer.key => {
+        app.cycle_footer_verbosity();
+      }
+      _ if key == DEFAULT_KEYBINDING.toggle_follow_selection.key => {
+        app.toggle_follow_selection();
+      }
+      _ if key == DEFAULT_KEYBINDING.toggle_problems_only.key => {
+        app.toggle_problems_only();
+      }
+      _ if key == DEFAULT_KEYBINDING.toggle_short_names.key => {
+        app.toggle_short_names();
+      }
+      _ if key == DEFAULT_KEYBINDING.apply_from_clipboard.key => {
+        apply_from_clipboard(app);
+      }
       _ if key == DEFAULT_KEYBINDING.refresh.key => {
         app.refresh();
       }
+      _ if key == DEFAULT_KEYBINDING.toggle_polling.key => {
+        app.polling_paused = !app.polling_paused;
+        app.set_status_message(if app.polling_paused {
+          format!(
+            "Auto-refresh paused, {} to refresh once",
+            DEFAULT_KEYBINDING.poll_now.key.symbol()
+          )
+        } else {
+          "Auto-refresh resumed".into()
+        });
+      }
+      _ if key == DEFAULT_KEYBINDING.poll_now.key => {
+        app.set_status_message("Refreshing...");
+        app.poll_current_route(true).await;
+      }
+      _ if key == DEFAULT_KEYBINDING.retry_failed.key && app.current_failed_event().is_some() => {
+        app.retry_failed_fetch().await;
+      }
       _ if key == DEFAULT_KEYBINDING.dump_error_log.key => {
         dump_error_history(app, None);
       }
+      _ if key == DEFAULT_KEYBINDING.audit_log.key => {
+        app.open_audit_log();
+      }
+      _ if key == DEFAULT_KEYBINDING.connection_info.key => {
+        app.open_connection_info();
+      }
+      _ if key == DEFAULT_KEYBINDING.toggle_redact.key => {
+        app.toggle_redact();
+      }
+      _ if key == DEFAULT_KEYBINDING.api_stats.key => {
+        app.show_api_stats = true;
+      }
+      _ if key == DEFAULT_KEYBINDING.snapshot_namespace.key => {
+        app.request_snapshot().await;
+      }
       _ if key == DEFAULT_KEYBINDING.help.key => {
         if app.get_current_route().active_block != ActiveBlock::Help {
           app.help_scroll = 0;
+          app.help_filter.clear();
+          app.help_filter_active = false;
           app.push_navigation_stack(RouteId::HelpMenu, ActiveBlock::Help);
         }
       }
@@ -242,6 +392,12 @@ pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
       _ if key == DEFAULT_KEYBINDING.jump_to_troubleshoot.key => {
         app.route_troubleshoot();
       }
+      _ if key == DEFAULT_KEYBINDING.jump_to_dashboard.key => {
+        app.route_dashboard();
+      }
+      _ if key == DEFAULT_KEYBINDING.jump_to_watchlist.key => {
+        app.route_watchlist();
+      }
       _ if key == DEFAULT_KEYBINDING.cycle_main_views.key => {
         app.cycle_main_routes();
       }
@@ -258,24 +414,98 @@ pub async fn handle_key_events(key: Key, key_event: KeyEvent, app: &mut App) {
       _ if key == DEFAULT_KEYBINDING.port_forwards_list.key => {
         app.open_port_forwards();
       }
-      _ => handle_route_events(key, app).await,
+      _ if key == DEFAULT_KEYBINDING.find_resource.key => {
+        app.open_finder();
+      }
+      _ if key == DEFAULT_KEYBINDING.pick_namespace.key => {
+        app.open_ns_picker();
+      }
+      _ if key == DEFAULT_KEYBINDING.copy_row.key => {
+        app.request_row_copy();
+      }
+      _ if key == DEFAULT_KEYBINDING.copy_kubectl_command.key => {
+        copy_kubectl_command(app);
+      }
+      _ if key == DEFAULT_KEYBINDING.export_csv.key => {
+        app.request_export(ExportFormat::Csv);
+      }
+      _ if key == DEFAULT_KEYBINDING.export_json.key => {
+        app.request_export(ExportFormat::Json);
+      }
+      // macros come after the global keys and before the view's own
+      _ => match app.macro_for_key(key) {
+        Some(config) => app.open_macro(&config),
+        None => handle_route_events(key, app).await,
+      },
+    }
+  }
+}
+
+/// How long `goto_view` waits for its second key.
+const GOTO_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// `g` groups rows in the events and utilization views instead.
+fn groups_on_goto_key(app: &App) -> bool {
+  let route = app.get_current_route();
+  route.id == RouteId::Utilization
+    || (route.id == RouteId::Home && route.active_block == ActiveBlock::Events)
+}
+
+/// `g: p pods · s services · n nodes · d deployments`
+fn goto_hint() -> String {
+  let views: Vec<String> = GOTO_VIEWS
+    .iter()
+    .map(|(code, _, view)| format!("{} {}", code, view))
+    .collect();
+  format!(
+    "{}: {}",
+    DEFAULT_KEYBINDING.goto_view.key.symbol(),
+    views.join(" · ")
+  )
+}
+
+/// The key after `goto_view`: open its view, or say which keys there are.
+fn handle_goto_key(key: Key, app: &mut App) {
+  let view = GOTO_VIEWS
+    .iter()
+    .find(|(code, _, _)| key == Key::Char(*code));
+  match view {
+    Some((_, index, _)) => {
+      app.main_tabs.set_index(0);
+      select_context_tab(app, *index);
+      app.clear_status_message();
     }
+    None if key == DEFAULT_KEYBINDING.esc.key => app.clear_status_message(),
+    None => app.set_status_message(format!(
+      "No view to go to with {}. {}",
+      key.symbol(),
+      goto_hint()
+    )),
   }
 }
 
 /// Handle keys while a confirmation modal overlay is active.
 async fn handle_modal_key(key: Key, app: &mut App) {
   if key == Key::Char('y') || key == DEFAULT_KEYBINDING.submit.key {
-    if let Some(modal) = app.modal.take() {
-      app.dispatch(modal.on_confirm).await;
-      // Refresh the affected view promptly after a write.
-      app.tick_count = 0;
-    }
+    confirm_modal(app).await;
   } else if key == Key::Char('n') || key == DEFAULT_KEYBINDING.esc.key {
     app.close_modal();
   }
 }
 
+/// Dispatch what the open confirmation modal guards and close it.
+async fn confirm_modal(app: &mut App) {
+  if let Some(modal) = app.modal.take() {
+    match modal.on_confirm {
+      ConfirmAction::Io(event) => app.dispatch(event).await,
+      ConfirmAction::Cmd(event) => app.dispatch_cmd(event).await,
+      ConfirmAction::Macro { name, events } => app.run_macro(name, events).await,
+    }
+    // Refresh the affected view promptly after a write.
+    app.tick_count = 0;
+  }
+}
+
 /// Handle keys while a single-line input overlay is active. Printable chars edit
 /// the buffer, `Enter` validates (chaining into a confirm modal on success or
 /// showing an inline error on failure), `Esc` cancels.
@@ -299,6 +529,25 @@ async fn handle_input_modal_key(key: Key, app: &mut App) {
           .start_port_forward(kind, namespace, name, local_port, remote_port)
           .await;
       }
+      Some(Ok(InputSubmit::SetLabelSelector(selector))) => {
+        app.close_input_modal();
+        set_label_selector(app, selector).await;
+      }
+      Some(Ok(InputSubmit::CreateNamespace(name))) => {
+        app.close_input_modal();
+        app.dispatch(IoEvent::CreateNamespace { name }).await;
+      }
+      Some(Ok(InputSubmit::AddLogMark(note))) => {
+        app.close_input_modal();
+        app.mark_logs(&note, utils::now());
+      }
+      Some(Ok(InputSubmit::SetLogSince(since))) => {
+        app.close_input_modal();
+        if app.log_since != since {
+          app.log_since = since;
+          app.restream_logs().await;
+        }
+      }
       Some(Err(err)) => {
         if let Some(input) = app.input_modal.as_mut() {
           input.error = Some(err);
@@ -314,6 +563,10 @@ async fn handle_input_modal_key(key: Key, app: &mut App) {
         input.buffer.push(c);
         input.error = None;
       }
+      Key::Shift(c) => {
+        input.buffer.push(c.to_ascii_uppercase());
+        input.error = None;
+      }
       Key::Backspace => {
         input.buffer.pop();
         input.error = None;
@@ -361,6 +614,9 @@ fn selected_target(app: &App, block: ActiveBlock) -> Option<(String, Option<Stri
     ActiveBlock::Ingresses => namespaced!(ingress),
     ActiveBlock::PersistentVolumeClaims => namespaced!(persistent_volume_claims),
     ActiveBlock::NetworkPolicies => namespaced!(network_policies),
+    ActiveBlock::ResourceQuotas => namespaced!(resource_quotas),
+    ActiveBlock::LimitRanges => namespaced!(limit_ranges),
+    ActiveBlock::PodDisruptionBudgets => namespaced!(pdbs),
     ActiveBlock::ServiceAccounts => namespaced!(service_accounts),
     ActiveBlock::Events => namespaced!(events),
     ActiveBlock::Nodes => cluster!(nodes),
@@ -368,6 +624,7 @@ fn selected_target(app: &App, block: ActiveBlock) -> Option<(String, Option<Stri
     ActiveBlock::StorageClasses => cluster!(storage_classes),
     ActiveBlock::ClusterRoles => cluster!(cluster_roles),
     ActiveBlock::ClusterRoleBindings => cluster!(cluster_role_bindings),
+    ActiveBlock::CustomResourceDefinitions => cluster!(crds),
     ActiveBlock::DynamicResource => app
       .data
       .dynamic_resources
@@ -377,53 +634,135 @@ fn selected_target(app: &App, block: ActiveBlock) -> Option<(String, Option<Stri
   }
 }
 
-/// Human-readable kind label for confirmation prompts.
-fn resource_kind_label(app: &App, block: ActiveBlock) -> String {
-  let label = match block {
-    ActiveBlock::Pods => "pod",
-    ActiveBlock::Services => "service",
-    ActiveBlock::ConfigMaps => "configmap",
-    ActiveBlock::Secrets => "secret",
-    ActiveBlock::StatefulSets => "statefulset",
-    ActiveBlock::ReplicaSets => "replicaset",
-    ActiveBlock::Deployments => "deployment",
-    ActiveBlock::Jobs => "job",
-    ActiveBlock::DaemonSets => "daemonset",
-    ActiveBlock::CronJobs => "cronjob",
-    ActiveBlock::ReplicationControllers => "replicationcontroller",
-    ActiveBlock::Roles => "role",
-    ActiveBlock::RoleBindings => "rolebinding",
-    ActiveBlock::Ingresses => "ingress",
-    ActiveBlock::PersistentVolumeClaims => "persistentvolumeclaim",
-    ActiveBlock::NetworkPolicies => "networkpolicy",
-    ActiveBlock::ServiceAccounts => "serviceaccount",
-    ActiveBlock::Events => "event",
-    ActiveBlock::Nodes => "node",
-    ActiveBlock::PersistentVolumes => "persistentvolume",
-    ActiveBlock::StorageClasses => "storageclass",
-    ActiveBlock::ClusterRoles => "clusterrole",
-    ActiveBlock::ClusterRoleBindings => "clusterrolebinding",
-    ActiveBlock::DynamicResource => {
-      return app
-        .data
-        .selected
-        .dynamic_kind
-        .as_ref()
-        .map(|kind| kind.kind.to_lowercase())
-        .unwrap_or_else(|| "resource".to_owned());
-    }
-    _ => "resource",
+/// Open the selected row in the web console configured for the active
+/// context, or explain how to configure one.
+fn open_in_console(app: &mut App) {
+  let block = app.get_current_route().active_block;
+  let Some((name, namespace)) = selected_target(app, block) else {
+    return;
+  };
+  let context = app.data.active_context.as_ref();
+  let Some(template) = app
+    .config
+    .console_url_for(context.map(|ctx| ctx.name.as_str()))
+  else {
+    app.set_status_message(console::NO_CONSOLE_URL);
+    return;
+  };
+  let kind = app.resource_kind_label(block);
+  let url = console::console_url(
+    template,
+    &ConsoleTarget {
+      context: context.map_or("", |ctx| ctx.name.as_str()),
+      cluster: context.map_or("", |ctx| ctx.cluster.as_str()),
+      namespace: namespace.as_deref().unwrap_or_default(),
+      kind: &kind,
+      name: &name,
+    },
+  );
+  match open::that_detached(&url) {
+    Ok(()) => app.set_status_message(format!("Opening {}", url)),
+    Err(error) => app.handle_error(anyhow!("Unable to open {}: {}", url, error)),
+  }
+}
+
+/// Pin the selected row to the watchlist, or unpin it. On the watchlist
+/// itself the selected pin is unpinned.
+fn toggle_pin_selected(app: &mut App) {
+  let block = app.get_current_route().active_block;
+  let pin = if block == ActiveBlock::Watchlist {
+    app
+      .data
+      .watchlist
+      .get_selected_item_copy()
+      .map(|row| row.pin)
+  } else if let Some((name, namespace)) = selected_target(app, block) {
+    let Some(pin) = app.pin_for(block, name, namespace) else {
+      app.set_status_message("Resources of this view can't be pinned");
+      return;
+    };
+    Some(pin)
+  } else {
+    None
+  };
+  if let Some(pin) = pin {
+    app.toggle_pin(pin);
+  }
+}
+
+/// Add the selected row of the current block to its batch selection, or drop
+/// it if it is already selected.
+fn toggle_selected_row(app: &mut App) {
+  let block = app.get_current_route().active_block;
+  let Some((name, namespace)) = selected_target(app, block) else {
+    return;
+  };
+  let Some(table) = app.resource_table_mut(block) else {
+    return;
+  };
+  table.toggle_marked(MarkedRow { name, namespace });
+  let count = table.marked().len();
+  app.set_status_message(format!("{} selected", count));
+}
+
+/// Shift the current table's columns by one. Scrolling right past the last
+/// column is clamped when the table is drawn.
+fn scroll_table_columns(app: &mut App, right: bool) {
+  let block = app.get_current_route().active_block;
+  let pin_name = app.pin_name_column();
+  let Some(table) = app.resource_table_mut(block) else {
+    return;
+  };
+  let scroll = table.column_scroll_mut();
+  scroll.pin_name = pin_name;
+  scroll.offset = if right {
+    scroll.offset + 1
+  } else {
+    scroll.offset.saturating_sub(1)
   };
-  label.to_owned()
 }
 
-/// Open a delete-confirmation modal for the selected row in the current block.
+/// Open a delete-confirmation modal for the selected rows of the current
+/// block, or for the row under the cursor when none are selected.
 async fn handle_delete_resource(app: &mut App) {
   let block = app.get_current_route().active_block;
+  if block == ActiveBlock::Namespaces {
+    if let Some(ns) = app.data.namespaces.get_selected_item_copy() {
+      app.open_modal(Modal::confirm(
+        "Confirm delete",
+        format!(
+          "Delete namespace '{}' and everything in it? This cannot be undone.",
+          ns.name
+        ),
+        IoEvent::DeleteNamespace { name: ns.name },
+      ));
+    }
+    return;
+  }
+  let marked = app
+    .resource_table(block)
+    .map(|table| table.marked().to_vec())
+    .unwrap_or_default();
+  if !marked.is_empty() {
+    let kind = app.resource_kind_label(block);
+    app.open_modal(Modal::confirm(
+      "Confirm delete",
+      format!(
+        "Delete {} selected {}(s)? This cannot be undone.",
+        marked.len(),
+        kind
+      ),
+      IoEvent::DeleteResources {
+        block,
+        rows: marked,
+      },
+    ));
+    return;
+  }
   let Some((name, namespace)) = selected_target(app, block) else {
     return;
   };
-  let kind = resource_kind_label(app, block);
+  let kind = app.resource_kind_label(block);
   let prompt = match &namespace {
     Some(ns) => format!(
       "Delete {} '{}' in namespace '{}'? This cannot be undone.",
@@ -455,7 +794,7 @@ async fn handle_restart_resource(app: &mut App) {
   let Some((name, namespace)) = selected_target(app, block) else {
     return;
   };
-  let kind = resource_kind_label(app, block);
+  let kind = app.resource_kind_label(block);
   let prompt = match &namespace {
     Some(ns) => format!("Rollout restart {} '{}' in namespace '{}'?", kind, name, ns),
     None => format!("Rollout restart {} '{}'?", kind, name),
@@ -521,7 +860,7 @@ fn handle_scale_resource(app: &mut App) {
   let Some((name, namespace)) = selected_target(app, block) else {
     return;
   };
-  let kind = resource_kind_label(app, block);
+  let kind = app.resource_kind_label(block);
   let buffer = current_replicas(app, block)
     .map(|n| n.to_string())
     .unwrap_or_default();
@@ -539,6 +878,27 @@ fn handle_scale_resource(app: &mut App) {
   });
 }
 
+/// Ask which revision to roll the selected deployment back to; the confirm
+/// that follows runs `kubectl rollout undo`.
+fn handle_rollout_undo(app: &mut App) {
+  let Some(deployment) = app.data.deployments.get_selected_item_copy() else {
+    return;
+  };
+  app.open_input_modal(InputModal {
+    title: "Rollout undo".to_owned(),
+    prompt: format!(
+      "Revision to roll deployment '{}' back to (empty for the previous one):",
+      deployment.name
+    ),
+    buffer: String::new(),
+    error: None,
+    action: InputAction::RolloutUndo {
+      name: deployment.name,
+      namespace: deployment.namespace,
+    },
+  });
+}
+
 /// Handle keys while the `m` action menu overlay is active.
 async fn handle_action_menu_key(key: Key, app: &mut App) {
   match key {
@@ -575,11 +935,68 @@ async fn handle_action_menu_key(key: Key, app: &mut App) {
   }
 }
 
+/// Handle keys while the resource finder is open. Printable chars edit the
+/// query, so only the arrow keys move the selection.
+fn handle_finder_key(key: Key, app: &mut App) {
+  let Some(finder) = app.finder.as_mut() else {
+    return;
+  };
+  match key {
+    _ if key == DEFAULT_KEYBINDING.esc.key => app.close_finder(),
+    _ if key == DEFAULT_KEYBINDING.submit.key => {
+      let selected = finder.selected().cloned();
+      app.close_finder();
+      if let Some(entry) = selected {
+        app.jump_to_finder_entry(&entry);
+      }
+    }
+    Key::Up => finder.handle_scroll(ScrollEvent::up()),
+    Key::Down => finder.handle_scroll(ScrollEvent::down()),
+    Key::PageUp => finder.handle_scroll(ScrollEvent::Relative(-10)),
+    Key::PageDown => finder.handle_scroll(ScrollEvent::Relative(10)),
+    Key::Char(c) => finder.push(c),
+    Key::Shift(c) => finder.push(c.to_ascii_uppercase()),
+    Key::Backspace => finder.pop(),
+    _ => {}
+  }
+}
+
+/// Handle keys while the namespace picker is open, like the finder. Enter
+/// switches the namespaced views to the selected namespace.
+async fn handle_ns_picker_key(key: Key, app: &mut App) {
+  let Some(picker) = app.ns_picker.as_mut() else {
+    return;
+  };
+  match key {
+    _ if key == DEFAULT_KEYBINDING.esc.key || key == DEFAULT_KEYBINDING.pick_namespace.key => {
+      app.close_ns_picker()
+    }
+    _ if key == DEFAULT_KEYBINDING.submit.key => {
+      let selected = picker.selected().cloned();
+      app.close_ns_picker();
+      if let Some(choice) = selected {
+        app.switch_namespace(choice.namespace()).await;
+      }
+    }
+    Key::Up => picker.handle_scroll(ScrollEvent::up()),
+    Key::Down => picker.handle_scroll(ScrollEvent::down()),
+    Key::PageUp => picker.handle_scroll(ScrollEvent::Relative(-10)),
+    Key::PageDown => picker.handle_scroll(ScrollEvent::Relative(10)),
+    Key::Char(c) => picker.push(c),
+    Key::Shift(c) => picker.push(c.to_ascii_uppercase()),
+    Key::Backspace => picker.pop(),
+    _ => {}
+  }
+}
+
 /// Dispatch a menu-selected action. Hotkey-backed actions replay their key so
 /// the menu and hotkey share one path; menu-only actions are handled directly.
 async fn execute_resource_action(action: ResourceAction, app: &mut App) {
   match action {
     ResourceAction::Scale => handle_scale_resource(app),
+    ResourceAction::RolloutUndo => handle_rollout_undo(app),
+    ResourceAction::Evict => handle_pod_evict(app),
+    ResourceAction::RestartCrashLooping => handle_restart_crash_looping(app),
     ResourceAction::Cordon => handle_cordon_toggle(app).await,
     ResourceAction::Suspend => handle_cronjob_suspend_toggle(app).await,
     ResourceAction::Trigger => handle_cronjob_trigger(app).await,
@@ -615,6 +1032,76 @@ async fn handle_cronjob_suspend_toggle(app: &mut App) {
   ));
 }
 
+/// Open a confirmation to evict the selected pod.
+fn handle_pod_evict(app: &mut App) {
+  let Some(pod) = app.data.pods.get_selected_item_copy() else {
+    return;
+  };
+  app.open_modal(Modal::confirm(
+    "Confirm eviction",
+    format!(
+      "Evict pod '{}' in namespace '{}'? Its PodDisruptionBudgets must allow it.",
+      pod.name, pod.namespace
+    ),
+    IoEvent::EvictPod {
+      name: pod.name.clone(),
+      namespace: pod.namespace.clone(),
+    },
+  ));
+}
+
+/// Open a confirmation to delete every crash-looping pod of the selected
+/// namespace that a controller will recreate. Standalone pods are left alone,
+/// since nothing would bring them back.
+fn handle_restart_crash_looping(app: &mut App) {
+  let Some(namespace) = app.data.selected.ns.clone() else {
+    app.set_status_message("Pick a namespace to restart its crash-looping pods");
+    return;
+  };
+  let crash_looping: Vec<&pods::KubePod> = app
+    .data
+    .pods
+    .items
+    .iter()
+    .filter(|pod| pod.namespace == namespace && pod.is_crash_looping())
+    .collect();
+  let rows: Vec<MarkedRow> = crash_looping
+    .iter()
+    .filter(|pod| pod.is_controller_managed())
+    .map(|pod| MarkedRow {
+      name: pod.name.clone(),
+      namespace: Some(pod.namespace.clone()),
+    })
+    .collect();
+  let standalone = crash_looping.len() - rows.len();
+  if rows.is_empty() {
+    app.set_status_message(if standalone == 0 {
+      format!("No crash-looping pods in namespace '{}'", namespace)
+    } else {
+      format!(
+        "The {} crash-looping pod(s) in namespace '{}' have no controller to recreate them",
+        standalone, namespace
+      )
+    });
+    return;
+  }
+  let skipped = if standalone == 0 {
+    String::new()
+  } else {
+    format!(" {} without a controller are left alone.", standalone)
+  };
+  app.open_modal(Modal::confirm(
+    "Confirm restart",
+    format!(
+      "Delete {} crash-looping pod(s) in namespace '{}' so their controllers recreate them?{}",
+      rows.len(),
+      namespace,
+      skipped
+    ),
+    IoEvent::RestartPods { rows },
+  ));
+}
+
 /// Open a confirmation to trigger an immediate run of the selected cronjob.
 async fn handle_cronjob_trigger(app: &mut App) {
   let Some(cronjob) = app.data.cronjobs.get_selected_item_copy() else {
@@ -665,14 +1152,36 @@ pub async fn handle_mouse_events(mouse: MouseEvent, app: &mut App) {
     // which calls [inverse_dir] where intended for mouse input
     MouseEventKind::ScrollDown => handle_block_scroll(app, ScrollEvent::down(), true).await,
     MouseEventKind::ScrollUp => handle_block_scroll(app, ScrollEvent::up(), true).await,
+    MouseEventKind::Down(MouseButton::Left) => handle_tab_click(app, mouse.column, mouse.row),
     _ => {}
   }
 }
 
-fn handle_escape(app: &mut App) {
+/// Clicking a main or resource tab title selects it, like its jump key.
+/// Clicks elsewhere, or while an overlay is open, are ignored.
+fn handle_tab_click(app: &mut App, column: u16, row: u16) {
+  if app.modal.is_some()
+    || app.input_modal.is_some()
+    || app.action_menu.is_some()
+    || app.finder.is_some()
+    || app.ns_picker.is_some()
+  {
+    return;
+  }
+  if let Some(index) = app.main_tabs.tab_at(column, row) {
+    let route = app.main_tabs.set_index(index).route.clone();
+    app.set_route(route);
+  } else if let Some(index) = app.context_tabs.tab_at(column, row) {
+    select_context_tab(app, index);
+  }
+}
+
+async fn handle_escape(app: &mut App) {
   // dismiss error
   if !app.api_error.is_empty() {
     app.api_error = String::default();
+  } else if let Some(alerts) = app.pod_alerts.as_mut().filter(|a| !a.toast.is_empty()) {
+    alerts.toast.clear();
   } else if !app.status_message.is_empty() {
     app.clear_status_message();
   }
@@ -688,6 +1197,18 @@ fn handle_escape(app: &mut App) {
     return;
   }
 
+  if app.get_current_route().active_block == ActiveBlock::Help && app.help_filter_active {
+    clear_or_deactivate_filter(&mut app.help_filter, &mut app.help_filter_active);
+    return;
+  }
+
+  if app.get_current_route().active_block == ActiveBlock::Logs
+    && (app.data.logs.search_active || !app.data.logs.search.is_empty())
+  {
+    app.data.logs.clear_search();
+    return;
+  }
+
   if let Some((filter, filter_active, _)) = app.current_resource_filter_mut() {
     if *filter_active {
       clear_or_deactivate_filter(filter, filter_active);
@@ -708,15 +1229,30 @@ fn handle_escape(app: &mut App) {
     _ => match app.get_current_route().active_block {
       ActiveBlock::Namespaces
       | ActiveBlock::Containers
+      | ActiveBlock::ConfigMapData
       | ActiveBlock::Yaml
-      | ActiveBlock::Describe => {
+      | ActiveBlock::Describe
+      | ActiveBlock::Diff => {
         app.pop_navigation_stack();
       }
+      ActiveBlock::Pods
+        if app.data.selected.pod_selector.is_none()
+          && app.data.selected.label_selector.is_some() =>
+      {
+        set_label_selector(app, None).await;
+      }
       ActiveBlock::Pods if app.data.selected.pod_selector.is_some() => {
         // Exiting a filtered pod view from workload drill-down
         app.data.selected.pod_selector = None;
         app.data.selected.pod_selector_ns = None;
         app.data.selected.pod_selector_resource = None;
+        app.stop_following_rollout();
+        app.pop_navigation_stack();
+      }
+      ActiveBlock::ReplicaSets if app.data.selected.replica_set_owner.is_some() => {
+        // Exiting the ReplicaSets of a deployment drill-down
+        app.data.selected.replica_set_owner = None;
+        app.data.selected.replica_set_owner_ns = None;
         app.pop_navigation_stack();
       }
       ActiveBlock::Logs => {
@@ -779,35 +1315,170 @@ fn handle_filter_text_key(filter: &mut String, key: Key) -> bool {
   }
 }
 
-fn clear_or_deactivate_filter(filter: &mut String, active: &mut bool) {
-  if filter.is_empty() {
-    *active = false;
-  } else {
-    filter.clear();
+/// Start typing a log search. Following is paused so matches stay put while
+/// new lines stream in.
+fn start_log_search(app: &mut App) {
+  app.data.logs.clear_search();
+  app.data.logs.search_active = true;
+  if app.log_auto_scroll {
+    app.data.logs.freeze_follow_position();
+    app.log_auto_scroll = false;
   }
 }
 
-fn handle_resource_filter_key(key: Key, app: &mut App) -> bool {
-  if let Some((filter, _, state)) = app.current_resource_filter_mut() {
-    let handled = handle_filter_text_key(filter, key);
-    if handled {
-      state.select(Some(0));
+fn handle_log_search_key(key: Key, app: &mut App) -> bool {
+  if key == DEFAULT_KEYBINDING.submit.key {
+    app.data.logs.search_active = false;
+    if app.data.logs.search.is_empty() {
+      return true;
     }
-    handled
+    jump_to_log_match(app, true);
+    true
   } else {
-    false
+    handle_filter_text_key(&mut app.data.logs.search, key)
   }
 }
 
-fn handle_namespace_filter_key(key: Key, app: &mut App) -> bool {
-  let handled = handle_filter_text_key(&mut app.ns_filter, key);
-  if handled {
-    app.data.namespaces.state.select(Some(0));
+fn jump_to_log_match(app: &mut App, forward: bool) {
+  if !app.data.logs.jump_to_match(forward) {
+    app.set_status_message(format!("No log lines match '{}'", app.data.logs.search));
   }
-  handled
 }
 
-fn get_active_menu_mut(app: &mut App) -> &mut StatefulList<(String, ActiveBlock)> {
+/// Jump to the newest log line and follow from there. A search keeps the view
+/// paused on the bottom so its matches stay put.
+async fn jump_to_log_bottom(app: &mut App) {
+  if app.data.logs.search.is_empty() {
+    app.log_auto_scroll = true;
+  } else {
+    handle_block_scroll(app, ScrollEvent::End, false).await;
+    app.set_status_message("Clear the log search with Esc to resume auto scroll");
+  }
+}
+
+fn toggle_log_auto_scroll(app: &mut App) {
+  if !app.log_auto_scroll && !app.data.logs.search.is_empty() {
+    app.set_status_message("Clear the log search with Esc to resume auto scroll");
+    return;
+  }
+  if app.log_auto_scroll {
+    app.data.logs.freeze_follow_position();
+  }
+  app.log_auto_scroll = !app.log_auto_scroll;
+}
+
+/// Whether a key press would be typed into an input: a prompt, the finder, the
+/// namespace picker, a filter or the log search.
+fn text_input_active(app: &App) -> bool {
+  let active_block = app.get_current_route().active_block;
+  app.input_modal.is_some()
+    || app.finder.is_some()
+    || app.ns_picker.is_some()
+    || (app.is_menu_active() && app.menu_filter_active)
+    || app
+      .current_resource_table()
+      .is_some_and(|table| table.is_filter_active())
+    || (active_block == ActiveBlock::Namespaces && app.ns_filter_active)
+    || (active_block == ActiveBlock::Help && app.help_filter_active)
+    || (active_block == ActiveBlock::Logs && app.data.logs.search_active)
+}
+
+/// Line breaks and tabs become spaces, as every input here is a single line.
+fn flatten_paste(text: &str) -> String {
+  text
+    .trim_end_matches(['\r', '\n'])
+    .replace("\r\n", " ")
+    .replace(['\r', '\n', '\t'], " ")
+}
+
+/// Type pasted text into the active input, one character at a time so each
+/// input reacts as it does to typing. Pastes outside an input are dropped
+/// rather than replayed as keybindings.
+pub fn handle_paste(text: &str, app: &mut App) {
+  if app.modal.is_some() || !text_input_active(app) {
+    return;
+  }
+  for c in flatten_paste(text).chars() {
+    let key = Key::Char(c);
+    if let Some(input) = app.input_modal.as_mut() {
+      input.buffer.push(c);
+      input.error = None;
+    } else if app.finder.is_some() {
+      handle_finder_key(key, app);
+    } else if let Some(picker) = app.ns_picker.as_mut() {
+      picker.push(c);
+    } else if app.is_menu_active() && app.menu_filter_active {
+      handle_menu_filter_key(key, app);
+    } else if app.get_current_route().active_block == ActiveBlock::Namespaces
+      && app.ns_filter_active
+    {
+      handle_namespace_filter_key(key, app);
+    } else if app.get_current_route().active_block == ActiveBlock::Help {
+      handle_help_filter_key(key, app);
+    } else if app.get_current_route().active_block == ActiveBlock::Logs {
+      app.data.logs.search.push(c);
+    } else {
+      handle_resource_filter_key(key, app);
+    }
+  }
+}
+
+fn paste_from_clipboard(app: &mut App) {
+  use copypasta::{ClipboardContext, ClipboardProvider};
+
+  match ClipboardContext::new().and_then(|mut ctx| ctx.get_contents()) {
+    Ok(text) => handle_paste(&text, app),
+    Err(err) => app.handle_error(anyhow!("Unable to read clipboard: {}", err)),
+  }
+}
+
+/// Ask to apply the manifest in the clipboard with `kubectl apply`.
+fn apply_from_clipboard(app: &mut App) {
+  use copypasta::{ClipboardContext, ClipboardProvider};
+
+  match ClipboardContext::new().and_then(|mut ctx| ctx.get_contents()) {
+    Ok(text) => app.open_apply_manifest(text),
+    Err(err) => app.handle_error(anyhow!("Unable to read clipboard: {}", err)),
+  }
+}
+
+fn clear_or_deactivate_filter(filter: &mut String, active: &mut bool) {
+  if filter.is_empty() {
+    *active = false;
+  } else {
+    filter.clear();
+  }
+}
+
+fn handle_resource_filter_key(key: Key, app: &mut App) -> bool {
+  if let Some((filter, _, state)) = app.current_resource_filter_mut() {
+    let handled = handle_filter_text_key(filter, key);
+    if handled {
+      state.select(Some(0));
+    }
+    handled
+  } else {
+    false
+  }
+}
+
+fn handle_namespace_filter_key(key: Key, app: &mut App) -> bool {
+  let handled = handle_filter_text_key(&mut app.ns_filter, key);
+  if handled {
+    app.data.namespaces.state.select(Some(0));
+  }
+  handled
+}
+
+fn handle_help_filter_key(key: Key, app: &mut App) -> bool {
+  let handled = handle_filter_text_key(&mut app.help_filter, key);
+  if handled {
+    app.help_scroll = 0;
+  }
+  handled
+}
+
+fn get_active_menu_mut(app: &mut App) -> &mut StatefulList<(String, ActiveBlock)> {
   match app.get_current_route().active_block {
     ActiveBlock::DynamicView => &mut app.dynamic_resources_menu,
     _ => &mut app.more_resources_menu,
@@ -833,32 +1504,28 @@ pub fn filter_menu_items<'a>(
     .collect()
 }
 
-async fn handle_describe_decode_or_yaml_action<T, S>(
-  key: Key,
-  app: &mut App,
-  res: &T,
-  action: IoCmdEvent,
-) -> bool
+async fn handle_decode_or_yaml_action<T, S>(key: Key, app: &mut App, res: &T) -> bool
 where
   T: KubeResource<S> + 'static,
   S: Serialize,
 {
-  if key == DEFAULT_KEYBINDING.describe_resource.key {
-    app.data.describe_out = ScrollableTxt::new();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Describe);
-    app.dispatch_cmd(action).await;
+  if key == DEFAULT_KEYBINDING.resource_yaml.key {
+    let (yaml, summary) = yaml_and_summary(res, &app.data.events.items);
+    app.open_yaml_view(yaml, summary, RouteId::Home);
     true
-  } else if key == DEFAULT_KEYBINDING.resource_yaml.key {
-    let yaml = res.resource_to_yaml();
-    app.data.describe_out = ScrollableTxt::with_string(yaml);
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Yaml);
+  } else if key == DEFAULT_KEYBINDING.copy_yaml.key {
+    copy_resource_yaml(app, res);
     true
   } else if key == DEFAULT_KEYBINDING.decode_secret.key {
     // make sure the resources is of type 'KubeSecret'
     let of_any = res as &dyn std::any::Any;
     if let Some(secret) = of_any.downcast_ref::<KubeSecret>() {
+      if app.refuse_in_redact_mode() {
+        return true;
+      }
       let display_output = secret.decode_secret();
       app.data.describe_out = ScrollableTxt::with_string(display_output);
+      app.data.pod_constraints = None;
       app.push_navigation_stack(RouteId::Home, ActiveBlock::Describe);
       true
     } else {
@@ -870,40 +1537,551 @@ where
   }
 }
 
-async fn handle_leaf_resource_action<T, S>(
-  key: Key,
-  app: &mut App,
-  res: &T,
-  kind: String,
-  ns: Option<String>,
-) where
+/// Copy a resource's YAML, saying in the toast what was copied and how big it
+/// is, since a large object pastes as a wall of text.
+fn copy_resource_yaml<T, S>(app: &mut App, res: &T)
+where
+  T: KubeResource<S>,
+  S: Serialize,
+{
+  let yaml = res.resource_to_yaml();
+  if yaml.is_empty() {
+    app.handle_error(anyhow!("Unable to render the YAML of this resource"));
+    return;
+  }
+  let message = copied_yaml_message(res.get_k8s_obj(), &yaml);
+  if copy_to_clipboard(yaml, app) {
+    app.set_status_message(message);
+  }
+}
+
+/// `Copied Pod web (42 lines, 1.3 KiB)` for a copied object.
+fn copied_yaml_message<S: Serialize>(obj: &S, yaml: &str) -> String {
+  let obj = serde_json::to_value(obj).unwrap_or_default();
+  let field = |pointer: &str| {
+    obj
+      .pointer(pointer)
+      .and_then(serde_json::Value::as_str)
+      .unwrap_or_default()
+      .to_owned()
+  };
+  let what = [field("/kind"), field("/metadata/name")]
+    .into_iter()
+    .filter(|part| !part.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+  format!(
+    "Copied {} YAML ({} lines, {})",
+    if what.is_empty() { "the" } else { &what },
+    yaml.lines().count(),
+    byte_size(yaml.len())
+  )
+}
+
+fn byte_size(bytes: usize) -> String {
+  match bytes {
+    0..=1023 => format!("{} B", bytes),
+    1024..=1_048_575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+    _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+  }
+}
+
+/// The YAML of a resource and, for kinds that have one, its summary.
+fn yaml_and_summary<T, S>(res: &T, events: &[KubeEvent]) -> (String, Option<String>)
+where
+  T: KubeResource<S>,
+  S: Serialize,
+{
+  let summary = serde_json::to_value(res.get_k8s_obj())
+    .ok()
+    .and_then(|obj| summary::summarize(&obj, events));
+  (res.resource_to_yaml(), summary)
+}
+
+/// Fetch which nodes the selected DaemonSet is missing pods on, shown above
+/// its describe output.
+async fn dispatch_daemon_set_coverage(app: &mut App) {
+  app.data.daemon_set_coverage = None;
+  if let Some(ds) = app.data.daemon_sets.get_selected_item_copy() {
+    app
+      .dispatch(IoEvent::GetDaemonSetCoverage {
+        name: ds.name,
+        namespace: ds.namespace,
+      })
+      .await;
+  }
+}
+
+/// Walk the owners of the selected pod, shown above its describe output.
+async fn dispatch_pod_controllers(app: &mut App) {
+  app.data.pod_controllers = None;
+  if let Some(pod) = app.data.pods.get_selected_item_copy() {
+    app
+      .dispatch(IoEvent::GetPodControllers {
+        name: pod.name,
+        namespace: pod.namespace,
+      })
+      .await;
+  }
+}
+
+/// Fetch the endpoints of the selected Service, shown above its describe output.
+async fn dispatch_service_endpoints(app: &mut App) {
+  app.data.service_endpoints = None;
+  if let Some(svc) = app.data.services.get_selected_item_copy() {
+    app
+      .dispatch(IoEvent::GetServiceEndpoints {
+        name: svc.name,
+        namespace: svc.namespace,
+      })
+      .await;
+  }
+}
+
+/// Resolve the rules of the role a binding references for its describe view.
+/// Rules from a previously described binding are cleared first.
+async fn dispatch_binding_rules(app: &mut App, role_ref: &RoleRef, namespace: Option<String>) {
+  app.data.binding_rules = None;
+  app
+    .dispatch(IoEvent::GetBindingRules {
+      role_kind: role_ref.kind.clone(),
+      role_name: role_ref.name.clone(),
+      namespace,
+    })
+    .await;
+}
+
+/// Swap the describe/YAML view for a diff of the underlying resource's
+/// last-applied configuration against its live state. The diff replaces the
+/// current view rather than stacking on it, so `Esc` returns to the list.
+async fn open_last_applied_diff(app: &mut App) {
+  let block = app.get_prev_route().active_block;
+  let Some((name, namespace)) = selected_target(app, block) else {
+    return;
+  };
+  app.pop_navigation_stack();
+  app.data.describe_out = ScrollableTxt::new();
+  app.push_navigation_stack(RouteId::Home, ActiveBlock::Diff);
+  app
+    .dispatch(IoEvent::GetLastAppliedDiff {
+      block,
+      name,
+      namespace,
+    })
+    .await;
+}
+
+/// Yaml or decode the selected row of a leaf resource view, or describe it
+/// on Enter since it has nothing to drill into.
+async fn handle_leaf_resource_action<T, S>(key: Key, app: &mut App, res: &T)
+where
   T: KubeResource<S> + 'static,
   S: Serialize,
 {
-  let describe_action = IoCmdEvent::GetDescribe {
+  if !handle_decode_or_yaml_action(key, app, res).await && key == DEFAULT_KEYBINDING.submit.key {
+    describe_selected(app).await;
+  }
+}
+
+/// List the objects of a kind in the dynamic resource view, using the kind
+/// found by discovery when there is one so the preferred version is used.
+fn open_dynamic_instances(app: &mut App, kind: KubeDynamicKind) {
+  let kind = app
+    .data
+    .dynamic_kinds
+    .iter()
+    .find(|known| known.api_resource.group == kind.api_resource.group && known.kind == kind.kind)
+    .cloned()
+    .unwrap_or(kind);
+  app.data.selected.dynamic_kind = Some(kind);
+  app.push_navigation_stack(RouteId::Home, ActiveBlock::DynamicResource);
+  if !app.apply_cached_dynamic_resources() {
+    app.data.dynamic_resources.set_items(vec![]);
+  }
+}
+
+/// The describe fetch for the row selected in `block`. This is the one place
+/// views map to the object they describe, so a view listed here gets the
+/// describe key without handling it itself.
+fn describe_action(app: &App, block: ActiveBlock) -> Option<IoCmdEvent> {
+  macro_rules! selected {
+    ($field:ident) => {
+      app.data.$field.get_selected_item_copy().map(|res| {
+        (
+          res.get_name().to_owned(),
+          res.get_namespace().map(str::to_owned),
+          res.resource_version(),
+        )
+      })
+    };
+  }
+
+  let (value, ns, resource_version) = match block {
+    // the container list describes the pod it belongs to
+    ActiveBlock::Pods | ActiveBlock::Containers => selected!(pods),
+    ActiveBlock::Services => selected!(services),
+    ActiveBlock::ConfigMaps => selected!(config_maps),
+    ActiveBlock::Secrets => selected!(secrets),
+    ActiveBlock::StatefulSets => selected!(stateful_sets),
+    ActiveBlock::ReplicaSets => selected!(replica_sets),
+    ActiveBlock::Deployments => selected!(deployments),
+    ActiveBlock::Jobs => selected!(jobs),
+    ActiveBlock::DaemonSets => selected!(daemon_sets),
+    ActiveBlock::CronJobs => selected!(cronjobs),
+    ActiveBlock::ReplicationControllers => selected!(replication_controllers),
+    ActiveBlock::Roles => selected!(roles),
+    ActiveBlock::RoleBindings => selected!(role_bindings),
+    ActiveBlock::Ingresses => selected!(ingress),
+    ActiveBlock::PersistentVolumeClaims => selected!(persistent_volume_claims),
+    ActiveBlock::NetworkPolicies => selected!(network_policies),
+    ActiveBlock::ResourceQuotas => selected!(resource_quotas),
+    ActiveBlock::LimitRanges => selected!(limit_ranges),
+    ActiveBlock::PodDisruptionBudgets => selected!(pdbs),
+    ActiveBlock::ServiceAccounts => selected!(service_accounts),
+    ActiveBlock::Events => selected!(events),
+    ActiveBlock::Namespaces => selected!(namespaces),
+    ActiveBlock::Nodes => selected!(nodes),
+    ActiveBlock::PersistentVolumes => selected!(persistent_volumes),
+    ActiveBlock::StorageClasses => selected!(storage_classes),
+    ActiveBlock::ClusterRoles => selected!(cluster_roles),
+    ActiveBlock::ClusterRoleBindings => selected!(cluster_role_bindings),
+    ActiveBlock::CustomResourceDefinitions => selected!(crds),
+    ActiveBlock::DynamicResource => selected!(dynamic_resources),
+    // a pods-by-node row describes its node; pending pods have none
+    ActiveBlock::PodsByNode => app
+      .data
+      .node_pods
+      .get_selected_item_copy()
+      .filter(|bucket| !bucket.is_pending)
+      .map(|bucket| (bucket.name, None, None)),
+    ActiveBlock::Watchlist => {
+      let row = app.data.watchlist.get_selected_item_copy()?;
+      return Some(IoCmdEvent::GetDescribe {
+        kind: row.kind,
+        value: row.pin.name,
+        ns: row.pin.namespace,
+        resource_version: None,
+      });
+    }
+    ActiveBlock::Troubleshoot => {
+      let finding = app.data.troubleshoot_findings.get_selected_item_copy()?;
+      let (kind, value, ns) = finding.describe_target();
+      return Some(IoCmdEvent::GetDescribe {
+        kind,
+        value: value.to_owned(),
+        ns: ns.map(str::to_owned),
+        resource_version: None,
+      });
+    }
+    _ => None,
+  }?;
+  let kind = match (block, app.data.selected.dynamic_kind.as_ref()) {
+    (ActiveBlock::DynamicResource, Some(dynamic_kind)) => dynamic_kind.kind.clone(),
+    _ => app.resource_kind_label(block),
+  };
+  Some(IoCmdEvent::GetDescribe {
     kind,
-    value: res.get_name().to_owned(),
+    value,
     ns,
+    resource_version,
+  })
+}
+
+/// The kubectl command equivalent to the current view and selection. This is
+/// the one place views map to a command: logs and containers read the pod's
+/// logs, describe, YAML and diff views show the object they were opened on,
+/// and list views get the selected row.
+fn kubectl_command(app: &App) -> Option<String> {
+  let route = app.get_current_route();
+  let mut args: Vec<String> = vec![];
+  let (kind, name, ns) = match route.active_block {
+    ActiveBlock::Logs | ActiveBlock::Containers => {
+      let pod = app.data.pods.get_selected_item_copy()?;
+      let container = match route.active_block {
+        ActiveBlock::Logs => app.data.selected.container.clone(),
+        _ => app.data.containers.get_selected_item_copy().map(|c| c.name),
+      };
+      args.push("logs".into());
+      args.push(format!("pod/{}", pod.name));
+      if let Some(container) = container {
+        args.extend(["-c".into(), container]);
+      }
+      if route.active_block == ActiveBlock::Logs && app.log_previous {
+        args.push("--previous".into());
+      }
+      return Some(kubectl_line(
+        app.data.selected.context.as_deref(),
+        Some(&pod.namespace),
+        args,
+      ));
+    }
+    ActiveBlock::Describe => {
+      args.push("describe".into());
+      app.data.last_describe.clone()?
+    }
+    ActiveBlock::Yaml => {
+      args.push("get".into());
+      describe_action(app, app.get_prev_route().active_block)?
+    }
+    ActiveBlock::Diff => {
+      args.extend(["apply".into(), "view-last-applied".into()]);
+      describe_action(app, app.get_prev_route().active_block)?
+    }
+    block => {
+      args.push("get".into());
+      describe_action(app, block)?
+    }
+  }
+  .described_object()?;
+  args.push(format!("{}/{}", kind, name));
+  if route.active_block == ActiveBlock::Yaml {
+    args.extend(["-o".into(), "yaml".into()]);
+  }
+  Some(kubectl_line(
+    app.data.selected.context.as_deref(),
+    ns.as_deref(),
+    args,
+  ))
+}
+
+/// `kubectl [--context c] [-n ns] args…`, with words the shell would split
+/// or expand single-quoted.
+fn kubectl_line(context: Option<&str>, ns: Option<&str>, args: Vec<String>) -> String {
+  let mut words = vec!["kubectl".to_owned()];
+  if let Some(context) = context {
+    words.extend(["--context".into(), context.to_owned()]);
+  }
+  if let Some(ns) = ns {
+    words.extend(["-n".into(), ns.to_owned()]);
+  }
+  words.extend(args);
+  words
+    .iter()
+    .map(|word| {
+      let plain = word
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "-_./:=@,+".contains(c));
+      if plain && !word.is_empty() {
+        word.clone()
+      } else {
+        format!("'{}'", word.replace('\'', "'\\''"))
+      }
+    })
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+fn copy_kubectl_command(app: &mut App) {
+  let Some(command) = kubectl_command(app) else {
+    app.set_status_message("No kubectl command for this view");
+    return;
   };
-  let handled = handle_describe_decode_or_yaml_action(key, app, res, describe_action.clone()).await;
-  dispatch_describe_on_submit(key, app, handled, describe_action).await;
+  if copy_to_clipboard(command.clone(), app) {
+    app.set_status_message(format!("Copied: {}", command));
+  }
 }
 
-async fn dispatch_describe_on_submit(
-  key: Key,
-  app: &mut App,
-  handled: bool,
-  describe_action: IoCmdEvent,
-) {
-  if !handled && key == DEFAULT_KEYBINDING.submit.key {
-    app.data.describe_out = ScrollableTxt::new();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Describe);
-    app.dispatch_cmd(describe_action).await;
+/// Describe the row selected in the current view, along with what its
+/// describe view shows above the output. Returns `false` when the view has
+/// nothing to describe.
+async fn describe_selected(app: &mut App) -> bool {
+  let route = app.get_current_route().clone();
+  let Some(action) = describe_action(app, route.active_block) else {
+    return false;
+  };
+  app.push_navigation_stack(route.id, ActiveBlock::Describe);
+  open_describe(app, action).await;
+  match route.active_block {
+    ActiveBlock::Pods => dispatch_pod_controllers(app).await,
+    ActiveBlock::Services => dispatch_service_endpoints(app).await,
+    ActiveBlock::DaemonSets => dispatch_daemon_set_coverage(app).await,
+    ActiveBlock::RoleBindings => {
+      if let Some(binding) = app.data.role_bindings.get_selected_item_copy() {
+        let role_ref = &binding.get_k8s_obj().role_ref;
+        dispatch_binding_rules(app, role_ref, Some(binding.namespace.clone())).await;
+      }
+    }
+    ActiveBlock::ClusterRoleBindings => {
+      if let Some(binding) = app.data.cluster_role_bindings.get_selected_item_copy() {
+        dispatch_binding_rules(app, &binding.get_k8s_obj().role_ref, None).await;
+      }
+    }
+    _ => {}
+  }
+  true
+}
+
+/// Show the describe output from the cache when this version of the object
+/// was described recently, otherwise fetch it.
+async fn open_describe(app: &mut App, action: IoCmdEvent) {
+  app.data.last_describe = Some(action.clone());
+  app.data.pod_constraints = None;
+  let cached = action
+    .describe_key()
+    .and_then(|key| app.data.describe_cache.get(&key, Instant::now()))
+    .map(String::from);
+  match cached {
+    Some(out) => app.data.describe_out = ScrollableTxt::with_string(out),
+    None => {
+      app.data.describe_out = ScrollableTxt::new();
+      app.dispatch_cmd(action).await;
+    }
+  }
+}
+
+/// Expand the described pod's scheduling constraints, or fold them again.
+fn toggle_pod_constraints(app: &mut App) {
+  if app.data.pod_constraints.take().is_some() {
+    return;
+  }
+  let Some(pod) = app.data.pods.get_selected_item_copy() else {
+    return;
+  };
+  let lines = pod.scheduling_constraints();
+  if lines.is_empty() {
+    app.set_status_message("No tolerations, node selector or affinity set on this pod");
+  } else {
+    app.data.pod_constraints = Some(ScrollableTxt::with_string(lines.join("\n")));
+  }
+}
+
+/// Expand the selected container's volume mounts, or fold them again.
+/// List the keys of a ConfigMap, with the selected one's value beside them.
+fn open_config_map_data(app: &mut App, cm: KubeConfigMap) {
+  let entries = cm.entries();
+  if entries.is_empty() {
+    app.set_status_message(format!("ConfigMap {} has no data", cm.name));
+    return;
+  }
+  app.data.selected.config_map = Some(cm.name);
+  app.data.config_map_data = StatefulTable::new();
+  app.data.config_map_data.set_items(entries);
+  app.push_navigation_stack(RouteId::Home, ActiveBlock::ConfigMapData);
+}
+
+/// Open the selected value where it scrolls like describe output. Binary
+/// values are only noted.
+fn open_config_map_value(app: &mut App) {
+  let Some(entry) = app.data.config_map_data.get_selected_item_copy() else {
+    return;
+  };
+  match entry.value {
+    Some(value) => {
+      app.data.describe_out = ScrollableTxt::with_string(value);
+      app.data.pod_constraints = None;
+      app.push_navigation_stack(RouteId::Home, ActiveBlock::Describe);
+    }
+    None => app.set_status_message(format!("{}: {}", entry.key, entry.binary_note())),
+  }
+}
+
+fn toggle_container_mounts(app: &mut App) {
+  if app.data.container_mounts.take().is_some() {
+    return;
+  }
+  let Some(container) = app.data.containers.get_selected_item_copy() else {
+    return;
+  };
+  if container.mounts.is_empty() {
+    app.set_status_message("No volumes mounted in this container");
+  } else {
+    let lines = ContainerMount::lines(&container.mounts);
+    app.data.container_env = None;
+    app.data.container_mounts = Some(ScrollableTxt::with_string(lines.join("\n")));
+  }
+}
+
+fn toggle_container_env(app: &mut App) {
+  if app.data.container_env.take().is_some() {
+    return;
+  }
+  let Some(container) = app.data.containers.get_selected_item_copy() else {
+    return;
+  };
+  if container.env.is_empty() {
+    app.set_status_message("No environment variables set in this container");
+  } else {
+    app.data.container_mounts = None;
+    let text = pods::container_env_text(app, &container, false);
+    app.data.container_env = Some(ContainerEnvView {
+      txt: ScrollableTxt::with_string(text),
+      reveal: false,
+    });
+  }
+}
+
+/// Show or hide the values of the expanded env's secret references. Secrets
+/// not loaded yet are fetched, and fill in as they arrive.
+async fn toggle_container_env_secrets(app: &mut App) {
+  let revealing = app
+    .data
+    .container_env
+    .as_ref()
+    .is_some_and(|env| !env.reveal);
+  if revealing && app.refuse_in_redact_mode() {
+    return;
+  }
+  let Some(env) = app.data.container_env.as_mut() else {
+    return;
+  };
+  env.reveal = !env.reveal;
+  if env.reveal {
+    app.set_status_message("Secret values shown");
+    app.dispatch(IoEvent::GetSecrets).await;
+  }
+}
+
+/// Switch the events view between one row per event and one per repeated
+/// event, regrouping the events already fetched.
+async fn toggle_event_grouping(app: &mut App) {
+  app.data.group_events = !app.data.group_events;
+  app.set_status_message(if app.data.group_events {
+    "Grouping repeated events"
+  } else {
+    "Listing every event"
+  });
+  app.refresh_event_rows();
+}
+
+/// Drop the cached output of the object being described and fetch it again.
+async fn refetch_describe(app: &mut App) {
+  if let Some(action) = app.data.last_describe.clone() {
+    if let Some(key) = action.describe_key() {
+      app.data.describe_cache.invalidate(&key);
+    }
+    open_describe(app, action).await;
   }
 }
 
 // Handle event for the current active block
+/// Open a resource tab on the overview, by index into `context_tabs`.
+fn select_context_tab(app: &mut App, index: usize) {
+  match index {
+    0 => {
+      // Clear any workload drill-down state so the pod view shows all pods
+      app.data.selected.pod_selector = None;
+      app.data.selected.pod_selector_ns = None;
+      app.data.selected.pod_selector_resource = None;
+      app.stop_following_rollout();
+    }
+    5 => {
+      // Clear any deployment drill-down so the view lists all ReplicaSets
+      app.data.selected.replica_set_owner = None;
+      app.data.selected.replica_set_owner_ns = None;
+    }
+    _ => {}
+  }
+  app.deactivate_current_resource_filter();
+  let route = app.context_tabs.set_index(index).route.clone();
+  app.set_route(route);
+}
+
 async fn handle_route_events(key: Key, app: &mut App) {
+  // describe works the same in every view, see `describe_action`
+  if key == DEFAULT_KEYBINDING.describe_resource.key && describe_selected(app).await {
+    return;
+  }
+
   // route specific events
   match app.get_current_route().id {
     // handle resource tabs on overview
@@ -935,10 +2113,25 @@ async fn handle_route_events(key: Key, app: &mut App) {
         _ if key == DEFAULT_KEYBINDING.toggle_info.key => {
           app.show_info_bar = !app.show_info_bar;
         }
-        _ if key == DEFAULT_KEYBINDING.select_all_namespace.key => app.data.selected.ns = None,
+        _ if key == DEFAULT_KEYBINDING.select_all_namespace.key => {
+          let toggled = app.toggle_all_namespaces();
+          if toggled {
+            app.cache_essential_data().await;
+            app.queue_background_resource_cache();
+          }
+        }
         _ if key == DEFAULT_KEYBINDING.delete_resource.key => {
           handle_delete_resource(app).await;
         }
+        _ if key == DEFAULT_KEYBINDING.toggle_select.key => {
+          toggle_selected_row(app);
+        }
+        _ if key == DEFAULT_KEYBINDING.scroll_columns_left.key => {
+          scroll_table_columns(app, false);
+        }
+        _ if key == DEFAULT_KEYBINDING.scroll_columns_right.key => {
+          scroll_table_columns(app, true);
+        }
         _ if key == DEFAULT_KEYBINDING.restart_resource.key => {
           handle_restart_resource(app).await;
         }
@@ -948,115 +2141,138 @@ async fn handle_route_events(key: Key, app: &mut App) {
         _ if key == DEFAULT_KEYBINDING.port_forward.key => {
           open_port_forward_input(app);
         }
+        _ if key == DEFAULT_KEYBINDING.open_in_console.key => {
+          open_in_console(app);
+        }
+        _ if key == DEFAULT_KEYBINDING.pin.key => {
+          toggle_pin_selected(app);
+        }
         _ if key == DEFAULT_KEYBINDING.jump_to_namespace.key
           && app.get_current_route().active_block != ActiveBlock::Namespaces =>
         {
           app.push_navigation_stack(RouteId::Home, ActiveBlock::Namespaces);
         }
         // as these are tabs with index the order here matters, atleast for readability
-        _ if key == DEFAULT_KEYBINDING.jump_to_pods.key => {
-          // Clear any workload drill-down state so the pod view shows all pods
-          app.data.selected.pod_selector = None;
-          app.data.selected.pod_selector_ns = None;
-          app.data.selected.pod_selector_resource = None;
-          app.deactivate_current_resource_filter();
-          let route = app.context_tabs.set_index(0).route.clone();
-          app.set_route(route);
-        }
-        _ if key == DEFAULT_KEYBINDING.jump_to_services.key => {
-          app.deactivate_current_resource_filter();
-          let route = app.context_tabs.set_index(1).route.clone();
-          app.set_route(route);
-        }
-        _ if key == DEFAULT_KEYBINDING.jump_to_nodes.key => {
-          app.deactivate_current_resource_filter();
-          let route = app.context_tabs.set_index(2).route.clone();
-          app.set_route(route);
-        }
-        _ if key == DEFAULT_KEYBINDING.jump_to_configmaps.key => {
-          app.deactivate_current_resource_filter();
-          let route = app.context_tabs.set_index(3).route.clone();
-          app.set_route(route);
-        }
-        _ if key == DEFAULT_KEYBINDING.jump_to_statefulsets.key => {
-          app.deactivate_current_resource_filter();
-          let route = app.context_tabs.set_index(4).route.clone();
-          app.set_route(route);
-        }
-        _ if key == DEFAULT_KEYBINDING.jump_to_replicasets.key => {
-          app.deactivate_current_resource_filter();
-          let route = app.context_tabs.set_index(5).route.clone();
-          app.set_route(route);
-        }
-        _ if key == DEFAULT_KEYBINDING.jump_to_deployments.key => {
-          app.deactivate_current_resource_filter();
-          let route = app.context_tabs.set_index(6).route.clone();
-          app.set_route(route);
-        }
-        _ if key == DEFAULT_KEYBINDING.jump_to_jobs.key => {
-          app.deactivate_current_resource_filter();
-          let route = app.context_tabs.set_index(7).route.clone();
-          app.set_route(route);
-        }
-        _ if key == DEFAULT_KEYBINDING.jump_to_daemonsets.key => {
-          app.deactivate_current_resource_filter();
-          let route = app.context_tabs.set_index(8).route.clone();
-          app.set_route(route);
-        }
-        _ if key == DEFAULT_KEYBINDING.jump_to_more_resources.key => {
-          app.deactivate_current_resource_filter();
-          let route = app.context_tabs.set_index(9).route.clone();
-          app.set_route(route);
-        }
-        _ if key == DEFAULT_KEYBINDING.jump_to_dynamic_resources.key => {
-          app.deactivate_current_resource_filter();
-          let route = app.context_tabs.set_index(10).route.clone();
-          app.set_route(route);
-        }
+        _ if key == DEFAULT_KEYBINDING.jump_to_pods.key => select_context_tab(app, 0),
+        _ if key == DEFAULT_KEYBINDING.jump_to_services.key => select_context_tab(app, 1),
+        _ if key == DEFAULT_KEYBINDING.jump_to_nodes.key => select_context_tab(app, 2),
+        _ if key == DEFAULT_KEYBINDING.jump_to_configmaps.key => select_context_tab(app, 3),
+        _ if key == DEFAULT_KEYBINDING.jump_to_statefulsets.key => select_context_tab(app, 4),
+        _ if key == DEFAULT_KEYBINDING.jump_to_replicasets.key => select_context_tab(app, 5),
+        _ if key == DEFAULT_KEYBINDING.jump_to_deployments.key => select_context_tab(app, 6),
+        _ if key == DEFAULT_KEYBINDING.jump_to_jobs.key => select_context_tab(app, 7),
+        _ if key == DEFAULT_KEYBINDING.jump_to_daemonsets.key => select_context_tab(app, 8),
+        _ if key == DEFAULT_KEYBINDING.jump_to_more_resources.key => select_context_tab(app, 9),
+        _ if key == DEFAULT_KEYBINDING.jump_to_dynamic_resources.key => select_context_tab(app, 10),
         _ => {}
       };
 
       // handle block specific stuff
       handle_resource_action!(app.get_current_route().active_block, key, app,
-        namespaced: [
-          (ActiveBlock::Services, services, "service"),
-          (ActiveBlock::ConfigMaps, config_maps, "configmap"),
-          (ActiveBlock::Secrets, secrets, "secret"),
-          (ActiveBlock::Roles, roles, "roles"),
-          (ActiveBlock::RoleBindings, role_bindings, "rolebindings"),
-          (ActiveBlock::Ingresses, ingress, "ingress"),
-          (ActiveBlock::PersistentVolumeClaims, persistent_volume_claims, "persistentvolumeclaims"),
-          (ActiveBlock::ServiceAccounts, service_accounts, "serviceaccounts"),
-          (ActiveBlock::Events, events, "event"),
-          (ActiveBlock::NetworkPolicies, network_policies, "networkpolicy"),
-        ],
-        cluster: [
-          (ActiveBlock::StorageClasses, storage_classes, "storageclass"),
-          (ActiveBlock::ClusterRoles, cluster_roles, "clusterroles"),
-          (ActiveBlock::ClusterRoleBindings, cluster_role_bindings, "clusterrolebinding"),
-          (ActiveBlock::PersistentVolumes, persistent_volumes, "persistentvolumes"),
+        [
+          (ActiveBlock::Secrets, secrets),
+          (ActiveBlock::Roles, roles),
+          (ActiveBlock::RoleBindings, role_bindings),
+          (ActiveBlock::Ingresses, ingress),
+          (ActiveBlock::PersistentVolumeClaims, persistent_volume_claims),
+          (ActiveBlock::ServiceAccounts, service_accounts),
+          (ActiveBlock::NetworkPolicies, network_policies),
+          (ActiveBlock::ResourceQuotas, resource_quotas),
+          (ActiveBlock::LimitRanges, limit_ranges),
+          (ActiveBlock::PodDisruptionBudgets, pdbs),
+          (ActiveBlock::StorageClasses, storage_classes),
+          (ActiveBlock::ClusterRoles, cluster_roles),
+          (ActiveBlock::ClusterRoleBindings, cluster_role_bindings),
+          (ActiveBlock::PersistentVolumes, persistent_volumes),
+          (ActiveBlock::DynamicResource, dynamic_resources),
         ],
         extra: {
+          ActiveBlock::ConfigMaps => {
+            if let Some(res) = handle_block_action(key, &app.data.config_maps) {
+              if !handle_decode_or_yaml_action(key, app, &res).await
+                && key == DEFAULT_KEYBINDING.submit.key
+              {
+                open_config_map_data(app, res);
+              }
+            }
+          }
+          ActiveBlock::ConfigMapData => {
+            if key == DEFAULT_KEYBINDING.submit.key {
+              open_config_map_value(app);
+            }
+          }
+          ActiveBlock::Events => {
+            if key == DEFAULT_KEYBINDING.group_events.key {
+              toggle_event_grouping(app).await;
+            } else if let Some(res) = handle_block_action(key, &app.data.events) {
+              handle_leaf_resource_action(key, app, &res).await;
+            }
+          }
+          ActiveBlock::CustomResourceDefinitions => {
+            if key == DEFAULT_KEYBINDING.submit.key {
+              if let Some(crd) = app.data.crds.get_selected_item_copy() {
+                open_dynamic_instances(app, crd.dynamic_kind());
+              }
+            } else if let Some(res) = handle_block_action(key, &app.data.crds) {
+              handle_leaf_resource_action(key, app, &res).await;
+            }
+          }
+          ActiveBlock::ApiResources => {
+            if key == DEFAULT_KEYBINDING.submit.key {
+              if let Some(res) = app.data.api_resources.get_selected_item_copy() {
+                if res.is_listable() {
+                  open_dynamic_instances(app, res.dynamic_kind());
+                } else {
+                  app.set_status_message(format!("{} cannot be listed", res.name));
+                }
+              }
+            }
+          }
+          ActiveBlock::PodsByNode => {
+            if key == DEFAULT_KEYBINDING.cycle_node_pods_sort.key {
+              let sort = app.data.node_pods_sort.next();
+              app.data.node_pods_sort = sort;
+              sort_node_pods(&mut app.data.node_pods.items, sort);
+            } else if key == DEFAULT_KEYBINDING.submit.key {
+              if let Some(res) = app.data.node_pods.get_selected_item_copy() {
+                if !res.is_pending {
+                  app.dispatch_node_pods(res.name, RouteId::Home).await;
+                }
+              }
+            }
+          }
+          ActiveBlock::Services => {
+            if key == DEFAULT_KEYBINDING.jump_to_logs.key {
+              if let Some(res) = app.data.services.get_selected_item_copy() {
+                jump_to_logs(app, &res, "service").await;
+              }
+            } else if let Some(res) = handle_block_action(key, &app.data.services) {
+              handle_leaf_resource_action(key, app, &res).await;
+            }
+          }
           ActiveBlock::Nodes => {
             if let Some(res) = handle_block_action(key, &app.data.nodes) {
-              let ok = handle_describe_decode_or_yaml_action(
-                key,
-                app,
-                &res,
-                IoCmdEvent::GetDescribe {
-                  kind: "node".to_owned(),
-                  value: res.name.to_owned(),
-                  ns: None,
-                },
-              )
-              .await;
-              if !ok {
+              if !handle_decode_or_yaml_action(key, app, &res).await {
                 app.dispatch_node_pods(res.name.clone(), RouteId::Home).await;
               }
             }
           }
           ActiveBlock::Deployments => {
-            handle_workload_action!(key, app, deployments, "deployment");
+            if key == DEFAULT_KEYBINDING.show_replica_sets.key {
+              if let Some(res) = app.data.deployments.get_selected_item_copy() {
+                app
+                  .dispatch_deployment_replica_sets(res.namespace, res.name, RouteId::Home)
+                  .await;
+              }
+            } else if key == DEFAULT_KEYBINDING.follow_rollout.key {
+              if let Some(res) = app.data.deployments.get_selected_item_copy() {
+                if let Some(selector) = res.pod_label_selector() {
+                  app.follow_rollout(&res, selector).await;
+                }
+              }
+            } else {
+              handle_workload_action!(key, app, deployments, "deployment");
+            }
           }
           ActiveBlock::StatefulSets => {
             handle_workload_action!(key, app, stateful_sets, "statefulset");
@@ -1077,7 +2293,9 @@ async fn handle_route_events(key: Key, app: &mut App) {
             handle_workload_action!(key, app, replication_controllers, "replicationcontroller");
           }
           ActiveBlock::Namespaces => {
-            if let Some(ns) = handle_block_action(key, &app.data.namespaces) {
+            if key == DEFAULT_KEYBINDING.create_namespace.key {
+              open_create_namespace_input(app);
+            } else if let Some(ns) = handle_block_action(key, &app.data.namespaces) {
               app.data.selected.ns = Some(ns.name);
               app.cache_essential_data().await;
               app.queue_background_resource_cache();
@@ -1085,7 +2303,25 @@ async fn handle_route_events(key: Key, app: &mut App) {
             }
           }
           ActiveBlock::Pods => {
-            if key == DEFAULT_KEYBINDING.aggregate_logs.key {
+            if key == DEFAULT_KEYBINDING.group_pods.key && app.data.selected.pod_selector.is_none()
+            {
+              app.toggle_pod_grouping();
+            } else if key == DEFAULT_KEYBINDING.submit.key
+              && app.pods_grouped()
+              && app.toggle_selected_pod_group()
+            {
+              // folded or unfolded the selected group
+            } else if key == DEFAULT_KEYBINDING.toggle_group.key && app.pods_grouped() {
+              app.toggle_pod_group_at_cursor();
+            } else if key == DEFAULT_KEYBINDING.collapse_all_groups.key && app.pods_grouped() {
+              app.collapse_all_pod_groups();
+            } else if key == DEFAULT_KEYBINDING.expand_all_groups.key && app.pods_grouped() {
+              app.expand_all_pod_groups();
+            } else if key == DEFAULT_KEYBINDING.label_selector.key
+              && app.data.selected.pod_selector.is_none()
+            {
+              open_label_selector_input(app);
+            } else if key == DEFAULT_KEYBINDING.aggregate_logs.key {
               if let Some(pod) = app.data.pods.get_selected_item_copy() {
                 app.data.selected.pod = Some(pod.name.clone());
                 app.data.selected.pod_selector_resource = Some("pod".into());
@@ -1095,19 +2331,10 @@ async fn handle_route_events(key: Key, app: &mut App) {
             } else if key == DEFAULT_KEYBINDING.previous_logs.key {
               handle_previous_logs_for_pod(app, RouteId::Home).await;
             } else if let Some(pod) = handle_block_action(key, &app.data.pods) {
-              let ok = handle_describe_decode_or_yaml_action(
-                key,
-                app,
-                &pod,
-                IoCmdEvent::GetDescribe {
-                  kind: "pod".to_owned(),
-                  value: pod.name.to_owned(),
-                  ns: Some(pod.namespace.to_owned()),
-                },
-              )
-              .await;
-              if !ok {
+              if !handle_decode_or_yaml_action(key, app, &pod).await {
                 app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
+                app.data.container_mounts = None;
+                app.data.container_env = None;
                 app.data.selected.pod = Some(pod.name);
                 app.data.containers.set_items(pod.containers);
               }
@@ -1118,6 +2345,14 @@ async fn handle_route_events(key: Key, app: &mut App) {
               queue_selected_container_shell_exec(app);
             } else if key == DEFAULT_KEYBINDING.previous_logs.key {
               handle_previous_logs_for_container(app, RouteId::Home).await;
+            } else if key == DEFAULT_KEYBINDING.toggle_container_mounts.key {
+              toggle_container_mounts(app);
+            } else if key == DEFAULT_KEYBINDING.toggle_container_env.key {
+              toggle_container_env(app);
+            } else if key == DEFAULT_KEYBINDING.decode_secret.key
+              && app.data.container_env.is_some()
+            {
+              toggle_container_env_secrets(app).await;
             } else if let Some(c) = handle_block_action(key, &app.data.containers) {
               app.data.selected.container = Some(c.name.clone());
               app.dispatch_container_logs(c.name, RouteId::Home).await;
@@ -1125,17 +2360,29 @@ async fn handle_route_events(key: Key, app: &mut App) {
           }
           ActiveBlock::Logs => {
             if key == DEFAULT_KEYBINDING.log_auto_scroll.key {
-              if app.log_auto_scroll {
-                app.data.logs.freeze_follow_position();
-              }
-              app.log_auto_scroll = !app.log_auto_scroll;
+              toggle_log_auto_scroll(app);
             } else if key == DEFAULT_KEYBINDING.copy_to_clipboard.key {
               copy_to_clipboard(app.data.logs.get_plain_text(), app);
             }
           }
-          ActiveBlock::Describe | ActiveBlock::Yaml => {
+          ActiveBlock::Describe | ActiveBlock::Yaml | ActiveBlock::Diff => {
             if key == DEFAULT_KEYBINDING.copy_to_clipboard.key {
               copy_to_clipboard(app.data.describe_out.get_txt().to_owned(), app);
+            } else if key == DEFAULT_KEYBINDING.diff_last_applied.key {
+              open_last_applied_diff(app).await;
+            } else if key == DEFAULT_KEYBINDING.toggle_yaml_summary.key
+              && app.get_current_route().active_block == ActiveBlock::Yaml
+            {
+              app.toggle_yaml_summary();
+            } else if key == DEFAULT_KEYBINDING.refresh_describe.key
+              && app.get_current_route().active_block == ActiveBlock::Describe
+            {
+              refetch_describe(app).await;
+            } else if key == DEFAULT_KEYBINDING.toggle_pod_constraints.key
+              && app.get_current_route().active_block == ActiveBlock::Describe
+              && app.get_prev_route().active_block == ActiveBlock::Pods
+            {
+              toggle_pod_constraints(app);
             }
           }
           ActiveBlock::More => {
@@ -1181,26 +2428,7 @@ async fn handle_route_events(key: Key, app: &mut App) {
               }
             }
           }
-          ActiveBlock::DynamicResource => {
-            if let Some(dynamic_res) = app.data.selected.dynamic_kind.as_ref() {
-              if let Some(res) = handle_block_action(key, &app.data.dynamic_resources) {
-                let describe_action = IoCmdEvent::GetDescribe {
-                  kind: dynamic_res.kind.to_owned(),
-                  value: res.name.to_owned(),
-                  ns: res.namespace.to_owned(),
-                };
-                let ok = handle_describe_decode_or_yaml_action(
-                  key,
-                  app,
-                  &res,
-                  describe_action.clone(),
-                )
-                .await;
-                dispatch_describe_on_submit(key, app, ok, describe_action).await;
-              }
-            }
-          }
-          ActiveBlock::Contexts | ActiveBlock::Utilization | ActiveBlock::Troubleshoot | ActiveBlock::Help => { /* Do nothing */ }
+          ActiveBlock::Contexts | ActiveBlock::Utilization | ActiveBlock::Troubleshoot | ActiveBlock::Dashboard | ActiveBlock::Watchlist | ActiveBlock::Help => { /* Do nothing */ }
         }
       )
     }
@@ -1210,10 +2438,8 @@ async fn handle_route_events(key: Key, app: &mut App) {
           *filter_active = true;
         }
       } else if let Some(ctx) = handle_block_action(key, &app.data.contexts) {
-        app.data.selected.context = Some(ctx.name);
         // Pre-select the namespace from the context if one is configured (#90)
-        app.data.selected.ns = ctx.namespace;
-        app.refresh();
+        app.switch_context(ctx);
       }
     }
     RouteId::Utilization => {
@@ -1250,6 +2476,13 @@ async fn handle_route_events(key: Key, app: &mut App) {
             queue_selected_container_shell_exec(app);
           } else if key == DEFAULT_KEYBINDING.previous_logs.key {
             handle_previous_logs_for_container(app, RouteId::Troubleshoot).await;
+          } else if key == DEFAULT_KEYBINDING.toggle_container_mounts.key {
+            toggle_container_mounts(app);
+          } else if key == DEFAULT_KEYBINDING.toggle_container_env.key {
+            toggle_container_env(app);
+          } else if key == DEFAULT_KEYBINDING.decode_secret.key && app.data.container_env.is_some()
+          {
+            toggle_container_env_secrets(app).await;
           } else if let Some(c) = handle_block_action(key, &app.data.containers) {
             app.data.selected.container = Some(c.name.clone());
             app
@@ -1259,10 +2492,7 @@ async fn handle_route_events(key: Key, app: &mut App) {
         }
         ActiveBlock::Logs => {
           if key == DEFAULT_KEYBINDING.log_auto_scroll.key {
-            if app.log_auto_scroll {
-              app.data.logs.freeze_follow_position();
-            }
-            app.log_auto_scroll = !app.log_auto_scroll;
+            toggle_log_auto_scroll(app);
           } else if key == DEFAULT_KEYBINDING.copy_to_clipboard.key {
             copy_to_clipboard(app.data.logs.get_plain_text(), app);
           }
@@ -1283,38 +2513,18 @@ async fn handle_route_events(key: Key, app: &mut App) {
                   app.data.pods.state.select(Some(idx));
                   app.data.selected.pod = Some(pod.name);
                   app.data.containers.set_items(pod.containers);
+                  app.data.container_mounts = None;
+                  app.data.container_env = None;
                   app.push_navigation_stack(RouteId::Troubleshoot, ActiveBlock::Containers);
                 }
               } else {
                 // Describe for non-pod findings
-                let (kind, value, ns) = finding.describe_target();
-                app.data.describe_out = ScrollableTxt::new();
-                app.push_navigation_stack(RouteId::Troubleshoot, ActiveBlock::Describe);
-                app
-                  .dispatch_cmd(IoCmdEvent::GetDescribe {
-                    kind,
-                    value: value.to_owned(),
-                    ns: ns.map(str::to_owned),
-                  })
-                  .await;
+                describe_selected(app).await;
               }
             }
-          } else if key == DEFAULT_KEYBINDING.describe_resource.key {
-            if let Some(finding) = handle_block_action(key, &app.data.troubleshoot_findings) {
-              let (kind, value, ns) = finding.describe_target();
-              app.data.describe_out = ScrollableTxt::new();
-              app.push_navigation_stack(RouteId::Troubleshoot, ActiveBlock::Describe);
-              app
-                .dispatch_cmd(IoCmdEvent::GetDescribe {
-                  kind,
-                  value: value.to_owned(),
-                  ns: ns.map(str::to_owned),
-                })
-                .await;
-            }
           } else if key == DEFAULT_KEYBINDING.resource_yaml.key {
             if let Some(finding) = handle_block_action(key, &app.data.troubleshoot_findings) {
-              let yaml = match finding.resource_kind {
+              let (yaml, summary) = match finding.resource_kind {
                 ResourceKind::Pod => app
                   .data
                   .pods
@@ -1327,7 +2537,7 @@ async fn handle_route_events(key: Key, app: &mut App) {
                         .as_deref()
                         .is_some_and(|ns| p.namespace == ns)
                   })
-                  .map(|p| p.resource_to_yaml())
+                  .map(|p| yaml_and_summary(p, &app.data.events.items))
                   .unwrap_or_default(),
                 ResourceKind::Pvc => app
                   .data
@@ -1341,7 +2551,7 @@ async fn handle_route_events(key: Key, app: &mut App) {
                         .as_deref()
                         .is_some_and(|ns| pvc.namespace == ns)
                   })
-                  .map(|pvc| pvc.resource_to_yaml())
+                  .map(|pvc| yaml_and_summary(pvc, &app.data.events.items))
                   .unwrap_or_default(),
                 ResourceKind::ReplicaSet => app
                   .data
@@ -1355,11 +2565,10 @@ async fn handle_route_events(key: Key, app: &mut App) {
                         .as_deref()
                         .is_some_and(|ns| rs.namespace == ns)
                   })
-                  .map(|rs| rs.resource_to_yaml())
+                  .map(|rs| yaml_and_summary(rs, &app.data.events.items))
                   .unwrap_or_default(),
               };
-              app.data.describe_out = ScrollableTxt::with_string(yaml);
-              app.push_navigation_stack(RouteId::Troubleshoot, ActiveBlock::Yaml);
+              app.open_yaml_view(yaml, summary, RouteId::Troubleshoot);
             }
           }
         }
@@ -1367,10 +2576,22 @@ async fn handle_route_events(key: Key, app: &mut App) {
       }
     }
     RouteId::HelpMenu => {
+      if key == DEFAULT_KEYBINDING.filter.key {
+        app.help_filter_active = true;
+      }
+    }
+    RouteId::Dashboard => { /* Do nothing */ }
+    RouteId::Watchlist => {
       if key == DEFAULT_KEYBINDING.filter.key {
         if let Some((_, filter_active, _)) = app.current_resource_filter_mut() {
           *filter_active = true;
         }
+      } else if app.get_current_route().active_block == ActiveBlock::Watchlist {
+        if key == DEFAULT_KEYBINDING.submit.key {
+          describe_selected(app).await;
+        } else if key == DEFAULT_KEYBINDING.pin.key {
+          toggle_pin_selected(app);
+        }
       }
     }
   }
@@ -1389,6 +2610,19 @@ async fn handle_previous_logs_for_container(app: &mut App, route_id: RouteId) {
   app.dispatch_previous_logs(container.name, route_id).await;
 }
 
+/// Open the logs of the newest ready pod of a workload or service.
+async fn jump_to_logs<T: Named + HasPodSelector>(app: &mut App, res: &T, kind: &str) {
+  let name = res.get_name().clone();
+  let Some(selector) = res.pod_label_selector() else {
+    app.set_status_message(format!("{} {} selects no pods", kind, name));
+    return;
+  };
+  let namespace = res.get_namespace().unwrap_or_default().to_owned();
+  app
+    .dispatch_jump_to_logs(name, namespace, selector, kind.to_owned())
+    .await;
+}
+
 /// View previous logs from the Pods view, resolving the target container the
 /// same way shell-exec does (single/selected container).
 async fn handle_previous_logs_for_pod(app: &mut App, route_id: RouteId) {
@@ -1407,7 +2641,7 @@ async fn handle_previous_logs_for_pod(app: &mut App, route_id: RouteId) {
   app.dispatch_previous_logs(container.name, route_id).await;
 }
 
-/// Queue the selected resource to open in `$EDITOR` via `kubectl edit`. Works on
+/// Queue the selected resource to edit in `$EDITOR` and re-apply on save. Works on
 /// any resource block with a selection; cluster-scoped kinds carry no namespace.
 fn queue_selected_resource_edit(app: &mut App) {
   let block = app.get_current_route().active_block;
@@ -1415,7 +2649,7 @@ fn queue_selected_resource_edit(app: &mut App) {
     app.handle_error(anyhow!("No resource selected to edit"));
     return;
   };
-  let kind = resource_kind_label(app, block);
+  let kind = app.resource_kind_label(block);
   app.queue_edit(PendingEdit {
     namespace,
     kind,
@@ -1459,8 +2693,65 @@ fn open_port_forward_input(app: &mut App) {
   });
 }
 
+/// Open the label-selector input for the pod list, pre-filled with the active
+/// selector so it can be refined. Submitting an empty selector clears it.
+fn open_label_selector_input(app: &mut App) {
+  app.open_input_modal(InputModal {
+    title: "Label selector".to_owned(),
+    prompt: "Selector (e.g. app=nginx,tier=frontend):".to_owned(),
+    buffer: app.data.selected.label_selector.clone().unwrap_or_default(),
+    error: None,
+    action: InputAction::LabelSelector,
+  });
+}
+
+fn open_create_namespace_input(app: &mut App) {
+  app.open_input_modal(InputModal {
+    title: "Create namespace".to_owned(),
+    prompt: "Name (lowercase letters, digits and '-'):".to_owned(),
+    buffer: String::new(),
+    error: None,
+    action: InputAction::CreateNamespace,
+  });
+}
+
+/// Open the since-window input for the log view, pre-filled with the active
+/// window. Submitting it empty goes back to the tail-line count.
+fn open_log_since_input(app: &mut App) {
+  app.open_input_modal(InputModal {
+    title: "Logs since".to_owned(),
+    prompt: "Duration (e.g. 10m, 1h; empty for last lines):".to_owned(),
+    buffer: app
+      .log_since
+      .map(|since| humantime::format_duration(since).to_string())
+      .unwrap_or_default(),
+    error: None,
+    action: InputAction::LogSince,
+  });
+}
+
+fn open_log_mark_input(app: &mut App) {
+  app.open_input_modal(InputModal {
+    title: "Mark logs".to_owned(),
+    prompt: "Note (optional):".to_owned(),
+    buffer: String::new(),
+    error: None,
+    action: InputAction::LogMark,
+  });
+}
+
+/// Apply (or clear) the pod label selector and re-fetch the pod list with it.
+async fn set_label_selector(app: &mut App, selector: Option<String>) {
+  if app.data.selected.label_selector == selector {
+    return;
+  }
+  app.data.selected.label_selector = selector;
+  app.data.pods.state.select(Some(0));
+  app.dispatch(IoEvent::GetPods).await;
+}
+
 /// Handle keys while the active-forwards overlay is open: navigate, stop the
-/// selected forward (`d`/Enter), or close (`Esc`).
+/// selected forward (`d`/Enter), restart a failed one (`r`), or close (`Esc`).
 async fn handle_port_forwards_key(key: Key, app: &mut App) {
   match key {
     _ if key == DEFAULT_KEYBINDING.esc.key => app.close_port_forwards(),
@@ -1488,6 +2779,61 @@ async fn handle_port_forwards_key(key: Key, app: &mut App) {
           .await;
       }
     }
+    Key::Char('r') => {
+      if let Some(id) = app.selected_port_forward_id() {
+        app.restart_port_forward(id).await;
+      }
+    }
+    _ => {}
+  }
+}
+
+/// The audit overlay is read-only: it scrolls and closes.
+fn handle_audit_log_key(key: Key, app: &mut App) {
+  let len = app.audit_log.len();
+  match key {
+    _ if key == DEFAULT_KEYBINDING.esc.key || key == DEFAULT_KEYBINDING.audit_log.key => {
+      app.close_audit_log()
+    }
+    _ if key == DEFAULT_KEYBINDING.up.key
+      || key == DEFAULT_KEYBINDING.up.alt.unwrap()
+      || key == Key::Up =>
+    {
+      move_list_selection(&mut app.audit_log_state, len, -1);
+    }
+    _ if key == DEFAULT_KEYBINDING.down.key
+      || key == DEFAULT_KEYBINDING.down.alt.unwrap()
+      || key == Key::Down =>
+    {
+      move_list_selection(&mut app.audit_log_state, len, 1);
+    }
+    _ => {}
+  }
+}
+
+fn handle_cmd_output_key(key: Key, app: &mut App) {
+  if key == DEFAULT_KEYBINDING.esc.key {
+    app.close_cmd_output();
+    return;
+  }
+  let Some(output) = app.cmd_output.as_mut() else {
+    return;
+  };
+  match key {
+    _ if key == DEFAULT_KEYBINDING.up.key
+      || key == DEFAULT_KEYBINDING.up.alt.unwrap()
+      || key == Key::Up =>
+    {
+      output.scroll(-1);
+    }
+    _ if key == DEFAULT_KEYBINDING.down.key
+      || key == DEFAULT_KEYBINDING.down.alt.unwrap()
+      || key == Key::Down =>
+    {
+      output.scroll(1);
+    }
+    _ if key == DEFAULT_KEYBINDING.pg_up.key => output.scroll(-10),
+    _ if key == DEFAULT_KEYBINDING.pg_down.key => output.scroll(10),
     _ => {}
   }
 }
@@ -1542,10 +2888,12 @@ fn resolve_shell_container(
     }
   }
 
-  let mut non_init = pod.containers.iter().filter(|container| !container.init);
-  let first_non_init = non_init.next();
-  if let Some(container) = first_non_init {
-    if non_init.next().is_none() {
+  let mut regular = pod
+    .containers
+    .iter()
+    .filter(|container| container.kind == crate::app::pods::ContainerKind::Regular);
+  if let Some(container) = regular.next() {
+    if regular.next().is_none() {
       return Some(container.clone());
     }
   }
@@ -1560,8 +2908,8 @@ fn resolve_shell_container(
 fn handle_block_action<T: Clone>(key: Key, item: &StatefulTable<T>) -> Option<T> {
   match key {
     _ if key == DEFAULT_KEYBINDING.submit.key
-      || key == DEFAULT_KEYBINDING.describe_resource.key
       || key == DEFAULT_KEYBINDING.resource_yaml.key
+      || key == DEFAULT_KEYBINDING.copy_yaml.key
       || key == DEFAULT_KEYBINDING.decode_secret.key =>
     {
       item.get_selected_item_copy()
@@ -1589,6 +2937,44 @@ impl ScrollEvent {
 }
 
 async fn handle_block_scroll(app: &mut App, event: ScrollEvent, is_mouse: bool) {
+  scroll_block(app, event, is_mouse).await;
+  load_next_page_near_end(app).await;
+}
+
+/// Fetch the next page of a paginated list once the selection gets within
+/// `PAGE_PREFETCH_ROWS` of the end of what is loaded.
+async fn load_next_page_near_end(app: &mut App) {
+  let block = app.get_current_route().active_block;
+  let near_end = app
+    .resource_table(block)
+    .is_some_and(|table| table.rows_below_selection() < PAGE_PREFETCH_ROWS);
+  let requested = near_end
+    && app
+      .data
+      .list_pages
+      .get_mut(&block)
+      .is_some_and(ListPages::request_next);
+  if requested {
+    app.dispatch_by_active_block(block).await;
+  }
+}
+
+async fn scroll_block(app: &mut App, event: ScrollEvent, is_mouse: bool) {
+  if app.get_current_route().active_block == ActiveBlock::Containers {
+    if let Some(mounts) = app.data.container_mounts.as_mut() {
+      mounts.handle_scroll(inverse_dir(event, is_mouse));
+      return;
+    }
+    if let Some(env) = app.data.container_env.as_mut() {
+      env.txt.handle_scroll(inverse_dir(event, is_mouse));
+      return;
+    }
+  }
+  if app.get_current_route().active_block == ActiveBlock::Pods && app.pods_grouped() {
+    app.data.pod_groups.handle_scroll(event);
+    app.sync_grouped_pod_selection();
+    return;
+  }
   handle_resource_scroll!(app.get_current_route().active_block, app, event, is_mouse,
     [
       (ActiveBlock::Namespaces, namespaces),
@@ -1597,6 +2983,7 @@ async fn handle_block_scroll(app: &mut App, event: ScrollEvent, is_mouse: bool)
       (ActiveBlock::Services, services),
       (ActiveBlock::Nodes, nodes),
       (ActiveBlock::ConfigMaps, config_maps),
+      (ActiveBlock::ConfigMapData, config_map_data),
       (ActiveBlock::StatefulSets, stateful_sets),
       (ActiveBlock::ReplicaSets, replica_sets),
       (ActiveBlock::Deployments, deployments),
@@ -1616,12 +3003,20 @@ async fn handle_block_scroll(app: &mut App, event: ScrollEvent, is_mouse: bool)
       (ActiveBlock::ServiceAccounts, service_accounts),
       (ActiveBlock::Events, events),
       (ActiveBlock::NetworkPolicies, network_policies),
+      (ActiveBlock::ResourceQuotas, resource_quotas),
+      (ActiveBlock::LimitRanges, limit_ranges),
+      (ActiveBlock::PodDisruptionBudgets, pdbs),
+      (ActiveBlock::CustomResourceDefinitions, crds),
+      (ActiveBlock::ApiResources, api_resources),
+      (ActiveBlock::PodsByNode, node_pods),
       (ActiveBlock::DynamicResource, dynamic_resources),
     ],
     extra: {
       ActiveBlock::Contexts => app.data.contexts.handle_scroll(event),
       ActiveBlock::Utilization => app.data.metrics.handle_scroll(event),
       ActiveBlock::Troubleshoot => app.data.troubleshoot_findings.handle_scroll(event),
+      ActiveBlock::Watchlist => app.data.watchlist.handle_scroll(event),
+      ActiveBlock::Dashboard => {}
       ActiveBlock::Help => {
         // Grouped help is a scrolled paragraph; the offset is clamped to the
         // content height at render time.
@@ -1649,7 +3044,13 @@ async fn handle_block_scroll(app: &mut App, event: ScrollEvent, is_mouse: bool)
         }
         app.data.logs.handle_scroll(inverse_dir(event, is_mouse));
       }
-      ActiveBlock::Describe | ActiveBlock::Yaml => app
+      ActiveBlock::Describe => app
+        .data
+        .pod_constraints
+        .as_mut()
+        .unwrap_or(&mut app.data.describe_out)
+        .handle_scroll(inverse_dir(event, is_mouse)),
+      ActiveBlock::Yaml | ActiveBlock::Diff => app
         .data
         .describe_out
         .handle_scroll(inverse_dir(event, is_mouse)),
@@ -1677,22 +3078,59 @@ fn handle_menu_scroll(
   menu.state.select(Some(newpos as usize));
 }
 
-fn copy_to_clipboard(content: String, app: &mut App) {
+/// Largest text sent to the terminal's clipboard over OSC 52; terminals and
+/// multiplexers drop longer sequences, so it is refused instead.
+const OSC52_MAX_BYTES: usize = 100_000;
+
+/// Put `content` on the system clipboard, reporting a failure as an error.
+/// Without a system clipboard, as over SSH, the terminal is asked to set its
+/// own with OSC 52. Returns whether it was set.
+pub fn copy_to_clipboard(content: String, app: &mut App) -> bool {
   use std::thread;
 
   use anyhow::anyhow;
   use copypasta::{ClipboardContext, ClipboardProvider};
 
-  match ClipboardContext::new() {
-    Ok(mut ctx) => match ctx.set_contents(content) {
+  let error = match ClipboardContext::new() {
+    Ok(mut ctx) => match ctx.set_contents(content.clone()) {
       // without this sleep the clipboard is not set in some OSes
-      Ok(_) => thread::sleep(std::time::Duration::from_millis(100)),
-      Err(_) => app.handle_error(anyhow!("Unable to set clipboard contents".to_string())),
+      Ok(_) => {
+        thread::sleep(std::time::Duration::from_millis(100));
+        return true;
+      }
+      Err(_) => "Unable to set clipboard contents".to_string(),
     },
-    Err(err) => {
-      app.handle_error(anyhow!("Unable to obtain clipboard: {}", err));
-    }
+    Err(err) => format!("Unable to obtain clipboard: {}", err),
   };
+  let sent = osc52_sequence(&content).and_then(|sequence| {
+    let mut stdout = io::stdout();
+    stdout.write_all(sequence.as_bytes())?;
+    stdout.flush()
+  });
+  match sent {
+    Ok(()) => true,
+    Err(e) => {
+      app.handle_error(anyhow!("{}. {}", error, e));
+      false
+    }
+  }
+}
+
+/// The OSC 52 escape sequence that sets the terminal's clipboard to `content`.
+fn osc52_sequence(content: &str) -> io::Result<String> {
+  use base64::Engine;
+
+  if content.len() > OSC52_MAX_BYTES {
+    return Err(io::Error::other(format!(
+      "{} is too large for the terminal clipboard (the limit is {})",
+      byte_size(content.len()),
+      byte_size(OSC52_MAX_BYTES)
+    )));
+  }
+  Ok(format!(
+    "\x1b]52;c;{}\x07",
+    base64::engine::general_purpose::STANDARD.encode(content)
+  ))
 }
 
 fn dump_error_history(app: &mut App, output_dir: Option<&Path>) {
@@ -1713,7 +3151,7 @@ fn write_error_history_file(
 
   let path = dir.join(format!(
     "kdash-errors-{}.log",
-    chrono::Local::now().format("%Y%m%d%H%M%S")
+    utils::now().format("%Y%m%d%H%M%S")
   ));
 
   fs::write(&path, format_error_history(history))?;
@@ -1749,20 +3187,27 @@ mod tests {
     time::{SystemTime, UNIX_EPOCH},
   };
 
-  use crossterm::event::KeyCode;
+  use crossterm::event::{KeyCode, KeyModifiers};
   use k8s_openapi::ByteString;
   use kube::{
     api::ObjectMeta,
     core::{ApiResource, DynamicObject},
     discovery::Scope,
   };
+  use ratatui::layout::Rect;
   use tokio::sync::mpsc;
 
   use super::*;
   use crate::app::{
+    api_resources::KubeApiResource,
     contexts::KubeContext,
-    dynamic::{dynamic_cache_key, KubeDynamicKind, KubeDynamicResource},
-    pods::{KubeContainer, KubePod},
+    crds::KubeCrd,
+    dynamic::{dynamic_cache_key, KubeDynamicResource},
+    load_state::LoadState,
+    models::ColumnScroll,
+    node_pods::{KubeNodePods, PENDING_BUCKET},
+    ns::KubeNs,
+    pods::{ContainerKind, KubeContainer, KubePod},
     PendingShellExec,
   };
 
@@ -1932,7 +3377,7 @@ mod tests {
     let mut init_container = KubeContainer::default();
     init_container.name = "init-db".into();
     init_container.pod_name = "pod-1".into();
-    init_container.init = true;
+    init_container.kind = ContainerKind::Init;
     pod.containers = vec![app_container, init_container];
     app.data.pods.set_items(vec![pod]);
     app.data.containers.items.clear();
@@ -2009,6 +3454,58 @@ mod tests {
     assert_eq!(app.pending_shell_exec(), None);
   }
 
+  #[tokio::test]
+  async fn test_end_in_logs_resumes_following() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+    app.data.logs.add_record("ready".into());
+
+    send_keys(&mut app, &[KeyCode::Up]).await;
+    assert!(!app.log_auto_scroll);
+    send_keys(&mut app, &[KeyCode::End]).await;
+    assert!(app.log_auto_scroll);
+
+    send_keys(&mut app, &[KeyCode::Up]).await;
+    let k = shift_char('G');
+    handle_key_events(Key::from(k), k, &mut app).await;
+    assert!(app.log_auto_scroll);
+  }
+
+  #[tokio::test]
+  async fn test_log_search_pauses_follow_and_escape_clears_it() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+    app.data.logs.add_record("ready".into());
+
+    for c in ['/', 'r', 'e'] {
+      let key_evt = KeyEvent::from(KeyCode::Char(c));
+      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    }
+    assert!(app.data.logs.search_active);
+    assert_eq!(app.data.logs.search, "re");
+    assert!(!app.log_auto_scroll);
+
+    let key_evt = KeyEvent::from(KeyCode::Enter);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(!app.data.logs.search_active);
+
+    // Following stays paused until the search is cleared
+    let key_evt = KeyEvent::from(KeyCode::Char('s'));
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(!app.log_auto_scroll);
+
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(app.data.logs.search.is_empty());
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Logs);
+
+    let key_evt = KeyEvent::from(KeyCode::Char('s'));
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(app.log_auto_scroll);
+  }
+
   #[tokio::test]
   async fn test_shell_exec_key_in_troubleshoot_containers_queues_request() {
     let mut app = App::default();
@@ -2117,6 +3614,8 @@ mod tests {
         ResourceAction::Logs,
         ResourceAction::PreviousLogs,
         ResourceAction::PortForward,
+        ResourceAction::Evict,
+        ResourceAction::RestartCrashLooping,
         ResourceAction::Delete
       ]
     );
@@ -2276,6 +3775,7 @@ mod tests {
   async fn test_menu_cordon_on_schedulable_node_confirms_cordon() {
     let mut app = App::default();
     app.route_home();
+    app.config.confirm = Some(vec!["cordon".into()]);
     app.push_navigation_stack(RouteId::Home, ActiveBlock::Nodes);
     app.data.nodes.set_items(vec![make_node("n1", false)]);
 
@@ -2298,10 +3798,43 @@ mod tests {
     );
   }
 
+  #[tokio::test]
+  async fn test_menu_cordon_runs_without_confirmation_by_default() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let (sync_io_stream_tx, _sync_io_stream_rx) = mpsc::channel(10);
+    let (sync_io_cmd_tx, _sync_io_cmd_rx) = mpsc::channel(10);
+    let mut app = App::new(
+      sync_io_tx,
+      sync_io_stream_tx,
+      sync_io_cmd_tx,
+      false,
+      1,
+      App::default().log_tail_lines,
+      crate::config::KdashConfig::default(),
+    );
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Nodes);
+    app.data.nodes.set_items(vec![make_node("n1", false)]);
+
+    open_menu_and_select(&mut app, 3).await;
+
+    assert!(app.modal.is_none());
+    assert_eq!(
+      sync_io_rx.recv().await.unwrap(),
+      IoEvent::PatchResource {
+        block: ActiveBlock::Nodes,
+        name: "n1".into(),
+        namespace: None,
+        patch: ResourcePatch::SetUnschedulable(true),
+      }
+    );
+  }
+
   #[tokio::test]
   async fn test_menu_cordon_on_cordoned_node_confirms_uncordon() {
     let mut app = App::default();
     app.route_home();
+    app.config.confirm = Some(vec!["uncordon".into()]);
     app.push_navigation_stack(RouteId::Home, ActiveBlock::Nodes);
     app.data.nodes.set_items(vec![make_node("n1", true)]);
 
@@ -2352,43 +3885,163 @@ mod tests {
   }
 
   #[tokio::test]
-  async fn test_delete_key_is_noop_without_selected_row() {
+  async fn test_read_only_delete_and_edit_keys_explain_instead_of_acting() {
     let mut app = App::default();
+    app.read_only = true;
     app.route_home();
-    // No pods set → nothing selected.
+    let mut pod = KubePod::default();
+    pod.namespace = "team-a".into();
+    pod.name = "pod-1".into();
+    app.data.pods.set_items(vec![pod]);
 
     let ctrl_d = ctrl_key('d');
     handle_key_events(Key::from(ctrl_d), ctrl_d, &mut app).await;
-
     assert!(app.modal.is_none());
+    assert_eq!(
+      app.status_message.text(),
+      crate::app::read_only::READ_ONLY_MESSAGE
+    );
+
+    let e = KeyEvent::from(KeyCode::Char('e'));
+    handle_key_events(Key::from(e), e, &mut app).await;
+    assert!(app.pending_edit().is_none());
   }
 
   #[tokio::test]
-  async fn test_modal_cancel_closes_without_dispatch() {
+  async fn test_angle_brackets_scroll_table_columns() {
     let mut app = App::default();
     app.route_home();
-    let mut pod = KubePod::default();
-    pod.namespace = "team-a".into();
-    pod.name = "pod-1".into();
-    app.data.pods.set_items(vec![pod]);
+    let left = KeyEvent::from(KeyCode::Char('<'));
+    let right = KeyEvent::from(KeyCode::Char('>'));
 
-    let ctrl_d = ctrl_key('d');
-    handle_key_events(Key::from(ctrl_d), ctrl_d, &mut app).await;
-    assert!(app.modal.is_some());
+    handle_key_events(Key::from(right), right, &mut app).await;
+    handle_key_events(Key::from(right), right, &mut app).await;
+    assert_eq!(
+      app.data.pods.column_scroll,
+      ColumnScroll {
+        offset: 2,
+        pin_name: true
+      }
+    );
 
-    let n = KeyEvent::from(KeyCode::Char('n'));
-    handle_key_events(Key::from(n), n, &mut app).await;
-    assert!(app.modal.is_none());
+    handle_key_events(Key::from(left), left, &mut app).await;
+    handle_key_events(Key::from(left), left, &mut app).await;
+    handle_key_events(Key::from(left), left, &mut app).await;
+    assert_eq!(app.data.pods.column_scroll.offset, 0);
   }
 
   #[tokio::test]
-  async fn test_modal_confirm_clears_modal() {
+  async fn test_space_selects_rows_for_a_batch_delete() {
     let mut app = App::default();
     app.route_home();
-    let mut pod = KubePod::default();
-    pod.namespace = "team-a".into();
-    pod.name = "pod-1".into();
-    app.data.pods.set_items(vec![pod]);
+    let pods = ["pod-1", "pod-2", "pod-3"].map(|name| {
+      let mut pod = KubePod::default();
+      pod.namespace = "team-a".into();
+      pod.name = name.into();
+      pod
+    });
+    app.data.pods.set_items(pods.to_vec());
+    let space = KeyEvent::from(KeyCode::Char(' '));
+    let down = KeyEvent::from(KeyCode::Down);
+    let marked = |name: &str| MarkedRow {
+      name: name.into(),
+      namespace: Some("team-a".into()),
+    };
+
+    handle_key_events(Key::from(space), space, &mut app).await;
+    handle_key_events(Key::from(down), down, &mut app).await;
+    handle_key_events(Key::from(space), space, &mut app).await;
+    handle_key_events(Key::from(down), down, &mut app).await;
+    handle_key_events(Key::from(space), space, &mut app).await;
+    // Space on a selected row unselects it.
+    handle_key_events(Key::from(space), space, &mut app).await;
+    assert_eq!(app.data.pods.marked, vec![marked("pod-1"), marked("pod-2")]);
+    assert!(marked("pod-2").matches(&pods[1]));
+    assert!(!marked("pod-2").matches(&pods[2]));
+
+    let ctrl_d = ctrl_key('d');
+    handle_key_events(Key::from(ctrl_d), ctrl_d, &mut app).await;
+    let modal = app
+      .modal
+      .as_ref()
+      .expect("delete should open a confirm modal");
+    assert_eq!(
+      modal.on_confirm,
+      IoEvent::DeleteResources {
+        block: ActiveBlock::Pods,
+        rows: vec![marked("pod-1"), marked("pod-2")],
+      }
+    );
+    assert!(modal.prompt.contains("Delete 2 selected pod(s)"));
+  }
+
+  #[tokio::test]
+  async fn test_delete_key_is_noop_without_selected_row() {
+    let mut app = App::default();
+    app.route_home();
+    // No pods set → nothing selected.
+
+    let ctrl_d = ctrl_key('d');
+    handle_key_events(Key::from(ctrl_d), ctrl_d, &mut app).await;
+
+    assert!(app.modal.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_modal_cancel_closes_without_dispatch() {
+    let mut app = App::default();
+    app.route_home();
+    let mut pod = KubePod::default();
+    pod.namespace = "team-a".into();
+    pod.name = "pod-1".into();
+    app.data.pods.set_items(vec![pod]);
+
+    let ctrl_d = ctrl_key('d');
+    handle_key_events(Key::from(ctrl_d), ctrl_d, &mut app).await;
+    assert!(app.modal.is_some());
+
+    let n = KeyEvent::from(KeyCode::Char('n'));
+    handle_key_events(Key::from(n), n, &mut app).await;
+    assert!(app.modal.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_declining_to_apply_a_rejected_edit_discards_it() {
+    let dir = std::env::temp_dir().join(format!("kdash-edit-declined-{}", std::process::id()));
+    fs::create_dir(&dir).unwrap();
+    let file = dir.join("deployment-web.yaml");
+    fs::write(&file, "replicas: 3\n").unwrap();
+    let mut app = App::default();
+    app.open_modal(Modal::confirm(
+      "Apply anyway?",
+      "The dry-run failed",
+      IoCmdEvent::ApplyEdit {
+        kind: "deployment".into(),
+        name: "web".into(),
+        file: file.clone(),
+        skip_dry_run: true,
+      },
+    ));
+
+    let n = KeyEvent::from(KeyCode::Char('n'));
+    handle_key_events(Key::from(n), n, &mut app).await;
+
+    assert!(app.modal.is_none());
+    assert!(!dir.exists());
+    assert_eq!(
+      app.status_message.text(),
+      "Discarded the changes to deployment 'web'"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_modal_confirm_clears_modal() {
+    let mut app = App::default();
+    app.route_home();
+    let mut pod = KubePod::default();
+    pod.namespace = "team-a".into();
+    pod.name = "pod-1".into();
+    app.data.pods.set_items(vec![pod]);
 
     let ctrl_d = ctrl_key('d');
     handle_key_events(Key::from(ctrl_d), ctrl_d, &mut app).await;
@@ -2409,10 +4062,11 @@ mod tests {
     app.data.pods.set_items(vec![pod]);
 
     // Open the action menu and move to the Delete entry
-    // (Describe, YAML, Edit, Logs, Previous logs, Port-forward, Delete → index 6).
+    // (Describe, YAML, Edit, Logs, Previous logs, Port-forward, Evict,
+    // Restart crash-looping pods, Delete → index 8).
     let m = KeyEvent::from(KeyCode::Char('m'));
     handle_key_events(Key::from(m), m, &mut app).await;
-    for _ in 0..6 {
+    for _ in 0..8 {
       let down = KeyEvent::from(KeyCode::Down);
       handle_key_events(Key::from(down), down, &mut app).await;
     }
@@ -2516,1268 +4170,3016 @@ mod tests {
     let w = KeyEvent::from(KeyCode::Char('w'));
     handle_key_events(Key::from(w), w, &mut app).await;
     assert!(!app.log_wrap, "'w' toggles wrap in the log view");
-  }
 
-  #[tokio::test]
-  async fn test_t_outside_log_view_does_not_toggle_timestamps() {
-    let mut app = App::default();
-    app.route_home();
-    assert_ne!(app.get_current_route().active_block, ActiveBlock::Logs);
+    assert!(!app.log_json);
+    let k = shift_char('J');
+    handle_key_events(Key::from(k), k, &mut app).await;
+    assert!(app.log_json, "'J' toggles JSON formatting in the log view");
+  }
 
-    let before = app.log_timestamps;
-    let t = KeyEvent::from(KeyCode::Char('t'));
-    handle_key_events(Key::from(t), t, &mut app).await;
-    assert_eq!(
-      app.log_timestamps, before,
-      "'t' cycles theme (not log timestamps) outside the log view"
-    );
+  #[test]
+  fn test_flatten_paste_joins_lines() {
+    assert_eq!(flatten_paste("web-1"), "web-1");
+    assert_eq!(flatten_paste("error\r\ntimeout\n"), "error timeout");
+    assert_eq!(flatten_paste("a\tb\nc"), "a b c");
   }
 
   #[tokio::test]
-  async fn test_restart_key_opens_confirm_for_deployment() {
-    use k8s_openapi::api::apps::v1::Deployment;
-
-    use crate::app::deployments::KubeDeployment;
-
+  async fn test_paste_types_into_active_input_only() {
     let mut app = App::default();
     app.route_home();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
-    let mut dep = KubeDeployment::from(Deployment::default());
-    dep.name = "web".into();
-    dep.namespace = "team-a".into();
-    app.data.deployments.set_items(vec![dep]);
-
-    let r = KeyEvent::from(KeyCode::Char('r'));
-    handle_key_events(Key::from(r), r, &mut app).await;
-
-    let modal = app
-      .modal
-      .as_ref()
-      .expect("restart should open a confirm modal");
-    assert_eq!(
-      modal.on_confirm,
-      IoEvent::PatchResource {
-        block: ActiveBlock::Deployments,
-        name: "web".into(),
-        namespace: Some("team-a".into()),
-        patch: ResourcePatch::RolloutRestart,
-      }
-    );
-  }
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
 
-  fn deployment_with_replicas(
-    name: &str,
-    ns: &str,
-    replicas: Option<i32>,
-  ) -> crate::app::deployments::KubeDeployment {
-    use k8s_openapi::api::apps::v1::{Deployment, DeploymentSpec};
+    // Outside an input a paste must not replay as keybindings
+    handle_paste("wt", &mut app);
+    assert!(app.log_wrap);
+    assert!(!app.log_timestamps);
 
-    use crate::app::deployments::KubeDeployment;
+    send_keys(&mut app, &[KeyCode::Char('/')]).await;
+    handle_paste("connection\nrefused\n", &mut app);
+    assert_eq!(app.data.logs.search, "connection refused");
 
-    let dep = Deployment {
-      spec: replicas.map(|r| DeploymentSpec {
-        replicas: Some(r),
-        ..Default::default()
-      }),
-      ..Default::default()
-    };
-    let mut dep = KubeDeployment::from(dep);
-    dep.name = name.into();
-    dep.namespace = ns.into();
-    dep
+    send_keys(&mut app, &[KeyCode::Enter]).await;
+    let s = shift_char('S');
+    handle_key_events(Key::from(s), s, &mut app).await;
+    handle_paste("15m", &mut app);
+    assert_eq!(app.input_modal.as_ref().unwrap().buffer, "15m");
   }
 
   #[tokio::test]
-  async fn test_menu_scale_deployment_opens_input_prefilled() {
+  async fn test_log_mark_input_drops_a_noted_marker() {
     let mut app = App::default();
     app.route_home();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
-    app
-      .data
-      .deployments
-      .set_items(vec![deployment_with_replicas("web", "team-a", Some(2))]);
-
-    // Deployments menu: Describe, YAML, Edit, Logs, Restart, Scale, Delete → Scale at index 5.
-    open_menu_and_select(&mut app, 5).await;
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+    app.data.logs.add_records(vec!["started".into()]);
 
-    let input = app
-      .input_modal
-      .as_ref()
-      .expect("scale should open an input modal");
-    // Prefilled with the current replica count.
-    assert_eq!(input.buffer, "2");
+    let m = shift_char('M');
+    handle_key_events(Key::from(m), m, &mut app).await;
     assert_eq!(
-      input.action,
-      InputAction::Scale {
-        block: ActiveBlock::Deployments,
-        name: "web".into(),
-        namespace: Some("team-a".into()),
-        kind: "deployment".into(),
-      }
+      app.input_modal.as_ref().map(|modal| modal.action.clone()),
+      Some(InputAction::LogMark)
     );
-    // No confirm modal yet — only the input overlay.
-    assert!(app.modal.is_none());
+    handle_paste("rollback", &mut app);
+    send_keys(&mut app, &[KeyCode::Enter]).await;
+
+    assert!(app.input_modal.is_none());
+    assert_eq!(app.data.logs.marker_count(), 1);
+    let saved = app.data.logs.saved_text();
+    let marker = saved.lines().nth(1).unwrap();
+    assert!(marker.starts_with("--- MARK ") && marker.ends_with(": rollback ---"));
+    assert!(app.status_message.text().starts_with("Marked the logs at "));
   }
 
   #[tokio::test]
-  async fn test_scale_input_submit_chains_to_confirm_modal() {
+  async fn test_log_since_input_sets_and_clears_window() {
     let mut app = App::default();
     app.route_home();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
-    app
-      .data
-      .deployments
-      .set_items(vec![deployment_with_replicas("web", "team-a", Some(2))]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+
+    let s = shift_char('S');
+    handle_key_events(Key::from(s), s, &mut app).await;
+    let modal = app
+      .input_modal
+      .as_ref()
+      .expect("Shift+S opens the since input");
+    assert_eq!(modal.action, InputAction::LogSince);
+    assert!(modal.buffer.is_empty());
 
-    // Menu: Describe, YAML, Edit, Logs, Restart, Scale, Delete → Scale at index 5.
-    // Open the input modal, replace the prefilled "2" with "5", then submit.
     send_keys(
       &mut app,
       &[
+        KeyCode::Char('1'),
+        KeyCode::Char('0'),
         KeyCode::Char('m'),
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Enter,
-        KeyCode::Backspace,
-        KeyCode::Char('5'),
         KeyCode::Enter,
       ],
     )
     .await;
-
-    // Input modal closed, confirm modal opened with the scale patch.
     assert!(app.input_modal.is_none());
-    let modal = app
-      .modal
-      .as_ref()
-      .expect("valid scale submit should chain into a confirm modal");
-    assert!(modal.prompt.contains("to 5 replica(s)"));
-    assert_eq!(
-      modal.on_confirm,
-      IoEvent::PatchResource {
-        block: ActiveBlock::Deployments,
-        name: "web".into(),
-        namespace: Some("team-a".into()),
-        patch: ResourcePatch::SetReplicas(5),
-      }
-    );
-  }
-
-  #[tokio::test]
-  async fn test_scale_input_invalid_keeps_modal_open_with_error() {
-    let mut app = App::default();
-    app.route_home();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
-    app
-      .data
-      .deployments
-      .set_items(vec![deployment_with_replicas("web", "team-a", Some(2))]);
+    assert_eq!(app.log_since, Some(std::time::Duration::from_secs(600)));
 
-    // Open the scale input modal (Scale at index 5), type a non-numeric value
-    // and submit.
+    handle_key_events(Key::from(s), s, &mut app).await;
+    assert_eq!(app.input_modal.as_ref().unwrap().buffer, "10m");
     send_keys(
       &mut app,
       &[
-        KeyCode::Char('m'),
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Enter,
-        KeyCode::Char('x'),
+        KeyCode::Backspace,
+        KeyCode::Backspace,
+        KeyCode::Backspace,
         KeyCode::Enter,
       ],
     )
     .await;
+    assert_eq!(app.log_since, None, "an empty duration clears the window");
+  }
 
-    // Stays open with an inline error; no confirm modal, no dispatch.
-    let input = app
+  #[tokio::test]
+  async fn test_log_since_input_rejects_bad_duration() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+
+    let s = shift_char('S');
+    handle_key_events(Key::from(s), s, &mut app).await;
+    send_keys(&mut app, &[KeyCode::Char('x'), KeyCode::Enter]).await;
+    let modal = app
       .input_modal
       .as_ref()
       .expect("invalid input keeps the modal open");
-    assert!(input.error.is_some());
-    assert!(app.modal.is_none());
+    assert!(modal.error.is_some());
+    assert_eq!(app.log_since, None);
   }
 
   #[tokio::test]
-  async fn test_scale_input_esc_cancels() {
+  async fn test_t_outside_log_view_does_not_toggle_timestamps() {
     let mut app = App::default();
     app.route_home();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
-    app
-      .data
-      .deployments
-      .set_items(vec![deployment_with_replicas("web", "team-a", Some(2))]);
+    assert_ne!(app.get_current_route().active_block, ActiveBlock::Logs);
 
-    // Open the scale input modal (Scale at index 5), then cancel with Esc.
-    send_keys(
-      &mut app,
-      &[
-        KeyCode::Char('m'),
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Down,
-        KeyCode::Enter,
-        KeyCode::Esc,
-      ],
-    )
-    .await;
-
-    assert!(app.input_modal.is_none());
-    assert!(app.modal.is_none());
+    let before = app.log_timestamps;
+    let t = KeyEvent::from(KeyCode::Char('t'));
+    handle_key_events(Key::from(t), t, &mut app).await;
+    assert_eq!(
+      app.log_timestamps, before,
+      "'t' cycles theme (not log timestamps) outside the log view"
+    );
   }
 
   #[tokio::test]
-  async fn test_port_forward_key_opens_input_for_pod() {
+  async fn test_describe_role_binding_resets_binding_rules() {
+    use k8s_openapi::api::rbac::v1::RoleBinding;
+
+    use crate::app::roles::{BindingRules, KubeRoleBinding};
+
     let mut app = App::default();
     app.route_home();
-    let mut pod = KubePod::default();
-    pod.name = "web".into();
-    pod.namespace = "team-a".into();
-    app.data.pods.set_items(vec![pod]);
-
-    let f = KeyEvent::from(KeyCode::Char('f'));
-    handle_key_events(Key::from(f), f, &mut app).await;
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::RoleBindings);
+    let mut rb = KubeRoleBinding::from(RoleBinding::default());
+    rb.name = "kiali".into();
+    rb.namespace = "default".into();
+    app.data.role_bindings.set_items(vec![rb]);
+    app.data.binding_rules = Some(BindingRules {
+      role: "Role/previous".into(),
+      rules: None,
+    });
+
+    let d = KeyEvent::from(KeyCode::Char('d'));
+    handle_key_events(Key::from(d), d, &mut app).await;
 
-    let input = app
-      .input_modal
-      .as_ref()
-      .expect("port-forward should open an input modal");
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
     assert_eq!(
-      input.action,
-      InputAction::PortForward {
-        kind: "pods".into(),
-        namespace: "team-a".into(),
-        name: "web".into(),
-      }
+      app.data.binding_rules, None,
+      "rules from a previous describe are not shown for this one"
     );
   }
 
   #[tokio::test]
-  async fn test_port_forward_input_submit_closes_modal() {
+  async fn test_describe_daemon_set_resets_node_coverage() {
+    use k8s_openapi::api::apps::v1::DaemonSet;
+
+    use crate::app::daemonsets::{DaemonSetCoverage, KubeDaemonSet};
+
     let mut app = App::default();
     app.route_home();
-    let mut pod = KubePod::default();
-    pod.name = "web".into();
-    pod.namespace = "team-a".into();
-    app.data.pods.set_items(vec![pod]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::DaemonSets);
+    let mut ds = KubeDaemonSet::from(DaemonSet::default());
+    ds.name = "fluent-bit".into();
+    ds.namespace = "logging".into();
+    app.data.daemon_sets.set_items(vec![ds]);
+    app.data.daemon_set_coverage = Some(DaemonSetCoverage::default());
 
-    // f → input modal, type "8080:80", submit. No io_stream channel in tests, so
-    // the dispatch is a no-op, but the modal should close cleanly.
-    send_keys(
-      &mut app,
-      &[
-        KeyCode::Char('f'),
-        KeyCode::Char('8'),
-        KeyCode::Char('0'),
-        KeyCode::Char('8'),
-        KeyCode::Char('0'),
-        KeyCode::Char(':'),
-        KeyCode::Char('8'),
-        KeyCode::Char('0'),
-        KeyCode::Enter,
-      ],
-    )
-    .await;
+    let d = KeyEvent::from(KeyCode::Char('d'));
+    handle_key_events(Key::from(d), d, &mut app).await;
 
-    assert!(app.input_modal.is_none());
-    assert!(app.modal.is_none());
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
+    assert_eq!(
+      app.data.daemon_set_coverage, None,
+      "coverage from a previous describe is not shown for this one"
+    );
   }
 
   #[tokio::test]
-  async fn test_port_forwards_list_key_empty_is_noop() {
+  async fn test_describe_service_resets_endpoints() {
+    use k8s_openapi::api::core::v1::Service;
+
+    use crate::app::svcs::{KubeSvc, ServiceEndpoints};
+
     let mut app = App::default();
     app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Services);
+    let mut svc = KubeSvc::from(Service::default());
+    svc.name = "web".into();
+    svc.namespace = "default".into();
+    app.data.services.set_items(vec![svc]);
+    app.data.service_endpoints = Some(ServiceEndpoints::default());
 
-    let f = shift_char('F');
-    handle_key_events(Key::from(f), f, &mut app).await;
+    let d = KeyEvent::from(KeyCode::Char('d'));
+    handle_key_events(Key::from(d), d, &mut app).await;
 
-    assert!(!app.show_port_forwards);
-    assert!(!app.status_message.is_empty());
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
+    assert_eq!(
+      app.data.service_endpoints, None,
+      "endpoints from a previous describe are not shown for this one"
+    );
   }
 
   #[tokio::test]
-  async fn test_port_forwards_overlay_navigates_and_swallows_keys() {
-    use crate::app::port_forward::{PortForward, PortForwardStatus};
+  async fn test_describe_pod_walks_its_controllers() {
+    use crate::app::pod_owners::PodControllers;
 
-    let mut app = App::default();
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let (sync_io_stream_tx, _sync_io_stream_rx) = mpsc::channel(10);
+    let (sync_io_cmd_tx, _sync_io_cmd_rx) = mpsc::channel(10);
+    let mut app = App::new(
+      sync_io_tx,
+      sync_io_stream_tx,
+      sync_io_cmd_tx,
+      false,
+      1,
+      App::default().log_tail_lines,
+      crate::config::KdashConfig::default(),
+    );
     app.route_home();
-    for (id, port) in [(0u64, 8080u16), (1, 9090)] {
-      app.port_forwards.push(PortForward {
-        id,
-        kind: "pods".into(),
-        namespace: "default".into(),
-        name: format!("web-{id}"),
-        local_port: port,
-        remote_port: 80,
-        status: PortForwardStatus::Active,
-        child: None,
-      });
-    }
-
-    let upper_f = shift_char('F');
-    handle_key_events(Key::from(upper_f), upper_f, &mut app).await;
-    assert!(app.show_port_forwards);
-    assert_eq!(app.selected_port_forward_id(), Some(0));
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
+    let mut pod = KubePod::default();
+    pod.name = "web-7f9c-abcde".into();
+    pod.namespace = "default".into();
+    app.data.pods.set_items(vec![pod]);
+    app.data.pod_controllers = Some(PodControllers::default());
 
-    // Down moves the selection; a tab-switch key is swallowed while open.
-    let block_before = app.get_current_route().active_block;
-    send_keys(&mut app, &[KeyCode::Down, KeyCode::Char('2')]).await;
-    assert_eq!(app.selected_port_forward_id(), Some(1));
-    assert_eq!(app.get_current_route().active_block, block_before);
+    let d = KeyEvent::from(KeyCode::Char('d'));
+    handle_key_events(Key::from(d), d, &mut app).await;
 
-    let esc = KeyEvent::from(KeyCode::Esc);
-    handle_key_events(Key::from(esc), esc, &mut app).await;
-    assert!(!app.show_port_forwards);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
+    assert_eq!(app.data.pod_controllers, None);
+    assert_eq!(
+      sync_io_rx.recv().await.unwrap(),
+      IoEvent::GetPodControllers {
+        name: "web-7f9c-abcde".into(),
+        namespace: "default".into(),
+      }
+    );
   }
 
   #[tokio::test]
-  async fn test_restart_key_is_noop_on_non_workload_block() {
+  async fn test_deployment_replica_set_drill_down_and_escape() {
+    use k8s_openapi::api::apps::v1::Deployment;
+
+    use crate::app::deployments::KubeDeployment;
+
     let mut app = App::default();
     app.route_home();
-    let mut pod = KubePod::default();
-    pod.name = "p".into();
-    pod.namespace = "n".into();
-    app.data.pods.set_items(vec![pod]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
+    let mut dep = KubeDeployment::from(Deployment::default());
+    dep.name = "web".into();
+    dep.namespace = "team-a".into();
+    app.data.deployments.set_items(vec![dep]);
 
-    let r = KeyEvent::from(KeyCode::Char('r'));
+    let r = shift_char('R');
     handle_key_events(Key::from(r), r, &mut app).await;
 
-    assert!(app.modal.is_none());
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::ReplicaSets
+    );
+    assert_eq!(app.data.selected.replica_set_owner, Some("web".into()));
+    assert_eq!(
+      app.data.selected.replica_set_owner_ns,
+      Some("team-a".into())
+    );
+
+    let esc = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(esc), esc, &mut app).await;
+
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Deployments
+    );
+    assert_eq!(app.data.selected.replica_set_owner, None);
+    assert_eq!(app.data.selected.replica_set_owner_ns, None);
   }
 
   #[tokio::test]
-  async fn test_menu_suspend_cronjob_confirms_suspend() {
-    use k8s_openapi::api::batch::v1::CronJob;
+  async fn test_restart_key_opens_confirm_for_deployment() {
+    use k8s_openapi::api::apps::v1::Deployment;
 
-    use crate::app::cronjobs::KubeCronJob;
+    use crate::app::deployments::KubeDeployment;
 
     let mut app = App::default();
     app.route_home();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::CronJobs);
-    let mut cronjob = KubeCronJob::from(CronJob::default());
-    cronjob.name = "backup".into();
-    cronjob.namespace = "default".into();
-    cronjob.suspend = false;
-    app.data.cronjobs.set_items(vec![cronjob]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
+    let mut dep = KubeDeployment::from(Deployment::default());
+    dep.name = "web".into();
+    dep.namespace = "team-a".into();
+    app.data.deployments.set_items(vec![dep]);
 
-    // CronJobs menu: Describe, YAML, Edit, Logs, Suspend, Trigger, Delete → Suspend at index 4.
-    open_menu_and_select(&mut app, 4).await;
+    let r = KeyEvent::from(KeyCode::Char('r'));
+    handle_key_events(Key::from(r), r, &mut app).await;
 
     let modal = app
       .modal
       .as_ref()
-      .expect("suspend should open a confirm modal");
+      .expect("restart should open a confirm modal");
     assert_eq!(
       modal.on_confirm,
       IoEvent::PatchResource {
-        block: ActiveBlock::CronJobs,
-        name: "backup".into(),
-        namespace: Some("default".into()),
-        patch: ResourcePatch::SetSuspend(true),
+        block: ActiveBlock::Deployments,
+        name: "web".into(),
+        namespace: Some("team-a".into()),
+        patch: ResourcePatch::RolloutRestart,
       }
     );
   }
 
   #[tokio::test]
-  async fn test_menu_trigger_cronjob_confirms_trigger() {
-    use k8s_openapi::api::batch::v1::CronJob;
+  async fn test_jump_to_logs_resolves_the_workload_pods_on_the_stream() {
+    use k8s_openapi::{
+      api::apps::v1::{Deployment, DeploymentSpec},
+      apimachinery::pkg::apis::meta::v1::LabelSelector,
+    };
 
-    use crate::app::cronjobs::KubeCronJob;
+    use crate::app::{deployments::KubeDeployment, svcs::KubeSvc};
 
-    let mut app = App::default();
+    let (sync_io_tx, _sync_io_rx) = mpsc::channel(10);
+    let (sync_io_stream_tx, mut sync_io_stream_rx) = mpsc::channel::<IoStreamEvent>(10);
+    let (sync_io_cmd_tx, _sync_io_cmd_rx) = mpsc::channel(10);
+    let mut app = App::new(
+      sync_io_tx,
+      sync_io_stream_tx,
+      sync_io_cmd_tx,
+      false,
+      1,
+      App::default().log_tail_lines,
+      crate::config::KdashConfig::default(),
+    );
     app.route_home();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::CronJobs);
-    let mut cronjob = KubeCronJob::from(CronJob::default());
-    cronjob.name = "backup".into();
-    cronjob.namespace = "default".into();
-    app.data.cronjobs.set_items(vec![cronjob]);
-
-    // CronJobs menu: Describe, YAML, Edit, Logs, Suspend, Trigger, Delete → Trigger at index 5.
-    open_menu_and_select(&mut app, 5).await;
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
+    let mut dep = KubeDeployment::from(Deployment {
+      spec: Some(DeploymentSpec {
+        selector: LabelSelector {
+          match_labels: Some([("app".to_owned(), "web".to_owned())].into()),
+          ..Default::default()
+        },
+        ..Default::default()
+      }),
+      ..Default::default()
+    });
+    dep.name = "web".into();
+    dep.namespace = "team-a".into();
+    app.data.deployments.set_items(vec![dep]);
 
-    let modal = app
-      .modal
-      .as_ref()
-      .expect("trigger should open a confirm modal");
+    let v = shift_char('V');
+    handle_key_events(Key::from(v), v, &mut app).await;
     assert_eq!(
-      modal.on_confirm,
-      IoEvent::TriggerCronJob {
-        name: "backup".into(),
-        namespace: "default".into(),
+      sync_io_stream_rx.try_recv().unwrap(),
+      IoStreamEvent::JumpToLogs {
+        name: "web".into(),
+        namespace: "team-a".into(),
+        selector: "app=web".into(),
+        resource_name: "deployment".into(),
       }
     );
+
+    // a service without a selector has no pods to pick from
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Services);
+    let mut svc = KubeSvc::from(k8s_openapi::api::core::v1::Service::default());
+    svc.name = "external-db".into();
+    app.data.services.set_items(vec![svc]);
+    handle_key_events(Key::from(v), v, &mut app).await;
+    assert!(sync_io_stream_rx.try_recv().is_err());
+    assert_eq!(
+      app.status_message.text(),
+      "service external-db selects no pods"
+    );
+  }
+
+  fn deployment_with_replicas(
+    name: &str,
+    ns: &str,
+    replicas: Option<i32>,
+  ) -> crate::app::deployments::KubeDeployment {
+    use k8s_openapi::api::apps::v1::{Deployment, DeploymentSpec};
+
+    use crate::app::deployments::KubeDeployment;
+
+    let dep = Deployment {
+      spec: replicas.map(|r| DeploymentSpec {
+        replicas: Some(r),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+    let mut dep = KubeDeployment::from(dep);
+    dep.name = name.into();
+    dep.namespace = ns.into();
+    dep
   }
 
   #[tokio::test]
-  async fn test_previous_logs_key_in_containers_opens_previous_log_view() {
+  async fn test_menu_scale_deployment_opens_input_prefilled() {
     let mut app = App::default();
     app.route_home();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
-    let mut pod = KubePod::default();
-    pod.namespace = "team-a".into();
-    pod.name = "pod-1".into();
-    app.data.pods.set_items(vec![pod]);
-    let mut container = KubeContainer::default();
-    container.name = "app".into();
-    app.data.containers.set_items(vec![container]);
-
-    let p = KeyEvent::from(KeyCode::Char('p'));
-    handle_key_events(Key::from(p), p, &mut app).await;
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
+    app
+      .data
+      .deployments
+      .set_items(vec![deployment_with_replicas("web", "team-a", Some(2))]);
 
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::Logs);
-    assert!(app.log_previous);
-    assert_eq!(app.data.selected.container.as_deref(), Some("app"));
-    // The log id must match the selected container so the log view's render
-    // guard (`container == logs.id`) shows the fetched previous logs.
-    assert_eq!(app.data.logs.id, "app");
+    // Deployments menu: Describe, YAML, Edit, Logs, Restart, Scale, Rollout undo, Delete → Scale at index 5.
+    open_menu_and_select(&mut app, 5).await;
+
+    let input = app
+      .input_modal
+      .as_ref()
+      .expect("scale should open an input modal");
+    // Prefilled with the current replica count.
+    assert_eq!(input.buffer, "2");
+    assert_eq!(
+      input.action,
+      InputAction::Scale {
+        block: ActiveBlock::Deployments,
+        name: "web".into(),
+        namespace: Some("team-a".into()),
+        kind: "deployment".into(),
+      }
+    );
+    // No confirm modal yet — only the input overlay.
+    assert!(app.modal.is_none());
   }
 
   #[tokio::test]
-  async fn test_leaving_previous_logs_resets_flag() {
+  async fn test_menu_rollout_undo_confirms_then_runs_kubectl() {
+    let (sync_io_tx, _sync_io_rx) = mpsc::channel(10);
+    let (sync_io_stream_tx, _sync_io_stream_rx) = mpsc::channel(10);
+    let (sync_io_cmd_tx, mut sync_io_cmd_rx) = mpsc::channel::<IoCmdEvent>(10);
+    let mut app = App::new(
+      sync_io_tx,
+      sync_io_stream_tx,
+      sync_io_cmd_tx,
+      false,
+      1,
+      App::default().log_tail_lines,
+      crate::config::KdashConfig::default(),
+    );
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
+    app
+      .data
+      .deployments
+      .set_items(vec![deployment_with_replicas("web", "team-a", Some(2))]);
+
+    // Rollout undo is at index 6; submit revision 3.
+    open_menu_and_select(&mut app, 6).await;
+    assert_eq!(
+      app.input_modal.as_ref().map(|input| &input.action),
+      Some(&InputAction::RolloutUndo {
+        name: "web".into(),
+        namespace: "team-a".into(),
+      })
+    );
+    send_keys(&mut app, &[KeyCode::Char('3'), KeyCode::Enter]).await;
+    let modal = app
+      .modal
+      .as_ref()
+      .expect("undo should ask for confirmation");
+    assert_eq!(
+      modal.prompt,
+      "Roll back deployment 'web' in namespace 'team-a' to revision 3?"
+    );
+
+    send_keys(&mut app, &[KeyCode::Enter]).await;
+    assert!(app.modal.is_none());
+    assert_eq!(
+      sync_io_cmd_rx.recv().await.unwrap(),
+      IoCmdEvent::RolloutUndo {
+        name: "web".into(),
+        namespace: "team-a".into(),
+        revision: Some(3),
+      }
+    );
+  }
+
+  #[tokio::test]
+  async fn test_scale_input_submit_chains_to_confirm_modal() {
     let mut app = App::default();
     app.route_home();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
-    let mut pod = KubePod::default();
-    pod.namespace = "team-a".into();
-    pod.name = "pod-1".into();
-    app.data.pods.set_items(vec![pod]);
-    let mut container = KubeContainer::default();
-    container.name = "app".into();
-    app.data.containers.set_items(vec![container]);
+    app.config.confirm = Some(vec!["scale".into()]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
+    app
+      .data
+      .deployments
+      .set_items(vec![deployment_with_replicas("web", "team-a", Some(2))]);
 
-    let p = KeyEvent::from(KeyCode::Char('p'));
-    handle_key_events(Key::from(p), p, &mut app).await;
-    assert!(app.log_previous);
+    // Menu: Describe, YAML, Edit, Logs, Restart, Scale, Rollout undo, Delete → Scale at index 5.
+    // Open the input modal, replace the prefilled "2" with "5", then submit.
+    send_keys(
+      &mut app,
+      &[
+        KeyCode::Char('m'),
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Enter,
+        KeyCode::Backspace,
+        KeyCode::Char('5'),
+        KeyCode::Enter,
+      ],
+    )
+    .await;
 
-    let esc = KeyEvent::from(KeyCode::Esc);
-    handle_key_events(Key::from(esc), esc, &mut app).await;
-    assert!(!app.log_previous);
+    // Input modal closed, confirm modal opened with the scale patch.
+    assert!(app.input_modal.is_none());
+    let modal = app
+      .modal
+      .as_ref()
+      .expect("valid scale submit should chain into a confirm modal");
+    assert!(modal.prompt.contains("to 5 replica(s)"));
     assert_eq!(
-      app.get_current_route().active_block,
-      ActiveBlock::Containers
+      modal.on_confirm,
+      IoEvent::PatchResource {
+        block: ActiveBlock::Deployments,
+        name: "web".into(),
+        namespace: Some("team-a".into()),
+        patch: ResourcePatch::SetReplicas(5),
+      }
     );
   }
 
   #[tokio::test]
-  async fn test_tab_switch_deactivates_resource_filter_but_preserves_text() {
+  async fn test_scale_input_invalid_keeps_modal_open_with_error() {
     let mut app = App::default();
     app.route_home();
-    app.data.pods.filter = "web".into();
-    app.data.pods.filter_active = true;
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
+    app
+      .data
+      .deployments
+      .set_items(vec![deployment_with_replicas("web", "team-a", Some(2))]);
 
-    let key_evt = KeyEvent::from(KeyCode::Right);
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    // Open the scale input modal (Scale at index 5), type a non-numeric value
+    // and submit.
+    send_keys(
+      &mut app,
+      &[
+        KeyCode::Char('m'),
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Enter,
+        KeyCode::Char('x'),
+        KeyCode::Enter,
+      ],
+    )
+    .await;
 
-    assert_eq!(app.data.pods.filter, "web");
-    assert!(!app.data.pods.filter_active);
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::Services);
+    // Stays open with an inline error; no confirm modal, no dispatch.
+    let input = app
+      .input_modal
+      .as_ref()
+      .expect("invalid input keeps the modal open");
+    assert!(input.error.is_some());
+    assert!(app.modal.is_none());
   }
 
   #[tokio::test]
-  async fn test_namespace_filter_key_flow() {
+  async fn test_scale_input_esc_cancels() {
     let mut app = App::default();
     app.route_home();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Namespaces);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Deployments);
+    app
+      .data
+      .deployments
+      .set_items(vec![deployment_with_replicas("web", "team-a", Some(2))]);
 
-    let key_evt = KeyEvent::from(KeyCode::Char('/'));
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(app.ns_filter_active);
+    // Open the scale input modal (Scale at index 5), then cancel with Esc.
+    send_keys(
+      &mut app,
+      &[
+        KeyCode::Char('m'),
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Down,
+        KeyCode::Enter,
+        KeyCode::Esc,
+      ],
+    )
+    .await;
 
-    for c in ['p', 'r', 'o', 'd'] {
-      let key_evt = KeyEvent::from(KeyCode::Char(c));
-      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    }
-    assert_eq!(app.ns_filter, "prod");
+    assert!(app.input_modal.is_none());
+    assert!(app.modal.is_none());
+  }
 
-    let key_evt = KeyEvent::from(KeyCode::Esc);
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(app.ns_filter_active);
-    assert!(app.ns_filter.is_empty());
+  #[tokio::test]
+  async fn test_port_forward_key_opens_input_for_pod() {
+    let mut app = App::default();
+    app.route_home();
+    let mut pod = KubePod::default();
+    pod.name = "web".into();
+    pod.namespace = "team-a".into();
+    app.data.pods.set_items(vec![pod]);
 
-    let key_evt = KeyEvent::from(KeyCode::Esc);
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(!app.ns_filter_active);
+    let f = KeyEvent::from(KeyCode::Char('f'));
+    handle_key_events(Key::from(f), f, &mut app).await;
+
+    let input = app
+      .input_modal
+      .as_ref()
+      .expect("port-forward should open an input modal");
+    assert_eq!(
+      input.action,
+      InputAction::PortForward {
+        kind: "pods".into(),
+        namespace: "team-a".into(),
+        name: "web".into(),
+      }
+    );
+  }
+
+  #[tokio::test]
+  async fn test_port_forward_input_submit_closes_modal() {
+    let mut app = App::default();
+    app.route_home();
+    let mut pod = KubePod::default();
+    pod.name = "web".into();
+    pod.namespace = "team-a".into();
+    app.data.pods.set_items(vec![pod]);
+
+    // f → input modal, type "8080:80", submit. No io_stream channel in tests, so
+    // the dispatch is a no-op, but the modal should close cleanly.
+    send_keys(
+      &mut app,
+      &[
+        KeyCode::Char('f'),
+        KeyCode::Char('8'),
+        KeyCode::Char('0'),
+        KeyCode::Char('8'),
+        KeyCode::Char('0'),
+        KeyCode::Char(':'),
+        KeyCode::Char('8'),
+        KeyCode::Char('0'),
+        KeyCode::Enter,
+      ],
+    )
+    .await;
+
+    assert!(app.input_modal.is_none());
+    assert!(app.modal.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_label_selector_input_applies_and_esc_clears() {
+    let mut app = App::default();
+    app.route_home();
+
+    let s = shift_char('S');
+    handle_key_events(Key::from(s), s, &mut app).await;
+    assert_eq!(
+      app.input_modal.as_ref().map(|input| &input.action),
+      Some(&InputAction::LabelSelector)
+    );
+    send_keys(
+      &mut app,
+      &[
+        KeyCode::Char('a'),
+        KeyCode::Char('p'),
+        KeyCode::Char('p'),
+        KeyCode::Char('='),
+        KeyCode::Char('w'),
+        KeyCode::Char('e'),
+        KeyCode::Char('b'),
+        KeyCode::Enter,
+      ],
+    )
+    .await;
+    assert!(app.input_modal.is_none());
+    assert_eq!(app.data.selected.label_selector, Some("app=web".into()));
+
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+    assert_eq!(app.data.selected.label_selector, None);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
+  }
+
+  #[tokio::test]
+  async fn test_label_selector_input_shows_inline_error() {
+    let mut app = App::default();
+    app.route_home();
+
+    let s = shift_char('S');
+    handle_key_events(Key::from(s), s, &mut app).await;
+    send_keys(
+      &mut app,
+      &[KeyCode::Char('a'), KeyCode::Char(','), KeyCode::Enter],
+    )
+    .await;
+
+    let input = app
+      .input_modal
+      .as_ref()
+      .expect("an invalid selector should keep the input open");
+    assert!(input.error.is_some());
+    assert_eq!(app.data.selected.label_selector, None);
+  }
+
+  #[tokio::test]
+  async fn test_namespace_block_creates_and_deletes_namespaces() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let (sync_io_stream_tx, _sync_io_stream_rx) = mpsc::channel(10);
+    let (sync_io_cmd_tx, _sync_io_cmd_rx) = mpsc::channel(10);
+    let mut app = App::new(
+      sync_io_tx,
+      sync_io_stream_tx,
+      sync_io_cmd_tx,
+      false,
+      1,
+      App::default().log_tail_lines,
+      crate::config::KdashConfig::default(),
+    );
+    app.route_home();
+    let mut scratch = KubeNs::default();
+    scratch.name = "scratch".into();
+    app.data.namespaces.set_items(vec![scratch]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Namespaces);
+
+    let n = shift_char('N');
+    handle_key_events(Key::from(n), n, &mut app).await;
+    let t = shift_char('T');
+    handle_key_events(Key::from(t), t, &mut app).await;
+    send_keys(&mut app, &[KeyCode::Enter]).await;
+    let error = app
+      .input_modal
+      .as_ref()
+      .and_then(|input| input.error.clone());
+    assert_eq!(
+      error.as_deref(),
+      Some("Use only lowercase letters, digits and '-'")
+    );
+
+    send_keys(
+      &mut app,
+      &[
+        KeyCode::Backspace,
+        KeyCode::Char('q'),
+        KeyCode::Char('a'),
+        KeyCode::Enter,
+      ],
+    )
+    .await;
+    assert!(app.input_modal.is_none());
+    assert_eq!(
+      sync_io_rx.try_recv().ok(),
+      Some(IoEvent::CreateNamespace { name: "qa".into() })
+    );
+
+    let ctrl_d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
+    handle_key_events(Key::from(ctrl_d), ctrl_d, &mut app).await;
+    let modal = app.modal.as_ref().expect("delete asks for confirmation");
+    assert!(modal.prompt.contains("'scratch' and everything in it"));
+    send_keys(&mut app, &[KeyCode::Char('y')]).await;
+    assert_eq!(
+      sync_io_rx.try_recv().ok(),
+      Some(IoEvent::DeleteNamespace {
+        name: "scratch".into()
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_diff_key_in_yaml_view_swaps_to_diff_view() {
+    let mut app = App::default();
+    app.route_home();
+    let mut pod = KubePod::default();
+    pod.name = "web".into();
+    pod.namespace = "team-a".into();
+    app.data.pods.set_items(vec![pod]);
+
+    send_keys(&mut app, &[KeyCode::Char('y')]).await;
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Yaml);
+
+    send_keys(&mut app, &[KeyCode::Char('v')]).await;
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Diff);
+    assert_eq!(app.get_prev_route().active_block, ActiveBlock::Pods);
+    assert!(app.data.describe_out.get_txt().is_empty());
+
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
+  }
+
+  #[tokio::test]
+  async fn test_port_forwards_list_key_empty_is_noop() {
+    let mut app = App::default();
+    app.route_home();
+
+    let f = shift_char('F');
+    handle_key_events(Key::from(f), f, &mut app).await;
+
+    assert!(!app.show_port_forwards);
+    assert!(!app.status_message.is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_port_forwards_overlay_navigates_and_swallows_keys() {
+    use crate::app::port_forward::{PortForward, PortForwardStatus};
+
+    let mut app = App::default();
+    app.route_home();
+    for (id, port) in [(0u64, 8080u16), (1, 9090)] {
+      app.port_forwards.push(PortForward {
+        id,
+        kind: "pods".into(),
+        namespace: "default".into(),
+        name: format!("web-{id}"),
+        local_port: port,
+        remote_port: 80,
+        status: PortForwardStatus::Active,
+        child: None,
+      });
+    }
+
+    let upper_f = shift_char('F');
+    handle_key_events(Key::from(upper_f), upper_f, &mut app).await;
+    assert!(app.show_port_forwards);
+    assert_eq!(app.selected_port_forward_id(), Some(0));
+
+    // Down moves the selection; a tab-switch key is swallowed while open.
+    let block_before = app.get_current_route().active_block;
+    send_keys(&mut app, &[KeyCode::Down, KeyCode::Char('2')]).await;
+    assert_eq!(app.selected_port_forward_id(), Some(1));
+    assert_eq!(app.get_current_route().active_block, block_before);
+
+    let esc = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(esc), esc, &mut app).await;
+    assert!(!app.show_port_forwards);
+  }
+
+  #[tokio::test]
+  async fn test_audit_log_overlay_navigates_and_swallows_keys() {
+    use crate::app::audit::AuditAction;
+
+    let mut app = App::default();
+    app.route_home();
+    for target in ["pod default/a", "pod default/b"] {
+      app.record_audit(
+        AuditAction {
+          action: "delete".into(),
+          target: target.into(),
+        },
+        true,
+      );
+    }
+
+    handle_key_events(
+      DEFAULT_KEYBINDING.audit_log.key,
+      KeyEvent::from(KeyCode::Null),
+      &mut app,
+    )
+    .await;
+    assert!(app.show_audit_log);
+    assert_eq!(app.audit_log_state.selected(), Some(1));
+
+    let block_before = app.get_current_route().active_block;
+    send_keys(&mut app, &[KeyCode::Up, KeyCode::Char('2')]).await;
+    assert_eq!(app.audit_log_state.selected(), Some(0));
+    assert_eq!(app.get_current_route().active_block, block_before);
+
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+    assert!(!app.show_audit_log);
+  }
+
+  #[tokio::test]
+  async fn test_connection_info_overlay_opens_and_swallows_keys() {
+    use crate::network::connection_info::{self, ConnectionInfo};
+
+    connection_info::record(ConnectionInfo {
+      context: "prod".into(),
+      ..ConnectionInfo::default()
+    });
+    let mut app = App::default();
+    app.route_home();
+    let block_before = app.get_current_route().active_block;
+
+    handle_key_events(
+      DEFAULT_KEYBINDING.connection_info.key,
+      KeyEvent::from(KeyCode::Null),
+      &mut app,
+    )
+    .await;
+    assert!(app.connection_info.is_some());
+
+    send_keys(&mut app, &[KeyCode::Char('2')]).await;
+    assert_eq!(app.get_current_route().active_block, block_before);
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+    assert!(app.connection_info.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_api_stats_overlay_toggles_and_swallows_keys() {
+    let mut app = App::default();
+    app.route_home();
+    let block_before = app.get_current_route().active_block;
+    let toggle = || {
+      (
+        DEFAULT_KEYBINDING.api_stats.key,
+        KeyEvent::from(KeyCode::Null),
+      )
+    };
+
+    let (key, event) = toggle();
+    handle_key_events(key, event, &mut app).await;
+    assert!(app.show_api_stats);
+    send_keys(&mut app, &[KeyCode::Char('2')]).await;
+    assert_eq!(app.get_current_route().active_block, block_before);
+    let (key, event) = toggle();
+    handle_key_events(key, event, &mut app).await;
+    assert!(!app.show_api_stats);
+  }
+
+  #[tokio::test]
+  async fn test_apply_status_overlay_swallows_keys_and_closes_on_escape() {
+    use crate::app::manifest::ManifestObject;
+
+    let mut app = App::default();
+    app.route_home();
+    app
+      .watch_applied(vec![ManifestObject {
+        kind: "Deployment".into(),
+        name: "web".into(),
+        namespace: None,
+      }])
+      .await;
+    let watching = app.apply_watch_rx();
+    let block_before = app.get_current_route().active_block;
+
+    send_keys(&mut app, &[KeyCode::Char('2')]).await;
+    assert_eq!(app.get_current_route().active_block, block_before);
+    assert!(app.apply_status.is_some());
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+    assert!(app.apply_status.is_none());
+    // closing ends the watches
+    assert!(watching.has_changed().unwrap());
+  }
+
+  #[tokio::test]
+  async fn test_cmd_output_overlay_scrolls_and_closes_on_escape() {
+    let mut app = App::default();
+    app.route_home();
+    app.open_cmd_output("kubectl apply -f web.yaml".into());
+    for i in 0..5 {
+      app.push_cmd_output_line(format!("line {}", i), false);
+    }
+    app.cmd_output.as_mut().unwrap().height = 2;
+
+    let block_before = app.get_current_route().active_block;
+    send_keys(&mut app, &[KeyCode::Up, KeyCode::Char('2')]).await;
+    assert_eq!(app.cmd_output.as_ref().unwrap().offset, Some(2));
+    assert_eq!(app.get_current_route().active_block, block_before);
+
+    send_keys(&mut app, &[KeyCode::Esc]).await;
+    assert_eq!(app.cmd_output, None);
+  }
+
+  #[tokio::test]
+  async fn test_restart_key_is_noop_on_non_workload_block() {
+    let mut app = App::default();
+    app.route_home();
+    let mut pod = KubePod::default();
+    pod.name = "p".into();
+    pod.namespace = "n".into();
+    app.data.pods.set_items(vec![pod]);
+
+    let r = KeyEvent::from(KeyCode::Char('r'));
+    handle_key_events(Key::from(r), r, &mut app).await;
+
+    assert!(app.modal.is_none());
+  }
+
+  #[tokio::test]
+  async fn test_menu_suspend_cronjob_confirms_suspend() {
+    use k8s_openapi::api::batch::v1::CronJob;
+
+    use crate::app::cronjobs::KubeCronJob;
+
+    let mut app = App::default();
+    app.route_home();
+    app.config.confirm = Some(vec!["suspend".into()]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::CronJobs);
+    let mut cronjob = KubeCronJob::from(CronJob::default());
+    cronjob.name = "backup".into();
+    cronjob.namespace = "default".into();
+    cronjob.suspend = false;
+    app.data.cronjobs.set_items(vec![cronjob]);
+
+    // CronJobs menu: Describe, YAML, Edit, Logs, Suspend, Trigger, Delete → Suspend at index 4.
+    open_menu_and_select(&mut app, 4).await;
+
+    let modal = app
+      .modal
+      .as_ref()
+      .expect("suspend should open a confirm modal");
+    assert_eq!(
+      modal.on_confirm,
+      IoEvent::PatchResource {
+        block: ActiveBlock::CronJobs,
+        name: "backup".into(),
+        namespace: Some("default".into()),
+        patch: ResourcePatch::SetSuspend(true),
+      }
+    );
+  }
+
+  #[tokio::test]
+  async fn test_menu_trigger_cronjob_confirms_trigger() {
+    use k8s_openapi::api::batch::v1::CronJob;
+
+    use crate::app::cronjobs::KubeCronJob;
+
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::CronJobs);
+    let mut cronjob = KubeCronJob::from(CronJob::default());
+    cronjob.name = "backup".into();
+    cronjob.namespace = "default".into();
+    app.data.cronjobs.set_items(vec![cronjob]);
+
+    // CronJobs menu: Describe, YAML, Edit, Logs, Suspend, Trigger, Delete → Trigger at index 5.
+    open_menu_and_select(&mut app, 5).await;
+
+    let modal = app
+      .modal
+      .as_ref()
+      .expect("trigger should open a confirm modal");
+    assert_eq!(
+      modal.on_confirm,
+      IoEvent::TriggerCronJob {
+        name: "backup".into(),
+        namespace: "default".into(),
+      }
+    );
+  }
+
+  #[tokio::test]
+  async fn test_menu_evict_pod_confirms_eviction() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
+    let mut pod = KubePod::default();
+    pod.name = "web-1".into();
+    pod.namespace = "default".into();
+    app.data.pods.set_items(vec![pod]);
+
+    // Pods menu: Describe, YAML, Edit, Logs, Previous logs, Port-forward, Evict → Evict at index 6.
+    open_menu_and_select(&mut app, 6).await;
+
+    let modal = app
+      .modal
+      .as_ref()
+      .expect("evict should open a confirm modal");
+    assert_eq!(
+      modal.on_confirm,
+      IoEvent::EvictPod {
+        name: "web-1".into(),
+        namespace: "default".into(),
+      }
+    );
+  }
+
+  fn crash_looping_pod(name: &str, namespace: &str, owned: bool) -> KubePod {
+    let owners = if owned {
+      serde_json::json!([{
+        "apiVersion": "apps/v1",
+        "kind": "ReplicaSet",
+        "name": "web-abc",
+        "uid": "rs-uid",
+        "controller": true
+      }])
+    } else {
+      serde_json::json!([])
+    };
+    let pod: k8s_openapi::api::core::v1::Pod = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": name, "namespace": namespace, "ownerReferences": owners },
+      "spec": { "containers": [{ "name": "app" }] },
+      "status": {
+        "phase": "Running",
+        "containerStatuses": [{
+          "name": "app",
+          "image": "web:1",
+          "imageID": "",
+          "ready": false,
+          "restartCount": 5,
+          "state": { "waiting": { "reason": "CrashLoopBackOff" } }
+        }]
+      }
+    }))
+    .unwrap();
+    KubePod::from(pod)
+  }
+
+  #[tokio::test]
+  async fn test_menu_restart_crash_looping_pods_skips_standalone_pods() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
+    let mut healthy = KubePod::default();
+    healthy.name = "web-ok".into();
+    healthy.namespace = "shop".into();
+    healthy.status = "Running".into();
+    app.data.pods.set_items(vec![
+      crash_looping_pod("web-1", "shop", true),
+      crash_looping_pod("debug", "shop", false),
+      crash_looping_pod("api-1", "other", true),
+      healthy,
+    ]);
+
+    // Pods menu: ..., Evict, Restart crash-looping pods → index 7.
+    open_menu_and_select(&mut app, 7).await;
+    assert!(app.modal.is_none(), "all namespaces is not a target");
+    assert_eq!(
+      app.status_message.text(),
+      "Pick a namespace to restart its crash-looping pods"
+    );
+
+    app.data.selected.ns = Some("shop".into());
+    open_menu_and_select(&mut app, 7).await;
+    let modal = app.modal.as_ref().expect("restart should be confirmed");
+    assert!(modal
+      .prompt
+      .contains("1 without a controller are left alone"));
+    assert_eq!(
+      modal.on_confirm,
+      IoEvent::RestartPods {
+        rows: vec![MarkedRow {
+          name: "web-1".into(),
+          namespace: Some("shop".into()),
+        }],
+      }
+    );
+  }
+
+  #[tokio::test]
+  async fn test_previous_logs_key_in_containers_opens_previous_log_view() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
+    let mut pod = KubePod::default();
+    pod.namespace = "team-a".into();
+    pod.name = "pod-1".into();
+    app.data.pods.set_items(vec![pod]);
+    let mut container = KubeContainer::default();
+    container.name = "app".into();
+    app.data.containers.set_items(vec![container]);
+
+    let p = KeyEvent::from(KeyCode::Char('p'));
+    handle_key_events(Key::from(p), p, &mut app).await;
+
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Logs);
+    assert!(app.log_previous);
+    assert_eq!(app.data.selected.container.as_deref(), Some("app"));
+    // The log id must match the selected container so the log view's render
+    // guard (`container == logs.id`) shows the fetched previous logs.
+    assert_eq!(app.data.logs.id, "app");
+  }
+
+  #[tokio::test]
+  async fn test_leaving_previous_logs_resets_flag() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
+    let mut pod = KubePod::default();
+    pod.namespace = "team-a".into();
+    pod.name = "pod-1".into();
+    app.data.pods.set_items(vec![pod]);
+    let mut container = KubeContainer::default();
+    container.name = "app".into();
+    app.data.containers.set_items(vec![container]);
+
+    let p = KeyEvent::from(KeyCode::Char('p'));
+    handle_key_events(Key::from(p), p, &mut app).await;
+    assert!(app.log_previous);
+
+    let esc = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(esc), esc, &mut app).await;
+    assert!(!app.log_previous);
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Containers
+    );
+  }
+
+  #[tokio::test]
+  async fn test_tab_switch_deactivates_resource_filter_but_preserves_text() {
+    let mut app = App::default();
+    app.route_home();
+    app.data.pods.filter = "web".into();
+    app.data.pods.filter_active = true;
+
+    let key_evt = KeyEvent::from(KeyCode::Right);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+
+    assert_eq!(app.data.pods.filter, "web");
+    assert!(!app.data.pods.filter_active);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Services);
+  }
+
+  #[tokio::test]
+  async fn test_namespace_filter_key_flow() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Namespaces);
+
+    let key_evt = KeyEvent::from(KeyCode::Char('/'));
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(app.ns_filter_active);
+
+    for c in ['p', 'r', 'o', 'd'] {
+      let key_evt = KeyEvent::from(KeyCode::Char(c));
+      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    }
+    assert_eq!(app.ns_filter, "prod");
+
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(app.ns_filter_active);
+    assert!(app.ns_filter.is_empty());
+
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(!app.ns_filter_active);
+  }
+
+  #[tokio::test]
+  async fn test_contexts_filter_key_flow() {
+    let mut app = App::default();
+    app.route_contexts();
+    assert!(!app.data.contexts.filter_active);
+    assert!(app.data.contexts.filter.is_empty());
+
+    let key_evt = KeyEvent::from(KeyCode::Char('/'));
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(app.data.contexts.filter_active);
+
+    for c in ['p', 'r', 'o', 'd'] {
+      let key_evt = KeyEvent::from(KeyCode::Char(c));
+      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    }
+    assert_eq!(app.data.contexts.filter, "prod");
+
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(app.data.contexts.filter_active);
+    assert!(app.data.contexts.filter.is_empty());
+
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(!app.data.contexts.filter_active);
+  }
+
+  #[tokio::test]
+  async fn test_utilization_filter_key_flow() {
+    let mut app = App::default();
+    app.route_utilization();
+    assert!(!app.data.metrics.filter_active);
+    assert!(app.data.metrics.filter.is_empty());
+
+    let key_evt = KeyEvent::from(KeyCode::Char('/'));
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(app.data.metrics.filter_active);
+
+    for c in ['c', 'p', 'u'] {
+      let key_evt = KeyEvent::from(KeyCode::Char(c));
+      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    }
+    assert_eq!(app.data.metrics.filter, "cpu");
+
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(app.data.metrics.filter_active);
+    assert!(app.data.metrics.filter.is_empty());
+
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(!app.data.metrics.filter_active);
+  }
+
+  #[tokio::test]
+  async fn test_troubleshoot_filter_key_flow() {
+    let mut app = App::default();
+    app.route_troubleshoot();
+    assert!(!app.data.troubleshoot_findings.filter_active);
+    assert!(app.data.troubleshoot_findings.filter.is_empty());
+
+    let key_evt = KeyEvent::from(KeyCode::Char('/'));
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(app.data.troubleshoot_findings.filter_active);
+
+    for c in ['p', 'o', 'd'] {
+      let key_evt = KeyEvent::from(KeyCode::Char(c));
+      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    }
+    assert_eq!(app.data.troubleshoot_findings.filter, "pod");
+
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(app.data.troubleshoot_findings.filter_active);
+    assert!(app.data.troubleshoot_findings.filter.is_empty());
+
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert!(!app.data.troubleshoot_findings.filter_active);
+  }
+
+  #[tokio::test]
+  async fn test_tab_and_back_tab_cycle_main_views_with_wrap_around() {
+    let mut app = App::default();
+    let views = app.main_tabs.items.len();
+    assert_eq!(app.main_tabs.index, 0);
+
+    let back_tab = KeyEvent::from(KeyCode::BackTab);
+    handle_key_events(Key::from(back_tab), back_tab, &mut app).await;
+    assert_eq!(app.main_tabs.index, views - 1);
+    assert_eq!(
+      app.get_current_route(),
+      &app.main_tabs.items[views - 1].route
+    );
+
+    let tab = KeyEvent::from(KeyCode::Tab);
+    handle_key_events(Key::from(tab), tab, &mut app).await;
+    assert_eq!(app.main_tabs.index, 0);
+    handle_key_events(Key::from(back_tab), back_tab, &mut app).await;
+    handle_key_events(Key::from(back_tab), back_tab, &mut app).await;
+    assert_eq!(app.main_tabs.index, views - 2);
+  }
+
+  #[tokio::test]
+  async fn test_help_filter_types_and_clears_with_esc() {
+    let mut app = App::default();
+    let press = |code| {
+      let key_evt = KeyEvent::from(code);
+      (Key::from(key_evt), key_evt)
+    };
+    let (key, evt) = press(KeyCode::Char('?'));
+    handle_key_events(key, evt, &mut app).await;
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Help);
+
+    let (key, evt) = press(KeyCode::Char('/'));
+    handle_key_events(key, evt, &mut app).await;
+    assert!(app.help_filter_active);
+    app.help_scroll = 4;
+    // keys that are bindings elsewhere are typed into the filter
+    for c in ['l', 'o', 'g'] {
+      let (key, evt) = press(KeyCode::Char(c));
+      handle_key_events(key, evt, &mut app).await;
+    }
+    assert_eq!(app.help_filter, "log");
+    assert_eq!(app.help_scroll, 0);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Help);
+
+    // Esc clears, then stops filtering, then leaves help
+    let (key, evt) = press(KeyCode::Esc);
+    handle_key_events(key, evt, &mut app).await;
+    assert!(app.help_filter.is_empty());
+    assert!(app.help_filter_active);
+    handle_key_events(key, evt, &mut app).await;
+    assert!(!app.help_filter_active);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Help);
+    handle_key_events(key, evt, &mut app).await;
+    assert_ne!(app.get_current_route().active_block, ActiveBlock::Help);
+  }
+
+  #[tokio::test]
+  async fn test_help_scroll_keys_adjust_offset() {
+    let mut app = App::default();
+    app.push_navigation_stack(RouteId::HelpMenu, ActiveBlock::Help);
+    assert_eq!(app.help_scroll, 0);
+
+    // Down scrolls; clamped-to-zero saturating-sub on up.
+    let down = KeyEvent::from(KeyCode::Down);
+    handle_key_events(Key::from(down), down, &mut app).await;
+    assert_eq!(app.help_scroll, 1);
+
+    let up = KeyEvent::from(KeyCode::Up);
+    handle_key_events(Key::from(up), up, &mut app).await;
+    assert_eq!(app.help_scroll, 0);
+    handle_key_events(Key::from(up), up, &mut app).await;
+    assert_eq!(app.help_scroll, 0);
+  }
+
+  #[tokio::test]
+  async fn test_handle_decode_or_yaml_action() {
+    let mut app = App::default();
+
+    app.route_home();
+    assert_eq!(app.data.pods.state.selected(), None);
+
+    let item = KubePod::default();
+
+    // describe goes through `describe_selected`, not the per-view action
+    assert!(!handle_decode_or_yaml_action(Key::Char('d'), &mut app, &item).await);
+
+    assert!(handle_decode_or_yaml_action(Key::Char('y'), &mut app, &item).await);
+
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Yaml);
+    // pods open on their summary, the full YAML is one `y` away
+    assert!(app
+      .data
+      .describe_out
+      .get_txt()
+      .starts_with("# Summary of this Pod."));
+    send_keys(&mut app, &[KeyCode::Char('y')]).await;
+    assert_eq!(
+      app.data.describe_out.get_txt(),
+      "apiVersion: v1\nkind: Pod\nmetadata: {}\n"
+    );
+    send_keys(&mut app, &[KeyCode::Char('y')]).await;
+    assert!(app.data.describe_out.get_txt().starts_with("# Summary"));
+
+    assert!(!handle_decode_or_yaml_action(Key::Char('s'), &mut app, &item).await);
+  }
+
+  #[test]
+  fn test_copied_yaml_message_names_the_object_and_its_size() {
+    let pod = KubePod::from(k8s_openapi::api::core::v1::Pod {
+      metadata: kube::api::ObjectMeta {
+        name: Some("web".into()),
+        ..Default::default()
+      },
+      ..Default::default()
+    });
+    let yaml = pod.resource_to_yaml();
+    assert_eq!(
+      copied_yaml_message(pod.get_k8s_obj(), &yaml),
+      format!("Copied Pod web YAML (5 lines, {} B)", yaml.len())
+    );
+    assert_eq!(byte_size(2048), "2.0 KiB");
+    assert_eq!(byte_size(3 * 1_048_576), "3.0 MiB");
+  }
+
+  #[test]
+  fn test_osc52_sequence_encodes_the_content_within_the_limit() {
+    assert_eq!(
+      osc52_sequence("kind: Pod").unwrap(),
+      "\x1b]52;c;a2luZDogUG9k\x07"
+    );
+    let error = osc52_sequence(&"a".repeat(OSC52_MAX_BYTES + 1)).unwrap_err();
+    assert!(error
+      .to_string()
+      .ends_with("is too large for the terminal clipboard (the limit is 97.7 KiB)"));
+  }
+
+  #[tokio::test]
+  async fn test_yaml_of_a_kind_without_summary_opens_on_the_yaml() {
+    use crate::app::configmaps::KubeConfigMap;
+
+    let mut app = App::default();
+    app.route_home();
+    let cm = KubeConfigMap::from(k8s_openapi::api::core::v1::ConfigMap::default());
+    assert!(handle_decode_or_yaml_action(Key::Char('y'), &mut app, &cm).await);
+    assert!(app
+      .data
+      .describe_out
+      .get_txt()
+      .starts_with("apiVersion: v1"));
+    assert_eq!(app.data.yaml_detail, None);
+
+    send_keys(&mut app, &[KeyCode::Char('y')]).await;
+    assert!(app
+      .data
+      .describe_out
+      .get_txt()
+      .starts_with("apiVersion: v1"));
+    assert_eq!(
+      app.status_message.text(),
+      "No summary for this kind, the full YAML is shown"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_goto_prefix_jumps_to_views() {
+    let mut app = App::default();
+    app.route_home();
+    select_context_tab(&mut app, 1);
+
+    send_keys(&mut app, &[KeyCode::Char('g'), KeyCode::Char('d')]).await;
+    assert_eq!(app.context_tabs.index, 6);
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Deployments
+    );
+
+    // works from other main views too
+    app.route_contexts();
+    send_keys(&mut app, &[KeyCode::Char('g'), KeyCode::Char('n')]).await;
+    assert_eq!(app.main_tabs.index, 0);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Nodes);
+
+    send_keys(&mut app, &[KeyCode::Char('g'), KeyCode::Char('x')]).await;
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Nodes);
+    assert!(app
+      .status_message
+      .text()
+      .starts_with("No view to go to with x."));
+
+    // after the timeout the second key is an ordinary key again
+    send_keys(&mut app, &[KeyCode::Char('g')]).await;
+    app.goto_pending = Some(Instant::now() - Duration::from_secs(2));
+    send_keys(&mut app, &[KeyCode::Char('n')]).await;
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Namespaces,
+      "'n' focuses namespaces when no goto is pending"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_grouped_pods_fold_on_enter_and_open_pods_as_before() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
+    let pods = ["a", "b"].map(|name| {
+      let mut pod = KubePod::default();
+      pod.name = name.into();
+      pod
+    });
+    app.data.pods.set_items(pods.to_vec());
+    app.data.pods.state.select(Some(1));
+
+    let key = DEFAULT_KEYBINDING.group_pods.key;
+    handle_key_events(key, KeyEvent::from(KeyCode::Char('g')), &mut app).await;
+    assert!(app.pods_grouped());
+    assert_eq!(app.status_message.text(), "Grouping pods by workload");
+    // the header of the unmanaged group, then a and b
+    assert_eq!(app.data.pod_groups.state.selected(), Some(2));
+
+    send_keys(&mut app, &[KeyCode::Up, KeyCode::Up, KeyCode::Enter]).await;
+    assert_eq!(app.data.collapsed_pod_groups.len(), 1);
+    assert_eq!(app.data.pod_groups.items.len(), 1);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
+
+    send_keys(&mut app, &[KeyCode::Enter, KeyCode::Down, KeyCode::Enter]).await;
+    assert!(app.data.collapsed_pod_groups.is_empty());
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Containers
+    );
+    assert_eq!(app.data.selected.pod.as_deref(), Some("a"));
+  }
+
+  #[tokio::test]
+  async fn test_group_events_key_toggles_grouping() {
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Events);
+
+    send_keys(&mut app, &[KeyCode::Char('g')]).await;
+    assert!(app.data.group_events);
+    assert_eq!(app.status_message.text(), "Grouping repeated events");
+
+    send_keys(&mut app, &[KeyCode::Char('g')]).await;
+    assert!(!app.data.group_events);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Events);
+  }
+
+  #[tokio::test]
+  async fn test_open_in_console_without_template_explains_the_setting() {
+    let mut app = App::default();
+    app.route_home();
+    let mut pod = KubePod::default();
+    pod.name = "web-1".into();
+    app.data.pods.set_items(vec![pod]);
+
+    let key = DEFAULT_KEYBINDING.open_in_console.key;
+    handle_key_events(key, KeyEvent::from(KeyCode::Char('O')), &mut app).await;
+
+    assert_eq!(app.status_message.text(), console::NO_CONSOLE_URL);
+  }
+
+  #[tokio::test]
+  async fn test_pin_key_pins_the_selected_row_and_unpins_it_on_the_watchlist() {
+    let mut app = App::default();
+    app.route_home();
+    let mut pod = KubePod::default();
+    pod.name = "web-1".into();
+    pod.namespace = "shop".into();
+    app.data.pods.set_items(vec![pod]);
+
+    let key = DEFAULT_KEYBINDING.pin.key;
+    handle_key_events(key, KeyEvent::from(KeyCode::Char('b')), &mut app).await;
+    assert_eq!(
+      app.status_message.text(),
+      "Pinned shop/web-1 to the watchlist"
+    );
+    assert_eq!(app.watchlist.len(), 1);
+    assert_eq!(app.watchlist[0].view, "pods");
+
+    let jump = DEFAULT_KEYBINDING.jump_to_watchlist.key;
+    handle_key_events(jump, KeyEvent::from(KeyCode::Char('B')), &mut app).await;
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Watchlist);
+    app.data.watchlist.state.select(Some(0));
+    handle_key_events(key, KeyEvent::from(KeyCode::Char('b')), &mut app).await;
+    assert!(app.watchlist.is_empty());
+    assert!(app.data.watchlist.items.is_empty());
+  }
+
+  #[test]
+  fn test_kubectl_line_quotes_words_the_shell_would_split() {
+    assert_eq!(
+      kubectl_line(None, Some("shop"), vec!["get".into(), "pod/web-1".into()]),
+      "kubectl -n shop get pod/web-1"
+    );
+    assert_eq!(
+      kubectl_line(
+        Some("arn:aws:eks:eu-west-1:1234:cluster/prod"),
+        None,
+        vec!["get".into(), "node/it's $HOME".into()]
+      ),
+      "kubectl --context arn:aws:eks:eu-west-1:1234:cluster/prod get 'node/it'\\''s $HOME'"
+    );
+  }
+
+  #[test]
+  fn test_kubectl_command_follows_the_view() {
+    let mut app = App::default();
+    app.route_home();
+    assert_eq!(kubectl_command(&app), None);
+
+    let mut pod = KubePod::default();
+    pod.name = "web-1".into();
+    pod.namespace = "shop".into();
+    let mut container = KubeContainer::default();
+    container.name = "app".into();
+    pod.containers = vec![container.clone()];
+    app.data.pods.set_items(vec![pod]);
+    assert_eq!(
+      kubectl_command(&app).as_deref(),
+      Some("kubectl -n shop get pod/web-1")
+    );
+
+    app.data.selected.context = Some("prod".into());
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Yaml);
+    assert_eq!(
+      kubectl_command(&app).as_deref(),
+      Some("kubectl --context prod -n shop get pod/web-1 -o yaml")
+    );
+    app.data.selected.context = None;
+
+    app.data.last_describe = Some(IoCmdEvent::GetDescribe {
+      kind: "node".into(),
+      value: "node-a".into(),
+      ns: None,
+      resource_version: None,
+    });
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Describe);
+    assert_eq!(
+      kubectl_command(&app).as_deref(),
+      Some("kubectl describe node/node-a")
+    );
+
+    app.route_home();
+    app.data.containers.set_items(vec![container]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
+    assert_eq!(
+      kubectl_command(&app).as_deref(),
+      Some("kubectl -n shop logs pod/web-1 -c app")
+    );
+
+    app.data.selected.container = Some("app".into());
+    app.log_previous = true;
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+    assert_eq!(
+      kubectl_command(&app).as_deref(),
+      Some("kubectl -n shop logs pod/web-1 -c app --previous")
+    );
+  }
+
+  #[tokio::test]
+  async fn test_describe_key_works_in_every_view() {
+    let describe = |app: &App| match app.data.last_describe.clone() {
+      Some(IoCmdEvent::GetDescribe {
+        kind, value, ns, ..
+      }) => (kind, value, ns),
+      other => panic!("unexpected describe {:?}", other),
+    };
+
+    let mut app = App::default();
+    app.route_home();
+    let mut pod = KubePod::default();
+    pod.name = "web-1".into();
+    pod.namespace = "shop".into();
+    app.data.pods.set_items(vec![pod]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
+    send_keys(&mut app, &[KeyCode::Char('d')]).await;
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
+    assert_eq!(
+      describe(&app),
+      ("pod".into(), "web-1".into(), Some("shop".into()))
+    );
+
+    let mut app = App::default();
+    app.route_home();
+    let mut ns = KubeNs::default();
+    ns.name = "shop".into();
+    app.data.namespaces.set_items(vec![ns]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Namespaces);
+    send_keys(&mut app, &[KeyCode::Char('d')]).await;
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
+    assert_eq!(describe(&app), ("namespace".into(), "shop".into(), None));
+    // describing a namespace does not switch to it
+    assert_eq!(app.data.selected.ns, None);
+
+    let mut app = App::default();
+    app.route_home();
+    app.data.node_pods.set_items(vec![
+      KubeNodePods {
+        name: PENDING_BUCKET.into(),
+        is_pending: true,
+        ..KubeNodePods::default()
+      },
+      KubeNodePods {
+        name: "node-a".into(),
+        ..KubeNodePods::default()
+      },
+    ]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::PodsByNode);
+    send_keys(&mut app, &[KeyCode::Char('d')]).await;
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::PodsByNode
+    );
+    send_keys(&mut app, &[KeyCode::Down, KeyCode::Char('d')]).await;
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
+    assert_eq!(describe(&app), ("node".into(), "node-a".into(), None));
+  }
+
+  #[tokio::test]
+  async fn test_decode_secret() {
+    const DATA1: &str = "Hello, World!";
+    const DATA2: &str =
+      "Neque porro quisquam est qui dolorem ipsum quia dolor sit amet, consectetur, adipisci velit";
+
+    let mut app = App::default();
+    app.route_home();
+
+    let mut secret = KubeSecret::default();
+    // ByteString base64 encodes the data
+    secret
+      .data
+      .insert(String::from("key1"), ByteString(DATA1.as_bytes().into()));
+    secret
+      .data
+      .insert(String::from("key2"), ByteString(DATA2.as_bytes().into()));
+
+    // ensure that 'x' decodes the secret data
+    assert!(handle_decode_or_yaml_action(Key::Char('x'), &mut app, &secret).await);
+
+    assert!(app
+      .data
+      .describe_out
+      .get_txt()
+      .contains(format!("key1: {}", DATA1).as_str()));
+    assert!(app
+      .data
+      .describe_out
+      .get_txt()
+      .contains(format!("key2: {}", DATA2).as_str()));
+  }
+
+  #[tokio::test]
+  async fn test_decode_secret_is_refused_in_redact_mode() {
+    let mut app = App::default();
+    app.route_home();
+    let mut secret = KubeSecret::default();
+    secret
+      .data
+      .insert(String::from("password"), ByteString(b"hunter2".to_vec()));
+
+    handle_key_events(
+      DEFAULT_KEYBINDING.toggle_redact.key,
+      KeyEvent::from(KeyCode::Null),
+      &mut app,
+    )
+    .await;
+    assert!(app.redact);
+    assert!(handle_decode_or_yaml_action(Key::Char('x'), &mut app, &secret).await);
+    assert!(!app.data.describe_out.get_txt().contains("hunter2"));
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
+    assert_eq!(
+      app.status_message.text(),
+      "Secret values stay hidden in redact mode"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_handle_scroll() {
+    let mut app = App::default();
+
+    app.route_home();
+    assert_eq!(app.data.pods.state.selected(), None);
+
+    app
+      .data
+      .pods
+      .set_items(vec![KubePod::default(), KubePod::default()]);
+
+    // mouse scroll
+    assert_eq!(app.data.pods.state.selected(), Some(0));
+    handle_block_scroll(&mut app, ScrollEvent::down(), true).await;
+    assert_eq!(app.data.pods.state.selected(), Some(1));
+    handle_block_scroll(&mut app, ScrollEvent::up(), true).await;
+    assert_eq!(app.data.pods.state.selected(), Some(0));
+    handle_block_scroll(&mut app, ScrollEvent::End, false).await;
+    assert_eq!(app.data.pods.state.selected(), Some(1));
+
+    // check logs keyboard scroll
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+    assert_eq!(app.data.logs.state.selected(), None);
+
+    app.data.logs.add_record("record".to_string());
+    app.data.logs.add_record("record 2".to_string());
+    app.data.logs.add_record("record 3".to_string());
+
+    handle_block_scroll(&mut app, ScrollEvent::down(), false).await;
+    assert_eq!(app.data.logs.state.selected(), Some(0));
+  }
+
+  #[tokio::test]
+  async fn test_context_switch() {
+    let mut app = App::default();
+    let ctx = KubeContext {
+      name: "test".into(),
+      ..KubeContext::default()
+    };
+    app.data.contexts.set_items(vec![ctx]);
+
+    assert_eq!(app.data.selected.context, None);
+    app.route_contexts();
+    handle_route_events(Key::Enter, &mut app).await;
+
+    assert_eq!(app.data.selected.context, Some("test".into()));
+    assert!(app.refresh);
+  }
+
+  #[tokio::test]
+  async fn test_context_switch_preselects_namespace() {
+    let mut app = App::default();
+    let ctx = KubeContext {
+      name: "prod".into(),
+      namespace: Some("prod-ns".into()),
+      ..KubeContext::default()
+    };
+    app.data.contexts.set_items(vec![ctx]);
+
+    assert_eq!(app.data.selected.ns, None);
+    app.route_contexts();
+    handle_route_events(Key::Enter, &mut app).await;
+
+    assert_eq!(app.data.selected.context, Some("prod".into()));
+    assert_eq!(app.data.selected.ns, Some("prod-ns".into()));
+    assert!(app.refresh);
+  }
+
+  #[tokio::test]
+  async fn test_select_all_namespace_toggles_back_to_previous_namespace() {
+    let mut app = App::default();
+    app.data.selected.ns = Some("team-a".into());
+
+    handle_route_events(DEFAULT_KEYBINDING.select_all_namespace.key, &mut app).await;
+    assert_eq!(app.data.selected.ns, None);
+    assert_eq!(app.data.selected.previous_ns, Some("team-a".into()));
+
+    handle_route_events(DEFAULT_KEYBINDING.select_all_namespace.key, &mut app).await;
+    assert_eq!(app.data.selected.ns, Some("team-a".into()));
+    assert_eq!(app.data.selected.previous_ns, None);
+  }
+
+  #[tokio::test]
+  async fn test_select_all_namespace_without_previous_namespace_is_noop() {
+    let mut app = App::default();
+
+    handle_route_events(DEFAULT_KEYBINDING.select_all_namespace.key, &mut app).await;
+
+    assert_eq!(app.data.selected.ns, None);
+    assert_eq!(app.data.selected.previous_ns, None);
+  }
+
+  #[tokio::test]
+  async fn test_context_switch_no_namespace_clears_ns() {
+    let mut app = App::default();
+    app.data.selected.ns = Some("old-ns".into());
+    let ctx = KubeContext {
+      name: "dev".into(),
+      namespace: None,
+      ..KubeContext::default()
+    };
+    app.data.contexts.set_items(vec![ctx]);
+
+    app.route_contexts();
+    handle_route_events(Key::Enter, &mut app).await;
+
+    assert_eq!(app.data.selected.context, Some("dev".into()));
+    assert_eq!(app.data.selected.ns, None);
+    assert!(app.refresh);
+  }
+
+  #[test]
+  fn test_filter_menu_items_empty_filter_returns_all() {
+    let items = vec![
+      ("CronJobs".into(), ActiveBlock::CronJobs),
+      ("Secrets".into(), ActiveBlock::Secrets),
+      ("Roles".into(), ActiveBlock::Roles),
+    ];
+    let filtered = filter_menu_items(&items, "");
+    assert_eq!(filtered.len(), 3);
+  }
+
+  #[test]
+  fn test_filter_menu_items_substring_match() {
+    let items = vec![
+      ("CronJobs".into(), ActiveBlock::CronJobs),
+      ("Secrets".into(), ActiveBlock::Secrets),
+      ("Roles".into(), ActiveBlock::Roles),
+    ];
+    let filtered = filter_menu_items(&items, "cron");
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(filtered[0].1 .0, "CronJobs");
+  }
+
+  #[test]
+  fn test_filter_menu_items_case_insensitive() {
+    let items = vec![
+      ("CronJobs".into(), ActiveBlock::CronJobs),
+      ("Secrets".into(), ActiveBlock::Secrets),
+    ];
+    let filtered = filter_menu_items(&items, "CRON");
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(filtered[0].1 .0, "CronJobs");
+  }
+
+  #[test]
+  fn test_filter_menu_items_glob_match() {
+    let items = vec![
+      ("ClusterRoles".into(), ActiveBlock::ClusterRoles),
+      (
+        "ClusterRoleBinding".into(),
+        ActiveBlock::ClusterRoleBindings,
+      ),
+      ("CronJobs".into(), ActiveBlock::CronJobs),
+    ];
+    let filtered = filter_menu_items(&items, "cluster*");
+    assert_eq!(filtered.len(), 2);
+    assert_eq!(filtered[0].1 .0, "ClusterRoles");
+    assert_eq!(filtered[1].1 .0, "ClusterRoleBinding");
+  }
+
+  #[test]
+  fn test_filter_menu_items_no_match() {
+    let items = vec![
+      ("CronJobs".into(), ActiveBlock::CronJobs),
+      ("Secrets".into(), ActiveBlock::Secrets),
+    ];
+    let filtered = filter_menu_items(&items, "zzz");
+    assert_eq!(filtered.len(), 0);
+  }
+
+  #[test]
+  fn test_filter_menu_items_preserves_original_index() {
+    let items = vec![
+      ("CronJobs".into(), ActiveBlock::CronJobs),
+      ("Secrets".into(), ActiveBlock::Secrets),
+      ("Roles".into(), ActiveBlock::Roles),
+    ];
+    let filtered = filter_menu_items(&items, "role");
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(filtered[0].0, 2); // original index
   }
 
   #[tokio::test]
-  async fn test_contexts_filter_key_flow() {
+  async fn test_menu_filter_captures_character_keys() {
     let mut app = App::default();
-    app.route_contexts();
-    assert!(!app.data.contexts.filter_active);
-    assert!(app.data.contexts.filter.is_empty());
+    // Navigate to More menu
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
 
+    // Activate filter mode with '/'
     let key_evt = KeyEvent::from(KeyCode::Char('/'));
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(app.data.contexts.filter_active);
-
-    for c in ['p', 'r', 'o', 'd'] {
-      let key_evt = KeyEvent::from(KeyCode::Char(c));
-      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    }
-    assert_eq!(app.data.contexts.filter, "prod");
+    assert!(app.menu_filter_active);
 
-    let key_evt = KeyEvent::from(KeyCode::Esc);
+    let key_evt = KeyEvent::from(KeyCode::Char('c'));
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(app.data.contexts.filter_active);
-    assert!(app.data.contexts.filter.is_empty());
+    assert_eq!(app.menu_filter, "c");
 
-    let key_evt = KeyEvent::from(KeyCode::Esc);
+    let key_evt = KeyEvent::from(KeyCode::Char('r'));
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(!app.data.contexts.filter_active);
+    assert_eq!(app.menu_filter, "cr");
   }
 
   #[tokio::test]
-  async fn test_utilization_filter_key_flow() {
+  async fn test_menu_filter_requires_slash_to_activate() {
     let mut app = App::default();
-    app.route_utilization();
-    assert!(!app.data.metrics.filter_active);
-    assert!(app.data.metrics.filter.is_empty());
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
 
-    let key_evt = KeyEvent::from(KeyCode::Char('/'));
+    // Typing without '/' should not filter
+    let key_evt = KeyEvent::from(KeyCode::Char('c'));
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(app.data.metrics.filter_active);
+    assert_eq!(app.menu_filter, "");
+    assert!(!app.menu_filter_active);
+  }
 
-    for c in ['c', 'p', 'u'] {
-      let key_evt = KeyEvent::from(KeyCode::Char(c));
-      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    }
-    assert_eq!(app.data.metrics.filter, "cpu");
+  #[tokio::test]
+  async fn test_menu_filter_backspace_removes_char() {
+    let mut app = App::default();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
+    app.menu_filter_active = true;
 
-    let key_evt = KeyEvent::from(KeyCode::Esc);
+    let key_evt = KeyEvent::from(KeyCode::Char('a'));
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(app.data.metrics.filter_active);
-    assert!(app.data.metrics.filter.is_empty());
+    let key_evt = KeyEvent::from(KeyCode::Char('b'));
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert_eq!(app.menu_filter, "ab");
 
-    let key_evt = KeyEvent::from(KeyCode::Esc);
+    let key_evt = KeyEvent::from(KeyCode::Backspace);
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(!app.data.metrics.filter_active);
+    assert_eq!(app.menu_filter, "a");
   }
 
   #[tokio::test]
-  async fn test_troubleshoot_filter_key_flow() {
+  async fn test_menu_filter_backspace_on_empty_does_not_panic() {
     let mut app = App::default();
-    app.route_troubleshoot();
-    assert!(!app.data.troubleshoot_findings.filter_active);
-    assert!(app.data.troubleshoot_findings.filter.is_empty());
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
+    app.menu_filter_active = true;
 
-    let key_evt = KeyEvent::from(KeyCode::Char('/'));
+    let key_evt = KeyEvent::from(KeyCode::Backspace);
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(app.data.troubleshoot_findings.filter_active);
+    assert_eq!(app.menu_filter, "");
+  }
 
-    for c in ['p', 'o', 'd'] {
-      let key_evt = KeyEvent::from(KeyCode::Char(c));
-      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    }
-    assert_eq!(app.data.troubleshoot_findings.filter, "pod");
+  #[tokio::test]
+  async fn test_menu_filter_escape_clears_filter_first() {
+    let mut app = App::default();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
+    app.menu_filter_active = true;
+
+    // Type a filter
+    let key_evt = KeyEvent::from(KeyCode::Char('x'));
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert_eq!(app.menu_filter, "x");
 
+    // First Escape clears filter but stays in menu
     let key_evt = KeyEvent::from(KeyCode::Esc);
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(app.data.troubleshoot_findings.filter_active);
-    assert!(app.data.troubleshoot_findings.filter.is_empty());
+    assert_eq!(app.menu_filter, "");
+    assert!(app.menu_filter_active); // still active, just cleared text
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::More);
 
+    // Second Escape deactivates filter mode
     let key_evt = KeyEvent::from(KeyCode::Esc);
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(!app.data.troubleshoot_findings.filter_active);
+    assert!(!app.menu_filter_active);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::More);
   }
 
   #[tokio::test]
-  async fn test_help_scroll_keys_adjust_offset() {
+  async fn test_menu_filter_escape_on_empty_closes_menu() {
     let mut app = App::default();
-    app.push_navigation_stack(RouteId::HelpMenu, ActiveBlock::Help);
-    assert_eq!(app.help_scroll, 0);
-
-    // Down scrolls; clamped-to-zero saturating-sub on up.
-    let down = KeyEvent::from(KeyCode::Down);
-    handle_key_events(Key::from(down), down, &mut app).await;
-    assert_eq!(app.help_scroll, 1);
+    // Push a base route then the menu
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
 
-    let up = KeyEvent::from(KeyCode::Up);
-    handle_key_events(Key::from(up), up, &mut app).await;
-    assert_eq!(app.help_scroll, 0);
-    handle_key_events(Key::from(up), up, &mut app).await;
-    assert_eq!(app.help_scroll, 0);
+    // Escape with empty filter
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    assert_eq!(app.menu_filter, "");
   }
 
   #[tokio::test]
-  async fn test_handle_describe_or_yaml_action() {
+  async fn test_menu_filter_enter_selects_filtered_item() {
     let mut app = App::default();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
+    app.menu_filter_active = true;
 
-    app.route_home();
-    assert_eq!(app.data.pods.state.selected(), None);
+    // Type "cron" to filter to CronJobs
+    for c in "cron".chars() {
+      let key_evt = KeyEvent::from(KeyCode::Char(c));
+      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    }
+    assert_eq!(app.menu_filter, "cron");
 
-    let item = KubePod::default();
+    // Selection should be at 0 (first filtered item)
+    assert_eq!(app.more_resources_menu.state.selected(), Some(0));
 
-    assert!(
-      handle_describe_decode_or_yaml_action(
-        Key::Char('d'),
-        &mut app,
-        &item,
-        IoCmdEvent::GetDescribe {
-          kind: "pod".to_owned(),
-          value: "name".to_owned(),
-          ns: Some("namespace".to_owned()),
-        }
-      )
-      .await
-    );
+    // Press Enter to select
+    let key_evt = KeyEvent::from(KeyCode::Enter);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
-    assert_eq!(app.data.describe_out.get_txt(), "");
+    // Should navigate to CronJobs, clear filter, and deactivate filter mode
+    assert_eq!(app.menu_filter, "");
+    assert!(!app.menu_filter_active);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::CronJobs);
+  }
 
-    assert!(
-      handle_describe_decode_or_yaml_action(
-        Key::Char('y'),
-        &mut app,
-        &item,
-        IoCmdEvent::GetDescribe {
-          kind: "pod".to_owned(),
-          value: "name".to_owned(),
-          ns: Some("namespace".to_owned()),
-        }
-      )
-      .await
-    );
+  #[test]
+  fn test_handle_menu_scroll_within_filtered_bounds() {
+    let mut menu = StatefulList::with_items(vec![
+      ("A".into(), ActiveBlock::CronJobs),
+      ("B".into(), ActiveBlock::Secrets),
+      ("C".into(), ActiveBlock::Roles),
+    ]);
 
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::Yaml);
-    assert_eq!(
-      app.data.describe_out.get_txt(),
-      "apiVersion: v1\nkind: Pod\nmetadata: {}\n"
-    );
+    // Scroll down within filtered_len=2
+    menu.state.select(Some(0));
+    handle_menu_scroll(&mut menu, ScrollEvent::down(), 2);
+    assert_eq!(menu.state.selected(), Some(1));
 
-    assert!(
-      !handle_describe_decode_or_yaml_action(
-        Key::Char('s'),
-        &mut app,
-        &item,
-        IoCmdEvent::GetDescribe {
-          kind: "pod".to_owned(),
-          value: "name".to_owned(),
-          ns: Some("namespace".to_owned()),
-        }
-      )
-      .await
-    );
+    // Scroll down wraps at filtered_len
+    handle_menu_scroll(&mut menu, ScrollEvent::down(), 2);
+    assert_eq!(menu.state.selected(), Some(0));
+
+    // Scroll up from 0 wraps to end of filtered
+    handle_menu_scroll(&mut menu, ScrollEvent::up(), 2);
+    assert_eq!(menu.state.selected(), Some(1));
   }
 
-  #[tokio::test]
-  async fn test_decode_secret() {
-    const DATA1: &str = "Hello, World!";
-    const DATA2: &str =
-      "Neque porro quisquam est qui dolorem ipsum quia dolor sit amet, consectetur, adipisci velit";
+  #[test]
+  fn test_handle_menu_scroll_empty_filtered() {
+    let mut menu = StatefulList::with_items(vec![("A".into(), ActiveBlock::CronJobs)]);
+    menu.state.select(Some(0));
+    // Should not panic with filtered_len=0
+    handle_menu_scroll(&mut menu, ScrollEvent::Relative(0), 0);
+    assert_eq!(menu.state.selected(), Some(0));
+  }
 
+  #[tokio::test]
+  async fn test_dispatch_resource_pods_sets_selector_state() {
     let mut app = App::default();
     app.route_home();
 
-    let mut secret = KubeSecret::default();
-    // ByteString base64 encodes the data
-    secret
-      .data
-      .insert(String::from("key1"), ByteString(DATA1.as_bytes().into()));
-    secret
-      .data
-      .insert(String::from("key2"), ByteString(DATA2.as_bytes().into()));
-
-    // ensure that 'x' decodes the secret data
-    assert!(
-      handle_describe_decode_or_yaml_action(
-        Key::Char('x'),
-        &mut app,
-        &secret,
-        IoCmdEvent::GetDescribe {
-          kind: "secret".to_owned(),
-          value: "name".to_owned(),
-          ns: Some("namespace".to_owned()),
-        }
+    app
+      .dispatch_resource_pods(
+        "default".into(),
+        "app=nginx".into(),
+        "deployment".into(),
+        RouteId::Home,
       )
-      .await
-    );
+      .await;
 
-    assert!(app
-      .data
-      .describe_out
-      .get_txt()
-      .contains(format!("key1: {}", DATA1).as_str()));
-    assert!(app
-      .data
-      .describe_out
-      .get_txt()
-      .contains(format!("key2: {}", DATA2).as_str()));
+    assert_eq!(app.data.selected.pod_selector, Some("app=nginx".into()));
+    assert_eq!(app.data.selected.pod_selector_ns, Some("default".into()));
+    assert_eq!(
+      app.data.selected.pod_selector_resource,
+      Some("deployment".into())
+    );
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
   }
 
   #[tokio::test]
-  async fn test_handle_scroll() {
+  async fn test_dispatch_aggregate_logs_sets_state() {
     let mut app = App::default();
-
     app.route_home();
-    assert_eq!(app.data.pods.state.selected(), None);
 
     app
-      .data
-      .pods
-      .set_items(vec![KubePod::default(), KubePod::default()]);
-
-    // mouse scroll
-    assert_eq!(app.data.pods.state.selected(), Some(0));
-    handle_block_scroll(&mut app, ScrollEvent::down(), true).await;
-    assert_eq!(app.data.pods.state.selected(), Some(1));
-    handle_block_scroll(&mut app, ScrollEvent::up(), true).await;
-    assert_eq!(app.data.pods.state.selected(), Some(0));
-    handle_block_scroll(&mut app, ScrollEvent::End, false).await;
-    assert_eq!(app.data.pods.state.selected(), Some(1));
-
-    // check logs keyboard scroll
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
-    assert_eq!(app.data.logs.state.selected(), None);
-
-    app.data.logs.add_record("record".to_string());
-    app.data.logs.add_record("record 2".to_string());
-    app.data.logs.add_record("record 3".to_string());
+      .dispatch_aggregate_logs(
+        "my-deploy".into(),
+        "default".into(),
+        "app=nginx".into(),
+        "deployment".into(),
+        RouteId::Home,
+      )
+      .await;
 
-    handle_block_scroll(&mut app, ScrollEvent::down(), false).await;
-    assert_eq!(app.data.logs.state.selected(), Some(0));
+    assert_eq!(app.data.logs.id, "agg:my-deploy");
+    assert_eq!(
+      app.data.selected.pod_selector_resource,
+      Some("deployment".into())
+    );
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Logs);
   }
 
   #[tokio::test]
-  async fn test_context_switch() {
+  async fn test_escape_from_more_selected_resource_returns_to_more_not_dynamic() {
     let mut app = App::default();
-    let ctx = KubeContext {
-      name: "test".into(),
-      ..KubeContext::default()
-    };
-    app.data.contexts.set_items(vec![ctx]);
+    // Both pickers visited (Dynamic, then More), then a resource picked from More.
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::DynamicView);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Secrets);
 
-    assert_eq!(app.data.selected.context, None);
-    app.route_contexts();
-    handle_route_events(Key::Enter, &mut app).await;
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
-    assert_eq!(app.data.selected.context, Some("test".into()));
-    assert!(app.refresh);
+    // Returns to the picker actually used (More), not the stacked Dynamic menu.
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::More);
   }
 
   #[tokio::test]
-  async fn test_context_switch_preselects_namespace() {
+  async fn test_reset_navigation_key_clears_stack_to_home() {
     let mut app = App::default();
-    let ctx = KubeContext {
-      name: "prod".into(),
-      namespace: Some("prod-ns".into()),
-      ..KubeContext::default()
-    };
-    app.data.contexts.set_items(vec![ctx]);
+    // Wander to another view and drill down a couple of levels.
+    app.route_utilization();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Describe);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Yaml);
 
-    assert_eq!(app.data.selected.ns, None);
-    app.route_contexts();
-    handle_route_events(Key::Enter, &mut app).await;
+    let reset_key = DEFAULT_KEYBINDING.reset_navigation.key;
+    let key_evt = KeyEvent::from(KeyCode::Char('h')); // representative; key dispatch uses `reset_key`
+    handle_key_events(reset_key, key_evt, &mut app).await;
 
-    assert_eq!(app.data.selected.context, Some("prod".into()));
-    assert_eq!(app.data.selected.ns, Some("prod-ns".into()));
-    assert!(app.refresh);
+    assert_eq!(app.get_current_route().id, RouteId::Home);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
+    // Back at the root: nothing left to pop.
+    assert_eq!(app.pop_navigation_stack(), None);
   }
 
   #[tokio::test]
-  async fn test_context_switch_no_namespace_clears_ns() {
+  async fn test_escape_from_filtered_pods_clears_selector_state() {
     let mut app = App::default();
-    app.data.selected.ns = Some("old-ns".into());
-    let ctx = KubeContext {
-      name: "dev".into(),
-      namespace: None,
-      ..KubeContext::default()
-    };
-    app.data.contexts.set_items(vec![ctx]);
+    app.route_home();
 
-    app.route_contexts();
-    handle_route_events(Key::Enter, &mut app).await;
+    // Simulate drill-down state
+    app.data.selected.pod_selector = Some("app=nginx".into());
+    app.data.selected.pod_selector_ns = Some("default".into());
+    app.data.selected.pod_selector_resource = Some("deployment".into());
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
 
-    assert_eq!(app.data.selected.context, Some("dev".into()));
-    assert_eq!(app.data.selected.ns, None);
-    assert!(app.refresh);
-  }
+    // Press Esc
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
-  #[test]
-  fn test_filter_menu_items_empty_filter_returns_all() {
-    let items = vec![
-      ("CronJobs".into(), ActiveBlock::CronJobs),
-      ("Secrets".into(), ActiveBlock::Secrets),
-      ("Roles".into(), ActiveBlock::Roles),
-    ];
-    let filtered = filter_menu_items(&items, "");
-    assert_eq!(filtered.len(), 3);
+    assert_eq!(app.data.selected.pod_selector, None);
+    assert_eq!(app.data.selected.pod_selector_ns, None);
+    assert_eq!(app.data.selected.pod_selector_resource, None);
   }
 
-  #[test]
-  fn test_filter_menu_items_substring_match() {
-    let items = vec![
-      ("CronJobs".into(), ActiveBlock::CronJobs),
-      ("Secrets".into(), ActiveBlock::Secrets),
-      ("Roles".into(), ActiveBlock::Roles),
-    ];
-    let filtered = filter_menu_items(&items, "cron");
-    assert_eq!(filtered.len(), 1);
-    assert_eq!(filtered[0].1 .0, "CronJobs");
-  }
+  #[tokio::test]
+  async fn test_escape_from_aggregate_logs_clears_resource_context() {
+    let mut app = App::default();
+    app.route_home();
 
-  #[test]
-  fn test_filter_menu_items_case_insensitive() {
-    let items = vec![
-      ("CronJobs".into(), ActiveBlock::CronJobs),
-      ("Secrets".into(), ActiveBlock::Secrets),
-    ];
-    let filtered = filter_menu_items(&items, "CRON");
-    assert_eq!(filtered.len(), 1);
-    assert_eq!(filtered[0].1 .0, "CronJobs");
-  }
+    // Simulate aggregate logs state (no pod_selector set)
+    app.data.selected.pod_selector_resource = Some("deployment".into());
+    app.data.logs = crate::app::models::LogsState::new("agg:my-deploy".into());
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
 
-  #[test]
-  fn test_filter_menu_items_glob_match() {
-    let items = vec![
-      ("ClusterRoles".into(), ActiveBlock::ClusterRoles),
-      (
-        "ClusterRoleBinding".into(),
-        ActiveBlock::ClusterRoleBindings,
-      ),
-      ("CronJobs".into(), ActiveBlock::CronJobs),
-    ];
-    let filtered = filter_menu_items(&items, "cluster*");
-    assert_eq!(filtered.len(), 2);
-    assert_eq!(filtered[0].1 .0, "ClusterRoles");
-    assert_eq!(filtered[1].1 .0, "ClusterRoleBinding");
-  }
+    // Press Esc
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
-  #[test]
-  fn test_filter_menu_items_no_match() {
-    let items = vec![
-      ("CronJobs".into(), ActiveBlock::CronJobs),
-      ("Secrets".into(), ActiveBlock::Secrets),
-    ];
-    let filtered = filter_menu_items(&items, "zzz");
-    assert_eq!(filtered.len(), 0);
+    // Resource context should be cleared for aggregate logs
+    assert_eq!(app.data.selected.pod_selector_resource, None);
   }
 
-  #[test]
-  fn test_filter_menu_items_preserves_original_index() {
-    let items = vec![
-      ("CronJobs".into(), ActiveBlock::CronJobs),
-      ("Secrets".into(), ActiveBlock::Secrets),
-      ("Roles".into(), ActiveBlock::Roles),
-    ];
-    let filtered = filter_menu_items(&items, "role");
-    assert_eq!(filtered.len(), 1);
-    assert_eq!(filtered[0].0, 2); // original index
+  #[tokio::test]
+  async fn test_escape_from_drilldown_logs_preserves_resource_context() {
+    let mut app = App::default();
+    app.route_home();
+
+    // Simulate drill-down: Deployment → Pods → Container → Logs
+    app.data.selected.pod_selector = Some("app=nginx".into());
+    app.data.selected.pod_selector_ns = Some("default".into());
+    app.data.selected.pod_selector_resource = Some("deployment".into());
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+
+    // Press Esc from Logs — should go back to Containers, resource context preserved
+    let key_evt = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+
+    assert_eq!(
+      app.data.selected.pod_selector_resource,
+      Some("deployment".into())
+    );
+    assert_eq!(app.data.selected.pod_selector, Some("app=nginx".into()));
   }
 
   #[tokio::test]
-  async fn test_menu_filter_captures_character_keys() {
+  async fn test_clicking_a_tab_selects_it() {
     let mut app = App::default();
-    // Navigate to More menu
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
+    app.route_home();
+    app.main_tabs.rects = vec![(0, Rect::new(1, 1, 20, 1)), (2, Rect::new(30, 1, 15, 1))];
+    app.context_tabs.rects = vec![(0, Rect::new(1, 5, 10, 1)), (6, Rect::new(12, 5, 15, 1))];
+    let click = |column, row| MouseEvent {
+      kind: MouseEventKind::Down(MouseButton::Left),
+      column,
+      row,
+      modifiers: KeyModifiers::NONE,
+    };
 
-    // Activate filter mode with '/'
-    let key_evt = KeyEvent::from(KeyCode::Char('/'));
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(app.menu_filter_active);
+    handle_mouse_events(click(14, 5), &mut app).await;
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Deployments
+    );
+    assert_eq!(app.context_tabs.index, 6);
 
-    let key_evt = KeyEvent::from(KeyCode::Char('c'));
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert_eq!(app.menu_filter, "c");
+    // Outside every tab: nothing changes.
+    handle_mouse_events(click(25, 1), &mut app).await;
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Deployments
+    );
 
-    let key_evt = KeyEvent::from(KeyCode::Char('r'));
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert_eq!(app.menu_filter, "cr");
+    handle_mouse_events(click(31, 1), &mut app).await;
+    assert_eq!(app.get_current_route().id, RouteId::Utilization);
+    assert_eq!(app.main_tabs.index, 2);
   }
 
   #[tokio::test]
-  async fn test_menu_filter_requires_slash_to_activate() {
+  async fn test_jump_to_pods_clears_selector_state() {
     let mut app = App::default();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
+    app.route_home();
 
-    // Typing without '/' should not filter
-    let key_evt = KeyEvent::from(KeyCode::Char('c'));
+    // Simulate leftover drill-down state
+    app.data.selected.pod_selector = Some("app=nginx".into());
+    app.data.selected.pod_selector_ns = Some("default".into());
+    app.data.selected.pod_selector_resource = Some("deployment".into());
+
+    // Press '1' to jump to pods tab
+    let key_evt = KeyEvent::from(KeyCode::Char('1'));
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert_eq!(app.menu_filter, "");
-    assert!(!app.menu_filter_active);
+
+    assert_eq!(app.data.selected.pod_selector, None);
+    assert_eq!(app.data.selected.pod_selector_ns, None);
+    assert_eq!(app.data.selected.pod_selector_resource, None);
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
   }
 
   #[tokio::test]
-  async fn test_menu_filter_backspace_removes_char() {
+  async fn test_enter_on_leaf_resource_runs_describe() {
     let mut app = App::default();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
-    app.menu_filter_active = true;
+    app.route_home();
 
-    let key_evt = KeyEvent::from(KeyCode::Char('a'));
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    let key_evt = KeyEvent::from(KeyCode::Char('b'));
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert_eq!(app.menu_filter, "ab");
+    // Navigate to Secrets (a leaf resource with no child views)
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Secrets);
 
-    let key_evt = KeyEvent::from(KeyCode::Backspace);
+    let mut secret = KubeSecret::default();
+    secret.name = "my-secret".into();
+    secret.namespace = "default".into();
+    app.data.secrets.set_items(vec![secret]);
+
+    // Press Enter
+    let key_evt = KeyEvent::from(KeyCode::Enter);
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert_eq!(app.menu_filter, "a");
+
+    // Should navigate to Describe view
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
   }
 
   #[tokio::test]
-  async fn test_menu_filter_backspace_on_empty_does_not_panic() {
+  async fn test_dispatch_node_pods_sets_state() {
     let mut app = App::default();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
-    app.menu_filter_active = true;
+    app.route_home();
 
-    let key_evt = KeyEvent::from(KeyCode::Backspace);
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert_eq!(app.menu_filter, "");
+    app
+      .dispatch_node_pods("my-node-01".into(), RouteId::Home)
+      .await;
+
+    assert_eq!(app.data.selected.pod_selector, Some("my-node-01".into()));
+    assert_eq!(app.data.selected.pod_selector_ns, None);
+    assert_eq!(app.data.selected.pod_selector_resource, Some("node".into()));
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
   }
 
   #[tokio::test]
-  async fn test_menu_filter_escape_clears_filter_first() {
-    let mut app = App::default();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
-    app.menu_filter_active = true;
+  async fn test_pods_by_node_sorts_and_drills_down() {
+    use crate::app::node_pods::{KubeNodePods, NodePodsSort};
 
-    // Type a filter
-    let key_evt = KeyEvent::from(KeyCode::Char('x'));
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert_eq!(app.menu_filter, "x");
+    let mut app = App::default();
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::PodsByNode);
+    app.data.node_pods.set_items(vec![
+      KubeNodePods {
+        name: "node-a".into(),
+        pods: 1,
+        mem_requests: "1Gi".parse().unwrap(),
+        ..KubeNodePods::default()
+      },
+      KubeNodePods {
+        name: "node-b".into(),
+        pods: 2,
+        ..KubeNodePods::default()
+      },
+    ]);
 
-    // First Escape clears filter but stays in menu
-    let key_evt = KeyEvent::from(KeyCode::Esc);
+    let key_evt = KeyEvent::from(KeyCode::Char('o'));
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert_eq!(app.menu_filter, "");
-    assert!(app.menu_filter_active); // still active, just cleared text
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::More);
-
-    // Second Escape deactivates filter mode
-    let key_evt = KeyEvent::from(KeyCode::Esc);
+    assert_eq!(app.data.node_pods_sort, NodePodsSort::CpuRequests);
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert!(!app.menu_filter_active);
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::More);
-  }
-
-  #[tokio::test]
-  async fn test_menu_filter_escape_on_empty_closes_menu() {
-    let mut app = App::default();
-    // Push a base route then the menu
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
+    assert_eq!(app.data.node_pods_sort, NodePodsSort::MemRequests);
+    assert_eq!(app.data.node_pods.items[0].name, "node-a");
 
-    // Escape with empty filter
-    let key_evt = KeyEvent::from(KeyCode::Esc);
+    app.data.node_pods.state.select(Some(1));
+    let key_evt = KeyEvent::from(KeyCode::Enter);
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    assert_eq!(app.menu_filter, "");
+    assert_eq!(app.data.selected.pod_selector, Some("node-b".into()));
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
   }
 
   #[tokio::test]
-  async fn test_menu_filter_enter_selects_filtered_item() {
+  async fn test_dynamic_view_selection_uses_cached_items_immediately() {
     let mut app = App::default();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
-    app.menu_filter_active = true;
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::DynamicView);
+    app.dynamic_resources_menu =
+      StatefulList::with_items(vec![("Widget".into(), ActiveBlock::DynamicResource)]);
+    app.dynamic_resources_menu.state.select(Some(0));
 
-    // Type "cron" to filter to CronJobs
-    for c in "cron".chars() {
-      let key_evt = KeyEvent::from(KeyCode::Char(c));
-      handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
-    }
-    assert_eq!(app.menu_filter, "cron");
+    let kind = KubeDynamicKind::new(
+      ApiResource {
+        group: "example.com".into(),
+        version: "v1".into(),
+        api_version: "example.com/v1".into(),
+        kind: "Widget".into(),
+        plural: "widgets".into(),
+      },
+      Scope::Namespaced,
+    );
+    app.data.dynamic_kinds = vec![kind.clone()];
+    app.data.selected.ns = Some("team-a".into());
 
-    // Selection should be at 0 (first filtered item)
-    assert_eq!(app.more_resources_menu.state.selected(), Some(0));
+    let cached_items = vec![KubeDynamicResource::from(DynamicObject {
+      types: None,
+      metadata: ObjectMeta {
+        name: Some("widget-1".into()),
+        namespace: Some("team-a".into()),
+        ..Default::default()
+      },
+      data: Default::default(),
+    })];
+    app.data.dynamic_resource_cache.insert(
+      dynamic_cache_key(&kind, Some("team-a")),
+      cached_items.clone(),
+    );
 
-    // Press Enter to select
     let key_evt = KeyEvent::from(KeyCode::Enter);
     handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
-    // Should navigate to CronJobs, clear filter, and deactivate filter mode
-    assert_eq!(app.menu_filter, "");
-    assert!(!app.menu_filter_active);
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::CronJobs);
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::DynamicResource
+    );
+    assert_eq!(
+      app
+        .data
+        .selected
+        .dynamic_kind
+        .as_ref()
+        .map(|it| it.kind.as_str()),
+      Some("Widget")
+    );
+    assert_eq!(app.data.dynamic_resources.items, cached_items);
   }
 
-  #[test]
-  fn test_handle_menu_scroll_within_filtered_bounds() {
-    let mut menu = StatefulList::with_items(vec![
-      ("A".into(), ActiveBlock::CronJobs),
-      ("B".into(), ActiveBlock::Secrets),
-      ("C".into(), ActiveBlock::Roles),
-    ]);
-
-    // Scroll down within filtered_len=2
-    menu.state.select(Some(0));
-    handle_menu_scroll(&mut menu, ScrollEvent::down(), 2);
-    assert_eq!(menu.state.selected(), Some(1));
+  #[tokio::test]
+  async fn test_enter_on_dynamic_resource_runs_describe() {
+    let (sync_io_tx, _sync_io_rx) = mpsc::channel(10);
+    let (sync_io_stream_tx, _sync_io_stream_rx) = mpsc::channel(10);
+    let (sync_io_cmd_tx, mut sync_io_cmd_rx) = mpsc::channel::<IoCmdEvent>(10);
+    let mut app = App::new(
+      sync_io_tx,
+      sync_io_stream_tx,
+      sync_io_cmd_tx,
+      false,
+      1,
+      App::default().log_tail_lines,
+      crate::config::KdashConfig::default(),
+    );
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::DynamicResource);
 
-    // Scroll down wraps at filtered_len
-    handle_menu_scroll(&mut menu, ScrollEvent::down(), 2);
-    assert_eq!(menu.state.selected(), Some(0));
+    let kind = KubeDynamicKind::new(
+      ApiResource {
+        group: "example.com".into(),
+        version: "v1".into(),
+        api_version: "example.com/v1".into(),
+        kind: "Widget".into(),
+        plural: "widgets".into(),
+      },
+      Scope::Namespaced,
+    );
+    app.data.selected.dynamic_kind = Some(kind);
+    app.data.dynamic_resources =
+      StatefulTable::with_items(vec![KubeDynamicResource::from(DynamicObject {
+        types: None,
+        metadata: ObjectMeta {
+          name: Some("widget-1".into()),
+          namespace: Some("team-a".into()),
+          ..Default::default()
+        },
+        data: Default::default(),
+      })]);
 
-    // Scroll up from 0 wraps to end of filtered
-    handle_menu_scroll(&mut menu, ScrollEvent::up(), 2);
-    assert_eq!(menu.state.selected(), Some(1));
-  }
+    let key_evt = KeyEvent::from(KeyCode::Enter);
+    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
 
-  #[test]
-  fn test_handle_menu_scroll_empty_filtered() {
-    let mut menu = StatefulList::with_items(vec![("A".into(), ActiveBlock::CronJobs)]);
-    menu.state.select(Some(0));
-    // Should not panic with filtered_len=0
-    handle_menu_scroll(&mut menu, ScrollEvent::Relative(0), 0);
-    assert_eq!(menu.state.selected(), Some(0));
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
+    assert_eq!(
+      sync_io_cmd_rx.recv().await.unwrap(),
+      IoCmdEvent::GetDescribe {
+        kind: "Widget".into(),
+        value: "widget-1".into(),
+        ns: Some("team-a".into()),
+        resource_version: None,
+      }
+    );
   }
 
   #[tokio::test]
-  async fn test_dispatch_resource_pods_sets_selector_state() {
-    let mut app = App::default();
+  async fn test_scrolling_near_the_end_loads_the_next_page() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let (sync_io_stream_tx, _sync_io_stream_rx) = mpsc::channel(10);
+    let (sync_io_cmd_tx, _sync_io_cmd_rx) = mpsc::channel(10);
+    let mut app = App::new(
+      sync_io_tx,
+      sync_io_stream_tx,
+      sync_io_cmd_tx,
+      false,
+      1,
+      App::default().log_tail_lines,
+      crate::config::KdashConfig::default(),
+    );
     app.route_home();
-
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
     app
-      .dispatch_resource_pods(
-        "default".into(),
-        "app=nginx".into(),
-        "deployment".into(),
-        RouteId::Home,
-      )
-      .await;
-
-    assert_eq!(app.data.selected.pod_selector, Some("app=nginx".into()));
-    assert_eq!(app.data.selected.pod_selector_ns, Some("default".into()));
-    assert_eq!(
-      app.data.selected.pod_selector_resource,
-      Some("deployment".into())
+      .data
+      .pods
+      .set_items(vec![KubePod::default(); PAGE_PREFETCH_ROWS + 5]);
+    app.data.list_pages.insert(
+      ActiveBlock::Pods,
+      ListPages {
+        requested: 1,
+        loaded: 1,
+        has_more: true,
+      },
     );
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
+
+    // Far from the end: nothing to fetch yet.
+    handle_block_scroll(&mut app, ScrollEvent::down(), false).await;
+    assert!(sync_io_rx.try_recv().is_err());
+
+    handle_block_scroll(&mut app, ScrollEvent::End, false).await;
+    assert_eq!(sync_io_rx.try_recv().unwrap(), IoEvent::GetPods);
+    assert_eq!(app.data.list_pages[&ActiveBlock::Pods].requested, 2);
+    assert!(app.view_load(ActiveBlock::Pods).has_more);
+
+    // The page is still on its way: scrolling again does not ask twice.
+    handle_block_scroll(&mut app, ScrollEvent::up(), false).await;
+    assert!(sync_io_rx.try_recv().is_err());
   }
 
   #[tokio::test]
-  async fn test_dispatch_aggregate_logs_sets_state() {
+  async fn test_toggle_polling_key() {
     let mut app = App::default();
     app.route_home();
+    let key = DEFAULT_KEYBINDING.toggle_polling.key;
 
-    app
-      .dispatch_aggregate_logs(
-        "my-deploy".into(),
-        "default".into(),
-        "app=nginx".into(),
-        "deployment".into(),
-        RouteId::Home,
-      )
-      .await;
-
-    assert_eq!(app.data.logs.id, "agg:my-deploy");
+    handle_key_events(key, KeyEvent::from(KeyCode::Char('P')), &mut app).await;
+    assert!(app.polling_paused);
     assert_eq!(
-      app.data.selected.pod_selector_resource,
-      Some("deployment".into())
+      app.status_message.text(),
+      "Auto-refresh paused, F5 to refresh once"
     );
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::Logs);
+
+    handle_key_events(key, KeyEvent::from(KeyCode::Char('P')), &mut app).await;
+    assert!(!app.polling_paused);
   }
 
   #[tokio::test]
-  async fn test_escape_from_more_selected_resource_returns_to_more_not_dynamic() {
+  async fn test_finder_jumps_to_the_selected_resource() {
     let mut app = App::default();
-    // Both pickers visited (Dynamic, then More), then a resource picked from More.
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::DynamicView);
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::More);
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Secrets);
+    app.route_home();
+    let mut pod = KubePod::default();
+    pod.name = "web-0".into();
+    pod.namespace = "team-a".into();
+    app.data.pods.set_items(vec![pod]);
+    let secrets = ["db-password", "api-token", "tls-cert"].map(|name| {
+      let mut secret = KubeSecret::default();
+      secret.name = name.into();
+      secret.namespace = "team-a".into();
+      secret
+    });
+    app.data.secrets.set_items(secrets.to_vec());
+    app.data.secrets.filter = "tls".into();
+
+    handle_key_events(
+      Key::Ctrl('p'),
+      KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL),
+      &mut app,
+    )
+    .await;
+    for c in "apitok".chars() {
+      handle_key_events(Key::Char(c), KeyEvent::from(KeyCode::Char(c)), &mut app).await;
+    }
+    assert_eq!(app.finder.as_ref().map(|finder| finder.matched), Some(1));
 
-    let key_evt = KeyEvent::from(KeyCode::Esc);
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    handle_key_events(Key::Enter, KeyEvent::from(KeyCode::Enter), &mut app).await;
 
-    // Returns to the picker actually used (More), not the stacked Dynamic menu.
+    assert!(app.finder.is_none());
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Secrets);
+    assert_eq!(app.data.secrets.state.selected(), Some(1));
+    assert!(app.data.secrets.filter.is_empty());
+    // Esc goes back to the More menu the secrets view was opened from.
+    app.pop_navigation_stack();
     assert_eq!(app.get_current_route().active_block, ActiveBlock::More);
   }
 
   #[tokio::test]
-  async fn test_reset_navigation_key_clears_stack_to_home() {
+  async fn test_ns_picker_switches_the_namespace() {
     let mut app = App::default();
-    // Wander to another view and drill down a couple of levels.
-    app.route_utilization();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Describe);
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Yaml);
-
-    let reset_key = DEFAULT_KEYBINDING.reset_navigation.key;
-    let key_evt = KeyEvent::from(KeyCode::Char('h')); // representative; key dispatch uses `reset_key`
-    handle_key_events(reset_key, key_evt, &mut app).await;
+    app.route_home();
+    let namespaces = ["default", "kube-system", "team-a"].map(|name| {
+      let mut ns = KubeNs::default();
+      ns.name = name.into();
+      ns
+    });
+    app.data.namespaces.set_items(namespaces.to_vec());
+    app.data.selected.ns = Some("default".into());
+    let open = Key::Ctrl('n');
+    let open_evt = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
+
+    handle_key_events(open, open_evt, &mut app).await;
+    for c in "team".chars() {
+      handle_key_events(Key::Char(c), KeyEvent::from(KeyCode::Char(c)), &mut app).await;
+    }
+    handle_key_events(Key::Enter, KeyEvent::from(KeyCode::Enter), &mut app).await;
+    assert!(app.ns_picker.is_none());
+    assert_eq!(app.data.selected.ns, Some("team-a".into()));
 
-    assert_eq!(app.get_current_route().id, RouteId::Home);
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
-    // Back at the root: nothing left to pop.
-    assert_eq!(app.pop_navigation_stack(), None);
+    // all namespaces sits on top of the list
+    handle_key_events(open, open_evt, &mut app).await;
+    send_keys(&mut app, &[KeyCode::PageUp, KeyCode::Enter]).await;
+    assert_eq!(app.data.selected.ns, None);
+    assert_eq!(app.data.selected.previous_ns, Some("team-a".into()));
+
+    handle_key_events(open, open_evt, &mut app).await;
+    handle_key_events(open, open_evt, &mut app).await;
+    assert!(app.ns_picker.is_none());
   }
 
   #[tokio::test]
-  async fn test_escape_from_filtered_pods_clears_selector_state() {
+  async fn test_enter_on_a_crd_lists_its_instances() {
+    use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+
     let mut app = App::default();
     app.route_home();
+    let crd: CustomResourceDefinition = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "certificates.cert-manager.io" },
+      "spec": {
+        "group": "cert-manager.io",
+        "names": { "kind": "Certificate", "plural": "certificates" },
+        "scope": "Namespaced",
+        "versions": [{ "name": "v1", "served": true, "storage": true }]
+      }
+    }))
+    .unwrap();
+    app.data.crds.set_items(vec![KubeCrd::from(crd)]);
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::CustomResourceDefinitions);
 
-    // Simulate drill-down state
-    app.data.selected.pod_selector = Some("app=nginx".into());
-    app.data.selected.pod_selector_ns = Some("default".into());
-    app.data.selected.pod_selector_resource = Some("deployment".into());
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
-
-    // Press Esc
-    let key_evt = KeyEvent::from(KeyCode::Esc);
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    handle_key_events(Key::Enter, KeyEvent::from(KeyCode::Enter), &mut app).await;
 
-    assert_eq!(app.data.selected.pod_selector, None);
-    assert_eq!(app.data.selected.pod_selector_ns, None);
-    assert_eq!(app.data.selected.pod_selector_resource, None);
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::DynamicResource
+    );
+    let kind = app.data.selected.dynamic_kind.as_ref().unwrap();
+    assert_eq!(kind.kind, "Certificate");
+    assert_eq!(kind.api_resource.api_version, "cert-manager.io/v1");
+    assert_eq!(kind.scope, Scope::Namespaced);
   }
 
   #[tokio::test]
-  async fn test_escape_from_aggregate_logs_clears_resource_context() {
-    let mut app = App::default();
+  async fn test_poll_now_refetches_the_current_view() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let (sync_io_stream_tx, _sync_io_stream_rx) = mpsc::channel(10);
+    let (sync_io_cmd_tx, _sync_io_cmd_rx) = mpsc::channel(10);
+    let mut app = App::new(
+      sync_io_tx,
+      sync_io_stream_tx,
+      sync_io_cmd_tx,
+      false,
+      1,
+      App::default().log_tail_lines,
+      crate::config::KdashConfig::default(),
+    );
     app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Services);
+    app.refresh = false;
 
-    // Simulate aggregate logs state (no pod_selector set)
-    app.data.selected.pod_selector_resource = Some("deployment".into());
-    app.data.logs = crate::app::models::LogsState::new("agg:my-deploy".into());
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
-
-    // Press Esc
-    let key_evt = KeyEvent::from(KeyCode::Esc);
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    handle_key_events(Key::F(5), KeyEvent::from(KeyCode::F(5)), &mut app).await;
 
-    // Resource context should be cleared for aggregate logs
-    assert_eq!(app.data.selected.pod_selector_resource, None);
+    let mut dispatched = vec![];
+    while let Ok(event) = sync_io_rx.try_recv() {
+      dispatched.push(event);
+    }
+    assert_eq!(
+      dispatched,
+      vec![
+        IoEvent::GetNamespaces,
+        IoEvent::GetNodes,
+        IoEvent::GetServices
+      ]
+    );
+    assert!(!app.refresh, "only the current view is re-fetched");
   }
 
   #[tokio::test]
-  async fn test_escape_from_drilldown_logs_preserves_resource_context() {
-    let mut app = App::default();
+  async fn test_api_resources_are_discovered_once_until_refetched() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let (sync_io_stream_tx, _sync_io_stream_rx) = mpsc::channel(10);
+    let (sync_io_cmd_tx, _sync_io_cmd_rx) = mpsc::channel(10);
+    let mut app = App::new(
+      sync_io_tx,
+      sync_io_stream_tx,
+      sync_io_cmd_tx,
+      false,
+      1,
+      App::default().log_tail_lines,
+      crate::config::KdashConfig::default(),
+    );
     app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::ApiResources);
+    app.refresh = false;
+    let mut drain = || {
+      let mut dispatched = vec![];
+      while let Ok(event) = sync_io_rx.try_recv() {
+        dispatched.push(event);
+      }
+      dispatched.contains(&IoEvent::GetApiResources)
+    };
 
-    // Simulate drill-down: Deployment → Pods → Container → Logs
-    app.data.selected.pod_selector = Some("app=nginx".into());
-    app.data.selected.pod_selector_ns = Some("default".into());
-    app.data.selected.pod_selector_resource = Some("deployment".into());
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Pods);
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Logs);
+    app.poll_current_route(false).await;
+    assert!(drain(), "nothing cached yet");
 
-    // Press Esc from Logs — should go back to Containers, resource context preserved
-    let key_evt = KeyEvent::from(KeyCode::Esc);
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    app.data.api_resources.set_items(vec![KubeApiResource {
+      name: "pods".into(),
+      api_version: "v1".into(),
+      kind: "Pod".into(),
+      ..KubeApiResource::default()
+    }]);
+    app.poll_current_route(false).await;
+    assert!(!drain(), "polling reuses the cached discovery");
 
-    assert_eq!(
-      app.data.selected.pod_selector_resource,
-      Some("deployment".into())
-    );
-    assert_eq!(app.data.selected.pod_selector, Some("app=nginx".into()));
+    handle_key_events(Key::F(5), KeyEvent::from(KeyCode::F(5)), &mut app).await;
+    assert!(drain(), "F5 discovers again");
   }
 
   #[tokio::test]
-  async fn test_jump_to_pods_clears_selector_state() {
-    let mut app = App::default();
+  async fn test_retry_failed_redispatches_the_failed_fetch() {
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let (sync_io_stream_tx, _sync_io_stream_rx) = mpsc::channel(10);
+    let (sync_io_cmd_tx, _sync_io_cmd_rx) = mpsc::channel(10);
+    let mut app = App::new(
+      sync_io_tx,
+      sync_io_stream_tx,
+      sync_io_cmd_tx,
+      false,
+      1,
+      App::default().log_tail_lines,
+      crate::config::KdashConfig::default(),
+    );
     app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Services);
+    let key = DEFAULT_KEYBINDING.retry_failed.key;
 
-    // Simulate leftover drill-down state
-    app.data.selected.pod_selector = Some("app=nginx".into());
-    app.data.selected.pod_selector_ns = Some("default".into());
-    app.data.selected.pod_selector_resource = Some("deployment".into());
+    // Nothing failed yet: the key does not dispatch anything.
+    handle_key_events(key, KeyEvent::from(KeyCode::Char('R')), &mut app).await;
+    assert!(sync_io_rx.try_recv().is_err());
 
-    // Press '1' to jump to pods tab
-    let key_evt = KeyEvent::from(KeyCode::Char('1'));
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    let errors_before = app.errors_recorded();
+    app.handle_error(anyhow!("Failed to get services. timed out"));
+    app.finish_load(IoEvent::GetServices, errors_before);
 
-    assert_eq!(app.data.selected.pod_selector, None);
-    assert_eq!(app.data.selected.pod_selector_ns, None);
-    assert_eq!(app.data.selected.pod_selector_resource, None);
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
+    handle_key_events(key, KeyEvent::from(KeyCode::Char('R')), &mut app).await;
+    assert_eq!(sync_io_rx.try_recv().unwrap(), IoEvent::GetServices);
+    assert_eq!(
+      app.view_load(ActiveBlock::Services).state,
+      LoadState::Loading
+    );
   }
 
   #[tokio::test]
-  async fn test_enter_on_leaf_resource_runs_describe() {
+  async fn test_config_map_data_lists_keys_and_opens_values() {
+    use k8s_openapi::{api::core::v1::ConfigMap, ByteString};
+
     let mut app = App::default();
     app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::ConfigMaps);
+    let mut cm = ConfigMap::default();
+    cm.metadata.name = Some("app-config".into());
+    cm.data = Some(
+      [("app.yaml".to_owned(), "port: 8080\ndebug: false".to_owned())]
+        .into_iter()
+        .collect(),
+    );
+    cm.binary_data = Some(
+      [(
+        "logo.png".to_owned(),
+        ByteString(vec![0x89, 0x50, 0x4e, 0x47]),
+      )]
+      .into_iter()
+      .collect(),
+    );
+    let mut empty = ConfigMap::default();
+    empty.metadata.name = Some("empty".into());
+    app
+      .data
+      .config_maps
+      .set_items(vec![cm.into(), empty.into()]);
 
-    // Navigate to Secrets (a leaf resource with no child views)
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::Secrets);
+    send_keys(&mut app, &[KeyCode::Enter]).await;
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::ConfigMapData
+    );
+    assert_eq!(app.data.selected.config_map.as_deref(), Some("app-config"));
+    assert_eq!(app.data.config_map_data.items.len(), 2);
 
-    let mut secret = KubeSecret::default();
-    secret.name = "my-secret".into();
-    secret.namespace = "default".into();
-    app.data.secrets.set_items(vec![secret]);
+    send_keys(&mut app, &[KeyCode::Enter]).await;
+    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
+    assert_eq!(app.data.describe_out.get_txt(), "port: 8080\ndebug: false");
 
-    // Press Enter
-    let key_evt = KeyEvent::from(KeyCode::Enter);
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    // binary values are noted, never dumped
+    send_keys(&mut app, &[KeyCode::Esc, KeyCode::Down, KeyCode::Enter]).await;
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::ConfigMapData
+    );
+    assert_eq!(
+      app.status_message.text(),
+      "logo.png: Binary data (4 B), not shown"
+    );
 
-    // Should navigate to Describe view
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
+    // a ConfigMap without data has nothing to open
+    send_keys(&mut app, &[KeyCode::Esc, KeyCode::Down, KeyCode::Enter]).await;
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::ConfigMaps
+    );
+    assert_eq!(app.status_message.text(), "ConfigMap empty has no data");
   }
 
   #[tokio::test]
-  async fn test_dispatch_node_pods_sets_state() {
+  async fn test_containers_expand_and_scroll_volume_mounts() {
+    use crate::app::pods::ContainerMount;
+
     let mut app = App::default();
     app.route_home();
-
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
+    let mount = |name: &str| ContainerMount {
+      name: name.into(),
+      path: format!("/{}", name),
+      read_only: true,
+      source: format!("configMap {}", name),
+    };
+    let mut container = KubeContainer::default();
+    container.mounts = vec![mount("a"), mount("b")];
     app
-      .dispatch_node_pods("my-node-01".into(), RouteId::Home)
-      .await;
+      .data
+      .containers
+      .set_items(vec![container, KubeContainer::default()]);
 
-    assert_eq!(app.data.selected.pod_selector, Some("my-node-01".into()));
-    assert_eq!(app.data.selected.pod_selector_ns, None);
-    assert_eq!(app.data.selected.pod_selector_resource, Some("node".into()));
-    assert_eq!(app.get_current_route().active_block, ActiveBlock::Pods);
+    send_keys(&mut app, &[KeyCode::Char('z'), KeyCode::Down]).await;
+    let mounts = app.data.container_mounts.as_ref().unwrap();
+    assert_eq!(
+      mounts.get_txt(),
+      "a  /a  ro  configMap a\nb  /b  ro  configMap b"
+    );
+    // Scrolling moves the expanded mounts, not the container selection.
+    assert_eq!(mounts.offset, 1);
+    assert_eq!(app.data.containers.state.selected(), Some(0));
+
+    send_keys(&mut app, &[KeyCode::Char('z'), KeyCode::Down]).await;
+    assert!(app.data.container_mounts.is_none());
+    assert_eq!(app.data.containers.state.selected(), Some(1));
+
+    send_keys(&mut app, &[KeyCode::Char('z')]).await;
+    assert!(app.data.container_mounts.is_none());
+    assert_eq!(
+      app.status_message.text(),
+      "No volumes mounted in this container"
+    );
   }
 
   #[tokio::test]
-  async fn test_dynamic_view_selection_uses_cached_items_immediately() {
-    let mut app = App::default();
-    app.push_navigation_stack(RouteId::Home, ActiveBlock::DynamicView);
-    app.dynamic_resources_menu =
-      StatefulList::with_items(vec![("Widget".into(), ActiveBlock::DynamicResource)]);
-    app.dynamic_resources_menu.state.select(Some(0));
+  async fn test_containers_expand_env_and_reveal_secrets() {
+    use crate::app::pods::{ContainerEnv, EnvSource};
 
-    let kind = KubeDynamicKind::new(
-      ApiResource {
-        group: "example.com".into(),
-        version: "v1".into(),
-        api_version: "example.com/v1".into(),
-        kind: "Widget".into(),
-        plural: "widgets".into(),
+    let (sync_io_tx, mut sync_io_rx) = mpsc::channel::<IoEvent>(10);
+    let (sync_io_stream_tx, _sync_io_stream_rx) = mpsc::channel(10);
+    let (sync_io_cmd_tx, _sync_io_cmd_rx) = mpsc::channel(10);
+    let mut app = App::new(
+      sync_io_tx,
+      sync_io_stream_tx,
+      sync_io_cmd_tx,
+      false,
+      1,
+      App::default().log_tail_lines,
+      crate::config::KdashConfig::default(),
+    );
+    app.route_home();
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Containers);
+    let mut container = KubeContainer::default();
+    container.env = vec![
+      ContainerEnv {
+        name: "PORT".into(),
+        source: EnvSource::Value("8080".into()),
       },
-      Scope::Namespaced,
+      ContainerEnv {
+        name: "TOKEN".into(),
+        source: EnvSource::SecretKey {
+          name: "creds".into(),
+          key: "token".into(),
+        },
+      },
+    ];
+    container.mounts = vec![crate::app::pods::ContainerMount {
+      name: "data".into(),
+      path: "/data".into(),
+      read_only: false,
+      source: "emptyDir".into(),
+    }];
+    app
+      .data
+      .containers
+      .set_items(vec![container, KubeContainer::default()]);
+
+    let shift_z = shift_char('Z');
+    handle_key_events(Key::from(shift_z), shift_z, &mut app).await;
+    send_keys(&mut app, &[KeyCode::Down]).await;
+    let env = app.data.container_env.as_ref().unwrap();
+    assert_eq!(
+      env.txt.get_txt(),
+      "PORT   8080\nTOKEN  from secret creds key token"
     );
-    app.data.dynamic_kinds = vec![kind.clone()];
-    app.data.selected.ns = Some("team-a".into());
+    assert_eq!(env.txt.offset, 1);
+    assert_eq!(app.data.containers.state.selected(), Some(0));
 
-    let cached_items = vec![KubeDynamicResource::from(DynamicObject {
-      types: None,
-      metadata: ObjectMeta {
-        name: Some("widget-1".into()),
-        namespace: Some("team-a".into()),
-        ..Default::default()
-      },
-      data: Default::default(),
-    })];
-    app.data.dynamic_resource_cache.insert(
-      dynamic_cache_key(&kind, Some("team-a")),
-      cached_items.clone(),
+    // 'x' reveals secret values instead of opening logs
+    send_keys(&mut app, &[KeyCode::Char('x')]).await;
+    assert!(app.data.container_env.as_ref().unwrap().reveal);
+    assert_eq!(sync_io_rx.recv().await.unwrap(), IoEvent::GetSecrets);
+    assert_eq!(
+      app.get_current_route().active_block,
+      ActiveBlock::Containers
     );
 
-    let key_evt = KeyEvent::from(KeyCode::Enter);
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    send_keys(&mut app, &[KeyCode::Char('x')]).await;
+    assert!(!app.data.container_env.as_ref().unwrap().reveal);
+
+    // expanding the mounts folds the env
+    send_keys(&mut app, &[KeyCode::Char('z')]).await;
+    assert!(app.data.container_env.is_none());
+    assert!(app.data.container_mounts.is_some());
 
+    send_keys(&mut app, &[KeyCode::Char('z'), KeyCode::Down]).await;
+    handle_key_events(Key::from(shift_z), shift_z, &mut app).await;
+    assert!(app.data.container_env.is_none());
     assert_eq!(
-      app.get_current_route().active_block,
-      ActiveBlock::DynamicResource
+      app.status_message.text(),
+      "No environment variables set in this container"
     );
+  }
+
+  #[tokio::test]
+  async fn test_pod_describe_expands_and_scrolls_scheduling_constraints() {
+    let mut app = App::default();
+    app.route_home();
+    let pod: k8s_openapi::api::core::v1::Pod = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web", "namespace": "default" },
+      "spec": {
+        "containers": [{ "name": "app" }],
+        "tolerations": [{ "operator": "Exists" }, { "key": "gpu", "operator": "Exists" }]
+      }
+    }))
+    .unwrap();
+    app
+      .data
+      .pods
+      .set_items(vec![KubePod::from(pod), KubePod::default()]);
+    app.data.describe_out = ScrollableTxt::with_string("Name: web\nNamespace: default".into());
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Describe);
+
+    send_keys(&mut app, &[KeyCode::Char('z'), KeyCode::Down]).await;
+    let constraints = app.data.pod_constraints.as_ref().unwrap();
     assert_eq!(
-      app
-        .data
-        .selected
-        .dynamic_kind
-        .as_ref()
-        .map(|it| it.kind.as_str()),
-      Some("Widget")
+      constraints.get_txt(),
+      "Tolerations\n  any taint\n  gpu exists"
+    );
+    // Scrolling moves the expanded constraints, not the describe output.
+    assert_eq!(constraints.offset, 1);
+    assert_eq!(app.data.describe_out.offset, 0);
+
+    send_keys(&mut app, &[KeyCode::Char('z'), KeyCode::Down]).await;
+    assert!(app.data.pod_constraints.is_none());
+    assert_eq!(app.data.describe_out.offset, 1);
+
+    app.data.pods.state.select(Some(1));
+    send_keys(&mut app, &[KeyCode::Char('z')]).await;
+    assert!(app.data.pod_constraints.is_none());
+    assert_eq!(
+      app.status_message.text(),
+      "No tolerations, node selector or affinity set on this pod"
     );
-    assert_eq!(app.data.dynamic_resources.items, cached_items);
   }
 
   #[tokio::test]
-  async fn test_enter_on_dynamic_resource_runs_describe() {
+  async fn test_describe_reuses_cached_output_until_refetched() {
     let (sync_io_tx, _sync_io_rx) = mpsc::channel(10);
     let (sync_io_stream_tx, _sync_io_stream_rx) = mpsc::channel(10);
     let (sync_io_cmd_tx, mut sync_io_cmd_rx) = mpsc::channel::<IoCmdEvent>(10);
@@ -3791,8 +7193,7 @@ mod tests {
       crate::config::KdashConfig::default(),
     );
     app.push_navigation_stack(RouteId::Home, ActiveBlock::DynamicResource);
-
-    let kind = KubeDynamicKind::new(
+    app.data.selected.dynamic_kind = Some(KubeDynamicKind::new(
       ApiResource {
         group: "example.com".into(),
         version: "v1".into(),
@@ -3801,30 +7202,45 @@ mod tests {
         plural: "widgets".into(),
       },
       Scope::Namespaced,
-    );
-    app.data.selected.dynamic_kind = Some(kind);
+    ));
     app.data.dynamic_resources =
       StatefulTable::with_items(vec![KubeDynamicResource::from(DynamicObject {
         types: None,
         metadata: ObjectMeta {
           name: Some("widget-1".into()),
           namespace: Some("team-a".into()),
+          resource_version: Some("42".into()),
           ..Default::default()
         },
         data: Default::default(),
       })]);
+    let describe = IoCmdEvent::GetDescribe {
+      kind: "Widget".into(),
+      value: "widget-1".into(),
+      ns: Some("team-a".into()),
+      resource_version: Some("42".into()),
+    };
 
-    let key_evt = KeyEvent::from(KeyCode::Enter);
-    handle_key_events(Key::from(key_evt), key_evt, &mut app).await;
+    let enter = KeyEvent::from(KeyCode::Enter);
+    handle_key_events(Key::from(enter), enter, &mut app).await;
+    assert_eq!(sync_io_cmd_rx.recv().await.unwrap(), describe);
+    // What the CmdRunner stores once kubectl returns
+    app.data.describe_cache.insert(
+      describe.describe_key().unwrap(),
+      "Name: widget-1".into(),
+      Instant::now(),
+    );
 
+    let esc = KeyEvent::from(KeyCode::Esc);
+    handle_key_events(Key::from(esc), esc, &mut app).await;
+    handle_key_events(Key::from(enter), enter, &mut app).await;
     assert_eq!(app.get_current_route().active_block, ActiveBlock::Describe);
-    assert_eq!(
-      sync_io_cmd_rx.recv().await.unwrap(),
-      IoCmdEvent::GetDescribe {
-        kind: "Widget".into(),
-        value: "widget-1".into(),
-        ns: Some("team-a".into()),
-      }
-    );
+    assert_eq!(app.data.describe_out.get_txt(), "Name: widget-1");
+    assert!(sync_io_cmd_rx.try_recv().is_err());
+
+    let refetch = KeyEvent::from(KeyCode::Char('u'));
+    handle_key_events(Key::from(refetch), refetch, &mut app).await;
+    assert_eq!(sync_io_cmd_rx.recv().await.unwrap(), describe);
+    assert_eq!(app.data.describe_out.get_txt(), "");
   }
 }
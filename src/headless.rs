@@ -0,0 +1,211 @@
+//! `--output json|yaml`: fetch the `--view` list once, print it to stdout as
+//! a Kubernetes `List` and exit, without starting the UI. The fetch goes
+//! through the same network handlers the UI polls, so namespaces, contexts,
+//! impersonation and errors behave the same.
+use std::{
+  io::{stdout, Write},
+  sync::Arc,
+};
+
+use anyhow::{anyhow, Context, Result};
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+
+use crate::{
+  app::{load_state::ListPages, models::KubeResource, ActiveBlock, App},
+  network::{get_client, IoEvent, Network},
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+  Json,
+  Yaml,
+}
+
+/// Fetch the view the app was started on and print its objects.
+pub async fn run(app: &Arc<Mutex<App>>, format: OutputFormat) -> Result<()> {
+  let (block, event, context, errors_before) = {
+    let mut app = app.lock().await;
+    let block = app.get_current_route().active_block;
+    let event = headless_event(&app, block).ok_or_else(|| {
+      anyhow!(
+        "--output does not support the {} view",
+        app.active_tab_name()
+      )
+    })?;
+    // every page, not just the first one the UI shows
+    app.data.list_pages.insert(
+      block,
+      ListPages {
+        requested: usize::MAX,
+        ..ListPages::default()
+      },
+    );
+    (
+      block,
+      event,
+      app.data.selected.context.clone(),
+      app.errors_recorded(),
+    )
+  };
+
+  let client = get_client(context).await?;
+  Network::new(client, app).handle_network_event(event).await;
+
+  let app = app.lock().await;
+  if app.errors_recorded() > errors_before {
+    let message = app.error_history.back().map_or_else(
+      || "Failed to fetch the view".to_owned(),
+      |e| e.message.clone(),
+    );
+    return Err(anyhow!(message));
+  }
+  let objects = view_objects(&app, block).unwrap_or_default();
+  let text = render(objects, format)?;
+  let mut out = stdout();
+  out.write_all(text.as_bytes())?;
+  out.flush()?;
+  Ok(())
+}
+
+/// The list fetch of a view. Namespaces and nodes are polled with every view
+/// in the UI, so they have no fetch of their own there.
+fn headless_event(app: &App, block: ActiveBlock) -> Option<IoEvent> {
+  match block {
+    ActiveBlock::Namespaces => Some(IoEvent::GetNamespaces),
+    ActiveBlock::Nodes => Some(IoEvent::GetNodes),
+    ActiveBlock::Pods
+    | ActiveBlock::Services
+    | ActiveBlock::ConfigMaps
+    | ActiveBlock::StatefulSets
+    | ActiveBlock::ReplicaSets
+    | ActiveBlock::Deployments
+    | ActiveBlock::Jobs
+    | ActiveBlock::DaemonSets
+    | ActiveBlock::CronJobs
+    | ActiveBlock::Secrets
+    | ActiveBlock::ReplicationControllers
+    | ActiveBlock::StorageClasses
+    | ActiveBlock::Roles
+    | ActiveBlock::RoleBindings
+    | ActiveBlock::ClusterRoles
+    | ActiveBlock::ClusterRoleBindings
+    | ActiveBlock::Ingresses
+    | ActiveBlock::PersistentVolumeClaims
+    | ActiveBlock::PersistentVolumes
+    | ActiveBlock::NetworkPolicies
+    | ActiveBlock::ServiceAccounts
+    | ActiveBlock::Events
+    | ActiveBlock::ResourceQuotas
+    | ActiveBlock::LimitRanges
+    | ActiveBlock::PodDisruptionBudgets
+    | ActiveBlock::CustomResourceDefinitions => app.fetch_event(block),
+    _ => None,
+  }
+}
+
+/// The objects behind the rows of a view, as the API returned them.
+fn view_objects(app: &App, block: ActiveBlock) -> Option<Vec<Value>> {
+  macro_rules! objects {
+    ($field:ident) => {
+      app
+        .data
+        .$field
+        .items
+        .iter()
+        .filter_map(|res| serde_json::to_value(res.get_k8s_obj()).ok())
+        .collect()
+    };
+  }
+
+  Some(match block {
+    ActiveBlock::Namespaces => objects!(namespaces),
+    ActiveBlock::Nodes => objects!(nodes),
+    ActiveBlock::Pods => objects!(pods),
+    ActiveBlock::Services => objects!(services),
+    ActiveBlock::ConfigMaps => objects!(config_maps),
+    ActiveBlock::StatefulSets => objects!(stateful_sets),
+    ActiveBlock::ReplicaSets => objects!(replica_sets),
+    ActiveBlock::Deployments => objects!(deployments),
+    ActiveBlock::Jobs => objects!(jobs),
+    ActiveBlock::DaemonSets => objects!(daemon_sets),
+    ActiveBlock::CronJobs => objects!(cronjobs),
+    ActiveBlock::Secrets => objects!(secrets),
+    ActiveBlock::ReplicationControllers => objects!(replication_controllers),
+    ActiveBlock::StorageClasses => objects!(storage_classes),
+    ActiveBlock::Roles => objects!(roles),
+    ActiveBlock::RoleBindings => objects!(role_bindings),
+    ActiveBlock::ClusterRoles => objects!(cluster_roles),
+    ActiveBlock::ClusterRoleBindings => objects!(cluster_role_bindings),
+    ActiveBlock::Ingresses => objects!(ingress),
+    ActiveBlock::PersistentVolumeClaims => objects!(persistent_volume_claims),
+    ActiveBlock::PersistentVolumes => objects!(persistent_volumes),
+    ActiveBlock::NetworkPolicies => objects!(network_policies),
+    ActiveBlock::ServiceAccounts => objects!(service_accounts),
+    ActiveBlock::Events => objects!(events),
+    ActiveBlock::ResourceQuotas => objects!(resource_quotas),
+    ActiveBlock::LimitRanges => objects!(limit_ranges),
+    ActiveBlock::PodDisruptionBudgets => objects!(pdbs),
+    ActiveBlock::CustomResourceDefinitions => objects!(crds),
+    _ => return None,
+  })
+}
+
+/// A `v1` `List` of the objects, like `kubectl get -o json|yaml` prints.
+fn render(objects: Vec<Value>, format: OutputFormat) -> Result<String> {
+  let list = json!({
+    "apiVersion": "v1",
+    "kind": "List",
+    "items": objects,
+  });
+  match format {
+    OutputFormat::Json => serde_json::to_string_pretty(&list)
+      .map(|json| json + "\n")
+      .context("Failed to write the list as JSON"),
+    OutputFormat::Yaml => {
+      serde_saphyr::to_string(&list).context("Failed to write the list as YAML")
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_render_list() {
+    let pod = json!({ "apiVersion": "v1", "kind": "Pod", "metadata": { "name": "web-1" } });
+    let json_out = render(vec![pod.clone()], OutputFormat::Json).unwrap();
+    let parsed: Value = serde_json::from_str(&json_out).unwrap();
+    assert_eq!(parsed["kind"], "List");
+    assert_eq!(parsed["items"][0]["metadata"]["name"], "web-1");
+
+    let yaml_out = render(vec![pod], OutputFormat::Yaml).unwrap();
+    assert!(yaml_out.contains("kind: List"));
+    assert!(yaml_out.contains("name: web-1"));
+  }
+
+  #[test]
+  fn test_headless_event_covers_lists_only() {
+    let mut app = App::default();
+    app.data.selected.pod_selector = Some("app=web".into());
+    app.data.selected.pod_selector_ns = Some("shop".into());
+    assert_eq!(
+      headless_event(&app, ActiveBlock::Pods),
+      Some(IoEvent::GetPodsBySelector {
+        namespace: "shop".into(),
+        selector: "app=web".into(),
+      })
+    );
+    assert_eq!(
+      headless_event(&app, ActiveBlock::Nodes),
+      Some(IoEvent::GetNodes)
+    );
+    assert_eq!(headless_event(&app, ActiveBlock::Utilization), None);
+    assert_eq!(headless_event(&app, ActiveBlock::Contexts), None);
+    assert!(view_objects(&app, ActiveBlock::Deployments)
+      .unwrap()
+      .is_empty());
+  }
+}
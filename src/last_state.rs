@@ -0,0 +1,223 @@
+//! The context, namespace and tab in use when KDash last exited. Written on
+//! shutdown to `<state dir>/kdash/last.json` and restored on the next start
+//! unless `--no-restore` is passed.
+use std::{
+  fs, io,
+  path::{Path, PathBuf},
+};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::app::App;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct LastState {
+  /// Context picked in KDash; `None` when the kubeconfig's current context
+  /// was in use, so an external `kubectl config use-context` still applies.
+  pub context: Option<String>,
+  pub namespace: Option<String>,
+  /// Tab name as accepted by [`App::select_tab_by_name`].
+  pub tab: Option<String>,
+}
+
+impl LastState {
+  pub fn capture(app: &App) -> Self {
+    LastState {
+      context: app.data.selected.context.clone(),
+      namespace: app.data.selected.ns.clone(),
+      tab: Some(app.active_tab_name()),
+    }
+  }
+
+  /// Apply the saved state to a freshly created app. With `scope` false (a
+  /// context was passed on the command line) only the tab is restored. A
+  /// saved context that is no longer in the kubeconfig is dropped together
+  /// with its namespace, leaving the defaults.
+  pub fn restore(self, app: &mut App, scope: bool, context_exists: impl Fn(&str) -> bool) {
+    if scope {
+      match self.context {
+        Some(context) if !context_exists(&context) => {
+          info!(
+            "Saved context {} no longer exists, using the default",
+            context
+          );
+        }
+        context => {
+          app.data.selected.context = context;
+          app.data.selected.ns = self.namespace;
+        }
+      }
+    }
+    if let Some(tab) = self.tab {
+      app.select_tab_by_name(&tab);
+    }
+  }
+}
+
+fn state_path_from(state_dir: Option<PathBuf>) -> Option<PathBuf> {
+  state_dir.map(|dir| dir.join("kdash").join("last.json"))
+}
+
+/// `$XDG_STATE_HOME`, falling back to the local data dir on platforms
+/// without a state dir (macOS, Windows).
+fn state_dir() -> Option<PathBuf> {
+  dirs::state_dir().or_else(dirs::data_local_dir)
+}
+
+pub fn state_path() -> Option<PathBuf> {
+  state_path_from(state_dir())
+}
+
+/// Where `--debug` logs go unless `--log-dir` says otherwise.
+pub fn log_dir() -> Option<PathBuf> {
+  state_dir().map(|dir| dir.join("kdash").join("logs"))
+}
+
+/// `name` in the same directory as `last.json`.
+pub fn state_file(name: &str) -> Option<PathBuf> {
+  state_dir().map(|dir| dir.join("kdash").join(name))
+}
+
+/// A missing or unreadable file just means nothing is restored.
+pub fn load_from_path(path: &Path) -> Option<LastState> {
+  let contents = fs::read_to_string(path).ok()?;
+  match serde_json::from_str(&contents) {
+    Ok(state) => Some(state),
+    Err(e) => {
+      warn!("Ignoring saved state {}: {}", path.display(), e);
+      None
+    }
+  }
+}
+
+pub fn save_to_path(state: &LastState, path: &Path) -> io::Result<()> {
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir)?;
+  }
+  let json = serde_json::to_string_pretty(state).map_err(io::Error::other)?;
+  fs::write(path, json)
+}
+
+pub fn load() -> Option<LastState> {
+  load_from_path(&state_path()?)
+}
+
+pub fn save(state: &LastState) {
+  let Some(path) = state_path() else {
+    return;
+  };
+  if let Err(e) = save_to_path(state, &path) {
+    warn!("Failed to save state to {}: {}", path.display(), e);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::{
+    env,
+    time::{SystemTime, UNIX_EPOCH},
+  };
+
+  fn temp_state_path(name: &str) -> PathBuf {
+    let suffix = SystemTime::now()
+      .duration_since(UNIX_EPOCH)
+      .expect("system time should be after epoch")
+      .as_nanos();
+    env::temp_dir()
+      .join(format!(
+        "kdash-state-tests-{}-{}-{}",
+        name,
+        std::process::id(),
+        suffix
+      ))
+      .join("kdash")
+      .join("last.json")
+  }
+
+  #[test]
+  fn test_state_path_from_uses_kdash_dir() {
+    assert_eq!(
+      state_path_from(Some(PathBuf::from("/tmp/state-home"))),
+      Some(PathBuf::from("/tmp/state-home/kdash/last.json"))
+    );
+    assert_eq!(state_path_from(None), None);
+  }
+
+  #[test]
+  fn test_save_and_load_round_trip() {
+    let path = temp_state_path("round-trip");
+    let state = LastState {
+      context: Some("prod".into()),
+      namespace: Some("team-a".into()),
+      tab: Some("Deployments".into()),
+    };
+
+    save_to_path(&state, &path).expect("state should be written");
+
+    assert_eq!(load_from_path(&path), Some(state));
+    let _ = fs::remove_dir_all(path.parent().unwrap().parent().unwrap());
+  }
+
+  #[test]
+  fn test_load_from_path_ignores_missing_and_invalid_files() {
+    let path = temp_state_path("invalid");
+    assert_eq!(load_from_path(&path), None);
+
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "not json").unwrap();
+    assert_eq!(load_from_path(&path), None);
+    let _ = fs::remove_dir_all(path.parent().unwrap().parent().unwrap());
+  }
+
+  #[test]
+  fn test_capture_and_restore_context_namespace_and_tab() {
+    let mut app = App::default();
+    app.data.selected.context = Some("prod".into());
+    app.data.selected.ns = Some("team-a".into());
+    app.select_tab_by_name("deployments");
+    let state = LastState::capture(&app);
+    assert_eq!(state.tab.as_deref(), Some("Deployments"));
+
+    let mut restored = App::default();
+    state.restore(&mut restored, true, |ctx| ctx == "prod");
+
+    assert_eq!(restored.data.selected.context.as_deref(), Some("prod"));
+    assert_eq!(restored.data.selected.ns.as_deref(), Some("team-a"));
+    assert_eq!(restored.active_tab_name(), "Deployments");
+  }
+
+  #[test]
+  fn test_restore_falls_back_when_context_is_gone() {
+    let state = LastState {
+      context: Some("deleted".into()),
+      namespace: Some("team-a".into()),
+      tab: Some("Utilization".into()),
+    };
+    let mut app = App::default();
+
+    state.restore(&mut app, true, |_| false);
+
+    assert_eq!(app.data.selected.context, None);
+    assert_eq!(app.data.selected.ns, None);
+    assert_eq!(app.active_tab_name(), "Utilization");
+  }
+
+  #[test]
+  fn test_restore_keeps_tab_only_without_scope() {
+    let state = LastState {
+      context: Some("prod".into()),
+      namespace: Some("team-a".into()),
+      tab: Some("no-such-tab".into()),
+    };
+    let mut app = App::default();
+
+    state.restore(&mut app, false, |_| true);
+
+    assert_eq!(app.data.selected.context, None);
+    assert_eq!(app.data.selected.ns, None);
+    assert_eq!(app.active_tab_name(), "Pods");
+  }
+}
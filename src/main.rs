@@ -6,36 +6,65 @@ mod cmd;
 mod config;
 mod event;
 mod handlers;
+mod headless;
+mod last_state;
 mod network;
 mod ui;
+mod wait;
 
 use std::{
+  ffi::OsString,
   fs::File,
-  io::{self, stdout, Stdout},
+  io::{self, stdout, Stdout, Write},
   panic::{self, PanicHookInfo},
-  sync::Arc,
+  path::{Path, PathBuf},
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+  time::{Duration, Instant, SystemTime},
 };
 
 use anyhow::{anyhow, Result};
-use app::{key_binding::initialize_keybindings, App, DEFAULT_LOG_TAIL_LINES};
-use banner::BANNER;
-use chrono::{self};
-use clap::{builder::PossibleValuesParser, Parser};
+use app::{
+  columns::column_config_warnings,
+  confirm::confirm_warnings,
+  footer::FooterVerbosity,
+  key_binding::initialize_keybindings,
+  macros::macro_warnings,
+  poll_rates::poll_rate_warnings,
+  prewarm::prewarm_warnings,
+  utils::{self, initialize_time_format, initialize_time_zone, DisplayZone},
+  view_slug, watchlist, App, DEFAULT_LOG_TAIL_LINES,
+};
+use banner::{
+  server_line, splash_header, ServerStatus, BANNER, SPLASH_DURATION, SPLASH_PROBE_TIMEOUT,
+};
+use clap::{builder::PossibleValuesParser, CommandFactory, FromArgMatches, Parser, Subcommand};
 use cmd::{
-  edit::{prepare_edit, run_edit, EditTarget},
+  binaries::initialize_binaries,
+  edit::{prepare_edit, resolve_editor, run_edit, EditOutcome, EditTarget},
   shell::{prepare_shell_exec, run_shell_exec, ShellExecTarget},
   CmdRunner, IoCmdEvent,
 };
-use config::load_config;
+use config::{config_path, load_config, read_config, KdashConfig};
 use crossterm::{
-  event::{KeyEvent, MouseEvent},
+  event::{
+    DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture, KeyEvent,
+    MouseEvent,
+  },
   execute,
   terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use event::Key;
-use log::{info, warn, LevelFilter, SetLoggerError};
+use futures::future::join_all;
+use last_state::LastState;
+use log::{info, warn, LevelFilter};
 use network::{
   get_client,
+  impersonation::{check_impersonation, impersonation, initialize_impersonation, Impersonation},
+  insecure_tls::{initialize_insecure_tls, insecure_tls},
+  kubeconfig_current_context, kubeconfig_has_context,
   stream::{IoStreamEvent, NetworkStream},
   IoEvent, Network,
 };
@@ -43,9 +72,27 @@ use ratatui::{
   backend::{Backend, CrosstermBackend},
   Terminal,
 };
-use simplelog::{Config, WriteLogger};
+use simplelog::{Config, ConfigBuilder, WriteLogger};
 use tokio::sync::{mpsc, Mutex};
-use ui::theme::initialize_theme;
+use ui::{
+  glyphs::{initialize_glyphs, GlyphSet},
+  theme::initialize_theme,
+};
+
+/// How long to wait on exit for the network, stream and cmd tasks to finish.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+/// Longest the UI goes without a redraw while nothing it tracks changes, so
+/// changes it does not track still show up.
+const IDLE_REDRAW_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Whether the terminal captures the mouse; read again when the TUI is
+/// restored after a shell or editor.
+static MOUSE_CAPTURE: AtomicBool = AtomicBool::new(false);
+
+/// Whether the UI draws on the alternate screen; off with `--no-alt-screen`.
+/// Only for the panic hook, which has no other way to know; the rest of the
+/// UI is handed the flag.
+static ALT_SCREEN: AtomicBool = AtomicBool::new(true);
 
 /// kdash CLI
 #[derive(Parser, Debug)]
@@ -58,10 +105,19 @@ pub struct Cli {
   /// the lower the number the higher the network calls.
   #[arg(short, long, value_parser, default_value_t = 5000)]
   pub poll_rate: u64,
-  /// whether unicode symbols are used to improve the overall look of the app
-  #[arg(short, long, value_parser, default_value_t = true)]
+  /// whether unicode symbols and syntax colours are used to improve the overall look of the app.
+  /// Pass `--enhanced-graphics=false` for plain rendering.
+  #[arg(
+    short,
+    long,
+    default_value_t = true,
+    default_missing_value = "true",
+    require_equals = true,
+    num_args = 0..=1,
+    action = clap::ArgAction::Set
+  )]
   pub enhanced_graphics: bool,
-  /// Enables debug mode and writes logs to 'kdash-debug-<timestamp>.log' file in the current directory.
+  /// Enables debug mode and writes logs to 'kdash-debug-<timestamp>.log' file in the log directory.
   /// Default behavior is to write INFO logs. Pass a log level to overwrite the default.
   #[arg(
     name = "debug",
@@ -74,15 +130,153 @@ pub struct Cli {
     value_parser = PossibleValuesParser::new(&["info", "debug", "trace", "warn", "error"])
   )]
   pub debug: Option<String>,
+  /// Where `--debug` writes its log, created if missing. Defaults to
+  /// `kdash/logs` under the OS state dir; the temp dir is used when the
+  /// directory can't be written to.
+  #[arg(long, value_name = "DIR")]
+  pub log_dir: Option<PathBuf>,
   /// Set how many historical log lines to fetch before live streaming starts.
   #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
   pub log_tail_lines: Option<u32>,
   /// Pre-select a namespace on startup (same as pressing `n` and picking the namespace).
-  #[arg(short = 'n', long, value_parser)]
+  #[arg(short = 'n', long, value_parser, global = true)]
   pub namespace: Option<String>,
   /// Pre-select a kubeconfig context on startup (same as picking it from the Contexts view).
-  #[arg(short = 'c', long, value_parser)]
+  #[arg(short = 'c', long, value_parser, global = true)]
   pub context: Option<String>,
+  /// Run this kubectl binary instead of `kubectl` from PATH.
+  #[arg(long, value_parser)]
+  pub kubectl_path: Option<String>,
+  /// Run this helm binary instead of `helm` from PATH.
+  #[arg(long, value_parser)]
+  pub helm_path: Option<String>,
+  /// Open this tab on startup, e.g. `pods`, `deployments` or `utilization`.
+  #[arg(long, value_parser = parse_view)]
+  pub view: Option<String>,
+  /// Start on the default context and first tab instead of restoring the
+  /// context, namespace and tab from the last session, and don't save them on exit.
+  #[arg(long)]
+  pub no_restore: bool,
+  /// Ring the terminal bell and show a warning when a pod starts crash-looping
+  /// or fails.
+  #[arg(long)]
+  pub alerts: bool,
+  /// Show the banner with the context and whether its API server answers for
+  /// a second before the UI starts.
+  #[arg(long)]
+  pub splash: bool,
+  /// Leave the banner off `--help` and never show the startup splash, even
+  /// when the config file asks for it.
+  #[arg(long)]
+  pub no_banner: bool,
+  /// Make URLs in logs, events and describe output clickable with OSC 8
+  /// hyperlinks. Only for terminals that support them.
+  #[arg(long)]
+  pub hyperlinks: bool,
+  /// Act as this user for every API call and kubectl command, like
+  /// `kubectl --as`. Shown in the footer while set.
+  #[arg(long = "as", value_name = "USER")]
+  pub as_user: Option<String>,
+  /// Act as a member of this group as well (repeatable, needs `--as`), like
+  /// `kubectl --as-group`.
+  #[arg(long = "as-group", value_name = "GROUP", requires = "as_user")]
+  pub as_group: Vec<String>,
+  /// Don't verify the API server's TLS certificate, for dev clusters with
+  /// self-signed certificates. Insecure: shown in the footer while set, and
+  /// never saved, so it has to be passed on every run.
+  #[arg(long)]
+  pub insecure_skip_tls_verify: bool,
+  /// Refuse every change to the cluster (delete, scale, restart, edit and the
+  /// like), to browse shared or production clusters safely.
+  #[arg(long)]
+  pub read_only: bool,
+  /// Fetch every view in the background from the start, rather than each
+  /// view once it is opened and those under `prewarm` in the config file.
+  #[arg(long)]
+  pub eager_load: bool,
+  /// Keep the footer to the context, namespace and errors, without
+  /// informational toasts. `Alt+v` cycles it at runtime.
+  #[arg(long)]
+  pub quiet: bool,
+  /// strftime-style format for absolute times, such as creation timestamps
+  /// and the error log, e.g. `%d/%m/%Y %H:%M`. Ages are not affected.
+  #[arg(long, value_name = "FORMAT")]
+  pub time_format: Option<String>,
+  /// Time zone for absolute times and the debug log: `UTC`, `local` (the
+  /// default) or an IANA name such as `Europe/Berlin`.
+  #[arg(long, value_name = "ZONE")]
+  pub timezone: Option<DisplayZone>,
+  /// Glyphs for borders, spinners and indicators: full Unicode, Nerd Font
+  /// icons, or ASCII only for terminals and fonts that show the others as
+  /// boxes. Defaults to `glyphs` in the config file, else unicode.
+  #[arg(long, value_enum, value_name = "SET")]
+  pub glyphs: Option<GlyphSet>,
+  /// Print the `--view` list (pods by default) once as JSON or YAML and exit
+  /// without starting the UI, for scripts and pipelines.
+  #[arg(short = 'o', long, value_enum)]
+  pub output: Option<headless::OutputFormat>,
+  /// Don't watch the config file; edits are then only picked up on restart.
+  #[arg(long)]
+  pub no_watch: bool,
+  /// Lay this profile from `profiles` in the config file over the rest of
+  /// it, e.g. `prod` for a read-only theme of its own. Flags still win.
+  #[arg(long, value_name = "NAME")]
+  pub profile: Option<String>,
+  /// Draw on the main screen instead of the alternate one, so the last frame
+  /// and anything printed stay in the terminal's scrollback on exit. Meant
+  /// for debugging KDash and CI smoke tests.
+  #[arg(long)]
+  pub no_alt_screen: bool,
+  #[command(subcommand)]
+  pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+  /// Block until an object is ready, available or deleted, printing its
+  /// status as it changes, like `kubectl wait`. Exits non-zero on timeout.
+  Wait(wait::WaitArgs),
+}
+
+/// The CLI definition, without the banner above `--help` when `--no-banner`
+/// is among the arguments.
+fn cli_command(args: &[OsString]) -> clap::Command {
+  let command = Cli::command();
+  if args.iter().any(|arg| arg == "--no-banner") {
+    command.before_help(None::<&str>)
+  } else {
+    command
+  }
+}
+
+fn parse_cli(args: Vec<OsString>) -> Cli {
+  let matches = cli_command(&args).get_matches_from(args);
+  Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit())
+}
+
+/// `--no-banner` wins over both `--splash` and the config file.
+fn splash_enabled(cli: &Cli, config: &KdashConfig) -> bool {
+  !cli.no_banner && (cli.splash || config.splash)
+}
+
+/// Print the banner with the context and whether its API server answers, and
+/// keep it up for at least [`SPLASH_DURATION`] before the UI takes the screen.
+async fn show_splash(context: Option<String>) {
+  let started = Instant::now();
+  let name = context.clone().or_else(kubeconfig_current_context);
+  print!("{}", splash_header(name.as_deref()));
+  let _ = stdout().flush();
+  let probe = async {
+    let info = get_client(context).await?.apiserver_version().await?;
+    anyhow::Ok(info.git_version)
+  };
+  let status = match tokio::time::timeout(SPLASH_PROBE_TIMEOUT, probe).await {
+    Ok(Ok(version)) => ServerStatus::Reachable { version },
+    Ok(Err(e)) => ServerStatus::Unreachable(e.root_cause().to_string()),
+    Err(_) => ServerStatus::TimedOut,
+  };
+  println!("{}", server_line(&status));
+  tokio::time::sleep(SPLASH_DURATION.saturating_sub(started.elapsed())).await;
 }
 
 #[tokio::main]
@@ -94,22 +288,31 @@ async fn main() -> Result<()> {
   }));
 
   // parse CLI arguments
-  let cli = Cli::parse();
+  let cli = parse_cli(std::env::args_os().collect());
+  ALT_SCREEN.store(!cli.no_alt_screen, Ordering::Relaxed);
+  initialize_time_zone(cli.timezone);
 
   // Setup logging if debug flag is set
+  let mut log_warning = None;
   if cli.debug.is_some() {
-    setup_logging(cli.debug.clone())?;
+    let (log_file, warning) = setup_logging(
+      cli.debug.clone(),
+      cli.timezone.is_some(),
+      cli.log_dir.clone().or_else(last_state::log_dir),
+    )?;
     info!(
-      "Debug mode is enabled. Level: {}, KDash version: {}",
+      "Debug mode is enabled. Level: {}, KDash version: {}, log file: {}",
       cli.debug.clone().unwrap(),
-      env!("CARGO_PKG_VERSION")
+      env!("CARGO_PKG_VERSION"),
+      log_file.display()
     );
+    log_warning = warning;
   }
 
   if cli.tick_rate >= 1000 {
     panic!("Tick rate must be below 1000");
   }
-  if (cli.poll_rate % cli.tick_rate) > 0u64 {
+  if !cli.poll_rate.is_multiple_of(cli.tick_rate) {
     panic!("Poll rate must be multiple of tick-rate");
   }
 
@@ -117,14 +320,53 @@ async fn main() -> Result<()> {
   let (sync_io_tx, sync_io_rx) = mpsc::channel::<IoEvent>(500);
   let (sync_io_stream_tx, sync_io_stream_rx) = mpsc::channel::<IoStreamEvent>(500);
   let (sync_io_cmd_tx, sync_io_cmd_rx) = mpsc::channel::<IoCmdEvent>(500);
-  let loaded_config = load_config();
+  let loaded_config = load_config(cli.profile.as_deref());
+  // the settings a profile was asked for can't be left out quietly
+  if let (Some(_), Some(warning)) = (&cli.profile, &loaded_config.warning) {
+    return Err(anyhow!("{}", warning.trim_end_matches(" Using defaults.")));
+  }
   let log_tail_lines = resolve_log_tail_lines(cli.log_tail_lines, &loaded_config.config);
+  let splash = splash_enabled(&cli, &loaded_config.config);
   let mut config_warnings = vec![];
   if let Some(warning) = loaded_config.warning.clone() {
     config_warnings.push(warning);
   }
+  config_warnings.extend(log_warning);
   config_warnings.extend(initialize_keybindings(&loaded_config.config));
   config_warnings.extend(initialize_theme(&loaded_config.config));
+  config_warnings.extend(column_config_warnings(&loaded_config.config));
+  config_warnings.extend(poll_rate_warnings(&loaded_config.config));
+  config_warnings.extend(prewarm_warnings(&loaded_config.config));
+  config_warnings.extend(confirm_warnings(&loaded_config.config));
+  config_warnings.extend(macro_warnings(&loaded_config.config));
+  config_warnings.extend(initialize_time_format(
+    cli.time_format.clone(),
+    &loaded_config.config,
+  ));
+  config_warnings.extend(initialize_glyphs(cli.glyphs, &loaded_config.config));
+  config_warnings.extend(initialize_binaries(
+    cli.kubectl_path.clone(),
+    cli.helm_path.clone(),
+    &loaded_config.config,
+  ));
+  initialize_impersonation(Impersonation {
+    user: cli.as_user.clone(),
+    groups: cli.as_group.clone(),
+  });
+  initialize_insecure_tls(cli.insecure_skip_tls_verify);
+  if insecure_tls() {
+    config_warnings.push(
+      "TLS verification is off (--insecure-skip-tls-verify): the API server certificate is not checked"
+        .into(),
+    );
+  }
+
+  if let Some(Command::Wait(args)) = cli.command {
+    for warning in &config_warnings {
+      eprintln!("kdash: {}", warning);
+    }
+    return wait::run(args, cli.context, cli.namespace).await;
+  }
 
   // Initialize app state
   let app = Arc::new(Mutex::new(App::new(
@@ -141,12 +383,47 @@ async fn main() -> Result<()> {
     let mut app = app.lock().await;
     // Seed startup scoping from CLI flags so the first client connection and the
     // UI reflect the requested namespace/context (#545).
+    // a one-shot output only follows its own flags
+    if !cli.no_restore && cli.output.is_none() {
+      if let Some(state) = last_state::load() {
+        state.restore(&mut app, cli.context.is_none(), kubeconfig_has_context);
+      }
+    }
     seed_startup_selection(&mut app, cli.namespace.clone(), cli.context.clone());
+    app.watchlist_path = watchlist::watchlist_path();
+    if let Some(path) = &app.watchlist_path {
+      app.watchlist = watchlist::load_from_path(path);
+    }
+    if let Some(view) = &cli.view {
+      app.select_tab_by_name(view);
+    }
+    if cli.alerts {
+      app.enable_pod_alerts();
+    }
+    if cli.hyperlinks {
+      app.hyperlinks = true;
+    }
+    app.data.impersonating = impersonation().label();
+    app.data.insecure_tls = insecure_tls();
+    app.read_only = cli.read_only || app.config.read_only;
+    app.redact = app.config.redact;
+    app.profile = cli.profile.clone();
+    app.eager_load = cli.eager_load;
+    if cli.quiet {
+      app.footer_verbosity = FooterVerbosity::Quiet;
+    }
     if app.config.keybindings.is_some() || app.config.theme.is_some() {
       info!("Loaded config overrides from file");
     }
   }
 
+  if let Some(format) = cli.output {
+    for warning in &config_warnings {
+      eprintln!("kdash: {}", warning);
+    }
+    return headless::run(&app, format).await;
+  }
+
   if !config_warnings.is_empty() {
     let mut app = app.lock().await;
     app.handle_error(anyhow!(config_warnings.join(" | ")));
@@ -158,7 +435,11 @@ async fn main() -> Result<()> {
   let app_nw = Arc::clone(&app);
   let app_stream = Arc::clone(&app);
   let app_cli = Arc::clone(&app);
-  let network_context = cli.context.clone();
+  let network_context = app.lock().await.data.selected.context.clone();
+  if splash {
+    show_splash(network_context.clone()).await;
+  }
+  let (network_done_tx, network_done_rx) = std::sync::mpsc::channel::<()>();
 
   std::thread::spawn(move || {
     let rt = tokio::runtime::Builder::new_multi_thread()
@@ -169,44 +450,70 @@ async fn main() -> Result<()> {
 
     rt.block_on(async move {
       let stream_context = network_context.clone();
-      tokio::spawn(async move {
-        info!("Starting network task");
-        start_network(sync_io_rx, &app_nw, network_context).await;
-      });
-
-      tokio::spawn(async move {
-        info!("Starting network stream task");
-        start_stream_network(sync_io_stream_rx, &app_stream, stream_context).await;
-      });
-
-      tokio::spawn(async move {
-        info!("Starting cmd runner task");
-        start_cmd_runner(sync_io_cmd_rx, &app_cli).await;
-      });
+      let tasks = [
+        tokio::spawn(async move {
+          info!("Starting network task");
+          start_network(sync_io_rx, &app_nw, network_context).await;
+        }),
+        tokio::spawn(async move {
+          info!("Starting network stream task");
+          start_stream_network(sync_io_stream_rx, &app_stream, stream_context).await;
+        }),
+        tokio::spawn(async move {
+          info!("Starting cmd runner task");
+          start_cmd_runner(sync_io_cmd_rx, &app_cli).await;
+        }),
+      ];
 
       // Keep this runtime alive until all tasks complete.
-      // When the UI exits and drops the channel senders, recv() returns None
+      // On shutdown the UI drops the channel senders, recv() returns None
       // and the tasks finish naturally.
-      tokio::signal::ctrl_c()
-        .await
-        .expect("Failed to listen for ctrl_c");
+      join_all(tasks).await;
     });
+    let _ = network_done_tx.send(());
   });
 
   // Launch the UI on the main runtime — it owns the terminal and must run here
-  start_ui(cli, &app).await?;
+  start_ui(cli, &app, network_done_rx).await?;
 
   Ok(())
 }
 
+/// Resolves on SIGTERM or SIGINT. In raw mode Ctrl-C arrives as a key
+/// press, so this only fires for signals sent from outside, e.g. by a window
+/// manager closing the terminal.
+async fn wait_for_termination() {
+  #[cfg(unix)]
+  {
+    use tokio::signal::unix::{signal, SignalKind};
+    match signal(SignalKind::terminate()) {
+      Ok(mut sigterm) => {
+        tokio::select! {
+          _ = sigterm.recv() => info!("Received SIGTERM"),
+          _ = tokio::signal::ctrl_c() => info!("Received SIGINT"),
+        }
+        return;
+      }
+      Err(e) => warn!("Unable to listen for SIGTERM: {}", e),
+    }
+  }
+  if tokio::signal::ctrl_c().await.is_ok() {
+    info!("Received SIGINT");
+  }
+}
+
 async fn start_network(
   mut io_rx: mpsc::Receiver<IoEvent>,
   app: &Arc<Mutex<App>>,
   context: Option<String>,
 ) {
-  match get_client(context).await {
+  match get_client(context.clone()).await {
     Ok(client) => {
+      if let Err(e) = check_impersonation(&client).await {
+        app.lock().await.handle_error(e);
+      }
       let mut network = Network::new(client, app);
+      network.context = context;
 
       while let Some(io_event) = io_rx.recv().await {
         info!("Network event received: {:?}", io_event);
@@ -229,9 +536,24 @@ fn resolve_log_tail_lines(cli_value: Option<u32>, config: &config::KdashConfig)
 /// Seed the selected namespace/context from CLI flags. Mirrors the in-TUI
 /// handlers so `--namespace`/`--context` land in the same `data.selected` fields
 /// the `n` and Contexts keybindings mutate.
+fn parse_view(view: &str) -> Result<String, String> {
+  let views = App::default().view_names();
+  let slug = view_slug(view);
+  if views.contains(&slug) {
+    Ok(slug)
+  } else {
+    Err(format!("unknown view. Valid views: {}", views.join(", ")))
+  }
+}
+
+/// CLI flags win over the restored session.
 fn seed_startup_selection(app: &mut App, namespace: Option<String>, context: Option<String>) {
-  app.data.selected.ns = namespace;
-  app.data.selected.context = context;
+  if namespace.is_some() {
+    app.data.selected.ns = namespace;
+  }
+  if context.is_some() {
+    app.data.selected.context = context;
+  }
 }
 
 async fn start_stream_network(
@@ -264,6 +586,31 @@ async fn start_cmd_runner(mut io_rx: mpsc::Receiver<IoCmdEvent>, app: &Arc<Mutex
   }
 }
 
+/// Re-read the config file and apply its keybindings, theme and the rest.
+/// A file that no longer parses leaves the running config as it was.
+fn reload_config(app: &mut App, path: &Path) {
+  let config = match read_config(path, app.profile.as_deref()) {
+    Ok(config) => config,
+    Err(error) => {
+      app.handle_error(anyhow!("{}. Keeping the previous config.", error));
+      return;
+    }
+  };
+  let mut warnings = initialize_keybindings(&config);
+  warnings.extend(initialize_theme(&config));
+  warnings.extend(column_config_warnings(&config));
+  warnings.extend(poll_rate_warnings(&config));
+  warnings.extend(prewarm_warnings(&config));
+  warnings.extend(confirm_warnings(&config));
+  warnings.extend(macro_warnings(&config));
+  app.apply_config(config);
+  if warnings.is_empty() {
+    app.set_status_message("Config reloaded");
+  } else {
+    app.handle_error(anyhow!("Config reloaded. {}", warnings.join(" | ")));
+  }
+}
+
 /// Process a single UI event.  Returns `true` when the app should exit (Ctrl+C).
 async fn process_event(
   app: &mut App,
@@ -278,14 +625,17 @@ async fn process_event(
         true
       } else {
         handlers::handle_key_events(key, key_event, app).await;
+        app.mark_dirty();
         false
       }
     }
     event::Event::MouseInput(mouse) => {
       handlers::handle_mouse_events(mouse, app).await;
+      app.mark_dirty();
       false
     }
     event::Event::Tick => {
+      app.note_tick_time(SystemTime::now());
       app.on_tick(*is_first_render).await;
       *is_first_render = false;
       *tick_seen = true;
@@ -296,26 +646,56 @@ async fn process_event(
       app.dispatch(IoEvent::GetKubeConfig).await;
       false
     }
+    event::Event::ConfigChange => {
+      if let Some(path) = config_path() {
+        info!("Config change detected, reloading");
+        reload_config(app, &path);
+      }
+      false
+    }
+    event::Event::Paste(text) => {
+      handlers::handle_paste(&text, app);
+      app.mark_dirty();
+      false
+    }
   }
 }
 
-async fn start_ui(cli: Cli, app: &Arc<Mutex<App>>) -> Result<()> {
+async fn start_ui(
+  cli: Cli,
+  app: &Arc<Mutex<App>>,
+  network_done: std::sync::mpsc::Receiver<()>,
+) -> Result<()> {
   info!("Starting UI");
+  // A termination signal quits like `q` does, so the cleanup below still runs.
+  let signal_app = Arc::clone(app);
+  tokio::spawn(async move {
+    wait_for_termination().await;
+    signal_app.lock().await.should_quit = true;
+  });
   // see https://docs.rs/crossterm/0.17.7/crossterm/terminal/#raw-mode
   enable_raw_mode()?;
   // Terminal initialization
   let mut stdout = stdout();
-  // not capturing mouse to make text select/copy possible
-  execute!(stdout, EnterAlternateScreen)?;
+  // mouse capture is opt-in, as it stops the terminal's own text select/copy
+  MOUSE_CAPTURE.store(app.lock().await.config.mouse_capture, Ordering::Relaxed);
+  // pastes arrive as one event, so they can't fire keybindings
+  let alt_screen = !cli.no_alt_screen;
+  enter_alt_screen(&mut stdout, alt_screen)?;
+  execute!(stdout, EnableBracketedPaste)?;
+  set_mouse_capture(&mut stdout)?;
   // terminal backend for cross platform support
   let backend = CrosstermBackend::new(stdout);
   let mut terminal = Terminal::new(backend)?;
   terminal.clear()?;
   terminal.hide_cursor()?;
   // custom events
-  let mut events = event::Events::new(cli.tick_rate);
+  let watched_config = config_path().filter(|_| !cli.no_watch);
+  let mut events = event::Events::new(cli.tick_rate, watched_config.clone());
   let mut is_first_render = true;
   // Perform initial draw so the user sees the UI immediately
+  let mut drawn_revision;
+  let mut drawn_at;
   {
     let mut app = app.lock().await;
     if let Ok(size) = terminal.backend().size() {
@@ -323,6 +703,8 @@ async fn start_ui(cli: Cli, app: &Arc<Mutex<App>>) -> Result<()> {
       app.size.height = size.height;
     }
     terminal.draw(|f| ui::draw(f, &mut app))?;
+    drawn_revision = app.revision();
+    drawn_at = Instant::now();
   }
   // main UI loop
   loop {
@@ -367,15 +749,34 @@ async fn start_ui(cli: Cli, app: &Arc<Mutex<App>>) -> Result<()> {
       }
 
       // Get the size of the screen on each loop to account for resize events
+      let mut resized = false;
       if let Ok(size) = terminal.backend().size() {
         if app.refresh || app.size.as_size() != size {
+          resized = app.size.as_size() != size;
           app.size.width = size.width;
           app.size.height = size.height;
         }
       }
 
-      // Draw the UI layout AFTER processing events so the frame is up-to-date
-      terminal.draw(|f| ui::draw(f, &mut app))?;
+      // Draw the UI layout AFTER processing events so the frame is up-to-date.
+      // An idle tick that changed nothing would draw the same frame again, so
+      // it is skipped, bar a periodic redraw for anything not tracked.
+      if resized || app.needs_redraw(drawn_revision) || drawn_at.elapsed() >= IDLE_REDRAW_INTERVAL {
+        terminal.draw(|f| ui::draw(f, &mut app))?;
+        drawn_revision = app.revision();
+        drawn_at = Instant::now();
+      }
+      if app
+        .pod_alerts
+        .as_mut()
+        .is_some_and(|alerts| alerts.take_bell())
+      {
+        let backend = terminal.backend_mut();
+        backend.write_all(b"\x07")?;
+        Write::flush(backend)?;
+      }
+
+      app.write_pending_export(&std::env::current_dir().unwrap_or_default());
 
       let pending_terminal_action = app.take_pending_terminal_action();
       let should_quit = app.should_quit;
@@ -384,8 +785,12 @@ async fn start_ui(cli: Cli, app: &Arc<Mutex<App>>) -> Result<()> {
 
     if let Some(action) = pending_terminal_action {
       drop(events);
-      execute_pending_terminal_action(app, &mut terminal, action).await?;
-      events = event::Events::new(cli.tick_rate);
+      execute_pending_terminal_action(app, &mut terminal, action, alt_screen).await?;
+      events = event::Events::new(cli.tick_rate, watched_config.clone());
+      // the shell or editor drew over the UI, and held the ticks back
+      let mut app = app.lock().await;
+      app.mark_dirty();
+      app.reset_tick_time();
     }
 
     if should_quit {
@@ -393,36 +798,90 @@ async fn start_ui(cli: Cli, app: &Arc<Mutex<App>>) -> Result<()> {
     }
   }
 
-  // Tear down any active port-forwards so kubectl children don't outlive the UI.
+  // Stop the log stream and port-forwards so kubectl children don't outlive
+  // the UI, and let the background tasks finish what they are doing.
   {
     let mut app = app.lock().await;
-    app.kill_all_port_forwards();
+    if !cli.no_restore {
+      last_state::save(&LastState::capture(&app));
+    }
+    app.begin_shutdown();
+  }
+  if network_done.recv_timeout(SHUTDOWN_TIMEOUT).is_err() {
+    warn!(
+      "Background tasks did not stop within {:?}",
+      SHUTDOWN_TIMEOUT
+    );
   }
 
   terminal.show_cursor()?;
-  shutdown(terminal)?;
+  shutdown(terminal, alt_screen)?;
   Ok(())
 }
 
 // shutdown the CLI and show terminal
-fn shutdown(mut terminal: Terminal<CrosstermBackend<Stdout>>) -> Result<()> {
+fn shutdown(mut terminal: Terminal<CrosstermBackend<Stdout>>, alt_screen: bool) -> Result<()> {
   info!("Shutting down");
+  log::logger().flush();
   disable_raw_mode()?;
-  execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+  execute!(
+    terminal.backend_mut(),
+    DisableMouseCapture,
+    DisableBracketedPaste
+  )?;
+  leave_alt_screen(terminal.backend_mut(), alt_screen)?;
   terminal.show_cursor()?;
   Ok(())
 }
 
-fn suspend_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Result<()> {
+/// Switch to the alternate screen, unless `--no-alt-screen` turned it off.
+fn enter_alt_screen(out: &mut impl io::Write, enabled: bool) -> io::Result<()> {
+  if enabled {
+    execute!(out, EnterAlternateScreen)?;
+  }
+  Ok(())
+}
+
+/// Back to the main screen, if the alternate one was entered.
+fn leave_alt_screen(out: &mut impl io::Write, enabled: bool) -> io::Result<()> {
+  if enabled {
+    execute!(out, LeaveAlternateScreen)?;
+  }
+  Ok(())
+}
+
+/// Capture mouse events when `mouse_capture` is set in the config, so tabs
+/// can be clicked.
+fn set_mouse_capture(out: &mut impl io::Write) -> Result<()> {
+  if MOUSE_CAPTURE.load(Ordering::Relaxed) {
+    execute!(out, EnableMouseCapture)?;
+  }
+  Ok(())
+}
+
+fn suspend_terminal(
+  terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+  alt_screen: bool,
+) -> Result<()> {
   disable_raw_mode()?;
-  execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+  execute!(
+    terminal.backend_mut(),
+    DisableMouseCapture,
+    DisableBracketedPaste
+  )?;
+  leave_alt_screen(terminal.backend_mut(), alt_screen)?;
   terminal.show_cursor()?;
   Ok(())
 }
 
-fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Result<()> {
+fn restore_terminal(
+  terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+  alt_screen: bool,
+) -> Result<()> {
   enable_raw_mode()?;
-  execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+  enter_alt_screen(terminal.backend_mut(), alt_screen)?;
+  execute!(terminal.backend_mut(), EnableBracketedPaste)?;
+  set_mouse_capture(terminal.backend_mut())?;
   terminal.hide_cursor()?;
   terminal.clear()?;
   Ok(())
@@ -433,23 +892,34 @@ trait ShellTerminal {
   fn restore(&mut self) -> Result<()>;
 }
 
-impl ShellTerminal for Terminal<CrosstermBackend<Stdout>> {
+/// The UI's terminal, with whether it draws on the alternate screen.
+struct UiTerminal<'a> {
+  terminal: &'a mut Terminal<CrosstermBackend<Stdout>>,
+  alt_screen: bool,
+}
+
+impl ShellTerminal for UiTerminal<'_> {
   fn suspend(&mut self) -> Result<()> {
-    suspend_terminal(self)
+    suspend_terminal(self.terminal, self.alt_screen)
   }
 
   fn restore(&mut self) -> Result<()> {
-    restore_terminal(self)
+    restore_terminal(self.terminal, self.alt_screen)
   }
 }
 
-/// Run a queued terminal action (shell-exec or `kubectl edit`): both suspend the
+/// Run a queued terminal action (shell-exec or `$EDITOR`): both suspend the
 /// TUI, run an interactive child inheriting stdio, then restore the TUI.
 async fn execute_pending_terminal_action(
   app: &Arc<Mutex<App>>,
   terminal: &mut Terminal<CrosstermBackend<Stdout>>,
   action: app::PendingTerminalAction,
+  alt_screen: bool,
 ) -> Result<()> {
+  let terminal = &mut UiTerminal {
+    terminal,
+    alt_screen,
+  };
   match action {
     app::PendingTerminalAction::Shell(request) => {
       execute_pending_shell_exec(app, terminal, request).await
@@ -460,7 +930,7 @@ async fn execute_pending_terminal_action(
 
 async fn execute_pending_shell_exec(
   app: &Arc<Mutex<App>>,
-  terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+  terminal: &mut UiTerminal<'_>,
   request: app::PendingShellExec,
 ) -> Result<()> {
   let context = app.lock().await.data.selected.context.clone();
@@ -481,7 +951,7 @@ async fn execute_pending_shell_exec(
 
 async fn execute_pending_edit(
   app: &Arc<Mutex<App>>,
-  terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+  terminal: &mut UiTerminal<'_>,
   request: app::PendingEdit,
 ) -> Result<()> {
   let context = app.lock().await.data.selected.context.clone();
@@ -492,9 +962,9 @@ async fn execute_pending_edit(
       name: request.name,
       context,
     };
-    let command = prepare_edit(&target).map_err(|error| anyhow!(error.to_string()))?;
-    run_edit(&command).map_err(|error| anyhow!(error.to_string()))?;
-    Ok(())
+    let editor = resolve_editor(std::env::var("VISUAL").ok(), std::env::var("EDITOR").ok());
+    let command = prepare_edit(&target, editor).map_err(|error| anyhow!(error.to_string()))?;
+    run_edit(&command).map_err(|error| anyhow!(error.to_string()))
   })
   .await
 }
@@ -550,7 +1020,7 @@ async fn execute_pending_edit_with<F, T>(
   run_edit: F,
 ) -> Result<()>
 where
-  F: FnOnce(app::PendingEdit) -> Result<()>,
+  F: FnOnce(app::PendingEdit) -> Result<EditOutcome>,
   T: ShellTerminal,
 {
   terminal.suspend()?;
@@ -565,14 +1035,25 @@ where
   }
 
   match edit_result {
-    Ok(()) => {
+    Ok(EditOutcome::Unchanged) => {
+      app.set_status_message(format!("No changes to {} '{}'", request.kind, request.name));
+      Ok(())
+    }
+    Ok(EditOutcome::Changed(file)) => {
+      // Dry-run and applied off the UI thread; the runner reports the outcome
+      // and refreshes.
       app.set_status_message(format!(
-        "Finished editing {} '{}'",
+        "Applying changes to {} '{}'",
         request.kind, request.name
       ));
-      // The resource may have changed; re-poll the active view on the next tick
-      // (mirrors the post-write refresh used by the confirm-modal path).
-      app.tick_count = 0;
+      app
+        .dispatch_cmd(IoCmdEvent::ApplyEdit {
+          kind: request.kind,
+          name: request.name,
+          file,
+          skip_dry_run: false,
+        })
+        .await;
       Ok(())
     }
     Err(error) => {
@@ -587,11 +1068,39 @@ where
   }
 }
 
-fn setup_logging(debug: Option<String>) -> Result<(), SetLoggerError> {
-  let log_file = format!(
-    "./kdash-debug-{}.log",
-    chrono::Local::now().format("%Y%m%d%H%M%S")
-  );
+/// Create `name` in `dir`, or in the temp dir when `dir` is unset or can't be
+/// written to. The warning says why the temp dir was used.
+fn create_log_file(dir: Option<&Path>, name: &str) -> io::Result<(PathBuf, File, Option<String>)> {
+  let mut warning = None;
+  if let Some(dir) = dir {
+    let path = dir.join(name);
+    match std::fs::create_dir_all(dir).and_then(|_| File::create(&path)) {
+      Ok(file) => return Ok((path, file, None)),
+      Err(e) => {
+        warning = Some(format!(
+          "can't write the debug log to {}: {}",
+          dir.display(),
+          e
+        ))
+      }
+    }
+  }
+  let path = std::env::temp_dir().join(name);
+  let file = File::create(&path)?;
+  let warning = warning.map(|warning| format!("{}; writing it to {}", warning, path.display()));
+  Ok((path, file, warning))
+}
+
+/// Log lines are stamped in UTC unless `--timezone` chose a zone, whose
+/// offset at startup is then used. Returns the log file's path, and why it
+/// is in the temp dir if it is.
+fn setup_logging(
+  debug: Option<String>,
+  zoned: bool,
+  dir: Option<PathBuf>,
+) -> Result<(PathBuf, Option<String>)> {
+  let now = utils::now();
+  let name = format!("kdash-debug-{}.log", now.format("%Y%m%d%H%M%S"));
   let log_level = debug
     .map(|level| match level.to_lowercase().as_str() {
       "debug" => LevelFilter::Debug,
@@ -602,11 +1111,17 @@ fn setup_logging(debug: Option<String>) -> Result<(), SetLoggerError> {
     })
     .unwrap_or_else(|| LevelFilter::Info);
 
-  WriteLogger::init(
-    log_level,
-    Config::default(),
-    File::create(log_file).unwrap(),
-  )
+  let config = match time::UtcOffset::from_whole_seconds(now.offset().local_minus_utc()) {
+    Ok(offset) if zoned => ConfigBuilder::new().set_time_offset(offset).build(),
+    _ => Config::default(),
+  };
+  let (path, file, warning) = create_log_file(dir.as_deref(), &name)
+    .map_err(|e| anyhow!("Can't create the debug log {}: {}", name, e))?;
+  WriteLogger::init(log_level, config, file)?;
+  if let Some(warning) = &warning {
+    warn!("{}", warning);
+  }
+  Ok((path, warning))
 }
 
 #[cfg(debug_assertions)]
@@ -619,9 +1134,10 @@ fn panic_hook(info: &PanicHookInfo<'_>) {
   let stacktrace: String = format!("{:?}", Backtrace::new()).replace('\n', "\n\r");
 
   disable_raw_mode().unwrap();
+  execute!(io::stdout(), DisableMouseCapture, DisableBracketedPaste).unwrap();
+  leave_alt_screen(&mut io::stdout(), ALT_SCREEN.load(Ordering::Relaxed)).unwrap();
   execute!(
     io::stdout(),
-    LeaveAlternateScreen,
     Print(format!(
       "thread '<unnamed>' panicked at '{}', {}\n\r{}",
       msg, location, stacktrace
@@ -651,9 +1167,10 @@ fn panic_hook(info: &PanicHookInfo<'_>) {
   );
 
   disable_raw_mode().unwrap();
+  execute!(io::stdout(), DisableMouseCapture, DisableBracketedPaste).unwrap();
+  leave_alt_screen(&mut io::stdout(), ALT_SCREEN.load(Ordering::Relaxed)).unwrap();
   execute!(
     io::stdout(),
-    LeaveAlternateScreen,
     Print(format!("Error: '{}' at {}\n", msg, location)),
   )
   .unwrap();
@@ -677,14 +1194,19 @@ fn get_panic_info(info: &PanicHookInfo<'_>) -> (String, String) {
 #[cfg(test)]
 mod tests {
   use super::{
-    execute_pending_shell_exec_with, process_event, resolve_log_tail_lines, seed_startup_selection,
-    Cli,
+    cli_command, create_log_file, enter_alt_screen, execute_pending_edit_with,
+    execute_pending_shell_exec_with, leave_alt_screen, process_event, resolve_log_tail_lines,
+    seed_startup_selection, splash_enabled, Cli, Command,
+  };
+  use crate::{
+    app::{utils::DisplayZone, ActiveBlock, App},
+    config::KdashConfig,
+    event, wait,
   };
-  use crate::{app::App, config::KdashConfig, event};
   use anyhow::anyhow;
   use clap::Parser;
   use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-  use std::sync::Arc;
+  use std::{ffi::OsString, sync::Arc, time::Duration};
   use tokio::sync::Mutex;
 
   struct StubTerminal;
@@ -724,6 +1246,112 @@ mod tests {
     assert_eq!(resolve_log_tail_lines(Some(500), &config), 500);
   }
 
+  #[test]
+  fn test_cli_parses_output_format() {
+    let cli = Cli::try_parse_from(["kdash", "-o", "yaml", "--view", "deployments"]).unwrap();
+    assert_eq!(cli.output, Some(crate::headless::OutputFormat::Yaml));
+    assert_eq!(
+      Cli::try_parse_from(["kdash", "--output", "json"])
+        .unwrap()
+        .output,
+      Some(crate::headless::OutputFormat::Json)
+    );
+    assert!(Cli::try_parse_from(["kdash", "--output", "xml"]).is_err());
+    assert_eq!(Cli::try_parse_from(["kdash"]).unwrap().output, None);
+  }
+
+  #[test]
+  fn test_cli_parses_the_wait_command() {
+    let cli = Cli::try_parse_from([
+      "kdash",
+      "wait",
+      "--for",
+      "deleted",
+      "deploy/web",
+      "-n",
+      "shop",
+      "--timeout",
+      "2m",
+    ])
+    .unwrap();
+    assert_eq!(cli.namespace.as_deref(), Some("shop"));
+    let Some(Command::Wait(args)) = cli.command else {
+      panic!("expected the wait command");
+    };
+    assert_eq!(args.condition, wait::WaitCondition::Deleted);
+    assert_eq!(args.target.block, ActiveBlock::Deployments);
+    assert_eq!(args.target.to_string(), "deploy/web");
+    assert_eq!(args.timeout, Duration::from_secs(120));
+
+    let defaults = Cli::try_parse_from(["kdash", "wait", "pod/web-0"]).unwrap();
+    let Some(Command::Wait(args)) = defaults.command else {
+      panic!("expected the wait command");
+    };
+    assert_eq!(args.condition, wait::WaitCondition::Ready);
+    assert_eq!(args.timeout, Duration::from_secs(300));
+    assert!(Cli::try_parse_from(["kdash", "wait", "--for", "gone", "pod/web-0"]).is_err());
+    assert!(Cli::try_parse_from(["kdash"]).unwrap().command.is_none());
+  }
+
+  #[test]
+  fn test_cli_validates_the_time_zone() {
+    assert_eq!(
+      Cli::try_parse_from(["kdash", "--timezone", "Asia/Kolkata"])
+        .unwrap()
+        .timezone,
+      Some(DisplayZone::Named(chrono_tz::Asia::Kolkata))
+    );
+    assert_eq!(
+      Cli::try_parse_from(["kdash", "--timezone", "UTC"])
+        .unwrap()
+        .timezone,
+      Some(DisplayZone::Utc)
+    );
+    let error = Cli::try_parse_from(["kdash", "--timezone", "Europe/Lodnon"])
+      .unwrap_err()
+      .to_string();
+    assert!(
+      error.contains("invalid value 'Europe/Lodnon' for '--timezone <ZONE>': unknown time zone"),
+      "{}",
+      error
+    );
+  }
+
+  #[test]
+  fn test_debug_log_falls_back_to_the_temp_dir() {
+    let base = std::env::temp_dir().join(format!("kdash-log-dir-test-{}", std::process::id()));
+    let dir = base.join("logs");
+    let (path, _, warning) = create_log_file(Some(&dir), "a.log").unwrap();
+    assert_eq!(path, dir.join("a.log"));
+    assert!(path.exists());
+    assert_eq!(warning, None);
+
+    // a file where the directory should be
+    let blocked = dir.join("a.log").join("nested");
+    let name = format!("kdash-log-dir-test-{}.log", std::process::id());
+    let (path, _, warning) = create_log_file(Some(&blocked), &name).unwrap();
+    assert_eq!(path, std::env::temp_dir().join(&name));
+    let warning = warning.unwrap();
+    assert!(
+      warning.starts_with(&format!(
+        "can't write the debug log to {}",
+        blocked.display()
+      )),
+      "{}",
+      warning
+    );
+    assert!(warning.ends_with(&format!("writing it to {}", path.display())));
+    std::fs::remove_file(path).unwrap();
+    std::fs::remove_dir_all(base).unwrap();
+
+    assert_eq!(
+      Cli::try_parse_from(["kdash", "--log-dir", "/var/log/kdash"])
+        .unwrap()
+        .log_dir,
+      Some("/var/log/kdash".into())
+    );
+  }
+
   #[test]
   fn test_cli_parses_namespace_and_context_long_flags() {
     let cli = Cli::try_parse_from(["kdash", "--namespace", "team-a", "--context", "prod"]).unwrap();
@@ -738,6 +1366,37 @@ mod tests {
     assert_eq!(cli.context.as_deref(), Some("prod"));
   }
 
+  #[test]
+  fn test_cli_parses_impersonation_and_requires_a_user_for_groups() {
+    let cli = Cli::try_parse_from([
+      "kdash",
+      "--as",
+      "alice",
+      "--as-group",
+      "dev",
+      "--as-group",
+      "qa",
+    ])
+    .unwrap();
+    assert_eq!(cli.as_user.as_deref(), Some("alice"));
+    assert_eq!(cli.as_group, vec!["dev", "qa"]);
+    assert!(Cli::try_parse_from(["kdash", "--as-group", "dev"]).is_err());
+  }
+
+  #[test]
+  fn test_cli_skips_tls_verify_only_when_asked() {
+    assert!(
+      !Cli::try_parse_from(["kdash"])
+        .unwrap()
+        .insecure_skip_tls_verify
+    );
+    assert!(
+      Cli::try_parse_from(["kdash", "--insecure-skip-tls-verify"])
+        .unwrap()
+        .insecure_skip_tls_verify
+    );
+  }
+
   #[test]
   fn test_cli_defaults_namespace_and_context_to_none() {
     let cli = Cli::try_parse_from(["kdash"]).unwrap();
@@ -745,6 +1404,33 @@ mod tests {
     assert_eq!(cli.context, None);
   }
 
+  #[test]
+  fn test_cli_parses_glyph_set() {
+    assert_eq!(Cli::try_parse_from(["kdash"]).unwrap().glyphs, None);
+    assert_eq!(
+      Cli::try_parse_from(["kdash", "--glyphs", "nerd-font"])
+        .unwrap()
+        .glyphs,
+      Some(crate::ui::glyphs::GlyphSet::NerdFont)
+    );
+    assert!(Cli::try_parse_from(["kdash", "--glyphs", "emoji"]).is_err());
+  }
+
+  #[test]
+  fn test_cli_enhanced_graphics_defaults_on_and_can_be_disabled() {
+    assert!(Cli::try_parse_from(["kdash"]).unwrap().enhanced_graphics);
+    assert!(
+      Cli::try_parse_from(["kdash", "-e"])
+        .unwrap()
+        .enhanced_graphics
+    );
+    assert!(
+      !Cli::try_parse_from(["kdash", "--enhanced-graphics=false"])
+        .unwrap()
+        .enhanced_graphics
+    );
+  }
+
   #[test]
   fn test_seed_startup_selection_sets_selected_fields() {
     let mut app = App::default();
@@ -765,6 +1451,141 @@ mod tests {
     assert_eq!(app.data.selected.context, None);
   }
 
+  #[test]
+  fn test_seed_startup_selection_keeps_restored_fields_without_flags() {
+    let mut app = App::default();
+    app.data.selected.ns = Some("team-a".into());
+    app.data.selected.context = Some("prod".into());
+
+    seed_startup_selection(&mut app, Some("team-b".into()), None);
+
+    assert_eq!(app.data.selected.ns.as_deref(), Some("team-b"));
+    assert_eq!(app.data.selected.context.as_deref(), Some("prod"));
+  }
+
+  #[test]
+  fn test_cli_parses_view_and_lists_valid_views_on_error() {
+    let cli = Cli::try_parse_from(["kdash", "--view", "Deployments"]).unwrap();
+    assert_eq!(cli.view.as_deref(), Some("deployments"));
+
+    let err = Cli::try_parse_from(["kdash", "--view", "podz"])
+      .unwrap_err()
+      .to_string();
+    assert!(err.contains("podz"), "{err}");
+    assert!(err.contains("Valid views: pods, services, nodes"), "{err}");
+    assert!(err.contains("utilization, troubleshoot"), "{err}");
+  }
+
+  #[test]
+  fn test_cli_parses_no_restore() {
+    assert!(!Cli::try_parse_from(["kdash"]).unwrap().no_restore);
+    assert!(
+      Cli::try_parse_from(["kdash", "--no-restore"])
+        .unwrap()
+        .no_restore
+    );
+  }
+
+  #[test]
+  fn test_cli_parses_no_alt_screen() {
+    assert!(!Cli::try_parse_from(["kdash"]).unwrap().no_alt_screen);
+    assert!(
+      Cli::try_parse_from(["kdash", "--no-alt-screen"])
+        .unwrap()
+        .no_alt_screen
+    );
+  }
+
+  #[test]
+  fn test_alt_screen_is_only_entered_and_left_when_enabled() {
+    let mut out = vec![];
+    enter_alt_screen(&mut out, true).unwrap();
+    leave_alt_screen(&mut out, true).unwrap();
+    assert_eq!(out, b"\x1b[?1049h\x1b[?1049l");
+
+    let mut out = vec![];
+    enter_alt_screen(&mut out, false).unwrap();
+    leave_alt_screen(&mut out, false).unwrap();
+    assert!(out.is_empty());
+  }
+
+  #[test]
+  fn test_cli_parses_no_watch() {
+    assert!(!Cli::try_parse_from(["kdash"]).unwrap().no_watch);
+    assert!(
+      Cli::try_parse_from(["kdash", "--no-watch"])
+        .unwrap()
+        .no_watch
+    );
+  }
+
+  #[test]
+  fn test_reload_config_keeps_previous_config_on_parse_error() {
+    let path = std::env::temp_dir().join(format!("kdash-reload-test-{}.yaml", std::process::id()));
+    std::fs::write(&path, "keybindings: [").unwrap();
+    let mut app = App::default();
+    app.config.log_tail_lines = Some(50);
+
+    super::reload_config(&mut app, &path);
+
+    assert_eq!(app.config.log_tail_lines, Some(50));
+    assert!(app.api_error.contains("Failed to parse config file"));
+    assert!(app.api_error.contains("Keeping the previous config"));
+    std::fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn test_reload_config_keeps_the_profile() {
+    let path = std::env::temp_dir().join(format!("kdash-profile-test-{}.yaml", std::process::id()));
+    std::fs::write(
+      &path,
+      "log_tail_lines: 100\nprofiles:\n  prod:\n    log_tail_lines: 20\n",
+    )
+    .unwrap();
+    let cli = Cli::try_parse_from(["kdash", "--profile", "prod"]).unwrap();
+    let mut app = App::default();
+    app.profile = cli.profile;
+
+    super::reload_config(&mut app, &path);
+
+    assert_eq!(app.config.log_tail_lines, Some(20));
+    std::fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn test_no_banner_drops_banner_from_help() {
+    let args = |list: &[&str]| list.iter().map(OsString::from).collect::<Vec<_>>();
+    let help = cli_command(&args(&["kdash", "--help"]))
+      .render_help()
+      .to_string();
+    assert!(help.contains("██╗"));
+    let help = cli_command(&args(&["kdash", "--no-banner", "--help"]))
+      .render_help()
+      .to_string();
+    assert!(!help.contains("██╗"));
+  }
+
+  #[test]
+  fn test_splash_enabled_by_flag_or_config_unless_no_banner() {
+    let cli = |list: &[&str]| Cli::try_parse_from(list).unwrap();
+    let config = KdashConfig {
+      splash: true,
+      ..KdashConfig::default()
+    };
+
+    assert!(!splash_enabled(&cli(&["kdash"]), &KdashConfig::default()));
+    assert!(splash_enabled(
+      &cli(&["kdash", "--splash"]),
+      &KdashConfig::default()
+    ));
+    assert!(splash_enabled(&cli(&["kdash"]), &config));
+    assert!(!splash_enabled(&cli(&["kdash", "--no-banner"]), &config));
+    assert!(!splash_enabled(
+      &cli(&["kdash", "--splash", "--no-banner"]),
+      &KdashConfig::default()
+    ));
+  }
+
   #[tokio::test]
   async fn test_execute_pending_shell_exec_with_sets_success_status_and_clears_request() {
     let app = Arc::new(Mutex::new(App::default()));
@@ -820,6 +1641,48 @@ mod tests {
     assert!(app.status_message.is_empty());
   }
 
+  fn pending_edit() -> crate::app::PendingEdit {
+    crate::app::PendingEdit {
+      namespace: Some("default".into()),
+      kind: "deployment".into(),
+      name: "web".into(),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_execute_pending_edit_with_reports_unchanged_edit() {
+    let app = Arc::new(Mutex::new(App::default()));
+    let mut terminal = StubTerminal;
+
+    let result = execute_pending_edit_with(&app, &mut terminal, pending_edit(), |_| {
+      Ok(crate::cmd::edit::EditOutcome::Unchanged)
+    })
+    .await;
+
+    assert!(result.is_ok());
+    let app = app.lock().await;
+    assert!(app.api_error.is_empty());
+    assert_eq!(app.status_message.text(), "No changes to deployment 'web'");
+  }
+
+  #[tokio::test]
+  async fn test_execute_pending_edit_with_reports_editor_errors_after_restoring_terminal() {
+    let app = Arc::new(Mutex::new(App::default()));
+    let mut terminal = StubTerminal;
+
+    let result = execute_pending_edit_with(&app, &mut terminal, pending_edit(), |_| {
+      Err(anyhow!("Editor exited unsuccessfully: 1"))
+    })
+    .await;
+
+    assert!(result.is_ok());
+    let app = app.lock().await;
+    assert_eq!(
+      app.api_error,
+      "Unable to edit deployment 'web': Editor exited unsuccessfully: 1"
+    );
+  }
+
   #[tokio::test]
   async fn test_process_event_tick_advances_state_and_sets_tick_seen() {
     let mut app = App::default();
@@ -0,0 +1,139 @@
+//! `--as` / `--as-group` impersonation for RBAC testing, set once at startup
+//! and applied to every kube client and every kubectl command KDash runs.
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, Result};
+
+static IMPERSONATION: OnceLock<Impersonation> = OnceLock::new();
+
+/// The user and groups to act as. Kubernetes only accepts groups together
+/// with a user, which the CLI enforces.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Impersonation {
+  pub user: Option<String>,
+  pub groups: Vec<String>,
+}
+
+impl Impersonation {
+  /// `alice` or `alice (groups: dev, qa)`, for the footer.
+  pub fn label(&self) -> Option<String> {
+    let user = self.user.as_deref()?;
+    Some(if self.groups.is_empty() {
+      user.to_owned()
+    } else {
+      format!("{} (groups: {})", user, self.groups.join(", "))
+    })
+  }
+
+  /// Set the impersonation headers on a client config.
+  pub fn apply(&self, config: &mut kube::Config) {
+    if let Some(user) = &self.user {
+      config.auth_info.impersonate = Some(user.clone());
+    }
+    if !self.groups.is_empty() {
+      config.auth_info.impersonate_groups = Some(self.groups.clone());
+    }
+  }
+
+  /// The same impersonation as kubectl flags.
+  pub fn kubectl_args(&self) -> Vec<String> {
+    let mut args = vec![];
+    if let Some(user) = &self.user {
+      args.push("--as".into());
+      args.push(user.clone());
+    }
+    for group in &self.groups {
+      args.push("--as-group".into());
+      args.push(group.clone());
+    }
+    args
+  }
+}
+
+/// Set the impersonation for this run.
+pub fn initialize_impersonation(impersonation: Impersonation) {
+  let _ = IMPERSONATION.set(impersonation);
+}
+
+pub fn impersonation() -> &'static Impersonation {
+  IMPERSONATION.get_or_init(Impersonation::default)
+}
+
+/// Make one request as the impersonated user, so a cluster that refuses the
+/// impersonation says so at startup instead of on the first list. Other
+/// failures are left to the views, which report them as usual.
+pub async fn check_impersonation(client: &kube::Client) -> Result<()> {
+  let impersonation = impersonation();
+  match impersonation.label() {
+    Some(label) => match client.apiserver_version().await {
+      Err(e) if is_impersonation_rejected(&e) => Err(anyhow!(
+        "The cluster does not allow impersonating {}. {}",
+        label,
+        e
+      )),
+      _ => Ok(()),
+    },
+    None => Ok(()),
+  }
+}
+
+/// The API server answers 403 before any other check when the real user may
+/// not impersonate, and `/version` needs no other permission.
+fn is_impersonation_rejected(error: &kube::Error) -> bool {
+  matches!(error, kube::Error::Api(status) if status.is_forbidden())
+}
+
+#[cfg(test)]
+mod tests {
+  use kube::core::Status;
+
+  use super::*;
+
+  fn alice() -> Impersonation {
+    Impersonation {
+      user: Some("alice".into()),
+      groups: vec!["dev".into(), "qa".into()],
+    }
+  }
+
+  #[test]
+  fn test_impersonation_label_and_kubectl_args() {
+    assert_eq!(Impersonation::default().label(), None);
+    assert!(Impersonation::default().kubectl_args().is_empty());
+    assert_eq!(alice().label().as_deref(), Some("alice (groups: dev, qa)"));
+    assert_eq!(
+      alice().kubectl_args(),
+      vec!["--as", "alice", "--as-group", "dev", "--as-group", "qa"]
+    );
+  }
+
+  #[test]
+  fn test_impersonation_sets_client_config_headers() {
+    let mut config = kube::Config::new("https://127.0.0.1:6443".parse().unwrap());
+    alice().apply(&mut config);
+    assert_eq!(config.auth_info.impersonate.as_deref(), Some("alice"));
+    assert_eq!(
+      config.auth_info.impersonate_groups,
+      Some(vec!["dev".to_owned(), "qa".to_owned()])
+    );
+  }
+
+  #[test]
+  fn test_forbidden_is_an_impersonation_rejection() {
+    let forbidden = kube::Error::Api(
+      Status::failure(
+        "users \"alice\" is forbidden: User \"bob\" cannot impersonate resource \"users\"",
+        "Forbidden",
+      )
+      .with_code(403)
+      .boxed(),
+    );
+    assert!(is_impersonation_rejected(&forbidden));
+    let unauthorized = kube::Error::Api(
+      Status::failure("Unauthorized", "Unauthorized")
+        .with_code(401)
+        .boxed(),
+    );
+    assert!(!is_impersonation_rejected(&unauthorized));
+  }
+}
@@ -0,0 +1,57 @@
+//! `--insecure-skip-tls-verify` for dev clusters with self-signed certificates:
+//! the API server's certificate is not checked by the kube client or by the
+//! kubectl commands KDash runs. Only ever set from the flag, so it has to be
+//! asked for on every run.
+use std::sync::OnceLock;
+
+use log::warn;
+
+static INSECURE_SKIP_TLS_VERIFY: OnceLock<bool> = OnceLock::new();
+
+/// Set for this run whether TLS verification is skipped.
+pub fn initialize_insecure_tls(skip_verify: bool) {
+  if skip_verify {
+    warn!(
+      "--insecure-skip-tls-verify is set: the API server certificate is NOT verified, \
+       so the connection can be intercepted"
+    );
+  }
+  let _ = INSECURE_SKIP_TLS_VERIFY.set(skip_verify);
+}
+
+pub fn insecure_tls() -> bool {
+  INSECURE_SKIP_TLS_VERIFY.get().copied().unwrap_or_default()
+}
+
+/// Accept any server certificate on a client config when the flag is set.
+pub fn apply(skip_verify: bool, config: &mut kube::Config) {
+  if skip_verify {
+    config.accept_invalid_certs = true;
+  }
+}
+
+/// The same as a kubectl flag.
+pub fn kubectl_args(skip_verify: bool) -> Vec<String> {
+  if skip_verify {
+    vec!["--insecure-skip-tls-verify".into()]
+  } else {
+    vec![]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_insecure_tls_sets_client_config_and_kubectl_flag() {
+    let mut config = kube::Config::new("https://127.0.0.1:6443".parse().unwrap());
+    apply(false, &mut config);
+    assert!(!config.accept_invalid_certs);
+    apply(true, &mut config);
+    assert!(config.accept_invalid_certs);
+
+    assert!(kubectl_args(false).is_empty());
+    assert_eq!(kubectl_args(true), vec!["--insecure-skip-tls-verify"]);
+  }
+}
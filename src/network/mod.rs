@@ -1,16 +1,21 @@
+pub(crate) mod connection_info;
+pub(crate) mod impersonation;
+pub(crate) mod insecure_tls;
 pub(crate) mod stream;
 
 use core::convert::TryFrom;
 use std::{
+  cell::Cell,
   env, fmt,
   io::ErrorKind,
   path::{Path, PathBuf},
   process::Stdio,
   sync::Arc,
-  time::Duration,
+  time::{Duration, Instant},
 };
 
 use anyhow::{anyhow, Context, Result};
+use futures::future::join_all;
 use k8s_openapi::{
   api::core::v1::Pod, apimachinery::pkg::apis::meta::v1::APIGroup as DiscoveryApiGroup,
   NamespaceResourceScope,
@@ -26,35 +31,55 @@ use log::{debug, error, info, warn};
 use serde::de::DeserializeOwned;
 use tokio::{process::Command, sync::Mutex, time::timeout};
 
+use self::{connection_info::ConnectionInfo, stream::IoStreamEvent};
 use crate::app::{
+  alerts,
+  api_resources::ApiResourcesResource,
+  columns,
   configmaps::ConfigMapResource,
-  contexts,
+  context_label,
+  contexts::{self, ContextHealth},
+  crds::CrdResource,
   cronjobs::CronJobResource,
-  daemonsets::DaemonSetResource,
+  daemonsets::{self, DaemonSetResource},
   deployments::DeploymentResource,
+  diff::last_applied_diff,
   dynamic::{api_resource_for_block, DynamicResource, KubeDynamicKind},
-  events::EventResource,
+  events::{self, EventResource},
+  footer::LastNetworkEvent,
   ingress::IngressResource,
   jobs::JobResource,
-  metrics::UtilizationResource,
-  models::{AppResource, StatefulList},
+  limit_ranges::LimitRangeResource,
+  load_state::LIST_PAGE_SIZE,
+  metrics::{self, MetricsSource, UtilizationResource},
+  models::{AppResource, MarkedRow, ScrollableTxt, StatefulList},
   network_policies::NetworkPolicyResource,
+  node_pods::{self, NodePodsResource},
   nodes::NodeResource,
-  ns::NamespaceResource,
-  pods::{KubePod, PodResource},
+  ns::{self, NamespaceResource},
+  pdbs::PdbResource,
+  pod_owners,
+  pods::{self, KubePod, PodResource},
   pvcs::PvcResource,
   pvs::PvResource,
-  replicasets::ReplicaSetResource,
+  replicasets::{self, ReplicaSetResource},
   replication_controllers::ReplicationControllerResource,
-  roles::{ClusterRoleBindingResource, ClusterRoleResource, RoleBindingResource, RoleResource},
+  resource_quotas::ResourceQuotaResource,
+  roles::{
+    self, ClusterRoleBindingResource, ClusterRoleResource, RoleBindingResource, RoleResource,
+  },
+  rollout_follow::sort_by_creation,
   secrets::SecretResource,
   serviceaccounts::SvcAcctResource,
+  snapshot,
   statefulsets::StatefulSetResource,
   storageclass::StorageClassResource,
-  svcs::SvcResource,
+  svcs::{self, SvcResource},
   troubleshoot::TroubleshootResource,
+  watchlist::WatchlistResource,
   ActiveBlock, App,
 };
+use crate::cmd::binaries;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IoEvent {
@@ -84,10 +109,23 @@ pub enum IoEvent {
   GetEvents,
   GetMetrics,
   GetTroubleshootFindings,
+  GetWatchlist,
   RefreshClient,
   DiscoverDynamicRes,
   GetDynamicRes,
   GetNetworkPolicies,
+  GetResourceQuotas,
+  GetLimitRanges,
+  GetPdbs,
+  GetCrds,
+  GetApiResources,
+  GetNodePodSummary,
+  /// List pods for `--alerts`, without touching the pods view.
+  CheckPodAlerts,
+  /// Check in the background which kubeconfig contexts are reachable.
+  ProbeContexts,
+  /// The API server's version, shown in the footer.
+  GetServerVersion,
   GetPodsBySelector {
     namespace: String,
     selector: String,
@@ -95,11 +133,62 @@ pub enum IoEvent {
   GetPodsByNode {
     node_name: String,
   },
+  GetDaemonSetCoverage {
+    name: String,
+    namespace: String,
+  },
+  /// The controller chain of a pod, shown in its describe view.
+  GetPodControllers {
+    name: String,
+    namespace: String,
+  },
+  /// EndpointSlices of a Service, shown above its describe output.
+  GetServiceEndpoints {
+    name: String,
+    namespace: String,
+  },
+  /// Events about one pod, shown in its containers view.
+  GetPodEvents {
+    name: String,
+    namespace: String,
+  },
+  /// Per-container usage of one pod, from its metrics.
+  GetContainerUsage {
+    name: String,
+    namespace: String,
+  },
+  GetReplicaSetsByOwner {
+    namespace: String,
+    owner: String,
+  },
+  /// Resolve the rules of the Role or ClusterRole a binding references.
+  GetBindingRules {
+    role_kind: String,
+    role_name: String,
+    namespace: Option<String>,
+  },
   DeleteResource {
     block: ActiveBlock,
     name: String,
     namespace: Option<String>,
   },
+  /// Delete every selected row of the block at once.
+  DeleteResources {
+    block: ActiveBlock,
+    rows: Vec<MarkedRow>,
+  },
+  /// Delete crash-looping, controller-managed pods so their controllers
+  /// recreate them.
+  RestartPods {
+    rows: Vec<MarkedRow>,
+  },
+  CreateNamespace {
+    name: String,
+  },
+  /// Delete a namespace and, through the namespace controller, everything in it.
+  DeleteNamespace {
+    name: String,
+  },
   PatchResource {
     block: ActiveBlock,
     name: String,
@@ -110,6 +199,84 @@ pub enum IoEvent {
     name: String,
     namespace: String,
   },
+  /// Evict a pod, respecting its PodDisruptionBudgets.
+  EvictPod {
+    name: String,
+    namespace: String,
+  },
+  GetLastAppliedDiff {
+    block: ActiveBlock,
+    name: String,
+    namespace: Option<String>,
+  },
+  /// Save every resource of the namespace to YAML files for a bug report.
+  SnapshotNamespace {
+    namespace: String,
+  },
+  /// Step `step` of `steps` of macro `name`: a delete or patch.
+  MacroStep {
+    name: String,
+    step: usize,
+    steps: usize,
+    event: Box<IoEvent>,
+  },
+}
+
+impl IoEvent {
+  /// The variant's name without its fields, e.g. `GetPods`.
+  pub fn name(&self) -> String {
+    let debug = format!("{:?}", self);
+    debug
+      .split(|c: char| !c.is_alphanumeric())
+      .next()
+      .unwrap_or_default()
+      .to_owned()
+  }
+
+  /// The view whose data this event fetches, so its load state can be tracked.
+  pub fn view_block(&self) -> Option<ActiveBlock> {
+    let block = match self {
+      IoEvent::GetKubeConfig => ActiveBlock::Contexts,
+      IoEvent::GetNodes => ActiveBlock::Nodes,
+      IoEvent::GetNamespaces => ActiveBlock::Namespaces,
+      IoEvent::GetPods | IoEvent::GetPodsBySelector { .. } | IoEvent::GetPodsByNode { .. } => {
+        ActiveBlock::Pods
+      }
+      IoEvent::GetServices => ActiveBlock::Services,
+      IoEvent::GetConfigMaps => ActiveBlock::ConfigMaps,
+      IoEvent::GetStatefulSets => ActiveBlock::StatefulSets,
+      IoEvent::GetReplicaSets | IoEvent::GetReplicaSetsByOwner { .. } => ActiveBlock::ReplicaSets,
+      IoEvent::GetDeployments => ActiveBlock::Deployments,
+      IoEvent::GetJobs => ActiveBlock::Jobs,
+      IoEvent::GetDaemonSets => ActiveBlock::DaemonSets,
+      IoEvent::GetCronJobs => ActiveBlock::CronJobs,
+      IoEvent::GetSecrets => ActiveBlock::Secrets,
+      IoEvent::GetReplicationControllers => ActiveBlock::ReplicationControllers,
+      IoEvent::GetStorageClasses => ActiveBlock::StorageClasses,
+      IoEvent::GetRoles => ActiveBlock::Roles,
+      IoEvent::GetRoleBindings => ActiveBlock::RoleBindings,
+      IoEvent::GetClusterRoles => ActiveBlock::ClusterRoles,
+      IoEvent::GetClusterRoleBinding => ActiveBlock::ClusterRoleBindings,
+      IoEvent::GetIngress => ActiveBlock::Ingresses,
+      IoEvent::GetPvcs => ActiveBlock::PersistentVolumeClaims,
+      IoEvent::GetPvs => ActiveBlock::PersistentVolumes,
+      IoEvent::GetServiceAccounts => ActiveBlock::ServiceAccounts,
+      IoEvent::GetEvents => ActiveBlock::Events,
+      IoEvent::GetMetrics => ActiveBlock::Utilization,
+      IoEvent::GetTroubleshootFindings => ActiveBlock::Troubleshoot,
+      IoEvent::GetWatchlist => ActiveBlock::Watchlist,
+      IoEvent::GetDynamicRes => ActiveBlock::DynamicResource,
+      IoEvent::GetNetworkPolicies => ActiveBlock::NetworkPolicies,
+      IoEvent::GetResourceQuotas => ActiveBlock::ResourceQuotas,
+      IoEvent::GetLimitRanges => ActiveBlock::LimitRanges,
+      IoEvent::GetPdbs => ActiveBlock::PodDisruptionBudgets,
+      IoEvent::GetCrds => ActiveBlock::CustomResourceDefinitions,
+      IoEvent::GetApiResources => ActiveBlock::ApiResources,
+      IoEvent::GetNodePodSummary => ActiveBlock::PodsByNode,
+      _ => return None,
+    };
+    Some(block)
+  }
 }
 
 /// A merge-patch a resource action applies. Kept as a small enum (rather than
@@ -184,28 +351,19 @@ async fn refresh_kube_config(context: &Option<String>) -> Result<kube::Client> {
 }
 
 fn should_retry_kubectl_refresh(error: &anyhow::Error) -> bool {
-  error.chain().any(|cause| {
-    cause
-      .downcast_ref::<kube::Error>()
-      .is_some_and(|error| match error {
-        kube::Error::Auth(_) => true,
-        kube::Error::Api(status) => status.code == 401 || status.reason == "Unauthorized",
-        _ => false,
-      })
-      || {
-        let message = cause.to_string().to_lowercase();
-        message.contains("auth exec")
-          || message.contains("failed exec auth")
-          || message.contains("exec-plugin")
-          || message.contains("oidc")
-          || message.contains("oauth")
-          || message.contains("unauthorized")
-      }
-  })
+  crate::app::utils::is_auth_failure(error)
+    || error.chain().any(|cause| {
+      let message = cause.to_string().to_lowercase();
+      message.contains("auth exec")
+        || message.contains("failed exec auth")
+        || message.contains("exec-plugin")
+        || message.contains("oidc")
+        || message.contains("oauth")
+    })
 }
 
 async fn run_kubectl_cluster_info(context: &Option<String>, max_wait: Duration) -> Result<()> {
-  let mut command = Command::new("kubectl");
+  let mut command = Command::new(binaries::kubectl());
   command
     .arg("cluster-info")
     .stderr(Stdio::null())
@@ -298,6 +456,19 @@ fn load_kubeconfig_from_paths(paths: &[PathBuf]) -> Result<Kubeconfig> {
   }
 }
 
+/// Whether the local kubeconfig defines this context.
+pub fn kubeconfig_has_context(context: &str) -> bool {
+  matches!(
+    load_local_kubeconfig(),
+    Ok(Some(config)) if config.contexts.iter().any(|ctx| ctx.name == context)
+  )
+}
+
+/// The local kubeconfig's current context, used when none is selected.
+pub fn kubeconfig_current_context() -> Option<String> {
+  load_local_kubeconfig().ok()??.current_context
+}
+
 fn load_local_kubeconfig() -> Result<Option<Kubeconfig>> {
   match env::var_os("KUBECONFIG") {
     Some(value) => {
@@ -388,196 +559,469 @@ fn env_https_proxy_url() -> Option<String> {
 
 pub async fn get_client(context: Option<String>) -> Result<kube::Client> {
   debug!("env KUBECONFIG: {:?}", env::var_os("KUBECONFIG"));
-  let client_config = match context.as_ref() {
+  let loaded = match context.as_ref() {
     Some(context) => {
       info!("Getting kubernetes client. Context: {}", context);
-      load_client_config(Some(context.to_owned())).await?
+      load_client_config(Some(context.to_owned())).await
     }
     None => {
       warn!("Getting kubernetes client by inference. No context given");
-      load_client_config(None).await?
+      load_client_config(None).await
+    }
+  };
+  let kubeconfig = load_local_kubeconfig().ok().flatten();
+  let mut client_config = match loaded {
+    Ok(config) => config,
+    Err(e) => {
+      connection_info::record(ConnectionInfo::from_error(
+        context.as_deref(),
+        kubeconfig.as_ref(),
+        &e,
+      ));
+      return Err(e);
     }
   };
+  impersonation::impersonation().apply(&mut client_config);
+  insecure_tls::apply(insecure_tls::insecure_tls(), &mut client_config);
+  connection_info::record(ConnectionInfo::from_config(
+    context.as_deref(),
+    kubeconfig.as_ref(),
+    &client_config,
+  ));
   debug!("Kubernetes client config: {:?}", client_config);
   info!("Kubernetes client connected");
   kube::Client::try_from(client_config).context("Failed to create Kubernetes client")
 }
 
+/// Status line for a batch delete: `Ok` when every row was deleted, `Err`
+/// with the per-row failures otherwise.
+fn batch_delete_summary(total: usize, failures: &[String]) -> Result<String, String> {
+  if failures.is_empty() {
+    return Ok(format!("Deleting {} resources", total));
+  }
+  Err(format!(
+    "Deleted {} of {} resources. Failed: {}",
+    total - failures.len(),
+    total,
+    failures.join("; ")
+  ))
+}
+
+/// Status line for restarting crash-looping pods, like [`batch_delete_summary`].
+fn restart_pods_summary(total: usize, failures: &[String]) -> Result<String, String> {
+  if failures.is_empty() {
+    return Ok(format!("Restarting {} crash-looping pods", total));
+  }
+  Err(format!(
+    "Restarted {} of {} crash-looping pods. Failed: {}",
+    total - failures.len(),
+    total,
+    failures.join("; ")
+  ))
+}
+
+/// Whether the context's API server answers a version request in time.
+async fn probe_context(kubeconfig: Kubeconfig, context: String) -> ContextHealth {
+  let options = KubeConfigOptions {
+    context: Some(context),
+    ..Default::default()
+  };
+  let probe = async {
+    let mut config = load_client_config_from_kubeconfig(kubeconfig, options).await?;
+    insecure_tls::apply(insecure_tls::insecure_tls(), &mut config);
+    let client = kube::Client::try_from(config)?;
+    client.apiserver_version().await?;
+    anyhow::Ok(())
+  };
+  match timeout(contexts::CONTEXT_PROBE_TIMEOUT, probe).await {
+    Ok(Ok(())) => ContextHealth::Reachable,
+    Ok(Err(e)) => {
+      debug!("Context probe failed: {:?}", e);
+      ContextHealth::Unreachable
+    }
+    Err(_) => ContextHealth::Unreachable,
+  }
+}
+
+tokio::task_local! {
+  /// Set when the event being handled reports an error, so its outcome is
+  /// told apart from errors the stream and command threads report meanwhile.
+  static EVENT_FAILED: Cell<bool>;
+}
+
+/// Mark the event the network thread is handling, if any, as failed.
+pub fn note_event_error() {
+  let _ = EVENT_FAILED.try_with(|failed| failed.set(true));
+}
+
 #[derive(Clone)]
 pub struct Network<'a> {
   pub client: Client,
   pub app: &'a Arc<Mutex<App>>,
+  /// View of the event being handled, whose list pages `list_paged` follows.
+  view: Option<ActiveBlock>,
+  /// The client was already rebuilt for the current authentication failure.
+  auth_rebuilt: bool,
+  /// Context the client was built for, gone back to when a switch fails.
+  pub context: Option<String>,
 }
 
 impl<'a> Network<'a> {
   pub fn new(client: Client, app: &'a Arc<Mutex<App>>) -> Self {
-    Network { client, app }
+    Network {
+      client,
+      app,
+      view: None,
+      auth_rebuilt: false,
+      context: None,
+    }
   }
 
+  /// Rebuild the client for the selected context and start the app over on
+  /// it, so nothing from the previous cluster is left. When the client can't
+  /// be built, the previous one and its context stay in use.
   pub async fn refresh_client(&mut self) {
     let (context, ns, main_tab_index, context_tab_index, route) = {
-      let mut app = self.app.lock().await;
-      let context = app.data.selected.context.clone();
-      let ns = app.data.selected.ns.clone();
-      let main_tab_index = app.main_tabs.index;
-      let context_tab_index = app.context_tabs.index;
-      let route = app.refresh_restore_route();
-      // so that if refresh fails we dont see mixed results
-      app.data.selected.context = None;
-      (context, ns, main_tab_index, context_tab_index, route)
+      let app = self.app.lock().await;
+      (
+        app.data.selected.context.clone(),
+        app.data.selected.ns.clone(),
+        app.main_tabs.index,
+        app.context_tabs.index,
+        app.refresh_restore_route(),
+      )
     };
 
     match refresh_kube_config(&context).await {
       Ok(client) => {
         self.client = client;
+        self.context = context.clone();
+        self.auth_rebuilt = false;
         let mut app = self.app.lock().await;
         app.reset();
+        app
+          .status_message
+          .show(format!("Connected to {}", context_label(&context)));
         app.data.selected.context = context;
         app.data.selected.ns = ns;
         app.restore_route_state(main_tab_index, context_tab_index, route);
-        app.status_message.show("Refresh complete!");
       }
       Err(e) => {
-        self
-          .handle_error(anyhow!(
-            "Failed to refresh client. {}. Loading default context.",
-            e
-          ))
-          .await;
+        let mut app = self.app.lock().await;
+        app.connecting_to = None;
+        app.reconnecting_after_sleep = false;
+        app.data.selected.context = self.context.clone();
+        // the stream thread may have switched already
+        app.dispatch_stream(IoStreamEvent::RefreshClient).await;
+        app.handle_error(anyhow!(
+          "Failed to connect to {}, staying on {}. {}",
+          context_label(&context),
+          context_label(&self.context),
+          e
+        ));
       }
     }
   }
 
   #[allow(clippy::cognitive_complexity)]
   pub async fn handle_network_event(&mut self, io_event: IoEvent) {
-    match io_event {
-      IoEvent::RefreshClient => {
-        self.refresh_client().await;
-      }
-      IoEvent::GetKubeConfig => {
-        self.get_kube_config().await;
-      }
-      IoEvent::GetNodes => {
-        NodeResource::get_resource(self).await;
-      }
-      IoEvent::GetNamespaces => {
-        NamespaceResource::get_resource(self).await;
-      }
-      IoEvent::GetPods => {
-        PodResource::get_resource(self).await;
-      }
-      IoEvent::GetServices => {
-        SvcResource::get_resource(self).await;
-      }
-      IoEvent::GetConfigMaps => {
-        ConfigMapResource::get_resource(self).await;
-      }
-      IoEvent::GetStatefulSets => {
-        StatefulSetResource::get_resource(self).await;
-      }
-      IoEvent::GetReplicaSets => {
-        ReplicaSetResource::get_resource(self).await;
-      }
-      IoEvent::GetJobs => {
-        JobResource::get_resource(self).await;
-      }
-      IoEvent::GetDaemonSets => {
-        DaemonSetResource::get_resource(self).await;
-      }
-      IoEvent::GetCronJobs => {
-        CronJobResource::get_resource(self).await;
-      }
-      IoEvent::GetSecrets => {
-        SecretResource::get_resource(self).await;
-      }
-      IoEvent::GetDeployments => {
-        DeploymentResource::get_resource(self).await;
-      }
-      IoEvent::GetReplicationControllers => {
-        ReplicationControllerResource::get_resource(self).await;
-      }
-      IoEvent::GetMetrics => {
-        UtilizationResource::get_resource(self).await;
-      }
-      IoEvent::GetTroubleshootFindings => {
-        TroubleshootResource::get_resource(self).await;
-      }
-      IoEvent::GetStorageClasses => {
-        StorageClassResource::get_resource(self).await;
-      }
-      IoEvent::GetRoles => {
-        RoleResource::get_resource(self).await;
-      }
-      IoEvent::GetRoleBindings => {
-        RoleBindingResource::get_resource(self).await;
-      }
-      IoEvent::GetClusterRoles => {
-        ClusterRoleResource::get_resource(self).await;
-      }
-      IoEvent::GetClusterRoleBinding => {
-        ClusterRoleBindingResource::get_resource(self).await;
-      }
-      IoEvent::GetIngress => {
-        IngressResource::get_resource(self).await;
-      }
-      IoEvent::GetPvcs => {
-        PvcResource::get_resource(self).await;
-      }
-      IoEvent::GetPvs => {
-        PvResource::get_resource(self).await;
-      }
-      IoEvent::GetServiceAccounts => {
-        SvcAcctResource::get_resource(self).await;
-      }
-      IoEvent::GetNetworkPolicies => {
-        NetworkPolicyResource::get_resource(self).await;
-      }
-      IoEvent::GetEvents => {
-        EventResource::get_resource(self).await;
-      }
-      IoEvent::DiscoverDynamicRes => {
-        self.discover_dynamic_resources().await;
-      }
-      IoEvent::GetDynamicRes => {
-        DynamicResource::get_resource(self).await;
-      }
-      IoEvent::GetPodsBySelector {
-        namespace,
-        selector,
-      } => {
-        self.get_pods_by_selector(&namespace, &selector).await;
-      }
-      IoEvent::GetPodsByNode { node_name } => {
-        self.get_pods_by_node(&node_name).await;
-      }
-      IoEvent::DeleteResource {
-        block,
-        name,
-        namespace,
-      } => {
-        self
-          .delete_resource(block, &name, namespace.as_deref())
-          .await;
-      }
-      IoEvent::PatchResource {
-        block,
-        name,
-        namespace,
-        patch,
-      } => {
-        self
-          .patch_resource(block, &name, namespace.as_deref(), patch)
-          .await;
-      }
-      IoEvent::TriggerCronJob { name, namespace } => {
-        self.trigger_cronjob(&name, &namespace).await;
-      }
+    let view = io_event.view_block();
+    self.view = view;
+    let tracked = self.view.map(|_| io_event.clone());
+    let started = Instant::now();
+    let name = io_event.name();
+    let (errors_before, audit) = {
+      let app = self.app.lock().await;
+      (app.errors_recorded(), io_event.audit_action(&app))
     };
+    if io_event != IoEvent::RefreshClient && self.app.lock().await.connecting_to.is_some() {
+      info!(
+        "Dropping {:?}, it was queued for the previous cluster",
+        io_event
+      );
+      self.app.lock().await.loading_complete();
+      return;
+    }
+    // errors other threads report meanwhile are not this event's
+    let failed = EVENT_FAILED
+      .scope(Cell::new(false), async {
+        match io_event {
+          IoEvent::RefreshClient => {
+            self.refresh_client().await;
+          }
+          IoEvent::GetKubeConfig => {
+            self.get_kube_config().await;
+          }
+          IoEvent::GetNodes => {
+            NodeResource::get_resource(self).await;
+          }
+          IoEvent::GetNamespaces => {
+            NamespaceResource::get_resource(self).await;
+          }
+          IoEvent::GetPods => {
+            PodResource::get_resource(self).await;
+          }
+          IoEvent::GetServices => {
+            SvcResource::get_resource(self).await;
+          }
+          IoEvent::GetConfigMaps => {
+            ConfigMapResource::get_resource(self).await;
+          }
+          IoEvent::GetStatefulSets => {
+            StatefulSetResource::get_resource(self).await;
+          }
+          IoEvent::GetReplicaSets => {
+            ReplicaSetResource::get_resource(self).await;
+          }
+          IoEvent::GetJobs => {
+            JobResource::get_resource(self).await;
+          }
+          IoEvent::GetDaemonSets => {
+            DaemonSetResource::get_resource(self).await;
+          }
+          IoEvent::GetCronJobs => {
+            CronJobResource::get_resource(self).await;
+          }
+          IoEvent::GetSecrets => {
+            SecretResource::get_resource(self).await;
+          }
+          IoEvent::GetDeployments => {
+            DeploymentResource::get_resource(self).await;
+          }
+          IoEvent::GetReplicationControllers => {
+            ReplicationControllerResource::get_resource(self).await;
+          }
+          IoEvent::GetMetrics => {
+            UtilizationResource::get_resource(self).await;
+          }
+          IoEvent::GetTroubleshootFindings => {
+            TroubleshootResource::get_resource(self).await;
+          }
+          IoEvent::GetWatchlist => {
+            WatchlistResource::get_resource(self).await;
+          }
+          IoEvent::GetStorageClasses => {
+            StorageClassResource::get_resource(self).await;
+          }
+          IoEvent::GetRoles => {
+            RoleResource::get_resource(self).await;
+          }
+          IoEvent::GetRoleBindings => {
+            RoleBindingResource::get_resource(self).await;
+          }
+          IoEvent::GetClusterRoles => {
+            ClusterRoleResource::get_resource(self).await;
+          }
+          IoEvent::GetClusterRoleBinding => {
+            ClusterRoleBindingResource::get_resource(self).await;
+          }
+          IoEvent::GetIngress => {
+            IngressResource::get_resource(self).await;
+          }
+          IoEvent::GetPvcs => {
+            PvcResource::get_resource(self).await;
+          }
+          IoEvent::GetPvs => {
+            PvResource::get_resource(self).await;
+          }
+          IoEvent::GetServiceAccounts => {
+            SvcAcctResource::get_resource(self).await;
+          }
+          IoEvent::GetNetworkPolicies => {
+            NetworkPolicyResource::get_resource(self).await;
+          }
+          IoEvent::GetResourceQuotas => {
+            ResourceQuotaResource::get_resource(self).await;
+          }
+          IoEvent::GetLimitRanges => {
+            LimitRangeResource::get_resource(self).await;
+          }
+          IoEvent::GetPdbs => {
+            PdbResource::get_resource(self).await;
+          }
+          IoEvent::GetCrds => {
+            CrdResource::get_resource(self).await;
+          }
+          IoEvent::GetApiResources => {
+            ApiResourcesResource::get_resource(self).await;
+          }
+          IoEvent::GetNodePodSummary => {
+            NodePodsResource::get_resource(self).await;
+          }
+          IoEvent::CheckPodAlerts => {
+            alerts::check_pod_alerts(self).await;
+          }
+          IoEvent::ProbeContexts => {
+            self.probe_contexts().await;
+          }
+          IoEvent::GetServerVersion => {
+            self.get_server_version().await;
+          }
+          IoEvent::GetEvents => {
+            EventResource::get_resource(self).await;
+          }
+          IoEvent::DiscoverDynamicRes => {
+            self.discover_dynamic_resources().await;
+          }
+          IoEvent::GetDynamicRes => {
+            DynamicResource::get_resource(self).await;
+          }
+          IoEvent::GetPodsBySelector {
+            namespace,
+            selector,
+          } => {
+            self.get_pods_by_selector(&namespace, &selector).await;
+          }
+          IoEvent::GetPodsByNode { node_name } => {
+            self.get_pods_by_node(&node_name).await;
+          }
+          IoEvent::GetDaemonSetCoverage { name, namespace } => {
+            daemonsets::get_daemon_set_coverage(self, &name, &namespace).await;
+          }
+          IoEvent::GetPodControllers { name, namespace } => {
+            pod_owners::get_pod_controllers(self, &name, &namespace).await;
+          }
+          IoEvent::GetServiceEndpoints { name, namespace } => {
+            svcs::get_service_endpoints(self, &name, &namespace).await;
+          }
+          IoEvent::GetPodEvents { name, namespace } => {
+            events::get_pod_events(self, &name, &namespace).await;
+          }
+          IoEvent::GetContainerUsage { name, namespace } => {
+            metrics::get_container_usage(self, &name, &namespace).await;
+          }
+          IoEvent::GetReplicaSetsByOwner { namespace, owner } => {
+            replicasets::get_replica_sets_by_owner(self, &namespace, &owner).await;
+          }
+          IoEvent::GetBindingRules {
+            role_kind,
+            role_name,
+            namespace,
+          } => {
+            roles::get_binding_rules(self, &role_kind, &role_name, namespace.as_deref()).await;
+          }
+          IoEvent::DeleteResource {
+            block,
+            name,
+            namespace,
+          } => {
+            self
+              .delete_resource(block, &name, namespace.as_deref())
+              .await;
+          }
+          IoEvent::DeleteResources { block, rows } => {
+            self.delete_resources(block, rows).await;
+          }
+          IoEvent::RestartPods { rows } => {
+            self.restart_pods(rows).await;
+          }
+          IoEvent::CreateNamespace { name } => {
+            ns::create_namespace(self, &name).await;
+          }
+          IoEvent::DeleteNamespace { name } => {
+            ns::delete_namespace(self, &name).await;
+          }
+          IoEvent::PatchResource {
+            block,
+            name,
+            namespace,
+            patch,
+          } => {
+            self
+              .patch_resource(block, &name, namespace.as_deref(), patch)
+              .await;
+          }
+          IoEvent::TriggerCronJob { name, namespace } => {
+            self.trigger_cronjob(&name, &namespace).await;
+          }
+          IoEvent::MacroStep {
+            name,
+            step,
+            steps,
+            event,
+          } => {
+            self.run_macro_step(&name, step, steps, *event).await;
+          }
+          IoEvent::EvictPod { name, namespace } => {
+            pods::evict_pod(self, &name, &namespace).await;
+          }
+          IoEvent::GetLastAppliedDiff {
+            block,
+            name,
+            namespace,
+          } => {
+            self
+              .get_last_applied_diff(block, &name, namespace.as_deref())
+              .await;
+          }
+          IoEvent::SnapshotNamespace { namespace } => {
+            snapshot::snapshot_namespace(self, &namespace).await;
+          }
+        };
+        EVENT_FAILED.with(Cell::get)
+      })
+      .await;
+
+    let auth_failed = self.app.lock().await.take_auth_failure();
+    if auth_failed {
+      self.recover_auth().await;
+    }
 
     let mut app = self.app.lock().await;
+    if let Some(audit) = audit {
+      app.record_audit(audit, !failed);
+    }
+    if let Some(event) = tracked {
+      if !failed {
+        // the credentials work again
+        app.auth_expired = false;
+        self.auth_rebuilt = false;
+      }
+      app.finish_load(event, errors_before);
+    }
+    let label = view
+      .and_then(columns::view_name)
+      .map_or_else(|| name.clone(), str::to_owned);
+    app
+      .api_stats
+      .record(&label, started.elapsed(), failed, Instant::now());
+    app.last_network_event = Some(LastNetworkEvent {
+      name,
+      took: started.elapsed(),
+    });
     app.loading_complete();
   }
 
+  /// Build the client again after the API server rejected its credentials,
+  /// which runs an exec credential plugin afresh, as EKS and GKE use. Only
+  /// one rebuild is tried; when that fails or the new client is rejected
+  /// too, the UI says the credentials need refreshing.
+  async fn recover_auth(&mut self) {
+    if self.auth_rebuilt {
+      self.app.lock().await.auth_expired = true;
+      return;
+    }
+    self.auth_rebuilt = true;
+    let context = self.app.lock().await.data.selected.context.clone();
+    match refresh_kube_config(&context).await {
+      Ok(client) => {
+        info!("Rebuilt the Kubernetes client after an authentication failure");
+        self.client = client;
+        let mut app = self.app.lock().await;
+        // log streams and watches hold a client with the same credentials
+        app.dispatch_stream(IoStreamEvent::RefreshClient).await;
+        app.set_status_message("Authentication failed, reconnected with fresh credentials");
+      }
+      Err(e) => {
+        warn!(
+          "Rebuilding the client after an authentication failure failed: {:#}",
+          e
+        );
+        self.app.lock().await.auth_expired = true;
+      }
+    }
+  }
+
   pub async fn handle_error(&self, e: anyhow::Error) {
     error!("{:?}", e);
     let mut app = self.app.lock().await;
@@ -603,6 +1047,17 @@ impl<'a> Network<'a> {
               prev_ctx, config.current_context
             );
             app.set_contexts(contexts::get_contexts(&config, None));
+            // land on the new context's namespace; without one, stay on the
+            // namespace selected before
+            let namespace = app
+              .data
+              .active_context
+              .as_ref()
+              .and_then(|ctx| ctx.namespace.clone());
+            if namespace.is_some() {
+              app.data.selected.ns = namespace;
+              app.data.selected.previous_ns = None;
+            }
             app.data.kubeconfig = Some(config);
             app.refresh();
             return;
@@ -625,8 +1080,56 @@ impl<'a> Network<'a> {
     }
   }
 
+  /// Probe every kubeconfig context concurrently on a separate task, so a
+  /// dead cluster neither blocks the other probes nor the request queue.
+  async fn probe_contexts(&self) {
+    let Some(kubeconfig) = self.app.lock().await.data.kubeconfig.clone() else {
+      return;
+    };
+    let app = Arc::clone(self.app);
+    tokio::spawn(async move {
+      let names: Vec<String> = kubeconfig.contexts.iter().map(|c| c.name.clone()).collect();
+      let results = join_all(
+        names
+          .iter()
+          .map(|name| probe_context(kubeconfig.clone(), name.clone())),
+      )
+      .await;
+      let mut app = app.lock().await;
+      for (name, health) in names.into_iter().zip(results) {
+        app.data.context_probes.set_health(name, health);
+      }
+      app.mark_dirty();
+    });
+  }
+
+  async fn get_server_version(&self) {
+    match self.client.apiserver_version().await {
+      Ok(info) => {
+        self.app.lock().await.data.server_version = Some(info.git_version);
+      }
+      // The footer just leaves the version out; the views report a dead cluster.
+      Err(e) => warn!("Failed to get the server version: {:?}", e),
+    }
+  }
+
   /// calls the kubernetes API to list the given resource for either selected namespace or all namespaces
   pub async fn get_namespaced_resources<K, T, F>(&self, map_fn: F) -> Vec<T>
+  where
+    <K as ApiResource>::DynamicType: Default,
+    K: kube::Resource<Scope = NamespaceResourceScope>,
+    K: Clone + DeserializeOwned + fmt::Debug,
+    F: Fn(K) -> T,
+  {
+    self.get_namespaced_resources_matching(None, map_fn).await
+  }
+
+  /// same as `get_namespaced_resources` but narrowed server-side by an optional label selector
+  pub async fn get_namespaced_resources_matching<K, T, F>(
+    &self,
+    label_selector: Option<&str>,
+    map_fn: F,
+  ) -> Vec<T>
   where
     <K as ApiResource>::DynamicType: Default,
     K: kube::Resource<Scope = NamespaceResourceScope>,
@@ -634,9 +1137,12 @@ impl<'a> Network<'a> {
     F: Fn(K) -> T,
   {
     let api: Api<K> = self.get_namespaced_api().await;
-    let lp = ListParams::default();
-    match api.list(&lp).await {
-      Ok(list) => list.into_iter().map(map_fn).collect::<Vec<_>>(),
+    let lp = match label_selector {
+      Some(selector) => ListParams::default().labels(selector),
+      None => ListParams::default(),
+    };
+    match self.list_paged(&api, lp).await {
+      Ok(items) => items.into_iter().map(map_fn).collect::<Vec<_>>(),
       Err(e) => {
         self
           .handle_error(anyhow!(
@@ -659,8 +1165,8 @@ impl<'a> Network<'a> {
   {
     let api: Api<K> = Api::all(self.client.clone());
     let lp = ListParams::default();
-    match api.list(&lp).await {
-      Ok(list) => list.into_iter().map(map_fn).collect::<Vec<_>>(),
+    match self.list_paged(&api, lp).await {
+      Ok(items) => items.into_iter().map(map_fn).collect::<Vec<_>>(),
       Err(e) => {
         self
           .handle_error(anyhow!(
@@ -674,6 +1180,77 @@ impl<'a> Network<'a> {
     }
   }
 
+  /// Lists `api` in pages of `LIST_PAGE_SIZE`, following continue tokens for
+  /// as many pages as the current view has asked for. Every poll walks from
+  /// the first page again, so loaded pages stay fresh and no token is reused
+  /// after it expires. Outside a view the whole list is fetched.
+  async fn list_paged<K>(&self, api: &Api<K>, lp: ListParams) -> kube::Result<Vec<K>>
+  where
+    K: Clone + DeserializeOwned + fmt::Debug,
+  {
+    let requested = match self.view {
+      Some(block) => {
+        let app = self.app.lock().await;
+        app.data.list_pages.get(&block).map_or(1, |p| p.requested)
+      }
+      None => usize::MAX,
+    };
+    let mut lp = lp.limit(LIST_PAGE_SIZE);
+    let mut items = vec![];
+    let mut loaded = 0;
+    let has_more = loop {
+      let list = api.list(&lp).await?;
+      loaded += 1;
+      let token = list.metadata.continue_.filter(|t| !t.is_empty());
+      items.extend(list.items);
+      match token {
+        Some(token) if loaded < requested => lp = lp.continue_token(&token),
+        token => break token.is_some(),
+      }
+    };
+    if let Some(block) = self.view {
+      let mut app = self.app.lock().await;
+      let pages = app.data.list_pages.entry(block).or_default();
+      pages.loaded = loaded;
+      pages.has_more = has_more;
+    }
+    Ok(items)
+  }
+
+  /// Where usage comes from, looking up the metrics API first when that is
+  /// due. A cluster without it is marked unavailable up front, so the usage
+  /// views show a note instead of failing on every poll. A failed lookup
+  /// leaves the source alone and the fetches report their own errors.
+  pub async fn metrics_source(&self) -> MetricsSource {
+    let (source, probed_at) = {
+      let app = self.app.lock().await;
+      (app.data.metrics_source, app.data.metrics_probed_at)
+    };
+    if !source.probe_due(probed_at, Instant::now()) {
+      return source;
+    }
+    match self.client.list_api_groups().await {
+      Ok(groups) if !metrics::serves_metrics_api(&groups) => {
+        info!("metrics.k8s.io is not served, usage is not polled");
+        let mut app = self.app.lock().await;
+        app.set_metrics_unavailable();
+        MetricsSource::Unavailable
+      }
+      Ok(_) => {
+        let mut app = self.app.lock().await;
+        app.data.metrics_probed_at = Some(Instant::now());
+        if source == MetricsSource::Unavailable {
+          app.data.metrics_source = MetricsSource::Unknown;
+        }
+        app.data.metrics_source
+      }
+      Err(e) => {
+        warn!("Failed to look up the metrics API: {}", e);
+        source
+      }
+    }
+  }
+
   pub async fn get_namespaced_api<K>(&self) -> Api<K>
   where
     <K as ApiResource>::DynamicType: Default,
@@ -693,8 +1270,11 @@ impl<'a> Network<'a> {
     let lp = ListParams::default().labels(selector);
     match api.list(&lp).await {
       Ok(list) => {
-        let items: Vec<KubePod> = list.into_iter().map(Pod::into).collect();
+        let mut items: Vec<KubePod> = list.into_iter().map(Pod::into).collect();
         let mut app = self.app.lock().await;
+        if app.data.selected.followed_rollout.is_some() {
+          sort_by_creation(&mut items);
+        }
         app.data.pods.set_items(items);
       }
       Err(e) => {
@@ -714,8 +1294,14 @@ impl<'a> Network<'a> {
     let lp = ListParams::default().fields(&format!("spec.nodeName={}", node_name));
     match api.list(&lp).await {
       Ok(list) => {
-        let items: Vec<KubePod> = list.into_iter().map(Pod::into).collect();
+        let mut items: Vec<KubePod> = list.into_iter().map(Pod::into).collect();
         let mut app = self.app.lock().await;
+        let usage = node_pods::pod_usage(
+          &app.data.metrics.items,
+          &app.utilization_group_by,
+          &app.data.pod_top_usage,
+        );
+        node_pods::sort_pods_by_usage(&mut items, &usage);
         app.data.pods.set_items(items);
       }
       Err(e) => {
@@ -757,25 +1343,75 @@ impl<'a> Network<'a> {
   /// Delete the named resource for the given block via the dynamic `Api`, then
   /// refresh the affected view. Works for any block that maps to a mutable
   /// resource (see [`api_resource_for_block`]).
-  pub async fn delete_resource(&self, block: ActiveBlock, name: &str, namespace: Option<&str>) {
+  /// Dynamic `Api` for the resource kind shown in `block`, scoped to
+  /// `namespace` when the kind is namespaced. `None` for blocks that are not a
+  /// Kubernetes resource kind.
+  pub(crate) async fn dynamic_api_for_block(
+    &self,
+    block: ActiveBlock,
+    namespace: Option<&str>,
+  ) -> Option<Api<DynamicObject>> {
     let dynamic_kind = {
       let app = self.app.lock().await;
       app.data.selected.dynamic_kind.clone()
     };
 
-    let Some((api_resource, scope)) = api_resource_for_block(block, dynamic_kind.as_ref()) else {
+    let (api_resource, scope) = api_resource_for_block(block, dynamic_kind.as_ref())?;
+    Some(match scope {
+      Scope::Cluster => Api::all_with(self.client.clone(), &api_resource),
+      Scope::Namespaced => match namespace {
+        Some(ns) => Api::namespaced_with(self.client.clone(), ns, &api_resource),
+        None => Api::all_with(self.client.clone(), &api_resource),
+      },
+    })
+  }
+
+  /// Fetch the live object and diff it against its last-applied-configuration
+  /// annotation into the describe buffer.
+  pub async fn get_last_applied_diff(
+    &self,
+    block: ActiveBlock,
+    name: &str,
+    namespace: Option<&str>,
+  ) {
+    let Some(api) = self.dynamic_api_for_block(block, namespace).await else {
       self
-        .handle_error(anyhow!("Delete is not supported for this resource."))
+        .handle_error(anyhow!("Diff is not supported for this resource."))
         .await;
       return;
     };
 
-    let api: Api<DynamicObject> = match scope {
-      Scope::Cluster => Api::all_with(self.client.clone(), &api_resource),
-      Scope::Namespaced => match namespace {
-        Some(ns) => Api::namespaced_with(self.client.clone(), ns, &api_resource),
-        None => Api::all_with(self.client.clone(), &api_resource),
+    match api.get(name).await {
+      Ok(obj) => match serde_json::to_value(&obj) {
+        Ok(live) => {
+          let mut app = self.app.lock().await;
+          app.data.describe_out = ScrollableTxt::with_string(last_applied_diff(&live));
+        }
+        Err(e) => {
+          self
+            .handle_error(anyhow!("Failed to read {}. {}", name, e))
+            .await;
+        }
       },
+      Err(e) => {
+        self
+          .handle_error(anyhow!("Failed to get {}. {}", name, e))
+          .await;
+      }
+    }
+  }
+
+  pub async fn delete_resource(
+    &self,
+    block: ActiveBlock,
+    name: &str,
+    namespace: Option<&str>,
+  ) -> bool {
+    let Some(api) = self.dynamic_api_for_block(block, namespace).await else {
+      self
+        .handle_error(anyhow!("Delete is not supported for this resource."))
+        .await;
+      return false;
     };
 
     match api.delete(name, &DeleteParams::default()).await {
@@ -783,42 +1419,78 @@ impl<'a> Network<'a> {
         let mut app = self.app.lock().await;
         app.set_status_message(format!("Deleting {}", name));
         app.dispatch_by_active_block(block).await;
+        true
       }
       Err(e) => {
         self
           .handle_error(anyhow!("Failed to delete {}. {}", name, e))
           .await;
+        false
       }
     }
   }
 
+  /// Delete the rows concurrently, then report how many went through and
+  /// which failed. The selection is cleared either way.
+  pub async fn delete_resources(&self, block: ActiveBlock, rows: Vec<MarkedRow>) {
+    let failures = self.delete_rows(block, &rows).await;
+
+    let mut app = self.app.lock().await;
+    if let Some(table) = app.resource_table_mut(block) {
+      table.clear_marked();
+    }
+    match batch_delete_summary(rows.len(), &failures) {
+      Ok(message) => app.set_status_message(message),
+      Err(message) => app.handle_error(anyhow!(message)),
+    }
+    app.dispatch_by_active_block(block).await;
+  }
+
+  /// Delete the pods at once and report how many went, so their controllers
+  /// bring up fresh ones.
+  pub async fn restart_pods(&self, rows: Vec<MarkedRow>) {
+    let failures = self.delete_rows(ActiveBlock::Pods, &rows).await;
+    let mut app = self.app.lock().await;
+    match restart_pods_summary(rows.len(), &failures) {
+      Ok(message) => app.set_status_message(message),
+      Err(message) => app.handle_error(anyhow!(message)),
+    }
+    app.dispatch_by_active_block(ActiveBlock::Pods).await;
+  }
+
+  /// Delete the rows concurrently, returning `name: error` for each that failed.
+  async fn delete_rows(&self, block: ActiveBlock, rows: &[MarkedRow]) -> Vec<String> {
+    let results = join_all(rows.iter().map(|row| async move {
+      let api = self
+        .dynamic_api_for_block(block, row.namespace.as_deref())
+        .await
+        .ok_or_else(|| anyhow!("Delete is not supported for this resource."))?;
+      api.delete(&row.name, &DeleteParams::default()).await?;
+      anyhow::Ok(())
+    }))
+    .await;
+    rows
+      .iter()
+      .zip(results)
+      .filter_map(|(row, result)| result.err().map(|e| format!("{}: {}", row.name, e)))
+      .collect()
+  }
+
   /// Apply a merge patch to the named resource for the given block via the
-  /// dynamic `Api`, then refresh the affected view.
+  /// dynamic `Api`, then refresh the affected view. Returns whether it was
+  /// applied.
   pub async fn patch_resource(
     &self,
     block: ActiveBlock,
     name: &str,
     namespace: Option<&str>,
     patch: ResourcePatch,
-  ) {
-    let dynamic_kind = {
-      let app = self.app.lock().await;
-      app.data.selected.dynamic_kind.clone()
-    };
-
-    let Some((api_resource, scope)) = api_resource_for_block(block, dynamic_kind.as_ref()) else {
+  ) -> bool {
+    let Some(api) = self.dynamic_api_for_block(block, namespace).await else {
       self
         .handle_error(anyhow!("This action is not supported for this resource."))
         .await;
-      return;
-    };
-
-    let api: Api<DynamicObject> = match scope {
-      Scope::Cluster => Api::all_with(self.client.clone(), &api_resource),
-      Scope::Namespaced => match namespace {
-        Some(ns) => Api::namespaced_with(self.client.clone(), ns, &api_resource),
-        None => Api::all_with(self.client.clone(), &api_resource),
-      },
+      return false;
     };
 
     let body = patch.to_merge_patch();
@@ -830,12 +1502,71 @@ impl<'a> Network<'a> {
         let mut app = self.app.lock().await;
         app.set_status_message(patch.status_message(name));
         app.dispatch_by_active_block(block).await;
+        true
       }
       Err(e) => {
         self
           .handle_error(anyhow!("Failed to update {}. {}", name, e))
           .await;
+        false
+      }
+    }
+  }
+
+  /// Run a step of macro `name` and report how far it got, unless an earlier
+  /// step failed and stopped it.
+  async fn run_macro_step(&self, name: &str, step: usize, steps: usize, event: IoEvent) {
+    let label = {
+      let app = self.app.lock().await;
+      if !app.macro_running(name) {
+        info!("Skipping step {} of stopped macro {}", step, name);
+        return;
+      }
+      event
+        .audit_action(&app)
+        .map(|audit| format!("{} {}", audit.action, audit.target))
+    };
+    let succeeded = match event {
+      IoEvent::DeleteResource {
+        block,
+        name,
+        namespace,
+      } => {
+        self
+          .delete_resource(block, &name, namespace.as_deref())
+          .await
+      }
+      IoEvent::PatchResource {
+        block,
+        name,
+        namespace,
+        patch,
+      } => {
+        self
+          .patch_resource(block, &name, namespace.as_deref(), patch)
+          .await
+      }
+      other => {
+        warn!("Not a macro step: {:?}", other);
+        false
       }
+    };
+
+    let mut app = self.app.lock().await;
+    if !succeeded {
+      app.running_macro = None;
+      app.set_status_message(format!(
+        "Macro {} stopped at step {} of {}",
+        name, step, steps
+      ));
+    } else if step == steps {
+      app.running_macro = None;
+      app.set_status_message(format!("Macro {} done: {} steps", name, steps));
+    } else {
+      app.set_status_message(match label {
+        Some(label) => format!("Macro {} {}/{}: {}", name, step, steps, label),
+        None => format!("Macro {} {}/{}", name, step, steps),
+      });
     }
   }
 
@@ -948,6 +1679,10 @@ impl<'a> Network<'a> {
       "Ingress",
       "Event",
       "NetworkPolicy",
+      "ResourceQuota",
+      "LimitRange",
+      "PodDisruptionBudget",
+      "CustomResourceDefinition",
     ];
 
     for api_group in api_groups {
@@ -989,7 +1724,7 @@ impl<'a> Network<'a> {
   }
 }
 
-fn preferred_group_version(api_group: &DiscoveryApiGroup) -> Option<GroupVersion> {
+pub(crate) fn preferred_group_version(api_group: &DiscoveryApiGroup) -> Option<GroupVersion> {
   api_group
     .preferred_version
     .as_ref()
@@ -1150,6 +1885,25 @@ users:
     );
   }
 
+  #[test]
+  fn test_probe_context_marks_unreachable_server() {
+    let _env_lock = env_lock();
+    let _proxy_env = ProxyEnvGuard::capture();
+    clear_https_proxy_env();
+
+    // Nothing listens on port 1, so the connection is refused right away.
+    let kubeconfig: Kubeconfig = serde_saphyr::from_str(
+      &kubeconfig_with_proxy(None).replace("https://127.0.0.1:6443", "https://127.0.0.1:1"),
+    )
+    .expect("kubeconfig should deserialize");
+
+    let health = tokio::runtime::Runtime::new()
+      .expect("runtime should build")
+      .block_on(probe_context(kubeconfig, "test-context".into()));
+
+    assert_eq!(health, ContextHealth::Unreachable);
+  }
+
   #[test]
   fn test_load_client_config_from_kubeconfig_uses_https_proxy_env_var() {
     let _env_lock = env_lock();
@@ -1483,6 +2237,120 @@ users:
     fs::remove_dir_all(dir).expect("temp test dir should be removed");
   }
 
+  #[allow(clippy::await_holding_lock)]
+  #[tokio::test]
+  async fn test_contexts_from_every_kubeconfig_file_are_listed_and_switchable() {
+    let _env_lock = env_lock();
+    let previous_kubeconfig = env::var_os("KUBECONFIG");
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data");
+    let joined = env::join_paths([
+      fixtures.join("kubeconfig_a.yaml"),
+      fixtures.join("kubeconfig_b.yaml"),
+    ])
+    .unwrap();
+    env::set_var("KUBECONFIG", &joined);
+
+    let client = get_client(None)
+      .await
+      .expect("merged kubeconfig should produce a client");
+    let app = Arc::new(Mutex::new(App::default()));
+    let network = Network::new(client, &app);
+    network.get_kube_config().await;
+    {
+      let app = app.lock().await;
+      let contexts: Vec<_> = app
+        .data
+        .contexts
+        .items
+        .iter()
+        .map(|ctx| (ctx.name.as_str(), ctx.cluster.as_str(), ctx.is_active))
+        .collect();
+      // like kubectl, the first file to name a context or set the current one wins
+      assert_eq!(
+        contexts,
+        vec![("ctx-a", "cluster-a", true), ("ctx-b", "cluster-b", false)]
+      );
+    }
+
+    app.lock().await.data.selected.context = Some("ctx-b".into());
+    network.get_kube_config().await;
+    assert_eq!(
+      app
+        .lock()
+        .await
+        .data
+        .active_context
+        .as_ref()
+        .map(|ctx| ctx.name.as_str()),
+      Some("ctx-b")
+    );
+    let config = load_client_config(Some("ctx-b".into()))
+      .await
+      .expect("a context of the second file should load");
+    assert_eq!(config.cluster_url.host(), Some("b.example.com"));
+    assert!(get_client(Some("ctx-b".into())).await.is_ok());
+
+    match previous_kubeconfig {
+      Some(value) => env::set_var("KUBECONFIG", value),
+      None => env::remove_var("KUBECONFIG"),
+    }
+  }
+
+  #[allow(clippy::await_holding_lock)]
+  #[tokio::test]
+  async fn test_switching_context_selects_its_kubeconfig_namespace() {
+    let _env_lock = env_lock();
+    let previous_kubeconfig = env::var_os("KUBECONFIG");
+    let fixture = fs::read_to_string(
+      Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data/kubeconfig_namespaces.yaml"),
+    )
+    .unwrap();
+    let dir = temp_test_dir("context-namespace");
+    let file = dir.join("config");
+    write_kubeconfig(&file, &fixture);
+    env::set_var("KUBECONFIG", &file);
+
+    let client = get_client(None)
+      .await
+      .expect("fixture kubeconfig should produce a client");
+    let app = Arc::new(Mutex::new(App::default()));
+    let network = Network::new(client, &app);
+    network.get_kube_config().await;
+    {
+      let mut app = app.lock().await;
+      app.refresh = false;
+      app.data.selected.ns = Some("team-a".into());
+    }
+
+    // the current context is switched outside KDash, e.g. by kubectx
+    write_kubeconfig(
+      &file,
+      &fixture.replace("current-context: dev", "current-context: staging"),
+    );
+    network.get_kube_config().await;
+    {
+      let app = app.lock().await;
+      assert!(app.refresh);
+      assert_eq!(app.data.selected.ns.as_deref(), Some("web"));
+    }
+
+    // switching to a context without a namespace keeps the selection
+    app.lock().await.refresh = false;
+    write_kubeconfig(&file, &fixture);
+    network.get_kube_config().await;
+    {
+      let app = app.lock().await;
+      assert!(app.refresh);
+      assert_eq!(app.data.selected.ns.as_deref(), Some("web"));
+    }
+
+    match previous_kubeconfig {
+      Some(value) => env::set_var("KUBECONFIG", value),
+      None => env::remove_var("KUBECONFIG"),
+    }
+    fs::remove_dir_all(dir).expect("temp test dir should be removed");
+  }
+
   #[test]
   fn test_preferred_group_version_uses_preferred_version() {
     let api_group = DiscoveryApiGroup {
@@ -1596,6 +2464,196 @@ users:
     assert_eq!(app.get_current_route().active_block, ActiveBlock::Services);
     assert_eq!(app.data.selected.context.as_deref(), Some("test-context"));
     assert_eq!(app.data.selected.ns.as_deref(), Some("team-a"));
+    assert_eq!(app.connecting_to, None);
+    assert_eq!(app.status_message.text(), "Connected to test-context");
+
+    match previous_kubeconfig {
+      Some(value) => env::set_var("KUBECONFIG", value),
+      None => env::remove_var("KUBECONFIG"),
+    }
+    fs::remove_dir_all(dir).expect("temp test dir should be removed");
+  }
+
+  #[allow(clippy::await_holding_lock)]
+  #[tokio::test]
+  async fn test_failed_switch_stays_on_the_previous_context() {
+    let _env_lock = env_lock();
+    let previous_kubeconfig = env::var_os("KUBECONFIG");
+    let dir = temp_test_dir("failed-switch");
+    let kubeconfig_path = dir.join("config");
+    write_kubeconfig(&kubeconfig_path, valid_kubeconfig());
+    env::set_var("KUBECONFIG", &kubeconfig_path);
+
+    let client = get_client(None)
+      .await
+      .expect("test kubeconfig should produce a client");
+    let app = Arc::new(Mutex::new(App::default()));
+    let mut network = Network::new(client, &app);
+    network.context = Some("test-context".into());
+    {
+      let mut app = app.lock().await;
+      app.data.selected.context = Some("missing".into());
+      app.connecting_to = Some("missing".into());
+    }
+
+    // queued before the switch, so dropped instead of sent to the old cluster
+    network.handle_network_event(IoEvent::GetNamespaces).await;
+    assert_eq!(app.lock().await.api_error, "");
+
+    network.handle_network_event(IoEvent::RefreshClient).await;
+    {
+      let app = app.lock().await;
+      assert_eq!(app.connecting_to, None);
+      assert_eq!(app.data.selected.context.as_deref(), Some("test-context"));
+      assert!(app
+        .api_error
+        .starts_with("Failed to connect to missing, staying on test-context."));
+    }
+    assert_eq!(network.context.as_deref(), Some("test-context"));
+
+    match previous_kubeconfig {
+      Some(value) => env::set_var("KUBECONFIG", value),
+      None => env::remove_var("KUBECONFIG"),
+    }
+    fs::remove_dir_all(dir).expect("temp test dir should be removed");
+  }
+
+  #[allow(clippy::await_holding_lock)]
+  #[tokio::test]
+  async fn test_recover_auth_rebuilds_the_client_once() {
+    let _env_lock = env_lock();
+    let previous_kubeconfig = env::var_os("KUBECONFIG");
+    let dir = temp_test_dir("recover-auth");
+    let kubeconfig_path = dir.join("config");
+    write_kubeconfig(&kubeconfig_path, valid_kubeconfig());
+    env::set_var("KUBECONFIG", &kubeconfig_path);
+
+    let client = get_client(None)
+      .await
+      .expect("test kubeconfig should produce a client");
+    let app = Arc::new(Mutex::new(App::default()));
+    app
+      .lock()
+      .await
+      .handle_error(anyhow!("Failed to get resource Pods. Unauthorized"));
+    assert!(app.lock().await.take_auth_failure());
+    assert!(!app.lock().await.take_auth_failure());
+
+    let mut network = Network::new(client, &app);
+    network.recover_auth().await;
+    assert!(
+      !app.lock().await.auth_expired,
+      "the first failure rebuilds the client"
+    );
+    network.recover_auth().await;
+    assert!(
+      app.lock().await.auth_expired,
+      "a failure after the rebuild gives up"
+    );
+
+    match previous_kubeconfig {
+      Some(value) => env::set_var("KUBECONFIG", value),
+      None => env::remove_var("KUBECONFIG"),
+    }
+    fs::remove_dir_all(dir).expect("temp test dir should be removed");
+  }
+
+  #[allow(clippy::await_holding_lock)]
+  #[tokio::test]
+  async fn test_recover_auth_refreshes_the_stream_client_too() {
+    let _env_lock = env_lock();
+    let previous_kubeconfig = env::var_os("KUBECONFIG");
+    let dir = temp_test_dir("recover-auth-stream");
+    let kubeconfig_path = dir.join("config");
+    write_kubeconfig(&kubeconfig_path, valid_kubeconfig());
+    env::set_var("KUBECONFIG", &kubeconfig_path);
+
+    let client = get_client(None)
+      .await
+      .expect("test kubeconfig should produce a client");
+    let (io_tx, _io_rx) = tokio::sync::mpsc::channel(10);
+    let (stream_tx, mut stream_rx) = tokio::sync::mpsc::channel(10);
+    let (cmd_tx, _cmd_rx) = tokio::sync::mpsc::channel(10);
+    let app = Arc::new(Mutex::new(App::new(
+      io_tx,
+      stream_tx,
+      cmd_tx,
+      false,
+      1,
+      100,
+      Default::default(),
+    )));
+    let mut network = Network::new(client, &app);
+    network.recover_auth().await;
+    assert_eq!(stream_rx.try_recv(), Ok(IoStreamEvent::RefreshClient));
+
+    match previous_kubeconfig {
+      Some(value) => env::set_var("KUBECONFIG", value),
+      None => env::remove_var("KUBECONFIG"),
+    }
+    fs::remove_dir_all(dir).expect("temp test dir should be removed");
+  }
+
+  #[tokio::test]
+  async fn test_only_errors_of_the_handled_event_count_against_it() {
+    let mut app = App::default();
+    // reported by another thread, outside of any event
+    app.handle_error(anyhow!("Log stream dropped"));
+    let handled = |fail: bool| {
+      EVENT_FAILED.scope(Cell::new(false), async move {
+        if fail {
+          App::default().handle_error(anyhow!("Failed to get resource Pods"));
+        }
+        EVENT_FAILED.with(Cell::get)
+      })
+    };
+    assert!(!handled(false).await);
+    assert!(handled(true).await);
+  }
+
+  #[allow(clippy::await_holding_lock)]
+  #[tokio::test]
+  async fn test_a_failed_macro_step_stops_the_macro() {
+    let _env_lock = env_lock();
+    let previous_kubeconfig = env::var_os("KUBECONFIG");
+    let dir = temp_test_dir("macro-step");
+    let kubeconfig_path = dir.join("config");
+    write_kubeconfig(&kubeconfig_path, valid_kubeconfig());
+    env::set_var("KUBECONFIG", &kubeconfig_path);
+
+    let client = get_client(None)
+      .await
+      .expect("test kubeconfig should produce a client");
+    let app = Arc::new(Mutex::new(App::default()));
+    app.lock().await.running_macro = Some("cleanup".into());
+    let mut network = Network::new(client, &app);
+    // a view that cannot be deleted from fails without reaching the cluster
+    let step = |step| IoEvent::MacroStep {
+      name: "cleanup".into(),
+      step,
+      steps: 2,
+      event: Box::new(IoEvent::DeleteResource {
+        block: ActiveBlock::Help,
+        name: "web".into(),
+        namespace: None,
+      }),
+    };
+
+    network.handle_network_event(step(1)).await;
+    {
+      let app = app.lock().await;
+      assert_eq!(app.running_macro, None);
+      assert_eq!(
+        app.status_message.text(),
+        "Macro cleanup stopped at step 1 of 2"
+      );
+    }
+    network.handle_network_event(step(2)).await;
+    assert_eq!(
+      app.lock().await.status_message.text(),
+      "Macro cleanup stopped at step 1 of 2",
+      "later steps are skipped"
+    );
 
     match previous_kubeconfig {
       Some(value) => env::set_var("KUBECONFIG", value),
@@ -1603,4 +2661,53 @@ users:
     }
     fs::remove_dir_all(dir).expect("temp test dir should be removed");
   }
+
+  #[test]
+  fn test_view_block_maps_fetches_to_their_view() {
+    assert_eq!(IoEvent::GetPods.view_block(), Some(ActiveBlock::Pods));
+    assert_eq!(
+      IoEvent::GetPodsByNode {
+        node_name: "node-1".into()
+      }
+      .view_block(),
+      Some(ActiveBlock::Pods)
+    );
+    assert_eq!(
+      IoEvent::GetNodePodSummary.view_block(),
+      Some(ActiveBlock::PodsByNode)
+    );
+    assert_eq!(IoEvent::RefreshClient.view_block(), None);
+    assert_eq!(
+      IoEvent::TriggerCronJob {
+        name: "backup".into(),
+        namespace: "default".into()
+      }
+      .view_block(),
+      None
+    );
+  }
+
+  #[test]
+  fn test_batch_delete_summary() {
+    assert_eq!(
+      batch_delete_summary(3, &[]),
+      Ok("Deleting 3 resources".to_string())
+    );
+    assert_eq!(
+      batch_delete_summary(3, &["web-1: forbidden".into()]),
+      Err("Deleted 2 of 3 resources. Failed: web-1: forbidden".to_string())
+    );
+  }
+
+  #[test]
+  fn test_restart_pods_summary() {
+    assert_eq!(
+      restart_pods_summary(2, &[]),
+      Ok("Restarting 2 crash-looping pods".to_string())
+    );
+    assert_eq!(
+      restart_pods_summary(2, &["web-1: forbidden".into()]),
+      Err("Restarted 1 of 2 crash-looping pods. Failed: web-1: forbidden".to_string())
+    );
+  }
 }
@@ -1,10 +1,17 @@
-use std::{collections::HashSet, process::Stdio, sync::Arc, time::Duration};
+use std::{
+  collections::{BTreeMap, HashSet, VecDeque},
+  future::Future,
+  process::Stdio,
+  sync::Arc,
+  time::Duration,
+};
 
 use anyhow::anyhow;
-use futures::AsyncBufReadExt;
-use k8s_openapi::api::core::v1::Pod;
+use futures::{future::join_all, AsyncBufReadExt};
+use k8s_openapi::api::core::v1::{Event, Pod};
 use kube::{
-  api::{ListParams, LogParams},
+  api::{DynamicObject, ListParams, LogParams, WatchEvent, WatchParams},
+  discovery::Scope,
   Api, Client,
 };
 use log::{debug, error, info, warn};
@@ -12,20 +19,34 @@ use tokio::{
   io::{AsyncBufReadExt as TokioAsyncBufReadExt, AsyncReadExt, BufReader},
   process::{ChildStderr, ChildStdout, Command},
   sync::Mutex,
+  task::AbortHandle,
   time::Instant,
 };
 use tokio_stream::StreamExt;
 
 use super::refresh_kube_config;
 use crate::app::port_forward::PortForwardStatus;
-use crate::app::App;
+use crate::app::{
+  apply_status::{block_for_kind, APPLY_WATCH_TIMEOUT},
+  dynamic::api_resource_for_block,
+  events::{KubeEvent, WatchedEvents},
+  manifest::ManifestObject,
+  pods::{KubePod, WatchedPod},
+  utils::sanitize_error_message,
+  watchlist::PinStatus,
+  ActiveBlock, App,
+};
 use crate::cmd::port_forward::{prepare_port_forward, PortForwardTarget};
 const BATCH_SIZE: usize = 50;
 const BATCH_FLUSH_MS: u64 = 100;
 const RECONNECT_OVERLAP_SECS: i64 = 5;
 const MAX_RECONNECT_ATTEMPTS: u32 = 10;
+const RECONNECT_BASE_BACKOFF_MS: u64 = 500;
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
 const DEDUP_WINDOW: usize = 50;
 const MAX_AGGREGATE_PODS: usize = 20;
+/// How often an aggregate stream lists its pods again to follow a rollout.
+const AGGREGATE_RESYNC: Duration = Duration::from_secs(5);
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IoStreamEvent {
@@ -37,6 +58,13 @@ pub enum IoStreamEvent {
     selector: String,
   },
   GetPodAllContainerLogs,
+  /// Open the logs of the newest ready pod of a workload or service.
+  JumpToLogs {
+    name: String,
+    namespace: String,
+    selector: String,
+    resource_name: String,
+  },
   StartPortForward {
     kind: String,
     namespace: String,
@@ -47,6 +75,21 @@ pub enum IoStreamEvent {
   StopPortForward {
     id: u64,
   },
+  /// Keep one pod up to date with a watch while its containers are shown.
+  WatchPod {
+    namespace: String,
+    name: String,
+  },
+  /// Add events as they happen while the events view is shown; `None` is
+  /// all namespaces.
+  WatchEvents {
+    namespace: Option<String>,
+  },
+  /// Watch the objects of apply `id` until they are ready.
+  WatchApplied {
+    id: u64,
+    objects: Vec<ManifestObject>,
+  },
 }
 
 #[derive(Clone)]
@@ -97,6 +140,16 @@ impl<'a> NetworkStream<'a> {
       IoStreamEvent::GetPodAllContainerLogs => {
         self.stream_pod_all_container_logs().await;
       }
+      IoStreamEvent::JumpToLogs {
+        name,
+        namespace,
+        selector,
+        resource_name,
+      } => {
+        self
+          .jump_to_logs(&name, &namespace, &selector, resource_name)
+          .await;
+      }
       IoStreamEvent::StartPortForward {
         kind,
         namespace,
@@ -111,6 +164,27 @@ impl<'a> NetworkStream<'a> {
       IoStreamEvent::StopPortForward { id } => {
         self.stop_port_forward(id).await;
       }
+      IoStreamEvent::WatchPod { namespace, name } => {
+        let client = self.client.clone();
+        let app = Arc::clone(self.app);
+        tokio::spawn(async move {
+          watch_pod(client, app, WatchedPod { namespace, name }).await;
+        });
+      }
+      IoStreamEvent::WatchEvents { namespace } => {
+        let client = self.client.clone();
+        let app = Arc::clone(self.app);
+        tokio::spawn(async move {
+          watch_events(client, app, WatchedEvents { namespace }).await;
+        });
+      }
+      IoStreamEvent::WatchApplied { id, objects } => {
+        let client = self.client.clone();
+        let app = Arc::clone(self.app);
+        tokio::spawn(async move {
+          watch_applied(client, app, id, objects).await;
+        });
+      }
     };
 
     let mut app = self.app.lock().await;
@@ -124,7 +198,7 @@ impl<'a> NetworkStream<'a> {
   }
 
   pub async fn stream_container_logs(&self, tail: bool) {
-    let (namespace, pod_name, cont_name, tail_lines, timestamps, cancel_rx) = {
+    let (namespace, pod_name, cont_name, tail_lines, since_window, timestamps, cancel_rx) = {
       let app = self.app.lock().await;
       let ns = app
         .data
@@ -140,9 +214,10 @@ impl<'a> NetworkStream<'a> {
         .unwrap_or_default();
       let cont = app.data.selected.container.clone().unwrap_or_default();
       let tail_lines = app.initial_log_tail_lines();
+      let since_window = app.log_since_seconds();
       let timestamps = app.log_timestamps;
       let rx = app.new_log_cancel_rx();
-      (ns, pod, cont, tail_lines, timestamps, rx)
+      (ns, pod, cont, tail_lines, since_window, timestamps, rx)
     };
 
     if pod_name.is_empty() || cont_name.is_empty() {
@@ -157,29 +232,30 @@ impl<'a> NetworkStream<'a> {
     let api: Api<Pod> = Api::namespaced(self.client.clone(), &namespace);
     let mut since_seconds: Option<i64> = None;
     let mut reconnect_count: u32 = 0;
+    let mut reconnecting = false;
+    let mut last_line_at = Instant::now();
+    let mut cancel_rx = cancel_rx;
 
     loop {
       let lp = LogParams {
         container: Some(cont_name.clone()),
         follow: true,
         previous: false,
-        tail_lines: if since_seconds.is_none() && tail {
+        tail_lines: if since_seconds.is_none() && since_window.is_none() && tail {
           Some(tail_lines)
         } else {
           None
         },
-        since_seconds,
+        since_seconds: since_seconds.or(since_window),
         timestamps,
         ..Default::default()
       };
 
       match api.log_stream(&pod_name, &lp).await {
         Ok(logs) => {
-          reconnect_count = 0;
           let mut lines_stream = logs.lines();
           let mut batch: Vec<String> = Vec::with_capacity(BATCH_SIZE);
           let mut last_flush = Instant::now();
-          let mut cancel_rx = cancel_rx.clone();
 
           // Build dedup set from existing records
           let dedup_set: HashSet<String> = if since_seconds.is_some() {
@@ -195,7 +271,7 @@ impl<'a> NetworkStream<'a> {
             HashSet::new()
           };
 
-          loop {
+          let dropped = loop {
             let flush_deadline =
               tokio::time::sleep_until(last_flush + Duration::from_millis(BATCH_FLUSH_MS));
 
@@ -206,7 +282,7 @@ impl<'a> NetworkStream<'a> {
                   // Flush remaining batch before exiting
                   if !batch.is_empty() {
                     let mut app = self.app.lock().await;
-                    app.data.logs.add_records(batch);
+                    app.add_log_records(batch);
                   }
                   debug!("Log stream cancelled for {}/{}", pod_name, cont_name);
                   let mut app = self.app.lock().await;
@@ -218,6 +294,10 @@ impl<'a> NetworkStream<'a> {
               line = lines_stream.next() => {
                 match line {
                   Some(Ok(line)) => {
+                    last_line_at = Instant::now();
+                    // Only a stream that delivers counts as reconnected.
+                    reconnect_count = 0;
+                    mark_reconnecting(self.app, &mut reconnecting, false).await;
                     let line = line.trim().to_string();
                     if !line.is_empty() {
                       // Skip duplicates on reconnect
@@ -228,7 +308,7 @@ impl<'a> NetworkStream<'a> {
 
                       if batch.len() >= BATCH_SIZE {
                         let mut app = self.app.lock().await;
-                        app.data.logs.add_records(std::mem::replace(
+                        app.add_log_records(std::mem::replace(
                           &mut batch,
                           Vec::with_capacity(BATCH_SIZE),
                         ));
@@ -238,11 +318,11 @@ impl<'a> NetworkStream<'a> {
                   }
                   Some(Err(e)) => {
                     warn!("Log stream read error for {}/{}: {}", pod_name, cont_name, e);
-                    break; // Break inner loop to reconnect
+                    break true; // Break inner loop to reconnect
                   }
                   None => {
                     debug!("Log stream ended for {}/{}", pod_name, cont_name);
-                    break; // Break inner loop to reconnect
+                    break false;
                   }
                 }
               }
@@ -250,7 +330,7 @@ impl<'a> NetworkStream<'a> {
               _ = flush_deadline => {
                 if !batch.is_empty() {
                   let mut app = self.app.lock().await;
-                  app.data.logs.add_records(std::mem::replace(
+                  app.add_log_records(std::mem::replace(
                     &mut batch,
                     Vec::with_capacity(BATCH_SIZE),
                   ));
@@ -258,12 +338,29 @@ impl<'a> NetworkStream<'a> {
                 }
               }
             }
-          }
+          };
 
           // Flush any remaining lines after inner loop break
           if !batch.is_empty() {
             let mut app = self.app.lock().await;
-            app.data.logs.add_records(batch);
+            app.add_log_records(batch);
+          }
+          // A stream the server ends cleanly is done, like a container that
+          // has exited; only a dropped one is reopened.
+          if !dropped || container_terminated(&api, &pod_name, &cont_name).await {
+            break;
+          }
+          reconnect_count += 1;
+          if reconnect_count >= MAX_RECONNECT_ATTEMPTS {
+            self
+              .handle_error(anyhow!(
+                "Log stream for {}/{} kept dropping after {} attempts",
+                pod_name,
+                cont_name,
+                MAX_RECONNECT_ATTEMPTS
+              ))
+              .await;
+            break;
           }
         }
         Err(e) => {
@@ -289,19 +386,23 @@ impl<'a> NetworkStream<'a> {
       if *cancel_rx.borrow() {
         break;
       }
+      mark_reconnecting(self.app, &mut reconnecting, true).await;
 
-      // Ask Kubernetes for a small overlap window so reconnects only refetch recent logs.
-      since_seconds = Some(RECONNECT_OVERLAP_SECS);
+      // Resume from the last line received, with a small overlap that the
+      // dedup set drops again, instead of re-dumping the tail.
+      since_seconds = Some(resume_since_seconds(last_line_at.elapsed()));
 
-      // Backoff before reconnecting
-      let backoff = Duration::from_millis(500 * (reconnect_count as u64).min(4));
+      let backoff = reconnect_backoff(reconnect_count);
       debug!(
         "Reconnecting log stream for {}/{} (attempt {}, backoff {:?})",
         pod_name, cont_name, reconnect_count, backoff
       );
-      tokio::time::sleep(backoff).await;
+      if !sleep_unless_cancelled(backoff, &mut cancel_rx).await {
+        break;
+      }
     }
 
+    mark_reconnecting(self.app, &mut reconnecting, false).await;
     let mut app = self.app.lock().await;
     app.is_streaming = false;
   }
@@ -349,12 +450,12 @@ impl<'a> NetworkStream<'a> {
         let lines: Vec<String> = logs.lines().map(|line| line.to_string()).collect();
         let mut app = self.app.lock().await;
         if lines.is_empty() {
-          app.data.logs.add_records(vec![format!(
+          app.add_log_records(vec![format!(
             "[kdash] No previous logs for container {} (it has not restarted)",
             cont_name
           )]);
         } else {
-          app.data.logs.add_records(lines);
+          app.add_log_records(lines);
         }
       }
       Err(e) => {
@@ -363,7 +464,7 @@ impl<'a> NetworkStream<'a> {
           pod_name, cont_name, e
         );
         let mut app = self.app.lock().await;
-        app.data.logs.add_records(vec![format!(
+        app.add_log_records(vec![format!(
           "[kdash] No previous logs available for {} (the container has no prior terminated instance)",
           cont_name
         )]);
@@ -373,7 +474,7 @@ impl<'a> NetworkStream<'a> {
 
   /// Stream logs from all containers of the selected pod concurrently.
   pub async fn stream_pod_all_container_logs(&self) {
-    let (namespace, pod_name, container_names, tail_lines, timestamps, cancel_rx) = {
+    let (namespace, pod_name, container_names, tail_lines, since_seconds, timestamps, cancel_rx) = {
       let app = self.app.lock().await;
       let pod = app.data.pods.get_selected_item_copy();
       let ns = pod
@@ -387,7 +488,15 @@ impl<'a> NetworkStream<'a> {
         .unwrap_or_default();
       let tail_lines = app.initial_log_tail_lines();
       let rx = app.new_log_cancel_rx();
-      (ns, name, containers, tail_lines, app.log_timestamps, rx)
+      (
+        ns,
+        name,
+        containers,
+        tail_lines,
+        app.log_since_seconds(),
+        app.log_timestamps,
+        rx,
+      )
     };
 
     if pod_name.is_empty() || container_names.is_empty() {
@@ -411,6 +520,11 @@ impl<'a> NetworkStream<'a> {
 
     let (tx, mut rx) = tokio::sync::mpsc::channel::<String>(256);
 
+    let options = LogStreamOptions {
+      tail_lines,
+      since_seconds,
+      timestamps,
+    };
     let mut join_set = tokio::task::JoinSet::new();
     for cont_name in container_names {
       let client = self.client.clone();
@@ -418,6 +532,7 @@ impl<'a> NetworkStream<'a> {
       let pod = pod_name.clone();
       let tx = tx.clone();
       let cancel_rx = cancel_rx.clone();
+      let app = self.app.clone();
 
       join_set.spawn(async move {
         stream_single_pod_for_aggregate(
@@ -428,10 +543,10 @@ impl<'a> NetworkStream<'a> {
             container_name: cont_name.clone(),
             short_name: cont_name,
           },
-          tail_lines,
-          timestamps,
+          options,
           tx,
           cancel_rx,
+          app,
         )
         .await;
       });
@@ -452,7 +567,7 @@ impl<'a> NetworkStream<'a> {
           if *cancel_rx_collector.borrow() {
             if !batch.is_empty() {
               let mut app = self.app.lock().await;
-              app.data.logs.add_records(batch);
+              app.add_log_records(batch);
             }
             break;
           }
@@ -463,7 +578,7 @@ impl<'a> NetworkStream<'a> {
               batch.push(line);
               if batch.len() >= BATCH_SIZE {
                 let mut app = self.app.lock().await;
-                app.data.logs.add_records(std::mem::replace(
+                app.add_log_records(std::mem::replace(
                   &mut batch,
                   Vec::with_capacity(BATCH_SIZE),
                 ));
@@ -473,7 +588,7 @@ impl<'a> NetworkStream<'a> {
             None => {
               if !batch.is_empty() {
                 let mut app = self.app.lock().await;
-                app.data.logs.add_records(batch);
+                app.add_log_records(batch);
               }
               break;
             }
@@ -482,7 +597,7 @@ impl<'a> NetworkStream<'a> {
         _ = flush_deadline => {
           if !batch.is_empty() {
             let mut app = self.app.lock().await;
-            app.data.logs.add_records(std::mem::replace(
+            app.add_log_records(std::mem::replace(
               &mut batch,
               Vec::with_capacity(BATCH_SIZE),
             ));
@@ -495,15 +610,63 @@ impl<'a> NetworkStream<'a> {
     join_set.shutdown().await;
     let mut app = self.app.lock().await;
     app.is_streaming = false;
+    // Aborted streams never get to count themselves out.
+    app.log_reconnecting = 0;
+  }
+
+  /// List the pods matching a workload's or service's selector and stream the
+  /// logs of the representative one, see [`representative_pod`].
+  pub async fn jump_to_logs(
+    &self,
+    name: &str,
+    namespace: &str,
+    selector: &str,
+    resource_name: String,
+  ) {
+    let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+    let lp = ListParams::default().labels(selector);
+    let pods = match api.list(&lp).await {
+      Ok(list) => list.items,
+      Err(e) => {
+        self
+          .handle_error(anyhow!(
+            "Failed to list pods for selector '{}': {}",
+            selector,
+            e
+          ))
+          .await;
+        return;
+      }
+    };
+
+    {
+      let mut app = self.app.lock().await;
+      let Some(index) = representative_pod(&pods) else {
+        app.set_status_message(format!("No pods found for {} {}", resource_name, name));
+        return;
+      };
+      let items: Vec<KubePod> = pods.into_iter().map(Pod::into).collect();
+      app.open_representative_pod_logs(
+        namespace.to_owned(),
+        selector.to_owned(),
+        resource_name,
+        items,
+        index,
+      );
+    }
+    self.stream_pod_all_container_logs().await;
   }
 
   /// Stream logs from all pods matching a label selector concurrently.
-  /// Lines are prefixed with the pod name for disambiguation.
+  /// Lines are prefixed with the pod name for disambiguation. The pods are
+  /// listed again every [`AGGREGATE_RESYNC`], so a rollout's new pods join
+  /// the stream and the ones it removed leave it.
   pub async fn stream_aggregate_logs(&self, namespace: &str, selector: &str) {
-    let (tail_lines, timestamps, cancel_rx) = {
+    let (tail_lines, since_seconds, timestamps, cancel_rx) = {
       let app = self.app.lock().await;
       (
         app.initial_log_tail_lines(),
+        app.log_since_seconds(),
         app.log_timestamps,
         app.new_log_cancel_rx(),
       )
@@ -528,20 +691,14 @@ impl<'a> NetworkStream<'a> {
 
     if pods.is_empty() {
       let mut app = self.app.lock().await;
-      app
-        .data
-        .logs
-        .add_records(vec!["[kdash] No pods found for this resource".to_string()]);
+      app.add_log_records(vec!["[kdash] No pods found for this resource".to_string()]);
       return;
     }
 
     let total_pods = pods.len();
-    let pods: Vec<Pod> = pods.into_iter().take(MAX_AGGREGATE_PODS).collect();
-    let streaming_count = pods.len();
-
     if total_pods > MAX_AGGREGATE_PODS {
       let mut app = self.app.lock().await;
-      app.data.logs.add_records(vec![format!(
+      app.add_log_records(vec![format!(
         "[kdash] Showing logs from {} of {} pods",
         MAX_AGGREGATE_PODS, total_pods
       )]);
@@ -552,50 +709,64 @@ impl<'a> NetworkStream<'a> {
       app.is_streaming = true;
     }
 
-    let pod_info = collect_pod_container_info(&pods);
-
-    info!(
-      "Starting aggregate log stream for {} container streams (selector: {})",
-      pod_info.len(),
-      selector
-    );
-
     // Use a channel to collect lines from all pod streams
     let (tx, mut rx) = tokio::sync::mpsc::channel::<String>(256);
 
-    // Spawn a task per container
+    let options = LogStreamOptions {
+      tail_lines,
+      since_seconds,
+      timestamps,
+    };
+    // A task per container, with the abort handles of each pod's tasks so a
+    // pod that goes away can be dropped from the stream.
     let mut join_set = tokio::task::JoinSet::new();
-    for (pod_name, cont_name, prefix) in pod_info {
-      let client = self.client.clone();
-      let ns = namespace.to_string();
-      let tx = tx.clone();
-      let cancel_rx = cancel_rx.clone();
+    let mut streamed: BTreeMap<String, Vec<AbortHandle>> = BTreeMap::new();
+    let spawn_pod_streams = |join_set: &mut tokio::task::JoinSet<()>, pod: &Pod| {
+      collect_pod_container_info(std::slice::from_ref(pod))
+        .into_iter()
+        .map(|(pod_name, cont_name, prefix)| {
+          let client = self.client.clone();
+          let ns = namespace.to_string();
+          let tx = tx.clone();
+          let cancel_rx = cancel_rx.clone();
+          let app = self.app.clone();
+          join_set.spawn(async move {
+            stream_single_pod_for_aggregate(
+              client,
+              AggregateStreamTarget {
+                namespace: ns,
+                pod_name,
+                container_name: cont_name,
+                short_name: prefix,
+              },
+              options,
+              tx,
+              cancel_rx,
+              app,
+            )
+            .await;
+          })
+        })
+        .collect::<Vec<_>>()
+    };
 
-      join_set.spawn(async move {
-        stream_single_pod_for_aggregate(
-          client,
-          AggregateStreamTarget {
-            namespace: ns,
-            pod_name,
-            container_name: cont_name,
-            short_name: prefix,
-          },
-          tail_lines,
-          timestamps,
-          tx,
-          cancel_rx,
-        )
-        .await;
-      });
+    let (joined, _) = aggregate_pod_changes(&streamed, &pods);
+    for pod in joined {
+      let handles = spawn_pod_streams(&mut join_set, pod);
+      streamed.insert(pod.metadata.name.clone().unwrap_or_default(), handles);
     }
 
-    // Drop our copy of tx so rx will close when all tasks finish
-    drop(tx);
+    info!(
+      "Starting aggregate log stream for {} pods (selector: {})",
+      streamed.len(),
+      selector
+    );
 
     // Collector loop: read from channel, batch-flush to app.data.logs
     let mut batch: Vec<String> = Vec::with_capacity(BATCH_SIZE);
     let mut last_flush = Instant::now();
     let mut cancel_rx_collector = cancel_rx.clone();
+    let mut resync = tokio::time::interval_at(Instant::now() + AGGREGATE_RESYNC, AGGREGATE_RESYNC);
 
     loop {
       let flush_deadline =
@@ -606,7 +777,7 @@ impl<'a> NetworkStream<'a> {
           if *cancel_rx_collector.borrow() {
             if !batch.is_empty() {
               let mut app = self.app.lock().await;
-              app.data.logs.add_records(batch);
+              app.add_log_records(batch);
             }
             debug!("Aggregate log stream cancelled (selector: {})", selector);
             break;
@@ -618,27 +789,41 @@ impl<'a> NetworkStream<'a> {
               batch.push(line);
               if batch.len() >= BATCH_SIZE {
                 let mut app = self.app.lock().await;
-                app.data.logs.add_records(std::mem::replace(
+                app.add_log_records(std::mem::replace(
                   &mut batch,
                   Vec::with_capacity(BATCH_SIZE),
                 ));
                 last_flush = Instant::now();
               }
             }
-            None => {
-              // All senders dropped — all pod streams finished
-              if !batch.is_empty() {
-                let mut app = self.app.lock().await;
-                app.data.logs.add_records(batch);
-              }
-              break;
+            // Our own sender is held for pods that join later
+            None => break,
+          }
+        }
+        _ = resync.tick() => {
+          while join_set.try_join_next().is_some() {}
+          // A failed listing keeps the current streams until the next one
+          let Ok(list) = api.list(&lp).await else {
+            continue;
+          };
+          let (joined, left) = aggregate_pod_changes(&streamed, &list.items);
+          for name in left {
+            if let Some(handles) = streamed.remove(&name) {
+              handles.iter().for_each(AbortHandle::abort);
+              batch.push(format!("[kdash] {} left", short_pod_name(&name)));
             }
           }
+          for pod in joined {
+            let name = pod.metadata.name.clone().unwrap_or_default();
+            batch.push(format!("[kdash] {} joined", short_pod_name(&name)));
+            let handles = spawn_pod_streams(&mut join_set, pod);
+            streamed.insert(name, handles);
+          }
         }
         _ = flush_deadline => {
           if !batch.is_empty() {
             let mut app = self.app.lock().await;
-            app.data.logs.add_records(std::mem::replace(
+            app.add_log_records(std::mem::replace(
               &mut batch,
               Vec::with_capacity(BATCH_SIZE),
             ));
@@ -648,14 +833,17 @@ impl<'a> NetworkStream<'a> {
       }
     }
 
-    // Wait for all spawned tasks to finish
+    // Stop the pod streams still running
     join_set.abort_all();
 
     let mut app = self.app.lock().await;
     app.is_streaming = false;
+    // Aborted streams never get to count themselves out.
+    app.log_reconnecting = 0;
     info!(
       "Aggregate log stream ended for {} pods (selector: {})",
-      streaming_count, selector
+      streamed.len(),
+      selector
     );
   }
 
@@ -745,6 +933,345 @@ impl<'a> NetworkStream<'a> {
   }
 }
 
+/// Follow one pod with a watch, applying every change to the app, until the
+/// pod is no longer the watched one. A dropped watch is reopened from the
+/// pod's current state with the same backoff as log streams; after
+/// [`MAX_RECONNECT_ATTEMPTS`] the watch is dropped and polling takes over.
+async fn watch_pod(client: Client, app: Arc<Mutex<App>>, target: WatchedPod) {
+  let mut watching = app.lock().await.pod_watch_rx();
+  let api: Api<Pod> = Api::namespaced(client, &target.namespace);
+  let params = WatchParams::default().fields(&format!("metadata.name={}", target.name));
+  let mut failures: u32 = 0;
+
+  loop {
+    if watching.borrow().as_ref() != Some(&target) {
+      return;
+    }
+    let opened = match api.get(&target.name).await {
+      Ok(pod) => {
+        let version = pod.metadata.resource_version.clone().unwrap_or_default();
+        app.lock().await.update_watched_pod(KubePod::from(pod));
+        api.watch(&params, &version).await
+      }
+      Err(e) => Err(e),
+    };
+    let error = match opened {
+      Ok(events) => {
+        failures = 0;
+        let mut events = std::pin::pin!(events);
+        loop {
+          tokio::select! {
+            changed = watching.changed() => {
+              if changed.is_err() || watching.borrow().as_ref() != Some(&target) {
+                debug!("Pod watch stopped for {}/{}", target.namespace, target.name);
+                return;
+              }
+            }
+            event = events.next() => match event {
+              Some(Ok(WatchEvent::Added(pod) | WatchEvent::Modified(pod))) => {
+                app.lock().await.update_watched_pod(KubePod::from(pod));
+              }
+              Some(Ok(WatchEvent::Deleted(pod))) => {
+                let mut app = app.lock().await;
+                app.update_watched_pod(KubePod::from(pod));
+                app.stop_pod_watch();
+                app.set_status_message(format!("Pod {} was deleted", target.name));
+                return;
+              }
+              Some(Ok(WatchEvent::Bookmark(_))) => {}
+              Some(Ok(WatchEvent::Error(status))) => {
+                failures += 1;
+                break status.message.clone();
+              }
+              Some(Err(e)) => {
+                failures += 1;
+                break e.to_string();
+              }
+              // the server ends watches after a while; reopen right away
+              None => break String::new(),
+            }
+          }
+        }
+      }
+      Err(e) => {
+        failures += 1;
+        e.to_string()
+      }
+    };
+
+    if failures > MAX_RECONNECT_ATTEMPTS {
+      let mut app = app.lock().await;
+      app.stop_pod_watch();
+      app.handle_error(anyhow!("Stopped watching pod {}. {}", target.name, error));
+      return;
+    }
+    if failures > 0 {
+      warn!(
+        "Pod watch for {}/{} failed, retrying: {}",
+        target.namespace, target.name, error
+      );
+      tokio::select! {
+        _ = tokio::time::sleep(reconnect_backoff(failures)) => {}
+        _ = watching.wait_for(|watched| watched.as_ref() != Some(&target)) => return,
+      }
+    }
+  }
+}
+
+/// Watch each object of apply `id` for as long as its overlay shows that
+/// apply, until every object is ready or [`APPLY_WATCH_TIMEOUT`] passes.
+/// Objects without a namespace are looked for in the client's default one,
+/// where `kubectl apply` puts them.
+async fn watch_applied(
+  client: Client,
+  app: Arc<Mutex<App>>,
+  id: u64,
+  objects: Vec<ManifestObject>,
+) {
+  let mut watching = app.lock().await.apply_watch_rx();
+  let watches = objects
+    .into_iter()
+    .enumerate()
+    .filter_map(|(index, object)| {
+      let block = block_for_kind(&object.kind)?;
+      let api = object_api(&client, block, object.namespace.as_deref())?;
+      Some(watch_applied_object(
+        api,
+        Arc::clone(&app),
+        id,
+        index,
+        object.name,
+      ))
+    });
+  let timed_out = tokio::select! {
+    _ = join_all(watches) => false,
+    _ = tokio::time::sleep(APPLY_WATCH_TIMEOUT) => true,
+    _ = watching.wait_for(|current| *current != id) => {
+      debug!("Apply watch {} stopped", id);
+      return;
+    }
+  };
+  app.lock().await.end_apply_watch(id, timed_out);
+}
+
+/// The API of the objects a view lists, in `namespace` or the client's
+/// default one for namespaced kinds.
+pub(crate) fn object_api(
+  client: &Client,
+  block: ActiveBlock,
+  namespace: Option<&str>,
+) -> Option<Api<DynamicObject>> {
+  let (resource, scope) = api_resource_for_block(block, None)?;
+  Some(match scope {
+    Scope::Cluster => Api::all_with(client.clone(), &resource),
+    Scope::Namespaced => Api::namespaced_with(
+      client.clone(),
+      namespace.unwrap_or(client.default_namespace()),
+      &resource,
+    ),
+  })
+}
+
+/// Watch one applied object until it is ready. Failures back off like log
+/// streams, and after [`MAX_RECONNECT_ATTEMPTS`] the error is shown in place
+/// of its status.
+async fn watch_applied_object(
+  api: Api<DynamicObject>,
+  app: Arc<Mutex<App>>,
+  id: u64,
+  index: usize,
+  name: String,
+) {
+  let seen = |object: Option<DynamicObject>| {
+    let app = Arc::clone(&app);
+    async move {
+      let mut app = app.lock().await;
+      match object.map(serde_json::to_value) {
+        Some(Ok(object)) => app.update_applied_object(id, index, object),
+        Some(Err(e)) => {
+          let status = PinStatus::Failed(format!("Unreadable object: {}", e));
+          app.set_applied_status(id, index, status);
+          false
+        }
+        None => {
+          app.set_applied_status(id, index, PinStatus::Gone);
+          false
+        }
+      }
+    }
+  };
+  if let Err(error) = watch_object(&api, &name, seen).await {
+    let status = PinStatus::Failed(sanitize_error_message(&anyhow!(error)));
+    app.lock().await.set_applied_status(id, index, status);
+  }
+}
+
+/// Watch the object `name` of `api`, calling `seen` with it as it is now
+/// and on each change, or with `None` while it does not exist, until `seen`
+/// says it is done. A watch the server ends is reopened; failures back off
+/// like log streams, and after [`MAX_RECONNECT_ATTEMPTS`] in a row the last
+/// error is returned.
+pub(crate) async fn watch_object<F, Fut>(
+  api: &Api<DynamicObject>,
+  name: &str,
+  mut seen: F,
+) -> Result<(), String>
+where
+  F: FnMut(Option<DynamicObject>) -> Fut,
+  Fut: Future<Output = bool>,
+{
+  let params = WatchParams::default().fields(&format!("metadata.name={}", name));
+  let mut failures: u32 = 0;
+
+  loop {
+    let opened = match api.get_opt(name).await {
+      Ok(Some(object)) => {
+        let version = object.metadata.resource_version.clone().unwrap_or_default();
+        if seen(Some(object)).await {
+          return Ok(());
+        }
+        api.watch(&params, &version).await
+      }
+      // not there (yet); a watch from the start sees it being added
+      Ok(None) => {
+        if seen(None).await {
+          return Ok(());
+        }
+        api.watch(&params, "0").await
+      }
+      Err(e) => Err(e),
+    };
+    let error = match opened {
+      Ok(events) => {
+        failures = 0;
+        let mut events = std::pin::pin!(events);
+        loop {
+          match events.next().await {
+            Some(Ok(WatchEvent::Added(object) | WatchEvent::Modified(object))) => {
+              if seen(Some(object)).await {
+                return Ok(());
+              }
+            }
+            Some(Ok(WatchEvent::Deleted(_))) => {
+              if seen(None).await {
+                return Ok(());
+              }
+            }
+            Some(Ok(WatchEvent::Bookmark(_))) => {}
+            Some(Ok(WatchEvent::Error(status))) => {
+              failures += 1;
+              break status.message.clone();
+            }
+            Some(Err(e)) => {
+              failures += 1;
+              break e.to_string();
+            }
+            // the server ends watches after a while; reopen right away
+            None => break String::new(),
+          }
+        }
+      }
+      Err(e) => {
+        failures += 1;
+        e.to_string()
+      }
+    };
+
+    if failures > MAX_RECONNECT_ATTEMPTS {
+      return Err(error);
+    }
+    if failures > 0 {
+      warn!("Watch of {} failed, retrying: {}", name, error);
+      tokio::time::sleep(reconnect_backoff(failures)).await;
+    }
+  }
+}
+
+/// Follow the events of a namespace, or of the cluster, with a watch: list
+/// them, then add each one as it is emitted, until the events view is left.
+/// A watch the server ends, or whose version expired, is reopened from a
+/// fresh list; failures back off like log streams, and after
+/// [`MAX_RECONNECT_ATTEMPTS`] the watch gives up and polling takes over.
+async fn watch_events(client: Client, app: Arc<Mutex<App>>, target: WatchedEvents) {
+  let mut watching = app.lock().await.event_watch_rx();
+  let api: Api<Event> = match &target.namespace {
+    Some(namespace) => Api::namespaced(client, namespace),
+    None => Api::all(client),
+  };
+  let scope = target.namespace.as_deref().unwrap_or("all namespaces");
+  let mut failures: u32 = 0;
+
+  loop {
+    if watching.borrow().as_ref() != Some(&target) {
+      return;
+    }
+    let opened = match api.list(&ListParams::default()).await {
+      Ok(list) => {
+        let version = list.metadata.resource_version.clone().unwrap_or_default();
+        app
+          .lock()
+          .await
+          .set_watched_events(list.items.into_iter().map(KubeEvent::from).collect());
+        api.watch(&WatchParams::default(), &version).await
+      }
+      Err(e) => Err(e),
+    };
+    let error = match opened {
+      Ok(events) => {
+        failures = 0;
+        let mut events = std::pin::pin!(events);
+        loop {
+          tokio::select! {
+            changed = watching.changed() => {
+              if changed.is_err() || watching.borrow().as_ref() != Some(&target) {
+                debug!("Events watch stopped for {}", scope);
+                return;
+              }
+            }
+            event = events.next() => match event {
+              Some(Ok(WatchEvent::Added(event) | WatchEvent::Modified(event))) => {
+                app.lock().await.add_watched_event(KubeEvent::from(event));
+              }
+              // deleted events are kept until they age out of the buffer
+              Some(Ok(WatchEvent::Deleted(_) | WatchEvent::Bookmark(_))) => {}
+              // the version is too old to resume from; list again
+              Some(Ok(WatchEvent::Error(status))) if status.code == 410 => break String::new(),
+              Some(Ok(WatchEvent::Error(status))) => {
+                failures += 1;
+                break status.message.clone();
+              }
+              Some(Err(e)) => {
+                failures += 1;
+                break e.to_string();
+              }
+              // the server ends watches after a while; reopen right away
+              None => break String::new(),
+            }
+          }
+        }
+      }
+      Err(e) => {
+        failures += 1;
+        e.to_string()
+      }
+    };
+
+    if failures > MAX_RECONNECT_ATTEMPTS {
+      let mut app = app.lock().await;
+      app.give_up_event_watch(target.clone());
+      app.handle_error(anyhow!("Stopped watching events in {}. {}", scope, error));
+      return;
+    }
+    if failures > 0 {
+      warn!("Events watch for {} failed, retrying: {}", scope, error);
+      tokio::select! {
+        _ = tokio::time::sleep(reconnect_backoff(failures)) => {}
+        _ = watching.wait_for(|watched| watched.as_ref() != Some(&target)) => return,
+      }
+    }
+  }
+}
+
 /// Read a forward's kubectl output until it exits. The first
 /// "Forwarding from …" line on stdout marks it active; an early exit (or any
 /// exit) reads stderr for the reason and marks it failed. Updates are no-ops if
@@ -808,6 +1335,57 @@ fn short_pod_name(name: &str) -> String {
 
 /// Collect (pod_name, container_name, log_line_prefix) for every container in every pod.
 /// When a pod has multiple containers, the prefix includes both pod suffix and container name.
+/// The pod whose logs stand for the whole workload: the most recently started
+/// of the ready pods, or of all pods when none is ready.
+fn representative_pod(pods: &[Pod]) -> Option<usize> {
+  let is_ready = |pod: &Pod| {
+    pod.metadata.deletion_timestamp.is_none()
+      && pod
+        .status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|conditions| {
+          conditions
+            .iter()
+            .any(|c| c.type_ == "Ready" && c.status == "True")
+        })
+  };
+  let started = |pod: &Pod| pod.status.as_ref().and_then(|s| s.start_time.clone());
+  pods
+    .iter()
+    .enumerate()
+    .max_by_key(|(_, pod)| (is_ready(pod), started(pod)))
+    .map(|(i, _)| i)
+}
+
+/// Pods to start streaming and names of streamed pods that are gone, for an
+/// aggregate stream. Only pods whose containers have started have logs, so
+/// pending ones join on a later listing; at most [`MAX_AGGREGATE_PODS`] are
+/// streamed at once.
+fn aggregate_pod_changes<'a, T>(
+  streamed: &BTreeMap<String, T>,
+  pods: &'a [Pod],
+) -> (Vec<&'a Pod>, Vec<String>) {
+  let name = |pod: &Pod| pod.metadata.name.clone().unwrap_or_default();
+  let current: HashSet<String> = pods.iter().map(name).collect();
+  let left: Vec<String> = streamed
+    .keys()
+    .filter(|streamed| !current.contains(*streamed))
+    .cloned()
+    .collect();
+  let room = MAX_AGGREGATE_PODS.saturating_sub(streamed.len() - left.len());
+  let joined = pods
+    .iter()
+    .filter(|pod| {
+      let phase = pod.status.as_ref().and_then(|s| s.phase.as_deref());
+      matches!(phase, Some("Running" | "Succeeded" | "Failed"))
+        && !streamed.contains_key(&name(pod))
+    })
+    .take(room)
+    .collect();
+  (joined, left)
+}
+
 fn collect_pod_container_info(pods: &[Pod]) -> Vec<(String, String, String)> {
   pods
     .iter()
@@ -842,6 +1420,80 @@ fn collect_pod_container_info(pods: &[Pod]) -> Vec<(String, String, String)> {
     .collect()
 }
 
+/// Whether `container` of `pod` has exited, so there is nothing left to
+/// follow. A container without a status yet is still starting.
+fn is_terminated(pod: &Pod, container: &str) -> bool {
+  pod.status.as_ref().is_some_and(|status| {
+    [
+      &status.container_statuses,
+      &status.init_container_statuses,
+      &status.ephemeral_container_statuses,
+    ]
+    .into_iter()
+    .flatten()
+    .flatten()
+    .find(|status| status.name == container)
+    .is_some_and(|status| {
+      status
+        .state
+        .as_ref()
+        .is_some_and(|state| state.terminated.is_some())
+    })
+  })
+}
+
+/// Whether a dropped log stream has nothing more to follow: its container has
+/// exited or its pod is gone. When the pod cannot be read, the stream is
+/// retried.
+async fn container_terminated(api: &Api<Pod>, pod_name: &str, container: &str) -> bool {
+  match api.get_opt(pod_name).await {
+    Ok(Some(pod)) => is_terminated(&pod, container),
+    Ok(None) => true,
+    Err(_) => false,
+  }
+}
+
+/// Delay before reopening a log stream: 500ms, doubled for each failed
+/// attempt in a row and capped at 30s.
+fn reconnect_backoff(failures: u32) -> Duration {
+  let millis = RECONNECT_BASE_BACKOFF_MS.saturating_mul(1 << failures.min(16));
+  Duration::from_millis(millis).min(MAX_RECONNECT_BACKOFF)
+}
+
+/// `since_seconds` for a reopened stream: the time since the last line was
+/// received, rounded up, plus an overlap so no line is lost in between.
+fn resume_since_seconds(since_last_line: Duration) -> i64 {
+  since_last_line.as_secs_f64().ceil() as i64 + RECONNECT_OVERLAP_SECS
+}
+
+/// Wait out a reconnect backoff. Returns false if the stream was cancelled
+/// meanwhile, so a long backoff does not hold up the next stream.
+async fn sleep_unless_cancelled(
+  backoff: Duration,
+  cancel_rx: &mut tokio::sync::watch::Receiver<bool>,
+) -> bool {
+  tokio::select! {
+    _ = tokio::time::sleep(backoff) => true,
+    _ = cancel_rx.wait_for(|cancelled| *cancelled) => false,
+  }
+}
+
+/// Count a stream in or out of `App::log_reconnecting`, at most once per
+/// outage.
+async fn mark_reconnecting(app: &Mutex<App>, reconnecting: &mut bool, value: bool) {
+  if *reconnecting == value {
+    return;
+  }
+  *reconnecting = value;
+  let mut app = app.lock().await;
+  app.log_reconnecting = if value {
+    app.log_reconnecting + 1
+  } else {
+    app.log_reconnecting.saturating_sub(1)
+  };
+  app.mark_dirty();
+}
+
 struct AggregateStreamTarget {
   namespace: String,
   pod_name: String,
@@ -849,14 +1501,24 @@ struct AggregateStreamTarget {
   short_name: String,
 }
 
+#[derive(Clone, Copy)]
+struct LogStreamOptions {
+  tail_lines: i64,
+  since_seconds: Option<i64>,
+  timestamps: bool,
+}
+
 /// Stream logs from a single pod, prefixing each line and sending to the channel.
+/// A stream that drops while its container runs is reopened from its last
+/// line with backoff; one that never opened reports the error instead, and
+/// one the server ends cleanly is done.
 async fn stream_single_pod_for_aggregate(
   client: Client,
   stream_target: AggregateStreamTarget,
-  tail_lines: i64,
-  timestamps: bool,
+  options: LogStreamOptions,
   tx: tokio::sync::mpsc::Sender<String>,
   cancel_rx: tokio::sync::watch::Receiver<bool>,
+  app: Arc<Mutex<App>>,
 ) {
   let AggregateStreamTarget {
     namespace,
@@ -865,73 +1527,214 @@ async fn stream_single_pod_for_aggregate(
     short_name,
   } = stream_target;
   let api: Api<Pod> = Api::namespaced(client, &namespace);
-  let lp = LogParams {
-    container: Some(container_name.clone()),
-    follow: true,
-    previous: false,
-    tail_lines: Some(tail_lines),
-    timestamps,
-    ..Default::default()
-  };
+  let mut cancel_rx = cancel_rx;
+  let mut resume_since: Option<i64> = None;
+  let mut connected = false;
+  let mut failures: u32 = 0;
+  let mut reconnecting = false;
+  let mut last_line_at = Instant::now();
+  let mut recent: VecDeque<String> = VecDeque::with_capacity(DEDUP_WINDOW);
+
+  loop {
+    let since_seconds = resume_since.or(options.since_seconds);
+    let lp = LogParams {
+      container: Some(container_name.clone()),
+      follow: true,
+      previous: false,
+      tail_lines: if since_seconds.is_none() {
+        Some(options.tail_lines)
+      } else {
+        None
+      },
+      since_seconds,
+      timestamps: options.timestamps,
+      ..Default::default()
+    };
 
-  match api.log_stream(&pod_name, &lp).await {
-    Ok(logs) => {
-      let mut lines_stream = logs.lines();
-      let mut cancel_rx = cancel_rx;
+    match api.log_stream(&pod_name, &lp).await {
+      Ok(logs) => {
+        connected = true;
+        let mut lines_stream = logs.lines();
+        let dedup_set: HashSet<String> = if resume_since.is_some() {
+          recent.iter().cloned().collect()
+        } else {
+          HashSet::new()
+        };
 
-      loop {
-        tokio::select! {
-          _ = cancel_rx.changed() => {
-            if *cancel_rx.borrow() {
-              return;
+        let dropped = loop {
+          tokio::select! {
+            _ = cancel_rx.changed() => {
+              if *cancel_rx.borrow() {
+                return;
+              }
             }
-          }
-          line = lines_stream.next() => {
-            match line {
-              Some(Ok(line)) => {
-                let line = line.trim().to_string();
-                if !line.is_empty() {
+            line = lines_stream.next() => {
+              match line {
+                Some(Ok(line)) => {
+                  last_line_at = Instant::now();
+                  failures = 0;
+                  mark_reconnecting(&app, &mut reconnecting, false).await;
+                  let line = line.trim().to_string();
+                  if line.is_empty() || dedup_set.contains(&line) {
+                    continue;
+                  }
+                  if recent.len() == DEDUP_WINDOW {
+                    recent.pop_front();
+                  }
+                  recent.push_back(line.clone());
                   let prefixed = format!("[{}] {}", short_name, line);
                   if tx.send(prefixed).await.is_err() {
                     return; // Receiver dropped
                   }
                 }
-              }
-              Some(Err(e)) => {
-                warn!(
-                  "Aggregate stream error for {}/{}: {}",
-                  pod_name, container_name, e
-                );
-                return;
-              }
-              None => {
-                debug!("Aggregate stream ended for {}/{}", pod_name, container_name);
-                return;
+                Some(Err(e)) => {
+                  warn!(
+                    "Aggregate stream error for {}/{}: {}",
+                    pod_name, container_name, e
+                  );
+                  break true;
+                }
+                None => {
+                  debug!("Aggregate stream ended for {}/{}", pod_name, container_name);
+                  break false;
+                }
               }
             }
           }
+        };
+        if !dropped || container_terminated(&api, &pod_name, &container_name).await {
+          break;
+        }
+        failures += 1;
+        if failures >= MAX_RECONNECT_ATTEMPTS {
+          mark_reconnecting(&app, &mut reconnecting, false).await;
+          let _ = tx
+            .send(format!(
+              "[{}] Error: log stream kept dropping after {} attempts",
+              short_name, MAX_RECONNECT_ATTEMPTS
+            ))
+            .await;
+          return;
+        }
+      }
+      Err(e) => {
+        warn!(
+          "Failed to open aggregate log stream for {}/{}: {}",
+          pod_name, container_name, e
+        );
+        failures += 1;
+        if !connected || failures >= MAX_RECONNECT_ATTEMPTS {
+          mark_reconnecting(&app, &mut reconnecting, false).await;
+          let _ = tx
+            .send(format!(
+              "[{}] Error: failed to stream logs: {}",
+              short_name, e
+            ))
+            .await;
+          return;
         }
       }
     }
-    Err(e) => {
-      warn!(
-        "Failed to open aggregate log stream for {}/{}: {}",
-        pod_name, container_name, e
-      );
-      let _ = tx
-        .send(format!(
-          "[{}] Error: failed to stream logs: {}",
-          short_name, e
-        ))
-        .await;
+
+    if *cancel_rx.borrow() {
+      break;
+    }
+    mark_reconnecting(&app, &mut reconnecting, true).await;
+    resume_since = Some(resume_since_seconds(last_line_at.elapsed()));
+    if !sleep_unless_cancelled(reconnect_backoff(failures), &mut cancel_rx).await {
+      break;
     }
   }
+  mark_reconnecting(&app, &mut reconnecting, false).await;
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_reconnect_backoff_doubles_up_to_the_cap() {
+    assert_eq!(reconnect_backoff(0), Duration::from_millis(500));
+    assert_eq!(reconnect_backoff(1), Duration::from_secs(1));
+    assert_eq!(reconnect_backoff(3), Duration::from_secs(4));
+    assert_eq!(reconnect_backoff(6), MAX_RECONNECT_BACKOFF);
+    assert_eq!(reconnect_backoff(u32::MAX), MAX_RECONNECT_BACKOFF);
+  }
+
+  #[test]
+  fn test_resume_since_seconds_covers_the_gap() {
+    assert_eq!(resume_since_seconds(Duration::ZERO), RECONNECT_OVERLAP_SECS);
+    assert_eq!(
+      resume_since_seconds(Duration::from_millis(61_200)),
+      62 + RECONNECT_OVERLAP_SECS
+    );
+  }
+
+  #[tokio::test]
+  async fn test_mark_reconnecting_counts_each_outage_once() {
+    let app = Mutex::new(App::default());
+    let mut first = false;
+    let mut second = false;
+
+    mark_reconnecting(&app, &mut first, true).await;
+    mark_reconnecting(&app, &mut first, true).await;
+    mark_reconnecting(&app, &mut second, true).await;
+    assert_eq!(app.lock().await.log_reconnecting, 2);
+
+    mark_reconnecting(&app, &mut first, false).await;
+    mark_reconnecting(&app, &mut first, false).await;
+    assert_eq!(app.lock().await.log_reconnecting, 1);
+  }
+
+  #[tokio::test]
+  async fn test_sleep_unless_cancelled_stops_on_cancel() {
+    let (cancel_tx, mut cancel_rx) = tokio::sync::watch::channel(false);
+    assert!(sleep_unless_cancelled(Duration::from_millis(1), &mut cancel_rx).await);
+
+    cancel_tx.send(true).unwrap();
+    assert!(!sleep_unless_cancelled(MAX_RECONNECT_BACKOFF, &mut cancel_rx).await);
+  }
+
+  #[test]
+  fn test_is_terminated_reads_the_container_state() {
+    use k8s_openapi::api::core::v1::{
+      ContainerState, ContainerStateRunning, ContainerStateTerminated, ContainerStatus, PodStatus,
+    };
+
+    let status = |name: &str, state: ContainerState| ContainerStatus {
+      name: name.into(),
+      state: Some(state),
+      ..Default::default()
+    };
+    let pod = Pod {
+      status: Some(PodStatus {
+        container_statuses: Some(vec![
+          status(
+            "app",
+            ContainerState {
+              running: Some(ContainerStateRunning::default()),
+              ..Default::default()
+            },
+          ),
+          status(
+            "job",
+            ContainerState {
+              terminated: Some(ContainerStateTerminated::default()),
+              ..Default::default()
+            },
+          ),
+        ]),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    assert!(!is_terminated(&pod, "app"));
+    assert!(is_terminated(&pod, "job"));
+    assert!(!is_terminated(&pod, "sidecar"));
+    assert!(!is_terminated(&Pod::default(), "app"));
+  }
+
   #[test]
   fn test_short_pod_name_extracts_suffix() {
     assert_eq!(short_pod_name("myapp-deploy-abc123"), "abc123");
@@ -1108,4 +1911,71 @@ mod tests {
     let info = collect_pod_container_info(&[pod]);
     assert!(info.is_empty());
   }
+
+  fn started_pod(name: &str, ready: bool, started: &str) -> Pod {
+    serde_json::from_value(serde_json::json!({
+      "metadata": { "name": name },
+      "status": {
+        "startTime": started,
+        "conditions": [{ "type": "Ready", "status": if ready { "True" } else { "False" } }]
+      }
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn test_representative_pod_is_the_newest_ready_one() {
+    let pods = vec![
+      started_pod("old-ready", true, "2024-01-01T00:00:00Z"),
+      started_pod("new-ready", true, "2024-01-03T00:00:00Z"),
+      started_pod("newest-crashing", false, "2024-01-04T00:00:00Z"),
+    ];
+    assert_eq!(representative_pod(&pods), Some(1));
+
+    // without a ready pod, the newest one still has something to show
+    let not_ready = vec![
+      started_pod("a", false, "2024-01-02T00:00:00Z"),
+      started_pod("b", false, "2024-01-01T00:00:00Z"),
+    ];
+    assert_eq!(representative_pod(&not_ready), Some(0));
+    assert_eq!(representative_pod(&[]), None);
+  }
+
+  fn phased_pod(name: &str, phase: &str) -> Pod {
+    serde_json::from_value(serde_json::json!({
+      "metadata": { "name": name },
+      "status": { "phase": phase }
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn test_aggregate_pod_changes_follows_a_rollout() {
+    let streamed: BTreeMap<String, ()> = [("web-old-a".to_string(), ()), ("web-old-b".into(), ())]
+      .into_iter()
+      .collect();
+    let pods = vec![
+      phased_pod("web-old-b", "Running"),
+      phased_pod("web-new-a", "Running"),
+      phased_pod("web-new-b", "Pending"),
+    ];
+    let (joined, left) = aggregate_pod_changes(&streamed, &pods);
+    let joined: Vec<_> = joined
+      .iter()
+      .map(|pod| pod.metadata.name.as_deref().unwrap())
+      .collect();
+    // a pending pod has no logs yet and joins on a later listing
+    assert_eq!(joined, vec!["web-new-a"]);
+    assert_eq!(left, vec!["web-old-a".to_string()]);
+
+    let full: BTreeMap<String, ()> = (0..MAX_AGGREGATE_PODS)
+      .map(|i| (format!("web-{}", i), ()))
+      .collect();
+    let mut more: Vec<Pod> = full
+      .keys()
+      .map(|name| phased_pod(name, "Running"))
+      .collect();
+    more.push(phased_pod("web-extra", "Running"));
+    assert_eq!(aggregate_pod_changes(&full, &more), (vec![], vec![]));
+  }
 }
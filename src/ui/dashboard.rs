@@ -0,0 +1,351 @@
+//! Cluster dashboard for a quick health check: node readiness, pod states,
+//! the namespace count, and CPU and memory use against what the nodes can
+//! allocate. Everything is summed from data the overview already polls.
+use ratatui::{
+  layout::{Constraint, Rect},
+  text::{Line, Span},
+  widgets::Paragraph,
+  Frame,
+};
+
+use super::{
+  overview::{pod_counts, pod_summary},
+  utils::{
+    gauge_line, horizontal_chunks, layout_block_default, loading, style_caution, style_failure,
+    style_help, style_label, style_success, style_text, vertical_chunks,
+    vertical_chunks_with_margin,
+  },
+};
+use crate::app::{nodes::KubeNode, utils::convert_to_f64, App};
+
+/// Totals shown on the dashboard.
+#[derive(Debug, Default, PartialEq)]
+struct ClusterTotals {
+  ready_nodes: usize,
+  total_nodes: usize,
+  running_pods: usize,
+  pending_pods: usize,
+  failed_pods: usize,
+  namespaces: usize,
+  cpu_used_milli: f64,
+  cpu_allocatable_milli: f64,
+  mem_used_mi: f64,
+  mem_allocatable_mi: f64,
+}
+
+impl ClusterTotals {
+  fn from_app(app: &App) -> Self {
+    let nodes = &app.data.nodes.items;
+    let (running_pods, pending_pods, failed_pods) = pod_counts(&app.data.pods.items);
+    Self {
+      ready_nodes: nodes.iter().filter(|n| n.status == "Ready").count(),
+      total_nodes: nodes.len(),
+      running_pods,
+      pending_pods,
+      failed_pods,
+      namespaces: app.data.namespaces.items.len(),
+      cpu_used_milli: nodes.iter().map(|n| milli(&n.cpu)).sum(),
+      cpu_allocatable_milli: nodes.iter().map(|n| milli(&n.cpu_a)).sum(),
+      mem_used_mi: nodes.iter().map(|n| mebi(&n.mem)).sum(),
+      mem_allocatable_mi: nodes.iter().map(|n| mebi(&n.mem_a)).sum(),
+    }
+  }
+
+  fn cpu_percent(&self) -> f64 {
+    percent(self.cpu_used_milli, self.cpu_allocatable_milli)
+  }
+
+  fn mem_percent(&self) -> f64 {
+    percent(self.mem_used_mi, self.mem_allocatable_mi)
+  }
+
+  /// `1.2 / 8.0 cores`
+  fn cpu_summary(&self) -> String {
+    format!(
+      "{:.1} / {:.1} cores",
+      self.cpu_used_milli / 1000.0,
+      self.cpu_allocatable_milli / 1000.0
+    )
+  }
+
+  /// `3.1Gi / 16.0Gi`
+  fn mem_summary(&self) -> String {
+    format!(
+      "{:.1}Gi / {:.1}Gi",
+      self.mem_used_mi / 1024.0,
+      self.mem_allocatable_mi / 1024.0
+    )
+  }
+}
+
+/// Millicores from the `123m` form node columns use.
+fn milli(cpu: &str) -> f64 {
+  convert_to_f64(cpu.trim_end_matches('m'))
+}
+
+/// Mebibytes from the `512Mi` form node columns use.
+fn mebi(mem: &str) -> f64 {
+  convert_to_f64(mem.trim_end_matches("Mi"))
+}
+
+fn percent(used: f64, total: f64) -> f64 {
+  if total > 0.0 {
+    used / total * 100.0
+  } else {
+    0.0
+  }
+}
+
+pub fn draw_dashboard(f: &mut Frame<'_>, app: &App, area: Rect) {
+  let chunks = vertical_chunks(
+    vec![
+      Constraint::Length(4),
+      Constraint::Length(4),
+      Constraint::Min(3),
+    ],
+    area,
+  );
+  let totals = ClusterTotals::from_app(app);
+  draw_counters(f, app, &totals, chunks[0]);
+  draw_capacity(f, app, &totals, chunks[1]);
+  draw_node_usage(f, app, chunks[2]);
+}
+
+fn draw_counters(f: &mut Frame<'_>, app: &App, totals: &ClusterTotals, area: Rect) {
+  let palette = app.palette;
+  let chunks = horizontal_chunks(
+    vec![
+      Constraint::Percentage(30),
+      Constraint::Percentage(45),
+      Constraint::Percentage(25),
+    ],
+    area,
+  );
+
+  let node_style = if totals.ready_nodes < totals.total_nodes {
+    style_failure(palette)
+  } else {
+    style_success(palette)
+  };
+  let pod_style = if totals.failed_pods > 0 {
+    style_failure(palette)
+  } else if totals.pending_pods > 0 {
+    style_caution(palette)
+  } else {
+    style_success(palette)
+  };
+  // pods follow the namespace filter, so say which pods are counted
+  let pods_title = match &app.data.selected.ns {
+    Some(ns) => format!(" Pods in {} ", ns),
+    None => " Pods ".to_owned(),
+  };
+
+  let counters = [
+    (
+      " Nodes ".to_owned(),
+      format!("{}/{} Ready", totals.ready_nodes, totals.total_nodes),
+      node_style,
+    ),
+    (
+      pods_title,
+      pod_summary(totals.running_pods, totals.pending_pods, totals.failed_pods),
+      pod_style,
+    ),
+    (
+      " Namespaces ".to_owned(),
+      totals.namespaces.to_string(),
+      style_text(palette),
+    ),
+  ];
+  for ((title, value, style), area) in counters.into_iter().zip(chunks.iter()) {
+    let block = layout_block_default(&title, palette);
+    f.render_widget(
+      Paragraph::new(Line::from(Span::styled(value, style))).block(block),
+      *area,
+    );
+  }
+}
+
+fn draw_capacity(f: &mut Frame<'_>, app: &App, totals: &ClusterTotals, area: Rect) {
+  let block = layout_block_default(" Cluster usage vs allocatable ", app.palette);
+  let inner = block.inner(area);
+  f.render_widget(block, area);
+
+  let lines = match app.metrics_note() {
+    Some(note) => vec![Line::from(Span::styled(note, style_help(app.palette)))],
+    None => vec![
+      gauge_line(
+        "CPU:     ".into(),
+        totals.cpu_percent(),
+        format!("{:>3.0}% {}", totals.cpu_percent(), totals.cpu_summary()),
+        inner.width,
+        app.palette,
+        app.enhanced_graphics,
+      ),
+      gauge_line(
+        "Memory:  ".into(),
+        totals.mem_percent(),
+        format!("{:>3.0}% {}", totals.mem_percent(), totals.mem_summary()),
+        inner.width,
+        app.palette,
+        app.enhanced_graphics,
+      ),
+    ],
+  };
+  f.render_widget(Paragraph::new(lines), inner);
+}
+
+/// One line per node with its CPU and memory use, so a hot node stands out
+/// from a cluster average that looks fine.
+fn draw_node_usage(f: &mut Frame<'_>, app: &App, area: Rect) {
+  let block = layout_block_default(" Nodes ", app.palette);
+  let nodes = &app.data.nodes.items;
+  if nodes.is_empty() {
+    loading(f, block, area, &app.global_load(), app.palette);
+    return;
+  }
+  f.render_widget(block, area);
+
+  let chunks = horizontal_chunks(
+    vec![Constraint::Percentage(50), Constraint::Percentage(50)],
+    vertical_chunks_with_margin(vec![Constraint::Min(1)], area, 1)[0],
+  );
+  let name_width = nodes.iter().map(|n| n.name.len()).max().unwrap_or(0);
+  let (cpu, mem): (Vec<_>, Vec<_>) = nodes
+    .iter()
+    .map(|node| node_lines(app, node, name_width, chunks[0].width, chunks[1].width))
+    .unzip();
+  f.render_widget(Paragraph::new(cpu), chunks[0]);
+  f.render_widget(Paragraph::new(mem), chunks[1]);
+}
+
+fn node_lines<'a>(
+  app: &App,
+  node: &KubeNode,
+  name_width: usize,
+  cpu_width: u16,
+  mem_width: u16,
+) -> (Line<'a>, Line<'a>) {
+  let label = format!("{:<name_width$} CPU ", node.name);
+  if node.status != "Ready" {
+    let line = Line::from(vec![
+      Span::styled(label, style_label(app.palette)),
+      Span::styled(node.status.clone(), style_failure(app.palette)),
+    ]);
+    return (line, Line::default());
+  }
+  let cpu_pct = convert_to_f64(&node.cpu_percent);
+  let mem_pct = convert_to_f64(&node.mem_percent);
+  (
+    gauge_line(
+      label,
+      cpu_pct,
+      format!("{cpu_pct:>3.0}%"),
+      cpu_width,
+      app.palette,
+      app.enhanced_graphics,
+    ),
+    gauge_line(
+      " Memory ".into(),
+      mem_pct,
+      format!("{mem_pct:>3.0}%"),
+      mem_width,
+      app.palette,
+      app.enhanced_graphics,
+    ),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use ratatui::{backend::TestBackend, Terminal};
+
+  use super::*;
+  use crate::app::{ns::KubeNs, pods::KubePod};
+
+  fn node(name: &str, status: &str, cpu: &str, cpu_a: &str, mem: &str, mem_a: &str) -> KubeNode {
+    let mut node = KubeNode::default();
+    node.name = name.into();
+    node.status = status.into();
+    node.cpu = cpu.into();
+    node.cpu_a = cpu_a.into();
+    node.mem = mem.into();
+    node.mem_a = mem_a.into();
+    node
+  }
+
+  #[test]
+  fn test_cluster_totals_sum_nodes_pods_and_namespaces() {
+    let mut app = App::default();
+    app.data.nodes.set_items(vec![
+      node("a", "Ready", "500m", "2000m", "1024Mi", "4096Mi"),
+      node("b", "Not Ready", "1500m", "2000m", "1024Mi", "4096Mi"),
+    ]);
+    let pods = ["Running", "Running", "Pending", "CrashLoopBackOff"]
+      .iter()
+      .map(|status| {
+        let mut pod = KubePod::default();
+        pod.status = (*status).into();
+        pod
+      })
+      .collect();
+    app.data.pods.set_items(pods);
+    app
+      .data
+      .namespaces
+      .set_items(vec![KubeNs::default(), KubeNs::default()]);
+
+    let totals = ClusterTotals::from_app(&app);
+    assert_eq!(
+      totals,
+      ClusterTotals {
+        ready_nodes: 1,
+        total_nodes: 2,
+        running_pods: 2,
+        pending_pods: 1,
+        failed_pods: 1,
+        namespaces: 2,
+        cpu_used_milli: 2000.0,
+        cpu_allocatable_milli: 4000.0,
+        mem_used_mi: 2048.0,
+        mem_allocatable_mi: 8192.0,
+      }
+    );
+    assert_eq!(totals.cpu_summary(), "2.0 / 4.0 cores");
+    assert_eq!(totals.mem_summary(), "2.0Gi / 8.0Gi");
+    assert_eq!(totals.cpu_percent().round(), 50.0);
+    assert_eq!(totals.mem_percent().round(), 25.0);
+    // no nodes yet: no division by zero
+    assert_eq!(ClusterTotals::default().cpu_percent(), 0.0);
+  }
+
+  #[test]
+  fn test_draw_dashboard_shows_counters_and_nodes() {
+    let mut app = App::default();
+    let mut ready = node("node-a", "Ready", "500m", "2000m", "1024Mi", "4096Mi");
+    ready.cpu_percent = "25".into();
+    ready.mem_percent = "25".into();
+    app.data.nodes.set_items(vec![
+      ready,
+      node("node-b", "Not Ready", "0m", "0m", "0Mi", "0Mi"),
+    ]);
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 14)).unwrap();
+    terminal
+      .draw(|f| draw_dashboard(f, &app, f.area()))
+      .unwrap();
+    let text: String = terminal
+      .backend()
+      .buffer()
+      .content()
+      .iter()
+      .map(|cell| cell.symbol())
+      .collect();
+
+    assert!(text.contains("1/2 Ready"));
+    assert!(text.contains("0 running"));
+    assert!(text.contains("0.5 / 2.0 cores"));
+    assert!(text.contains("node-a CPU"));
+    assert!(text.contains("node-b CPU Not Ready"));
+  }
+}
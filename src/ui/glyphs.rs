@@ -0,0 +1,368 @@
+//! The glyphs the UI draws with, from `--glyphs` or `glyphs` in the config
+//! file: full Unicode by default, Nerd Font icons for indicators, or plain
+//! ASCII for terminals and fonts that show box-drawing and symbol glyphs as
+//! tofu.
+//!
+//! Borders, spinners and indicators are looked up in the table of the chosen
+//! set. With ASCII, the finished frame is also swept for the symbols left in
+//! hint text, such as the `·` separator, so nothing outside ASCII is drawn
+//! by KDash itself.
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+use log::warn;
+use ratatui::{buffer::Buffer, symbols::border};
+
+use crate::{banner::BANNER, config::KdashConfig};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum GlyphSet {
+  #[default]
+  Unicode,
+  NerdFont,
+  Ascii,
+}
+
+pub struct Glyphs {
+  pub border: border::Set<'static>,
+  /// Separator between tabs and under tab bars.
+  pub vertical: &'static str,
+  pub horizontal: &'static str,
+  /// Tree prefixes: last child, middle child, and the line past a parent.
+  pub tree_edge: &'static str,
+  pub tree_branch: &'static str,
+  pub tree_pipe: &'static str,
+  /// Before the header of an expanded and a collapsed group.
+  pub expanded: &'static str,
+  pub collapsed: &'static str,
+  /// Frames of the spinner shown while a view loads.
+  pub spinner: &'static [&'static str],
+  /// Frames of the header's network activity indicator.
+  pub activity: &'static [&'static str],
+  pub gauge_fill: char,
+  pub gauge_trough: char,
+  pub connected: &'static str,
+  pub disconnected: &'static str,
+  pub theme: &'static str,
+  pub paused: &'static str,
+  pub warning: &'static str,
+  pub ok: &'static str,
+  pub fail: &'static str,
+  /// Marks the current item of a list, such as the active context.
+  pub current: &'static str,
+  /// From a port or host to where it leads.
+  pub arrow: &'static str,
+  pub port_arrow: &'static str,
+  pub scroll_left: &'static str,
+  pub scroll_right: &'static str,
+  pub cursor: &'static str,
+  pub key_enter: &'static str,
+  pub key_tab: &'static str,
+  pub key_back_tab: &'static str,
+  pub key_left: &'static str,
+  pub key_right: &'static str,
+  pub key_up: &'static str,
+  pub key_down: &'static str,
+  pub logo: &'static str,
+}
+
+const UNICODE: Glyphs = Glyphs {
+  border: border::PLAIN,
+  vertical: "│",
+  horizontal: "─",
+  tree_edge: " └─",
+  tree_branch: " ├─",
+  tree_pipe: " │ ",
+  expanded: "▾",
+  collapsed: "▸",
+  spinner: &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+  activity: &["⠋⠴", "⠦⠙", "⠏⠼", "⠧⠹", "⠯⠽"],
+  gauge_fill: '█',
+  gauge_trough: '░',
+  connected: "●",
+  disconnected: "○",
+  theme: "◐",
+  paused: "⏸",
+  warning: "⚠",
+  ok: "✓",
+  fail: "✗",
+  current: "●",
+  arrow: "→",
+  port_arrow: "►",
+  scroll_left: "‹",
+  scroll_right: "›",
+  cursor: "▏",
+  key_enter: "⏎",
+  key_tab: "↹",
+  key_back_tab: "⇧↹",
+  key_left: "←",
+  key_right: "→",
+  key_up: "↑",
+  key_down: "↓",
+  logo: BANNER,
+};
+
+/// Unicode with Font Awesome icons from a patched Nerd Font for the
+/// indicators.
+const NERD_FONT: Glyphs = Glyphs {
+  connected: "\u{f1e6}",
+  disconnected: "\u{f127}",
+  theme: "\u{f53f}",
+  paused: "\u{f04c}",
+  warning: "\u{f071}",
+  ok: "\u{f00c}",
+  fail: "\u{f00d}",
+  current: "\u{f111}",
+  arrow: "\u{f061}",
+  port_arrow: "\u{f0da}",
+  scroll_left: "\u{f053}",
+  scroll_right: "\u{f054}",
+  ..UNICODE
+};
+
+const ASCII_LOGO: &str = r"
+ _  __
+| |/ /
+| ' /  ____
+| . \ |____|
+|_|\_\
+";
+
+const ASCII: Glyphs = Glyphs {
+  border: border::Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+  },
+  vertical: "|",
+  horizontal: "-",
+  tree_edge: " `-",
+  tree_branch: " |-",
+  tree_pipe: " | ",
+  expanded: "v",
+  collapsed: ">",
+  spinner: &["|", "/", "-", "\\"],
+  activity: &["|/", "/-", "-\\", "\\|"],
+  gauge_fill: '#',
+  gauge_trough: '.',
+  connected: "*",
+  disconnected: "o",
+  theme: "@",
+  paused: "||",
+  warning: "!",
+  ok: "+",
+  fail: "x",
+  current: "*",
+  arrow: "->",
+  port_arrow: ">",
+  scroll_left: "<",
+  scroll_right: ">",
+  cursor: "_",
+  key_enter: "Enter",
+  key_tab: "Tab",
+  key_back_tab: "S-Tab",
+  key_left: "Left",
+  key_right: "Right",
+  key_up: "Up",
+  key_down: "Down",
+  logo: ASCII_LOGO,
+};
+
+impl GlyphSet {
+  pub fn glyphs(self) -> &'static Glyphs {
+    match self {
+      Self::Unicode => &UNICODE,
+      Self::NerdFont => &NERD_FONT,
+      Self::Ascii => &ASCII,
+    }
+  }
+}
+
+static GLYPH_SET: OnceLock<GlyphSet> = OnceLock::new();
+
+/// The glyph set from the CLI, else the config file. An unknown name in the
+/// config is reported and Unicode is kept.
+fn resolve_glyph_set(cli: Option<GlyphSet>, config: &KdashConfig) -> (GlyphSet, Vec<String>) {
+  match (cli, config.glyphs.as_deref()) {
+    (Some(set), _) => (set, vec![]),
+    (None, Some(name)) => match GlyphSet::from_str(name.trim(), true) {
+      Ok(set) => (set, vec![]),
+      Err(_) => {
+        let warning = format!(
+          "Unknown glyph set '{}', using unicode. Use one of unicode, nerd-font or ascii",
+          name
+        );
+        warn!("{}", warning);
+        (GlyphSet::default(), vec![warning])
+      }
+    },
+    (None, None) => (GlyphSet::default(), vec![]),
+  }
+}
+
+pub fn initialize_glyphs(cli: Option<GlyphSet>, config: &KdashConfig) -> Vec<String> {
+  let (set, warnings) = resolve_glyph_set(cli, config);
+  let _ = GLYPH_SET.set(set);
+  warnings
+}
+
+pub fn glyph_set() -> GlyphSet {
+  GLYPH_SET.get().copied().unwrap_or_default()
+}
+
+/// The glyphs of the chosen set.
+pub fn glyphs() -> &'static Glyphs {
+  glyph_set().glyphs()
+}
+
+/// ASCII stand-in for a symbol in hint or status text, so the sweep keeps
+/// every cell one column wide.
+fn ascii_fallback(symbol: &str) -> Option<&'static str> {
+  let mut chars = symbol.chars();
+  let (Some(c), None) = (chars.next(), chars.next()) else {
+    return None;
+  };
+  Some(match c {
+    c if c.is_ascii() => return None,
+    '·' | '—' | '–' => "-",
+    '…' => ".",
+    '‹' | '«' => "<",
+    '›' | '»' => ">",
+    // Box drawing: lines that run across, down, and the corners and joins.
+    '─' | '━' | '═' | '┄' | '┈' | '╌' => "-",
+    '│' | '┃' | '║' | '┆' | '┊' | '╎' | '▏' | '▕' => "|",
+    '\u{2500}'..='\u{257f}' => "+",
+    '\u{2580}'..='\u{259f}' => "#",
+    '\u{2800}'..='\u{28ff}' => "*",
+    _ => return None,
+  })
+}
+
+/// Replace the symbols KDash draws that are outside ASCII. Text from the
+/// cluster, such as log lines and names, is left alone.
+pub fn asciify(buffer: &mut Buffer) {
+  let area = buffer.area;
+  for y in area.top()..area.bottom() {
+    for x in area.left()..area.right() {
+      let cell = &mut buffer[(x, y)];
+      if let Some(ascii) = ascii_fallback(cell.symbol()) {
+        cell.set_symbol(ascii);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ratatui::{
+    layout::Rect,
+    widgets::{Block, Borders, Paragraph, Widget},
+  };
+
+  use super::*;
+
+  #[test]
+  fn test_resolve_glyph_set_prefers_the_cli() {
+    let mut config = KdashConfig {
+      glyphs: Some("Nerd-Font".into()),
+      ..KdashConfig::default()
+    };
+    assert_eq!(
+      resolve_glyph_set(None, &config),
+      (GlyphSet::NerdFont, vec![])
+    );
+    assert_eq!(
+      resolve_glyph_set(Some(GlyphSet::Ascii), &config),
+      (GlyphSet::Ascii, vec![])
+    );
+
+    config.glyphs = Some("emoji".into());
+    assert_eq!(
+      resolve_glyph_set(None, &config),
+      (
+        GlyphSet::Unicode,
+        vec![
+          "Unknown glyph set 'emoji', using unicode. Use one of unicode, nerd-font or ascii".into()
+        ]
+      )
+    );
+    assert_eq!(
+      resolve_glyph_set(None, &KdashConfig::default()),
+      (GlyphSet::Unicode, vec![])
+    );
+  }
+
+  #[test]
+  fn test_ascii_glyphs_are_ascii() {
+    let glyphs = GlyphSet::Ascii.glyphs();
+    let border = glyphs.border;
+    let mut symbols = vec![
+      border.top_left,
+      border.top_right,
+      border.bottom_left,
+      border.bottom_right,
+      border.vertical_left,
+      border.vertical_right,
+      border.horizontal_top,
+      border.horizontal_bottom,
+      glyphs.vertical,
+      glyphs.horizontal,
+      glyphs.tree_edge,
+      glyphs.tree_branch,
+      glyphs.tree_pipe,
+      glyphs.connected,
+      glyphs.disconnected,
+      glyphs.theme,
+      glyphs.paused,
+      glyphs.warning,
+      glyphs.ok,
+      glyphs.fail,
+      glyphs.current,
+      glyphs.arrow,
+      glyphs.port_arrow,
+      glyphs.scroll_left,
+      glyphs.scroll_right,
+      glyphs.cursor,
+      glyphs.key_enter,
+      glyphs.key_tab,
+      glyphs.key_back_tab,
+      glyphs.key_left,
+      glyphs.key_right,
+      glyphs.key_up,
+      glyphs.key_down,
+      glyphs.logo,
+    ];
+    symbols.extend(glyphs.spinner);
+    symbols.extend(glyphs.activity);
+    for symbol in symbols {
+      assert!(symbol.is_ascii(), "{:?}", symbol);
+    }
+    assert!(glyphs.gauge_fill.is_ascii() && glyphs.gauge_trough.is_ascii());
+    // The Nerd Font set only swaps the indicators.
+    assert_eq!(GlyphSet::NerdFont.glyphs().border, border::PLAIN);
+  }
+
+  #[test]
+  fn test_asciify_replaces_drawn_symbols() {
+    let area = Rect::new(0, 0, 24, 3);
+    let mut buffer = Buffer::empty(area);
+    Paragraph::new("a · b… ├ █ ⠋ ✓ 日")
+      .block(Block::default().borders(Borders::ALL))
+      .render(area, &mut buffer);
+    asciify(&mut buffer);
+
+    let lines: Vec<String> = (0..area.height)
+      .map(|y| (0..area.width).map(|x| buffer[(x, y)].symbol()).collect())
+      .collect();
+    assert_eq!(lines[0], "+----------------------+");
+    // Text that is not a drawing symbol, like a check mark from a log line or
+    // wide characters, passes through.
+    assert_eq!(lines[1], "|a - b. + # * ✓ 日      |");
+    assert_eq!(lines[2], "+----------------------+");
+  }
+}
@@ -6,9 +6,10 @@ use ratatui::{
   Frame,
 };
 
+use super::glyphs::glyphs;
 use super::utils::{
-  help_part, key_hints, mixed_bold_line, style_label, style_primary, style_secondary, style_text,
-  title_with_dual_style,
+  help_part, key_hints, mixed_bold_line, owned_filter_status_parts, style_help, style_label,
+  style_primary, style_secondary, style_text, text_matches_filter, title_with_dual_style,
 };
 use crate::app::{
   key_binding::{get_help_sections, HContext, HelpSection, DEFAULT_KEYBINDING},
@@ -17,21 +18,30 @@ use crate::app::{
 use crate::ui::theme::Palette;
 
 /// Full-page help: keybindings grouped by context into two columns,
-/// scrollable with up/down. Layout is derived entirely from the keymap.
+/// scrollable with up/down and narrowed by the help filter. Layout is
+/// derived entirely from the keymap.
 pub fn draw_help(f: &mut Frame<'_>, app: &mut App, area: Rect) {
   let palette = app.palette;
-  let sections = get_help_sections();
-
-  let hint = mixed_bold_line(
-    [help_part(format!(
-      "{}:scroll · {}:back ",
-      key_hints(&[DEFAULT_KEYBINDING.up.key, DEFAULT_KEYBINDING.down.key]),
-      DEFAULT_KEYBINDING.esc.key.symbol(),
-    ))],
-    palette,
-  );
+  let sections = filter_sections(get_help_sections(), &app.help_filter);
+
+  let mut parts = vec![help_part(format!(
+    "{}:scroll · ",
+    key_hints(&[DEFAULT_KEYBINDING.up.key, DEFAULT_KEYBINDING.down.key]),
+  ))];
+  parts.extend(owned_filter_status_parts(
+    &app.help_filter,
+    app.help_filter_active,
+  ));
+  if !app.help_filter_active {
+    parts.push(help_part(format!(
+      " · {}:back ",
+      DEFAULT_KEYBINDING.esc.key.symbol()
+    )));
+  }
+  let hint = mixed_bold_line(parts, palette);
   let block = Block::default()
     .borders(Borders::ALL)
+    .border_set(glyphs().border)
     .border_style(style_secondary(palette))
     .title(title_with_dual_style(" Help ".to_string(), hint, palette))
     .padding(Padding::new(2, 2, 1, 1));
@@ -41,6 +51,14 @@ pub fn draw_help(f: &mut Frame<'_>, app: &mut App, area: Rect) {
   if inner.width == 0 || inner.height == 0 {
     return;
   }
+  if sections.is_empty() {
+    f.render_widget(
+      Paragraph::new(format!("No keybinding matches '{}'", app.help_filter))
+        .style(style_help(palette)),
+      inner,
+    );
+    return;
+  }
 
   let n_cols = if inner.width >= 70 { 2 } else { 1 };
   let columns = columns_for(&sections, n_cols);
@@ -73,6 +91,23 @@ pub fn draw_help(f: &mut Frame<'_>, app: &mut App, area: Rect) {
   }
 }
 
+/// Only the bindings whose keys or description match `filter`, in sections
+/// that still have any.
+fn filter_sections(sections: Vec<HelpSection>, filter: &str) -> Vec<HelpSection> {
+  if filter.is_empty() {
+    return sections;
+  }
+  sections
+    .into_iter()
+    .filter_map(|mut section| {
+      section.rows.retain(|(keys, desc)| {
+        text_matches_filter(filter, keys) || text_matches_filter(filter, desc)
+      });
+      (!section.rows.is_empty()).then_some(section)
+    })
+    .collect()
+}
+
 /// Rows a section occupies: title + one per binding + a trailing blank.
 fn section_height(section: &HelpSection) -> usize {
   section.rows.len() + 2
@@ -126,11 +161,20 @@ mod tests {
   use crate::ui::theme::{palette_for, ThemeName};
 
   fn render(width: u16, height: u16) -> (Vec<String>, ratatui::buffer::Buffer) {
+    render_filtered(width, height, "")
+  }
+
+  fn render_filtered(
+    width: u16,
+    height: u16,
+    filter: &str,
+  ) -> (Vec<String>, ratatui::buffer::Buffer) {
     let backend = TestBackend::new(width, height);
     let mut terminal = Terminal::new(backend).unwrap();
     terminal
       .draw(|f| {
         let mut app = App::default();
+        app.help_filter = filter.into();
         draw_help(f, &mut app, f.area());
       })
       .unwrap();
@@ -147,7 +191,7 @@ mod tests {
 
   #[test]
   fn test_draw_help_renders_grouped_sections() {
-    let (lines, _) = render(160, 40);
+    let (lines, _) = render(160, 66);
     let joined = lines.join("\n");
 
     // Panel title + the three context group headings.
@@ -170,7 +214,7 @@ mod tests {
 
   #[test]
   fn test_help_two_columns_stack_utilization_under_general() {
-    let (lines, _) = render(160, 40);
+    let (lines, _) = render(160, 66);
 
     let general_x = title_column(&lines, "General").expect("General heading");
     let util_x = title_column(&lines, "Utilization").expect("Utilization heading");
@@ -184,7 +228,7 @@ mod tests {
 
   #[test]
   fn test_draw_help_colours() {
-    let (_, buffer) = render(160, 40);
+    let (_, buffer) = render(160, 60);
     let p = palette_for(ThemeName::Macchiato);
 
     // Border + " Help " title → secondary.
@@ -192,4 +236,21 @@ mod tests {
     assert_eq!(buffer[(2, 0)].fg, p.secondary);
     assert!(buffer[(2, 0)].modifier.contains(Modifier::BOLD));
   }
+
+  #[test]
+  fn test_help_filter_keeps_only_matching_bindings() {
+    let (lines, _) = render_filtered(160, 30, "namespace");
+    let joined = lines.join("\n");
+    assert!(joined.contains("Select namespace"));
+    assert!(!joined.contains("Cycle through main views"));
+    // sections without a match are left out
+    assert!(!joined.contains("Utilization"));
+
+    // keys match too
+    let (lines, _) = render_filtered(160, 30, "alt+v");
+    assert!(lines.join("\n").contains("Cycle footer"));
+
+    let (lines, _) = render_filtered(160, 30, "zzzz");
+    assert!(lines.join("\n").contains("No keybinding matches 'zzzz'"));
+  }
 }
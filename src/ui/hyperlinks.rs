@@ -0,0 +1,120 @@
+//! Make URLs on screen clickable with OSC 8 hyperlinks, for terminals that
+//! support them.
+//!
+//! Links are found in the finished frame rather than in each view, so logs,
+//! events, describe output and tables all get them. Each cell of a URL is
+//! wrapped in its own link escape and given a forced width of one, since
+//! ratatui would otherwise count the escape's bytes as screen columns.
+use std::{num::NonZeroU16, ops::Range};
+
+use ratatui::buffer::{Buffer, CellDiffOption};
+
+const SCHEMES: [&str; 2] = ["https://", "http://"];
+
+/// Wrap every `http(s)://` URL in the buffer in hyperlink escapes. URLs are
+/// found one row at a time, so one wrapped across lines links its first part.
+pub fn linkify(buffer: &mut Buffer) {
+  let area = buffer.area;
+  for y in area.top()..area.bottom() {
+    // Only single ASCII characters can be part of a URL; anything else breaks
+    // the row so a link never spans a wide or styled-symbol cell.
+    let row: String = (area.left()..area.right())
+      .map(|x| {
+        let mut chars = buffer[(x, y)].symbol().chars();
+        match (chars.next(), chars.next()) {
+          (Some(c), None) if c.is_ascii_graphic() => c,
+          _ => ' ',
+        }
+      })
+      .collect();
+    for range in find_urls(&row) {
+      let url = &row[range.clone()];
+      for x in range {
+        let cell = &mut buffer[(area.left() + x as u16, y)];
+        let symbol = hyperlink(url, cell.symbol());
+        cell.set_symbol(&symbol);
+        cell.diff_option = CellDiffOption::ForcedWidth(NonZeroU16::MIN);
+      }
+    }
+  }
+}
+
+fn hyperlink(url: &str, text: &str) -> String {
+  format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+/// Byte ranges of the URLs in `text`, which must be ASCII. A URL runs to the
+/// next space or quote, less trailing punctuation and an unmatched closing
+/// bracket, so `(see https://x.io/a).` links just `https://x.io/a`.
+fn find_urls(text: &str) -> Vec<Range<usize>> {
+  let mut urls = vec![];
+  let mut from = 0;
+  while let Some((start, scheme)) = SCHEMES
+    .iter()
+    .filter_map(|scheme| text[from..].find(scheme).map(|i| (from + i, scheme)))
+    .min_by_key(|(start, _)| *start)
+  {
+    let rest = &text[start..];
+    let mut end = start
+      + rest
+        .find(|c: char| c.is_ascii_whitespace() || matches!(c, '"' | '\'' | '<' | '>' | '`'))
+        .unwrap_or(rest.len());
+    loop {
+      let url = &text[start..end];
+      let trimmed = match url.chars().last() {
+        Some('.' | ',' | ';' | ':' | '!' | '?') => true,
+        Some(')') => url.matches('(').count() < url.matches(')').count(),
+        Some(']') => url.matches('[').count() < url.matches(']').count(),
+        _ => false,
+      };
+      if !trimmed {
+        break;
+      }
+      end -= 1;
+    }
+    if end > start + scheme.len() {
+      urls.push(start..end);
+    }
+    from = end.max(start + scheme.len());
+  }
+  urls
+}
+
+#[cfg(test)]
+mod tests {
+  use ratatui::{buffer::Cell, layout::Rect};
+
+  use super::*;
+
+  #[test]
+  fn test_find_urls_trims_trailing_punctuation() {
+    let text = "pulled (see https://docs.k8s.io/a_(b)).  http://10.0.0.1:8080/healthz, https:// x";
+    let urls: Vec<&str> = find_urls(text).into_iter().map(|r| &text[r]).collect();
+    assert_eq!(
+      urls,
+      vec!["https://docs.k8s.io/a_(b)", "http://10.0.0.1:8080/healthz"]
+    );
+    assert!(find_urls("no links here").is_empty());
+  }
+
+  #[test]
+  fn test_linkify_wraps_each_url_cell() {
+    let mut buffer = Buffer::with_lines(["url: https://a.io ok", "plain"]);
+    linkify(&mut buffer);
+
+    assert_eq!(buffer[(4, 0)].symbol(), " ");
+    let cell = &buffer[(5, 0)];
+    assert_eq!(cell.symbol(), "\x1b]8;;https://a.io\x1b\\h\x1b]8;;\x1b\\");
+    assert_eq!(
+      cell.diff_option,
+      CellDiffOption::ForcedWidth(NonZeroU16::MIN)
+    );
+    assert_eq!(
+      buffer[(16, 0)].symbol(),
+      "\x1b]8;;https://a.io\x1b\\o\x1b]8;;\x1b\\"
+    );
+    assert_eq!(buffer[(18, 0)].symbol(), "o");
+    assert_eq!(buffer[(0, 1)], Cell::new("p"));
+    assert_eq!(buffer.area, Rect::new(0, 0, 20, 2));
+  }
+}
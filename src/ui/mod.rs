@@ -1,7 +1,11 @@
 use rand::RngExt;
+mod dashboard;
+pub mod glyphs;
 mod help;
+mod hyperlinks;
 mod overview;
 pub mod resource_tabs;
+pub mod summary;
 pub mod theme;
 pub mod utils;
 
@@ -14,18 +18,28 @@ use ratatui::{
 };
 
 use self::{
+  dashboard::draw_dashboard,
+  glyphs::{asciify, glyph_set, glyphs, GlyphSet},
   help::draw_help,
   overview::draw_overview,
+  resource_tabs::tab_rects,
   utils::{
     action_hint, centered_rect, default_part, draw_popup_menu, help_part, hint_key_glyph,
-    key_hints, mixed_bold_line, mixed_line, split_hint_suffix, style_failure,
-    style_main_background, style_secondary, style_success, style_text, style_warning,
+    key_hints, mixed_bold_line, mixed_line, spinner_frame, split_hint_suffix, style_failure,
+    style_help, style_main_background, style_secondary, style_success, style_text, style_warning,
     title_with_dual_style, vertical_chunks,
   },
 };
 use crate::app::{
-  contexts::ContextResource, key_binding::DEFAULT_KEYBINDING, metrics::UtilizationResource,
-  models::AppResource, port_forward::PortForwardStatus, troubleshoot::TroubleshootResource,
+  apply_status::{ApplyProgress, APPLY_WATCH_TIMEOUT},
+  contexts::ContextResource,
+  footer::FooterVerbosity,
+  key_binding::DEFAULT_KEYBINDING,
+  metrics::UtilizationResource,
+  models::AppResource,
+  port_forward::PortForwardStatus,
+  troubleshoot::TroubleshootResource,
+  watchlist::{PinStatus, WatchlistResource},
   ActiveBlock, App, RouteId,
 };
 use crate::event::Key;
@@ -35,6 +49,8 @@ pub static HIGHLIGHT: &str = "=> ";
 pub fn draw(f: &mut Frame<'_>, app: &mut App) {
   let block = Block::default().style(style_main_background(app.palette));
   f.render_widget(block, f.area());
+  // Only drawn on the overview; recorded again below when it is.
+  app.context_tabs.rects.clear();
 
   // Errors and status both surface as floating toasts (drawn last), so the
   // layout no longer reshuffles for them.
@@ -43,6 +59,7 @@ pub fn draw(f: &mut Frame<'_>, app: &mut App) {
       Constraint::Length(1), // title
       Constraint::Length(3), // header tabs
       Constraint::Min(0),    // main tabs
+      Constraint::Length(1), // footer
     ],
     f.area(),
   );
@@ -50,8 +67,9 @@ pub fn draw(f: &mut Frame<'_>, app: &mut App) {
   draw_app_title(f, app, chunks[0]);
   // draw header tabs amd text
   draw_app_header(f, app, chunks[1]);
+  draw_app_footer(f, app, chunks[3]);
 
-  let last_chunk = chunks[chunks.len() - 1];
+  let last_chunk = chunks[2];
   match app.get_current_route().id {
     RouteId::HelpMenu => {
       draw_help(f, app, last_chunk);
@@ -62,6 +80,23 @@ pub fn draw(f: &mut Frame<'_>, app: &mut App) {
     RouteId::Utilization => {
       UtilizationResource::render(ActiveBlock::Utilization, f, app, last_chunk);
     }
+    RouteId::Dashboard => {
+      draw_dashboard(f, app, last_chunk);
+    }
+    RouteId::Watchlist => {
+      let active_block = app.get_current_route().active_block;
+      if active_block == ActiveBlock::Watchlist {
+        WatchlistResource::render(active_block, f, app, last_chunk);
+      } else {
+        let outer_block = Block::default()
+          .borders(Borders::ALL)
+          .border_set(glyphs().border)
+          .style(style_secondary(app.palette));
+        let inner = outer_block.inner(last_chunk);
+        f.render_widget(outer_block, last_chunk);
+        WatchlistResource::render(active_block, f, app, inner);
+      }
+    }
     RouteId::Troubleshoot => {
       let active_block = app.get_current_route().active_block;
       if active_block == ActiveBlock::Troubleshoot {
@@ -69,6 +104,7 @@ pub fn draw(f: &mut Frame<'_>, app: &mut App) {
       } else {
         let outer_block = Block::default()
           .borders(Borders::ALL)
+          .border_set(glyphs().border)
           .style(style_secondary(app.palette));
         let inner = outer_block.inner(last_chunk);
         f.render_widget(outer_block, last_chunk);
@@ -84,9 +120,30 @@ pub fn draw(f: &mut Frame<'_>, app: &mut App) {
   if app.action_menu.is_some() {
     draw_action_menu(f, app);
   }
+  if app.finder.is_some() {
+    draw_finder(f, app);
+  }
+  if app.ns_picker.is_some() {
+    draw_ns_picker(f, app);
+  }
   if app.show_port_forwards {
     draw_port_forwards(f, app);
   }
+  if app.show_audit_log {
+    draw_audit_log(f, app);
+  }
+  if app.connection_info.is_some() {
+    draw_connection_info(f, app);
+  }
+  if app.show_api_stats {
+    draw_api_stats(f, app);
+  }
+  if app.apply_status.is_some() {
+    draw_apply_status(f, app);
+  }
+  if app.cmd_output.is_some() {
+    draw_cmd_output(f, app);
+  }
   if app.input_modal.is_some() {
     draw_input_modal(f, app);
   }
@@ -97,6 +154,13 @@ pub fn draw(f: &mut Frame<'_>, app: &mut App) {
   // Toasts float over everything, bottom-centred: the persistent error toast
   // (dismissed with Esc) on the bottom row, the transient status toast above it.
   draw_toasts(f, app);
+
+  if glyph_set() == GlyphSet::Ascii {
+    asciify(f.buffer_mut());
+  }
+  if app.hyperlinks {
+    hyperlinks::linkify(f.buffer_mut());
+  }
 }
 
 fn draw_modal(f: &mut Frame<'_>, app: &App) {
@@ -134,6 +198,7 @@ fn draw_modal(f: &mut Frame<'_>, app: &App) {
       palette,
     ))
     .borders(Borders::ALL)
+    .border_set(glyphs().border)
     // `Clear` resets the area to the terminal default; the block style fills
     // the whole popup, so set the theme background here too.
     .style(style_failure(palette).bg(palette.bg));
@@ -187,6 +252,7 @@ fn draw_input_modal(f: &mut Frame<'_>, app: &App) {
       palette,
     ))
     .borders(Borders::ALL)
+    .border_set(glyphs().border)
     // `Clear` resets the area to the terminal default; the block style fills
     // the whole popup, so set the theme background here too.
     .style(style_secondary(palette).bg(palette.bg));
@@ -239,8 +305,111 @@ fn draw_action_menu(f: &mut Frame<'_>, app: &mut App) {
   draw_popup_menu(f, area, title, items, &mut menu.state, palette);
 }
 
+/// `Ctrl-p` finder: the query in the title and the best matches below it as
+/// `kind  namespace/name`.
+fn draw_finder(f: &mut Frame<'_>, app: &mut App) {
+  let palette = app.palette;
+  let Some(finder) = app.finder.as_mut() else {
+    return;
+  };
+
+  let kind_width = finder.matches().map(|e| e.kind.len()).max().unwrap_or(0);
+  let mut items: Vec<ListItem<'_>> = finder
+    .matches()
+    .map(|entry| {
+      ListItem::new(mixed_line(
+        [
+          help_part(format!("{:<width$}  ", entry.kind, width = kind_width)),
+          default_part(entry.path()),
+        ],
+        palette,
+      ))
+    })
+    .collect();
+  if items.is_empty() {
+    let empty = if finder.entry_count() == 0 {
+      "Nothing loaded yet"
+    } else {
+      "No matches"
+    };
+    items.push(ListItem::new(mixed_line([help_part(empty)], palette)));
+  }
+
+  let screen = f.area();
+  let width = screen.width.saturating_sub(4).min(80);
+  let height = (items.len() as u16)
+    .saturating_add(2)
+    .min(screen.height.saturating_sub(4).max(3));
+  let area = centered_rect(width, height, screen);
+  let title = title_with_dual_style(
+    format!(" Find > {}_ ", finder.query),
+    mixed_bold_line(
+      [help_part(format!(
+        "{}/{} · {}:jump · {}:close ",
+        finder.matched,
+        finder.entry_count(),
+        DEFAULT_KEYBINDING.submit.key.symbol(),
+        DEFAULT_KEYBINDING.esc.key.symbol()
+      ))],
+      palette,
+    ),
+    palette,
+  );
+  draw_popup_menu(f, area, title, items, &mut finder.state, palette);
+}
+
+/// `Ctrl-n` namespace picker: the query in the title, all namespaces on top
+/// and the namespace in view marked as active.
+fn draw_ns_picker(f: &mut Frame<'_>, app: &mut App) {
+  let palette = app.palette;
+  let Some(picker) = app.ns_picker.as_mut() else {
+    return;
+  };
+
+  let mut items: Vec<ListItem<'_>> = picker
+    .matches()
+    .map(|choice| {
+      if *choice == picker.active {
+        ListItem::new(Line::from(vec![
+          Span::styled(choice.label().to_owned(), style_success(palette)),
+          Span::styled(" (active)", style_help(palette)),
+        ]))
+      } else {
+        ListItem::new(Span::styled(choice.label().to_owned(), style_text(palette)))
+      }
+    })
+    .collect();
+  if items.is_empty() {
+    items.push(ListItem::new(mixed_line(
+      [help_part("No matches")],
+      palette,
+    )));
+  }
+
+  let screen = f.area();
+  let width = screen.width.saturating_sub(4).min(60);
+  let height = (items.len() as u16)
+    .saturating_add(2)
+    .min(screen.height.saturating_sub(4).max(3));
+  let area = centered_rect(width, height, screen);
+  let title = title_with_dual_style(
+    format!(" Namespace > {}_ ", picker.query),
+    mixed_bold_line(
+      [help_part(format!(
+        "{}:select · {}:close ",
+        DEFAULT_KEYBINDING.submit.key.symbol(),
+        DEFAULT_KEYBINDING.esc.key.symbol()
+      ))],
+      palette,
+    ),
+    palette,
+  );
+  draw_popup_menu(f, area, title, items, &mut picker.state, palette);
+}
+
 /// Active `kubectl port-forward` overlay: one row per forward with a
-/// status-coloured tag, navigable, stop with `d`/Enter.
+/// status-coloured tag, navigable, stop with `d`/Enter and restart a failed
+/// one with `r`.
 fn draw_port_forwards(f: &mut Frame<'_>, app: &mut App) {
   let palette = app.palette;
   if app.port_forwards.is_empty() {
@@ -259,8 +428,13 @@ fn draw_port_forwards(f: &mut Frame<'_>, app: &mut App) {
       ListItem::new(Line::from(vec![
         Span::styled(
           format!(
-            "{}/{} ({})  {} → {}  ",
-            pf.kind, pf.name, pf.namespace, pf.local_port, pf.remote_port
+            "{} {} {}/{}/{}:{}  ",
+            pf.local_port,
+            glyphs().arrow,
+            pf.namespace,
+            pf.kind,
+            pf.name,
+            pf.remote_port
           ),
           style_text(palette),
         ),
@@ -274,9 +448,10 @@ fn draw_port_forwards(f: &mut Frame<'_>, app: &mut App) {
     " Port-forwards ".to_string(),
     mixed_bold_line(
       [help_part(format!(
-        "· {}/{}:stop · {}:close ",
+        "· {}/{}:stop · {}:restart failed · {}:close ",
         Key::Char('d').symbol(),
         DEFAULT_KEYBINDING.submit.key.symbol(),
+        Key::Char('r').symbol(),
         DEFAULT_KEYBINDING.esc.key.symbol()
       ))],
       palette,
@@ -286,6 +461,317 @@ fn draw_port_forwards(f: &mut Frame<'_>, app: &mut App) {
   draw_popup_menu(f, area, title, items, &mut app.port_forwards_state, palette);
 }
 
+/// Audit overlay: the mutating actions taken this session, oldest first,
+/// with the context they ran against and whether they went through.
+fn draw_audit_log(f: &mut Frame<'_>, app: &mut App) {
+  let palette = app.palette;
+  let items: Vec<ListItem<'_>> = app
+    .audit_log
+    .iter()
+    .map(|entry| {
+      let (outcome, outcome_style) = if entry.succeeded {
+        ("ok", style_success(palette))
+      } else {
+        ("failed", style_failure(palette))
+      };
+      ListItem::new(Line::from(vec![
+        Span::styled(format!("{} ", entry.timestamp), style_secondary(palette)),
+        Span::styled(format!("[{}] ", entry.context), style_help(palette)),
+        Span::styled(
+          format!("{} {}  ", entry.action, entry.target),
+          style_text(palette),
+        ),
+        Span::styled(format!("[{}]", outcome), outcome_style),
+      ]))
+    })
+    .collect();
+
+  let height = (items.len() as u16).min(15).saturating_add(2);
+  let area = centered_rect(80, height, f.area());
+  let title = title_with_dual_style(
+    " Audit log ".to_string(),
+    mixed_bold_line(
+      [help_part(format!(
+        "· {}:scroll · {}:close ",
+        key_hints(&[DEFAULT_KEYBINDING.up.key, DEFAULT_KEYBINDING.down.key]),
+        DEFAULT_KEYBINDING.esc.key.symbol()
+      ))],
+      palette,
+    ),
+    palette,
+  );
+  draw_popup_menu(f, area, title, items, &mut app.audit_log_state, palette);
+}
+
+/// Connection info overlay: the context, cluster, user, server, auth and TLS
+/// of the latest kube client, or why it could not be built.
+fn draw_connection_info(f: &mut Frame<'_>, app: &App) {
+  let palette = app.palette;
+  let Some(info) = app.connection_info.as_ref() else {
+    return;
+  };
+  let mut rows = info.rows();
+  if let Some(redactor) = app.redactor() {
+    for (_, value) in &mut rows {
+      *value = redactor.redact_pairs(value).into_owned();
+    }
+  }
+  let label_width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+  let lines: Vec<Line<'_>> = rows
+    .into_iter()
+    .map(|(label, value)| {
+      let value_style = if label == "Error" || value.starts_with("not verified") {
+        style_failure(palette)
+      } else {
+        style_text(palette)
+      };
+      Line::from(vec![
+        Span::styled(
+          format!(" {:<width$}  ", label, width = label_width),
+          style_secondary(palette),
+        ),
+        Span::styled(value, value_style),
+      ])
+    })
+    .collect();
+
+  let height = (lines.len() as u16).saturating_add(2);
+  let area = centered_rect(90, height, f.area());
+  let title = title_with_dual_style(
+    " Connection ".to_string(),
+    mixed_bold_line(
+      [help_part(format!(
+        "· {}:close ",
+        DEFAULT_KEYBINDING.esc.key.symbol()
+      ))],
+      palette,
+    ),
+    palette,
+  );
+  let block = Block::default()
+    .borders(Borders::ALL)
+    .border_set(glyphs().border)
+    .border_style(style_secondary(palette))
+    .title(title)
+    .style(style_text(palette).bg(palette.bg));
+  f.render_widget(Clear, area);
+  f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// API requests overlay: requests per view since start, the rate over the
+/// last minute, errors and average latency, the busiest view first.
+fn draw_api_stats(f: &mut Frame<'_>, app: &App) {
+  let palette = app.palette;
+  let now = std::time::Instant::now();
+  let rows = app.api_stats.rows(now);
+  let label_width = rows
+    .iter()
+    .map(|row| row.label.chars().count())
+    .max()
+    .unwrap_or(0)
+    .max(4);
+  let cells = |label: &str, requests: &str, rate: &str, errors: &str, average: &str| {
+    format!(
+      " {:<width$}  {:>8}  {:>7}  {:>6}  {:>8}",
+      label,
+      requests,
+      rate,
+      errors,
+      average,
+      width = label_width
+    )
+  };
+  let mut lines = vec![Line::styled(
+    cells("View", "Requests", "Per sec", "Errors", "Average"),
+    style_secondary(palette),
+  )];
+  lines.extend(rows.iter().enumerate().map(|(i, row)| {
+    let style = if row.errors > 0 {
+      style_failure(palette)
+    } else {
+      style_text(palette)
+    };
+    let style = if i == 0 {
+      style.add_modifier(Modifier::BOLD)
+    } else {
+      style
+    };
+    Line::styled(
+      cells(
+        &row.label,
+        &row.requests.to_string(),
+        &format!("{:.2}", row.per_second),
+        &row.errors.to_string(),
+        &row.average_label(),
+      ),
+      style,
+    )
+  }));
+
+  let height = (lines.len() as u16).saturating_add(2);
+  let area = centered_rect(80, height, f.area());
+  let since = chrono::Duration::from_std(app.api_stats.elapsed(now)).unwrap_or_default();
+  let title = title_with_dual_style(
+    format!(
+      " API requests · last {} ",
+      crate::app::utils::duration_to_age(since, true)
+    ),
+    mixed_bold_line(
+      [help_part(format!(
+        "· {}:close ",
+        DEFAULT_KEYBINDING.esc.key.symbol()
+      ))],
+      palette,
+    ),
+    palette,
+  );
+  let block = Block::default()
+    .borders(Borders::ALL)
+    .border_set(glyphs().border)
+    .border_style(style_secondary(palette))
+    .title(title)
+    .style(style_text(palette).bg(palette.bg));
+  f.render_widget(Clear, area);
+  f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Apply status overlay: a checklist of the objects applied from the
+/// clipboard, each ready or with what it is waiting on, and the overall
+/// progress in the title.
+fn draw_apply_status(f: &mut Frame<'_>, app: &App) {
+  let palette = app.palette;
+  let Some(status) = app.apply_status.as_ref() else {
+    return;
+  };
+  let label_width = status
+    .objects
+    .iter()
+    .map(|applied| applied.object.to_string().chars().count())
+    .max()
+    .unwrap_or(0);
+  let lines: Vec<Line<'_>> = status
+    .objects
+    .iter()
+    .map(|applied| {
+      let (mark, style) = match &applied.status {
+        _ if applied.is_ready() => (glyphs().ok, style_success(palette)),
+        PinStatus::Loading | PinStatus::Live { .. } => {
+          (spinner_frame(app.tick_count), style_warning(palette))
+        }
+        PinStatus::Gone | PinStatus::Failed(_) => (glyphs().fail, style_failure(palette)),
+      };
+      Line::from(vec![
+        Span::styled(format!(" {} ", mark), style),
+        Span::styled(
+          format!("{:<width$}  ", applied.object, width = label_width),
+          style_text(palette),
+        ),
+        Span::styled(applied.status.text().to_owned(), style_secondary(palette)),
+      ])
+    })
+    .collect();
+
+  let (ready, total) = (status.ready_count(), status.objects.len());
+  let progress = match status.progress {
+    ApplyProgress::Watching => format!("{}/{} ready", ready, total),
+    ApplyProgress::AllReady => "all ready".to_owned(),
+    ApplyProgress::TimedOut => format!(
+      "{}/{} ready, stopped after {} min",
+      ready,
+      total,
+      APPLY_WATCH_TIMEOUT.as_secs() / 60
+    ),
+    ApplyProgress::Stopped => format!("{}/{} ready, stopped", ready, total),
+  };
+  let height = (lines.len() as u16).saturating_add(2);
+  let area = centered_rect(f.area().width.saturating_sub(8).max(20), height, f.area());
+  let title = title_with_dual_style(
+    format!(" Applied: {} ", progress),
+    mixed_bold_line(
+      [help_part(format!(
+        "· {}:close ",
+        DEFAULT_KEYBINDING.esc.key.symbol()
+      ))],
+      palette,
+    ),
+    palette,
+  );
+  let block = Block::default()
+    .borders(Borders::ALL)
+    .border_set(glyphs().border)
+    .border_style(style_secondary(palette))
+    .title(title)
+    .style(style_text(palette).bg(palette.bg));
+  f.render_widget(Clear, area);
+  f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Command-output overlay: what a kubectl action printed, stderr in the
+/// caution colour, following new lines unless scrolled back, and the exit
+/// status on the last row.
+fn draw_cmd_output(f: &mut Frame<'_>, app: &mut App) {
+  let palette = app.palette;
+  let screen = f.area();
+  let area = centered_rect(
+    screen.width.saturating_sub(8).max(20),
+    (screen.height * 2 / 3).max(5),
+    screen,
+  );
+  let Some(output) = app.cmd_output.as_mut() else {
+    return;
+  };
+  // rows inside the borders, less the exit status row
+  output.height = area.height.saturating_sub(3) as usize;
+
+  let mut lines: Vec<Line<'_>> = output
+    .lines
+    .iter()
+    .skip(output.first_visible())
+    .take(output.height)
+    .map(|line| {
+      let style = if line.stderr {
+        style_warning(palette)
+      } else {
+        style_text(palette)
+      };
+      Line::styled(line.text.clone(), style)
+    })
+    .collect();
+  lines.resize(output.height, Line::default());
+  let status_style = match output.exit {
+    None => style_help(palette),
+    Some((true, _)) => style_success(palette),
+    Some((false, _)) => style_failure(palette).add_modifier(Modifier::BOLD),
+  };
+  lines.push(Line::styled(output.status_line(), status_style));
+
+  let title = title_with_dual_style(
+    format!(" {} ", output.command),
+    mixed_bold_line(
+      [help_part(format!(
+        "· {}:scroll · {}:close ",
+        key_hints(&[DEFAULT_KEYBINDING.up.key, DEFAULT_KEYBINDING.down.key]),
+        DEFAULT_KEYBINDING.esc.key.symbol()
+      ))],
+      palette,
+    ),
+    palette,
+  );
+  f.render_widget(Clear, area);
+  f.render_widget(
+    Paragraph::new(lines)
+      .style(style_text(palette).bg(palette.bg))
+      .block(
+        Block::default()
+          .borders(Borders::ALL)
+          .border_set(glyphs().border)
+          .border_style(style_secondary(palette))
+          .title(title),
+      ),
+    area,
+  );
+}
+
 fn draw_app_title(f: &mut Frame<'_>, app: &App, area: Rect) {
   let p = app.palette;
   // Mauve (accent) title bar; text sits in the base colour for contrast.
@@ -308,17 +794,32 @@ fn draw_app_title(f: &mut Frame<'_>, app: &App, area: Rect) {
       } else {
         Style::default().fg(p.success)
       };
-      left.push(Span::styled("● ", conn));
+      left.push(Span::styled(format!("{} ", glyphs().connected), conn));
       left.push(Span::styled(ctx.name.clone(), fg));
     }
-    None => left.push(Span::styled("○ disconnected", fg)),
+    None => left.push(Span::styled(
+      format!("{} disconnected", glyphs().disconnected),
+      fg,
+    )),
   }
   left.push(sep());
-  left.push(Span::styled(format!("◐ {}", p.name), fg));
+  left.push(Span::styled(format!("{} {}", glyphs().theme, p.name), fg));
   let spinner = nw_loading_indicator(app.is_loading());
   if !spinner.is_empty() {
     left.push(Span::styled(format!("  {}", spinner), fg));
   }
+  if app.read_only {
+    left.push(Span::styled(
+      "  READ-ONLY",
+      style_warning(app.palette).add_modifier(Modifier::BOLD),
+    ));
+  }
+  if app.polling_paused {
+    left.push(Span::styled(
+      format!("  {} PAUSED", glyphs().paused),
+      fg.add_modifier(Modifier::BOLD),
+    ));
+  }
   f.render_widget(
     Paragraph::new(Line::from(left)).alignment(Alignment::Left),
     area,
@@ -332,6 +833,87 @@ fn draw_app_title(f: &mut Frame<'_>, app: &App, area: Rect) {
   );
 }
 
+/// Where kdash is pointed: context, namespace and the server and kubectl
+/// versions, with a warning when they are too far apart. The quiet footer
+/// keeps the context and namespace, and the verbose one adds the last
+/// network event.
+fn draw_app_footer(f: &mut Frame<'_>, app: &App, area: Rect) {
+  let muted = style_help(app.palette);
+  let context = app
+    .data
+    .active_context
+    .as_ref()
+    .map_or("-", |ctx| ctx.name.as_str());
+  let namespace = app.data.selected.ns.as_deref().unwrap_or("all");
+  let quiet = app.footer_verbosity == FooterVerbosity::Quiet;
+  let server = app.data.server_version.as_deref().filter(|_| !quiet);
+  let kubectl = app
+    .data
+    .clis
+    .iter()
+    .find(|cli| cli.name == "kubectl client" && cli.status && !quiet)
+    .map(|cli| cli.version.as_str());
+
+  let mut text = format!(" context: {} · namespace: {}", context, namespace);
+  if let Some(server) = server {
+    text.push_str(&format!(" · server {}", server));
+  }
+  if let Some(kubectl) = kubectl {
+    text.push_str(&format!(" · kubectl {}", kubectl));
+  }
+  let mut spans = vec![Span::styled(text, muted)];
+  if app.redact {
+    spans.push(Span::styled(
+      " · REDACTED",
+      style_warning(app.palette).add_modifier(Modifier::BOLD),
+    ));
+  }
+  if let Some(user) = &app.data.impersonating {
+    spans.push(Span::styled(
+      format!(" · as {}", user),
+      style_warning(app.palette).add_modifier(Modifier::BOLD),
+    ));
+  }
+  if app.data.insecure_tls {
+    spans.push(Span::styled(
+      " · TLS unverified",
+      style_failure(app.palette).add_modifier(Modifier::BOLD),
+    ));
+  }
+  if let Some(skew) = server.zip(kubectl).and_then(|(s, k)| version_skew(s, k)) {
+    spans.push(Span::styled(
+      format!(
+        " {} kubectl is {} minor versions from the server",
+        glyphs().warning,
+        skew
+      ),
+      style_warning(app.palette),
+    ));
+  }
+  if let Some(last) = app
+    .last_network_event
+    .as_ref()
+    .filter(|_| app.footer_verbosity == FooterVerbosity::Verbose)
+  {
+    spans.push(Span::styled(format!(" · last: {}", last.label()), muted));
+  }
+  f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// The minor version in `v1.30.2` or `v1.30.2-eks-1234`.
+fn minor_version(version: &str) -> Option<u32> {
+  let minor = version.trim_start_matches('v').split('.').nth(1)?;
+  let digits: String = minor.chars().take_while(char::is_ascii_digit).collect();
+  digits.parse().ok()
+}
+
+/// How many minor versions apart the server and kubectl are, when that is more
+/// than the one minor version of skew kubectl supports.
+fn version_skew(server: &str, kubectl: &str) -> Option<u32> {
+  let skew = minor_version(server)?.abs_diff(minor_version(kubectl)?);
+  (skew > 1).then_some(skew)
+}
+
 /// All keybinding hints for the title row: route-contextual hints first,
 /// then the always-on global strip (help, tab cycling, theme, quit).
 fn title_hint_line(app: &App) -> Line<'static> {
@@ -347,7 +929,8 @@ fn title_hint_line(app: &App) -> Line<'static> {
       action_hint("select", kb.submit.key),
     ),
     RouteId::Utilization | RouteId::Troubleshoot => scroll.clone(),
-    RouteId::HelpMenu => String::new(),
+    RouteId::Watchlist => format!("{} · {}", scroll, action_hint("describe", kb.submit.key)),
+    RouteId::HelpMenu | RouteId::Dashboard => String::new(),
   };
   let tabs = format!(
     "{}/{}/{}/{}:tabs",
@@ -376,18 +959,16 @@ fn title_hint_line(app: &App) -> Line<'static> {
   ))
 }
 
-// loading animation frames
-const FRAMES: &[&str] = &["⠋⠴", "⠦⠙", "⠏⠼", "⠧⠹", "⠯⠽"];
-
-fn nw_loading_indicator<'a>(loading: bool) -> &'a str {
+fn nw_loading_indicator(loading: bool) -> &'static str {
   if loading {
-    FRAMES[rand::rng().random_range(0..FRAMES.len())]
+    let frames = glyphs().activity;
+    frames[rand::rng().random_range(0..frames.len())]
   } else {
     ""
   }
 }
 
-fn draw_app_header(f: &mut Frame<'_>, app: &App, area: Rect) {
+fn draw_app_header(f: &mut Frame<'_>, app: &mut App, area: Rect) {
   let titles: Vec<Line<'_>> = app
     .main_tabs
     .items
@@ -407,8 +988,16 @@ fn draw_app_header(f: &mut Frame<'_>, app: &App, area: Rect) {
       }
     })
     .collect();
+  let block = Block::default()
+    .borders(Borders::ALL)
+    .border_set(glyphs().border);
+  app.main_tabs.rects = tab_rects(block.inner(area), &titles)
+    .into_iter()
+    .enumerate()
+    .collect();
   let tabs = Tabs::new(titles)
-    .block(Block::default().borders(Borders::ALL))
+    .block(block)
+    .divider(glyphs().vertical)
     .highlight_style(style_secondary(app.palette))
     .select(app.main_tabs.index);
 
@@ -446,12 +1035,41 @@ fn draw_toast_bar(f: &mut Frame<'_>, body: &str, bg: Color, fg: Color, rows_from
   );
 }
 
-/// LlamaStash-style toasts, bottom-centred. The error toast is persistent
-/// (dismissed with Esc) and sits on the bottom row; the transient status toast
-/// (auto-expired by `StatusMessage`'s TTL) stacks just above it.
+/// LlamaStash-style toasts, bottom-centred. The expired-credentials banner
+/// stays on the bottom row until a request succeeds again. The error toast
+/// is persistent (dismissed with Esc) and sits above it, with the persistent
+/// pod alert (`--alerts`) above that; the transient status toast
+/// (auto-expired by `StatusMessage`'s TTL) stacks on top, unless the footer
+/// is quiet.
 fn draw_toasts(f: &mut Frame<'_>, app: &App) {
   let p = app.palette;
   let mut row = 2;
+  if let Some(context) = &app.connecting_to {
+    let body = if app.reconnecting_after_sleep {
+      format!(
+        "{} Reconnecting to {} after sleep…",
+        spinner_frame(app.tick_count),
+        context
+      )
+    } else {
+      format!(
+        "{} Connecting to {}…",
+        spinner_frame(app.tick_count),
+        context
+      )
+    };
+    draw_toast_bar(f, &body, p.accent, p.on_accent, row);
+    row += 1;
+  }
+  if app.auth_expired {
+    let body = format!(
+      "{} Authentication expired — credentials may need refreshing · {}:reconnect",
+      glyphs().warning,
+      DEFAULT_KEYBINDING.refresh.key.symbol()
+    );
+    draw_toast_bar(f, &body, p.error, p.on_accent, row);
+    row += 1;
+  }
   if !app.api_error.is_empty() {
     let body = format!(
       "{} · {}:dismiss",
@@ -461,7 +1079,16 @@ fn draw_toasts(f: &mut Frame<'_>, app: &App) {
     draw_toast_bar(f, &body, p.error, p.on_accent, row);
     row += 1;
   }
-  if !app.status_message.is_empty() {
+  if let Some(alerts) = app.pod_alerts.as_ref().filter(|a| !a.toast.is_empty()) {
+    let body = format!(
+      "{} · {}:dismiss",
+      alerts.toast,
+      DEFAULT_KEYBINDING.esc.key.symbol()
+    );
+    draw_toast_bar(f, &body, p.warning, p.on_accent, row);
+    row += 1;
+  }
+  if !app.status_message.is_empty() && app.shows_status_messages() {
     draw_toast_bar(f, app.status_message.text(), p.accent, p.on_accent, row);
   }
 }
@@ -485,8 +1112,8 @@ mod tests {
   use crate::{
     app::{
       configmaps::KubeConfigMap, contexts::KubeContext, daemonsets::KubeDaemonSet,
-      deployments::KubeDeployment, jobs::KubeJob, metrics::KubeNodeMetrics, nodes::KubeNode,
-      ns::KubeNs, pods::KubePod, replicasets::KubeReplicaSet, svcs::KubeSvc, Cli,
+      deployments::KubeDeployment, jobs::KubeJob, layout::LayoutMode, metrics::KubeNodeMetrics,
+      nodes::KubeNode, ns::KubeNs, pods::KubePod, replicasets::KubeReplicaSet, svcs::KubeSvc, Cli,
     },
     ui::theme::{palette_for, ThemeName},
   };
@@ -542,6 +1169,265 @@ mod tests {
     assert!(buffer[(1, 19)].modifier.contains(Modifier::REVERSED));
   }
 
+  #[test]
+  fn test_version_skew_flags_more_than_one_minor_version() {
+    assert_eq!(minor_version("v1.30.2-eks-1234"), Some(30));
+    assert_eq!(minor_version("unknown"), None);
+    assert_eq!(version_skew("v1.30.2", "v1.29.0"), None);
+    assert_eq!(version_skew("v1.30.2", "v1.31.1"), None);
+    assert_eq!(version_skew("v1.30.2", "v1.27.4"), Some(3));
+    assert_eq!(version_skew("v1.27.4", "v1.30.2"), Some(3));
+  }
+
+  #[test]
+  fn test_draw_footer_shows_context_namespace_and_versions() {
+    let backend = TestBackend::new(120, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::default();
+    app.data.selected.ns = Some("team-a".into());
+    app.data.server_version = Some("v1.30.2".into());
+    app.data.clis = vec![Cli {
+      name: "kubectl client".into(),
+      version: "v1.27.4".into(),
+      status: true,
+      index: 0,
+    }];
+
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+
+    let footer = buffer_lines(terminal.backend().buffer()).pop().unwrap();
+    assert!(
+      footer.starts_with(
+        " context: - · namespace: team-a · server v1.30.2 · kubectl v1.27.4 ⚠ kubectl is 3 minor"
+      ),
+      "{}",
+      footer
+    );
+
+    app.data.impersonating = Some("alice (groups: dev)".into());
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    let footer = buffer_lines(terminal.backend().buffer()).pop().unwrap();
+    assert!(
+      footer.contains("kubectl v1.27.4 · as alice (groups: dev) ⚠"),
+      "{}",
+      footer
+    );
+
+    app.data.insecure_tls = true;
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    let footer = buffer_lines(terminal.backend().buffer()).pop().unwrap();
+    assert!(
+      footer.contains("· as alice (groups: dev) · TLS unverified ⚠"),
+      "{}",
+      footer
+    );
+  }
+
+  #[test]
+  fn test_redact_mode_masks_the_describe_view_and_flags_the_footer() {
+    use crate::app::models::ScrollableTxt;
+
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::default();
+    app.data.describe_out = ScrollableTxt::with_string(
+      "env:\n- name: DB_PASSWORD\n  value: hunter2\n- name: PORT\n  value: \"80\"".into(),
+    );
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Describe);
+
+    for enhanced_graphics in [false, true] {
+      app.enhanced_graphics = enhanced_graphics;
+      app.redact = false;
+      terminal.draw(|f| draw(f, &mut app)).unwrap();
+      let screen = buffer_lines(terminal.backend().buffer()).join("\n");
+      assert!(screen.contains("value: hunter2"), "{}", screen);
+      assert!(!screen.contains("REDACTED"), "{}", screen);
+
+      app.redact = true;
+      terminal.draw(|f| draw(f, &mut app)).unwrap();
+      let mut lines = buffer_lines(terminal.backend().buffer());
+      let footer = lines.pop().unwrap();
+      let screen = lines.join("\n");
+      assert!(!screen.contains("hunter2"), "{}", screen);
+      assert!(screen.contains("value: REDACTED"), "{}", screen);
+      assert!(screen.contains("value: \"80\""), "{}", screen);
+      assert!(
+        footer.starts_with(" context: - · namespace: all · REDACTED"),
+        "{}",
+        footer
+      );
+    }
+  }
+
+  #[test]
+  fn test_draw_footer_follows_its_verbosity() {
+    use std::time::Duration;
+
+    use crate::app::footer::LastNetworkEvent;
+
+    let backend = TestBackend::new(120, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::default();
+    app.data.selected.ns = Some("team-a".into());
+    app.data.server_version = Some("v1.30.2".into());
+    app.last_network_event = Some(LastNetworkEvent {
+      name: "GetPods".into(),
+      took: Duration::from_millis(120),
+    });
+    app.set_status_message("Exported 3 rows");
+
+    app.footer_verbosity = FooterVerbosity::Quiet;
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    let lines = buffer_lines(terminal.backend().buffer());
+    assert_eq!(
+      lines.last().unwrap().trim_end(),
+      " context: - · namespace: team-a"
+    );
+    assert!(!lines.iter().any(|line| line.contains("Exported 3 rows")));
+    // errors still show
+    app.handle_error(anyhow::anyhow!("Forbidden"));
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    let lines = buffer_lines(terminal.backend().buffer());
+    assert!(lines.iter().any(|line| line.contains("Forbidden")));
+
+    app.footer_verbosity = FooterVerbosity::Verbose;
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    let footer = buffer_lines(terminal.backend().buffer()).pop().unwrap();
+    assert!(
+      footer.contains("· server v1.30.2 · last: GetPods 120 ms"),
+      "{}",
+      footer
+    );
+  }
+
+  #[test]
+  fn test_draw_stacks_the_overview_in_a_small_terminal() {
+    let backend = TestBackend::new(80, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::default();
+    app.size = Rect::new(0, 0, 80, 24);
+    app.route_home();
+
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    let screen = buffer_lines(terminal.backend().buffer()).join("\n");
+    assert!(screen.contains("Resources"));
+    assert!(!screen.contains("CLI Info"), "{}", screen);
+    assert!(!screen.contains("Namespaces"));
+
+    app.push_navigation_stack(RouteId::Home, ActiveBlock::Namespaces);
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    let screen = buffer_lines(terminal.backend().buffer()).join("\n");
+    assert!(
+      screen.contains("Namespaces"),
+      "focused namespaces stack on top"
+    );
+    assert!(screen.contains("Resources"));
+
+    app.layout_mode = LayoutMode::Wide;
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    let screen = buffer_lines(terminal.backend().buffer()).join("\n");
+    assert!(screen.contains("CLI Info"), "the wide layout can be forced");
+  }
+
+  #[test]
+  fn test_draw_cmd_output_shows_the_newest_lines_and_exit_status() {
+    let backend = TestBackend::new(80, 20);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::default();
+    app.open_cmd_output("kubectl rollout undo deployment/web".into());
+    for i in 0..30 {
+      app.push_cmd_output_line(format!("line {}", i), false);
+    }
+    app.finish_cmd_output(true, Some(0));
+
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    let screen = buffer_lines(terminal.backend().buffer()).join("\n");
+    assert!(screen.contains("kubectl rollout undo deployment/web"));
+    assert!(screen.contains("line 29"));
+    assert!(!screen.contains("line 0 "));
+    assert!(screen.contains("exit 0"));
+  }
+
+  #[test]
+  fn test_draw_api_stats_lists_requests_per_view() {
+    use std::time::{Duration, Instant};
+
+    let backend = TestBackend::new(100, 20);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::default();
+    let now = Instant::now();
+    app
+      .api_stats
+      .record("pods", Duration::from_millis(120), false, now);
+    app
+      .api_stats
+      .record("GetPodEvents", Duration::from_millis(1500), true, now);
+    app.show_api_stats = true;
+
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    let screen = buffer_lines(terminal.backend().buffer()).join("\n");
+    assert!(screen.contains("API requests"), "{}", screen);
+    assert!(screen.contains("Requests  Per sec  Errors   Average"));
+    assert!(screen.contains("Total"));
+    assert!(screen.contains("GetPodEvents"));
+    assert!(screen.contains("120 ms"));
+    assert!(screen.contains("1.5 s"));
+  }
+
+  #[tokio::test]
+  async fn test_draw_apply_status_checks_off_ready_objects() {
+    use crate::app::manifest::ManifestObject;
+
+    let backend = TestBackend::new(100, 20);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::default();
+    let object = |kind: &str, name: &str| ManifestObject {
+      kind: kind.into(),
+      name: name.into(),
+      namespace: Some("shop".into()),
+    };
+    app
+      .watch_applied(vec![object("Pod", "web"), object("ConfigMap", "settings")])
+      .await;
+    let id = app.apply_status.as_ref().unwrap().id;
+    let config_map = serde_json::json!({
+      "apiVersion": "v1",
+      "kind": "ConfigMap",
+      "metadata": { "name": "settings", "namespace": "shop" }
+    });
+    app.update_applied_object(id, 1, config_map);
+
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    let screen = buffer_lines(terminal.backend().buffer()).join("\n");
+    assert!(screen.contains("Applied: 1/2 ready"), "{}", screen);
+    assert!(screen.contains("Pod shop/web"));
+    assert!(screen.contains(&format!("{} ConfigMap shop/settings", glyphs().ok)));
+    assert!(screen.contains("Present"));
+  }
+
+  #[test]
+  fn test_draw_links_urls_only_when_hyperlinks_are_on() {
+    let backend = TestBackend::new(120, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::default();
+    app.data.selected.ns = Some("https://example.com".into());
+    let linked = |terminal: &Terminal<TestBackend>| {
+      let buffer = terminal.backend().buffer();
+      buffer.content().iter().any(|cell| {
+        cell
+          .symbol()
+          .starts_with("\x1b]8;;https://example.com\x1b\\")
+      })
+    };
+
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    assert!(!linked(&terminal));
+
+    app.hyperlinks = true;
+    terminal.draw(|f| draw(f, &mut app)).unwrap();
+    assert!(linked(&terminal));
+  }
+
   #[test]
   fn test_nw_loading_indicator_is_empty_when_not_loading() {
     assert_eq!(nw_loading_indicator(false), "");
@@ -549,7 +1435,7 @@ mod tests {
 
   #[test]
   fn test_nw_loading_indicator_uses_known_spinner_frames_when_loading() {
-    assert!(FRAMES.contains(&nw_loading_indicator(true)));
+    assert!(glyphs().activity.contains(&nw_loading_indicator(true)));
   }
 
   #[test]
@@ -776,7 +1662,8 @@ mod tests {
     let joined = lines.join("\n");
 
     assert!(lines[4].starts_with('┌'));
-    assert!(lines.last().is_some_and(|line| line.ends_with('┘')));
+    // the footer line sits below the outer border
+    assert!(lines[lines.len() - 2].ends_with('┘'));
     assert!(joined.contains("s:shell"));
     assert!(joined.contains("⏎:logs"));
   }
@@ -800,6 +1687,7 @@ mod tests {
       user: Some("admin@k3d-mycluster".into()),
       namespace: Some("default".into()),
       is_active: true,
+      ..KubeContext::default()
     });
 
     app.data.node_metrics = vec![KubeNodeMetrics {
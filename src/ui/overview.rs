@@ -7,30 +7,30 @@ use ratatui::{
 };
 
 use super::{
+  glyphs::glyphs,
   resource_tabs::draw_resource_tabs_block,
   utils::{
     action_hint, gauge_line, help_part, horizontal_chunks, layout_block_default,
-    layout_block_default_line, loading, mixed_bold_line, style_caution, style_failure, style_label,
-    style_logo, style_primary, style_text, title_with_dual_style, vertical_chunks,
+    layout_block_default_line, loading, mixed_bold_line, style_caution, style_failure, style_help,
+    style_label, style_logo, style_primary, style_text, title_with_dual_style, vertical_chunks,
     vertical_chunks_with_margin,
   },
 };
-use crate::{
-  app::{
-    key_binding::DEFAULT_KEYBINDING,
-    metrics::KubeNodeMetrics,
-    models::{AppResource, KubeResource},
-    nodes::KubeNode,
-    ns::NamespaceResource,
-    pods::KubePod,
-    utils::to_age,
-    ActiveBlock, App,
-  },
-  banner::BANNER,
+use crate::app::{
+  key_binding::DEFAULT_KEYBINDING,
+  metrics::KubeNodeMetrics,
+  models::{AppResource, KubeResource},
+  nodes::KubeNode,
+  ns::NamespaceResource,
+  pods::KubePod,
+  utils::to_age,
+  ActiveBlock, App,
 };
 
 pub fn draw_overview(f: &mut Frame<'_>, app: &mut App, area: Rect) {
-  if app.show_info_bar {
+  if app.compact_layout() {
+    draw_compact_overview(f, app, area);
+  } else if app.show_info_bar {
     let chunks = vertical_chunks(vec![Constraint::Length(9), Constraint::Min(10)], area);
     draw_status_block(f, app, chunks[0]);
     draw_resource_tabs_block(f, app, chunks[1]);
@@ -39,6 +39,19 @@ pub fn draw_overview(f: &mut Frame<'_>, app: &mut App, area: Rect) {
   }
 }
 
+/// The compact layout has no room for the info bar's panes side by side, so
+/// only the namespaces pane is kept, stacked above the resources while it is
+/// focused.
+fn draw_compact_overview(f: &mut Frame<'_>, app: &mut App, area: Rect) {
+  if app.get_current_route().active_block == ActiveBlock::Namespaces {
+    let chunks = vertical_chunks(vec![Constraint::Length(9), Constraint::Min(5)], area);
+    NamespaceResource::render(ActiveBlock::Namespaces, f, app, chunks[0]);
+    draw_resource_tabs_block(f, app, chunks[1]);
+  } else {
+    draw_resource_tabs_block(f, app, area);
+  }
+}
+
 fn draw_status_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
   let hide_logo = app.config.hide_logo;
   let mut constraints = vec![
@@ -62,10 +75,11 @@ fn draw_status_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
 fn draw_logo_block(f: &mut Frame<'_>, app: &App, area: Rect) {
   let palette = app.palette;
   // Banner text with correct styling
-  let text = Text::from(BANNER);
+  let text = Text::from(glyphs().logo);
   let text = text.patch_style(style_logo(palette));
   let block = Block::default()
     .borders(Borders::ALL)
+    .border_set(glyphs().border)
     .border_style(style_primary(palette));
   // Contains the banner
   let paragraph = Paragraph::new(text).block(block);
@@ -94,7 +108,7 @@ fn draw_cli_version_block(f: &mut Frame<'_>, app: &App, area: Rect) {
     .block(block);
     f.render_widget(table, area);
   } else {
-    loading(f, block, area, app.is_loading(), app.palette);
+    loading(f, block, area, &app.global_load(), app.palette);
   }
 }
 
@@ -171,29 +185,34 @@ fn draw_context_info_block(f: &mut Frame<'_>, app: &App, area: Rect) {
   let paragraph = Paragraph::new(text).block(Block::default());
   f.render_widget(paragraph, chunks[0]);
 
-  let cpu_pct = get_nm_ratio(app.data.node_metrics.as_ref(), |nm| nm.cpu_percent) * 100.0;
-  let mem_pct = get_nm_ratio(app.data.node_metrics.as_ref(), |nm| nm.mem_percent) * 100.0;
-  // both gauges share one paragraph so they sit on consecutive lines (no gap),
-  // and the percentage is right-padded to 3 digits so the bar end stays put as
-  // the value grows from single to triple digits.
-  let gauges = vec![
-    gauge_line(
-      "CPU:     ".into(),
-      cpu_pct,
-      format!("{cpu_pct:>3.0}%"),
-      chunks[1].width,
-      app.palette,
-      app.enhanced_graphics,
-    ),
-    gauge_line(
-      "Memory:  ".into(),
-      mem_pct,
-      format!("{mem_pct:>3.0}%"),
-      chunks[1].width,
-      app.palette,
-      app.enhanced_graphics,
-    ),
-  ];
+  let gauges = match app.metrics_note() {
+    Some(note) => vec![Line::from(Span::styled(note, style_help(app.palette)))],
+    None => {
+      let cpu_pct = get_nm_ratio(app.data.node_metrics.as_ref(), |nm| nm.cpu_percent) * 100.0;
+      let mem_pct = get_nm_ratio(app.data.node_metrics.as_ref(), |nm| nm.mem_percent) * 100.0;
+      // both gauges share one paragraph so they sit on consecutive lines (no gap),
+      // and the percentage is right-padded to 3 digits so the bar end stays put as
+      // the value grows from single to triple digits.
+      vec![
+        gauge_line(
+          "CPU:     ".into(),
+          cpu_pct,
+          format!("{cpu_pct:>3.0}%"),
+          chunks[1].width,
+          app.palette,
+          app.enhanced_graphics,
+        ),
+        gauge_line(
+          "Memory:  ".into(),
+          mem_pct,
+          format!("{mem_pct:>3.0}%"),
+          chunks[1].width,
+          app.palette,
+          app.enhanced_graphics,
+        ),
+      ]
+    }
+  };
   f.render_widget(Paragraph::new(gauges), chunks[1]);
 
   draw_cluster_facts(f, app, chunks[2]);
@@ -252,7 +271,7 @@ fn oldest_node_age(nodes: &[KubeNode]) -> Option<String> {
 
 /// `(running, pending, failed)` pod counts. "failed" is anything that is not
 /// Running, Pending, or a finished job (Completed/Succeeded).
-fn pod_counts(pods: &[KubePod]) -> (usize, usize, usize) {
+pub(super) fn pod_counts(pods: &[KubePod]) -> (usize, usize, usize) {
   let mut running = 0;
   let mut pending = 0;
   let mut failed = 0;
@@ -269,7 +288,7 @@ fn pod_counts(pods: &[KubePod]) -> (usize, usize, usize) {
 
 /// `16 running · 2 pending · 1 failed` — pending/failed segments appear only
 /// when non-zero, so a healthy cluster reads simply `16 running`.
-fn pod_summary(running: usize, pending: usize, failed: usize) -> String {
+pub(super) fn pod_summary(running: usize, pending: usize, failed: usize) -> String {
   let mut parts = vec![format!("{running} running")];
   if pending > 0 {
     parts.push(format!("{pending} pending"));
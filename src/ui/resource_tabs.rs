@@ -5,13 +5,16 @@ use ratatui::{
   Frame,
 };
 
+use super::glyphs::glyphs;
 use super::utils::{
   centered_rect, default_part, draw_popup_menu, filter_cursor_position, help_part, hint_key_glyph,
   layout_block_default, mixed_bold_line, mixed_line, split_hint_suffix, style_secondary,
   title_with_dual_style, vertical_chunks_with_margin,
 };
 use crate::app::{
+  api_resources::ApiResourcesResource,
   configmaps::ConfigMapResource,
+  crds::CrdResource,
   cronjobs::CronJobResource,
   daemonsets::DaemonSetResource,
   deployments::DeploymentResource,
@@ -20,14 +23,18 @@ use crate::app::{
   ingress::IngressResource,
   jobs::JobResource,
   key_binding::DEFAULT_KEYBINDING,
+  limit_ranges::LimitRangeResource,
   models::{AppResource, StatefulList},
   network_policies::NetworkPolicyResource,
+  node_pods::NodePodsResource,
   nodes::NodeResource,
+  pdbs::PdbResource,
   pods::PodResource,
   pvcs::PvcResource,
   pvs::PvResource,
   replicasets::ReplicaSetResource,
   replication_controllers::ReplicationControllerResource,
+  resource_quotas::ResourceQuotaResource,
   roles::{ClusterRoleBindingResource, ClusterRoleResource, RoleBindingResource, RoleResource},
   secrets::SecretResource,
   serviceaccounts::SvcAcctResource,
@@ -67,8 +74,14 @@ pub fn draw_resource_tabs_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
   );
   let visible_titles = titles[visible_range.clone()].to_vec();
   let selected_index = app.context_tabs.index.saturating_sub(visible_range.start);
+  app.context_tabs.rects = tab_rects(block.inner(area), &visible_titles)
+    .into_iter()
+    .enumerate()
+    .map(|(i, rect)| (visible_range.start + i, rect))
+    .collect();
   let tabs = Tabs::new(visible_titles)
     .block(block)
+    .divider(glyphs().vertical)
     .highlight_style(style_secondary(app.palette))
     .select(selected_index);
 
@@ -87,7 +100,8 @@ pub fn draw_resource_tabs_block(f: &mut Frame<'_>, app: &mut App, area: Rect) {
     height: 1,
   };
   f.render_widget(
-    Paragraph::new("─".repeat(separator_area.width as usize)).style(style_secondary(app.palette)),
+    Paragraph::new(glyphs().horizontal.repeat(separator_area.width as usize))
+      .style(style_secondary(app.palette)),
     separator_area,
   );
   let content_chunk = chunks[1];
@@ -231,7 +245,7 @@ fn render_tab_overflow_indicators(
 
   if has_left_overflow {
     f.render_widget(
-      Paragraph::new("‹").style(style_secondary(palette)),
+      Paragraph::new(glyphs().scroll_left).style(style_secondary(palette)),
       Rect {
         x: area.x.saturating_add(1),
         y: indicator_y,
@@ -243,7 +257,7 @@ fn render_tab_overflow_indicators(
 
   if has_right_overflow {
     f.render_widget(
-      Paragraph::new("›").style(style_secondary(palette)),
+      Paragraph::new(glyphs().scroll_right).style(style_secondary(palette)),
       Rect {
         x: area.x + area.width.saturating_sub(2),
         y: indicator_y,
@@ -322,6 +336,23 @@ fn missing_neighbors(
   missing
 }
 
+/// Cells each title occupies when `Tabs` renders them on the first row of
+/// `area`: padding, title, padding, then a divider before the next one.
+pub fn tab_rects(area: Rect, titles: &[Line<'_>]) -> Vec<Rect> {
+  let mut rects = vec![];
+  let mut x = area.x;
+  for title in titles {
+    if x >= area.right() {
+      break;
+    }
+    let width = (TAB_PADDING_WIDTH + title.width()) as u16;
+    let width = width.min(area.right() - x);
+    rects.push(Rect::new(x, area.y, width, 1));
+    x = x.saturating_add(width + TAB_DIVIDER_WIDTH as u16);
+  }
+  rects
+}
+
 fn title_width(title: &Line<'_>) -> usize {
   title
     .spans
@@ -381,6 +412,21 @@ fn draw_more(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
       ActiveBlock::NetworkPolicies,
       app.data.network_policies.items.len(),
     ),
+    (
+      ActiveBlock::ResourceQuotas,
+      app.data.resource_quotas.items.len(),
+    ),
+    (ActiveBlock::LimitRanges, app.data.limit_ranges.items.len()),
+    (ActiveBlock::PodDisruptionBudgets, app.data.pdbs.items.len()),
+    (
+      ActiveBlock::CustomResourceDefinitions,
+      app.data.crds.items.len(),
+    ),
+    (
+      ActiveBlock::ApiResources,
+      app.data.api_resources.items.len(),
+    ),
+    (ActiveBlock::PodsByNode, app.data.node_pods.items.len()),
   ];
   match block {
     ActiveBlock::More => draw_menu(
@@ -451,8 +497,14 @@ fn draw_more(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
     ActiveBlock::PersistentVolumes => PvResource::render(block, f, app, area),
     ActiveBlock::ServiceAccounts => SvcAcctResource::render(block, f, app, area),
     ActiveBlock::NetworkPolicies => NetworkPolicyResource::render(block, f, app, area),
+    ActiveBlock::ResourceQuotas => ResourceQuotaResource::render(block, f, app, area),
+    ActiveBlock::LimitRanges => LimitRangeResource::render(block, f, app, area),
+    ActiveBlock::PodDisruptionBudgets => PdbResource::render(block, f, app, area),
+    ActiveBlock::CustomResourceDefinitions => CrdResource::render(block, f, app, area),
+    ActiveBlock::ApiResources => ApiResourcesResource::render(block, f, app, area),
+    ActiveBlock::PodsByNode => NodePodsResource::render(block, f, app, area),
     ActiveBlock::DynamicResource => DynamicResource::render(block, f, app, area),
-    ActiveBlock::Describe | ActiveBlock::Yaml => {
+    ActiveBlock::Describe | ActiveBlock::Yaml | ActiveBlock::Diff => {
       let mut prev_route = app.get_prev_route();
       if prev_route.active_block == block {
         prev_route = app.get_nth_route_from_last(2);
@@ -474,6 +526,10 @@ fn draw_more(block: ActiveBlock, f: &mut Frame<'_>, app: &mut App, area: Rect) {
         ActiveBlock::PersistentVolumes => PvResource::render(block, f, app, area),
         ActiveBlock::ServiceAccounts => SvcAcctResource::render(block, f, app, area),
         ActiveBlock::NetworkPolicies => NetworkPolicyResource::render(block, f, app, area),
+        ActiveBlock::ResourceQuotas => ResourceQuotaResource::render(block, f, app, area),
+        ActiveBlock::LimitRanges => LimitRangeResource::render(block, f, app, area),
+        ActiveBlock::PodDisruptionBudgets => PdbResource::render(block, f, app, area),
+        ActiveBlock::CustomResourceDefinitions => CrdResource::render(block, f, app, area),
         ActiveBlock::DynamicResource => DynamicResource::render(block, f, app, area),
         _ => { /* do nothing */ }
       }
@@ -603,8 +659,8 @@ mod tests {
         "│──────────────────────────────────────────────────────────────────────────────────────────────────│",
         "│                                                                                                  │",
         "│ Pods (ns: all) [1] ⏎:containers · /:filter · d:describe · y:yaml · m:menu · L:logs  · w:wide─────│",
-        "│   Namespace                Name                         Ready      Status    Restarts   Age      │",
-        "│=> pod namespace test       pod name test                0/2        Failed    0          6h52m    │",
+        "│   Namespace             Name                     Ready     Status    Restarts  Uptime   Age      │",
+        "│=> pod namespace test    pod name test            0/2       Failed    0                  6h52m    │",
         "│                                                                                                  │",
         "│                                                                                                  │",
         "└──────────────────────────────────────────────────────────────────────────────────────────────────┘",
@@ -631,6 +687,31 @@ mod tests {
     assert_eq!(buffer[(99, 9)].fg, p.secondary);
   }
 
+  #[test]
+  fn test_draw_resource_tabs_block_records_tab_rects() {
+    let backend = TestBackend::new(100, 10);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut app = App::default();
+    app.data.pods.set_items(vec![KubePod::default()]);
+
+    terminal
+      .draw(|f| draw_resource_tabs_block(f, &mut app, f.area()))
+      .unwrap();
+
+    // "│ Pods [1] │ 2:Services │ ..."
+    assert_eq!(
+      app.context_tabs.rects[..2],
+      [(0, Rect::new(1, 1, 10, 1)), (1, Rect::new(12, 1, 12, 1))]
+    );
+    assert_eq!(app.context_tabs.tab_at(15, 1), Some(1));
+    // Divider between the tabs, and the row below them.
+    assert_eq!(app.context_tabs.tab_at(11, 1), None);
+    assert_eq!(app.context_tabs.tab_at(15, 2), None);
+    // Tabs cut off behind the overflow marker are not clickable.
+    let last = app.context_tabs.rects.last().unwrap();
+    assert!(last.0 < app.context_tabs.items.len() - 1);
+  }
+
   #[test]
   fn test_draw_resource_tabs_block_shows_active_filter_inline() {
     let backend = TestBackend::new(100, 10);
@@ -0,0 +1,599 @@
+//! Per-kind summaries for the YAML view: the fields worth a quick glance,
+//! the status conditions and the recent events of a resource, so the full
+//! YAML is only needed for a deep dive. Kinds without a formatter open
+//! straight on their YAML.
+use serde_json::Value;
+
+use crate::app::{events::KubeEvent, key_binding::DEFAULT_KEYBINDING, utils::format_rfc3339};
+
+/// Events listed at most, newest first.
+const MAX_EVENTS: usize = 10;
+
+/// Summarize a resource serialized to JSON, or `None` when its kind has no
+/// formatter.
+pub fn summarize(obj: &Value, events: &[KubeEvent]) -> Option<String> {
+  let kind = str_at(obj, "/kind")?;
+  let format: fn(&Value, &mut Summary) = match kind {
+    "Pod" => pod,
+    "Deployment" => deployment,
+    "StatefulSet" => stateful_set,
+    "DaemonSet" => daemon_set,
+    "ReplicaSet" => replica_set,
+    "Job" => job,
+    "CronJob" => cron_job,
+    "Service" => service,
+    "Node" => node,
+    "PersistentVolumeClaim" => pvc,
+    _ => return None,
+  };
+
+  let mut summary = Summary::default();
+  summary.line(format!(
+    "# Summary of this {}. Press {} for the full YAML.",
+    kind, DEFAULT_KEYBINDING.toggle_yaml_summary.key
+  ));
+  metadata(obj, &mut summary);
+  format(obj, &mut summary);
+  summary.section("Conditions", conditions(obj));
+  summary.section("Events", resource_events(obj, kind, events));
+  Some(summary.lines.join("\n"))
+}
+
+#[derive(Default)]
+struct Summary {
+  lines: Vec<String>,
+}
+
+impl Summary {
+  fn line(&mut self, line: String) {
+    self.lines.push(line);
+  }
+
+  /// `label: value`, left out when there is no value.
+  fn field(&mut self, label: &str, value: Option<String>) {
+    if let Some(value) = value.filter(|v| !v.is_empty()) {
+      self.lines.push(format!("{}: {}", label, value));
+    }
+  }
+
+  /// A heading with one indented line per item, left out when empty.
+  fn section(&mut self, title: &str, items: Vec<String>) {
+    if items.is_empty() {
+      return;
+    }
+    self.lines.push(format!("{}:", title));
+    self
+      .lines
+      .extend(items.into_iter().map(|item| format!("  {}", item)));
+  }
+}
+
+fn str_at<'a>(obj: &'a Value, pointer: &str) -> Option<&'a str> {
+  obj.pointer(pointer)?.as_str()
+}
+
+/// A timestamp field in the configured time format.
+fn time_at(obj: &Value, pointer: &str) -> Option<String> {
+  string_at(obj, pointer).map(|time| format_rfc3339(&time))
+}
+
+fn string_at(obj: &Value, pointer: &str) -> Option<String> {
+  obj.pointer(pointer).and_then(|v| match v {
+    Value::String(s) => Some(s.clone()),
+    Value::Number(n) => Some(n.to_string()),
+    Value::Bool(b) => Some(b.to_string()),
+    _ => None,
+  })
+}
+
+fn i64_at(obj: &Value, pointer: &str) -> i64 {
+  obj.pointer(pointer).and_then(Value::as_i64).unwrap_or(0)
+}
+
+fn array_at<'a>(obj: &'a Value, pointer: &str) -> &'a [Value] {
+  obj
+    .pointer(pointer)
+    .and_then(Value::as_array)
+    .map_or(&[], Vec::as_slice)
+}
+
+/// `k=v` pairs of a string map, comma-separated.
+fn pairs_at(obj: &Value, pointer: &str) -> Option<String> {
+  let map = obj.pointer(pointer)?.as_object()?;
+  Some(
+    map
+      .iter()
+      .map(|(k, v)| format!("{}={}", k, v.as_str().unwrap_or_default()))
+      .collect::<Vec<_>>()
+      .join(", "),
+  )
+}
+
+fn metadata(obj: &Value, summary: &mut Summary) {
+  summary.field("Name", string_at(obj, "/metadata/name"));
+  summary.field("Namespace", string_at(obj, "/metadata/namespace"));
+  summary.field("Created", time_at(obj, "/metadata/creationTimestamp"));
+  summary.field("Labels", pairs_at(obj, "/metadata/labels"));
+  let owners: Vec<String> = array_at(obj, "/metadata/ownerReferences")
+    .iter()
+    .filter_map(|owner| {
+      Some(format!(
+        "{}/{}",
+        str_at(owner, "/kind")?,
+        str_at(owner, "/name")?
+      ))
+    })
+    .collect();
+  summary.field("Controlled by", Some(owners.join(", ")));
+}
+
+/// `name: image` for each container of a pod spec at `spec`.
+fn images(obj: &Value, spec: &str) -> Vec<String> {
+  array_at(obj, &format!("{}/containers", spec))
+    .iter()
+    .map(|c| {
+      format!(
+        "{}: {}",
+        str_at(c, "/name").unwrap_or_default(),
+        str_at(c, "/image").unwrap_or_default()
+      )
+    })
+    .collect()
+}
+
+fn pod(obj: &Value, summary: &mut Summary) {
+  let status = match str_at(obj, "/status/reason") {
+    Some(reason) => format!(
+      "{} ({})",
+      str_at(obj, "/status/phase").unwrap_or_default(),
+      reason
+    ),
+    None => str_at(obj, "/status/phase").unwrap_or_default().to_owned(),
+  };
+  summary.field("Status", Some(status));
+  summary.field("Node", string_at(obj, "/spec/nodeName"));
+  summary.field("Pod IP", string_at(obj, "/status/podIP"));
+  summary.field("QoS class", string_at(obj, "/status/qosClass"));
+  let statuses = array_at(obj, "/status/containerStatuses");
+  let containers = array_at(obj, "/spec/containers")
+    .iter()
+    .map(|c| {
+      let name = str_at(c, "/name").unwrap_or_default();
+      let image = str_at(c, "/image").unwrap_or_default();
+      match statuses.iter().find(|s| str_at(s, "/name") == Some(name)) {
+        Some(s) => {
+          let state = if s.pointer("/ready").and_then(Value::as_bool) == Some(true) {
+            "ready".to_owned()
+          } else {
+            str_at(s, "/state/waiting/reason")
+              .or_else(|| str_at(s, "/state/terminated/reason"))
+              .unwrap_or("not ready")
+              .to_owned()
+          };
+          format!(
+            "{}: {}, {}, {} restarts",
+            name,
+            image,
+            state,
+            i64_at(s, "/restartCount")
+          )
+        }
+        None => format!("{}: {}", name, image),
+      }
+    })
+    .collect();
+  summary.section("Containers", containers);
+}
+
+fn deployment(obj: &Value, summary: &mut Summary) {
+  summary.field(
+    "Replicas",
+    Some(format!(
+      "{}/{} ready, {} up to date, {} available",
+      i64_at(obj, "/status/readyReplicas"),
+      i64_at(obj, "/spec/replicas"),
+      i64_at(obj, "/status/updatedReplicas"),
+      i64_at(obj, "/status/availableReplicas")
+    )),
+  );
+  summary.field("Strategy", string_at(obj, "/spec/strategy/type"));
+  summary.field("Selector", pairs_at(obj, "/spec/selector/matchLabels"));
+  summary.section("Images", images(obj, "/spec/template/spec"));
+}
+
+fn stateful_set(obj: &Value, summary: &mut Summary) {
+  summary.field(
+    "Replicas",
+    Some(format!(
+      "{}/{} ready, {} up to date",
+      i64_at(obj, "/status/readyReplicas"),
+      i64_at(obj, "/spec/replicas"),
+      i64_at(obj, "/status/updatedReplicas")
+    )),
+  );
+  summary.field("Service", string_at(obj, "/spec/serviceName"));
+  summary.field(
+    "Update strategy",
+    string_at(obj, "/spec/updateStrategy/type"),
+  );
+  summary.field("Selector", pairs_at(obj, "/spec/selector/matchLabels"));
+  summary.section("Images", images(obj, "/spec/template/spec"));
+}
+
+fn daemon_set(obj: &Value, summary: &mut Summary) {
+  summary.field(
+    "Pods",
+    Some(format!(
+      "{}/{} ready, {} up to date, {} available",
+      i64_at(obj, "/status/numberReady"),
+      i64_at(obj, "/status/desiredNumberScheduled"),
+      i64_at(obj, "/status/updatedNumberScheduled"),
+      i64_at(obj, "/status/numberAvailable")
+    )),
+  );
+  summary.field(
+    "Node selector",
+    pairs_at(obj, "/spec/template/spec/nodeSelector"),
+  );
+  summary.field(
+    "Update strategy",
+    string_at(obj, "/spec/updateStrategy/type"),
+  );
+  summary.section("Images", images(obj, "/spec/template/spec"));
+}
+
+fn replica_set(obj: &Value, summary: &mut Summary) {
+  summary.field(
+    "Replicas",
+    Some(format!(
+      "{}/{} ready",
+      i64_at(obj, "/status/readyReplicas"),
+      i64_at(obj, "/spec/replicas")
+    )),
+  );
+  summary.field("Selector", pairs_at(obj, "/spec/selector/matchLabels"));
+  summary.section("Images", images(obj, "/spec/template/spec"));
+}
+
+fn job(obj: &Value, summary: &mut Summary) {
+  let completions = obj
+    .pointer("/spec/completions")
+    .and_then(Value::as_i64)
+    .unwrap_or(1);
+  summary.field(
+    "Completions",
+    Some(format!(
+      "{}/{} succeeded, {} active, {} failed",
+      i64_at(obj, "/status/succeeded"),
+      completions,
+      i64_at(obj, "/status/active"),
+      i64_at(obj, "/status/failed")
+    )),
+  );
+  summary.field("Started", time_at(obj, "/status/startTime"));
+  summary.field("Completed", time_at(obj, "/status/completionTime"));
+  summary.section("Images", images(obj, "/spec/template/spec"));
+}
+
+fn cron_job(obj: &Value, summary: &mut Summary) {
+  summary.field("Schedule", string_at(obj, "/spec/schedule"));
+  summary.field("Time zone", string_at(obj, "/spec/timeZone"));
+  let suspended = obj.pointer("/spec/suspend").and_then(Value::as_bool) == Some(true);
+  summary.field(
+    "Suspended",
+    Some(if suspended { "yes" } else { "no" }.into()),
+  );
+  summary.field("Last schedule", time_at(obj, "/status/lastScheduleTime"));
+  summary.field(
+    "Active jobs",
+    Some(array_at(obj, "/status/active").len().to_string()),
+  );
+  summary.section(
+    "Images",
+    images(obj, "/spec/jobTemplate/spec/template/spec"),
+  );
+}
+
+fn service(obj: &Value, summary: &mut Summary) {
+  summary.field("Type", string_at(obj, "/spec/type"));
+  summary.field("Cluster IP", string_at(obj, "/spec/clusterIP"));
+  let external: Vec<String> = array_at(obj, "/status/loadBalancer/ingress")
+    .iter()
+    .filter_map(|ingress| string_at(ingress, "/ip").or_else(|| string_at(ingress, "/hostname")))
+    .chain(
+      array_at(obj, "/spec/externalIPs")
+        .iter()
+        .filter_map(|ip| ip.as_str().map(str::to_owned)),
+    )
+    .collect();
+  summary.field("External IPs", Some(external.join(", ")));
+  summary.field("Selector", pairs_at(obj, "/spec/selector"));
+  let ports = array_at(obj, "/spec/ports")
+    .iter()
+    .map(|p| {
+      let port = format!(
+        "{}/{} -> {}",
+        i64_at(p, "/port"),
+        str_at(p, "/protocol").unwrap_or("TCP"),
+        string_at(p, "/targetPort").unwrap_or_else(|| i64_at(p, "/port").to_string())
+      );
+      match str_at(p, "/name") {
+        Some(name) => format!("{}: {}", name, port),
+        None => port,
+      }
+    })
+    .collect();
+  summary.section("Ports", ports);
+}
+
+fn node(obj: &Value, summary: &mut Summary) {
+  let roles: Vec<&str> = obj
+    .pointer("/metadata/labels")
+    .and_then(Value::as_object)
+    .map(|labels| {
+      labels
+        .keys()
+        .filter_map(|k| k.strip_prefix("node-role.kubernetes.io/"))
+        .collect()
+    })
+    .unwrap_or_default();
+  summary.field("Roles", Some(roles.join(", ")));
+  let cordoned = obj.pointer("/spec/unschedulable").and_then(Value::as_bool) == Some(true);
+  summary.field(
+    "Scheduling",
+    Some(if cordoned { "cordoned" } else { "schedulable" }.into()),
+  );
+  let internal_ip = array_at(obj, "/status/addresses")
+    .iter()
+    .find(|a| str_at(a, "/type") == Some("InternalIP"))
+    .and_then(|a| string_at(a, "/address"));
+  summary.field("Internal IP", internal_ip);
+  summary.field("Kubelet", string_at(obj, "/status/nodeInfo/kubeletVersion"));
+  summary.field("OS image", string_at(obj, "/status/nodeInfo/osImage"));
+  let resources = |pointer: &str| {
+    Some(format!(
+      "cpu {}, memory {}, pods {}",
+      str_at(obj, &format!("{}/cpu", pointer)).unwrap_or("-"),
+      str_at(obj, &format!("{}/memory", pointer)).unwrap_or("-"),
+      str_at(obj, &format!("{}/pods", pointer)).unwrap_or("-")
+    ))
+  };
+  summary.field("Capacity", resources("/status/capacity"));
+  summary.field("Allocatable", resources("/status/allocatable"));
+  let taints = array_at(obj, "/spec/taints")
+    .iter()
+    .map(|t| {
+      format!(
+        "{}={}:{}",
+        str_at(t, "/key").unwrap_or_default(),
+        str_at(t, "/value").unwrap_or_default(),
+        str_at(t, "/effect").unwrap_or_default()
+      )
+    })
+    .collect();
+  summary.section("Taints", taints);
+}
+
+fn pvc(obj: &Value, summary: &mut Summary) {
+  summary.field("Status", string_at(obj, "/status/phase"));
+  summary.field("Volume", string_at(obj, "/spec/volumeName"));
+  summary.field("Capacity", string_at(obj, "/status/capacity/storage"));
+  let modes: Vec<&str> = array_at(obj, "/spec/accessModes")
+    .iter()
+    .filter_map(Value::as_str)
+    .collect();
+  summary.field("Access modes", Some(modes.join(", ")));
+  summary.field("Storage class", string_at(obj, "/spec/storageClassName"));
+}
+
+/// `Type: Status`, with the reason and message of conditions that are not met.
+/// A pod's readiness gates are marked, and listed as not reported while they
+/// have no condition yet.
+fn conditions(obj: &Value) -> Vec<String> {
+  let gates: Vec<&str> = array_at(obj, "/spec/readinessGates")
+    .iter()
+    .filter_map(|gate| str_at(gate, "/conditionType"))
+    .collect();
+  let reported = array_at(obj, "/status/conditions");
+  let mut lines: Vec<String> = reported
+    .iter()
+    .map(|c| {
+      let type_ = str_at(c, "/type").unwrap_or_default();
+      let status = str_at(c, "/status").unwrap_or_default();
+      let gate = if gates.contains(&type_) {
+        " [readiness gate]"
+      } else {
+        ""
+      };
+      let detail = [str_at(c, "/reason"), str_at(c, "/message")]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(": ");
+      if status == "True" || detail.is_empty() {
+        format!("{}: {}{}", type_, status, gate)
+      } else {
+        format!("{}: {}{} ({})", type_, status, gate, detail)
+      }
+    })
+    .collect();
+  lines.extend(
+    gates
+      .into_iter()
+      .filter(|gate| !reported.iter().any(|c| str_at(c, "/type") == Some(gate)))
+      .map(|gate| format!("{}: not reported [readiness gate]", gate)),
+  );
+  lines
+}
+
+/// The loaded events about this resource, newest first.
+fn resource_events(obj: &Value, kind: &str, events: &[KubeEvent]) -> Vec<String> {
+  let name = str_at(obj, "/metadata/name").unwrap_or_default();
+  let namespace = str_at(obj, "/metadata/namespace").unwrap_or_default();
+  let mut matching: Vec<&KubeEvent> = events
+    .iter()
+    .filter(|e| e.involved_kind == kind && e.involved_name == name && e.namespace == namespace)
+    .collect();
+  matching.sort_by_key(|e| std::cmp::Reverse(e.last_seen().map(|t| t.0)));
+  matching
+    .into_iter()
+    .take(MAX_EVENTS)
+    .map(|e| {
+      format!(
+        "{} {} (x{}, {}): {}",
+        e.type_,
+        e.reason,
+        e.occurrences(),
+        e.age,
+        e.message
+      )
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use k8s_openapi::api::core::v1::Event;
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_kinds_without_a_formatter_open_on_the_yaml() {
+    assert_eq!(summarize(&json!({ "kind": "ConfigMap" }), &[]), None);
+    assert_eq!(summarize(&json!({}), &[]), None);
+  }
+
+  #[test]
+  fn test_pod_summary_lists_key_fields_conditions_and_events() {
+    let pod = json!({
+      "kind": "Pod",
+      "metadata": {
+        "name": "web-1",
+        "namespace": "shop",
+        "labels": { "app": "web" },
+        "ownerReferences": [{ "kind": "ReplicaSet", "name": "web-abc" }]
+      },
+      "spec": {
+        "nodeName": "node-1",
+        "containers": [{ "name": "web", "image": "nginx:1.25" }]
+      },
+      "status": {
+        "phase": "Running",
+        "podIP": "10.0.0.7",
+        "conditions": [
+          { "type": "PodScheduled", "status": "True" },
+          {
+            "type": "Ready",
+            "status": "False",
+            "reason": "ContainersNotReady",
+            "message": "containers with unready status: [web]"
+          }
+        ],
+        "containerStatuses": [{
+          "name": "web",
+          "ready": false,
+          "restartCount": 3,
+          "state": { "waiting": { "reason": "CrashLoopBackOff" } }
+        }]
+      }
+    });
+    let event: KubeEvent = serde_json::from_value::<Event>(json!({
+      "metadata": { "name": "web-1.1", "namespace": "shop" },
+      "involvedObject": { "kind": "Pod", "name": "web-1", "namespace": "shop" },
+      "type": "Warning",
+      "reason": "BackOff",
+      "message": "Back-off restarting failed container",
+      "count": 3
+    }))
+    .unwrap()
+    .into();
+
+    let summary = summarize(&pod, &[event]).unwrap();
+    let lines: Vec<&str> = summary.lines().collect();
+    assert!(lines[0].starts_with("# Summary of this Pod."));
+    for expected in [
+      "Name: web-1",
+      "Namespace: shop",
+      "Labels: app=web",
+      "Controlled by: ReplicaSet/web-abc",
+      "Status: Running",
+      "Node: node-1",
+      "Pod IP: 10.0.0.7",
+      "  web: nginx:1.25, CrashLoopBackOff, 3 restarts",
+      "  PodScheduled: True",
+      "  Ready: False (ContainersNotReady: containers with unready status: [web])",
+    ] {
+      assert!(
+        lines.contains(&expected),
+        "missing {:?} in\n{}",
+        expected,
+        summary
+      );
+    }
+    assert!(summary.contains("Events:\n  Warning BackOff (x3"));
+    assert!(summary.ends_with("Back-off restarting failed container"));
+    // fields without a value are left out
+    assert!(!summary.contains("QoS class"));
+  }
+
+  #[test]
+  fn test_pod_summary_marks_readiness_gates() {
+    let pod = json!({
+      "kind": "Pod",
+      "metadata": { "name": "web-1", "namespace": "shop" },
+      "spec": {
+        "containers": [{ "name": "web", "image": "nginx:1.25" }],
+        "readinessGates": [
+          { "conditionType": "example.com/lb-registered" },
+          { "conditionType": "example.com/warmed-up" }
+        ]
+      },
+      "status": {
+        "phase": "Running",
+        "conditions": [{ "type": "example.com/warmed-up", "status": "True" }]
+      }
+    });
+    let summary = summarize(&pod, &[]).unwrap();
+    assert!(
+      summary.contains(
+        "Conditions:\n  example.com/warmed-up: True [readiness gate]\n  example.com/lb-registered: not reported [readiness gate]"
+      ),
+      "{}",
+      summary
+    );
+  }
+
+  #[test]
+  fn test_workload_and_service_summaries() {
+    let deployment = json!({
+      "kind": "Deployment",
+      "metadata": { "name": "web" },
+      "spec": {
+        "replicas": 3,
+        "strategy": { "type": "RollingUpdate" },
+        "template": { "spec": { "containers": [{ "name": "web", "image": "nginx" }] } }
+      },
+      "status": { "readyReplicas": 2, "updatedReplicas": 3, "availableReplicas": 2 }
+    });
+    let summary = summarize(&deployment, &[]).unwrap();
+    assert!(summary.contains("Replicas: 2/3 ready, 3 up to date, 2 available"));
+    assert!(summary.contains("Strategy: RollingUpdate"));
+    assert!(summary.contains("Images:\n  web: nginx"));
+
+    let service = json!({
+      "kind": "Service",
+      "metadata": { "name": "web" },
+      "spec": {
+        "type": "ClusterIP",
+        "clusterIP": "10.96.0.10",
+        "selector": { "app": "web" },
+        "ports": [{ "name": "http", "port": 80, "protocol": "TCP", "targetPort": 8080 }]
+      }
+    });
+    let summary = summarize(&service, &[]).unwrap();
+    assert!(summary.contains("Cluster IP: 10.96.0.10"));
+    assert!(summary.contains("Selector: app=web"));
+    assert!(summary.contains("Ports:\n  http: 80/TCP -> 8080"));
+  }
+}
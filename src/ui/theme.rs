@@ -1,4 +1,9 @@
-use std::{collections::BTreeMap, fmt, str::FromStr, sync::OnceLock};
+use std::{
+  collections::BTreeMap,
+  fmt,
+  str::FromStr,
+  sync::{PoisonError, RwLock},
+};
 
 use log::warn;
 use ratatui::style::Color;
@@ -364,7 +369,7 @@ pub struct ThemeOverrides {
   light: BTreeMap<String, Color>,
 }
 
-static ACTIVE_THEME_OVERRIDES: OnceLock<ThemeOverrides> = OnceLock::new();
+static ACTIVE_THEME_OVERRIDES: RwLock<Option<ThemeOverrides>> = RwLock::new(None);
 
 /// Config keys accepted in the legacy `theme: { dark, light }` map. The
 /// historical eight keep their names but now address the remapped slots
@@ -386,7 +391,7 @@ const THEME_KEYS: &[&str] = &[
 
 pub fn initialize_theme(config: &KdashConfig) -> Vec<String> {
   let (overrides, warnings) = build_theme_overrides(config);
-  let _ = ACTIVE_THEME_OVERRIDES.set(overrides);
+  set_active_overrides(overrides);
 
   for warning in &warnings {
     warn!("{}", warning);
@@ -395,11 +400,20 @@ pub fn initialize_theme(config: &KdashConfig) -> Vec<String> {
   warnings
 }
 
+fn set_active_overrides(overrides: ThemeOverrides) {
+  *ACTIVE_THEME_OVERRIDES
+    .write()
+    .unwrap_or_else(PoisonError::into_inner) = Some(overrides);
+}
+
 /// Apply the legacy `theme: { dark, light }` overrides onto a built-in
 /// catppuccin palette. The `dark` section tints Macchiato, the `light`
 /// section tints Latte — matching the old two-theme toggle behaviour.
 pub fn apply_legacy_overrides(palette: &mut Palette) {
-  let Some(overrides) = ACTIVE_THEME_OVERRIDES.get() else {
+  let active = ACTIVE_THEME_OVERRIDES
+    .read()
+    .unwrap_or_else(PoisonError::into_inner);
+  let Some(overrides) = active.as_ref() else {
     return;
   };
   let colors = if palette.is_dark {
@@ -524,10 +538,10 @@ mod tests {
   use crate::config::{KdashConfig, ThemeConfig};
 
   fn set_overrides(config: &KdashConfig) {
-    // Tests share one process; build overrides directly instead of racing on
-    // the global OnceLock that `initialize_theme` sets.
+    // Tests share one process and the global; build overrides directly
+    // instead of going through `initialize_theme`.
     let (overrides, _) = build_theme_overrides(config);
-    let _ = ACTIVE_THEME_OVERRIDES.set(overrides);
+    set_active_overrides(overrides);
   }
 
   #[test]
@@ -684,7 +698,7 @@ mod tests {
 
   #[test]
   fn test_apply_legacy_overrides_remaps_keys_onto_slots() {
-    // `set_overrides` writes the shared OnceLock; keep this the only test that
+    // `set_overrides` writes the shared global; keep this the only test that
     // relies on the global so the value is deterministic.
     let config = KdashConfig {
       theme: Some(ThemeConfig {
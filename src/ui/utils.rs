@@ -1,22 +1,36 @@
 use std::{borrow::Cow, io::Cursor, rc::Rc, sync::OnceLock};
 
+use chrono::{DateTime, Duration, Utc};
 use glob_match::glob_match;
 use ratatui::{
-  layout::{Constraint, Direction, Layout, Position, Rect},
+  buffer::Buffer,
+  layout::{Constraint, Direction, Flex, Layout, Position, Rect},
   style::{Color, Modifier, Style},
   text::{Line, Span, Text},
-  widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Row, Table, Wrap},
+  widgets::{
+    Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, Widget, Wrap,
+  },
   Frame,
 };
 
-use super::HIGHLIGHT;
+use super::{glyphs::glyphs, HIGHLIGHT};
 use crate::app::{
+  export::{TableExport, TableText},
+  health::Health,
   key_binding::DEFAULT_KEYBINDING,
-  models::{Named, StatefulTable},
+  load_state::{LoadState, ViewLoad},
+  models::{ColumnScroll, MarkedRow, Named, StatefulTable},
   ActiveBlock, App,
 };
 use crate::event::Key;
-use crate::ui::theme::Palette;
+use crate::ui::theme::{Palette, ThemeName};
+use syntect::{
+  highlighting::{
+    Color as SyntectColor, HighlightIterator, HighlightState, Highlighter, StyleModifier, Theme,
+    ThemeItem, ThemeSettings,
+  },
+  parsing::{ParseState, ScopeStack},
+};
 // Viewport width thresholds for responsive column display
 pub const COMPACT_WIDTH_THRESHOLD: u16 = 120;
 pub const WIDE_WIDTH_THRESHOLD: u16 = 180;
@@ -97,6 +111,88 @@ pub fn responsive_columns(columns: &[ColumnDef], tier: ViewTier) -> (Vec<&str>,
     .unzip()
 }
 
+/// Columns a resource view draws: the `ColumnDef`s visible at its tier, or
+/// the ones named by a `columns` entry in the config file, in that order.
+/// Rows are built with one cell per `ColumnDef` and narrowed by [`Self::row`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnLayout {
+  pub headers: Vec<&'static str>,
+  pub widths: Vec<Constraint>,
+  indices: Vec<usize>,
+}
+
+impl ColumnLayout {
+  /// Configured names match labels case-insensitively; unknown names and
+  /// repeats are skipped, and a list without any known name falls back to
+  /// the defaults. Configured columns show at every tier and share the width
+  /// in proportion to their widest default.
+  pub fn new(columns: &[ColumnDef], tier: ViewTier, configured: Option<&[String]>) -> Self {
+    let mut picked: Vec<usize> = vec![];
+    for name in configured.unwrap_or_default() {
+      let found = columns
+        .iter()
+        .position(|col| col.label.eq_ignore_ascii_case(name.trim()));
+      if let Some(i) = found.filter(|i| !picked.contains(i)) {
+        picked.push(i);
+      }
+    }
+    if picked.is_empty() {
+      let (indices, widths): (Vec<usize>, Vec<Constraint>) = columns
+        .iter()
+        .enumerate()
+        .filter_map(|(i, col)| {
+          let w = match tier {
+            ViewTier::Wide => col.wide,
+            ViewTier::Standard => col.standard,
+            ViewTier::Compact => col.compact,
+          };
+          w.map(|w| (i, Constraint::Percentage(w)))
+        })
+        .unzip();
+      return Self {
+        headers: indices.iter().map(|&i| columns[i].label).collect(),
+        widths,
+        indices,
+      };
+    }
+    Self {
+      headers: picked.iter().map(|&i| columns[i].label).collect(),
+      widths: picked
+        .iter()
+        .map(|&i| {
+          let col = &columns[i];
+          let widest = col.wide.or(col.standard).or(col.compact).unwrap_or(1);
+          Constraint::Fill(widest)
+        })
+        .collect(),
+      indices: picked,
+    }
+  }
+
+  /// Keep the cells of a full row, one per `ColumnDef`, that this layout shows.
+  pub fn row<'a>(&self, cells: Vec<Cell<'a>>) -> Row<'a> {
+    let mut cells: Vec<Option<Cell<'a>>> = cells.into_iter().map(Some).collect();
+    Row::new(
+      self
+        .indices
+        .iter()
+        .map(|&i| cells.get_mut(i).and_then(Option::take).unwrap_or_default()),
+    )
+  }
+
+  /// Width of the `label` column in a table `area_width` wide, laid out as
+  /// the resource table lays it out; `None` when the column is not shown.
+  pub fn cell_width(&self, label: &str, area_width: u16) -> Option<u16> {
+    let index = self.headers.iter().position(|h| *h == label)?;
+    let table_width = area_width.saturating_sub(HIGHLIGHT.chars().count() as u16);
+    let cells = Layout::horizontal(self.widths.clone())
+      .flex(Flex::Start)
+      .spacing(1)
+      .split(Rect::new(0, 0, table_width, 1));
+    cells.get(index).map(|cell| cell.width)
+  }
+}
+
 // Utils
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -242,6 +338,34 @@ pub fn style_main_background(palette: Palette) -> Style {
 pub fn style_highlight() -> Style {
   Style::default().add_modifier(Modifier::REVERSED)
 }
+/// Rows selected for a batch action.
+pub fn style_marked(palette: Palette) -> Style {
+  Style::default()
+    .fg(palette.highlight)
+    .add_modifier(Modifier::BOLD)
+}
+
+/// A row whose resource changed `window` or less ago: bold at first, then
+/// fading over the rest of the window as the polls come in.
+pub fn style_changed(
+  changed: Option<DateTime<Utc>>,
+  now: DateTime<Utc>,
+  window: Duration,
+  palette: Palette,
+) -> Option<Style> {
+  let age = now.signed_duration_since(changed?).max(Duration::zero());
+  if age >= window {
+    return None;
+  }
+  let style = Style::default().fg(palette.accent);
+  Some(
+    match age.num_milliseconds() * 3 / window.num_milliseconds().max(1) {
+      0 => style.add_modifier(Modifier::BOLD),
+      1 => style,
+      _ => style.add_modifier(Modifier::DIM),
+    },
+  )
+}
 
 fn line_part_style(part: &LinePart<'_>, palette: Palette, bold: bool) -> Style {
   let style = match part {
@@ -371,7 +495,7 @@ pub fn gauge_fill_style(ratio: f64, palette: Palette) -> Style {
 /// LlamaStash-style block bar `████░░░░` of `width` cells: `█` fill, `░`
 /// trough. The fill colour owns the whole span — the 25%-density trough
 /// glyph naturally reads as a dimmer shade. ASCII fallback when unicode
-/// symbols are disabled.
+/// symbols are disabled or the glyph set is ASCII.
 fn gauge_bar_span(pct: f64, width: usize, fill: Style, enhanced_graphics: bool) -> Span<'static> {
   if width == 0 {
     return Span::raw("");
@@ -382,7 +506,7 @@ fn gauge_bar_span(pct: f64, width: usize, fill: Style, enhanced_graphics: bool)
     0.0
   };
   let (fill_char, trough_char) = if enhanced_graphics {
-    ('█', '░')
+    (glyphs().gauge_fill, glyphs().gauge_trough)
   } else {
     ('#', '.')
   };
@@ -427,6 +551,34 @@ pub fn gauge_line<'a>(
   ])
 }
 
+/// Narrowest table cell that takes a gauge; narrower ones keep the number.
+const GAUGE_CELL_MIN_WIDTH: u16 = 8;
+
+/// A percentage table cell, `42 ███░░░`, with the bar filling what is left
+/// of a cell `width` wide. The number leads so a cell cut short loses bar,
+/// not digits. Plain text without enhanced graphics, in a narrow cell or
+/// when there is no percentage to show.
+pub fn gauge_cell<'a>(
+  pct: &str,
+  width: u16,
+  palette: Palette,
+  enhanced_graphics: bool,
+) -> Cell<'a> {
+  let value = pct.parse::<f64>().ok().filter(|v| v.is_finite());
+  match value {
+    Some(value) if enhanced_graphics && width >= GAUGE_CELL_MIN_WIDTH => {
+      let number = format!("{:>3} ", pct);
+      let bar_width = (width as usize).saturating_sub(number.chars().count());
+      let fill = gauge_fill_style((value / 100.0).clamp(0.0, 1.0), palette);
+      Cell::from(Line::from(vec![
+        Span::styled(number, style_text(palette)),
+        gauge_bar_span(value, bar_width, fill, true),
+      ]))
+    }
+    _ => Cell::from(pct.to_owned()),
+  }
+}
+
 pub fn table_header_style(cells: Vec<&str>, palette: Palette) -> Row<'_> {
   Row::new(cells).style(style_label(palette)).bottom_margin(0)
 }
@@ -477,6 +629,7 @@ fn active_border_style(palette: Palette) -> Style {
 pub fn layout_block(title: Span<'_>, palette: Palette) -> Block<'_> {
   Block::default()
     .borders(Borders::ALL)
+    .border_set(glyphs().border)
     .border_style(border_style(palette))
     .title(title)
 }
@@ -488,6 +641,7 @@ pub fn layout_block_default<'a>(title: &'a str, palette: Palette) -> Block<'a> {
 pub fn layout_block_default_line(title: Line<'_>, palette: Palette) -> Block<'_> {
   Block::default()
     .borders(Borders::ALL)
+    .border_set(glyphs().border)
     .border_style(border_style(palette))
     .title(title)
 }
@@ -495,6 +649,7 @@ pub fn layout_block_default_line(title: Line<'_>, palette: Palette) -> Block<'_>
 pub fn layout_block_active_line(title: Line<'_>, palette: Palette) -> Block<'_> {
   Block::default()
     .borders(Borders::ALL)
+    .border_set(glyphs().border)
     .border_style(active_border_style(palette))
     .title(title)
 }
@@ -506,6 +661,7 @@ pub fn layout_block_active_span(title: Line<'_>, palette: Palette) -> Block<'_>
 pub fn layout_block_top_border(title: Line<'_>, palette: Palette) -> Block<'_> {
   Block::default()
     .borders(Borders::TOP)
+    .border_set(glyphs().border)
     .border_style(border_style(palette))
     .title(title)
 }
@@ -524,6 +680,7 @@ pub fn draw_popup_menu(
 ) {
   let block = Block::default()
     .borders(Borders::ALL)
+    .border_set(glyphs().border)
     .border_style(style_secondary(palette))
     .title(title);
   f.render_widget(Clear, area);
@@ -627,6 +784,7 @@ pub fn copy_and_escape_title_line<'a, S: Into<Cow<'a, str>>>(
 pub fn copy_scroll_and_escape_title_line<'a, S: Into<Cow<'a, str>>>(
   _target: S,
   auto_scroll: bool,
+  wrap: bool,
   palette: Palette,
 ) -> Line<'a> {
   let auto_scroll_action = if auto_scroll {
@@ -634,6 +792,7 @@ pub fn copy_scroll_and_escape_title_line<'a, S: Into<Cow<'a, str>>>(
   } else {
     "resume scroll"
   };
+  let wrap_action = if wrap { "unwrap" } else { "wrap" };
   mixed_bold_line(
     [
       help_part(format!(
@@ -641,7 +800,7 @@ pub fn copy_scroll_and_escape_title_line<'a, S: Into<Cow<'a, str>>>(
         action_hint("copy", DEFAULT_KEYBINDING.copy_to_clipboard.key),
         action_hint(auto_scroll_action, DEFAULT_KEYBINDING.log_auto_scroll.key),
         action_hint("timestamps", DEFAULT_KEYBINDING.toggle_log_timestamps.key),
-        action_hint("wrap", DEFAULT_KEYBINDING.toggle_log_wrap.key)
+        action_hint(wrap_action, DEFAULT_KEYBINDING.toggle_log_wrap.key)
       )),
       help_part(format!("{}:back ", DEFAULT_KEYBINDING.esc.key.symbol())),
     ],
@@ -663,63 +822,56 @@ pub fn hint_key_glyph(hint: &str) -> &str {
   hint.trim_start_matches('<').trim_end_matches('>')
 }
 
-/// helper function to create a centered rect using up
-/// certain percentage of the available rect `r`
+/// A `width` x `height` rect centred in `r`, clamped to it. The margins are
+/// worked out directly rather than with a three-way layout, which would take a
+/// row off an odd-sized popup to make the margins fit.
 pub fn centered_rect(width: u16, height: u16, r: Rect) -> Rect {
-  let Rect {
-    width: grid_width,
-    height: grid_height,
-    ..
-  } = r;
-  let outer_height = (grid_height / 2).saturating_sub(height / 2);
-
-  let popup_layout = Layout::default()
-    .direction(Direction::Vertical)
-    .constraints(
-      [
-        Constraint::Length(outer_height),
-        Constraint::Length(height),
-        Constraint::Length(outer_height),
-      ]
-      .as_ref(),
-    )
-    .split(r);
-
-  let outer_width = (grid_width / 2).saturating_sub(width / 2);
+  let top = (r.height / 2).saturating_sub(height / 2);
+  let left = (r.width / 2).saturating_sub(width / 2);
+  Rect {
+    x: r.x + left,
+    y: r.y + top,
+    width: width.min(r.width - left),
+    height: height.min(r.height - top),
+  }
+}
 
-  Layout::default()
-    .direction(Direction::Horizontal)
-    .constraints(
-      [
-        Constraint::Length(outer_width),
-        Constraint::Length(width),
-        Constraint::Length(outer_width),
-      ]
-      .as_ref(),
-    )
-    .split(popup_layout[1])[1]
+/// Spinner shown while a view loads, one frame per tick.
+pub fn spinner_frame(tick: u64) -> &'static str {
+  let frames = glyphs().spinner;
+  frames[(tick % frames.len() as u64) as usize]
 }
 
-pub fn loading(
-  f: &mut Frame<'_>,
-  block: Block<'_>,
-  area: Rect,
-  is_loading: bool,
-  palette: Palette,
-) {
-  if is_loading {
-    let text = "\n\n Loading ...\n\n".to_owned();
-    let text = Text::from(text);
-    let text = text.patch_style(style_secondary(palette));
-
-    // Contains the text
-    let paragraph = Paragraph::new(text)
-      .style(style_secondary(palette))
-      .block(block);
-    f.render_widget(paragraph, area);
-  } else {
-    f.render_widget(block, area)
-  }
+/// Body of a view with nothing to list: a spinner while loading, a note when
+/// the API returned no items, or the error with a retry hint.
+pub fn loading(f: &mut Frame<'_>, block: Block<'_>, area: Rect, load: &ViewLoad, palette: Palette) {
+  let (text, style) = match &load.state {
+    LoadState::Loading => (
+      format!("\n\n {} Loading ...\n\n", spinner_frame(load.tick)),
+      style_secondary(palette),
+    ),
+    LoadState::Empty => (
+      "\n\n No resources found\n\n".to_owned(),
+      style_help(palette),
+    ),
+    LoadState::Error(message) => (
+      format!(
+        "\n\n {}\n\n {}\n",
+        message,
+        action_hint("retry", DEFAULT_KEYBINDING.retry_failed.key)
+      ),
+      style_failure(palette),
+    ),
+    LoadState::Loaded => {
+      f.render_widget(block, area);
+      return;
+    }
+  };
+  let paragraph = Paragraph::new(Text::from(text).patch_style(style))
+    .style(style)
+    .block(block)
+    .wrap(Wrap { trim: false });
+  f.render_widget(paragraph, area);
 }
 
 // using a macro to reuse code as generics will make handling lifetimes a PITA
@@ -747,6 +899,16 @@ macro_rules! draw_resource_tab {
           $app.palette,
         ),
       ),
+      ActiveBlock::Diff => $crate::ui::utils::draw_diff_block(
+        $f,
+        $app,
+        $area,
+        title_with_dual_style(
+          get_resource_title($app, $title, get_describe_active($block), $res.items.len()),
+          $crate::ui::utils::copy_and_escape_title_line($title, $app.palette),
+          $app.palette,
+        ),
+      ),
       ActiveBlock::Pods => $crate::app::pods::draw_block_as_sub($f, $app, $area),
       ActiveBlock::Containers => $crate::app::pods::draw_containers_block($f, $app, $area),
       ActiveBlock::Logs => $crate::app::pods::draw_logs_block($f, $app, $area),
@@ -768,40 +930,102 @@ pub fn draw_describe_block(f: &mut Frame<'_>, app: &mut App, area: Rect, title:
   draw_yaml_block(f, app, area, title);
 }
 
-/// Refreshes the syntax-highlight cache when empty or the theme changed.
-/// Returns `false` when there is no content to highlight.
-fn ensure_highlight_cache(app: &mut App) -> bool {
-  if app.data.describe_out.get_txt().is_empty() {
-    return false;
+/// Syntect theme for the active palette. Macchiato and Latte ship matching
+/// tmThemes; every other theme gets one built from its own palette so YAML
+/// colours follow the theme cycle.
+fn yaml_theme_for(palette: Palette) -> Cow<'static, Theme> {
+  match palette.name {
+    ThemeName::Macchiato => Cow::Borrowed(&get_yaml_themes().dark),
+    ThemeName::Latte => Cow::Borrowed(&get_yaml_themes().light),
+    _ => Cow::Owned(palette_yaml_theme(palette)),
   }
-  if app.data.describe_out.highlighted_lines.is_empty()
-    || app.data.describe_out.highlight_is_dark != app.is_dark()
-  {
-    let ss = get_syntax_set();
-    let syntax = get_yaml_syntax_reference();
-    let theme = if app.is_dark() {
-      &get_yaml_themes().dark
-    } else {
-      &get_yaml_themes().light
-    };
-    let mut h = syntect::easy::HighlightLines::new(syntax, theme);
-    let txt = app.data.describe_out.get_txt();
-    let lines: Vec<_> = syntect::util::LinesWithEndings::from(txt)
-      .filter_map(|line| match h.highlight_line(line, ss) {
-        Ok(segments) => {
-          let line_spans: Vec<_> = segments
-            .into_iter()
+}
+
+/// Map YAML scopes onto palette roles: keys use `label`, strings `success`,
+/// numbers `warning`, booleans/null `accent` and comments `muted`.
+fn palette_yaml_theme(palette: Palette) -> Theme {
+  let item = |scope: &str, color: Color| ThemeItem {
+    scope: scope.parse().expect("static scope selector should parse"),
+    style: StyleModifier {
+      foreground: Some(syntect_color(color)),
+      background: None,
+      font_style: None,
+    },
+  };
+  Theme {
+    settings: ThemeSettings {
+      foreground: Some(syntect_color(palette.fg)),
+      background: Some(syntect_color(Color::Reset)),
+      ..ThemeSettings::default()
+    },
+    scopes: vec![
+      item("entity.name.tag", palette.label),
+      item("string", palette.success),
+      item("constant.numeric", palette.warning),
+      item("constant.language", palette.accent),
+      item("comment", palette.muted),
+    ],
+    ..Theme::default()
+  }
+}
+
+/// Only RGB colours have a syntect equivalent; anything else (named colours on
+/// Mono, `Reset`) becomes fully transparent, which renders as the terminal
+/// default.
+fn syntect_color(color: Color) -> SyntectColor {
+  match color {
+    Color::Rgb(r, g, b) => SyntectColor { r, g, b, a: 0xFF },
+    _ => SyntectColor {
+      r: 0,
+      g: 0,
+      b: 0,
+      a: 0,
+    },
+  }
+}
+
+/// Extends the syntax-highlight cache so it covers the first `upto` lines,
+/// resetting it when the theme changed. Later windows resume from the stored
+/// parser state, so only what has been scrolled into view is highlighted.
+fn ensure_highlight_cache(app: &mut App, upto: usize) {
+  let palette = app.palette;
+  let out = &mut app.data.describe_out;
+  if out.highlight_theme != Some(palette.name) {
+    out.highlighted_lines.clear();
+    out.highlight_state = None;
+    out.highlight_theme = Some(palette.name);
+  }
+  let done = out.highlighted_lines.len();
+  if done >= upto {
+    return;
+  }
+
+  let ss = get_syntax_set();
+  let theme = yaml_theme_for(palette);
+  let highlighter = Highlighter::new(&theme);
+  let (mut parse_state, mut highlight_state) = out.highlight_state.take().unwrap_or_else(|| {
+    (
+      ParseState::new(get_yaml_syntax_reference()),
+      HighlightState::new(&highlighter, ScopeStack::new()),
+    )
+  });
+  let lines: Vec<_> = syntect::util::LinesWithEndings::from(out.get_txt())
+    .skip(done)
+    .take(upto - done)
+    .map(|line| match parse_state.parse_line(line, ss) {
+      Ok(ops) => {
+        let line_spans: Vec<_> =
+          HighlightIterator::new(&mut highlight_state, &ops, line, &highlighter)
             .filter_map(syntect_to_ratatui_span_owned)
             .collect();
-          Some(ratatui::text::Line::from(line_spans))
-        }
-        Err(_) => None,
-      })
-      .collect();
-    app.data.describe_out.highlighted_lines = lines;
-    app.data.describe_out.highlight_is_dark = app.is_dark();
-  }
-  true
+        Line::from(line_spans)
+      }
+      // Keep the line (unstyled) so indices stay aligned with the text.
+      Err(_) => Line::from(line.trim_end_matches(['\r', '\n']).to_owned()),
+    })
+    .collect();
+  out.highlighted_lines.extend(lines);
+  out.highlight_state = Some((parse_state, highlight_state));
 }
 
 /// Compute the (start, end, scroll-within-slice) window into a buffer of
@@ -821,46 +1045,129 @@ fn highlight_window(offset: usize, total: usize, view_h: usize) -> (usize, usize
 pub fn draw_yaml_block(f: &mut Frame<'_>, app: &mut App, area: Rect, title: Line<'_>) {
   let palette = app.palette;
   let block = layout_block_top_border(title, palette);
-  if ensure_highlight_cache(app) {
-    let total = app.data.describe_out.highlighted_lines.len();
-    if total == 0 {
-      loading(f, block, area, app.is_loading(), palette);
-      return;
+  let total = app.data.describe_out.line_count();
+  if total == 0 {
+    loading(f, block, area, &app.global_load(), palette);
+    return;
+  }
+  let offset = app.data.describe_out.offset;
+  // Subtract 2 for the top-border of the block; clamp to >=1 so a tiny
+  // terminal doesn't degenerate into an empty slice.
+  let view_h = (area.height.saturating_sub(2) as usize).max(1);
+  let (slice_start, slice_end, adjusted_offset) = highlight_window(offset, total, view_h);
+  // Without enhanced graphics the text renders plain, skipping the highlighter.
+  let mut visible_lines: Vec<Line<'_>> = if app.enhanced_graphics {
+    ensure_highlight_cache(app, slice_end);
+    let lines = &app.data.describe_out.highlighted_lines;
+    lines[slice_start.min(lines.len())..slice_end.min(lines.len())].to_vec()
+  } else {
+    app
+      .data
+      .describe_out
+      .get_txt()
+      .lines()
+      .skip(slice_start)
+      .take(slice_end - slice_start)
+      .map(|line| Line::from(line.to_owned()))
+      .collect()
+  };
+  // masked lines lose their highlighting rather than keep a stale one
+  if app.redact {
+    app.redact_describe();
+    for (index, line) in (slice_start..).zip(visible_lines.iter_mut()) {
+      if let Some(masked) = app.data.describe_out.redacted_line(index) {
+        *line = Line::from(masked.to_owned());
+      }
     }
-    let offset = app.data.describe_out.offset;
-    // Subtract 2 for the top-border of the block; clamp to >=1 so a tiny
-    // terminal doesn't degenerate into an empty slice.
-    let view_h = (area.height.saturating_sub(2) as usize).max(1);
-    let (slice_start, slice_end, adjusted_offset) = highlight_window(offset, total, view_h);
-    let visible_lines = app.data.describe_out.highlighted_lines[slice_start..slice_end].to_vec();
-    let paragraph = Paragraph::new(visible_lines)
-      .block(block)
-      .wrap(Wrap { trim: false })
-      .scroll((adjusted_offset, 0));
-    f.render_widget(paragraph, area);
+  }
+  let paragraph = Paragraph::new(visible_lines)
+    .block(block)
+    .style(style_text(palette))
+    .wrap(Wrap { trim: false })
+    .scroll((adjusted_offset, 0));
+  f.render_widget(paragraph, area);
+}
+
+/// Unified diff view: added lines in `success`, removed lines in `failure`
+/// and hunk headers in `help`. Anything that is not a diff (e.g. the "no
+/// annotation" notice) renders as plain text.
+pub fn draw_diff_block(f: &mut Frame<'_>, app: &mut App, area: Rect, title: Line<'_>) {
+  let palette = app.palette;
+  let block = layout_block_top_border(title, palette);
+  let total = app.data.describe_out.line_count();
+  if total == 0 {
+    loading(f, block, area, &app.global_load(), palette);
+    return;
+  }
+  let view_h = (area.height.saturating_sub(2) as usize).max(1);
+  let (slice_start, slice_end, adjusted_offset) =
+    highlight_window(app.data.describe_out.offset, total, view_h);
+  app.redact_describe();
+  let out = &app.data.describe_out;
+  let lines: Vec<Line<'_>> = out
+    .get_txt()
+    .lines()
+    .enumerate()
+    .skip(slice_start)
+    .take(slice_end - slice_start)
+    .map(|(index, line)| {
+      let line = out
+        .redacted_line(index)
+        .filter(|_| app.redact)
+        .unwrap_or(line);
+      Line::styled(line.to_owned(), diff_line_style(index, line, palette))
+    })
+    .collect();
+  let paragraph = Paragraph::new(lines)
+    .block(block)
+    .wrap(Wrap { trim: false })
+    .scroll((adjusted_offset, 0));
+  f.render_widget(paragraph, area);
+}
+
+/// Style of the diff's line at `index`. Only its opening `--- `/`+++ ` pair
+/// is the file header; further on, those are removed or added lines that
+/// happen to start with dashes or pluses.
+fn diff_line_style(index: usize, line: &str, palette: Palette) -> Style {
+  let header = match index {
+    0 => line.starts_with("--- "),
+    1 => line.starts_with("+++ "),
+    _ => false,
+  };
+  if header {
+    style_text(palette).add_modifier(Modifier::BOLD)
+  } else if line.starts_with("@@") {
+    style_help(palette)
+  } else if line.starts_with('+') {
+    style_success(palette)
+  } else if line.starts_with('-') {
+    style_failure(palette)
   } else {
-    loading(f, block, area, app.is_loading(), palette);
+    style_text(palette)
   }
 }
 
-fn draw_resource_table<'a, T: Named, F>(
+fn draw_resource_table<'a, T: Named + Health, F>(
   f: &mut Frame<'_>,
   area: Rect,
   table_props: ResourceTableProps<'a, T>,
   row_cell_mapper: F,
   palette: Palette,
-  is_loading: bool,
+  load: ViewLoad,
   block: Block<'a>,
 ) where
   F: Fn(&T) -> Row<'a>,
 {
   if !table_props.resource.items.is_empty() {
     let filter = table_props.resource.filter.to_lowercase();
-    let has_filter = !filter.is_empty();
+    let problems_only = load.problems_only && T::CHECKED;
+    let has_filter = !filter.is_empty() || problems_only;
     let mut filtered_indices: Vec<usize> = Vec::new();
     let mut filtered_items: Vec<&T> = Vec::new();
     for (idx, item) in table_props.resource.items.iter().enumerate() {
-      if !has_filter || filter_by_name(&filter, item) {
+      if (filter.is_empty() || filter_by_name(&filter, item))
+        && !(problems_only && item.is_healthy())
+      {
         if has_filter {
           filtered_indices.push(idx);
         }
@@ -877,6 +1184,47 @@ fn draw_resource_table<'a, T: Named, F>(
       }
     }
     table_props.resource.filtered_indices = filtered_indices;
+    table_props.resource.problems_only = problems_only;
+    // rows inserted above or a new sort order would otherwise leave the
+    // selection on another resource
+    if std::mem::take(&mut table_props.resource.items_replaced) && load.follow_selection {
+      let moved_to = table_props
+        .resource
+        .selected_row
+        .as_ref()
+        .and_then(|row| filtered_items.iter().position(|item| row.matches(*item)));
+      if let Some(index) = moved_to {
+        table_props.resource.state.select(Some(index));
+      }
+    }
+    table_props.resource.selected_row = table_props
+      .resource
+      .state
+      .selected()
+      .and_then(|i| filtered_items.get(i))
+      .map(|item| MarkedRow::of(*item));
+    match table_props.resource.export {
+      TableExport::Requested(format) => {
+        let text = table_text(
+          &table_props.table_headers,
+          filtered_items.iter().map(|item| row_cell_mapper(item)),
+        );
+        table_props.resource.export = TableExport::Ready(format, text);
+      }
+      TableExport::RowRequested => {
+        let selected = table_props
+          .resource
+          .state
+          .selected()
+          .and_then(|i| filtered_items.get(i));
+        let text = table_text(
+          &table_props.table_headers,
+          selected.into_iter().map(|item| row_cell_mapper(item)),
+        );
+        table_props.resource.export = TableExport::RowReady(text);
+      }
+      TableExport::Idle | TableExport::Ready(..) | TableExport::RowReady(_) => {}
+    }
 
     // Skip row_cell_mapper for off-screen items: ratatui's Table only paints
     // rows intersecting the visible area, so we can hand it cheap empty Rows
@@ -889,28 +1237,181 @@ fn draw_resource_table<'a, T: Named, F>(
     let visible_start = selected.saturating_sub(view_h);
     let visible_end = (selected + view_h * 2).min(filtered_items.len());
 
+    let marked = &table_props.resource.marked;
+    let now = Utc::now();
     let rows: Vec<Row<'a>> = filtered_items
       .iter()
       .enumerate()
       .map(|(fi, item)| {
         if fi >= visible_start && fi < visible_end {
-          row_cell_mapper(item)
+          let row = row_cell_mapper(item);
+          if marked.iter().any(|m| m.matches(*item)) {
+            row.style(style_marked(palette))
+          } else if let Some(style) = load
+            .highlight_changes
+            .and_then(|window| style_changed(item.get_changed(), now, window, palette))
+          {
+            row.style(style)
+          } else {
+            row
+          }
         } else {
           Row::default()
         }
       })
       .collect();
 
-    let table = Table::new(rows, &table_props.column_widths)
+    let widths = scroll_columns(
+      &table_props.table_headers,
+      &table_props.column_widths,
+      &mut table_props.resource.column_scroll,
+    );
+    let scrolled = table_props.resource.column_scroll.offset > 0;
+    let more_right = columns_cut_off(
+      &table_props.table_headers,
+      &widths,
+      &table_props.resource.column_scroll,
+      block.inner(area).width,
+    );
+    let block = if load.has_more {
+      block.title_bottom(Line::from(Span::styled(
+        " more items, scroll down to load ",
+        style_help(palette),
+      )))
+    } else {
+      block
+    };
+    let table = Table::new(rows, widths)
       .header(table_header_style(table_props.table_headers, palette))
       .block(block)
       .row_highlight_style(style_highlight())
       .highlight_symbol(HIGHLIGHT);
 
     f.render_stateful_widget(table, area, &mut table_props.resource.state);
+    if scrolled && area.height > 1 {
+      // Header row, in the highlight-symbol gutter: columns are hidden on the left.
+      f.render_widget(
+        Paragraph::new("‹").style(style_secondary(palette)),
+        Rect::new(area.x, area.y + 1, 1, 1),
+      );
+    }
+    if more_right && area.height > 1 && area.width > 1 {
+      // ...and on the right edge when scrolling right shows more.
+      f.render_widget(
+        Paragraph::new("›").style(style_secondary(palette)),
+        Rect::new(area.right() - 1, area.y + 1, 1, 1),
+      );
+    }
   } else {
-    loading(f, block, area, is_loading, palette);
+    loading(f, block, area, &load, palette);
+  }
+}
+
+/// Widest cell kept in an export; longer values are cut like they would be on
+/// a terminal this wide.
+const EXPORT_CELL_WIDTH: u16 = 1024;
+
+/// The text of each row under `headers`. Rows are rendered off-screen at a
+/// generous width, so exports show what the table shows, uncut.
+fn table_text<'a>(headers: &[&str], rows: impl Iterator<Item = Row<'a>>) -> TableText {
+  let columns = headers.len().max(1) as u16;
+  let cell_width = EXPORT_CELL_WIDTH.min(u16::MAX / columns);
+  let area = Rect::new(0, 0, cell_width * columns, 1);
+  let mut buf = Buffer::empty(area);
+  let rows = rows
+    .map(|row| {
+      buf.reset();
+      Table::new([row], vec![Constraint::Length(cell_width); headers.len()])
+        .column_spacing(0)
+        .render(area, &mut buf);
+      (0..headers.len() as u16)
+        .map(|column| {
+          let mut text = String::new();
+          let mut covered = 0;
+          for x in column * cell_width..(column + 1) * cell_width {
+            // Wide characters cover the cells after them.
+            if covered > 0 {
+              covered -= 1;
+              continue;
+            }
+            let symbol = buf[(x, 0)].symbol();
+            covered = Span::raw(symbol).width().saturating_sub(1);
+            text.push_str(symbol);
+          }
+          text.trim_end().to_owned()
+        })
+        .collect()
+    })
+    .collect();
+  TableText {
+    headers: headers.iter().map(|header| header.to_string()).collect(),
+    rows,
+  }
+}
+
+/// Widths with `scroll.offset` columns hidden from the left, skipping the
+/// `Name` column when it is pinned. The columns left share the freed space.
+/// The offset is clamped so one scrollable column always stays visible.
+fn scroll_columns(
+  headers: &[&str],
+  widths: &[Constraint],
+  scroll: &mut ColumnScroll,
+) -> Vec<Constraint> {
+  let pinned = pinned_column(headers, scroll);
+  let scrollable: Vec<usize> = (0..widths.len()).filter(|i| Some(*i) != pinned).collect();
+  scroll.offset = scroll.offset.min(scrollable.len().saturating_sub(1));
+  if scroll.offset == 0 {
+    return widths.to_vec();
+  }
+  let hidden = &scrollable[..scroll.offset];
+  widths
+    .iter()
+    .enumerate()
+    .map(|(i, width)| match width {
+      _ if hidden.contains(&i) => Constraint::Length(0),
+      Constraint::Percentage(p) => Constraint::Fill(*p),
+      other => *other,
+    })
+    .collect()
+}
+
+/// The `Name` column, when it is pinned.
+fn pinned_column(headers: &[&str], scroll: &ColumnScroll) -> Option<usize> {
+  scroll
+    .pin_name
+    .then(|| headers.iter().position(|h| *h == "Name"))
+    .flatten()
+}
+
+/// Whether the table, laid out at `width` like `Table` does with the scrolled
+/// `widths`, cuts a column narrower than its header while scrolling right is
+/// still possible.
+fn columns_cut_off(
+  headers: &[&str],
+  widths: &[Constraint],
+  scroll: &ColumnScroll,
+  width: u16,
+) -> bool {
+  let pinned = pinned_column(headers, scroll);
+  let scrollable = widths.len() - usize::from(pinned.is_some());
+  if scroll.offset + 1 >= scrollable {
+    return false;
   }
+  let columns = Rect::new(
+    0,
+    0,
+    width.saturating_sub(Span::raw(HIGHLIGHT).width() as u16),
+    1,
+  );
+  Layout::horizontal(widths)
+    .spacing(1)
+    .split(columns)
+    .iter()
+    .zip(headers)
+    .zip(widths)
+    .any(|((rect, header), constraint)| {
+      *constraint != Constraint::Length(0) && (rect.width as usize) < Span::raw(*header).width()
+    })
 }
 
 /// Builds the help `Line` for a resource block title, weaving filter status
@@ -949,13 +1450,31 @@ fn build_resource_help_line(
 }
 
 /// Draw a kubernetes resource overview tab
-pub fn draw_resource_block<'a, T: Named, F>(
+/// Append the selected row's exact creation time to a table title, so the
+/// compact age column can be read as a date.
+fn with_created<T: Named>(title: String, resource: &StatefulTable<T>) -> String {
+  match resource.get_selected_item().and_then(Named::get_created) {
+    Some(created) => format!("{} · created {}", title, created),
+    None => title,
+  }
+}
+
+/// Flag a table title while healthy rows are hidden.
+fn with_problems_only<T: Health>(title: String, load: &ViewLoad) -> String {
+  if load.problems_only && T::CHECKED {
+    format!("{} · problems only", title)
+  } else {
+    title
+  }
+}
+
+pub fn draw_resource_block<'a, T: Named + Health, F>(
   f: &mut Frame<'_>,
   area: Rect,
   table_props: ResourceTableProps<'a, T>,
   row_cell_mapper: F,
   palette: Palette,
-  is_loading: bool,
+  load: ViewLoad,
 ) where
   F: Fn(&T) -> Row<'a>,
 {
@@ -966,6 +1485,7 @@ pub fn draw_resource_block<'a, T: Named, F>(
     table_headers,
     column_widths,
   } = table_props;
+  let title = with_problems_only::<T>(with_created(title, resource), &load);
   let filter = resource.filter.clone();
   let filter_active = resource.filter_active;
   if filter_active {
@@ -988,7 +1508,7 @@ pub fn draw_resource_block<'a, T: Named, F>(
       },
       row_cell_mapper,
       palette,
-      is_loading,
+      load,
       block,
     );
     f.set_cursor_position(filter_cursor_position(area, title_width, &filter));
@@ -1010,18 +1530,18 @@ pub fn draw_resource_block<'a, T: Named, F>(
     },
     row_cell_mapper,
     palette,
-    is_loading,
+    load,
     block,
   );
 }
 
-pub fn draw_route_resource_block<'a, T: Named, F>(
+pub fn draw_route_resource_block<'a, T: Named + Health, F>(
   f: &mut Frame<'_>,
   area: Rect,
   table_props: ResourceTableProps<'a, T>,
   row_cell_mapper: F,
   palette: Palette,
-  is_loading: bool,
+  load: ViewLoad,
 ) where
   F: Fn(&T) -> Row<'a>,
 {
@@ -1032,6 +1552,7 @@ pub fn draw_route_resource_block<'a, T: Named, F>(
     table_headers,
     column_widths,
   } = table_props;
+  let title = with_problems_only::<T>(with_created(title, resource), &load);
   let filter = resource.filter.clone();
   let filter_active = resource.filter_active;
   if filter_active {
@@ -1054,7 +1575,7 @@ pub fn draw_route_resource_block<'a, T: Named, F>(
       },
       row_cell_mapper,
       palette,
-      is_loading,
+      load,
       block,
     );
     f.set_cursor_position(filter_cursor_position(area, title_width, &filter));
@@ -1075,7 +1596,7 @@ pub fn draw_route_resource_block<'a, T: Named, F>(
     },
     row_cell_mapper,
     palette,
-    is_loading,
+    load,
     block,
   );
 }
@@ -1134,10 +1655,12 @@ pub fn get_resource_title<S: AsRef<str>>(
 
 static DESCRIBE_ACTIVE: &str = "-> Describe ";
 static YAML_ACTIVE: &str = "-> YAML ";
+static DIFF_ACTIVE: &str = "-> Diff (last-applied vs live) ";
 
 pub fn get_describe_active<'a>(block: ActiveBlock) -> &'a str {
   match block {
     ActiveBlock::Describe => DESCRIBE_ACTIVE,
+    ActiveBlock::Diff => DIFF_ACTIVE,
     _ => YAML_ACTIVE,
   }
 }
@@ -1154,8 +1677,52 @@ mod tests {
   };
 
   use super::*;
-  use crate::ui::theme::{palette_for, ThemeName};
+  use crate::{
+    app::models::FilterableTable,
+    ui::theme::{palette_for, ThemeName},
+  };
+
+  #[test]
+  fn test_centered_rect_keeps_odd_heights_and_clamps() {
+    let screen = Rect::new(0, 0, 120, 30);
+    assert_eq!(centered_rect(40, 11, screen), Rect::new(40, 10, 40, 11));
+    assert_eq!(centered_rect(40, 10, screen), Rect::new(40, 10, 40, 10));
+    assert_eq!(
+      centered_rect(200, 50, Rect::new(2, 3, 120, 30)),
+      Rect::new(2, 3, 120, 30)
+    );
+  }
+
+  #[test]
+  fn test_style_changed_fades_over_the_window() {
+    let palette = palette_for(ThemeName::Macchiato);
+    let now = Utc::now();
+    let window = Duration::seconds(30);
+    let style =
+      |secs: i64| style_changed(Some(now - Duration::seconds(secs)), now, window, palette);
 
+    assert_eq!(
+      style(0),
+      Some(
+        Style::default()
+          .fg(palette.accent)
+          .add_modifier(Modifier::BOLD)
+      )
+    );
+    assert_eq!(style(15), Some(Style::default().fg(palette.accent)));
+    assert_eq!(
+      style(25),
+      Some(
+        Style::default()
+          .fg(palette.accent)
+          .add_modifier(Modifier::DIM)
+      )
+    );
+    assert_eq!(style(30), None);
+    // a clock a little ahead of ours still counts as just changed
+    assert!(style(-5).is_some());
+    assert_eq!(style_changed(None, now, window, palette), None);
+  }
   #[test]
   fn test_gauge_fill_style_tiers() {
     let palette = palette_for(ThemeName::Macchiato);
@@ -1186,6 +1753,29 @@ mod tests {
     assert_eq!(text, "CPU  ######..... 50%");
   }
 
+  #[test]
+  fn test_with_created_appends_selected_creation_time() {
+    use k8s_openapi::api::core::v1::Namespace;
+
+    use crate::app::ns::KubeNs;
+
+    let ns: Namespace = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "team-a", "creationTimestamp": "2024-03-01T10:00:00Z" }
+    }))
+    .unwrap();
+    let mut table = StatefulTable::new();
+    assert_eq!(
+      with_created("Namespaces [0]".into(), &table),
+      "Namespaces [0]"
+    );
+
+    table.set_items(vec![KubeNs::from(ns)]);
+    assert_eq!(
+      with_created("Namespaces [1]".into(), &table),
+      "Namespaces [1] · created 2024-03-01T10:00:00Z"
+    );
+  }
+
   #[test]
   fn test_draw_resource_block() {
     let backend = TestBackend::new(100, 6);
@@ -1204,6 +1794,7 @@ mod tests {
         &self.name
       }
     }
+    impl Health for RenderTest {}
     terminal
       .draw(|f| {
         let size = f.area();
@@ -1253,7 +1844,7 @@ mod tests {
             .style(style_primary(p))
           },
           p,
-          false,
+          ViewLoad::default(),
         );
       })
       .unwrap();
@@ -1337,6 +1928,7 @@ mod tests {
         &self.name
       }
     }
+    impl Health for RenderTest {}
 
     terminal
       .draw(|f| {
@@ -1388,7 +1980,7 @@ mod tests {
             .style(style_primary(p))
           },
           p,
-          false,
+          ViewLoad::default(),
         );
       })
       .unwrap();
@@ -1461,6 +2053,174 @@ mod tests {
     terminal.backend().assert_buffer(&expected);
   }
 
+  #[test]
+  fn test_draw_resource_block_keeps_the_selection_on_its_resource() {
+    let backend = TestBackend::new(30, 8);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let p = palette_for(ThemeName::Macchiato);
+
+    struct RenderTest {
+      pub name: String,
+    }
+    impl Named for RenderTest {
+      fn get_name(&self) -> &String {
+        &self.name
+      }
+    }
+    impl Health for RenderTest {}
+
+    let rows = |names: &[&str]| {
+      names
+        .iter()
+        .map(|name| RenderTest {
+          name: (*name).into(),
+        })
+        .collect::<Vec<_>>()
+    };
+    let mut draw = |resource: &mut StatefulTable<RenderTest>, follow_selection: bool| {
+      terminal
+        .draw(|f| {
+          draw_resource_block(
+            f,
+            f.area(),
+            ResourceTableProps {
+              title: "Test".into(),
+              inline_help: Line::default(),
+              resource,
+              table_headers: vec!["Name"],
+              column_widths: vec![Constraint::Percentage(100)],
+            },
+            |c| Row::new(vec![Cell::from(c.name.to_owned())]),
+            p,
+            ViewLoad {
+              follow_selection,
+              ..ViewLoad::default()
+            },
+          );
+        })
+        .unwrap();
+    };
+    let selected = |resource: &StatefulTable<RenderTest>| {
+      resource
+        .get_selected_item()
+        .map(|item| item.name.clone())
+        .unwrap()
+    };
+
+    let mut resource = StatefulTable::new();
+    resource.set_items(rows(&["web-1", "web-2", "web-3"]));
+    resource.state.select(Some(1));
+    draw(&mut resource, true);
+
+    // a new pod sorts in above the selected one
+    resource.set_items(rows(&["web-0", "web-1", "web-2", "web-3"]));
+    draw(&mut resource, true);
+    assert_eq!(selected(&resource), "web-2");
+
+    // moving the selection is not undone by the next draw
+    resource.state.select(Some(3));
+    draw(&mut resource, true);
+    assert_eq!(selected(&resource), "web-3");
+
+    // a resource that is gone leaves the selection where it was
+    resource.set_items(rows(&["web-0", "web-1", "web-2", "web-4"]));
+    draw(&mut resource, true);
+    assert_eq!(selected(&resource), "web-4");
+
+    // and with following off the row index is kept
+    resource.state.select(Some(1));
+    draw(&mut resource, false);
+    resource.set_items(rows(&["new", "web-0", "web-1", "web-2"]));
+    draw(&mut resource, false);
+    assert_eq!(selected(&resource), "web-0");
+  }
+
+  #[test]
+  fn test_draw_resource_block_collects_requested_export() {
+    use crate::app::{export::ExportFormat, models::FilterableTable};
+
+    // Far narrower than the values, which the export still holds uncut.
+    let backend = TestBackend::new(30, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let p = palette_for(ThemeName::Macchiato);
+
+    struct RenderTest {
+      pub name: String,
+      pub status: String,
+    }
+    impl Named for RenderTest {
+      fn get_name(&self) -> &String {
+        &self.name
+      }
+    }
+    impl Health for RenderTest {}
+
+    let mut resource: StatefulTable<RenderTest> = StatefulTable::new();
+    resource.set_items(
+      ["web-0", "db-long-name-that-is-cut-on-screen", "cache"]
+        .into_iter()
+        .map(|name| RenderTest {
+          name: name.into(),
+          status: "✓ Running, ready".into(),
+        })
+        .collect(),
+    );
+    resource.filter = "-".into();
+    resource.request_export(ExportFormat::Json);
+
+    let mut draw = |resource: &mut StatefulTable<RenderTest>| {
+      terminal
+        .draw(|f| {
+          draw_resource_block(
+            f,
+            f.area(),
+            ResourceTableProps {
+              title: "Test".into(),
+              inline_help: Line::default(),
+              resource,
+              table_headers: vec!["Name", "Status"],
+              column_widths: vec![Constraint::Percentage(50), Constraint::Percentage(50)],
+            },
+            |c| {
+              Row::new(vec![
+                Cell::from(c.name.to_owned()),
+                Cell::from(c.status.to_owned()),
+              ])
+            },
+            p,
+            ViewLoad::default(),
+          );
+        })
+        .unwrap();
+    };
+    draw(&mut resource);
+
+    let cells = |name: &str| vec![name.to_owned(), "✓ Running, ready".to_owned()];
+    assert_eq!(
+      resource.take_export(),
+      TableExport::Ready(
+        ExportFormat::Json,
+        TableText {
+          headers: vec!["Name".into(), "Status".into()],
+          rows: vec![cells("web-0"), cells("db-long-name-that-is-cut-on-screen"),],
+        }
+      )
+    );
+    assert_eq!(resource.take_export(), TableExport::Idle);
+
+    // A row copy collects only the selected row, counted among the matches.
+    resource.state.select(Some(1));
+    resource.request_row_copy();
+    draw(&mut resource);
+    assert_eq!(
+      resource.take_export(),
+      TableExport::RowReady(TableText {
+        headers: vec!["Name".into(), "Status".into()],
+        rows: vec![cells("db-long-name-that-is-cut-on-screen")],
+      })
+    );
+  }
+
   #[test]
   fn test_draw_resource_block_filter_glob() {
     let backend = TestBackend::new(100, 6);
@@ -1478,6 +2238,7 @@ mod tests {
         &self.name
       }
     }
+    impl Health for RenderTest {}
 
     terminal
       .draw(|f| {
@@ -1529,7 +2290,7 @@ mod tests {
             .style(style_primary(p))
           },
           p,
-          false,
+          ViewLoad::default(),
         );
       })
       .unwrap();
@@ -1626,6 +2387,7 @@ mod tests {
         &self.name
       }
     }
+    impl Health for RenderTest {}
 
     terminal
       .draw(|f| {
@@ -1648,7 +2410,7 @@ mod tests {
           },
           |c| Row::new(vec![Cell::from(c.name.to_owned())]).style(style_primary(p)),
           p,
-          false,
+          ViewLoad::default(),
         );
       })
       .unwrap();
@@ -1662,6 +2424,86 @@ mod tests {
     assert!(!first_line.contains("Esc:back"));
   }
 
+  #[test]
+  fn test_draw_resource_block_shows_problems_only() {
+    let backend = TestBackend::new(60, 6);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let p = palette_for(ThemeName::Macchiato);
+
+    struct RenderTest {
+      pub name: String,
+      pub healthy: bool,
+    }
+
+    impl Named for RenderTest {
+      fn get_name(&self) -> &String {
+        &self.name
+      }
+    }
+    impl Health for RenderTest {
+      const CHECKED: bool = true;
+
+      fn is_healthy(&self) -> bool {
+        self.healthy
+      }
+    }
+
+    let mut resource: StatefulTable<RenderTest> = StatefulTable::new();
+    resource.set_items(
+      [("web", true), ("db", false), ("cache", true)]
+        .into_iter()
+        .map(|(name, healthy)| RenderTest {
+          name: name.into(),
+          healthy,
+        })
+        .collect(),
+    );
+    let mut draw = |resource: &mut StatefulTable<RenderTest>, problems_only: bool| {
+      terminal
+        .draw(|f| {
+          draw_resource_block(
+            f,
+            f.area(),
+            ResourceTableProps {
+              title: "Test".into(),
+              inline_help: Line::default(),
+              resource,
+              table_headers: vec!["Name"],
+              column_widths: vec![Constraint::Percentage(100)],
+            },
+            |c| Row::new(vec![Cell::from(c.name.to_owned())]),
+            p,
+            ViewLoad {
+              problems_only,
+              ..ViewLoad::default()
+            },
+          );
+        })
+        .unwrap();
+      let buffer = terminal.backend().buffer().clone();
+      (0..buffer.area.height)
+        .map(|row| {
+          (0..buffer.area.width)
+            .map(|col| buffer[(col, row)].symbol())
+            .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+    };
+
+    let rows = draw(&mut resource, true);
+    assert!(rows[0].contains("Test · problems only"));
+    assert!(rows[2].contains("db"));
+    assert!(!rows[3].contains("cache"));
+    assert_eq!(resource.filtered_indices, vec![1]);
+    assert_eq!(resource.count_label(), "1/3");
+    assert_eq!(resource.get_selected_item().unwrap().name, "db");
+
+    let rows = draw(&mut resource, false);
+    assert!(!rows[0].contains("problems only"));
+    assert!(rows[2].contains("web"));
+    assert_eq!(resource.count_label(), "3");
+  }
+
   #[test]
   fn test_title_with_ns() {
     assert_eq!(title_with_ns("Title", "hello", 3), "Title (ns: hello) [3]");
@@ -1773,4 +2615,362 @@ mod tests {
     assert_eq!(end, 1);
     assert_eq!(scroll, 0);
   }
+
+  fn yaml_app(enhanced_graphics: bool, lines: usize) -> App {
+    let mut app = App::default();
+    app.enhanced_graphics = enhanced_graphics;
+    let yaml: String = (0..lines).map(|i| format!("key{i}: {i}\n")).collect();
+    app.data.describe_out = crate::app::models::ScrollableTxt::with_string(yaml);
+    app
+  }
+
+  #[test]
+  fn test_ensure_highlight_cache_is_lazy_and_resumable() {
+    let mut app = yaml_app(true, 100);
+
+    ensure_highlight_cache(&mut app, 10);
+    assert_eq!(app.data.describe_out.highlighted_lines.len(), 10);
+
+    ensure_highlight_cache(&mut app, 25);
+    assert_eq!(app.data.describe_out.highlighted_lines.len(), 25);
+    let text: String = app.data.describe_out.highlighted_lines[24]
+      .spans
+      .iter()
+      .map(|s| s.content.as_ref())
+      .collect();
+    assert_eq!(text, "key24: 24\n");
+
+    // Asking past the end stops at the last line.
+    ensure_highlight_cache(&mut app, 500);
+    assert_eq!(app.data.describe_out.highlighted_lines.len(), 100);
+  }
+
+  #[test]
+  fn test_ensure_highlight_cache_resets_on_theme_change() {
+    let mut app = yaml_app(true, 20);
+    ensure_highlight_cache(&mut app, 20);
+    assert_eq!(
+      app.data.describe_out.highlight_theme,
+      Some(ThemeName::Macchiato)
+    );
+
+    app.theme = ThemeName::GruvboxDark;
+    app.palette = palette_for(ThemeName::GruvboxDark);
+    ensure_highlight_cache(&mut app, 5);
+    assert_eq!(app.data.describe_out.highlighted_lines.len(), 5);
+    assert_eq!(
+      app.data.describe_out.highlight_theme,
+      Some(ThemeName::GruvboxDark)
+    );
+    // Keys pick up the palette's label colour on themes without a tmTheme.
+    let key_span = &app.data.describe_out.highlighted_lines[0].spans[0];
+    assert_eq!(key_span.content, "key0");
+    assert_eq!(
+      key_span.style.fg,
+      Some(palette_for(ThemeName::GruvboxDark).label)
+    );
+  }
+
+  #[test]
+  fn test_loading_renders_spinner_empty_and_error() {
+    let p = palette_for(ThemeName::Macchiato);
+    let render = |load: ViewLoad| {
+      let backend = TestBackend::new(60, 6);
+      let mut terminal = Terminal::new(backend).unwrap();
+      terminal
+        .draw(|f| loading(f, Block::default(), f.area(), &load, p))
+        .unwrap();
+      let buffer = terminal.backend().buffer();
+      (0..6)
+        .map(|y| {
+          (0..60)
+            .map(|x| buffer[(x, y)].symbol().to_string())
+            .collect::<String>()
+            .trim_end()
+            .to_owned()
+        })
+        .collect::<Vec<_>>()
+    };
+
+    let loading_rows = render(ViewLoad {
+      state: LoadState::Loading,
+      tick: 11,
+      ..ViewLoad::default()
+    });
+    assert_eq!(loading_rows[2], " ⠙ Loading ...");
+
+    let empty_rows = render(ViewLoad {
+      state: LoadState::Empty,
+      tick: 0,
+      ..ViewLoad::default()
+    });
+    assert_eq!(empty_rows[2], " No resources found");
+
+    let error_rows = render(ViewLoad {
+      state: LoadState::Error("Failed to get pods. forbidden".into()),
+      tick: 0,
+      ..ViewLoad::default()
+    });
+    assert_eq!(error_rows[2], " Failed to get pods. forbidden");
+    assert_eq!(
+      error_rows[4].trim(),
+      action_hint("retry", DEFAULT_KEYBINDING.retry_failed.key)
+    );
+  }
+
+  #[test]
+  fn test_scroll_columns_hides_leading_columns_and_pins_name() {
+    let headers = ["Namespace", "Name", "Status", "Age"];
+    let widths = [
+      Constraint::Percentage(30),
+      Constraint::Percentage(40),
+      Constraint::Percentage(20),
+      Constraint::Percentage(10),
+    ];
+    let mut scroll = ColumnScroll::default();
+    assert_eq!(scroll_columns(&headers, &widths, &mut scroll), widths);
+
+    scroll.offset = 2;
+    scroll.pin_name = true;
+    assert_eq!(
+      scroll_columns(&headers, &widths, &mut scroll),
+      [
+        Constraint::Length(0),
+        Constraint::Fill(40),
+        Constraint::Length(0),
+        Constraint::Fill(10),
+      ]
+    );
+
+    // Unpinned, scrolling past the end stops at the last column.
+    scroll.offset = 10;
+    scroll.pin_name = false;
+    assert_eq!(
+      scroll_columns(&headers, &widths, &mut scroll),
+      [
+        Constraint::Length(0),
+        Constraint::Length(0),
+        Constraint::Length(0),
+        Constraint::Fill(10),
+      ]
+    );
+    assert_eq!(scroll.offset, 3);
+  }
+
+  #[test]
+  fn test_draw_resource_table_marks_scrolled_columns() {
+    let backend = TestBackend::new(40, 4);
+    let mut terminal = Terminal::new(backend).unwrap();
+    let mut table = StatefulTable::with_items(vec![crate::app::node_pods::KubeNodePods {
+      name: "node-a".into(),
+      pods: 3,
+      ..Default::default()
+    }]);
+    table.column_scroll = ColumnScroll {
+      offset: 1,
+      pin_name: false,
+    };
+    let palette = palette_for(ThemeName::Macchiato);
+
+    terminal
+      .draw(|f| {
+        draw_resource_block(
+          f,
+          f.area(),
+          ResourceTableProps {
+            title: " Nodes ".into(),
+            inline_help: Line::default(),
+            resource: &mut table,
+            table_headers: vec!["Name", "Pods"],
+            column_widths: vec![Constraint::Percentage(50), Constraint::Percentage(50)],
+          },
+          |c| {
+            Row::new(vec![
+              Cell::from(c.name.clone()),
+              Cell::from(c.pods.to_string()),
+            ])
+          },
+          palette,
+          ViewLoad::default(),
+        );
+      })
+      .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let line = |row: u16| -> String { (0..40).map(|col| buffer[(col, row)].symbol()).collect() };
+    // The hidden column still takes its one-cell column spacing.
+    assert!(line(1).starts_with("‹   Pods"), "{:?}", line(1));
+    assert!(line(2).starts_with("=>  3"), "{:?}", line(2));
+    assert!(!line(1).ends_with('›'), "{:?}", line(1));
+  }
+
+  #[test]
+  fn test_draw_resource_table_marks_columns_past_the_right_edge() {
+    let mut table = StatefulTable::with_items(vec![crate::app::node_pods::KubeNodePods {
+      name: "node-a".into(),
+      pods: 3,
+      ..Default::default()
+    }]);
+    let palette = palette_for(ThemeName::Macchiato);
+    let mut header_at = |offset: usize| -> String {
+      table.column_scroll = ColumnScroll {
+        offset,
+        pin_name: false,
+      };
+      let mut terminal = Terminal::new(TestBackend::new(24, 4)).unwrap();
+      terminal
+        .draw(|f| {
+          draw_resource_block(
+            f,
+            f.area(),
+            ResourceTableProps {
+              title: " Nodes ".into(),
+              inline_help: Line::default(),
+              resource: &mut table,
+              table_headers: vec!["Name", "Role", "Restarts"],
+              column_widths: vec![Constraint::Length(20); 3],
+            },
+            |c| {
+              Row::new(vec![
+                Cell::from(c.name.clone()),
+                Cell::from("worker"),
+                Cell::from(c.pods.to_string()),
+              ])
+            },
+            palette,
+            ViewLoad::default(),
+          );
+        })
+        .unwrap();
+      let buffer = terminal.backend().buffer();
+      (0..24).map(|col| buffer[(col, 1)].symbol()).collect()
+    };
+
+    // Restarts is squeezed below its header next to the other two
+    let unscrolled = header_at(0);
+    assert!(unscrolled.ends_with('›'), "{:?}", unscrolled);
+    assert!(!unscrolled.starts_with('‹'), "{:?}", unscrolled);
+    // once Name is scrolled away, it fits
+    let scrolled = header_at(1);
+    assert!(scrolled.starts_with('‹'), "{:?}", scrolled);
+    assert!(!scrolled.ends_with('›'), "{:?}", scrolled);
+  }
+
+  #[test]
+  fn test_spinner_frame_advances_with_ticks() {
+    assert_eq!(spinner_frame(0), "⠋");
+    assert_eq!(spinner_frame(1), "⠙");
+    assert_eq!(spinner_frame(10), spinner_frame(0));
+  }
+
+  #[test]
+  fn test_draw_yaml_block_plain_without_enhanced_graphics() {
+    let mut app = yaml_app(false, 5);
+    let backend = TestBackend::new(30, 8);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+      .draw(|f| draw_yaml_block(f, &mut app, f.area(), Line::from("yaml")))
+      .unwrap();
+
+    assert!(app.data.describe_out.highlighted_lines.is_empty());
+    let buffer = terminal.backend().buffer();
+    let row: String = (0..30)
+      .map(|x| buffer[(x, 1)].symbol().to_string())
+      .collect();
+    assert_eq!(row.trim_end(), "key0: 0");
+  }
+
+  #[test]
+  fn test_diff_line_style() {
+    let p = palette_for(ThemeName::Macchiato);
+    let header = style_text(p).add_modifier(Modifier::BOLD);
+    assert_eq!(diff_line_style(0, "--- last-applied", p), header);
+    assert_eq!(diff_line_style(1, "+++ live", p), header);
+    assert_eq!(diff_line_style(2, "@@ -1,3 +1,3 @@", p), style_help(p));
+    assert_eq!(diff_line_style(3, "+  replicas: 5", p), style_success(p));
+    assert_eq!(diff_line_style(4, "-  replicas: 2", p), style_failure(p));
+    assert_eq!(diff_line_style(5, "   kind: Deployment", p), style_text(p));
+    // a removed `---` document separator or `-- note` comment, not a header
+    assert_eq!(diff_line_style(6, "----", p), style_failure(p));
+    assert_eq!(diff_line_style(7, "--- note", p), style_failure(p));
+    assert_eq!(diff_line_style(8, "+++ added", p), style_success(p));
+  }
+
+  const LAYOUT_COLUMNS: [ColumnDef; 3] = [
+    ColumnDef::all("Name", 50, 40, 30),
+    ColumnDef::standard("Node", 30, 30),
+    ColumnDef::all("Age", 50, 30, 40),
+  ];
+
+  #[test]
+  fn test_column_layout_defaults_follow_the_tier() {
+    let layout = ColumnLayout::new(&LAYOUT_COLUMNS, ViewTier::Compact, None);
+    assert_eq!(
+      (layout.headers, layout.widths),
+      responsive_columns(&LAYOUT_COLUMNS, ViewTier::Compact)
+    );
+
+    let empty = ColumnLayout::new(&LAYOUT_COLUMNS, ViewTier::Wide, Some(&["Colour".into()]));
+    assert_eq!(empty.headers, vec!["Name", "Node", "Age"]);
+  }
+
+  #[test]
+  fn test_column_layout_cell_width() {
+    let layout = ColumnLayout::new(&LAYOUT_COLUMNS, ViewTier::Compact, Some(&["Name".into()]));
+    // the whole table, less the highlight symbol
+    assert_eq!(layout.cell_width("Name", 53), Some(50));
+    assert_eq!(layout.cell_width("Age", 53), None);
+  }
+
+  #[test]
+  fn test_gauge_cell_falls_back_to_the_number() {
+    let palette = palette_for(ThemeName::Macchiato);
+    let bar = |pct: &str, width: u16, enhanced: bool| {
+      let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+      Table::new(
+        [Row::new([gauge_cell(pct, width, palette, enhanced)])],
+        [Constraint::Length(width)],
+      )
+      .render(buf.area, &mut buf);
+      (0..width).map(|x| buf[(x, 0)].symbol()).collect::<String>()
+    };
+
+    assert_eq!(bar("50", 12, true), " 50 ████░░░░");
+    assert_eq!(bar("100", 8, true), "100 ████");
+    assert_eq!(bar("50", 12, false).trim_end(), "50");
+    assert_eq!(bar("50", 7, true).trim_end(), "50");
+    assert_eq!(bar("", 12, true).trim_end(), "");
+    assert_eq!(
+      gauge_cell("90", 12, palette, true),
+      Cell::from(Line::from(vec![
+        Span::styled(" 90 ", style_text(palette)),
+        Span::styled("███████░", style_failure(palette)),
+      ]))
+    );
+  }
+
+  #[test]
+  fn test_column_layout_configured_order_at_every_tier() {
+    let configured = [
+      "age".to_string(),
+      "Colour".into(),
+      "Node".into(),
+      "AGE".into(),
+    ];
+    let layout = ColumnLayout::new(&LAYOUT_COLUMNS, ViewTier::Compact, Some(&configured));
+
+    assert_eq!(layout.headers, vec!["Age", "Node"]);
+    assert_eq!(
+      layout.widths,
+      vec![Constraint::Fill(40), Constraint::Fill(30)]
+    );
+    assert_eq!(
+      layout.row(vec![
+        Cell::from("web-1"),
+        Cell::from("node-a"),
+        Cell::from("2d"),
+      ]),
+      Row::new(vec![Cell::from("2d"), Cell::from("node-a")])
+    );
+  }
 }
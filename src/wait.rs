@@ -0,0 +1,292 @@
+//! `kdash wait --for ready deployment/web`: block until an object is ready,
+//! available or deleted, printing each status it goes through, and exit
+//! without starting the UI, for CI/CD scripts. The object is followed with the
+//! watch the apply status overlay uses and read as its own view would, with
+//! the same client setup as the UI: context, impersonation and TLS flags
+//! included. It exits non-zero when the timeout passes first or the watch
+//! keeps failing.
+use std::{
+  fmt,
+  io::{stdout, Write},
+  time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use clap::{Args, ValueEnum};
+use kube::api::DynamicObject;
+use serde_json::Value;
+
+use crate::{
+  app::{
+    apply_status::applied_status, columns, dynamic::api_resource_for_block, watchlist::PinStatus,
+    ActiveBlock,
+  },
+  network::{
+    get_client,
+    stream::{object_api, watch_object},
+  },
+};
+
+/// kubectl's short names of the kinds KDash has a view of.
+const SHORT_NAMES: [(&str, &str); 13] = [
+  ("po", "pods"),
+  ("svc", "services"),
+  ("no", "nodes"),
+  ("ns", "namespaces"),
+  ("cm", "configmaps"),
+  ("deploy", "deployments"),
+  ("sts", "statefulsets"),
+  ("ds", "daemonsets"),
+  ("rs", "replicasets"),
+  ("rc", "replicationcontrollers"),
+  ("pvc", "persistentvolumeclaims"),
+  ("pv", "persistentvolumes"),
+  ("ing", "ingresses"),
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum WaitCondition {
+  /// Ready as its view shows it; a deployment once its rollout completes.
+  Ready,
+  /// Its `Available` condition is true, as deployments report.
+  Available,
+  /// Gone from the cluster, or never there.
+  Deleted,
+}
+
+impl fmt::Display for WaitCondition {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      WaitCondition::Ready => "ready",
+      WaitCondition::Available => "available",
+      WaitCondition::Deleted => "deleted",
+    })
+  }
+}
+
+/// The object to wait for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WaitTarget {
+  pub block: ActiveBlock,
+  /// The kind as given, e.g. `deploy`.
+  pub kind: String,
+  pub name: String,
+}
+
+impl fmt::Display for WaitTarget {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}/{}", self.kind, self.name)
+  }
+}
+
+/// `kind/name`, the kind singular, plural or a kubectl short name, matched
+/// like `kubectl` does and only among kinds KDash has a view of.
+fn parse_target(target: &str) -> Result<WaitTarget, String> {
+  let (kind, name) = target
+    .split_once('/')
+    .filter(|(kind, name)| !kind.is_empty() && !name.is_empty())
+    .ok_or("expected kind/name, e.g. deployment/web")?;
+  let wanted = kind.to_lowercase();
+  let wanted = SHORT_NAMES
+    .iter()
+    .find(|(short, _)| *short == wanted)
+    .map_or(wanted.as_str(), |(_, plural)| plural);
+  let block = columns::view_names()
+    .filter_map(columns::view_block)
+    .find(|&block| {
+      api_resource_for_block(block, None).is_some_and(|(resource, _)| {
+        resource.kind.to_lowercase() == wanted || resource.plural == wanted
+      })
+    })
+    .ok_or_else(|| format!("unknown kind '{}'", kind))?;
+  Ok(WaitTarget {
+    block,
+    kind: kind.to_owned(),
+    name: name.to_owned(),
+  })
+}
+
+#[derive(Args, Debug)]
+pub struct WaitArgs {
+  /// What to wait for.
+  #[arg(long = "for", value_enum, default_value_t = WaitCondition::Ready)]
+  pub condition: WaitCondition,
+  /// The object, as `kind/name`, e.g. `deployment/web` or `sts/db`, in
+  /// `--namespace` or the context's namespace.
+  #[arg(value_parser = parse_target)]
+  pub target: WaitTarget,
+  /// How long to wait before giving up, e.g. `120s` or `5m`.
+  #[arg(long, default_value = "5m", value_parser = humantime::parse_duration)]
+  pub timeout: Duration,
+}
+
+/// Where an object stands: a status line to print and whether the condition
+/// is met.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Progress {
+  status: String,
+  met: bool,
+}
+
+impl WaitCondition {
+  /// How far `object`, `None` when there is none, is from the condition.
+  fn progress(self, block: ActiveBlock, object: Option<Value>) -> Progress {
+    let Some(object) = object else {
+      return Progress {
+        status: "not found".into(),
+        met: self == WaitCondition::Deleted,
+      };
+    };
+    match self {
+      WaitCondition::Ready | WaitCondition::Deleted => {
+        let status = applied_status(block, object);
+        Progress {
+          status: status.text().to_owned(),
+          met: self == WaitCondition::Ready
+            && matches!(status, PinStatus::Live { healthy: true, .. }),
+        }
+      }
+      WaitCondition::Available => {
+        let conditions = object["status"]["conditions"].as_array().cloned();
+        let available = conditions
+          .iter()
+          .flatten()
+          .find(|condition| condition["type"] == "Available");
+        match available {
+          Some(condition) => {
+            let value = condition["status"].as_str().unwrap_or("Unknown");
+            let status = match condition["reason"].as_str() {
+              Some(reason) => format!("Available {} ({})", value, reason),
+              None => format!("Available {}", value),
+            };
+            Progress {
+              status,
+              met: value == "True",
+            }
+          }
+          None => Progress {
+            status: "no Available condition".into(),
+            met: false,
+          },
+        }
+      }
+    }
+  }
+}
+
+/// Wait for `args`, printing the object's status each time it changes.
+pub async fn run(args: WaitArgs, context: Option<String>, namespace: Option<String>) -> Result<()> {
+  let WaitArgs {
+    condition,
+    target,
+    timeout,
+  } = args;
+  let client = get_client(context).await?;
+  let api = object_api(&client, target.block, namespace.as_deref())
+    .ok_or_else(|| anyhow!("Cannot watch {}", target))?;
+
+  let mut last_status = None;
+  let seen = |object: Option<DynamicObject>| {
+    let object = object.and_then(|object| serde_json::to_value(object).ok());
+    let progress = condition.progress(target.block, object);
+    if last_status.as_ref() != Some(&progress.status) {
+      println!("{}: {}", target, progress.status);
+      let _ = stdout().flush();
+      last_status = Some(progress.status);
+    }
+    async move { progress.met }
+  };
+  match tokio::time::timeout(timeout, watch_object(&api, &target.name, seen)).await {
+    Ok(Ok(())) => {
+      println!("{} is {}", target, condition);
+      Ok(())
+    }
+    Ok(Err(error)) => Err(anyhow!("Failed to watch {}: {}", target, error)),
+    Err(_) => Err(anyhow!(
+      "Timed out after {} waiting for {} to be {}",
+      humantime::format_duration(timeout),
+      target,
+      condition
+    )),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn test_parse_target_accepts_kubectl_kind_names() {
+    for kind in ["deployment", "deployments", "Deployment", "deploy"] {
+      let target = parse_target(&format!("{}/web", kind)).unwrap();
+      assert_eq!(target.block, ActiveBlock::Deployments);
+      assert_eq!(target.name, "web");
+    }
+    assert_eq!(
+      parse_target("sts/db").unwrap().block,
+      ActiveBlock::StatefulSets
+    );
+    assert_eq!(
+      parse_target("node/worker-1").unwrap().block,
+      ActiveBlock::Nodes
+    );
+    assert_eq!(
+      parse_target("certificate/web-tls"),
+      Err("unknown kind 'certificate'".into())
+    );
+    assert!(parse_target("web").is_err());
+    assert!(parse_target("deployment/").is_err());
+  }
+
+  #[test]
+  fn test_progress_follows_each_condition() {
+    let deployment = |available: i64, condition: &str| {
+      json!({
+        "apiVersion": "apps/v1",
+        "kind": "Deployment",
+        "metadata": { "name": "web", "namespace": "shop", "generation": 1 },
+        "spec": { "replicas": 2 },
+        "status": {
+          "observedGeneration": 1,
+          "replicas": 2,
+          "updatedReplicas": 2,
+          "availableReplicas": available,
+          "conditions": [
+            { "type": "Available", "status": condition, "reason": "MinimumReplicasAvailable" }
+          ]
+        }
+      })
+    };
+    let block = ActiveBlock::Deployments;
+    let ready = |object| WaitCondition::Ready.progress(block, object);
+    assert!(!ready(Some(deployment(1, "True"))).met);
+    assert!(ready(Some(deployment(2, "True"))).met);
+    assert_eq!(
+      ready(None),
+      Progress {
+        status: "not found".into(),
+        met: false
+      }
+    );
+
+    let available = WaitCondition::Available.progress(block, Some(deployment(1, "True")));
+    assert_eq!(
+      available,
+      Progress {
+        status: "Available True (MinimumReplicasAvailable)".into(),
+        met: true
+      }
+    );
+    assert!(
+      !WaitCondition::Available
+        .progress(block, Some(json!({ "status": {} })))
+        .met
+    );
+
+    let deleted = |object| WaitCondition::Deleted.progress(block, object);
+    assert!(!deleted(Some(deployment(2, "True"))).met);
+    assert!(deleted(None).met);
+  }
+}